@@ -14,4 +14,5 @@ pub mod services_cache;
 mod terminal;
 
 // Re-export the public entrypoint so callers keep using `app::run(...)`.
+pub(crate) use runtime::apply_global_settings;
 pub use runtime::run;
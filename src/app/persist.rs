@@ -1,4 +1,5 @@
 use std::fs;
+use std::time::{Duration, Instant};
 
 use super::deps_cache;
 use super::files_cache;
@@ -6,6 +7,42 @@ use super::sandbox_cache;
 use super::services_cache;
 use crate::state::AppState;
 
+/// What: Decide whether a dirty persisted file should be written now.
+///
+/// Inputs:
+/// - `dirty`: Whether there are unsaved changes.
+/// - `last_write`: Reference time of the throttle window (last write or last change), if any.
+/// - `now`: Current time.
+/// - `interval`: Configured `persist_throttle_ms` window.
+///
+/// Output:
+/// - `true` when dirty and no write happened yet or at least `interval` has elapsed.
+pub fn should_flush(
+    dirty: bool,
+    last_write: Option<Instant>,
+    now: Instant,
+    interval: Duration,
+) -> bool {
+    dirty && last_write.is_none_or(|t| now.saturating_duration_since(t) >= interval)
+}
+
+/// What: Check the throttle window of the persisted file identified by `label`.
+///
+/// Inputs:
+/// - `app`: Application state holding `persist_last_write` and `persist_throttle`.
+/// - `label`: Short key of the persisted file (e.g., `recent`).
+///
+/// Output:
+/// - `true` when enough time has passed since the last write of that file.
+fn write_due(app: &AppState, label: &'static str) -> bool {
+    should_flush(
+        true,
+        app.persist_last_write.get(label).copied(),
+        Instant::now(),
+        app.persist_throttle,
+    )
+}
+
 /// What: Persist the details cache to disk if marked dirty.
 ///
 /// Inputs:
@@ -14,12 +51,14 @@ use crate::state::AppState;
 /// Output:
 /// - Writes `details_cache` JSON to `cache_path` and clears the dirty flag on success.
 pub fn maybe_flush_cache(app: &mut AppState) {
-    if !app.cache_dirty {
+    if !app.cache_dirty || !write_due(app, "details_cache") {
         return;
     }
     if let Ok(s) = serde_json::to_string(&app.details_cache) {
         let _ = fs::write(&app.cache_path, s);
         app.cache_dirty = false;
+        app.persist_last_write
+            .insert("details_cache", Instant::now());
     }
 }
 
@@ -31,12 +70,13 @@ pub fn maybe_flush_cache(app: &mut AppState) {
 /// Output:
 /// - Writes `recent` JSON to `recent_path` and clears the dirty flag on success.
 pub fn maybe_flush_recent(app: &mut AppState) {
-    if !app.recent_dirty {
+    if !app.recent_dirty || !write_due(app, "recent") {
         return;
     }
     if let Ok(s) = serde_json::to_string(&app.recent) {
         let _ = fs::write(&app.recent_path, s);
         app.recent_dirty = false;
+        app.persist_last_write.insert("recent", Instant::now());
     }
 }
 
@@ -48,12 +88,13 @@ pub fn maybe_flush_recent(app: &mut AppState) {
 /// Output:
 /// - Writes `news_read_urls` JSON to `news_read_path` and clears the dirty flag on success.
 pub fn maybe_flush_news_read(app: &mut AppState) {
-    if !app.news_read_dirty {
+    if !app.news_read_dirty || !write_due(app, "news_read") {
         return;
     }
     if let Ok(s) = serde_json::to_string(&app.news_read_urls) {
         let _ = fs::write(&app.news_read_path, s);
         app.news_read_dirty = false;
+        app.persist_last_write.insert("news_read", Instant::now());
     }
 }
 
@@ -72,12 +113,13 @@ pub fn maybe_flush_deps_cache(app: &mut AppState) {
         app.deps_cache_dirty = false;
         return;
     }
-    if !app.deps_cache_dirty {
+    if !app.deps_cache_dirty || !write_due(app, "deps_cache") {
         return;
     }
     let signature = deps_cache::compute_signature(&app.install_list);
     deps_cache::save_cache(&app.deps_cache_path, &signature, &app.install_list_deps);
     app.deps_cache_dirty = false;
+    app.persist_last_write.insert("deps_cache", Instant::now());
 }
 
 /// What: Persist the file cache to disk if marked dirty.
@@ -95,12 +137,13 @@ pub fn maybe_flush_files_cache(app: &mut AppState) {
         app.files_cache_dirty = false;
         return;
     }
-    if !app.files_cache_dirty {
+    if !app.files_cache_dirty || !write_due(app, "files_cache") {
         return;
     }
     let signature = files_cache::compute_signature(&app.install_list);
     files_cache::save_cache(&app.files_cache_path, &signature, &app.install_list_files);
     app.files_cache_dirty = false;
+    app.persist_last_write.insert("files_cache", Instant::now());
 }
 
 /// What: Persist the service cache to disk if marked dirty.
@@ -118,7 +161,7 @@ pub fn maybe_flush_services_cache(app: &mut AppState) {
         app.services_cache_dirty = false;
         return;
     }
-    if !app.services_cache_dirty {
+    if !app.services_cache_dirty || !write_due(app, "services_cache") {
         return;
     }
     let signature = services_cache::compute_signature(&app.install_list);
@@ -128,6 +171,8 @@ pub fn maybe_flush_services_cache(app: &mut AppState) {
         &app.install_list_services,
     );
     app.services_cache_dirty = false;
+    app.persist_last_write
+        .insert("services_cache", Instant::now());
 }
 
 /// What: Persist the sandbox cache to disk if marked dirty.
@@ -145,7 +190,7 @@ pub fn maybe_flush_sandbox_cache(app: &mut AppState) {
         app.sandbox_cache_dirty = false;
         return;
     }
-    if !app.sandbox_cache_dirty {
+    if !app.sandbox_cache_dirty || !write_due(app, "sandbox_cache") {
         return;
     }
     let signature = sandbox_cache::compute_signature(&app.install_list);
@@ -155,9 +200,11 @@ pub fn maybe_flush_sandbox_cache(app: &mut AppState) {
         &app.install_list_sandbox,
     );
     app.sandbox_cache_dirty = false;
+    app.persist_last_write
+        .insert("sandbox_cache", Instant::now());
 }
 
-/// What: Persist the install list to disk if marked dirty, throttled to ~1s.
+/// What: Persist the install list to disk if marked dirty, throttled by `persist_throttle`.
 ///
 /// Inputs:
 /// - `app`: Application state with `install_list`, `install_path`, and throttle timestamps
@@ -166,13 +213,13 @@ pub fn maybe_flush_sandbox_cache(app: &mut AppState) {
 /// - Writes `install_list` JSON to `install_path` and clears dirty flags when written.
 pub fn maybe_flush_install(app: &mut AppState) {
     // Throttle disk writes: only flush if dirty and either never written
-    // before or the last change is at least 1s ago.
-    if !app.install_dirty {
-        return;
-    }
-    if let Some(when) = app.last_install_change
-        && when.elapsed() < std::time::Duration::from_millis(1000)
-    {
+    // before or the last change is at least one throttle interval ago.
+    if !should_flush(
+        app.install_dirty,
+        app.last_install_change,
+        Instant::now(),
+        app.persist_throttle,
+    ) {
         return;
     }
     if let Ok(s) = serde_json::to_string(&app.install_list) {
@@ -182,6 +229,29 @@ pub fn maybe_flush_install(app: &mut AppState) {
     }
 }
 
+/// What: Write every dirty persisted file immediately, ignoring the throttle.
+///
+/// Inputs:
+/// - `app`: Application state to persist
+///
+/// Output:
+/// - Same effects as calling each `maybe_flush_*` with an elapsed throttle window.
+///
+/// Details:
+/// - Used on shutdown so pending changes are never lost to throttling.
+pub fn flush_all_now(app: &mut AppState) {
+    app.persist_last_write.clear();
+    app.last_install_change = None;
+    maybe_flush_cache(app);
+    maybe_flush_recent(app);
+    maybe_flush_news_read(app);
+    maybe_flush_install(app);
+    maybe_flush_deps_cache(app);
+    maybe_flush_files_cache(app);
+    maybe_flush_services_cache(app);
+    maybe_flush_sandbox_cache(app);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -197,6 +267,42 @@ mod tests {
         }
     }
 
+    #[test]
+    /// What: Ensure the flush decision honours the dirty flag and the configured interval.
+    ///
+    /// Inputs:
+    /// - A 500 ms interval with no previous write, a write 200 ms ago, and one 500 ms ago.
+    ///
+    /// Output:
+    /// - Flushes when never written or the window elapsed; skips when clean or too recent.
+    ///
+    /// Details:
+    /// - A zero interval flushes on every dirty tick; a throttled recent write blocks
+    ///   `maybe_flush_recent` until the window passes.
+    fn should_flush_respects_interval() {
+        let now = Instant::now();
+        let interval = Duration::from_millis(500);
+        let ago = |ms| now.checked_sub(Duration::from_millis(ms));
+        assert!(should_flush(true, None, now, interval));
+        assert!(!should_flush(false, None, now, interval));
+        assert!(!should_flush(true, ago(200), now, interval));
+        assert!(should_flush(true, ago(500), now, interval));
+        assert!(should_flush(true, Some(now), now, Duration::ZERO));
+
+        let mut app = new_app();
+        let dir = tempfile::tempdir().expect("tempdir");
+        app.recent_path = dir.path().join("recent.json");
+        app.persist_throttle = Duration::from_secs(3600);
+        app.persist_last_write.insert("recent", Instant::now());
+        app.recent_dirty = true;
+        maybe_flush_recent(&mut app);
+        assert!(app.recent_dirty);
+        assert!(!app.recent_path.exists());
+        flush_all_now(&mut app);
+        assert!(!app.recent_dirty);
+        assert!(app.recent_path.exists());
+    }
+
     #[test]
     /// What: Ensure `maybe_flush_cache` persists the details cache and clears the dirty flag.
     ///
@@ -22,13 +22,51 @@ use crate::util::{match_rank, repo_order};
 use super::deps_cache;
 use super::files_cache;
 use super::persist::{
-    maybe_flush_cache, maybe_flush_deps_cache, maybe_flush_files_cache, maybe_flush_install,
-    maybe_flush_news_read, maybe_flush_recent, maybe_flush_sandbox_cache,
+    flush_all_now, maybe_flush_cache, maybe_flush_deps_cache, maybe_flush_files_cache,
+    maybe_flush_install, maybe_flush_news_read, maybe_flush_recent, maybe_flush_sandbox_cache,
     maybe_flush_services_cache,
 };
 use super::recent::maybe_save_recent;
 use super::sandbox_cache;
 
+/// What: Push settings that live outside `AppState` into their process-wide holders.
+///
+/// Inputs:
+/// - `prefs`: Parsed settings.
+///
+/// Output:
+/// - None; updates caches, AUR/pacman/privilege choices, proxies, index compression, and color
+///   support.
+///
+/// Details:
+/// - Runs at startup and again when the reload-settings keybind re-reads the config.
+pub(crate) fn apply_global_settings(prefs: &crate::theme::Settings) {
+    crate::sources::pkgbuild_cache::set_ttl_secs(prefs.pkgbuild_cache_ttl_secs);
+    crate::sources::set_aur_disabled(prefs.aur_disabled);
+    crate::util::set_proxy_settings(&prefs.http_proxy, &prefs.https_proxy);
+    let aur_helper = crate::logic::deps::AurHelper::from_name(&prefs.preferred_aur_helper);
+    if aur_helper.is_none() && !prefs.preferred_aur_helper.trim().is_empty() {
+        tracing::warn!(
+            helper = %prefs.preferred_aur_helper,
+            "unknown preferred_aur_helper; using the default order"
+        );
+    }
+    crate::logic::deps::set_preferred_aur_helper(aur_helper);
+    crate::logic::deps::set_resolve_concurrency(prefs.deps_resolve_concurrency);
+    crate::install::privilege::set_pacman_binary(&prefs.pacman_binary);
+    let privilege =
+        crate::install::privilege::PrivilegeTool::from_name(&prefs.privilege_escalation);
+    if privilege.is_none() {
+        tracing::warn!(
+            tool = %prefs.privilege_escalation,
+            "unknown privilege_escalation; using sudo"
+        );
+    }
+    crate::install::privilege::set_privilege_tool(privilege.unwrap_or_default());
+    pkgindex::set_compress_index(prefs.compress_index);
+    crate::theme::set_color_support(crate::theme::resolve_color_support(&prefs.force_color));
+}
+
 /// What: Initialize the locale system: resolve locale, load translations, set up fallbacks.
 ///
 /// Inputs:
@@ -202,21 +240,22 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
     let prefs = crate::theme::settings();
     // Ensure config has all known settings keys (non-destructive append)
     crate::theme::ensure_settings_keys_present(&prefs);
-    app.layout_left_pct = prefs.layout_left_pct;
-    app.layout_center_pct = prefs.layout_center_pct;
-    app.layout_right_pct = prefs.layout_right_pct;
-    app.keymap = prefs.keymap.clone();
-    app.sort_mode = prefs.sort_mode;
-    app.package_marker = prefs.package_marker;
-    // Apply initial visibility for middle row panes from settings
-    app.show_recent_pane = prefs.show_recent_pane;
-    app.show_install_pane = prefs.show_install_pane;
-    // Apply initial keybind footer visibility (default true if not present)
-    app.show_keybinds_footer = prefs.show_keybinds_footer;
+    app.apply_startup_settings(&prefs);
+    apply_global_settings(&prefs);
+    let active_theme = prefs.active_theme.trim();
+    if !active_theme.is_empty()
+        && let Err(e) = crate::theme::switch_theme(active_theme)
+    {
+        tracing::warn!(theme = %active_theme, error = %e, "active_theme not loaded; using theme.conf");
+    }
+    if prefs.theme_auto_reload && !headless {
+        crate::theme::start_theme_watcher();
+    }
 
     // Initialize locale system (clone locale string to avoid borrow issues)
     let locale_pref = prefs.locale.clone();
     initialize_locale_system(&mut app, &locale_pref, &prefs);
+    crate::events::notify_layout_rescaled(&mut app, &prefs);
 
     // GNOME desktop: prompt to install a GNOME terminal if none present (gnome-terminal or gnome-console/kgx)
     // Skip this check in headless mode to avoid slow PATH scanning
@@ -231,6 +270,50 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
         if is_gnome && !(has_gterm || has_gconsole) {
             app.modal = crate::state::Modal::GnomeTerminalPrompt;
         }
+        // Every network fetch shells out to curl; explain a missing binary once, up front
+        if !crate::util::curl_available() {
+            tracing::warn!("curl not found on PATH; network features will fail");
+            let key = if cfg!(target_os = "windows") {
+                "app.modals.curl_missing.windows"
+            } else {
+                "app.modals.curl_missing.linux"
+            };
+            app.modal = crate::state::Modal::Alert {
+                message: crate::i18n::t(&app, key),
+            };
+        }
+    }
+
+    // Point out keybinds.conf lines the loader ignored and chords that shadow each other
+    if !headless {
+        let issues = crate::theme::validate_keybinds();
+        for (line, reason) in &issues {
+            tracing::warn!(line, reason = %reason, "keybinds.conf problem");
+        }
+        let conflicts = crate::theme::find_keymap_conflicts(&app.keymap);
+        for c in &conflicts {
+            tracing::warn!(context = c.context, chord = %c.chord.label(), actions = ?c.actions, "conflicting keybinding");
+        }
+        if !conflicts.is_empty() {
+            let list = conflicts
+                .iter()
+                .map(|c| format!("{} → {}", c.chord.label(), c.actions.join(", ")))
+                .collect::<Vec<_>>()
+                .join("; ");
+            app.toast_message = Some(crate::i18n::t_fmt1(
+                &app,
+                "app.toasts.keybind_conflicts",
+                list,
+            ));
+            app.toast_expires_at = Some(Instant::now() + Duration::from_secs(8));
+        } else if !issues.is_empty() {
+            app.toast_message = Some(crate::i18n::t_fmt1(
+                &app,
+                "app.toasts.keybinds_invalid",
+                issues.len(),
+            ));
+            app.toast_expires_at = Some(Instant::now() + Duration::from_secs(6));
+        }
     }
 
     if let Ok(s) = std::fs::read_to_string(&app.cache_path)
@@ -327,6 +410,7 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
     }
 
     pkgindex::load_from_disk(&app.official_index_path);
+    app.index_modified_at = pkgindex::index_file_modified(&app.official_index_path);
     tracing::info!(path = %app.official_index_path.display(), "attempted to load official index from disk");
 
     let (event_tx, mut event_rx) = mpsc::unbounded_channel::<CEvent>();
@@ -340,17 +424,23 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
     let (preview_tx, mut preview_rx) = mpsc::unbounded_channel::<PackageItem>();
     let (add_tx, mut add_rx) = mpsc::unbounded_channel::<PackageItem>();
     let (index_notify_tx, mut index_notify_rx) = mpsc::unbounded_channel::<()>();
+    let (index_refresh_done_tx, mut index_refresh_done_rx) =
+        mpsc::unbounded_channel::<Option<pkgindex::IndexDiff>>();
     let (pkgb_req_tx, mut pkgb_req_rx) = mpsc::unbounded_channel::<PackageItem>();
     let (pkgb_res_tx, mut pkgb_res_rx) = mpsc::unbounded_channel::<(String, String)>();
     let (status_tx, mut status_rx) =
         mpsc::unbounded_channel::<(String, crate::state::ArchStatusColor)>();
     let (news_tx, mut news_rx) = mpsc::unbounded_channel::<Vec<NewsItem>>();
+    let (hook_res_tx, mut hook_res_rx) =
+        mpsc::unbounded_channel::<std::result::Result<Option<std::path::PathBuf>, String>>();
     let (deps_req_tx, mut deps_req_rx) = mpsc::unbounded_channel::<Vec<PackageItem>>();
     let (deps_res_tx, mut deps_res_rx) =
         mpsc::unbounded_channel::<Vec<crate::state::modal::DependencyInfo>>();
     let (files_req_tx, mut files_req_rx) = mpsc::unbounded_channel::<Vec<PackageItem>>();
     let (files_res_tx, mut files_res_rx) =
         mpsc::unbounded_channel::<Vec<crate::state::modal::PackageFileInfo>>();
+    let (resolve_progress_tx, mut resolve_progress_rx) =
+        mpsc::unbounded_channel::<crate::state::modal::ResolveProgress>();
     let (services_req_tx, mut services_req_rx) = mpsc::unbounded_channel::<Vec<PackageItem>>();
     let (services_res_tx, mut services_res_rx) =
         mpsc::unbounded_channel::<Vec<crate::state::modal::ServiceImpact>>();
@@ -361,6 +451,17 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
         mpsc::unbounded_channel::<(Vec<PackageItem>, crate::state::modal::PreflightAction)>();
     let (summary_res_tx, mut summary_res_rx) =
         mpsc::unbounded_channel::<crate::logic::preflight::PreflightSummaryOutcome>();
+    let (upgrades_res_tx, mut upgrades_res_rx) =
+        mpsc::unbounded_channel::<(Vec<PackageItem>, Vec<String>)>();
+    let (leftovers_res_tx, mut leftovers_res_rx) =
+        mpsc::unbounded_channel::<Vec<crate::logic::pacnew::ConfigLeftover>>();
+    let (clipboard_res_tx, mut clipboard_res_rx) =
+        mpsc::unbounded_channel::<std::result::Result<(), String>>();
+    let (why_res_tx, mut why_res_rx) = mpsc::unbounded_channel::<(String, Vec<Vec<String>>)>();
+    let (files_list_tx, mut files_list_rx) =
+        mpsc::unbounded_channel::<(String, std::result::Result<Vec<String>, String>)>();
+    let (orphans_res_tx, mut orphans_res_rx) =
+        mpsc::unbounded_channel::<std::result::Result<Vec<String>, String>>();
 
     let net_err_tx_details = net_err_tx.clone();
     tokio::spawn(async move {
@@ -447,14 +548,32 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
 
     // Background dependency resolution worker
     let deps_res_tx_bg = deps_res_tx.clone();
+    let deps_cancel = app.preflight_cancelled.clone();
+    let deps_progress_tx = resolve_progress_tx.clone();
     tokio::spawn(async move {
         while let Some(items) = deps_req_rx.recv().await {
             // Run blocking dependency resolution in a thread pool
             let items_clone = items.clone();
             let res_tx = deps_res_tx_bg.clone();
             let res_tx_error = deps_res_tx_bg.clone(); // Clone for error handling
+            let cancel = deps_cancel.clone();
+            let progress_tx = deps_progress_tx.clone();
             let handle = tokio::task::spawn_blocking(move || {
-                let deps = crate::logic::deps::resolve_dependencies(&items_clone);
+                // Progress stops once the modal is closed (cancelled)
+                let report = |done, total| {
+                    if !cancel.load(std::sync::atomic::Ordering::Relaxed) {
+                        let _ = progress_tx.send(crate::state::modal::ResolveProgress {
+                            stage: crate::state::PreflightTab::Deps,
+                            done,
+                            total,
+                        });
+                    }
+                };
+                let deps = crate::logic::deps::resolve_dependencies_with_progress(
+                    &items_clone,
+                    &cancel,
+                    &report,
+                );
                 let _ = res_tx.send(deps);
             });
             // CRITICAL: Always await and send a result, even if task panics
@@ -478,15 +597,29 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
 
     // Background file resolution worker
     let files_res_tx_bg = files_res_tx.clone();
+    let files_cancel = app.preflight_cancelled.clone();
+    let files_progress_tx = resolve_progress_tx.clone();
     tokio::spawn(async move {
         while let Some(items) = files_req_rx.recv().await {
             // Run blocking file resolution in a thread pool
             let items_clone = items.clone();
             let res_tx = files_res_tx_bg.clone();
+            let cancel = files_cancel.clone();
+            let progress_tx = files_progress_tx.clone();
             tokio::task::spawn_blocking(move || {
-                let files = crate::logic::files::resolve_file_changes(
+                let report = |done, total| {
+                    if !cancel.load(std::sync::atomic::Ordering::Relaxed) {
+                        let _ = progress_tx.send(crate::state::modal::ResolveProgress {
+                            stage: crate::state::PreflightTab::Files,
+                            done,
+                            total,
+                        });
+                    }
+                };
+                let files = crate::logic::files::resolve_file_changes_with_progress(
                     &items_clone,
                     crate::state::modal::PreflightAction::Install,
+                    &report,
                 );
                 let _ = res_tx.send(files);
             });
@@ -570,6 +703,7 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
                                 service_restart_units: Vec::new(),
                                 summary_warnings: vec!["Summary computation failed".to_string()],
                                 summary_notes: Vec::new(),
+                                resolved_transaction: None,
                             },
                             header: crate::state::modal::PreflightHeaderChips {
                                 package_count: 0,
@@ -632,6 +766,8 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
     if !headless {
         pkgindex::refresh_installed_cache().await;
         pkgindex::refresh_explicit_cache().await;
+        // AUR helper `--version` probes are answered from this cache afterwards
+        tokio::task::spawn_blocking(crate::logic::deps::probe_aur_helpers);
     }
 
     // Trigger background dependency resolution if cache was missing/invalid
@@ -718,7 +854,6 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
     let net_err_tx_search = net_err_tx.clone();
     let index_path = app.official_index_path.clone();
     tokio::spawn(async move {
-        const DEBOUNCE_MS: u64 = 250;
         const MIN_INTERVAL_MS: u64 = 300;
         let mut last_sent = Instant::now() - Duration::from_millis(MIN_INTERVAL_MS);
         loop {
@@ -726,8 +861,18 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
                 Some(q) => q,
                 None => break,
             };
-            loop {
-                select! { Some(new_q) = query_rx.recv() => { latest = new_q; } _ = sleep(Duration::from_millis(DEBOUNCE_MS)) => { break; } }
+            // Each query carries its own debounce (`search_debounce_ms`, zero when flushed on Enter)
+            while !latest.debounce.is_zero() {
+                select! { Some(new_q) = query_rx.recv() => { latest = new_q; } _ = sleep(latest.debounce) => { break; } }
+            }
+            // Results of older queries would be dropped anyway; stop their requests early
+            let killed = sources::cancel_searches_before(latest.id);
+            if killed > 0 {
+                tracing::debug!(
+                    killed,
+                    query_id = latest.id,
+                    "cancelled superseded searches"
+                );
             }
             if latest.text.trim().is_empty() {
                 let mut items = pkgindex::all_official_or_fetch(&index_path).await;
@@ -748,11 +893,12 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
                 let _ = search_result_tx.send(SearchResults {
                     id: latest.id,
                     items,
+                    local_fallback: false,
                 });
                 continue;
             }
             let elapsed = last_sent.elapsed();
-            if elapsed < Duration::from_millis(MIN_INTERVAL_MS) {
+            if !latest.debounce.is_zero() && elapsed < Duration::from_millis(MIN_INTERVAL_MS) {
                 sleep(Duration::from_millis(MIN_INTERVAL_MS) - elapsed).await;
             }
             last_sent = Instant::now();
@@ -766,10 +912,27 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
                 if crate::index::all_official().is_empty() {
                     let _ = crate::index::all_official_or_fetch(&ipath).await;
                 }
+                let index_empty = crate::index::all_official().is_empty();
                 let mut items = pkgindex::search_official(&qtext);
                 let q_for_net = qtext.clone();
-                let (aur_items, errors) = sources::fetch_all_with_errors(q_for_net).await;
+                // The AUR RPC has no regex search; `/.../` queries only match the local index
+                let is_regex = crate::logic::filter::regex_body(qtext.trim()).is_some();
+                let (aur_items, errors) = if is_regex {
+                    (Vec::new(), Vec::new())
+                } else {
+                    sources::fetch_all_with_errors_for(q_for_net, sid).await
+                };
                 items.extend(aur_items);
+                // Keep official search working when the index could not be built or the web is
+                // unreachable; duplicates of index entries are dropped by the dedupe below
+                let local_fallback = !is_regex && (index_empty || !errors.is_empty());
+                if local_fallback {
+                    let q = qtext.clone();
+                    let local = tokio::task::spawn_blocking(move || sources::search_sync_dbs(&q))
+                        .await
+                        .unwrap_or_default();
+                    items.extend(local);
+                }
                 let ql = qtext.trim().to_lowercase();
                 items.sort_by(|a, b| {
                     let oa = repo_order(&a.source);
@@ -793,7 +956,11 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
                 for e in errors {
                     let _ = err_tx.send(e);
                 }
-                let _ = tx.send(SearchResults { id: sid, items });
+                let _ = tx.send(SearchResults {
+                    id: sid,
+                    items,
+                    local_fallback,
+                });
             });
         }
     });
@@ -801,6 +968,7 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
     send_query(&mut app, &query_tx);
 
     loop {
+        crate::logic::files::refresh_file_collisions(&mut app);
         if let Some(t) = terminal.as_mut() {
             let _ = t.draw(|f| ui(f, &mut app));
         }
@@ -809,46 +977,30 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
             Some(ev) = event_rx.recv() => { if crate::events::handle_event(ev, &mut app, &query_tx, &details_req_tx, &preview_tx, &add_tx, &pkgb_req_tx) { break; } }
             Some(_) = index_notify_rx.recv() => {
                 app.loading_index = false;
+                app.index_modified_at = pkgindex::index_file_modified(&app.official_index_path);
                 let _ = tick_tx.send(());
             }
+            Some(diff) = index_refresh_done_rx.recv() => {
+                app.loading_index = false;
+                app.index_modified_at = pkgindex::index_file_modified(&app.official_index_path);
+                if let Some(diff) = diff {
+                    app.toast_message = Some(crate::i18n::t_fmt(
+                        &app,
+                        "app.toasts.index_updated",
+                        &[&diff.changed, &diff.added, &diff.removed],
+                    ));
+                    app.toast_expires_at = Some(Instant::now() + Duration::from_secs(4));
+                }
+            }
             Some(new_results) = results_rx.recv() => {
                 if new_results.id != app.latest_query_id { continue; }
+                app.search_local_fallback = new_results.local_fallback;
                 let prev_selected_name = app.results.get(app.selected).map(|p| p.name.clone());
                 // Respect installed-only mode: keep results restricted to explicit installs
                 let mut incoming = new_results.items;
                 if app.installed_only_mode {
                     let explicit = crate::index::explicit_names();
-                    if app.input.trim().is_empty() {
-                        // For empty query, reconstruct full installed list (official + AUR fallbacks)
-                        let mut items: Vec<PackageItem> = crate::index::all_official()
-                            .into_iter()
-                            .filter(|p| explicit.contains(&p.name))
-                            .collect();
-                        use std::collections::HashSet;
-                        let official_names: HashSet<String> =
-                            items.iter().map(|p| p.name.clone()).collect();
-                        for name in explicit.into_iter() {
-                            if !official_names.contains(&name) {
-                                let is_eos = name.to_lowercase().contains("eos-");
-                                let src = if is_eos {
-                                    Source::Official { repo: "EOS".to_string(), arch: String::new() }
-                                } else {
-                                    Source::Aur
-                                };
-                                items.push(PackageItem {
-                                    name: name.clone(),
-                                    version: String::new(),
-                                    description: String::new(),
-                                    source: src,
-                                    popularity: None,
-                                });
-                            }
-                        }
-                        incoming = items;
-                    } else {
-                        // For non-empty query, just intersect results with explicit installed set
-                        incoming.retain(|p| explicit.contains(&p.name));
-                    }
+                    incoming = crate::logic::filter::installed_only_results(incoming, &app.input, &explicit);
                 }
                 app.all_results = incoming;
                 crate::logic::apply_filters_and_sort_preserve_selection(&mut app);
@@ -875,6 +1027,15 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
                 }
                 app.details_cache.insert(details.name.clone(), details.clone());
                 app.cache_dirty = true;
+                if app.pending_homepage_for.as_deref() == Some(details.name.as_str()) {
+                    app.pending_homepage_for = None;
+                    if details.url.is_empty() {
+                        app.toast_message = Some(crate::i18n::t(&app, "app.toasts.homepage_missing"));
+                        app.toast_expires_at = Some(Instant::now() + Duration::from_secs(3));
+                    } else {
+                        crate::util::open_url(&details.url);
+                    }
+                }
                 if let Some(pos) = app.results.iter().position(|p| p.name == details.name) {
                     app.results[pos].description = details.description.clone();
                     if !details.version.is_empty() && app.results[pos].version != details.version { app.results[pos].version = details.version.clone(); }
@@ -919,12 +1080,23 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
                     let _ = sandbox_req_tx.send(app.install_list.clone());
                 }
             }
+            Some(progress) = resolve_progress_rx.recv() => {
+                // Late reports from a closed (cancelled) preflight are dropped
+                if !app.preflight_cancelled.load(std::sync::atomic::Ordering::Relaxed) {
+                    let slot = match progress.stage {
+                        crate::state::PreflightTab::Files => &mut app.preflight_files_progress,
+                        _ => &mut app.preflight_deps_progress,
+                    };
+                    *slot = Some((progress.done, progress.total));
+                }
+            }
             Some(deps) = deps_res_rx.recv() => {
                 // Check if cancelled before updating
                 let cancelled = app.preflight_cancelled.load(std::sync::atomic::Ordering::Relaxed);
                 let was_preflight = app.preflight_deps_resolving;
                 app.deps_resolving = false; // CRITICAL: Always reset this flag when we receive ANY result
                 app.preflight_deps_resolving = false; // Also reset preflight flag
+                app.preflight_deps_progress = None;
 
                 if !cancelled {
                     // Update cached dependencies
@@ -973,6 +1145,7 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
                 let was_preflight = app.preflight_files_resolving;
                 app.files_resolving = false;
                 app.preflight_files_resolving = false; // Also reset preflight flag
+                app.preflight_files_progress = None;
 
                 if !cancelled {
                     // Update cached files
@@ -1176,10 +1349,40 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
                 app.preflight_summary_items = None;
                 let _ = tick_tx.send(());
             }
-            Some(msg) = net_err_rx.recv() => { app.modal = Modal::Alert { message: msg }; }
+            Some((upgrades, commands)) = upgrades_res_rx.recv() => {
+                crate::events::finish_upgrades_query(&mut app, upgrades, commands);
+                let _ = tick_tx.send(());
+            }
+            Some(found) = leftovers_res_rx.recv() => {
+                crate::events::finish_leftovers_scan(&mut app, found);
+                let _ = tick_tx.send(());
+            }
+            Some(result) = clipboard_res_rx.recv() => {
+                crate::events::finish_view_settings_copy(&mut app, result);
+            }
+            Some((package, chains)) = why_res_rx.recv() => {
+                crate::events::finish_why_installed(&mut app, &package, chains);
+                let _ = tick_tx.send(());
+            }
+            Some((package, result)) = files_list_rx.recv() => {
+                crate::events::finish_package_files(&mut app, &package, result);
+                let _ = tick_tx.send(());
+            }
+            Some(result) = orphans_res_rx.recv() => {
+                crate::events::finish_orphans(&mut app, result, &details_req_tx);
+                let _ = tick_tx.send(());
+            }
+            Some(msg) = net_err_rx.recv() => {
+                crate::logic::errors::record_error(&mut app, "network", msg.clone());
+                app.modal = Modal::Alert { message: msg };
+            }
             Some(_) = tick_rx.recv() => { maybe_save_recent(&mut app); maybe_flush_cache(&mut app); maybe_flush_recent(&mut app); maybe_flush_news_read(&mut app); maybe_flush_install(&mut app); maybe_flush_deps_cache(&mut app); maybe_flush_files_cache(&mut app); maybe_flush_services_cache(&mut app); maybe_flush_sandbox_cache(&mut app);
                 // Check cancellation flag - if cancelled, clear queues and skip work
                 let cancelled = app.preflight_cancelled.load(std::sync::atomic::Ordering::Relaxed);
+                // Follow terminal scans started from Preflight; closing it stops their scanners
+                if !app.scan_progress.is_empty() {
+                    crate::install::poll_scans(&mut app.scan_progress, cancelled);
+                }
                 if cancelled {
                     // Clear all queues if cancelled
                     app.preflight_summary_items = None;
@@ -1187,6 +1390,8 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
                     app.preflight_files_items = None;
                     app.preflight_services_items = None;
                     app.preflight_sandbox_items = None;
+                    app.preflight_deps_progress = None;
+                    app.preflight_files_progress = None;
                 } else {
                     // Check for preflight resolution requests - each stage has its own queue
                     if let Some((ref items, ref action)) = app.preflight_summary_items {
@@ -1227,6 +1432,76 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
                         let _ = sandbox_req_tx.send(items.clone());
                     }
                 }
+                // System Update review: `pacman -Qu` and the helper's `-Qua` can take seconds
+                if let Some((official, aur, commands)) = app.upgrades_query_request.take() {
+                    let tx = upgrades_res_tx.clone();
+                    tokio::task::spawn_blocking(move || {
+                        let upgrades = crate::logic::upgrades::list_system_upgrades(official, aur);
+                        let _ = tx.send((upgrades, commands));
+                    });
+                }
+                // Config leftovers: walking /etc, /boot and /opt can take a while on a cold cache
+                if std::mem::take(&mut app.leftovers_scan_requested) {
+                    let tx = leftovers_res_tx.clone();
+                    tokio::task::spawn_blocking(move || {
+                        let _ = tx.send(crate::logic::pacnew::scan_system_leftovers());
+                    });
+                }
+                // Why installed: reads `pacman -Qi` for every installed package
+                if let Some(package) = app.why_installed_request.take() {
+                    let tx = why_res_tx.clone();
+                    tokio::task::spawn_blocking(move || {
+                        let chains = crate::logic::deps::reverse_why(&package);
+                        let _ = tx.send((package, chains));
+                    });
+                }
+                // Package files: `pacman -Fl` reads the whole files database
+                if let Some(item) = app.package_files_request.take() {
+                    let tx = files_list_tx.clone();
+                    tokio::task::spawn_blocking(move || {
+                        let result = crate::logic::files::package_file_list(&item);
+                        let _ = tx.send((item.name, result));
+                    });
+                }
+                // Remove orphans: `pacman -Qtdq` can wait on a locked database
+                if std::mem::take(&mut app.orphans_requested) {
+                    let tx = orphans_res_tx.clone();
+                    tokio::task::spawn_blocking(move || {
+                        let _ = tx.send(crate::index::find_orphans());
+                    });
+                }
+                // Ctrl+Y: clipboard tools block until they exit
+                if let Some(payload) = app.view_settings_copy_request.take() {
+                    let tx = clipboard_res_tx.clone();
+                    tokio::task::spawn_blocking(move || {
+                        let _ = tx.send(crate::util::copy_to_clipboard(&payload));
+                    });
+                }
+                // Periodic (index_refresh_interval_mins) or click-requested official index refresh;
+                // never overlaps a running one
+                #[cfg(not(windows))]
+                if !headless
+                    && ((app.index_refresh_requested && !app.loading_index)
+                        || pkgindex::index_refresh_due(
+                            app.last_index_refresh,
+                            Instant::now(),
+                            app.index_refresh_interval_mins,
+                            app.loading_index,
+                        ))
+                {
+                    app.index_refresh_requested = false;
+                    app.loading_index = true;
+                    app.last_index_refresh = Instant::now();
+                    let path = app.official_index_path.clone();
+                    let notify_tx = index_notify_tx.clone();
+                    let done_tx = index_refresh_done_tx.clone();
+                    tokio::spawn(async move {
+                        // Errors are only logged: an offline session should not raise an alert every interval
+                        let (err_tx, _) = mpsc::unbounded_channel::<String>();
+                        let diff = pkgindex::refresh_official_index(path, err_tx, notify_tx).await;
+                        let _ = done_tx.send(diff);
+                    });
+                }
                 // Check for pending PKGBUILD reload request (debounce delay)
                 const PKGBUILD_DEBOUNCE_MS: u64 = 250;
                 if let (Some(requested_at), Some(requested_for)) = (app.pkgb_reload_requested_at, &app.pkgb_reload_requested_for) {
@@ -1251,6 +1526,7 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
                         app.refresh_installed_until = None;
                         app.next_installed_refresh_at = None;
                         app.pending_install_names = None;
+                        app.pending_install_baseline.clear();
                     } else {
                         let should_poll = app
                             .next_installed_refresh_at
@@ -1270,14 +1546,32 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
                             send_query(&mut app, &query_tx);
                             // If we are tracking pending installs, check if all are installed now
                             if let Some(pending) = maybe_pending_installs {
-                                let all_installed = pending
-                                    .iter()
-                                    .all(|n| crate::index::is_installed(n));
+                                // Already-installed packages count only once pacman rewrote them
+                                let all_installed = crate::index::installs_finished(
+                                    &pending,
+                                    &app.pending_install_baseline,
+                                    crate::index::install_stamp,
+                                );
                                 if all_installed {
+                                    // Transaction succeeded: run the user's post-install hook (never on dry-run)
+                                    let hook = crate::theme::settings().post_install_hook;
+                                    if !app.dry_run && !hook.trim().is_empty() {
+                                        let tx = hook_res_tx.clone();
+                                        let names = pending.clone();
+                                        tokio::task::spawn_blocking(move || {
+                                            let _ = tx.send(crate::install::run_post_install_hook(&hook, &names));
+                                        });
+                                    }
+                                    if !app.dry_run {
+                                        crate::logic::activity::append(crate::logic::activity::ActivityKind::Install, &pending);
+                                        // The install may have bootstrapped an AUR helper
+                                        tokio::task::spawn_blocking(crate::logic::deps::probe_aur_helpers);
+                                    }
                                     // Clear install list and stop tracking
                                     app.install_list.clear();
                                     app.install_dirty = true;
                                     app.pending_install_names = None;
+                                    app.pending_install_baseline.clear();
                                     // Clear dependency cache when install list is cleared
                                     app.install_list_deps.clear();
                                     app.install_list_files.clear();
@@ -1296,6 +1590,9 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
                                     if let Err(e) = crate::install::log_removed(&pending_rm) {
                                         let _ = e; // ignore logging errors
                                     }
+                                    if !app.dry_run {
+                                        crate::logic::activity::append(crate::logic::activity::ActivityKind::Remove, &pending_rm);
+                                    }
                                     app.pending_remove_names = None;
                                     // End polling soon to avoid extra work
                                     app.refresh_installed_until = Some(now + Duration::from_secs(1));
@@ -1313,12 +1610,92 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
                 if app.sort_menu_open && let Some(deadline) = app.sort_menu_auto_close_at && std::time::Instant::now() >= deadline {
                     app.sort_menu_open = false; app.sort_menu_auto_close_at = None;
                 }
+                // Ranked mirrors wait until no other dialog is open so the preview is not lost
+                if matches!(app.modal, crate::state::Modal::None)
+                    && let Some(res) = crate::index::take_rank_result()
+                {
+                    app.mirror_rank_running = false;
+                    let staged = crate::theme::lists_dir().join("mirrorlist.ranked");
+                    let msg = match res.map(|r| std::fs::write(&staged, &r.content).map(|()| r.servers)) {
+                        Ok(Ok(servers)) => {
+                            app.modal = crate::state::Modal::MirrorRankPreview { servers, staged };
+                            None
+                        }
+                        Ok(Err(e)) => Some(crate::i18n::t_fmt1(&app, "app.toasts.mirror_rank_failed", e)),
+                        Err(crate::index::RankError::ReflectorMissing) => Some(crate::i18n::t(&app, "app.toasts.mirror_rank_no_reflector")),
+                        Err(crate::index::RankError::Failed(e)) => Some(crate::i18n::t_fmt1(&app, "app.toasts.mirror_rank_failed", e)),
+                    };
+                    if let Some(m) = &msg {
+                        crate::logic::errors::record_error(&mut app, "mirrors", m.clone());
+                    }
+                    app.toast_expires_at = msg.as_ref().map(|_| Instant::now() + Duration::from_secs(6));
+                    app.toast_message = msg;
+                }
+                // theme.conf saved while theme_auto_reload is on; a bad edit keeps the old theme
+                if crate::theme::take_theme_changed() {
+                    app.toast_message = Some(match crate::theme::reload_theme() {
+                        Ok(()) => crate::i18n::t(&app, "app.toasts.theme_reloaded"),
+                        Err(e) => {
+                            let msg = crate::i18n::t_fmt1(&app, "app.toasts.theme_reload_failed", e);
+                            crate::logic::errors::record_error(&mut app, "theme", msg.clone());
+                            msg
+                        }
+                    });
+                    app.toast_expires_at = Some(Instant::now() + Duration::from_secs(5));
+                }
+                // Background file-DB sync finished: toast and re-resolve an open Files tab
+                if crate::logic::filedb::take_sync_finished() {
+                    let key = match crate::logic::filedb::sync_status() {
+                        crate::logic::filedb::SyncStatus::Failed(_) => "app.toasts.file_db_sync_failed",
+                        _ => "app.toasts.file_db_synced",
+                    };
+                    app.toast_message = Some(crate::i18n::t(&app, key));
+                    app.toast_expires_at = Some(Instant::now() + Duration::from_secs(5));
+                    if key == "app.toasts.file_db_synced"
+                        && let crate::state::Modal::Preflight {
+                            items,
+                            action: crate::state::PreflightAction::Install,
+                            file_info,
+                            file_selected,
+                            files_error,
+                            ..
+                        } = &mut app.modal
+                    {
+                        file_info.clear();
+                        *file_selected = 0;
+                        *files_error = None;
+                        let items = items.clone();
+                        app.install_list_files.retain(|f| !items.iter().any(|i| i.name == f.name));
+                        app.preflight_files_items = Some(items);
+                        app.preflight_files_resolving = true;
+                    }
+                }
+                crate::logic::errors::collect_reported(&mut app);
+                if let Some(summary) = crate::logic::package_list::take_import_summary() {
+                    crate::events::open_import_preview(&mut app, summary);
+                }
                 if let Some(deadline) = app.toast_expires_at
                     && std::time::Instant::now() >= deadline {
                         app.toast_message = None;
                         app.toast_expires_at = None;
                     }
             }
+            Some(res) = hook_res_rx.recv() => {
+                match res {
+                    Ok(Some(log)) => {
+                        app.toast_message = Some(crate::i18n::t_fmt1(&app, "app.toasts.post_install_hook_ok", log.display()));
+                        app.toast_expires_at = Some(Instant::now() + Duration::from_secs(4));
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        tracing::warn!(error = %e, "post-install hook failed");
+                        let msg = crate::i18n::t_fmt1(&app, "app.toasts.post_install_hook_failed", e);
+                        crate::logic::errors::record_error(&mut app, "install", msg.clone());
+                        app.toast_message = Some(msg);
+                        app.toast_expires_at = Some(Instant::now() + Duration::from_secs(6));
+                    }
+                }
+            }
             Some(todays) = news_rx.recv() => {
                 if todays.is_empty() {
                     app.toast_message = Some(crate::i18n::t(&app, "app.toasts.no_new_news"));
@@ -1347,14 +1724,8 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
     // Signal event reading thread to exit immediately
     event_thread_cancelled.store(true, std::sync::atomic::Ordering::Relaxed);
 
-    maybe_flush_cache(&mut app);
-    maybe_flush_recent(&mut app);
-    maybe_flush_news_read(&mut app);
-    maybe_flush_install(&mut app);
-    maybe_flush_deps_cache(&mut app);
-    maybe_flush_files_cache(&mut app);
-    maybe_flush_services_cache(&mut app);
-    maybe_flush_sandbox_cache(&mut app);
+    flush_all_now(&mut app);
+    crate::i18n::flush_missing_keys(&app.locale);
 
     if !headless {
         restore_terminal()?;
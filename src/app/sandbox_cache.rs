@@ -144,6 +144,11 @@ mod tests {
             makedepends: vec![],
             checkdepends: vec![],
             optdepends: vec![],
+            sources: vec![],
+            missing_pgp_keys: vec![],
+            pkgbuild_risk: None,
+            warnings: vec![],
+            custom_scan: None,
         }]
     }
 
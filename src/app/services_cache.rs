@@ -141,6 +141,8 @@ mod tests {
             unit_name: "sshd.service".into(),
             providers: vec!["sshd".into()],
             is_active: true,
+            active_state: String::new(),
+            enabled_state: String::new(),
             needs_restart: true,
             recommended_decision: ServiceRestartDecision::Restart,
             restart_decision: ServiceRestartDecision::Restart,
@@ -0,0 +1,337 @@
+//! Central list of user-facing actions shared by the global key dispatcher and the command
+//! palette.
+
+use crate::i18n;
+use crate::state::AppState;
+use crate::theme::{KeyChord, KeyMap};
+
+/// An action the user can trigger globally, by keybind, dropdown menu, or command palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Help,
+    ReloadTheme,
+    ReloadSettings,
+    CycleTheme,
+    Exit,
+    TogglePkgbuild,
+    PkgbuildNextMark,
+    ChangeSort,
+    ToggleDownloadServer,
+    ToggleStatusVerbose,
+    ToggleStatusSpans,
+    CompareSlotA,
+    CompareSlotB,
+    OpenHomepage,
+    OpenPackagePage,
+    PreviewCommands,
+    WhyInstalled,
+    ShowFiles,
+    ClearAllLists,
+    Undo,
+    Redo,
+    ReopenModal,
+    CopyViewSettings,
+    CommandPalette,
+    OpenLogs,
+    DetailsScrollLeft,
+    DetailsScrollRight,
+    ToggleWrap,
+    RefreshInstalled,
+    // Dropdown-only actions (no default keybind)
+    ToggleInstalledOnly,
+    UpdateSystem,
+    News,
+    OptionalDeps,
+    ToggleSearchScope,
+    RankMirrors,
+    Themes,
+    CheckKeybinds,
+    ToggleUpgradableOnly,
+    ConfigLeftovers,
+    ErrorLog,
+    ActivityLog,
+    AddOrphans,
+    SaveProfile,
+    LoadProfile,
+}
+
+impl Action {
+    /// Every action, in the order keybinds are matched and the palette lists them.
+    pub const ALL: [Action; 44] = [
+        Action::Help,
+        Action::ReloadTheme,
+        Action::ReloadSettings,
+        Action::CycleTheme,
+        Action::Exit,
+        Action::TogglePkgbuild,
+        Action::PkgbuildNextMark,
+        Action::ChangeSort,
+        Action::ToggleDownloadServer,
+        Action::ToggleStatusVerbose,
+        Action::ToggleStatusSpans,
+        Action::CompareSlotA,
+        Action::CompareSlotB,
+        Action::OpenHomepage,
+        Action::OpenPackagePage,
+        Action::PreviewCommands,
+        Action::WhyInstalled,
+        Action::ShowFiles,
+        Action::ClearAllLists,
+        Action::Undo,
+        Action::Redo,
+        Action::ReopenModal,
+        Action::CopyViewSettings,
+        Action::CommandPalette,
+        Action::OpenLogs,
+        Action::DetailsScrollLeft,
+        Action::DetailsScrollRight,
+        Action::ToggleWrap,
+        Action::RefreshInstalled,
+        Action::ToggleInstalledOnly,
+        Action::UpdateSystem,
+        Action::News,
+        Action::OptionalDeps,
+        Action::ToggleSearchScope,
+        Action::RankMirrors,
+        Action::Themes,
+        Action::CheckKeybinds,
+        Action::ToggleUpgradableOnly,
+        Action::ConfigLeftovers,
+        Action::ErrorLog,
+        Action::ActivityLog,
+        Action::AddOrphans,
+        Action::SaveProfile,
+        Action::LoadProfile,
+    ];
+
+    /// What: Chords bound to this action in `km`.
+    ///
+    /// Output:
+    /// - The configured chords; empty for dropdown-only actions.
+    pub fn chords(self, km: &KeyMap) -> &[KeyChord] {
+        match self {
+            Action::Help => &km.help_overlay,
+            Action::ReloadTheme => &km.reload_theme,
+            Action::ReloadSettings => &km.reload_settings,
+            Action::CycleTheme => &km.cycle_theme,
+            Action::Exit => &km.exit,
+            Action::TogglePkgbuild => &km.show_pkgbuild,
+            Action::PkgbuildNextMark => &km.pkgbuild_next_mark,
+            Action::ChangeSort => &km.change_sort,
+            Action::ToggleDownloadServer => &km.toggle_download_server,
+            Action::ToggleStatusVerbose => &km.toggle_status_verbose,
+            Action::ToggleStatusSpans => &km.toggle_status_spans,
+            Action::CompareSlotA => &km.compare_slot_a,
+            Action::CompareSlotB => &km.compare_slot_b,
+            Action::OpenHomepage => &km.open_homepage,
+            Action::OpenPackagePage => &km.open_package_page,
+            Action::PreviewCommands => &km.preview_commands,
+            Action::WhyInstalled => &km.why_installed,
+            Action::ShowFiles => &km.show_files,
+            Action::ClearAllLists => &km.clear_all_lists,
+            Action::Undo => &km.undo,
+            Action::Redo => &km.redo,
+            Action::ReopenModal => &km.reopen_modal,
+            Action::CopyViewSettings => &km.copy_view_settings,
+            Action::CommandPalette => &km.command_palette,
+            Action::OpenLogs => &km.open_logs,
+            Action::DetailsScrollLeft => &km.details_scroll_left,
+            Action::DetailsScrollRight => &km.details_scroll_right,
+            Action::ToggleWrap => &km.toggle_wrap,
+            Action::RefreshInstalled => &km.refresh_installed,
+            _ => &[],
+        }
+    }
+
+    /// What: Translated name shown in the command palette.
+    ///
+    /// Details:
+    /// - Reuses the help overlay and dropdown labels; toggles name the state they switch to.
+    pub fn label(self, app: &AppState) -> String {
+        let key = match self {
+            Action::Help => "app.modals.help.key_labels.help_overlay",
+            Action::ReloadTheme => "app.modals.help.key_labels.reload_theme",
+            Action::ReloadSettings => "app.modals.help.key_labels.reload_settings",
+            Action::CycleTheme => "app.modals.help.key_labels.cycle_theme",
+            Action::Exit => "app.modals.help.key_labels.exit",
+            Action::TogglePkgbuild => "app.modals.help.key_labels.show_pkgbuild",
+            Action::PkgbuildNextMark => "app.modals.help.key_labels.pkgbuild_next_mark",
+            Action::ChangeSort => "app.modals.help.key_labels.change_sorting",
+            Action::ToggleDownloadServer => "app.modals.help.key_labels.toggle_download_server",
+            Action::ToggleStatusVerbose => "app.modals.help.key_labels.toggle_status_verbose",
+            Action::ToggleStatusSpans => "app.modals.help.key_labels.toggle_status_spans",
+            Action::CompareSlotA => "app.modals.help.key_labels.compare_slot_a",
+            Action::CompareSlotB => "app.modals.help.key_labels.compare_slot_b",
+            Action::OpenHomepage => "app.modals.help.key_labels.open_homepage",
+            Action::OpenPackagePage => "app.modals.help.key_labels.open_package_page",
+            Action::PreviewCommands => "app.modals.help.key_labels.preview_commands",
+            Action::WhyInstalled => "app.modals.help.key_labels.why_installed",
+            Action::ShowFiles => "app.modals.help.key_labels.show_files",
+            Action::ClearAllLists => "app.modals.help.key_labels.clear_all_lists",
+            Action::Undo => "app.modals.help.key_labels.undo",
+            Action::Redo => "app.modals.help.key_labels.redo",
+            Action::ReopenModal => "app.modals.help.key_labels.reopen_modal",
+            Action::CopyViewSettings => "app.modals.help.key_labels.copy_view_settings",
+            Action::CommandPalette => "app.modals.help.key_labels.command_palette",
+            Action::OpenLogs => "app.modals.help.key_labels.open_logs",
+            Action::DetailsScrollLeft => "app.modals.help.key_labels.details_scroll_left",
+            Action::DetailsScrollRight => "app.modals.help.key_labels.details_scroll_right",
+            Action::ToggleWrap => "app.modals.help.key_labels.toggle_wrap",
+            Action::RefreshInstalled => "app.modals.help.key_labels.refresh_installed",
+            Action::ToggleInstalledOnly if app.installed_only_mode => {
+                "app.results.options_menu.list_all_packages"
+            }
+            Action::ToggleInstalledOnly => "app.results.options_menu.list_installed_packages",
+            Action::UpdateSystem => "app.results.options_menu.update_system",
+            Action::News => "app.results.options_menu.news",
+            Action::OptionalDeps => "app.results.options_menu.tui_optional_deps",
+            Action::ToggleSearchScope => match app.search_scope {
+                crate::state::SearchScope::NameAndDescription => {
+                    "app.results.options_menu.search_scope_name_desc"
+                }
+                crate::state::SearchScope::NameOnly => {
+                    "app.results.options_menu.search_scope_name_only"
+                }
+            },
+            Action::RankMirrors => "app.results.options_menu.rank_mirrors",
+            Action::Themes => "app.results.options_menu.themes",
+            Action::CheckKeybinds => "app.results.options_menu.check_keybinds",
+            Action::ToggleUpgradableOnly if app.results_filter_upgradable_only => {
+                "app.results.options_menu.upgradable_only_on"
+            }
+            Action::ToggleUpgradableOnly => "app.results.options_menu.upgradable_only_off",
+            Action::ConfigLeftovers => "app.results.options_menu.config_leftovers",
+            Action::ErrorLog => "app.results.options_menu.error_log",
+            Action::ActivityLog => "app.results.options_menu.activity_log",
+            Action::AddOrphans => "app.results.config_menu.options.remove_orphans",
+            Action::SaveProfile => "app.results.config_menu.options.save_profile",
+            Action::LoadProfile => "app.results.config_menu.options.load_profile",
+        };
+        i18n::t(app, key)
+    }
+
+    /// What: Whether the action does anything in the current state.
+    ///
+    /// Output:
+    /// - `false` for actions that need a selection, a visible PKGBUILD, list history, or a
+    ///   closed dialog that is not there; the palette greys these out.
+    pub fn is_available(self, app: &AppState) -> bool {
+        match self {
+            Action::PkgbuildNextMark => app.pkgb_visible && app.pkgb_text.is_some(),
+            Action::CompareSlotA
+            | Action::CompareSlotB
+            | Action::OpenHomepage
+            | Action::OpenPackagePage
+            | Action::WhyInstalled
+            | Action::ShowFiles => app.results.get(app.selected).is_some(),
+            Action::ClearAllLists => {
+                !(app.install_list.is_empty()
+                    && app.remove_list.is_empty()
+                    && app.downgrade_list.is_empty())
+            }
+            Action::Undo => app.list_history.can_undo(),
+            Action::Redo => app.list_history.can_redo(),
+            Action::ReopenModal => app.last_closed_modal.is_some(),
+            Action::DetailsScrollLeft if app.pkgb_visible => app.pkgb_hscroll > 0,
+            Action::DetailsScrollLeft => app.details_hscroll > 0,
+            Action::DetailsScrollRight if app.pkgb_visible => {
+                app.pkgb_hscroll < app.pkgb_hscroll_max
+            }
+            Action::DetailsScrollRight => app.details_hscroll < app.details_hscroll_max,
+            Action::CommandPalette => false,
+            _ => true,
+        }
+    }
+
+    /// What: Action bound to a key chord, as the global dispatcher sees it.
+    ///
+    /// Inputs:
+    /// - `km`: Active key map.
+    /// - `chord`: Pressed chord (BackTab already normalized).
+    /// - `app`: Application state, for keys that only act in some states.
+    ///
+    /// Output:
+    /// - The first matching action in [`Action::ALL`] order; `None` when the key is not global.
+    ///
+    /// Details:
+    /// - The PKGBUILD mark key only counts while the viewer is open so the key stays free for
+    ///   the panes otherwise.
+    pub fn from_chord(km: &KeyMap, chord: KeyChord, app: &AppState) -> Option<Action> {
+        Action::ALL.into_iter().find(|a| {
+            a.chords(km).contains(&chord) && (*a != Action::PkgbuildNextMark || app.pkgb_visible)
+        })
+    }
+}
+
+/// What: Actions matching a command palette query, best match first.
+///
+/// Inputs:
+/// - `app`: Application state (labels, availability).
+/// - `query`: Text typed into the palette.
+///
+/// Output:
+/// - Matching actions ranked by fuzzy score on their label; ties keep [`Action::ALL`] order, and
+///   unavailable actions sort after available ones.
+pub fn palette_matches(app: &AppState, query: &str) -> Vec<Action> {
+    let mut scored: Vec<(bool, i64, usize, Action)> = Action::ALL
+        .into_iter()
+        .enumerate()
+        .filter(|(_, a)| *a != Action::CommandPalette)
+        .filter_map(|(i, a)| {
+            let (score, _) = crate::logic::filter::fuzzy_score(&a.label(app), query.trim())?;
+            Some((!a.is_available(app), -score, i, a))
+        })
+        .collect();
+    scored.sort_by_key(|(unavailable, score, i, _)| (*unavailable, *score, *i));
+    scored.into_iter().map(|(_, _, _, a)| a).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure keybinds map to actions and the palette filters and ranks them.
+    ///
+    /// Inputs:
+    /// - Default key map and state; queries for "theme" and nonsense.
+    ///
+    /// Output:
+    /// - `Ctrl+R` resolves to reload theme, the PKGBUILD mark key only with the viewer open,
+    ///   "theme" lists the theme actions first, and unavailable actions sort last.
+    fn actions_dispatch_and_palette_matching() {
+        let app = AppState {
+            ..Default::default()
+        };
+        let km = &app.keymap;
+        assert_eq!(
+            Action::from_chord(km, km.reload_theme[0], &app),
+            Some(Action::ReloadTheme)
+        );
+        assert_eq!(Action::from_chord(km, km.pkgbuild_next_mark[0], &app), None);
+        let viewer = AppState {
+            pkgb_visible: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            Action::from_chord(km, km.pkgbuild_next_mark[0], &viewer),
+            Some(Action::PkgbuildNextMark)
+        );
+
+        let all = palette_matches(&app, "");
+        assert_eq!(all.len(), Action::ALL.len() - 1);
+        let first_unavailable = all.iter().position(|a| !a.is_available(&app)).unwrap();
+        assert!(
+            all[first_unavailable..]
+                .iter()
+                .all(|a| !a.is_available(&app))
+        );
+        assert!(!all.contains(&Action::CommandPalette));
+
+        let themed = palette_matches(&app, "theme");
+        assert!(themed[..3].contains(&Action::ReloadTheme));
+        assert!(themed[..3].contains(&Action::CycleTheme));
+        assert!(themed[..3].contains(&Action::Themes));
+        assert!(palette_matches(&app, "zzqx").is_empty());
+    }
+}
@@ -3,6 +3,7 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use tokio::sync::mpsc;
 
+use crate::events::actions::Action;
 use crate::events::utils;
 use crate::state::{AppState, PackageItem};
 use crate::theme::reload_theme;
@@ -59,94 +60,19 @@ pub(crate) fn handle_global_key(
         }
         return Some(false); // Handled - don't process further
     }
-    let km = &app.keymap;
-    // Global keybinds (only if no modal is active, except Preflight which handles its own help)
-    if !matches!(app.modal, crate::state::Modal::Preflight { .. }) {
-        // Normalize BackTab so that SHIFT modifier does not affect matching across terminals
-        let normalized_mods = if matches!(ke.code, KeyCode::BackTab) {
-            KeyModifiers::empty()
-        } else {
-            ke.modifiers
-        };
-        let chord = (ke.code, normalized_mods);
-        let matches_any =
-            |list: &Vec<crate::theme::KeyChord>| list.iter().any(|c| (c.code, c.mods) == chord);
-        if matches_any(&km.help_overlay) {
-            app.modal = crate::state::Modal::Help;
-            return Some(false); // Handled - don't process further
-        }
-    }
+    // Global keybinds; Preflight handles its own keys before this point
     // Normalize BackTab so that SHIFT modifier does not affect matching across terminals
     let normalized_mods = if matches!(ke.code, KeyCode::BackTab) {
         KeyModifiers::empty()
     } else {
         ke.modifiers
     };
-    let chord = (ke.code, normalized_mods);
-    let matches_any =
-        |list: &Vec<crate::theme::KeyChord>| list.iter().any(|c| (c.code, c.mods) == chord);
-    if matches_any(&km.reload_theme) {
-        match reload_theme() {
-            Ok(()) => {
-                app.toast_message = Some(crate::i18n::t(app, "app.toasts.theme_reloaded"));
-                app.toast_expires_at =
-                    Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
-            }
-            Err(msg) => {
-                app.modal = crate::state::Modal::Alert { message: msg };
-            }
-        }
-        return Some(false); // Handled - don't process further
-    }
-    if matches_any(&km.exit) {
-        return Some(true); // Exit requested
-    }
-    // Toggle PKGBUILD viewer globally
-    if matches_any(&km.show_pkgbuild) {
-        if app.pkgb_visible {
-            app.pkgb_visible = false;
-            app.pkgb_text = None;
-            app.pkgb_package_name = None;
-            app.pkgb_scroll = 0;
-            app.pkgb_rect = None;
-        } else {
-            app.pkgb_visible = true;
-            app.pkgb_text = None;
-            app.pkgb_package_name = None;
-            if let Some(item) = app.results.get(app.selected).cloned() {
-                let _ = pkgb_tx.send(item);
-            }
-        }
-        return Some(false); // Handled - don't process further
-    }
-    // Global: Change sorting via configured keybind
-    if matches_any(&km.change_sort) {
-        // Cycle through sort modes in fixed order
-        app.sort_mode = match app.sort_mode {
-            crate::state::SortMode::RepoThenName => {
-                crate::state::SortMode::AurPopularityThenOfficial
-            }
-            crate::state::SortMode::AurPopularityThenOfficial => {
-                crate::state::SortMode::BestMatches
-            }
-            crate::state::SortMode::BestMatches => crate::state::SortMode::RepoThenName,
-        };
-        // Persist preference and apply immediately
-        crate::theme::save_sort_mode(app.sort_mode);
-        crate::logic::sort_results_preserve_selection(app);
-        // Jump selection to top and refresh details
-        if !app.results.is_empty() {
-            app.selected = 0;
-            app.list_state.select(Some(0));
-            utils::refresh_selected_details(app, details_tx);
-        } else {
-            app.list_state.select(None);
-        }
-        // Show the dropdown so the user sees the current option with a check mark
-        app.sort_menu_open = true;
-        app.sort_menu_auto_close_at =
-            Some(std::time::Instant::now() + std::time::Duration::from_secs(2));
-        return Some(false); // Handled - don't process further
+    let chord = crate::theme::KeyChord {
+        code: ke.code,
+        mods: normalized_mods,
+    };
+    if let Some(action) = Action::from_chord(&app.keymap, chord, app) {
+        return Some(run_action(action, app, details_tx, pkgb_tx));
     }
     // Note: menu toggles (Shift+C/O/P) handled in Search Normal mode and not globally
     // Global: When a dropdown is open, allow numeric selection 1..9 to activate rows
@@ -155,420 +81,10 @@ pub(crate) fn handle_global_key(
         && ch != '0'
     {
         let idx = (ch as u8 - b'1') as usize; // '1' -> 0
-        // Options menu rows: 0 toggle installed-only, 1 update system, 2 news, 3 optional deps
+        // Options menu rows: 0 toggle installed-only, 1 update system, 2 news, 3 optional deps,
+        // 4 search scope, 5 rank mirrors, 6 themes, 7 check keybinds, 8 upgradable only
         if app.options_menu_open {
-            match idx {
-                0 => {
-                    // same as mouse options handler row 0
-                    if app.installed_only_mode {
-                        if let Some(prev) = app.results_backup_for_toggle.take() {
-                            app.all_results = prev;
-                        }
-                        app.installed_only_mode = false;
-                        app.right_pane_focus = crate::state::RightPaneFocus::Install;
-                        crate::logic::apply_filters_and_sort_preserve_selection(app);
-                        utils::refresh_selected_details(app, details_tx);
-                    } else {
-                        app.results_backup_for_toggle = Some(app.all_results.clone());
-                        let explicit = crate::index::explicit_names();
-                        let mut items: Vec<crate::state::PackageItem> =
-                            crate::index::all_official()
-                                .into_iter()
-                                .filter(|p| explicit.contains(&p.name))
-                                .collect();
-                        use std::collections::HashSet;
-                        let official_names: HashSet<String> =
-                            items.iter().map(|p| p.name.clone()).collect();
-                        for name in explicit.into_iter() {
-                            if !official_names.contains(&name) {
-                                let is_eos = crate::index::is_eos_name(&name);
-                                let src = if is_eos {
-                                    crate::state::Source::Official {
-                                        repo: "EOS".to_string(),
-                                        arch: String::new(),
-                                    }
-                                } else {
-                                    crate::state::Source::Aur
-                                };
-                                items.push(crate::state::PackageItem {
-                                    name: name.clone(),
-                                    version: String::new(),
-                                    description: String::new(),
-                                    source: src,
-                                    popularity: None,
-                                });
-                            }
-                        }
-                        app.all_results = items;
-                        app.installed_only_mode = true;
-                        app.right_pane_focus = crate::state::RightPaneFocus::Remove;
-                        crate::logic::apply_filters_and_sort_preserve_selection(app);
-                        utils::refresh_selected_details(app, details_tx);
-                        let path = crate::theme::config_dir().join("installed_packages.txt");
-                        let mut names: Vec<String> =
-                            crate::index::explicit_names().into_iter().collect();
-                        names.sort();
-                        let body = names.join("\n");
-                        let _ = std::fs::write(path, body);
-                    }
-                }
-                1 => {
-                    let countries = vec![
-                        "Worldwide".to_string(),
-                        "Germany".to_string(),
-                        "United States".to_string(),
-                        "United Kingdom".to_string(),
-                        "France".to_string(),
-                        "Netherlands".to_string(),
-                        "Sweden".to_string(),
-                        "Canada".to_string(),
-                        "Australia".to_string(),
-                        "Japan".to_string(),
-                    ];
-                    let prefs = crate::theme::settings();
-                    let initial_country_idx = {
-                        let sel = prefs
-                            .selected_countries
-                            .split(',')
-                            .next()
-                            .map(|s| s.trim().to_string())
-                            .unwrap_or_else(|| "Worldwide".to_string());
-                        countries.iter().position(|c| c == &sel).unwrap_or(0)
-                    };
-                    app.modal = crate::state::Modal::SystemUpdate {
-                        do_mirrors: false,
-                        do_pacman: true,
-                        do_aur: true,
-                        do_cache: false,
-                        country_idx: initial_country_idx,
-                        countries,
-                        mirror_count: prefs.mirror_count,
-                        cursor: 0,
-                    };
-                }
-                2 => {
-                    let (tx, rx) = std::sync::mpsc::channel();
-                    std::thread::spawn(move || {
-                        let rt = tokio::runtime::Builder::new_current_thread()
-                            .enable_all()
-                            .build();
-                        let res = match rt {
-                            Ok(rt) => rt.block_on(crate::sources::fetch_arch_news(10)),
-                            Err(e) => {
-                                Err::<Vec<crate::state::NewsItem>, _>(format!("rt: {e}").into())
-                            }
-                        };
-                        let _ = tx.send(res);
-                    });
-                    match rx.recv_timeout(std::time::Duration::from_secs(3)) {
-                        Ok(Ok(list)) => {
-                            app.modal = crate::state::Modal::News {
-                                items: list,
-                                selected: 0,
-                            };
-                        }
-                        Ok(Err(e)) => {
-                            app.modal = crate::state::Modal::Alert {
-                                message: format!("Failed to fetch news: {e}"),
-                            };
-                        }
-                        Err(_) => {
-                            app.modal = crate::state::Modal::Alert {
-                                message: "Timed out fetching news".to_string(),
-                            };
-                        }
-                    }
-                }
-                3 => {
-                    // Open Optional Deps modal (same as mouse handler row 3)
-                    let mut rows: Vec<crate::state::types::OptionalDepRow> = Vec::new();
-                    let is_pkg_installed = |pkg: &str| crate::index::is_installed(pkg);
-                    let on_path = |cmd: &str| crate::install::command_on_path(cmd);
-                    // (Security rows will be appended after AUR helper for desired order)
-                    // Editor
-                    let editor_candidates: &[(&str, &str)] = &[
-                        ("nvim", "neovim"),
-                        ("vim", "vim"),
-                        ("hx", "helix"),
-                        ("helix", "helix"),
-                        ("emacsclient", "emacs"),
-                        ("emacs", "emacs"),
-                        ("nano", "nano"),
-                    ];
-                    let mut editor_installed: Option<(&str, &str)> = None;
-                    for (bin, pkg) in editor_candidates.iter() {
-                        if on_path(bin) || is_pkg_installed(pkg) {
-                            editor_installed = Some((*bin, *pkg));
-                            break;
-                        }
-                    }
-                    if let Some((bin, pkg)) = editor_installed {
-                        rows.push(crate::state::types::OptionalDepRow {
-                            label: format!("Editor: {}", bin),
-                            package: pkg.to_string(),
-                            installed: (is_pkg_installed(pkg)
-                                || on_path(bin)
-                                || ((pkg == "helix") && (on_path("hx") || on_path("helix")))
-                                || ((pkg == "emacs")
-                                    && (on_path("emacs") || on_path("emacsclient")))),
-                            selectable: false,
-                            note: None,
-                        });
-                    } else {
-                        let mut seen = std::collections::HashSet::new();
-                        for (bin, pkg) in editor_candidates.iter() {
-                            if seen.insert(*pkg) {
-                                rows.push(crate::state::types::OptionalDepRow {
-                                    label: format!("Editor: {}", bin),
-                                    package: pkg.to_string(),
-                                    installed: (is_pkg_installed(pkg)
-                                        || on_path(bin)
-                                        || ((*pkg == "helix")
-                                            && (on_path("hx") || on_path("helix")))
-                                        || ((*pkg == "emacs")
-                                            && (on_path("emacs") || on_path("emacsclient")))),
-                                    selectable: !(is_pkg_installed(pkg)
-                                        || on_path(bin)
-                                        || ((*pkg == "helix")
-                                            && (on_path("hx") || on_path("helix")))
-                                        || ((*pkg == "emacs")
-                                            && (on_path("emacs") || on_path("emacsclient")))),
-                                    note: None,
-                                });
-                            }
-                        }
-                    }
-                    // Terminal
-                    let term_candidates: &[(&str, &str)] = &[
-                        ("alacritty", "alacritty"),
-                        ("ghostty", "ghostty"),
-                        ("kitty", "kitty"),
-                        ("xterm", "xterm"),
-                        ("gnome-terminal", "gnome-terminal"),
-                        ("konsole", "konsole"),
-                        ("xfce4-terminal", "xfce4-terminal"),
-                        ("tilix", "tilix"),
-                        ("mate-terminal", "mate-terminal"),
-                    ];
-                    let mut term_installed: Option<(&str, &str)> = None;
-                    for (bin, pkg) in term_candidates.iter() {
-                        if on_path(bin) || is_pkg_installed(pkg) {
-                            term_installed = Some((*bin, *pkg));
-                            break;
-                        }
-                    }
-                    if let Some((bin, pkg)) = term_installed {
-                        rows.push(crate::state::types::OptionalDepRow {
-                            label: format!("Terminal: {}", bin),
-                            package: pkg.to_string(),
-                            installed: (is_pkg_installed(pkg) || on_path(bin)),
-                            selectable: false,
-                            note: None,
-                        });
-                    } else {
-                        for (bin, pkg) in term_candidates.iter() {
-                            rows.push(crate::state::types::OptionalDepRow {
-                                label: format!("Terminal: {}", bin),
-                                package: pkg.to_string(),
-                                installed: (is_pkg_installed(pkg) || on_path(bin)),
-                                selectable: !(is_pkg_installed(pkg) || on_path(bin)),
-                                note: None,
-                            });
-                        }
-                    }
-                    // Clipboard: Prefer Klipper when KDE session detected; else Wayland/X11 specific
-                    let is_kde = std::env::var("KDE_FULL_SESSION").is_ok()
-                        || std::env::var("XDG_CURRENT_DESKTOP")
-                            .ok()
-                            .map(|v| {
-                                let u = v.to_uppercase();
-                                u.contains("KDE") || u.contains("PLASMA")
-                            })
-                            .unwrap_or(false)
-                        || on_path("klipper");
-                    if is_kde {
-                        let pkg = "plasma-workspace";
-                        rows.push(crate::state::types::OptionalDepRow {
-                            label: "Clipboard: Klipper (KDE)".to_string(),
-                            package: pkg.to_string(),
-                            installed: is_pkg_installed(pkg) || on_path("klipper"),
-                            selectable: !(is_pkg_installed(pkg) || on_path("klipper")),
-                            note: Some("KDE Plasma".to_string()),
-                        });
-                    } else {
-                        let is_wayland = std::env::var("WAYLAND_DISPLAY").is_ok();
-                        if is_wayland {
-                            let pkg = "wl-clipboard";
-                            rows.push(crate::state::types::OptionalDepRow {
-                                label: "Clipboard: wl-clipboard".to_string(),
-                                package: pkg.to_string(),
-                                installed: is_pkg_installed(pkg) || on_path("wl-copy"),
-                                selectable: !(is_pkg_installed(pkg) || on_path("wl-copy")),
-                                note: Some("Wayland".to_string()),
-                            });
-                        } else {
-                            let pkg = "xclip";
-                            rows.push(crate::state::types::OptionalDepRow {
-                                label: "Clipboard: xclip".to_string(),
-                                package: pkg.to_string(),
-                                installed: is_pkg_installed(pkg) || on_path("xclip"),
-                                selectable: !(is_pkg_installed(pkg) || on_path("xclip")),
-                                note: Some("X11".to_string()),
-                            });
-                        }
-                    }
-                    // Mirrors: Manjaro -> pacman-mirrors, Artix -> rate-mirrors, else reflector
-                    let os_release = std::fs::read_to_string("/etc/os-release").unwrap_or_default();
-                    let manjaro = os_release.contains("Manjaro");
-                    let artix = os_release.contains("Artix");
-                    if manjaro {
-                        let pkg = "pacman-mirrors";
-                        rows.push(crate::state::types::OptionalDepRow {
-                            label: "Mirrors: pacman-mirrors".to_string(),
-                            package: pkg.to_string(),
-                            installed: is_pkg_installed(pkg),
-                            selectable: !is_pkg_installed(pkg),
-                            note: Some("Manjaro".to_string()),
-                        });
-                    } else if artix {
-                        let pkg = "rate-mirrors";
-                        rows.push(crate::state::types::OptionalDepRow {
-                            label: "Mirrors: rate mirrors".to_string(),
-                            package: pkg.to_string(),
-                            installed: on_path("rate-mirrors") || is_pkg_installed(pkg),
-                            selectable: !(on_path("rate-mirrors") || is_pkg_installed(pkg)),
-                            note: Some("Artix".to_string()),
-                        });
-                    } else {
-                        let pkg = "reflector";
-                        rows.push(crate::state::types::OptionalDepRow {
-                            label: "Mirrors: reflector".to_string(),
-                            package: pkg.to_string(),
-                            installed: is_pkg_installed(pkg),
-                            selectable: !is_pkg_installed(pkg),
-                            note: None,
-                        });
-                    }
-                    // (VirusTotal API row will be appended at the end for desired order)
-                    // AUR helper
-                    let paru_inst = on_path("paru") || is_pkg_installed("paru");
-                    let yay_inst = on_path("yay") || is_pkg_installed("yay");
-                    if paru_inst || yay_inst {
-                        if paru_inst {
-                            rows.push(crate::state::types::OptionalDepRow {
-                                label: "AUR helper: paru".to_string(),
-                                package: "paru".to_string(),
-                                installed: true,
-                                selectable: false,
-                                note: None,
-                            });
-                        } else if yay_inst {
-                            rows.push(crate::state::types::OptionalDepRow {
-                                label: "AUR helper: yay".to_string(),
-                                package: "yay".to_string(),
-                                installed: true,
-                                selectable: false,
-                                note: None,
-                            });
-                        }
-                    } else {
-                        rows.push(crate::state::types::OptionalDepRow {
-                            label: "AUR helper: paru".to_string(),
-                            package: "paru".to_string(),
-                            installed: false,
-                            selectable: true,
-                            note: Some("Install via git clone + makepkg -si".to_string()),
-                        });
-                        rows.push(crate::state::types::OptionalDepRow {
-                            label: "AUR helper: yay".to_string(),
-                            package: "yay".to_string(),
-                            installed: false,
-                            selectable: true,
-                            note: Some("Install via git clone + makepkg -si".to_string()),
-                        });
-                    }
-                    // Append Security rows after AUR helper
-                    {
-                        // Security: clamav (official)
-                        let pkg = "clamav";
-                        let installed = is_pkg_installed(pkg) || on_path("clamscan");
-                        rows.push(crate::state::types::OptionalDepRow {
-                            label: "Security: clamav".to_string(),
-                            package: pkg.to_string(),
-                            installed,
-                            selectable: !installed,
-                            note: None,
-                        });
-                        // Security: trivy (official)
-                        let pkg = "trivy";
-                        let installed = is_pkg_installed(pkg) || on_path("trivy");
-                        rows.push(crate::state::types::OptionalDepRow {
-                            label: "Security: trivy".to_string(),
-                            package: pkg.to_string(),
-                            installed,
-                            selectable: !installed,
-                            note: None,
-                        });
-                        // Security: semgrep-bin (AUR)
-                        let pkg = "semgrep-bin";
-                        let installed = is_pkg_installed(pkg) || on_path("semgrep");
-                        rows.push(crate::state::types::OptionalDepRow {
-                            label: "Security: semgrep-bin".to_string(),
-                            package: pkg.to_string(),
-                            installed,
-                            selectable: !installed,
-                            note: Some("AUR".to_string()),
-                        });
-                        // Security: shellcheck (official)
-                        let pkg = "shellcheck";
-                        let installed = is_pkg_installed(pkg) || on_path("shellcheck");
-                        rows.push(crate::state::types::OptionalDepRow {
-                            label: "Security: shellcheck".to_string(),
-                            package: pkg.to_string(),
-                            installed,
-                            selectable: !installed,
-                            note: None,
-                        });
-                        // Security: VirusTotal API (Setup)
-                        {
-                            let vt_key_present =
-                                !crate::theme::settings().virustotal_api_key.is_empty();
-                            rows.push(crate::state::types::OptionalDepRow {
-                                label: "Security: VirusTotal API".to_string(),
-                                package: "virustotal-setup".to_string(),
-                                installed: vt_key_present,
-                                selectable: true,
-                                note: Some("Setup".to_string()),
-                            });
-                            // aur-sleuth (LLM audit) setup
-                            let sleuth_installed = {
-                                let onpath = on_path("aur-sleuth");
-                                let home = std::env::var("HOME").ok();
-                                let user_local = home
-                                    .as_deref()
-                                    .map(|h| {
-                                        std::path::Path::new(h)
-                                            .join(".local/bin/aur-sleuth")
-                                            .exists()
-                                    })
-                                    .unwrap_or(false);
-                                let usr_local =
-                                    std::path::Path::new("/usr/local/bin/aur-sleuth").exists();
-                                onpath || user_local || usr_local
-                            };
-                            rows.push(crate::state::types::OptionalDepRow {
-                                label: "Security: aur-sleuth".to_string(),
-                                package: "aur-sleuth-setup".to_string(),
-                                installed: sleuth_installed,
-                                selectable: true,
-                                note: Some("Setup".to_string()),
-                            });
-                        }
-                    }
-                    app.modal = crate::state::Modal::OptionalDeps { rows, selected: 0 };
-                }
-                _ => {}
-            }
+            activate_options_row(app, idx, details_tx);
             app.options_menu_open = false;
             return Some(false); // Handled - don't process further
         }
@@ -598,8 +114,14 @@ pub(crate) fn handle_global_key(
             // Keep menu open after toggling panels
             return Some(false); // Handled - don't process further
         }
-        // Config menu rows: 0 settings, 1 theme, 2 keybinds, 3 install list, 4 installed list, 5 recent
+        // Config menu rows: 0 settings, 1 theme, 2 keybinds, 3 install list, 4 installed list, 5 recent, 6 orphans
         if app.config_menu_open {
+            if idx == 6 {
+                utils::add_orphans_to_remove_list(app);
+                app.config_menu_open = false;
+                app.artix_filter_menu_open = false;
+                return Some(false); // Handled - don't process further
+            }
             let settings_path = crate::theme::config_dir().join("settings.conf");
             let theme_path = crate::theme::config_dir().join("theme.conf");
             let keybinds_path = crate::theme::config_dir().join("keybinds.conf");
@@ -656,6 +178,760 @@ pub(crate) fn handle_global_key(
     None // Key not handled by global shortcuts
 }
 
+/// Columns the Package Info pane or PKGBUILD viewer moves per horizontal scroll key press.
+const DETAILS_HSCROLL_STEP: u16 = 8;
+
+/// What: Activate an Options dropdown row (digit shortcut or command palette).
+///
+/// Inputs:
+/// - `app`: Mutable application state
+/// - `idx`: Row index: 0 toggle installed-only, 1 update system, 2 news, 3 optional deps,
+///   4 search scope, 5 rank mirrors, 6 themes, 7 check keybinds, 8 upgradable only,
+///   9 config leftovers, 10 logs, 11 error history
+/// - `details_tx`: Channel used to request package detail refreshes
+///
+/// Output:
+/// - No return value; unknown rows are ignored. The caller closes the menu.
+pub(crate) fn activate_options_row(
+    app: &mut AppState,
+    idx: usize,
+    details_tx: &mpsc::UnboundedSender<PackageItem>,
+) {
+    match idx {
+        0 => {
+            // same as mouse options handler row 0
+            if app.installed_only_mode {
+                if let Some(prev) = app.results_backup_for_toggle.take() {
+                    app.all_results = prev;
+                }
+                app.installed_only_mode = false;
+                app.right_pane_focus = crate::state::RightPaneFocus::Install;
+                crate::logic::apply_filters_and_sort_preserve_selection(app);
+                utils::refresh_selected_details(app, details_tx);
+            } else {
+                utils::enter_installed_only_mode(app, details_tx);
+            }
+        }
+        1 => {
+            let countries = vec![
+                "Worldwide".to_string(),
+                "Germany".to_string(),
+                "United States".to_string(),
+                "United Kingdom".to_string(),
+                "France".to_string(),
+                "Netherlands".to_string(),
+                "Sweden".to_string(),
+                "Canada".to_string(),
+                "Australia".to_string(),
+                "Japan".to_string(),
+            ];
+            let prefs = crate::theme::settings();
+            let initial_country_idx = {
+                let sel = prefs
+                    .selected_countries
+                    .split(',')
+                    .next()
+                    .map(|s| s.trim().to_string())
+                    .unwrap_or_else(|| "Worldwide".to_string());
+                countries.iter().position(|c| c == &sel).unwrap_or(0)
+            };
+            app.modal = crate::state::Modal::SystemUpdate {
+                do_mirrors: false,
+                do_pacman: true,
+                do_aur: true,
+                do_cache: false,
+                country_idx: initial_country_idx,
+                countries,
+                mirror_count: prefs.mirror_count,
+                cursor: 0,
+                loading: false,
+            };
+        }
+        2 => {
+            let (tx, rx) = std::sync::mpsc::channel();
+            std::thread::spawn(move || {
+                let rt = tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build();
+                let res = match rt {
+                    Ok(rt) => rt.block_on(crate::sources::fetch_arch_news(10)),
+                    Err(e) => Err::<Vec<crate::state::NewsItem>, _>(format!("rt: {e}").into()),
+                };
+                let _ = tx.send(res);
+            });
+            match rx.recv_timeout(std::time::Duration::from_secs(3)) {
+                Ok(Ok(list)) => {
+                    app.modal = crate::state::Modal::News {
+                        items: list,
+                        selected: 0,
+                    };
+                }
+                Ok(Err(e)) => {
+                    app.modal = crate::state::Modal::Alert {
+                        message: format!("Failed to fetch news: {e}"),
+                    };
+                }
+                Err(_) => {
+                    app.modal = crate::state::Modal::Alert {
+                        message: "Timed out fetching news".to_string(),
+                    };
+                }
+            }
+        }
+        3 => {
+            // Open Optional Deps modal (same as mouse handler row 3)
+            let mut rows: Vec<crate::state::types::OptionalDepRow> = Vec::new();
+            let is_pkg_installed = |pkg: &str| crate::index::is_installed(pkg);
+            let on_path = |cmd: &str| crate::install::command_on_path(cmd);
+            // (Security rows will be appended after AUR helper for desired order)
+            // Editor
+            let editor_candidates: &[(&str, &str)] = &[
+                ("nvim", "neovim"),
+                ("vim", "vim"),
+                ("hx", "helix"),
+                ("helix", "helix"),
+                ("emacsclient", "emacs"),
+                ("emacs", "emacs"),
+                ("nano", "nano"),
+            ];
+            let mut editor_installed: Option<(&str, &str)> = None;
+            for (bin, pkg) in editor_candidates.iter() {
+                if on_path(bin) || is_pkg_installed(pkg) {
+                    editor_installed = Some((*bin, *pkg));
+                    break;
+                }
+            }
+            if let Some((bin, pkg)) = editor_installed {
+                rows.push(crate::state::types::OptionalDepRow {
+                    label: format!("Editor: {}", bin),
+                    package: pkg.to_string(),
+                    installed: (is_pkg_installed(pkg)
+                        || on_path(bin)
+                        || ((pkg == "helix") && (on_path("hx") || on_path("helix")))
+                        || ((pkg == "emacs") && (on_path("emacs") || on_path("emacsclient")))),
+                    selectable: false,
+                    note: None,
+                });
+            } else {
+                let mut seen = std::collections::HashSet::new();
+                for (bin, pkg) in editor_candidates.iter() {
+                    if seen.insert(*pkg) {
+                        rows.push(crate::state::types::OptionalDepRow {
+                            label: format!("Editor: {}", bin),
+                            package: pkg.to_string(),
+                            installed: (is_pkg_installed(pkg)
+                                || on_path(bin)
+                                || ((*pkg == "helix") && (on_path("hx") || on_path("helix")))
+                                || ((*pkg == "emacs")
+                                    && (on_path("emacs") || on_path("emacsclient")))),
+                            selectable: !(is_pkg_installed(pkg)
+                                || on_path(bin)
+                                || ((*pkg == "helix") && (on_path("hx") || on_path("helix")))
+                                || ((*pkg == "emacs")
+                                    && (on_path("emacs") || on_path("emacsclient")))),
+                            note: None,
+                        });
+                    }
+                }
+            }
+            // Terminal
+            let term_candidates: &[(&str, &str)] = &[
+                ("alacritty", "alacritty"),
+                ("ghostty", "ghostty"),
+                ("kitty", "kitty"),
+                ("xterm", "xterm"),
+                ("gnome-terminal", "gnome-terminal"),
+                ("konsole", "konsole"),
+                ("xfce4-terminal", "xfce4-terminal"),
+                ("tilix", "tilix"),
+                ("mate-terminal", "mate-terminal"),
+            ];
+            let mut term_installed: Option<(&str, &str)> = None;
+            for (bin, pkg) in term_candidates.iter() {
+                if on_path(bin) || is_pkg_installed(pkg) {
+                    term_installed = Some((*bin, *pkg));
+                    break;
+                }
+            }
+            if let Some((bin, pkg)) = term_installed {
+                rows.push(crate::state::types::OptionalDepRow {
+                    label: format!("Terminal: {}", bin),
+                    package: pkg.to_string(),
+                    installed: (is_pkg_installed(pkg) || on_path(bin)),
+                    selectable: false,
+                    note: None,
+                });
+            } else {
+                for (bin, pkg) in term_candidates.iter() {
+                    rows.push(crate::state::types::OptionalDepRow {
+                        label: format!("Terminal: {}", bin),
+                        package: pkg.to_string(),
+                        installed: (is_pkg_installed(pkg) || on_path(bin)),
+                        selectable: !(is_pkg_installed(pkg) || on_path(bin)),
+                        note: None,
+                    });
+                }
+            }
+            // Clipboard: Prefer Klipper when KDE session detected; else Wayland/X11 specific
+            let is_kde = std::env::var("KDE_FULL_SESSION").is_ok()
+                || std::env::var("XDG_CURRENT_DESKTOP")
+                    .ok()
+                    .map(|v| {
+                        let u = v.to_uppercase();
+                        u.contains("KDE") || u.contains("PLASMA")
+                    })
+                    .unwrap_or(false)
+                || on_path("klipper");
+            if is_kde {
+                let pkg = "plasma-workspace";
+                rows.push(crate::state::types::OptionalDepRow {
+                    label: "Clipboard: Klipper (KDE)".to_string(),
+                    package: pkg.to_string(),
+                    installed: is_pkg_installed(pkg) || on_path("klipper"),
+                    selectable: !(is_pkg_installed(pkg) || on_path("klipper")),
+                    note: Some("KDE Plasma".to_string()),
+                });
+            } else {
+                let is_wayland = std::env::var("WAYLAND_DISPLAY").is_ok();
+                if is_wayland {
+                    let pkg = "wl-clipboard";
+                    rows.push(crate::state::types::OptionalDepRow {
+                        label: "Clipboard: wl-clipboard".to_string(),
+                        package: pkg.to_string(),
+                        installed: is_pkg_installed(pkg) || on_path("wl-copy"),
+                        selectable: !(is_pkg_installed(pkg) || on_path("wl-copy")),
+                        note: Some("Wayland".to_string()),
+                    });
+                } else {
+                    let pkg = "xclip";
+                    rows.push(crate::state::types::OptionalDepRow {
+                        label: "Clipboard: xclip".to_string(),
+                        package: pkg.to_string(),
+                        installed: is_pkg_installed(pkg) || on_path("xclip"),
+                        selectable: !(is_pkg_installed(pkg) || on_path("xclip")),
+                        note: Some("X11".to_string()),
+                    });
+                }
+            }
+            // Mirrors: Manjaro -> pacman-mirrors, Artix -> rate-mirrors, else reflector
+            let os_release = std::fs::read_to_string("/etc/os-release").unwrap_or_default();
+            let manjaro = os_release.contains("Manjaro");
+            let artix = os_release.contains("Artix");
+            if manjaro {
+                let pkg = "pacman-mirrors";
+                rows.push(crate::state::types::OptionalDepRow {
+                    label: "Mirrors: pacman-mirrors".to_string(),
+                    package: pkg.to_string(),
+                    installed: is_pkg_installed(pkg),
+                    selectable: !is_pkg_installed(pkg),
+                    note: Some("Manjaro".to_string()),
+                });
+            } else if artix {
+                let pkg = "rate-mirrors";
+                rows.push(crate::state::types::OptionalDepRow {
+                    label: "Mirrors: rate mirrors".to_string(),
+                    package: pkg.to_string(),
+                    installed: on_path("rate-mirrors") || is_pkg_installed(pkg),
+                    selectable: !(on_path("rate-mirrors") || is_pkg_installed(pkg)),
+                    note: Some("Artix".to_string()),
+                });
+            } else {
+                let pkg = "reflector";
+                rows.push(crate::state::types::OptionalDepRow {
+                    label: "Mirrors: reflector".to_string(),
+                    package: pkg.to_string(),
+                    installed: is_pkg_installed(pkg),
+                    selectable: !is_pkg_installed(pkg),
+                    note: None,
+                });
+            }
+            // (VirusTotal API row will be appended at the end for desired order)
+            // AUR helper
+            let paru_inst = on_path("paru") || is_pkg_installed("paru");
+            let yay_inst = on_path("yay") || is_pkg_installed("yay");
+            if paru_inst || yay_inst {
+                if paru_inst {
+                    rows.push(crate::state::types::OptionalDepRow {
+                        label: "AUR helper: paru".to_string(),
+                        package: "paru".to_string(),
+                        installed: true,
+                        selectable: false,
+                        note: None,
+                    });
+                } else if yay_inst {
+                    rows.push(crate::state::types::OptionalDepRow {
+                        label: "AUR helper: yay".to_string(),
+                        package: "yay".to_string(),
+                        installed: true,
+                        selectable: false,
+                        note: None,
+                    });
+                }
+            } else {
+                rows.push(crate::state::types::OptionalDepRow {
+                    label: "AUR helper: paru".to_string(),
+                    package: "paru".to_string(),
+                    installed: false,
+                    selectable: true,
+                    note: Some("Install via git clone + makepkg -si".to_string()),
+                });
+                rows.push(crate::state::types::OptionalDepRow {
+                    label: "AUR helper: yay".to_string(),
+                    package: "yay".to_string(),
+                    installed: false,
+                    selectable: true,
+                    note: Some("Install via git clone + makepkg -si".to_string()),
+                });
+            }
+            // Append Security rows after AUR helper
+            {
+                // Security: clamav (official)
+                let pkg = "clamav";
+                let installed = is_pkg_installed(pkg) || on_path("clamscan");
+                rows.push(crate::state::types::OptionalDepRow {
+                    label: "Security: clamav".to_string(),
+                    package: pkg.to_string(),
+                    installed,
+                    selectable: !installed,
+                    note: None,
+                });
+                // Security: trivy (official)
+                let pkg = "trivy";
+                let installed = is_pkg_installed(pkg) || on_path("trivy");
+                rows.push(crate::state::types::OptionalDepRow {
+                    label: "Security: trivy".to_string(),
+                    package: pkg.to_string(),
+                    installed,
+                    selectable: !installed,
+                    note: None,
+                });
+                // Security: semgrep-bin (AUR)
+                let pkg = "semgrep-bin";
+                let installed = is_pkg_installed(pkg) || on_path("semgrep");
+                rows.push(crate::state::types::OptionalDepRow {
+                    label: "Security: semgrep-bin".to_string(),
+                    package: pkg.to_string(),
+                    installed,
+                    selectable: !installed,
+                    note: Some("AUR".to_string()),
+                });
+                // Security: shellcheck (official)
+                let pkg = "shellcheck";
+                let installed = is_pkg_installed(pkg) || on_path("shellcheck");
+                rows.push(crate::state::types::OptionalDepRow {
+                    label: "Security: shellcheck".to_string(),
+                    package: pkg.to_string(),
+                    installed,
+                    selectable: !installed,
+                    note: None,
+                });
+                // Security: VirusTotal API (Setup)
+                {
+                    let vt_key_present = !crate::theme::settings().virustotal_api_key.is_empty();
+                    rows.push(crate::state::types::OptionalDepRow {
+                        label: "Security: VirusTotal API".to_string(),
+                        package: "virustotal-setup".to_string(),
+                        installed: vt_key_present,
+                        selectable: true,
+                        note: Some("Setup".to_string()),
+                    });
+                    // aur-sleuth (LLM audit) setup
+                    let sleuth_installed = {
+                        let onpath = on_path("aur-sleuth");
+                        let home = std::env::var("HOME").ok();
+                        let user_local = home
+                            .as_deref()
+                            .map(|h| {
+                                std::path::Path::new(h)
+                                    .join(".local/bin/aur-sleuth")
+                                    .exists()
+                            })
+                            .unwrap_or(false);
+                        let usr_local = std::path::Path::new("/usr/local/bin/aur-sleuth").exists();
+                        onpath || user_local || usr_local
+                    };
+                    rows.push(crate::state::types::OptionalDepRow {
+                        label: "Security: aur-sleuth".to_string(),
+                        package: "aur-sleuth-setup".to_string(),
+                        installed: sleuth_installed,
+                        selectable: true,
+                        note: Some("Setup".to_string()),
+                    });
+                }
+            }
+            app.modal = crate::state::Modal::OptionalDeps { rows, selected: 0 };
+        }
+        4 => utils::toggle_search_scope(app, details_tx),
+        5 => utils::start_mirror_ranking(app),
+        6 => utils::open_theme_picker(app),
+        7 => utils::open_keybind_diagnostics(app),
+        8 => utils::toggle_upgradable_only(app, details_tx),
+        9 => utils::open_config_leftovers(app),
+        10 => utils::open_log_viewer(app),
+        11 => utils::open_error_log(app),
+        12 => utils::open_activity_log(app),
+        _ => {}
+    }
+}
+
+/// What: Run a global action, whether triggered by its keybind or the command palette.
+///
+/// Inputs:
+/// - `action`: Action to run
+/// - `app`: Mutable application state
+/// - `details_tx`: Channel used to request package detail refreshes
+/// - `pkgb_tx`: Channel used to request PKGBUILD content for the focused result
+///
+/// Output:
+/// - `true` when the application should exit; otherwise `false`.
+///
+/// Details:
+/// - Menu-only actions reuse the Options/Config dropdown handlers.
+pub(crate) fn run_action(
+    action: Action,
+    app: &mut AppState,
+    details_tx: &mpsc::UnboundedSender<PackageItem>,
+    pkgb_tx: &mpsc::UnboundedSender<PackageItem>,
+) -> bool {
+    match action {
+        Action::Help => app.modal = crate::state::Modal::Help,
+        Action::ReloadTheme => match reload_theme() {
+            Ok(()) => {
+                app.toast_message = Some(crate::i18n::t(app, "app.toasts.theme_reloaded"));
+                app.toast_expires_at =
+                    Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+            }
+            Err(msg) => {
+                app.modal = crate::state::Modal::Alert { message: msg };
+            }
+        },
+        Action::ReloadSettings => utils::reload_settings(app),
+        Action::CycleTheme => {
+            utils::cycle_theme(app);
+        }
+        Action::Exit => {
+            return true;
+        }
+        // Toggle PKGBUILD viewer globally
+        Action::TogglePkgbuild => {
+            if app.pkgb_visible {
+                app.pkgb_visible = false;
+                app.pkgb_text = None;
+                app.pkgb_package_name = None;
+                app.pkgb_scroll = 0;
+                app.pkgb_hscroll = 0;
+                app.pkgb_rect = None;
+            } else {
+                app.pkgb_visible = true;
+                app.pkgb_text = None;
+                app.pkgb_package_name = None;
+                if let Some(item) = app.results.get(app.selected).cloned() {
+                    let _ = pkgb_tx.send(item);
+                }
+            }
+        }
+        // Jump the PKGBUILD viewer to the next install-path/backup line
+        Action::PkgbuildNextMark => {
+            if let Some(text) = app.pkgb_text.as_deref() {
+                let marks = crate::logic::files::pkgbuild_line_marks(text);
+                match crate::logic::files::next_marked_line(&marks, app.pkgb_scroll as usize) {
+                    Some(line) => app.pkgb_scroll = u16::try_from(line).unwrap_or(u16::MAX),
+                    None => {
+                        app.toast_message =
+                            Some(crate::i18n::t(app, "app.toasts.pkgbuild_no_marks"));
+                        app.toast_expires_at =
+                            Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+                    }
+                }
+            }
+        }
+        // Global: Change sorting via configured keybind
+        Action::ChangeSort => {
+            // Cycle through sort modes in fixed order
+            app.sort_mode = match app.sort_mode {
+                crate::state::SortMode::RepoThenName => {
+                    crate::state::SortMode::AurPopularityThenOfficial
+                }
+                crate::state::SortMode::AurPopularityThenOfficial => {
+                    crate::state::SortMode::BestMatches
+                }
+                crate::state::SortMode::BestMatches => crate::state::SortMode::RepoThenName,
+            };
+            app.sort_descending = false;
+            // Persist preference and apply immediately
+            crate::theme::save_sort_mode(app.sort_mode);
+            crate::logic::sort_results_preserve_selection(app);
+            // Jump selection to top and refresh details
+            if !app.results.is_empty() {
+                app.selected = 0;
+                app.list_state.select(Some(0));
+                utils::refresh_selected_details(app, details_tx);
+            } else {
+                app.list_state.select(None);
+            }
+            // Show the dropdown so the user sees the current option with a check mark
+            app.sort_menu_open = true;
+            app.sort_menu_auto_close_at =
+                Some(std::time::Instant::now() + std::time::Duration::from_secs(2));
+        }
+        // Global: Show/Hide mirror download URL in Package Info
+        Action::ToggleDownloadServer => {
+            app.show_download_server = !app.show_download_server;
+            let key = if app.show_download_server {
+                "app.toasts.download_server_shown"
+            } else {
+                "app.toasts.download_server_hidden"
+            };
+            app.toast_message = Some(crate::i18n::t(app, key));
+            app.toast_expires_at =
+                Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+        }
+        // Global: Expand/collapse the Results status line
+        Action::ToggleStatusVerbose => {
+            app.status_verbose = !app.status_verbose;
+            if app.status_verbose {
+                // Snapshot once per toggle; both are local reads (file mtimes and `pacman -Qu`)
+                app.status_db_sync = crate::logic::files::get_file_db_sync_info()
+                    .map(|(days, date, _)| (days, date));
+                app.status_upgradable = Some(crate::logic::deps::get_upgradable_packages().len());
+            }
+        }
+        // Global: Show/hide active resolution stages in the Results status line
+        Action::ToggleStatusSpans => {
+            app.status_spans = !app.status_spans;
+        }
+        // Global: Store the selected package in a compare slot; slot B opens the diff
+        Action::CompareSlotA | Action::CompareSlotB => {
+            let compare_a = action == Action::CompareSlotA;
+            let Some(details) = selected_details(app) else {
+                app.toast_message = Some(crate::i18n::t(app, "app.toasts.compare_no_details"));
+                app.toast_expires_at =
+                    Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+                return false;
+            };
+            let name = details.name.clone();
+            if compare_a {
+                app.compare_slot_a = Some(details);
+            } else {
+                app.compare_slot_b = Some(details);
+            }
+            match (&app.compare_slot_a, &app.compare_slot_b) {
+                (Some(a), Some(b)) if !compare_a => {
+                    app.modal = crate::state::Modal::PackageCompare {
+                        left: a.name.clone(),
+                        right: b.name.clone(),
+                        rows: crate::logic::compare::compare_details(a, b),
+                    };
+                }
+                _ => {
+                    let slot = if compare_a { "A" } else { "B" };
+                    app.toast_message = Some(crate::i18n::t_fmt(
+                        app,
+                        "app.toasts.compare_slot_set",
+                        &[&name, &slot],
+                    ));
+                    app.toast_expires_at =
+                        Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+                }
+            }
+        }
+        // Global: Open the selected package's upstream URL (fetching details first if needed)
+        Action::OpenHomepage => {
+            if let Some(item) = app.results.get(app.selected).cloned() {
+                let url = selected_details(app)
+                    .map(|d| d.url)
+                    .filter(|u| !u.is_empty());
+                let key = if let Some(url) = url {
+                    crate::util::open_url(&url);
+                    None
+                } else if app.details_cache.contains_key(&item.name) {
+                    Some("app.toasts.homepage_missing")
+                } else {
+                    app.pending_homepage_for = Some(item.name.clone());
+                    let _ = details_tx.send(item);
+                    Some("app.toasts.homepage_fetching")
+                };
+                if let Some(key) = key {
+                    app.toast_message = Some(crate::i18n::t(app, key));
+                    app.toast_expires_at =
+                        Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+                }
+            }
+        }
+        // Global: Open the selected package's AUR / archlinux.org page
+        Action::OpenPackagePage => super::utils::open_package_page(app),
+        // Global: Preview the exact commands the focused list would run
+        Action::PreviewCommands => {
+            let lines = preview_command_lines(app);
+            if lines.is_empty() {
+                app.toast_message = Some(crate::i18n::t(app, "app.toasts.command_preview_empty"));
+                app.toast_expires_at =
+                    Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+            } else {
+                app.modal = crate::state::Modal::CommandPreview { lines };
+            }
+        }
+        // Global: Explain why the selected package is installed
+        Action::WhyInstalled => {
+            if let Some(item) = app.results.get(app.selected).cloned() {
+                if crate::index::is_installed(&item.name) {
+                    // `pacman -Qi` over every installed package runs in the runtime
+                    app.why_installed_request = Some(item.name.clone());
+                    app.modal = crate::state::Modal::WhyInstalled {
+                        package: item.name,
+                        chains: Vec::new(),
+                        loading: true,
+                    };
+                } else {
+                    app.toast_message = Some(crate::i18n::t(app, "app.toasts.why_not_installed"));
+                    app.toast_expires_at =
+                        Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+                }
+            }
+        }
+        // Global: List the files the selected package installs
+        Action::ShowFiles => utils::open_package_files(app),
+        // Global: Clear the install, remove, and downgrade lists (confirm first)
+        Action::ClearAllLists => {
+            let (install, remove, downgrade) = (
+                app.install_list.len(),
+                app.remove_list.len(),
+                app.downgrade_list.len(),
+            );
+            if install + remove + downgrade == 0 {
+                app.toast_message = Some(crate::i18n::t(app, "app.toasts.lists_already_empty"));
+                app.toast_expires_at =
+                    Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+            } else {
+                app.modal = crate::state::Modal::ConfirmClearLists {
+                    install,
+                    remove,
+                    downgrade,
+                };
+            }
+        }
+        // Global: Undo / redo install, remove, and downgrade list changes
+        Action::Undo | Action::Redo => {
+            let key = if action == Action::Undo {
+                if crate::logic::lists::undo_list_change(app) {
+                    "app.toasts.list_undone"
+                } else {
+                    "app.toasts.list_nothing_to_undo"
+                }
+            } else if crate::logic::lists::redo_list_change(app) {
+                "app.toasts.list_redone"
+            } else {
+                "app.toasts.list_nothing_to_redo"
+            };
+            app.toast_message = Some(crate::i18n::t(app, key));
+            app.toast_expires_at =
+                Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+        }
+        // Global: Reopen the most recently closed modal
+        Action::ReopenModal => {
+            if let Some(modal) = app.last_closed_modal.take() {
+                app.modal = modal;
+            } else {
+                app.toast_message = Some(crate::i18n::t(app, "app.toasts.no_modal_to_reopen"));
+                app.toast_expires_at =
+                    Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+            }
+        }
+        // Global: Copy current filters + sort as settings.conf lines
+        Action::CopyViewSettings => {
+            // The runtime copies off the UI thread and toasts the outcome
+            app.view_settings_copy_request = Some(crate::logic::filter::view_settings_lines(app));
+        }
+        Action::CommandPalette => {
+            app.modal = crate::state::Modal::CommandPalette {
+                query: String::new(),
+                selected: 0,
+            };
+        }
+        Action::OpenLogs => utils::open_log_viewer(app),
+        // Sideways scrolling and wrapping act on the PKGBUILD viewer while it is open
+        Action::DetailsScrollLeft if app.pkgb_visible => {
+            app.pkgb_hscroll = app.pkgb_hscroll.saturating_sub(DETAILS_HSCROLL_STEP);
+        }
+        Action::DetailsScrollLeft => {
+            app.details_hscroll = app.details_hscroll.saturating_sub(DETAILS_HSCROLL_STEP);
+        }
+        Action::DetailsScrollRight if app.pkgb_visible => {
+            app.pkgb_hscroll = app
+                .pkgb_hscroll
+                .saturating_add(DETAILS_HSCROLL_STEP)
+                .min(app.pkgb_hscroll_max);
+        }
+        Action::DetailsScrollRight => {
+            app.details_hscroll = app
+                .details_hscroll
+                .saturating_add(DETAILS_HSCROLL_STEP)
+                .min(app.details_hscroll_max);
+        }
+        Action::ToggleWrap => utils::toggle_wrap(app),
+        Action::RefreshInstalled => utils::refresh_installed_now(app),
+        Action::ToggleInstalledOnly => activate_options_row(app, 0, details_tx),
+        Action::UpdateSystem => activate_options_row(app, 1, details_tx),
+        Action::News => activate_options_row(app, 2, details_tx),
+        Action::OptionalDeps => activate_options_row(app, 3, details_tx),
+        Action::ToggleSearchScope => utils::toggle_search_scope(app, details_tx),
+        Action::RankMirrors => utils::start_mirror_ranking(app),
+        Action::Themes => utils::open_theme_picker(app),
+        Action::CheckKeybinds => utils::open_keybind_diagnostics(app),
+        Action::ToggleUpgradableOnly => utils::toggle_upgradable_only(app, details_tx),
+        Action::ConfigLeftovers => utils::open_config_leftovers(app),
+        Action::ErrorLog => utils::open_error_log(app),
+        Action::ActivityLog => utils::open_activity_log(app),
+        Action::AddOrphans => utils::add_orphans_to_remove_list(app),
+        Action::SaveProfile => utils::open_profiles_modal(app, true),
+        Action::LoadProfile => utils::open_profiles_modal(app, false),
+    }
+    false
+}
+
+/// What: Details of the selected result, if they have been loaded.
+///
+/// Inputs:
+/// - `app`: Application state with the current selection and details caches
+///
+/// Output:
+/// - The shown details when they belong to the selection, else the cached entry; `None` otherwise.
+fn selected_details(app: &AppState) -> Option<crate::state::PackageDetails> {
+    let item = app.results.get(app.selected)?;
+    if app.details.name == item.name {
+        return Some(app.details.clone());
+    }
+    app.details_cache.get(&item.name).cloned()
+}
+
+/// What: Command lines the focused right-pane list would run.
+///
+/// Inputs:
+/// - `app`: Application state with the install/remove/downgrade lists and pane focus
+///
+/// Output:
+/// - Lines from `install::command` for the Remove or Downgrade pane in installed-only mode,
+///   otherwise for the Install list; empty when that list is empty.
+fn preview_command_lines(app: &AppState) -> Vec<String> {
+    use crate::install::command;
+    use crate::state::RightPaneFocus;
+    match (app.installed_only_mode, app.right_pane_focus) {
+        (true, RightPaneFocus::Remove) if !app.remove_list.is_empty() => {
+            let names: Vec<String> = app.remove_list.iter().map(|p| p.name.clone()).collect();
+            vec![command::remove_command_line(
+                &names,
+                app.remove_cascade_mode,
+            )]
+        }
+        (true, RightPaneFocus::Remove) => Vec::new(),
+        (true, RightPaneFocus::Downgrade) => {
+            let (paths, names) = super::install::downgrade_plan(app);
+            command::downgrade_command_lines(&paths, &names)
+        }
+        _ => command::install_command_lines(&app.install_list, command::detect_aur_helper()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -796,4 +1072,43 @@ mod tests {
 
         assert_eq!(exit, Some(true));
     }
+
+    #[test]
+    /// What: Ensure the sideways scroll keys step, clamp, and reset with the selection.
+    ///
+    /// Inputs:
+    /// - A rendered maximum of 20 columns; `Alt+Right` three times, `Alt+Left` once, then a new
+    ///   selection.
+    ///
+    /// Output:
+    /// - Offsets 8, 16, 20 (clamped), back to 12, and 0 after the selected package changes.
+    fn global_details_hscroll_clamps_and_resets() {
+        let mut app = new_app();
+        app.details_hscroll_max = 20;
+        app.results = vec![PackageItem {
+            name: "pkg".into(),
+            version: String::new(),
+            description: String::new(),
+            source: crate::state::Source::Aur,
+            popularity: None,
+        }];
+        let (details_tx, _details_rx) = mpsc::unbounded_channel::<PackageItem>();
+        let (pkgb_tx, _pkgb_rx) = mpsc::unbounded_channel::<PackageItem>();
+        let press = |app: &mut AppState, code| {
+            handle_global_key(
+                KeyEvent::new(code, KeyModifiers::ALT),
+                app,
+                &details_tx,
+                &pkgb_tx,
+            )
+        };
+        for expected in [8, 16, 20] {
+            assert_eq!(press(&mut app, KeyCode::Right), Some(false));
+            assert_eq!(app.details_hscroll, expected);
+        }
+        press(&mut app, KeyCode::Left);
+        assert_eq!(app.details_hscroll, 12);
+        utils::refresh_selected_details(&mut app, &details_tx);
+        assert_eq!(app.details_hscroll, 0);
+    }
 }
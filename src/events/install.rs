@@ -2,6 +2,7 @@ use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use tokio::sync::mpsc;
 
 use crate::i18n;
+use crate::logic::history::ListOp;
 use crate::state::{AppState, PackageItem};
 
 use super::utils::{
@@ -34,6 +35,8 @@ use super::utils::{
 ///   list (Install/Remove/Downgrade) and updates selection and details.
 /// - Clear list: Configured `install_clear` clears the respective list (or all in normal mode),
 ///   and resets selection.
+/// - Remove group: Configured `install_remove_group` drops every Install entry expanded from the
+///   selected entry's package group.
 /// - Enter:
 ///   - Normal mode with non-empty Install list: opens `Modal::ConfirmInstall` for batch install.
 ///   - Installed-only Remove focus with non-empty list: opens `Modal::ConfirmRemove`.
@@ -179,7 +182,22 @@ pub fn handle_install_key(
             );
             let skip = crate::theme::settings().skip_preflight || skip_preflight_for_modals;
             if !app.installed_only_mode && !app.install_list.is_empty() {
-                if skip {
+                let guard = skip
+                    .then(|| {
+                        super::utils::confirm_guard(
+                            app,
+                            crate::state::PreflightAction::Install,
+                            app.install_list.len(),
+                        )
+                    })
+                    .flatten();
+                if guard.is_some() {
+                    // Large transaction without review: require the typed count first
+                    app.modal = crate::state::Modal::ConfirmInstall {
+                        items: app.install_list.clone(),
+                        guard,
+                    };
+                } else if skip {
                     crate::install::spawn_install_all(&app.install_list, app.dry_run);
                     app.toast_message = Some(crate::i18n::t(
                         app,
@@ -378,6 +396,7 @@ pub fn handle_install_key(
                         } else {
                             vec![]
                         },
+                        resolved_transaction: None,
                     };
                     let minimal_header = crate::state::modal::PreflightHeaderChips {
                         package_count: items.len(),
@@ -484,8 +503,22 @@ pub fn handle_install_key(
             } else if app.installed_only_mode
                 && matches!(app.right_pane_focus, crate::state::RightPaneFocus::Remove)
             {
+                let guard = skip
+                    .then(|| {
+                        super::utils::confirm_guard(
+                            app,
+                            crate::state::PreflightAction::Remove,
+                            app.remove_list.len(),
+                        )
+                    })
+                    .flatten();
                 if !app.remove_list.is_empty() {
-                    if skip {
+                    if guard.is_some() {
+                        app.modal = crate::state::Modal::ConfirmRemove {
+                            items: app.remove_list.clone(),
+                            guard,
+                        };
+                    } else if skip {
                         let names: Vec<String> =
                             app.remove_list.iter().map(|p| p.name.clone()).collect();
                         crate::install::spawn_remove_all(
@@ -558,21 +591,59 @@ pub fn handle_install_key(
                 )
                 && !app.downgrade_list.is_empty()
             {
-                let names: Vec<String> =
-                    app.downgrade_list.iter().map(|p| p.name.clone()).collect();
-                let joined = names.join(" ");
-                let cmd = if app.dry_run {
-                    format!("echo DRY RUN: downgrade {joined}")
+                let (picked, names) = downgrade_plan(app);
+                let lines = crate::install::command::downgrade_command_lines(&picked, &names);
+                let cmds: Vec<String> = if app.dry_run {
+                    lines
+                        .iter()
+                        .map(|l| format!("echo DRY RUN: {}", crate::install::shell_single_quote(l)))
+                        .collect()
                 } else {
-                    format!(
-                        "((command -v downgrade >/dev/null 2>&1) || sudo pacman -Qi downgrade >/dev/null 2>&1) && downgrade {joined} || echo 'downgrade tool not found. Install \"downgrade\" from AUR.'"
-                    )
+                    lines
                 };
-                crate::install::spawn_shell_commands_in_terminal(&[cmd]);
+                crate::install::spawn_shell_commands_in_terminal(&cmds);
+                if !app.dry_run {
+                    // Downgrades run in a terminal without completion tracking; record on launch
+                    let downgraded: Vec<String> =
+                        app.downgrade_list.iter().map(|p| p.name.clone()).collect();
+                    crate::logic::activity::append(
+                        crate::logic::activity::ActivityKind::Downgrade,
+                        &downgraded,
+                    );
+                }
                 app.downgrade_list.clear();
+                app.downgrade_targets.clear();
                 app.downgrade_state.select(None);
             }
         }
+        code if matches_any(&km.install_pick_version) && code == ke.code => {
+            if app.installed_only_mode
+                && matches!(
+                    app.right_pane_focus,
+                    crate::state::RightPaneFocus::Downgrade
+                )
+                && let Some(item) = app
+                    .downgrade_state
+                    .selected()
+                    .and_then(|i| app.downgrade_list.get(i))
+                    .cloned()
+            {
+                let versions = crate::logic::downgrade::cached_versions(&item.name);
+                let selected = versions
+                    .iter()
+                    .position(|c| {
+                        crate::logic::preflight::compare_versions(&c.version, &item.version)
+                            == std::cmp::Ordering::Less
+                    })
+                    .unwrap_or(0);
+                app.modal = crate::state::Modal::DowngradeVersions {
+                    package: item.name,
+                    installed: item.version,
+                    versions,
+                    selected,
+                };
+            }
+        }
         KeyCode::Esc => {
             app.focus = crate::state::Focus::Search;
             // Activate Search Normal mode when returning with Esc
@@ -687,6 +758,7 @@ pub fn handle_install_key(
                         if let Some(sel) = app.downgrade_state.selected()
                             && sel < app.downgrade_list.len()
                         {
+                            crate::logic::lists::record_list_change(app, ListOp::Remove);
                             app.downgrade_list.remove(sel);
                             let len = app.downgrade_list.len();
                             if len == 0 {
@@ -702,6 +774,7 @@ pub fn handle_install_key(
                         if let Some(sel) = app.remove_state.selected()
                             && sel < app.remove_list.len()
                         {
+                            crate::logic::lists::record_list_change(app, ListOp::Remove);
                             app.remove_list.remove(sel);
                             let len = app.remove_list.len();
                             if len == 0 {
@@ -722,6 +795,7 @@ pub fn handle_install_key(
                         if let Some(vsel) = app.install_state.selected() {
                             let i = inds.get(vsel).copied().unwrap_or(0);
                             if i < app.install_list.len() {
+                                crate::logic::lists::record_list_change(app, ListOp::Remove);
                                 app.install_list.remove(i);
                                 app.install_dirty = true;
                                 // Clear dependency cache when list changes
@@ -750,6 +824,7 @@ pub fn handle_install_key(
                 if let Some(vsel) = app.install_state.selected() {
                     let i = inds.get(vsel).copied().unwrap_or(0);
                     if i < app.install_list.len() {
+                        crate::logic::lists::record_list_change(app, ListOp::Remove);
                         app.install_list.remove(i);
                         app.install_dirty = true;
                         let vis_len = inds.len().saturating_sub(1); // one less visible
@@ -768,16 +843,26 @@ pub fn handle_install_key(
             if app.installed_only_mode {
                 match app.right_pane_focus {
                     crate::state::RightPaneFocus::Downgrade => {
+                        if !app.downgrade_list.is_empty() {
+                            crate::logic::lists::record_list_change(app, ListOp::Clear);
+                        }
                         app.downgrade_list.clear();
                         app.downgrade_state.select(None);
                     }
                     crate::state::RightPaneFocus::Remove => {
+                        if !app.remove_list.is_empty() {
+                            crate::logic::lists::record_list_change(app, ListOp::Clear);
+                        }
                         app.remove_list.clear();
                         app.remove_state.select(None);
                     }
                     crate::state::RightPaneFocus::Install => {
+                        if !app.install_list.is_empty() {
+                            crate::logic::lists::record_list_change(app, ListOp::Clear);
+                        }
                         app.install_list.clear();
                         app.install_state.select(None);
+                        app.install_group_of.clear();
                         app.install_dirty = true;
                         // Clear dependency cache when list is cleared
                         app.install_list_deps.clear();
@@ -787,14 +872,39 @@ pub fn handle_install_key(
                     }
                 }
             } else {
+                if !app.install_list.is_empty() {
+                    crate::logic::lists::record_list_change(app, ListOp::Clear);
+                }
                 app.install_list.clear();
                 app.install_state.select(None);
+                app.install_group_of.clear();
                 app.install_dirty = true;
                 // Clear dependency cache when list is cleared
                 app.install_list_deps.clear();
                 app.deps_resolving = false;
             }
         }
+        code if matches_any(&km.install_remove_group) && code == ke.code => {
+            let inds = crate::ui::helpers::filtered_install_indices(app);
+            let group = app
+                .install_state
+                .selected()
+                .and_then(|vsel| inds.get(vsel).copied())
+                .and_then(|i| app.install_list.get(i))
+                .and_then(|p| app.install_group_of.get(&p.name.to_lowercase()).cloned());
+            app.toast_message = Some(match group {
+                Some(group) => {
+                    let removed = crate::logic::lists::remove_install_group(app, &group);
+                    if app.install_state.selected().is_some() {
+                        refresh_install_details(app, details_tx);
+                    }
+                    crate::i18n::t_fmt(app, "app.toasts.install_group_removed", &[&removed, &group])
+                }
+                None => crate::i18n::t(app, "app.toasts.install_group_none"),
+            });
+            app.toast_expires_at =
+                Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+        }
         code if matches_any(&km.install_remove) && code == ke.code => {
             // Support 'd' (and other configured keys) as an alternative to Delete everywhere
             if app.installed_only_mode {
@@ -803,6 +913,7 @@ pub fn handle_install_key(
                         if let Some(sel) = app.downgrade_state.selected()
                             && sel < app.downgrade_list.len()
                         {
+                            crate::logic::lists::record_list_change(app, ListOp::Remove);
                             app.downgrade_list.remove(sel);
                             let len = app.downgrade_list.len();
                             if len == 0 {
@@ -818,6 +929,7 @@ pub fn handle_install_key(
                         if let Some(sel) = app.remove_state.selected()
                             && sel < app.remove_list.len()
                         {
+                            crate::logic::lists::record_list_change(app, ListOp::Remove);
                             app.remove_list.remove(sel);
                             let len = app.remove_list.len();
                             if len == 0 {
@@ -838,6 +950,7 @@ pub fn handle_install_key(
                         if let Some(vsel) = app.install_state.selected() {
                             let i = inds.get(vsel).copied().unwrap_or(0);
                             if i < app.install_list.len() {
+                                crate::logic::lists::record_list_change(app, ListOp::Remove);
                                 app.install_list.remove(i);
                                 app.install_dirty = true;
                                 // Clear dependency cache when list changes
@@ -866,6 +979,7 @@ pub fn handle_install_key(
                 if let Some(vsel) = app.install_state.selected() {
                     let i = inds.get(vsel).copied().unwrap_or(0);
                     if i < app.install_list.len() {
+                        crate::logic::lists::record_list_change(app, ListOp::Remove);
                         app.install_list.remove(i);
                         app.install_dirty = true;
                         let vis_len = inds.len().saturating_sub(1); // one less visible
@@ -952,6 +1066,26 @@ pub fn handle_install_key(
     false
 }
 
+/// What: Split the downgrade list into picked cache archives and packages left to `downgrade`.
+///
+/// Inputs:
+/// - `app`: Application state with the downgrade list and picked targets
+///
+/// Output:
+/// - `(archive_paths, names)`: entries with a version picked from the cache install that archive
+///   directly; the rest go through the `downgrade` helper.
+pub(crate) fn downgrade_plan(app: &AppState) -> (Vec<String>, Vec<String>) {
+    let mut paths = Vec::new();
+    let mut names = Vec::new();
+    for p in &app.downgrade_list {
+        match app.downgrade_targets.get(&p.name) {
+            Some(c) => paths.push(c.path.display().to_string()),
+            None => names.push(p.name.clone()),
+        }
+    }
+    (paths, names)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
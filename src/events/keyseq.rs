@@ -0,0 +1,268 @@
+//! Multi-key sequence bindings (e.g., `g g` to jump to the top of a list).
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+use crate::state::{AppState, Focus, PackageItem, RightPaneFocus};
+use crate::theme::KeyChord;
+
+/// How long a typed prefix waits for the next chord before it is discarded.
+const SEQUENCE_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Actions reachable through key sequences.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SeqAction {
+    GotoTop,
+    GotoBottom,
+}
+
+/// What: Compare a configured chord against a key press, tolerating Shift/uppercase differences.
+///
+/// Inputs:
+/// - `c`: Configured chord.
+/// - `code`/`mods`: Key code and modifiers reported by the terminal.
+///
+/// Output:
+/// - `true` when the press should count as the configured chord.
+///
+/// Details:
+/// - Terminals report Shift+g either as `G` with or without the SHIFT flag; both match `Shift+g`.
+fn chord_matches(c: &KeyChord, code: KeyCode, mods: KeyModifiers) -> bool {
+    if (c.code, c.mods) == (code, mods) {
+        return true;
+    }
+    match (c.code, code) {
+        (KeyCode::Char(cfg_ch), KeyCode::Char(ev_ch)) if c.mods.contains(KeyModifiers::SHIFT) => {
+            ev_ch == cfg_ch.to_ascii_uppercase()
+                || (mods.contains(KeyModifiers::SHIFT) && ev_ch.to_ascii_lowercase() == cfg_ch)
+        }
+        _ => false,
+    }
+}
+
+/// What: Decide whether plain keys are free to be read as sequence chords in the current context.
+///
+/// Inputs:
+/// - `app`: Application state (focus, Search mode, pane find).
+///
+/// Output:
+/// - `true` for Search normal mode and the Recent/Install lists when not typing a find pattern.
+fn sequences_active(app: &AppState) -> bool {
+    match app.focus {
+        Focus::Search => app.search_normal_mode,
+        Focus::Recent | Focus::Install => app.pane_find.is_none(),
+    }
+}
+
+/// What: Select the first or last entry of the focused list and refresh its preview/details.
+///
+/// Inputs:
+/// - `app`: Mutable application state.
+/// - `top`: `true` for the first entry, `false` for the last.
+/// - `details_tx`/`preview_tx`: Channels used to refresh the details pane.
+///
+/// Output:
+/// - Updates the focused list selection; no-op when that list is empty.
+fn jump(
+    app: &mut AppState,
+    top: bool,
+    details_tx: &mpsc::UnboundedSender<PackageItem>,
+    preview_tx: &mpsc::UnboundedSender<PackageItem>,
+) {
+    let edge = |len: usize| if top { 0 } else { len.saturating_sub(1) };
+    match app.focus {
+        Focus::Search => {
+            let len = app.results.len() as isize;
+            crate::logic::move_sel_cached(app, if top { -len } else { len }, details_tx);
+        }
+        Focus::Recent => {
+            let len = crate::ui::helpers::filtered_recent_indices(app).len();
+            if len > 0 {
+                app.history_state.select(Some(edge(len)));
+                crate::ui::helpers::trigger_recent_preview(app, preview_tx);
+            }
+        }
+        Focus::Install => {
+            if !app.installed_only_mode || matches!(app.right_pane_focus, RightPaneFocus::Install) {
+                let len = crate::ui::helpers::filtered_install_indices(app).len();
+                if len > 0 {
+                    app.install_state.select(Some(edge(len)));
+                    super::utils::refresh_install_details(app, details_tx);
+                }
+            } else if matches!(app.right_pane_focus, RightPaneFocus::Remove) {
+                let len = app.remove_list.len();
+                if len > 0 {
+                    app.remove_state.select(Some(edge(len)));
+                    super::utils::refresh_remove_details(app, details_tx);
+                }
+            } else {
+                let len = app.downgrade_list.len();
+                if len > 0 {
+                    app.downgrade_state.select(Some(edge(len)));
+                    super::utils::refresh_downgrade_details(app, details_tx);
+                }
+            }
+        }
+    }
+}
+
+/// What: Feed a key press into the pending sequence buffer and run a completed sequence binding.
+///
+/// Inputs:
+/// - `ke`: Key event to process.
+/// - `app`: Mutable application state holding the keymap and pending prefix.
+/// - `details_tx`/`preview_tx`: Channels forwarded to list jumps.
+///
+/// Output:
+/// - `true` when the key was consumed (completed a sequence or extended a valid prefix);
+///   `false` when normal key handling should proceed.
+///
+/// Details:
+/// - A prefix older than `SEQUENCE_TIMEOUT` is dropped before matching.
+/// - If a pending prefix is followed by a key that matches no sequence, the prefix is discarded
+///   and the key falls through unchanged.
+pub(super) fn handle_key_sequence(
+    ke: KeyEvent,
+    app: &mut AppState,
+    details_tx: &mpsc::UnboundedSender<PackageItem>,
+    preview_tx: &mpsc::UnboundedSender<PackageItem>,
+) -> bool {
+    if !sequences_active(app) {
+        app.pending_key_sequence.clear();
+        app.pending_key_sequence_at = None;
+        return false;
+    }
+    if app
+        .pending_key_sequence_at
+        .is_some_and(|t| t.elapsed() > SEQUENCE_TIMEOUT)
+    {
+        app.pending_key_sequence.clear();
+    }
+    let mut typed = std::mem::take(&mut app.pending_key_sequence);
+    app.pending_key_sequence_at = None;
+    typed.push(KeyChord {
+        code: ke.code,
+        mods: ke.modifiers,
+    });
+
+    let km = &app.keymap;
+    let bindings = [
+        (SeqAction::GotoTop, &km.goto_top),
+        (SeqAction::GotoBottom, &km.goto_bottom),
+    ];
+    let mut completed: Option<SeqAction> = None;
+    let mut is_prefix = false;
+    for (action, seqs) in bindings {
+        for seq in seqs {
+            if seq.len() < typed.len()
+                || !seq
+                    .iter()
+                    .zip(&typed)
+                    .all(|(c, t)| chord_matches(c, t.code, t.mods))
+            {
+                continue;
+            }
+            if seq.len() == typed.len() {
+                completed.get_or_insert(action);
+            } else {
+                is_prefix = true;
+            }
+        }
+    }
+
+    match completed {
+        Some(action) => {
+            jump(app, action == SeqAction::GotoTop, details_tx, preview_tx);
+            true
+        }
+        None if is_prefix => {
+            app.pending_key_sequence = typed;
+            app.pending_key_sequence_at = Some(Instant::now());
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// What: Build an app with three results in Search normal mode, selection on the middle item.
+    ///
+    /// Output:
+    /// - `AppState` ready for sequence tests.
+    fn app_with_results() -> AppState {
+        let mut app = AppState {
+            search_normal_mode: true,
+            ..Default::default()
+        };
+        app.results = ["a", "b", "c"]
+            .iter()
+            .map(|n| PackageItem {
+                name: (*n).to_string(),
+                version: "1".into(),
+                description: String::new(),
+                source: crate::state::Source::Aur,
+                popularity: None,
+            })
+            .collect();
+        app.selected = 1;
+        app.list_state.select(Some(1));
+        app
+    }
+
+    #[test]
+    /// What: Ensure `g g` jumps to the top and `G` jumps to the bottom of Results.
+    ///
+    /// Inputs:
+    /// - Two `g` presses, then an uppercase `G` press in Search normal mode.
+    ///
+    /// Output:
+    /// - First `g` is consumed as a prefix without moving; second selects index 0; `G` selects the last.
+    ///
+    /// Details:
+    /// - Uses the default keymap.
+    fn goto_top_and_bottom_sequences() {
+        let mut app = app_with_results();
+        let (dtx, _drx) = mpsc::unbounded_channel();
+        let (ptx, _prx) = mpsc::unbounded_channel();
+        let g = KeyEvent::new(KeyCode::Char('g'), KeyModifiers::empty());
+        assert!(handle_key_sequence(g, &mut app, &dtx, &ptx));
+        assert_eq!(app.selected, 1);
+        assert!(handle_key_sequence(g, &mut app, &dtx, &ptx));
+        assert_eq!(app.selected, 0);
+        assert!(app.pending_key_sequence.is_empty());
+        let big_g = KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT);
+        assert!(handle_key_sequence(big_g, &mut app, &dtx, &ptx));
+        assert_eq!(app.selected, 2);
+    }
+
+    #[test]
+    /// What: Verify a pending prefix followed by an unrelated key falls through to normal handling.
+    ///
+    /// Inputs:
+    /// - `g` followed by `j` in Search normal mode; then `g` in insert mode.
+    ///
+    /// Output:
+    /// - `j` is not consumed and clears the prefix; insert mode never buffers `g`.
+    ///
+    /// Details:
+    /// - Guards vim-style navigation keys from being swallowed by a dangling prefix.
+    fn unmatched_key_after_prefix_falls_through() {
+        let mut app = app_with_results();
+        let (dtx, _drx) = mpsc::unbounded_channel();
+        let (ptx, _prx) = mpsc::unbounded_channel();
+        let g = KeyEvent::new(KeyCode::Char('g'), KeyModifiers::empty());
+        let j = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::empty());
+        assert!(handle_key_sequence(g, &mut app, &dtx, &ptx));
+        assert!(!handle_key_sequence(j, &mut app, &dtx, &ptx));
+        assert!(app.pending_key_sequence.is_empty());
+        assert_eq!(app.selected, 1);
+
+        app.search_normal_mode = false;
+        assert!(!handle_key_sequence(g, &mut app, &dtx, &ptx));
+        assert!(app.pending_key_sequence.is_empty());
+    }
+}
@@ -8,17 +8,24 @@ use tokio::sync::mpsc;
 
 use crate::state::{AppState, Focus, PackageItem, QueryInput};
 
+mod actions;
 mod distro;
 mod global;
 mod install;
+mod keyseq;
 mod modals;
 mod mouse;
+mod palette;
 mod preflight;
 mod recent;
 mod search;
 mod utils;
 
-// re-export intentionally omitted; handled internally
+pub use actions::{Action, palette_matches};
+pub(crate) use utils::{
+    finish_leftovers_scan, finish_orphans, finish_package_files, finish_upgrades_query,
+    finish_view_settings_copy, finish_why_installed, notify_layout_rescaled, open_import_preview,
+};
 
 /// What: Dispatch a single terminal event (keyboard/mouse) and mutate the [`AppState`].
 ///
@@ -38,6 +45,9 @@ mod utils;
 /// - Handles active modal interactions first (Alert/SystemUpdate/ConfirmInstall/ConfirmRemove/Help/News).
 /// - Supports global shortcuts (help overlay, theme reload, exit, PKGBUILD viewer toggle, change sort).
 /// - Delegates pane-specific handling to `search`, `recent`, and `install` submodules.
+/// - Terminal resizes drop stale hit-test geometry via `utils::handle_resize`.
+/// - Handlers close modals through `AppState::close_modal`, which records them in
+///   `app.last_closed_modal` for the reopen keybind.
 pub fn handle_event(
     ev: CEvent,
     app: &mut AppState,
@@ -57,6 +67,11 @@ pub fn handle_event(
             return preflight::handle_preflight_key(ke, app);
         }
 
+        // The command palette runs global actions, so it needs the same channels
+        if matches!(app.modal, crate::state::Modal::CommandPalette { .. }) {
+            return palette::handle_palette_key(ke, app, details_tx, pkgb_tx);
+        }
+
         // Handle all other modals
         if modals::handle_modal_key(ke, app, add_tx) {
             return false;
@@ -76,6 +91,18 @@ pub fn handle_event(
             return false;
         }
 
+        // Multi-key sequences (e.g., `g g`); unmatched keys fall through to the panes
+        if keyseq::handle_key_sequence(ke, app, details_tx, preview_tx) {
+            return false;
+        }
+
+        // Open Preflight straight on a tab from the Search or Install pane
+        if let Some(tab) = preflight_tab_shortcut(&app.keymap, ke)
+            && open_preflight_on_tab(tab, app, query_tx, details_tx, preview_tx, add_tx)
+        {
+            return false;
+        }
+
         // Pane-specific handling (Search, Recent, Install)
         // Recent pane focused
         if matches!(app.focus, Focus::Recent) {
@@ -103,11 +130,106 @@ pub fn handle_event(
 
     // Mouse handling delegated
     if let CEvent::Mouse(m) = ev {
-        return mouse::handle_mouse_event(m, app, details_tx, preview_tx, add_tx, pkgb_tx);
+        return mouse::handle_mouse_event(
+            m, app, query_tx, details_tx, preview_tx, add_tx, pkgb_tx,
+        );
+    }
+    if let CEvent::Resize(..) = ev {
+        utils::handle_resize(app);
     }
     false
 }
 
+/// What: Preflight tab opened by a `preflight_tab_*` keybind, if `ke` is one.
+fn preflight_tab_shortcut(
+    km: &crate::theme::KeyMap,
+    ke: crossterm::event::KeyEvent,
+) -> Option<crate::state::PreflightTab> {
+    let chord = crate::theme::KeyChord {
+        code: ke.code,
+        mods: ke.modifiers,
+    };
+    [
+        (&km.preflight_tab_deps, crate::state::PreflightTab::Deps),
+        (&km.preflight_tab_files, crate::state::PreflightTab::Files),
+        (
+            &km.preflight_tab_services,
+            crate::state::PreflightTab::Services,
+        ),
+    ]
+    .into_iter()
+    .find(|(chords, _)| chords.contains(&chord))
+    .map(|(_, tab)| tab)
+}
+
+/// What: Open Preflight the way the focused pane's install key does, then jump to `tab`.
+///
+/// Inputs:
+/// - `tab`: Tab to show once the modal is open
+/// - `app`: Mutable application state
+/// - Channels: Forwarded to the pane handler that opens the modal
+///
+/// Output:
+/// - `true` when the key was consumed; `false` when the focused pane cannot open Preflight
+///   (e.g. Recent) so the key falls through to it.
+///
+/// Details:
+/// - Search opens Preflight for the selected result, Install for the Install (or, in
+///   installed-only mode, Remove) list; the pane's own Enter handling builds the modal.
+/// - With `skip_preflight` enabled nothing is opened (Enter would install directly); a toast
+///   says so instead.
+/// - Switching tabs goes through [`preflight::switch_preflight_tab`], so the tab's background
+///   resolution is queued as when switching inside the modal.
+fn open_preflight_on_tab(
+    tab: crate::state::PreflightTab,
+    app: &mut AppState,
+    query_tx: &mpsc::UnboundedSender<QueryInput>,
+    details_tx: &mpsc::UnboundedSender<PackageItem>,
+    preview_tx: &mpsc::UnboundedSender<PackageItem>,
+    add_tx: &mpsc::UnboundedSender<PackageItem>,
+) -> bool {
+    let enter = crossterm::event::KeyEvent::new(
+        crossterm::event::KeyCode::Enter,
+        crossterm::event::KeyModifiers::empty(),
+    );
+    let can_open = match app.focus {
+        Focus::Search => !app.results.is_empty(),
+        Focus::Install => {
+            app.pane_find.is_none()
+                && if app.installed_only_mode {
+                    matches!(app.right_pane_focus, crate::state::RightPaneFocus::Remove)
+                        && !app.remove_list.is_empty()
+                } else {
+                    !app.install_list.is_empty()
+                }
+        }
+        Focus::Recent => return false,
+    };
+    if !can_open {
+        return true;
+    }
+    if crate::theme::settings().skip_preflight {
+        app.toast_message = Some(crate::i18n::t(app, "app.toasts.preflight_disabled"));
+        app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+        return true;
+    }
+    if matches!(app.focus, Focus::Search) {
+        let key = if app.search_normal_mode {
+            app.keymap
+                .search_install
+                .first()
+                .map_or(enter, |c| crossterm::event::KeyEvent::new(c.code, c.mods))
+        } else {
+            enter
+        };
+        search::handle_search_key(key, app, query_tx, details_tx, add_tx, preview_tx);
+    } else {
+        install::handle_install_key(enter, app, details_tx, preview_tx, add_tx);
+    }
+    preflight::switch_preflight_tab(app, tab);
+    true
+}
+
 #[cfg(all(test, not(target_os = "windows")))]
 mod tests {
     use super::*;
@@ -440,4 +562,136 @@ mod tests {
         }
         let _ = fs::remove_dir_all(&dir);
     }
+
+    #[test]
+    /// What: Ensure the reopen keybind restores the modal that was just closed.
+    ///
+    /// Inputs:
+    /// - Help modal open, closed with `Esc`, then the default reopen chord (`Ctrl+O`).
+    ///
+    /// Output:
+    /// - Closing records the Help modal; reopening shows it again and clears the record.
+    ///
+    /// Details:
+    /// - A second reopen with nothing recorded leaves the main view untouched.
+    fn reopen_modal_restores_last_closed_modal() {
+        let mut app = AppState {
+            modal: crate::state::Modal::Help,
+            ..Default::default()
+        };
+        let (qtx, _qrx) = mpsc::unbounded_channel();
+        let (dtx, _drx) = mpsc::unbounded_channel();
+        let (ptx, _prx) = mpsc::unbounded_channel();
+        let (atx, _arx) = mpsc::unbounded_channel();
+        let (pkgb_tx, _pkgb_rx) = mpsc::unbounded_channel();
+        let esc = CEvent::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()));
+        let reopen = CEvent::Key(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::CONTROL));
+
+        let _ = handle_event(esc, &mut app, &qtx, &dtx, &ptx, &atx, &pkgb_tx);
+        assert!(matches!(app.modal, crate::state::Modal::None));
+        assert!(matches!(
+            app.last_closed_modal,
+            Some(crate::state::Modal::Help)
+        ));
+
+        let _ = handle_event(reopen.clone(), &mut app, &qtx, &dtx, &ptx, &atx, &pkgb_tx);
+        assert!(matches!(app.modal, crate::state::Modal::Help));
+        assert!(app.last_closed_modal.is_none());
+
+        app.modal = crate::state::Modal::None;
+        let _ = handle_event(reopen, &mut app, &qtx, &dtx, &ptx, &atx, &pkgb_tx);
+        assert!(matches!(app.modal, crate::state::Modal::None));
+    }
+
+    #[test]
+    /// What: Ensure a guarded removal confirmation ignores Enter until the count is typed.
+    ///
+    /// Inputs:
+    /// - `ConfirmRemove` guarded for 12 operations; keys `1`, `3`, `Enter`, `Backspace`, `2`, `Esc`.
+    ///
+    /// Output:
+    /// - Enter with a wrong count keeps the dialog; digits and Backspace edit the typed count;
+    ///   Esc still cancels.
+    fn confirm_remove_guard_requires_typed_count() {
+        let mut app = AppState {
+            modal: crate::state::Modal::ConfirmRemove {
+                items: Vec::new(),
+                guard: crate::state::modal::TypedConfirm::for_count(12, 10),
+            },
+            ..Default::default()
+        };
+        let (qtx, _qrx) = mpsc::unbounded_channel();
+        let (dtx, _drx) = mpsc::unbounded_channel();
+        let (ptx, _prx) = mpsc::unbounded_channel();
+        let (atx, _arx) = mpsc::unbounded_channel();
+        let (pkgb_tx, _pkgb_rx) = mpsc::unbounded_channel();
+        let key = |code| CEvent::Key(KeyEvent::new(code, KeyModifiers::empty()));
+        let typed = |app: &AppState| match &app.modal {
+            crate::state::Modal::ConfirmRemove {
+                guard: Some(guard), ..
+            } => Some((guard.typed.clone(), guard.satisfied())),
+            _ => None,
+        };
+
+        for code in [KeyCode::Char('1'), KeyCode::Char('3'), KeyCode::Enter] {
+            let _ = handle_event(key(code), &mut app, &qtx, &dtx, &ptx, &atx, &pkgb_tx);
+        }
+        assert_eq!(typed(&app), Some(("13".to_string(), false)));
+
+        for code in [KeyCode::Backspace, KeyCode::Char('2')] {
+            let _ = handle_event(key(code), &mut app, &qtx, &dtx, &ptx, &atx, &pkgb_tx);
+        }
+        assert_eq!(typed(&app), Some(("12".to_string(), true)));
+
+        let _ = handle_event(
+            key(KeyCode::Esc),
+            &mut app,
+            &qtx,
+            &dtx,
+            &ptx,
+            &atx,
+            &pkgb_tx,
+        );
+        assert!(matches!(app.modal, crate::state::Modal::None));
+    }
+
+    #[test]
+    /// What: Ensure the refresh-installed chord schedules a cache refresh and debounces repeats.
+    ///
+    /// Inputs:
+    /// - Two presses of the default chord (`Alt+I`), then a press after the debounce elapsed,
+    ///   while a longer post-install polling window is active.
+    ///
+    /// Output:
+    /// - The first press forces an immediate poll; the repeat leaves the request time alone;
+    ///   the longer window is never shortened.
+    fn refresh_installed_chord_is_debounced() {
+        let mut app = AppState::default();
+        let (qtx, _qrx) = mpsc::unbounded_channel();
+        let (dtx, _drx) = mpsc::unbounded_channel();
+        let (ptx, _prx) = mpsc::unbounded_channel();
+        let (atx, _arx) = mpsc::unbounded_channel();
+        let (pkgb_tx, _pkgb_rx) = mpsc::unbounded_channel();
+        let press = || CEvent::Key(KeyEvent::new(KeyCode::Char('i'), KeyModifiers::ALT));
+
+        let _ = handle_event(press(), &mut app, &qtx, &dtx, &ptx, &atx, &pkgb_tx);
+        let first = app
+            .installed_refresh_requested_at
+            .expect("refresh requested");
+        assert!(app.refresh_installed_until.is_some());
+        assert!(app.next_installed_refresh_at.is_none());
+
+        app.next_installed_refresh_at = Some(first);
+        let _ = handle_event(press(), &mut app, &qtx, &dtx, &ptx, &atx, &pkgb_tx);
+        assert_eq!(app.installed_refresh_requested_at, Some(first));
+        assert_eq!(app.next_installed_refresh_at, Some(first));
+
+        let window = std::time::Instant::now() + std::time::Duration::from_secs(12);
+        app.refresh_installed_until = Some(window);
+        app.installed_refresh_requested_at = Some(first - std::time::Duration::from_secs(10));
+        let _ = handle_event(press(), &mut app, &qtx, &dtx, &ptx, &atx, &pkgb_tx);
+        assert!(app.installed_refresh_requested_at > Some(first));
+        assert_eq!(app.refresh_installed_until, Some(window));
+        assert!(app.next_installed_refresh_at.is_none());
+    }
 }
@@ -1,6 +1,6 @@
 //! Modal event handling (excluding Preflight which is in preflight.rs).
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use tokio::sync::mpsc;
 
 use crate::events::distro;
@@ -40,7 +40,7 @@ pub(crate) fn handle_modal_key(
                     if let Some(prev_modal) = app.previous_modal.take() {
                         app.modal = prev_modal;
                     } else {
-                        app.modal = crate::state::Modal::None;
+                        app.close_modal();
                     }
                 }
                 KeyCode::Up if is_help => {
@@ -62,7 +62,7 @@ pub(crate) fn handle_modal_key(
             ..
         } => {
             match ke.code {
-                KeyCode::Esc | KeyCode::Char('q') => app.modal = crate::state::Modal::None,
+                KeyCode::Esc | KeyCode::Char('q') => app.close_modal(),
                 KeyCode::Enter => {
                     // Compute real counts best-effort and show summary
                     let data = crate::logic::compute_post_summary(items);
@@ -98,9 +98,7 @@ pub(crate) fn handle_modal_key(
             snapshot_label: _,
         } => {
             match ke.code {
-                KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
-                    app.modal = crate::state::Modal::None
-                }
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => app.close_modal(),
                 KeyCode::Char('r') => {
                     app.toast_message = Some(crate::i18n::t(app, "app.toasts.rollback"));
                 }
@@ -126,10 +124,16 @@ pub(crate) fn handle_modal_key(
             countries,
             mirror_count,
             cursor,
+            loading,
         } => {
+            // Only Esc applies while upgrades are being listed; it also drops the late result
+            if *loading && ke.code != KeyCode::Esc {
+                return false;
+            }
             match ke.code {
                 KeyCode::Esc => {
-                    app.modal = crate::state::Modal::None;
+                    app.upgrades_query_request = None;
+                    app.close_modal();
                 }
                 KeyCode::Up => {
                     if *cursor > 0 {
@@ -177,38 +181,36 @@ pub(crate) fn handle_modal_key(
                         crate::theme::save_mirror_count(*mirror_count);
                     }
                 }
-                KeyCode::Enter => {
-                    // Build the command lines and run in a terminal
-                    let mut cmds: Vec<String> = Vec::new();
-                    if *do_mirrors {
-                        let sel = if *country_idx < countries.len() {
-                            countries[*country_idx].as_str()
-                        } else {
-                            "Worldwide"
-                        };
-                        // Build distro-aware mirror command via helper using user settings for multi-country and count
-                        let prefs = crate::theme::settings();
-                        let countries_arg = if sel == "Worldwide" {
-                            prefs.selected_countries.as_str()
-                        } else {
-                            sel
-                        };
-                        let count = *mirror_count;
-                        // Persist selection and mirror count to settings.conf
-                        crate::theme::save_selected_countries(countries_arg);
-                        crate::theme::save_mirror_count(count);
-                        cmds.push(distro::mirror_update_command(countries_arg, count));
-                    }
-                    if *do_pacman {
-                        cmds.push("sudo pacman -Syyu --noconfirm".to_string());
-                    }
-                    if *do_aur {
-                        cmds.push("(if command -v paru >/dev/null 2>&1 || sudo pacman -Qi paru >/dev/null 2>&1; then paru -Syyu --noconfirm; elif command -v yay >/dev/null 2>&1 || sudo pacman -Qi yay >/dev/null 2>&1; then yay -Syyu --noconfirm; else echo 'No AUR helper (paru/yay) found.'; echo; echo 'Choose AUR helper to install:'; echo '  1) paru'; echo '  2) yay'; echo '  3) cancel'; read -rp 'Enter 1/2/3: ' choice; case \"$choice\" in 1) rm -rf paru && git clone https://aur.archlinux.org/paru.git && cd paru && makepkg -si ;; 2) rm -rf yay && git clone https://aur.archlinux.org/yay.git && cd yay && makepkg -si ;; *) echo 'Cancelled.'; exit 1 ;; esac; if command -v paru >/dev/null 2>&1 || sudo pacman -Qi paru >/dev/null 2>&1; then paru -Syyu --noconfirm; elif command -v yay >/dev/null 2>&1 || sudo pacman -Qi yay >/dev/null 2>&1; then yay -Syyu --noconfirm; else echo 'AUR helper installation failed or was cancelled.'; exit 1; fi; fi)".to_string());
-                    }
-                    if *do_cache {
-                        cmds.push("sudo pacman -Sc --noconfirm".to_string());
-                        cmds.push("((command -v paru >/dev/null 2>&1 || sudo pacman -Qi paru >/dev/null 2>&1) && paru -Sc --noconfirm) || ((command -v yay >/dev/null 2>&1 || sudo pacman -Qi yay >/dev/null 2>&1) && yay -Sc --noconfirm) || true".to_string());
+                KeyCode::Char('r') | KeyCode::Char('R') => {
+                    if !*do_pacman && !*do_aur {
+                        app.toast_message =
+                            Some(crate::i18n::t(app, "app.toasts.upgrade_review_nothing"));
+                        app.toast_expires_at =
+                            Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+                        return false;
                     }
+                    let cmds = system_update_commands(
+                        *do_mirrors,
+                        *do_pacman,
+                        *do_aur,
+                        *do_cache,
+                        countries.get(*country_idx).map(String::as_str),
+                        *mirror_count,
+                    );
+                    // Listed by the runtime off the UI thread; see `utils::finish_upgrades_query`
+                    *loading = true;
+                    app.upgrades_query_request = Some((*do_pacman, *do_aur, cmds));
+                    return true;
+                }
+                KeyCode::Enter => {
+                    let cmds = system_update_commands(
+                        *do_mirrors,
+                        *do_pacman,
+                        *do_aur,
+                        *do_cache,
+                        countries.get(*country_idx).map(String::as_str),
+                        *mirror_count,
+                    );
                     if cmds.is_empty() {
                         app.modal = crate::state::Modal::Alert {
                             message: "No actions selected".to_string(),
@@ -223,7 +225,7 @@ pub(crate) fn handle_modal_key(
                             cmds
                         };
                         crate::install::spawn_shell_commands_in_terminal(&to_run);
-                        app.modal = crate::state::Modal::None;
+                        app.close_modal();
                         // Return true to stop event propagation and prevent preflight from being triggered
                         return true;
                     }
@@ -232,14 +234,18 @@ pub(crate) fn handle_modal_key(
             }
             return false;
         }
-        crate::state::Modal::ConfirmInstall { items } => {
+        crate::state::Modal::ConfirmInstall { items, guard } => {
+            if type_guard_digit(guard, ke.code) {
+                return false;
+            }
             match ke.code {
                 KeyCode::Esc => {
-                    app.modal = crate::state::Modal::None;
+                    app.close_modal();
                 }
+                KeyCode::Enter if guard.as_ref().is_some_and(|g| !g.satisfied()) => {}
                 KeyCode::Enter => {
                     let list = items.clone();
-                    app.modal = crate::state::Modal::None;
+                    app.close_modal();
                     if list.len() <= 1 {
                         if let Some(it) = list.first() {
                             crate::install::spawn_install(it, None, app.dry_run);
@@ -249,7 +255,9 @@ pub(crate) fn handle_modal_key(
                                     std::time::Instant::now() + std::time::Duration::from_secs(12),
                                 );
                                 app.next_installed_refresh_at = None;
-                                app.pending_install_names = Some(vec![it.name.clone()]);
+                                let names = vec![it.name.clone()];
+                                app.pending_install_baseline = crate::index::install_stamps(&names);
+                                app.pending_install_names = Some(names);
                             }
                         }
                     } else {
@@ -259,8 +267,9 @@ pub(crate) fn handle_modal_key(
                                 std::time::Instant::now() + std::time::Duration::from_secs(12),
                             );
                             app.next_installed_refresh_at = None;
-                            app.pending_install_names =
-                                Some(list.iter().map(|p| p.name.clone()).collect());
+                            let names: Vec<String> = list.iter().map(|p| p.name.clone()).collect();
+                            app.pending_install_baseline = crate::index::install_stamps(&names);
+                            app.pending_install_names = Some(names);
                         }
                     }
                 }
@@ -297,8 +306,12 @@ pub(crate) fn handle_modal_key(
             }
             return false;
         }
-        crate::state::Modal::ConfirmRemove { items } => {
+        crate::state::Modal::ConfirmRemove { items, guard } => {
+            if type_guard_digit(guard, ke.code) {
+                return false;
+            }
             match ke.code {
+                KeyCode::Enter if guard.as_ref().is_some_and(|g| !g.satisfied()) => {}
                 KeyCode::Esc | KeyCode::Enter => {
                     if ke.code == KeyCode::Enter {
                         let names: Vec<String> = items.iter().map(|p| p.name.clone()).collect();
@@ -327,7 +340,7 @@ pub(crate) fn handle_modal_key(
                             app.pending_remove_names = Some(names);
                         }
                     }
-                    app.modal = crate::state::Modal::None;
+                    app.close_modal();
                 }
                 _ => {}
             }
@@ -335,7 +348,7 @@ pub(crate) fn handle_modal_key(
         }
         crate::state::Modal::Help => {
             match ke.code {
-                KeyCode::Esc | KeyCode::Enter => app.modal = crate::state::Modal::None,
+                KeyCode::Esc | KeyCode::Enter => app.close_modal(),
                 _ => {}
             }
             return false;
@@ -363,7 +376,7 @@ pub(crate) fn handle_modal_key(
             }
             match ke.code {
                 KeyCode::Esc => {
-                    app.modal = crate::state::Modal::None;
+                    app.close_modal();
                     return true; // Stop propagation to prevent global Esc handler from running
                 }
                 KeyCode::Up => {
@@ -388,7 +401,7 @@ pub(crate) fn handle_modal_key(
         crate::state::Modal::OptionalDeps { rows, selected } => {
             match ke.code {
                 KeyCode::Esc => {
-                    app.modal = crate::state::Modal::None;
+                    app.close_modal();
                 }
                 KeyCode::Up => {
                     if *selected > 0 {
@@ -464,7 +477,7 @@ pub(crate) fn handle_modal_key(
                                 vec![cmd]
                             };
                             crate::install::spawn_shell_commands_in_terminal(&to_run);
-                            app.modal = crate::state::Modal::None;
+                            app.close_modal();
                             // Return true to stop event propagation and prevent preflight from being triggered
                             return true;
                         } else if !row.installed && row.selectable {
@@ -494,7 +507,7 @@ pub(crate) fn handle_modal_key(
                                 vec![cmd]
                             };
                             crate::install::spawn_shell_commands_in_terminal(&to_run);
-                            app.modal = crate::state::Modal::None;
+                            app.close_modal();
                             // Return true to stop event propagation and prevent preflight from being triggered
                             return true;
                         }
@@ -520,7 +533,7 @@ pub(crate) fn handle_modal_key(
                     if let Some(prev_modal) = app.previous_modal.take() {
                         app.modal = prev_modal;
                     } else {
-                        app.modal = crate::state::Modal::None;
+                        app.close_modal();
                     }
                 }
                 KeyCode::Up => {
@@ -543,10 +556,13 @@ pub(crate) fn handle_modal_key(
                     6 => *do_sleuth = !*do_sleuth,
                     _ => {}
                 },
-                KeyCode::Enter => {
+                KeyCode::Enter | KeyCode::Char('f') => {
+                    // 'f' runs the same scans but ignores cached verdicts
+                    let force_rescan = ke.code == KeyCode::Char('f');
                     tracing::info!(
                         event = "scan_config_confirm",
                         dry_run = app.dry_run,
+                        force_rescan,
                         do_clamav = *do_clamav,
                         do_trivy = *do_trivy,
                         do_semgrep = *do_semgrep,
@@ -569,6 +585,10 @@ pub(crate) fn handle_modal_key(
 
                     // Spawn scans for pending names (set when opening modal)
 
+                    let from_preflight = matches!(
+                        app.previous_modal,
+                        Some(crate::state::Modal::Preflight { .. })
+                    );
                     #[cfg(not(target_os = "windows"))]
                     if let Some(names) = app.pending_install_names.clone() {
                         tracing::info!(
@@ -584,7 +604,7 @@ pub(crate) fn handle_modal_key(
                                 "Dry-run: spawning AUR scan terminal"
                                 );
                                 let msg = format!(
-                                    "echo DRY RUN: AUR scan {} (clamav={} trivy={} semgrep={} shellcheck={} virustotal={} custom={} sleuth={})",
+                                    "echo DRY RUN: AUR scan {} (clamav={} trivy={} semgrep={} shellcheck={} virustotal={} custom={} sleuth={} force_rescan={})",
                                     n,
                                     *do_clamav,
                                     *do_trivy,
@@ -592,7 +612,8 @@ pub(crate) fn handle_modal_key(
                                     *do_shellcheck,
                                     *do_virustotal,
                                     *do_custom,
-                                    *do_sleuth
+                                    *do_sleuth,
+                                    force_rescan
                                 );
                                 crate::install::spawn_shell_commands_in_terminal(&[msg]);
                             }
@@ -608,7 +629,7 @@ pub(crate) fn handle_modal_key(
                                 do_custom = *do_custom,
                                 "Spawning AUR scan terminal"
                                 );
-                                crate::install::spawn_aur_scan_for_with_config(
+                                let progress = crate::install::spawn_aur_scan_for_with_config(
                                     n,
                                     *do_clamav,
                                     *do_trivy,
@@ -617,7 +638,14 @@ pub(crate) fn handle_modal_key(
                                     *do_virustotal,
                                     *do_custom,
                                     *do_sleuth,
+                                    force_rescan,
                                 );
+                                // Scans started from Preflight report into its Sandbox tab and
+                                // stop when it closes (`preflight_cancelled`)
+                                if from_preflight {
+                                    app.scan_progress.retain(|p| p.package != *n);
+                                    app.scan_progress.push(progress);
+                                }
                             }
                         }
                     } else {
@@ -626,7 +654,16 @@ pub(crate) fn handle_modal_key(
                         );
                     }
 
-                    app.modal = crate::state::Modal::None;
+                    // Return to Preflight on the Sandbox tab to follow the scanners
+                    match app.previous_modal.take() {
+                        Some(mut preflight) if from_preflight => {
+                            if let crate::state::Modal::Preflight { tab, .. } = &mut preflight {
+                                *tab = crate::state::PreflightTab::Sandbox;
+                            }
+                            app.modal = preflight;
+                        }
+                        _ => app.close_modal(),
+                    }
                 }
                 _ => {}
             }
@@ -635,7 +672,7 @@ pub(crate) fn handle_modal_key(
         crate::state::Modal::VirusTotalSetup { input, cursor } => {
             match ke.code {
                 KeyCode::Esc => {
-                    app.modal = crate::state::Modal::None;
+                    app.close_modal();
                 }
                 KeyCode::Enter => {
                     let key = input.trim().to_string();
@@ -652,7 +689,7 @@ pub(crate) fn handle_modal_key(
                     // Keep the setup modal open so the user can paste the key after opening the link
                     } else {
                         crate::theme::save_virustotal_api_key(&key);
-                        app.modal = crate::state::Modal::None;
+                        app.close_modal();
                     }
                 }
                 KeyCode::Backspace => {
@@ -707,7 +744,7 @@ pub(crate) fn handle_modal_key(
                     } else {
                         crate::install::spawn_shell_commands_in_terminal(&[cmd]);
                     }
-                    app.modal = crate::state::Modal::None;
+                    app.close_modal();
                 }
 
                 KeyCode::Esc => {
@@ -716,19 +753,471 @@ pub(crate) fn handle_modal_key(
                         Some(crate::i18n::t(app, "app.toasts.gnome_terminal_warning"));
                     app.toast_expires_at =
                         Some(std::time::Instant::now() + std::time::Duration::from_secs(6));
-                    app.modal = crate::state::Modal::None;
+                    app.close_modal();
+                }
+                _ => {}
+            }
+            return false;
+        }
+        crate::state::Modal::DowngradeVersions {
+            package,
+            versions,
+            selected,
+            ..
+        } => {
+            match ke.code {
+                KeyCode::Esc => app.close_modal(),
+                KeyCode::Up | KeyCode::Char('k') => {
+                    *selected = selected.saturating_sub(1);
+                }
+                KeyCode::Down | KeyCode::Char('j') if *selected + 1 < versions.len() => {
+                    *selected += 1;
+                }
+                KeyCode::Enter => {
+                    if let Some(choice) = versions.get(*selected).cloned() {
+                        let package = package.clone();
+                        app.toast_message = Some(crate::i18n::t_fmt(
+                            app,
+                            "app.toasts.downgrade_version_set",
+                            &[&package, &choice.version],
+                        ));
+                        app.toast_expires_at =
+                            Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+                        app.downgrade_targets.insert(package, choice);
+                    }
+                    app.close_modal();
+                }
+                _ => {}
+            }
+            return false;
+        }
+        crate::state::Modal::PackageCompare { .. }
+        | crate::state::Modal::CommandPreview { .. }
+        | crate::state::Modal::WhyInstalled { .. } => {
+            if matches!(ke.code, KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q')) {
+                app.close_modal();
+            }
+            return false;
+        }
+        crate::state::Modal::ProfileSave { input } => {
+            match ke.code {
+                KeyCode::Esc => app.close_modal(),
+                KeyCode::Enter => {
+                    let name = input.clone();
+                    match crate::logic::profiles::save_profile(
+                        &crate::logic::profiles::profiles_dir(),
+                        &name,
+                        &app.install_list,
+                    ) {
+                        Ok(path) => {
+                            app.close_modal();
+                            app.toast_message = Some(crate::i18n::t_fmt1(
+                                app,
+                                "app.toasts.profile_saved",
+                                path.display(),
+                            ));
+                        }
+                        Err(e) => {
+                            // Keep the prompt open so the name can be corrected
+                            app.toast_message = Some(
+                                if crate::logic::profiles::sanitize_profile_name(&name).is_none() {
+                                    crate::i18n::t(app, "app.toasts.profile_name_invalid")
+                                } else {
+                                    crate::i18n::t_fmt1(app, "app.toasts.profile_save_failed", e)
+                                },
+                            );
+                        }
+                    }
+                    app.toast_expires_at =
+                        Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+                }
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char(ch) if !ch.is_control() => input.push(ch),
+                _ => {}
+            }
+            return false;
+        }
+        crate::state::Modal::ThemePicker { names, selected } => {
+            match ke.code {
+                KeyCode::Esc | KeyCode::Char('q') => app.close_modal(),
+                KeyCode::Up | KeyCode::Char('k') => *selected = selected.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => {
+                    *selected = (*selected + 1).min(names.len().saturating_sub(1));
+                }
+                KeyCode::Enter => {
+                    let name = names.get(*selected).cloned().unwrap_or_default();
+                    app.close_modal();
+                    super::utils::apply_theme(app, &name);
+                }
+                _ => {}
+            }
+            return false;
+        }
+        crate::state::Modal::KeybindDiagnostics { issues, scroll } => {
+            match ke.code {
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                    app.close_modal();
+                }
+                KeyCode::Up | KeyCode::Char('k') => *scroll = scroll.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => {
+                    *scroll = (*scroll + 1).min(issues.len().saturating_sub(1));
+                }
+                KeyCode::Char('r') => {
+                    *issues = crate::theme::validate_keybinds();
+                    *scroll = 0;
+                }
+                _ => {}
+            }
+            return false;
+        }
+        crate::state::Modal::ConfigLeftovers {
+            entries,
+            selected,
+            loading,
+        } => {
+            match ke.code {
+                KeyCode::Esc | KeyCode::Char('q') => app.close_modal(),
+                KeyCode::Up | KeyCode::Char('k') => *selected = selected.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => {
+                    *selected = (*selected + 1).min(entries.len().saturating_sub(1));
+                }
+                KeyCode::Char('d') => {
+                    let (entries, selected) = (std::mem::take(entries), *selected);
+                    super::utils::open_config_diff(app, entries, selected);
+                }
+                KeyCode::Char('r') if !*loading => {
+                    // Filled by `utils::finish_leftovers_scan` once the runtime's walk finishes
+                    entries.clear();
+                    *loading = true;
+                    app.leftovers_scan_requested = true;
+                }
+                KeyCode::Enter | KeyCode::Char('e') | KeyCode::Char('x') | KeyCode::Delete => {
+                    let Some(leftover) = entries.get(*selected) else {
+                        return false;
+                    };
+                    let cmd = if matches!(ke.code, KeyCode::Char('x') | KeyCode::Delete) {
+                        crate::logic::pacnew::remove_command(leftover)
+                    } else {
+                        crate::logic::pacnew::merge_command(leftover)
+                    };
+                    let cmds = if app.dry_run {
+                        vec![format!("echo DRY RUN: {cmd}")]
+                    } else {
+                        vec![cmd]
+                    };
+                    crate::install::spawn_shell_commands_in_terminal(&cmds);
                 }
                 _ => {}
             }
             return false;
         }
+        crate::state::Modal::ConfigDiff {
+            leftover,
+            lines,
+            scroll,
+            entries,
+            selected,
+        } => {
+            let max = lines
+                .as_ref()
+                .map_or(0, |l| l.len().saturating_sub(1))
+                .min(u16::MAX as usize) as u16;
+            match ke.code {
+                KeyCode::Esc | KeyCode::Char('q') => {
+                    app.modal = crate::state::Modal::ConfigLeftovers {
+                        entries: std::mem::take(entries),
+                        selected: *selected,
+                        loading: false,
+                    };
+                }
+                KeyCode::Up | KeyCode::Char('k') => *scroll = scroll.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => *scroll = (*scroll + 1).min(max),
+                KeyCode::PageUp => *scroll = scroll.saturating_sub(10),
+                KeyCode::PageDown => *scroll = scroll.saturating_add(10).min(max),
+                KeyCode::Enter | KeyCode::Char('e') => {
+                    let cmd = crate::logic::pacnew::merge_command(leftover);
+                    let cmds = if app.dry_run {
+                        vec![format!("echo DRY RUN: {cmd}")]
+                    } else {
+                        vec![cmd]
+                    };
+                    crate::install::spawn_shell_commands_in_terminal(&cmds);
+                }
+                _ => {}
+            }
+            return false;
+        }
+        crate::state::Modal::ErrorLog { scroll } => {
+            match ke.code {
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                    app.close_modal();
+                }
+                KeyCode::Up | KeyCode::Char('k') => *scroll = scroll.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => {
+                    *scroll = (*scroll + 1).min(app.error_log.len().saturating_sub(1));
+                }
+                KeyCode::Char('c') => {
+                    *scroll = 0;
+                    app.error_log.clear();
+                }
+                _ => {}
+            }
+            return false;
+        }
+        crate::state::Modal::ActivityLog { entries, scroll } => {
+            match ke.code {
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                    app.close_modal();
+                }
+                KeyCode::Up | KeyCode::Char('k') => *scroll = scroll.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => {
+                    *scroll = (*scroll + 1).min(entries.len().saturating_sub(1));
+                }
+                KeyCode::Char('c') => {
+                    *scroll = 0;
+                    entries.clear();
+                    crate::logic::activity::clear();
+                }
+                _ => {}
+            }
+            return false;
+        }
+        crate::state::Modal::PackageFiles {
+            files,
+            filter,
+            scroll,
+            ..
+        } => {
+            let max = crate::logic::files::filter_file_list(files, filter)
+                .len()
+                .saturating_sub(1);
+            match ke.code {
+                KeyCode::Esc => app.close_modal(),
+                KeyCode::Up => *scroll = scroll.saturating_sub(1),
+                KeyCode::Down => *scroll = (*scroll + 1).min(max),
+                KeyCode::PageUp => *scroll = scroll.saturating_sub(10),
+                KeyCode::PageDown => *scroll = (*scroll + 10).min(max),
+                KeyCode::Home => *scroll = 0,
+                KeyCode::End => *scroll = max,
+                // Typing narrows the list; the view jumps back to the first match
+                KeyCode::Backspace => {
+                    filter.pop();
+                    *scroll = 0;
+                }
+                KeyCode::Char(c) if !ke.modifiers.contains(KeyModifiers::CONTROL) => {
+                    filter.push(c);
+                    *scroll = 0;
+                }
+                _ => {}
+            }
+            return false;
+        }
+        crate::state::Modal::LogViewer {
+            lines,
+            min_level,
+            scroll,
+            ..
+        } => {
+            let max = crate::logic::logs::filter_by_level(lines, *min_level)
+                .len()
+                .saturating_sub(1);
+            match ke.code {
+                KeyCode::Esc | KeyCode::Char('q') => app.close_modal(),
+                KeyCode::Up | KeyCode::Char('k') => *scroll = (*scroll + 1).min(max),
+                KeyCode::Down | KeyCode::Char('j') => *scroll = scroll.saturating_sub(1),
+                KeyCode::PageUp => *scroll = (*scroll + 10).min(max),
+                KeyCode::PageDown => *scroll = scroll.saturating_sub(10),
+                KeyCode::Home => *scroll = max,
+                KeyCode::End => *scroll = 0,
+                KeyCode::Char('l') => {
+                    *min_level = crate::logic::logs::next_level_filter(*min_level);
+                    *scroll = 0;
+                }
+                KeyCode::Char('r') => super::utils::open_log_viewer(app),
+                _ => {}
+            }
+            return false;
+        }
+        crate::state::Modal::ProfileLoad { names, selected } => {
+            match ke.code {
+                KeyCode::Esc | KeyCode::Char('q') => app.close_modal(),
+                KeyCode::Up | KeyCode::Char('k') => *selected = selected.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => {
+                    *selected = (*selected + 1).min(names.len().saturating_sub(1));
+                }
+                KeyCode::Enter | KeyCode::Char('a') => {
+                    let append = ke.code == KeyCode::Char('a');
+                    let Some(name) = names.get(*selected).cloned() else {
+                        return false;
+                    };
+                    match crate::logic::profiles::load_profile(
+                        &crate::logic::profiles::profiles_dir(),
+                        &name,
+                    ) {
+                        Ok(items) => {
+                            let diff = crate::logic::lists::diff_against_installed(
+                                &items,
+                                crate::state::PreflightAction::Install,
+                            );
+                            app.modal = crate::state::Modal::ProfileDiff {
+                                name,
+                                items,
+                                append,
+                                diff,
+                                import: false,
+                            };
+                        }
+                        Err(e) => {
+                            app.close_modal();
+                            app.toast_message = Some(crate::i18n::t_fmt(
+                                app,
+                                "app.toasts.profile_load_failed",
+                                &[&name, &e],
+                            ));
+                            app.toast_expires_at =
+                                Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+                        }
+                    }
+                }
+                _ => {}
+            }
+            return false;
+        }
+        crate::state::Modal::ProfileDiff { .. } => {
+            let skip_installed = match ke.code {
+                KeyCode::Enter | KeyCode::Char('y') => false,
+                KeyCode::Char('s') => true,
+                KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('q') => {
+                    app.close_modal();
+                    return false;
+                }
+                _ => return false,
+            };
+            let crate::state::Modal::ProfileDiff {
+                name,
+                items,
+                append,
+                diff,
+                import,
+            } = std::mem::replace(&mut app.modal, crate::state::Modal::None)
+            else {
+                return false;
+            };
+            let items = if skip_installed {
+                items
+                    .into_iter()
+                    .filter(|p| !diff.already_installed.iter().any(|i| i.name == p.name))
+                    .collect()
+            } else {
+                items
+            };
+            let added = crate::logic::lists::load_into_install_list(app, items, append);
+            app.toast_message = Some(if import {
+                crate::i18n::t_fmt1(app, "app.toasts.import_done", added)
+            } else {
+                crate::i18n::t_fmt(app, "app.toasts.profile_loaded", &[&name, &added])
+            });
+            app.toast_expires_at =
+                Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+            return false;
+        }
+        crate::state::Modal::MirrorRankPreview { staged, .. } => {
+            match ke.code {
+                KeyCode::Enter | KeyCode::Char('y') => {
+                    let staged = staged.clone();
+                    app.close_modal();
+                    #[cfg(not(windows))]
+                    {
+                        let cmds = vec![crate::index::install_mirrorlist_command(&staged)];
+                        std::thread::spawn(move || {
+                            crate::install::spawn_shell_commands_in_terminal(&cmds);
+                        });
+                        app.toast_message =
+                            Some(crate::i18n::t(app, "app.toasts.mirror_rank_writing"));
+                    }
+                    #[cfg(windows)]
+                    {
+                        app.toast_message = Some(crate::i18n::t_fmt1(
+                            app,
+                            "app.toasts.mirror_rank_saved",
+                            staged.display(),
+                        ));
+                    }
+                    app.toast_expires_at =
+                        Some(std::time::Instant::now() + std::time::Duration::from_secs(4));
+                }
+                KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('q') => {
+                    app.close_modal();
+                }
+                _ => {}
+            }
+            return false;
+        }
+        crate::state::Modal::ConfirmClearLists { .. } => {
+            match ke.code {
+                KeyCode::Enter | KeyCode::Char('y') => {
+                    app.close_modal();
+                    let cleared = crate::logic::lists::clear_all_lists(app);
+                    app.toast_message = Some(crate::i18n::t_fmt1(
+                        app,
+                        "app.toasts.lists_cleared",
+                        cleared,
+                    ));
+                    app.toast_expires_at =
+                        Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+                }
+                KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('q') => {
+                    app.close_modal();
+                }
+                _ => {}
+            }
+            return false;
+        }
+        crate::state::Modal::GroupExpand { is_package, .. } => {
+            let is_package = *is_package;
+            let choice = match ke.code {
+                KeyCode::Enter | KeyCode::Char('y') if !is_package => Some(true),
+                KeyCode::Char('g') if is_package => Some(true),
+                KeyCode::Char('p') if is_package => Some(false),
+                KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('q') => None,
+                _ => return false,
+            };
+            let crate::state::Modal::GroupExpand {
+                item,
+                members,
+                queued,
+                ..
+            } = std::mem::replace(&mut app.modal, crate::state::Modal::None)
+            else {
+                return false;
+            };
+            match choice {
+                Some(true) => {
+                    for it in crate::logic::lists::group_member_items(app, &item.name, &members) {
+                        let _ = add_tx.send(it);
+                    }
+                }
+                Some(false) => {
+                    app.install_group_as_package
+                        .insert(item.name.to_lowercase());
+                    let _ = add_tx.send(item);
+                }
+                None => {}
+            }
+            // Ask about groups that were added while this prompt was open
+            for q in queued {
+                crate::logic::lists::add_to_install_list(app, q);
+            }
+            return false;
+        }
         crate::state::Modal::ImportHelp => {
             match ke.code {
                 KeyCode::Enter => {
                     tracing::info!("import: Enter pressed in ImportHelp modal");
-                    app.modal = crate::state::Modal::None;
+                    app.close_modal();
                     // Trigger import file picker immediately (executed in background thread)
-                    let add_tx_clone = add_tx.clone();
                     std::thread::spawn(move || {
                         tracing::info!("import: thread started, opening file picker");
                         #[cfg(target_os = "windows")]
@@ -736,7 +1225,7 @@ pub(crate) fn handle_modal_key(
                             let script = r#"
         Add-Type -AssemblyName System.Windows.Forms
         $ofd = New-Object System.Windows.Forms.OpenFileDialog
-        $ofd.Filter = 'Text Files (*.txt)|*.txt|All Files (*.*)|*.*'
+        $ofd.Filter = 'Package lists (*.txt;*.json)|*.txt;*.json|All Files (*.*)|*.*'
         $ofd.Multiselect = $false
         if ($ofd.ShowDialog() -eq [System.Windows.Forms.DialogResult]::OK) { Write-Output $ofd.FileName }
         "#;
@@ -777,12 +1266,12 @@ pub(crate) fn handle_modal_key(
                                 &[
                                     "--file-selection",
                                     "--title=Import packages",
-                                    "--file-filter=*.txt",
+                                    "--file-filter=*.txt *.json",
                                 ],
                             )
                             .or_else(|| {
                                 tracing::debug!("import: zenity failed, trying kdialog");
-                                try_cmd("kdialog", &["--getopenfilename", ".", "*.txt"])
+                                try_cmd("kdialog", &["--getopenfilename", ".", "*.txt *.json"])
                             })
                         };
 
@@ -790,36 +1279,28 @@ pub(crate) fn handle_modal_key(
                             let path = path.trim().to_string();
                             tracing::info!(path = %path, "import: selected file");
                             if let Ok(body) = std::fs::read_to_string(&path) {
-                                use std::collections::HashSet;
-                                let mut official_names: HashSet<String> = HashSet::new();
-                                for it in crate::index::all_official().iter() {
-                                    official_names.insert(it.name.to_lowercase());
-                                }
-                                let mut imported: usize = 0;
-                                for line in body.lines() {
-                                    let name = line.trim();
-                                    if name.is_empty() || name.starts_with('#') {
-                                        continue;
-                                    }
-                                    let src = if official_names.contains(&name.to_lowercase()) {
-                                        crate::state::Source::Official {
-                                            repo: String::new(),
-                                            arch: String::new(),
-                                        }
-                                    } else {
-                                        crate::state::Source::Aur
-                                    };
-                                    let item = crate::state::PackageItem {
-                                        name: name.to_string(),
-                                        version: String::new(),
-                                        description: String::new(),
-                                        source: src,
-                                        popularity: None,
-                                    };
-                                    let _ = add_tx_clone.send(item);
-                                    imported += 1;
-                                }
-                                tracing::info!(path = %path, imported, "import: queued items from list");
+                                use crate::logic::package_list::{
+                                    ImportSummary, ListFormat, parse_package_list,
+                                    post_import_summary, resolve_imported_names,
+                                };
+                                let format = ListFormat::from_path(std::path::Path::new(&path));
+                                let names = parse_package_list(&body, format);
+                                let (items, skipped) = resolve_imported_names(&names);
+                                let diff = crate::logic::lists::diff_against_installed(
+                                    &items,
+                                    crate::state::PreflightAction::Install,
+                                );
+                                let source = std::path::Path::new(&path).file_name().map_or_else(
+                                    || path.clone(),
+                                    |n| n.to_string_lossy().into_owned(),
+                                );
+                                tracing::info!(path = %path, resolved = items.len(), skipped = skipped.len(), "import: resolved items from list");
+                                post_import_summary(ImportSummary {
+                                    source,
+                                    items,
+                                    diff,
+                                    skipped,
+                                });
                             } else {
                                 tracing::warn!(path = %path, "import: failed to read file");
                             }
@@ -828,7 +1309,7 @@ pub(crate) fn handle_modal_key(
                         }
                     });
                 }
-                KeyCode::Esc => app.modal = crate::state::Modal::None,
+                KeyCode::Esc => app.close_modal(),
                 _ => {}
             }
             return false;
@@ -838,6 +1319,90 @@ pub(crate) fn handle_modal_key(
             // Preflight is handled separately in preflight.rs
             return false;
         }
+        crate::state::Modal::CommandPalette { .. } => {
+            // The command palette is handled separately in palette.rs
+            return false;
+        }
     }
     false
 }
+
+/// What: Build the shell commands for the selected System Update options.
+///
+/// Inputs:
+/// - `do_mirrors`/`do_pacman`/`do_aur`/`do_cache`: Dialog toggles.
+/// - `country`: Selected mirror country (`None` or "Worldwide" uses the configured countries).
+/// - `mirror_count`: Number of mirrors to rank.
+///
+/// Output:
+/// - Commands in run order; empty when nothing is selected.
+///
+/// Details:
+/// - Persists the mirror country and count to settings.conf when mirrors are updated.
+/// - Package and cache steps run the configured pacman binary through the configured
+///   privilege tool (`privilege::root_pacman`).
+fn system_update_commands(
+    do_mirrors: bool,
+    do_pacman: bool,
+    do_aur: bool,
+    do_cache: bool,
+    country: Option<&str>,
+    mirror_count: u16,
+) -> Vec<String> {
+    let mut cmds: Vec<String> = Vec::new();
+    if do_mirrors {
+        let sel = country.unwrap_or("Worldwide");
+        // Build distro-aware mirror command via helper using user settings for multi-country and count
+        let prefs = crate::theme::settings();
+        let countries_arg = if sel == "Worldwide" {
+            prefs.selected_countries.as_str()
+        } else {
+            sel
+        };
+        let count = mirror_count;
+        // Persist selection and mirror count to settings.conf
+        crate::theme::save_selected_countries(countries_arg);
+        crate::theme::save_mirror_count(count);
+        cmds.push(distro::mirror_update_command(countries_arg, count));
+    }
+    let root_pacman = crate::install::privilege::root_pacman();
+    let pacman = crate::install::privilege::pacman_bin();
+    if do_pacman {
+        cmds.push(format!("{root_pacman} -Syyu --noconfirm"));
+    }
+    if do_aur {
+        cmds.push(format!("(if command -v paru >/dev/null 2>&1 || {pacman} -Qi paru >/dev/null 2>&1; then paru -Syyu --noconfirm; elif command -v yay >/dev/null 2>&1 || {pacman} -Qi yay >/dev/null 2>&1; then yay -Syyu --noconfirm; else echo 'No AUR helper (paru/yay) found.'; echo; echo 'Choose AUR helper to install:'; echo '  1) paru'; echo '  2) yay'; echo '  3) cancel'; read -rp 'Enter 1/2/3: ' choice; case \"$choice\" in 1) rm -rf paru && git clone https://aur.archlinux.org/paru.git && cd paru && makepkg -si ;; 2) rm -rf yay && git clone https://aur.archlinux.org/yay.git && cd yay && makepkg -si ;; *) echo 'Cancelled.'; exit 1 ;; esac; if command -v paru >/dev/null 2>&1 || {pacman} -Qi paru >/dev/null 2>&1; then paru -Syyu --noconfirm; elif command -v yay >/dev/null 2>&1 || {pacman} -Qi yay >/dev/null 2>&1; then yay -Syyu --noconfirm; else echo 'AUR helper installation failed or was cancelled.'; exit 1; fi; fi)"));
+    }
+    if do_cache {
+        cmds.push(format!("{root_pacman} -Sc --noconfirm"));
+        cmds.push(format!("((command -v paru >/dev/null 2>&1 || {pacman} -Qi paru >/dev/null 2>&1) && paru -Sc --noconfirm) || ((command -v yay >/dev/null 2>&1 || {pacman} -Qi yay >/dev/null 2>&1) && yay -Sc --noconfirm) || true"));
+    }
+    cmds
+}
+
+/// What: Feed a key to the typed-count guard of a confirmation dialog.
+///
+/// Inputs:
+/// - `guard`: Active guard, if the transaction is above `confirm_threshold`.
+/// - `code`: Key pressed.
+///
+/// Output:
+/// - `true` when the key edited the typed digits (digit or Backspace) and was consumed.
+fn type_guard_digit(guard: &mut Option<crate::state::modal::TypedConfirm>, code: KeyCode) -> bool {
+    let Some(guard) = guard else {
+        return false;
+    };
+    match code {
+        KeyCode::Char(c) if c.is_ascii_digit() => {
+            if guard.typed.len() < 20 {
+                guard.typed.push(c);
+            }
+            true
+        }
+        KeyCode::Backspace => {
+            guard.typed.pop();
+            true
+        }
+        _ => false,
+    }
+}
@@ -3,7 +3,7 @@ use crossterm::execute;
 use tokio::sync::mpsc;
 
 use crate::state::modal::ServiceRestartDecision;
-use crate::state::{AppState, PackageItem};
+use crate::state::{AppState, PackageItem, QueryInput};
 
 use super::utils::{refresh_install_details, refresh_selected_details};
 use crate::logic::move_sel_cached;
@@ -25,6 +25,7 @@ use crate::logic::move_sel_cached;
 /// Inputs:
 /// - `m`: Mouse event including position, button, and modifiers
 /// - `app`: Mutable application state (rects, focus, lists, details)
+/// - `query_tx`: Channel to start a search (clicked Provides entries search for providers)
 /// - `details_tx`: Channel to request package details when selection changes
 /// - `preview_tx`: Channel to request preview details for Recent pane interactions
 /// - `_add_tx`: Channel for adding items (used by Import button handler)
@@ -36,7 +37,8 @@ use crate::logic::move_sel_cached;
 /// Details:
 /// - Modal-first: When Help or News is open, clicks/scroll are handled within modal bounds
 ///   (close on outside click), consuming the event.
-/// - Details area: Ctrl+Shift+LeftClick opens URL; PKGBUILD toggle and copy button respond to clicks;
+/// - Details area: Ctrl+Shift+LeftClick opens URL; PKGBUILD toggle, copy button, package page and
+///   Provides entries respond to clicks;
 ///   while text selection is enabled, clicks inside details are ignored by the app.
 /// - Title bar: Sort/options/panels/config buttons toggle menus; filter toggles apply filters.
 /// - Results: Click selects; scroll wheel moves selection and triggers details fetch.
@@ -46,11 +48,15 @@ use crate::logic::move_sel_cached;
 pub fn handle_mouse_event(
     m: MouseEvent,
     app: &mut AppState,
+    query_tx: &mpsc::UnboundedSender<QueryInput>,
     details_tx: &mpsc::UnboundedSender<PackageItem>,
     preview_tx: &mpsc::UnboundedSender<PackageItem>,
     _add_tx: &mpsc::UnboundedSender<PackageItem>,
     pkgb_tx: &mpsc::UnboundedSender<PackageItem>,
 ) -> bool {
+    // Natural scrolling flips the wheel once here so every pane below sees the same direction
+    let m = apply_wheel_direction(m, app.mouse_natural_scroll);
+    let step = app.mouse_scroll_lines.max(1);
     // Ensure mouse capture is enabled (important after external terminal processes)
     crate::util::ensure_mouse_capture();
     if !app.mouse_capture_enabled {
@@ -77,11 +83,11 @@ pub fn handle_mouse_event(
         {
             match m.kind {
                 MouseEventKind::ScrollUp => {
-                    app.help_scroll = app.help_scroll.saturating_sub(1);
+                    app.help_scroll = app.help_scroll.saturating_sub(step);
                     return false;
                 }
                 MouseEventKind::ScrollDown => {
-                    app.help_scroll = app.help_scroll.saturating_add(1);
+                    app.help_scroll = app.help_scroll.saturating_add(step);
                     return false;
                 }
                 _ => {}
@@ -100,11 +106,11 @@ pub fn handle_mouse_event(
                     || my < outer_y
                     || my >= outer_y + outer_h
                 {
-                    app.modal = crate::state::Modal::None;
+                    app.close_modal();
                 }
             } else {
                 // Fallback: close on any click if no rect is known
-                app.modal = crate::state::Modal::None;
+                app.close_modal();
             }
             return false;
         }
@@ -165,13 +171,8 @@ pub fn handle_mouse_event(
                     && my < y + h
                 {
                     // Clicked on tab i - switch to that tab
-                    let new_tab = match i {
-                        0 => crate::state::PreflightTab::Summary,
-                        1 => crate::state::PreflightTab::Deps,
-                        2 => crate::state::PreflightTab::Files,
-                        3 => crate::state::PreflightTab::Services,
-                        4 => crate::state::PreflightTab::Sandbox,
-                        _ => continue,
+                    let Some(new_tab) = super::preflight::PREFLIGHT_TABS.get(i).copied() else {
+                        continue;
                     };
                     *tab = new_tab;
 
@@ -547,7 +548,7 @@ pub fn handle_mouse_event(
                 && (mx < x || mx >= x + w || my < y || my >= y + h)
             {
                 // Click outside closes the modal
-                app.modal = crate::state::Modal::None;
+                app.close_modal();
             }
             return false;
         }
@@ -575,6 +576,62 @@ pub fn handle_mouse_event(
         return false;
     }
 
+    // 0) Pane splitter: drag a middle-row border to resize; the split is saved on release
+    if let Some(border) = app.pane_drag {
+        match m.kind {
+            MouseEventKind::Drag(MouseButton::Left) => {
+                if let Some((x, _, w, _)) = app.middle_rect
+                    && w > 0
+                {
+                    let at_pct =
+                        (u32::from(mx.saturating_sub(x)) * 100 + u32::from(w) / 2) / u32::from(w);
+                    let scale = if app.installed_only_mode {
+                        (3, 2)
+                    } else {
+                        (1, 1)
+                    };
+                    let (left, center, right) = crate::logic::layout::drag_split(
+                        (
+                            app.layout_left_pct,
+                            app.layout_center_pct,
+                            app.layout_right_pct,
+                        ),
+                        border,
+                        at_pct.min(100) as u16,
+                        scale,
+                    );
+                    app.layout_left_pct = left;
+                    app.layout_center_pct = center;
+                    app.layout_right_pct = right;
+                }
+                return false;
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                app.pane_drag = None;
+                crate::theme::save_layout_pcts(
+                    app.layout_left_pct,
+                    app.layout_center_pct,
+                    app.layout_right_pct,
+                );
+                return false;
+            }
+            _ => {}
+        }
+    }
+    if is_left_down
+        && let Some((_, y, _, h)) = app.middle_rect
+        && my >= y
+        && my < y + h
+        && let Some(border) = app
+            .pane_border_cols
+            .iter()
+            .find(|(_, col)| *col == mx)
+            .map(|(border, _)| *border)
+    {
+        app.pane_drag = Some(border);
+        return false;
+    }
+
     // 1) Handle modifier-clicks in details first, even when selection is enabled
     if is_left_down && ctrl && shift {
         // URL click
@@ -607,6 +664,7 @@ pub fn handle_mouse_event(
             app.pkgb_text = None;
             app.pkgb_package_name = None;
             app.pkgb_scroll = 0;
+            app.pkgb_hscroll = 0;
             app.pkgb_rect = None;
         } else {
             // Open and (re)load
@@ -620,6 +678,39 @@ pub fn handle_mouse_event(
         return false;
     }
 
+    // 2a) Click on "Open package page" in Package Info
+    if is_left_down
+        && let Some((x, y, w, h)) = app.package_page_button_rect
+        && mx >= x
+        && mx < x + w
+        && my >= y
+        && my < y + h
+    {
+        app.mouse_disabled_in_details = false;
+        super::utils::open_package_page(app);
+        return false;
+    }
+
+    // 2a') Click on a Provides entry: search for packages providing it (e.g. `cron`)
+    if is_left_down
+        && let Some((spec, _)) = app
+            .provide_button_rects
+            .iter()
+            .find(|(_, (x, y, w, h))| mx >= *x && mx < x + w && my >= *y && my < y + h)
+            .cloned()
+    {
+        app.mouse_disabled_in_details = false;
+        let (name, _) = crate::logic::deps::parse_dep_spec(&spec);
+        app.input = name;
+        app.focus = crate::state::Focus::Search;
+        app.last_input_change = std::time::Instant::now();
+        app.last_saved_value = None;
+        app.search_caret = app.input.chars().count();
+        app.search_select_anchor = None;
+        crate::logic::send_query(app, query_tx);
+        return false;
+    }
+
     // 2b) Click on "Copy PKGBUILD" title button
     if is_left_down
         && let Some((x, y, w, h)) = app.pkgb_check_button_rect
@@ -630,71 +721,54 @@ pub fn handle_mouse_event(
     {
         app.mouse_disabled_in_details = false;
         if let Some(text) = app.pkgb_text.clone() {
-            // Best-effort: Wayland -> wl-copy; X11 -> xclip; otherwise show guidance modal
-            let (tx_msg, rx_msg) = std::sync::mpsc::channel::<Option<String>>();
-            std::thread::spawn(move || {
-                let suffix = {
-                    let s = crate::theme::settings().clipboard_suffix;
-                    if s.trim().is_empty() {
-                        String::new()
-                    } else {
-                        format!("\n\n{s}\n")
-                    }
-                };
-                let payload = if suffix.is_empty() {
-                    text.clone()
-                } else {
-                    format!("{text}{suffix}")
-                };
-                // Try wl-copy on Wayland
-                let tried_wl = if std::env::var("WAYLAND_DISPLAY").is_ok() {
-                    if let Ok(mut child) = std::process::Command::new("wl-copy")
-                        .stdin(std::process::Stdio::piped())
-                        .stdout(std::process::Stdio::null())
-                        .stderr(std::process::Stdio::null())
-                        .spawn()
-                    {
-                        if let Some(mut sin) = child.stdin.take() {
-                            let _ = std::io::Write::write_all(&mut sin, payload.as_bytes());
-                        }
-                        let _ = child.wait();
-                        let _ = tx_msg.send(Some("PKGBUILD is added to the Clipboard".to_string()));
-                        true
-                    } else {
-                        false
-                    }
+            let suffix = {
+                let s = crate::theme::settings().clipboard_suffix;
+                if s.trim().is_empty() {
+                    String::new()
                 } else {
-                    false
-                };
-
-                if tried_wl {
-                    return;
+                    format!("\n\n{s}\n")
                 }
-
-                // Try xclip as a generic fallback on X11
-                if let Ok(mut child) = std::process::Command::new("xclip")
-                    .args(["-selection", "clipboard"]) // send to clipboard selection
-                    .stdin(std::process::Stdio::piped())
-                    .stdout(std::process::Stdio::null())
-                    .stderr(std::process::Stdio::null())
-                    .spawn()
-                {
-                    if let Some(mut sin) = child.stdin.take() {
-                        let _ = std::io::Write::write_all(&mut sin, payload.as_bytes());
+            };
+            let payload = if suffix.is_empty() {
+                text
+            } else {
+                format!("{text}{suffix}")
+            };
+            // Without wl-copy/xclip or OSC 52 the copy would do nothing; save to a file instead
+            if !crate::util::clipboard_available() {
+                let name = app
+                    .pkgb_package_name
+                    .clone()
+                    .unwrap_or_else(|| "unknown".to_string());
+                let msg = match crate::util::save_pkgbuild_fallback(
+                    &crate::theme::logs_dir(),
+                    &name,
+                    &payload,
+                ) {
+                    Ok(path) => crate::i18n::t_fmt1(
+                        app,
+                        "app.toasts.pkgbuild_saved_no_clipboard",
+                        path.display(),
+                    ),
+                    Err(e) => {
+                        let msg = crate::i18n::t_fmt1(app, "app.toasts.pkgbuild_save_failed", e);
+                        crate::logic::errors::record_error(app, "clipboard", msg.clone());
+                        msg
                     }
-                    let _ = child.wait();
-                    let _ = tx_msg.send(Some("PKGBUILD is added to the Clipboard".to_string()));
-                    return;
-                }
-
-                // Neither wl-copy nor xclip worked — report guidance to UI thread
-                let hint = if std::env::var("WAYLAND_DISPLAY").is_ok() {
-                    "Clipboard tool not found. Please install 'wl-clipboard' (provides wl-copy) or 'xclip'.".to_string()
-                } else {
-                    "Clipboard tool not found. Please install 'xclip' or 'wl-clipboard' (wl-copy)."
-                        .to_string()
                 };
-                let _ = tx_msg.send(Some(hint));
+                app.toast_message = Some(msg);
+                app.toast_expires_at =
+                    Some(std::time::Instant::now() + std::time::Duration::from_secs(6));
+                return false;
+            }
+            // Best-effort clipboard copy on a worker; failures surface an install hint
+            let (tx_msg, rx_msg) = std::sync::mpsc::channel::<Option<String>>();
+            std::thread::spawn(move || {
+                let msg = match crate::util::copy_to_clipboard(&payload) {
+                    Ok(()) => "PKGBUILD is added to the Clipboard".to_string(),
+                    Err(hint) => hint,
+                };
+                let _ = tx_msg.send(Some(msg));
             });
             // Default optimistic toast; overwritten by worker if needed
             app.toast_message = Some(crate::i18n::t(app, "app.toasts.copying_pkgbuild"));
@@ -724,6 +798,8 @@ pub fn handle_mouse_event(
     {
         app.mouse_disabled_in_details = false;
         if let Some(item) = app.results.get(app.selected).cloned() {
+            // Explicit reload bypasses the on-disk PKGBUILD cache
+            crate::sources::pkgbuild_cache::invalidate(&item.name);
             // Schedule debounced reload (same as auto-reload)
             app.pkgb_reload_requested_at = Some(std::time::Instant::now());
             app.pkgb_reload_requested_for = Some(item.name.clone());
@@ -742,11 +818,11 @@ pub fn handle_mouse_event(
     {
         match m.kind {
             MouseEventKind::ScrollUp => {
-                app.details_scroll = app.details_scroll.saturating_sub(1);
+                app.details_scroll = app.details_scroll.saturating_sub(step);
                 return false;
             }
             MouseEventKind::ScrollDown => {
-                app.details_scroll = app.details_scroll.saturating_add(1);
+                app.details_scroll = app.details_scroll.saturating_add(step);
                 return false;
             }
             _ => {}
@@ -814,7 +890,10 @@ pub fn handle_mouse_event(
                     break export_dir.join(format!("install_list_{date_str}_fallback.txt"));
                 }
             };
-            let body = names.join("\n");
+            let body = crate::logic::package_list::format_package_list(
+                &names,
+                crate::logic::package_list::ListFormat::Plain,
+            );
             match std::fs::write(&file_path, body) {
                 Ok(_) => {
                     app.toast_message = Some(crate::i18n::t_fmt1(
@@ -828,11 +907,9 @@ pub fn handle_mouse_event(
                 }
                 Err(e) => {
                     let error_msg = format!("{}", e);
-                    app.toast_message = Some(crate::i18n::t_fmt1(
-                        app,
-                        "app.toasts.export_failed",
-                        &error_msg,
-                    ));
+                    let msg = crate::i18n::t_fmt1(app, "app.toasts.export_failed", &error_msg);
+                    crate::logic::errors::record_error(app, "export", msg.clone());
+                    app.toast_message = Some(msg);
                     app.toast_expires_at =
                         Some(std::time::Instant::now() + std::time::Duration::from_secs(5));
                     tracing::error!(error = %e, path = %file_path.display().to_string(), "export: failed to write install list");
@@ -850,6 +927,19 @@ pub fn handle_mouse_event(
             crate::util::open_url("https://status.archlinux.org");
             return false;
         }
+        // Click on the index freshness indicator (requests an official index refresh)
+        if let Some((x, y, w, h)) = app.index_age_rect
+            && mx >= x
+            && mx < x + w
+            && my >= y
+            && my < y + h
+        {
+            app.index_refresh_requested = true;
+            app.toast_message = Some(crate::i18n::t(app, "app.toasts.index_refresh_requested"));
+            app.toast_expires_at =
+                Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+            return false;
+        }
         // Toggle sort menu when clicking the button on the title
         if let Some((x, y, w, h)) = app.sort_button_rect
             && mx >= x
@@ -1170,6 +1260,7 @@ pub fn handle_mouse_event(
                 }
                 _ => {}
             }
+            app.sort_descending = false;
             app.sort_menu_open = false;
             app.sort_menu_auto_close_at = None;
             // Apply sort immediately
@@ -1192,7 +1283,7 @@ pub fn handle_mouse_event(
             && my >= y
             && my < y + h
         {
-            let row = my.saturating_sub(y) as usize; // rows: 0 installed-only toggle, 1 update system, 2 news
+            let row = my.saturating_sub(y) as usize; // rows: 0 installed-only toggle, 1 update system, 2 news, 3 optional deps, 4 search scope, 5 rank mirrors, 6 themes, 7 check keybinds, 8 upgradable only, 9 config leftovers, 10 logs, 11 error history
             match row {
                 0 => {
                     if app.installed_only_mode {
@@ -1206,52 +1297,7 @@ pub fn handle_mouse_event(
                         super::utils::refresh_selected_details(app, details_tx);
                     } else {
                         // Toggle ON: show only explicitly installed leaf packages
-                        app.results_backup_for_toggle = Some(app.all_results.clone());
-                        let explicit = crate::index::explicit_names();
-                        // Official items filtered by explicit set
-                        let mut items: Vec<crate::state::PackageItem> =
-                            crate::index::all_official()
-                                .into_iter()
-                                .filter(|p| explicit.contains(&p.name))
-                                .collect();
-                        // For explicit names that are not in official index, represent as AUR entries
-                        use std::collections::HashSet;
-                        let official_names: HashSet<String> =
-                            items.iter().map(|p| p.name.clone()).collect();
-                        for name in explicit.into_iter() {
-                            if !official_names.contains(&name) {
-                                // If name indicates EOS, classify as official EOS
-                                let is_eos = crate::index::is_eos_name(&name);
-                                let src = if is_eos {
-                                    crate::state::Source::Official {
-                                        repo: "EOS".to_string(),
-                                        arch: String::new(),
-                                    }
-                                } else {
-                                    crate::state::Source::Aur
-                                };
-                                items.push(crate::state::PackageItem {
-                                    name: name.clone(),
-                                    version: String::new(),
-                                    description: String::new(),
-                                    source: src,
-                                    popularity: None,
-                                });
-                            }
-                        }
-                        app.all_results = items;
-                        app.installed_only_mode = true;
-                        app.right_pane_focus = crate::state::RightPaneFocus::Remove;
-                        crate::logic::apply_filters_and_sort_preserve_selection(app);
-                        super::utils::refresh_selected_details(app, details_tx);
-
-                        // Save exported list to config directory as requested
-                        let path = crate::theme::config_dir().join("installed_packages.txt");
-                        let mut names: Vec<String> =
-                            crate::index::explicit_names().into_iter().collect();
-                        names.sort();
-                        let body = names.join("\n");
-                        let _ = std::fs::write(path, body);
+                        super::utils::enter_installed_only_mode(app, details_tx);
                     }
                 }
                 1 => {
@@ -1287,6 +1333,7 @@ pub fn handle_mouse_event(
                         countries,
                         mirror_count: prefs.mirror_count,
                         cursor: 0,
+                        loading: false,
                     };
                 }
                 2 => {
@@ -1623,6 +1670,15 @@ pub fn handle_mouse_event(
                     }
                     app.modal = crate::state::Modal::OptionalDeps { rows, selected: 0 };
                 }
+                4 => super::utils::toggle_search_scope(app, details_tx),
+                5 => super::utils::start_mirror_ranking(app),
+                6 => super::utils::open_theme_picker(app),
+                7 => super::utils::open_keybind_diagnostics(app),
+                8 => super::utils::toggle_upgradable_only(app, details_tx),
+                9 => super::utils::open_config_leftovers(app),
+                10 => super::utils::open_log_viewer(app),
+                11 => super::utils::open_error_log(app),
+                12 => super::utils::open_activity_log(app),
                 _ => {}
             }
             app.options_menu_open = false;
@@ -1636,7 +1692,19 @@ pub fn handle_mouse_event(
             && my >= y
             && my < y + h
         {
-            let row = my.saturating_sub(y) as usize; // rows: 0 settings.conf, 1 theme.conf, 2 keybinds.conf, 3 install_list, 4 installed_list, 5 recent_searches
+            let row = my.saturating_sub(y) as usize; // rows: 0 settings.conf, 1 theme.conf, 2 keybinds.conf, 3 install_list, 4 installed_list, 5 recent_searches, 6 orphans, 7 save profile, 8 load profile
+            if row == 6 {
+                super::utils::add_orphans_to_remove_list(app);
+                app.config_menu_open = false;
+                app.artix_filter_menu_open = false;
+                return false;
+            }
+            if row == 7 || row == 8 {
+                super::utils::open_profiles_modal(app, row == 7);
+                app.config_menu_open = false;
+                app.artix_filter_menu_open = false;
+                return false;
+            }
             // Resolve file paths
             let settings_path = crate::theme::config_dir().join("settings.conf");
             let theme_path = crate::theme::config_dir().join("theme.conf");
@@ -1746,6 +1814,24 @@ pub fn handle_mouse_event(
         }
     }
 
+    // 5a) Results column headers: click sorts by that column, clicking it again flips the order
+    if is_left_down
+        && let Some(mode) = app
+            .results_header_rects
+            .iter()
+            .find(|(_, (x, y, w, h))| mx >= *x && mx < x + w && my >= *y && my < y + h)
+            .map(|(mode, _)| *mode)
+    {
+        crate::logic::sort_by_header(app, mode);
+        crate::theme::save_sort_mode(app.sort_mode);
+        if !app.results.is_empty() {
+            app.selected = 0;
+            app.list_state.select(Some(0));
+            refresh_selected_details(app, details_tx);
+        }
+        return false;
+    }
+
     // 5) Results: click to select
     if is_left_down
         && let Some((x, y, w, h)) = app.results_rect
@@ -1772,10 +1858,10 @@ pub fn handle_mouse_event(
     {
         match m.kind {
             MouseEventKind::ScrollUp => {
-                move_sel_cached(app, -1, details_tx);
+                move_sel_cached(app, -(step as isize), details_tx);
             }
             MouseEventKind::ScrollDown => {
-                move_sel_cached(app, 1, details_tx);
+                move_sel_cached(app, step as isize, details_tx);
             }
             _ => {}
         }
@@ -1956,10 +2042,10 @@ pub fn handle_mouse_event(
     {
         match m.kind {
             MouseEventKind::ScrollUp => {
-                app.pkgb_scroll = app.pkgb_scroll.saturating_sub(1);
+                app.pkgb_scroll = app.pkgb_scroll.saturating_sub(step);
             }
             MouseEventKind::ScrollDown => {
-                app.pkgb_scroll = app.pkgb_scroll.saturating_add(1);
+                app.pkgb_scroll = app.pkgb_scroll.saturating_add(step);
             }
             _ => {}
         }
@@ -1968,10 +2054,143 @@ pub fn handle_mouse_event(
     false
 }
 
+/// What: Apply the natural-scroll preference to a mouse event.
+///
+/// Inputs:
+/// - `m`: Incoming mouse event.
+/// - `natural`: Whether the wheel direction is inverted.
+///
+/// Output:
+/// - The event with `ScrollUp`/`ScrollDown` swapped when `natural` is set; otherwise unchanged.
+fn apply_wheel_direction(mut m: MouseEvent, natural: bool) -> MouseEvent {
+    if natural {
+        m.kind = match m.kind {
+            MouseEventKind::ScrollUp => MouseEventKind::ScrollDown,
+            MouseEventKind::ScrollDown => MouseEventKind::ScrollUp,
+            other => other,
+        };
+    }
+    m
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    /// What: Ensure pressing on a middle-row border and dragging resizes the neighbouring panes.
+    ///
+    /// Inputs:
+    /// - A 100-column middle row with the Recent/Search border at column 20; press there, drag to 30.
+    ///
+    /// Output:
+    /// - The drag starts on the border and the split becomes 30/50/20 (still summing to 100).
+    fn drag_middle_border_resizes_panes() {
+        let mut app = AppState {
+            middle_rect: Some((0, 10, 100, 5)),
+            pane_border_cols: vec![(crate::logic::layout::PaneBorder::LeftCenter, 20)],
+            ..Default::default()
+        };
+        let (qtx, _qrx) = mpsc::unbounded_channel::<QueryInput>();
+        let (dtx, _drx) = mpsc::unbounded_channel::<PackageItem>();
+        let (ptx, _prx) = mpsc::unbounded_channel::<PackageItem>();
+        let (atx, _arx) = mpsc::unbounded_channel::<PackageItem>();
+        let (ktx, _krx) = mpsc::unbounded_channel::<PackageItem>();
+        let ev = |kind, column| MouseEvent {
+            kind,
+            column,
+            row: 12,
+            modifiers: KeyModifiers::empty(),
+        };
+        handle_mouse_event(
+            ev(MouseEventKind::Down(MouseButton::Left), 20),
+            &mut app,
+            &qtx,
+            &dtx,
+            &ptx,
+            &atx,
+            &ktx,
+        );
+        assert_eq!(
+            app.pane_drag,
+            Some(crate::logic::layout::PaneBorder::LeftCenter)
+        );
+        handle_mouse_event(
+            ev(MouseEventKind::Drag(MouseButton::Left), 30),
+            &mut app,
+            &qtx,
+            &dtx,
+            &ptx,
+            &atx,
+            &ktx,
+        );
+        assert_eq!(
+            (
+                app.layout_left_pct,
+                app.layout_center_pct,
+                app.layout_right_pct
+            ),
+            (30, 50, 20)
+        );
+    }
+
+    #[test]
+    /// What: Ensure the wheel step and natural-scroll settings drive Package Info scrolling.
+    ///
+    /// Inputs:
+    /// - `mouse_scroll_lines = 3` with and without `mouse_natural_scroll`, wheel events in `details_rect`.
+    ///
+    /// Output:
+    /// - ScrollDown moves 3 lines down; with natural scroll the same event moves back up and stops at 0.
+    fn wheel_step_and_natural_direction_apply_to_details() {
+        let mut app = AppState {
+            details_rect: Some((0, 0, 40, 10)),
+            mouse_scroll_lines: 3,
+            ..Default::default()
+        };
+        let (qtx, _qrx) = mpsc::unbounded_channel::<QueryInput>();
+        let (dtx, _drx) = mpsc::unbounded_channel::<PackageItem>();
+        let (ptx, _prx) = mpsc::unbounded_channel::<PackageItem>();
+        let (atx, _arx) = mpsc::unbounded_channel::<PackageItem>();
+        let (ktx, _krx) = mpsc::unbounded_channel::<PackageItem>();
+        let wheel = |kind| MouseEvent {
+            kind,
+            column: 5,
+            row: 5,
+            modifiers: KeyModifiers::empty(),
+        };
+        handle_mouse_event(
+            wheel(MouseEventKind::ScrollDown),
+            &mut app,
+            &qtx,
+            &dtx,
+            &ptx,
+            &atx,
+            &ktx,
+        );
+        assert_eq!(app.details_scroll, 3);
+        app.mouse_natural_scroll = true;
+        handle_mouse_event(
+            wheel(MouseEventKind::ScrollDown),
+            &mut app,
+            &qtx,
+            &dtx,
+            &ptx,
+            &atx,
+            &ktx,
+        );
+        handle_mouse_event(
+            wheel(MouseEventKind::ScrollDown),
+            &mut app,
+            &qtx,
+            &dtx,
+            &ptx,
+            &atx,
+            &ktx,
+        );
+        assert_eq!(app.details_scroll, 0);
+    }
+
     /// What: Provide a fresh `AppState` tailored for mouse-event tests without repeated boilerplate.
     ///
     /// Inputs:
@@ -2011,6 +2230,7 @@ mod tests {
         }];
         app.selected = 0;
         app.pkgb_button_rect = Some((10, 10, 5, 1));
+        let (qtx, _qrx) = mpsc::unbounded_channel::<QueryInput>();
         let (dtx, _drx) = mpsc::unbounded_channel::<PackageItem>();
         let (ptx, _prx) = mpsc::unbounded_channel::<PackageItem>();
         let (atx, _arx) = mpsc::unbounded_channel::<PackageItem>();
@@ -2021,7 +2241,7 @@ mod tests {
             row: 10,
             modifiers: KeyModifiers::empty(),
         };
-        let _ = handle_mouse_event(ev, &mut app, &dtx, &ptx, &atx, &pkgb_tx);
+        let _ = handle_mouse_event(ev, &mut app, &qtx, &dtx, &ptx, &atx, &pkgb_tx);
         assert!(app.pkgb_visible);
         assert!(pkgb_rx.try_recv().ok().is_some());
     }
@@ -2055,6 +2275,7 @@ mod tests {
         app.pkgb_scroll = 7;
         app.pkgb_rect = Some((50, 50, 20, 5));
 
+        let (qtx, _qrx) = mpsc::unbounded_channel::<QueryInput>();
         let (dtx, _drx) = mpsc::unbounded_channel::<PackageItem>();
         let (ptx, _prx) = mpsc::unbounded_channel::<PackageItem>();
         let (atx, _arx) = mpsc::unbounded_channel::<PackageItem>();
@@ -2066,11 +2287,39 @@ mod tests {
             row: 10,
             modifiers: KeyModifiers::empty(),
         };
-        let _ = handle_mouse_event(ev, &mut app, &dtx, &ptx, &atx, &pkgb_tx);
+        let _ = handle_mouse_event(ev, &mut app, &qtx, &dtx, &ptx, &atx, &pkgb_tx);
 
         assert!(!app.pkgb_visible);
         assert!(app.pkgb_text.is_none());
         assert_eq!(app.pkgb_scroll, 0);
         assert!(app.pkgb_rect.is_none());
     }
+
+    #[test]
+    /// What: Clicking a Provides entry should search for its providers.
+    ///
+    /// Inputs:
+    /// - `provide_button_rects` holding a versioned `cron=1.0` entry and a left click inside it.
+    ///
+    /// Output:
+    /// - The query becomes the bare `cron`, Search is focused, and a query is sent.
+    fn click_provide_searches_providers() {
+        let mut app = new_app();
+        app.provide_button_rects = vec![("cron=1.0".into(), (20, 8, 8, 1))];
+        let (qtx, mut qrx) = mpsc::unbounded_channel::<QueryInput>();
+        let (dtx, _drx) = mpsc::unbounded_channel::<PackageItem>();
+        let (ptx, _prx) = mpsc::unbounded_channel::<PackageItem>();
+        let (atx, _arx) = mpsc::unbounded_channel::<PackageItem>();
+        let (pkgb_tx, _pkgb_rx) = mpsc::unbounded_channel::<PackageItem>();
+        let ev = MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 23,
+            row: 8,
+            modifiers: KeyModifiers::empty(),
+        };
+        let _ = handle_mouse_event(ev, &mut app, &qtx, &dtx, &ptx, &atx, &pkgb_tx);
+        assert_eq!(app.input, "cron");
+        assert!(matches!(app.focus, crate::state::Focus::Search));
+        assert_eq!(qrx.try_recv().map(|q| q.text).ok().as_deref(), Some("cron"));
+    }
 }
@@ -0,0 +1,125 @@
+//! Key handling for the command palette modal.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use tokio::sync::mpsc;
+
+use super::actions::palette_matches;
+use crate::state::{AppState, Modal, PackageItem};
+
+/// What: Handle a key while the command palette is open.
+///
+/// Inputs:
+/// - `ke`: Key event
+/// - `app`: Mutable application state (holds `Modal::CommandPalette`)
+/// - `details_tx`: Channel used to request package detail refreshes
+/// - `pkgb_tx`: Channel used to request PKGBUILD content
+///
+/// Output:
+/// - `true` when the chosen action asks the application to exit; otherwise `false`.
+///
+/// Details:
+/// - Typing filters, Up/Down move, Enter closes the palette and runs the highlighted action,
+///   Esc closes. Greyed-out actions are not run; a toast says why instead.
+pub(super) fn handle_palette_key(
+    ke: KeyEvent,
+    app: &mut AppState,
+    details_tx: &mpsc::UnboundedSender<PackageItem>,
+    pkgb_tx: &mpsc::UnboundedSender<PackageItem>,
+) -> bool {
+    let count = match &app.modal {
+        Modal::CommandPalette { query, .. } => palette_matches(app, query).len(),
+        _ => return false,
+    };
+    let Modal::CommandPalette { query, selected } = &mut app.modal else {
+        return false;
+    };
+    match ke.code {
+        KeyCode::Esc => app.close_modal(),
+        KeyCode::Up => *selected = selected.saturating_sub(1),
+        KeyCode::Down => *selected = (*selected + 1).min(count.saturating_sub(1)),
+        KeyCode::Backspace => {
+            query.pop();
+            *selected = 0;
+        }
+        KeyCode::Char(ch)
+            if !ke
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+        {
+            query.push(ch);
+            *selected = 0;
+        }
+        KeyCode::Enter => {
+            let query = query.clone();
+            let selected = *selected;
+            let matches = palette_matches(app, &query);
+            let Some(action) = matches.get(selected).copied() else {
+                return false;
+            };
+            if !action.is_available(app) {
+                app.toast_message = Some(crate::i18n::t_fmt1(
+                    app,
+                    "app.toasts.action_unavailable",
+                    action.label(app),
+                ));
+                app.toast_expires_at =
+                    Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+                return false;
+            }
+            app.close_modal();
+            return super::global::run_action(action, app, details_tx, pkgb_tx);
+        }
+        _ => {}
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure typing filters the palette and Enter runs the highlighted action.
+    ///
+    /// Inputs:
+    /// - An open palette; the query typed key by key, then Enter.
+    ///
+    /// Output:
+    /// - The query accumulates, the selection resets, and Enter on the Help action closes the
+    ///   palette and opens Help.
+    fn palette_typing_and_enter_run_action() {
+        let mut app = AppState {
+            modal: Modal::CommandPalette {
+                query: String::new(),
+                selected: 3,
+            },
+            ..Default::default()
+        };
+        let (dtx, _drx) = mpsc::unbounded_channel();
+        let (ptx, _prx) = mpsc::unbounded_channel();
+        let key = |c| KeyEvent::new(c, KeyModifiers::empty());
+        let query = crate::events::Action::Help.label(&app);
+        for ch in query.chars() {
+            assert!(!handle_palette_key(
+                key(KeyCode::Char(ch)),
+                &mut app,
+                &dtx,
+                &ptx
+            ));
+        }
+        match &app.modal {
+            Modal::CommandPalette { query: q, selected } => {
+                assert_eq!(q, &query);
+                assert_eq!(*selected, 0);
+            }
+            other => panic!("palette closed early: {other:?}"),
+        }
+        assert!(!handle_palette_key(
+            key(KeyCode::Enter),
+            &mut app,
+            &dtx,
+            &ptx
+        ));
+        assert!(matches!(app.modal, Modal::Help));
+    }
+}
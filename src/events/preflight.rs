@@ -149,6 +149,188 @@ pub(crate) fn build_file_display_items(
     display_items
 }
 
+/// Preflight tabs in display order; number keys 1–5 and `preflight_tab_rects` follow it.
+pub(crate) const PREFLIGHT_TABS: [crate::state::PreflightTab; 5] = [
+    crate::state::PreflightTab::Summary,
+    crate::state::PreflightTab::Deps,
+    crate::state::PreflightTab::Files,
+    crate::state::PreflightTab::Services,
+    crate::state::PreflightTab::Sandbox,
+];
+
+/// What: Show `new_tab` in the open Preflight modal and load or request its data.
+///
+/// Inputs:
+/// - `app`: Mutable application state holding `Modal::Preflight`
+/// - `new_tab`: Tab to switch to
+///
+/// Output:
+/// - Updates the modal's tab; no-op when Preflight is not open.
+///
+/// Details:
+/// - Empty Deps/Files/Sandbox tabs take matching entries from the install-list caches, or queue
+///   background resolution through the corresponding `preflight_*_items` field.
+/// - Services are taken from the cache when its signature matches; otherwise `r` resolves them.
+pub(crate) fn switch_preflight_tab(app: &mut AppState, new_tab: crate::state::PreflightTab) {
+    let crate::state::Modal::Preflight {
+        tab,
+        items,
+        action,
+        dependency_info,
+        dep_selected,
+        file_info,
+        file_selected,
+        service_info,
+        service_selected,
+        services_loaded,
+        sandbox_info,
+        sandbox_selected,
+        sandbox_loaded,
+        ..
+    } = &mut app.modal
+    else {
+        return;
+    };
+    *tab = new_tab;
+
+    // Check for cached dependencies when switching to Deps tab
+    // Auto-resolve if cache is empty
+    if *tab == crate::state::PreflightTab::Deps && dependency_info.is_empty() {
+        match *action {
+            crate::state::PreflightAction::Install => {
+                // Try to use cached dependencies from app state
+                let item_names: std::collections::HashSet<String> =
+                    items.iter().map(|i| i.name.clone()).collect();
+                let cached_deps: Vec<crate::state::modal::DependencyInfo> = app
+                    .install_list_deps
+                    .iter()
+                    .filter(|dep| {
+                        dep.required_by
+                            .iter()
+                            .any(|req_by| item_names.contains(req_by))
+                    })
+                    .cloned()
+                    .collect();
+                if !cached_deps.is_empty() {
+                    tracing::debug!(
+                        "[Preflight] Using {} cached dependencies when switching to Deps tab",
+                        cached_deps.len()
+                    );
+                    *dependency_info = cached_deps;
+                    *dep_selected = 0;
+                } else {
+                    // No cached deps - trigger background resolution
+                    tracing::debug!(
+                        "[Preflight] Triggering background dependency resolution for {} packages",
+                        items.len()
+                    );
+                    app.preflight_deps_items = Some(items.to_vec());
+                    app.preflight_deps_resolving = true;
+                    // Resolution will happen in background, UI will show loading state
+                    // Results will be synced to preflight modal when they arrive
+                }
+                app.remove_preflight_summary.clear();
+            }
+            crate::state::PreflightAction::Remove => {
+                // For remove action, reverse deps are computed on-demand
+                // User can press 'r' to resolve if needed
+            }
+        }
+    }
+    // Check for cached files when switching to Files tab
+    if *tab == crate::state::PreflightTab::Files && file_info.is_empty() {
+        // Try to use cached files from app state
+        let item_names: std::collections::HashSet<String> =
+            items.iter().map(|i| i.name.clone()).collect();
+        let cached_files: Vec<crate::state::modal::PackageFileInfo> = app
+            .install_list_files
+            .iter()
+            .filter(|file_info| item_names.contains(&file_info.name))
+            .cloned()
+            .collect();
+        if !cached_files.is_empty() {
+            *file_info = cached_files;
+            *file_selected = 0;
+        } else {
+            // No cached files - trigger background resolution
+            tracing::debug!(
+                "[Preflight] Triggering background file resolution for {} packages",
+                items.len()
+            );
+            app.preflight_files_items = Some(items.to_vec());
+            app.preflight_files_resolving = true;
+            // Resolution will happen in background, UI will show loading state
+            // Results will be synced to preflight modal when they arrive
+        }
+    }
+    // Check for cached services when switching to Services tab
+    if *tab == crate::state::PreflightTab::Services && service_info.is_empty() {
+        // Try to use cached services from app state (for install actions)
+        if matches!(*action, crate::state::PreflightAction::Install) && !app.services_resolving {
+            // Check if cache file exists with matching signature
+            let cache_exists = if !items.is_empty() {
+                let signature = crate::app::services_cache::compute_signature(items);
+                crate::app::services_cache::load_cache(&app.services_cache_path, &signature)
+                    .is_some()
+            } else {
+                false
+            };
+            if cache_exists && !app.install_list_services.is_empty() {
+                *service_info = app.install_list_services.clone();
+                *service_selected = 0;
+                *services_loaded = true;
+            }
+        }
+        // If no cached services, user can press 'r' to resolve
+    }
+    // Check for cached sandbox when switching to Sandbox tab
+    if *tab == crate::state::PreflightTab::Sandbox && sandbox_info.is_empty() && !*sandbox_loaded {
+        match *action {
+            crate::state::PreflightAction::Install => {
+                // Try to use cached sandbox from app state
+                let item_names: std::collections::HashSet<String> =
+                    items.iter().map(|i| i.name.clone()).collect();
+                let cached_sandbox: Vec<crate::logic::sandbox::SandboxInfo> = app
+                    .install_list_sandbox
+                    .iter()
+                    .filter(|s| item_names.contains(&s.package_name))
+                    .cloned()
+                    .collect();
+                if !cached_sandbox.is_empty() {
+                    *sandbox_info = cached_sandbox;
+                    *sandbox_selected = 0;
+                    *sandbox_loaded = true;
+                } else {
+                    // No cached sandbox - trigger background resolution
+                    // Only resolve for AUR packages
+                    let aur_items: Vec<_> = items
+                        .iter()
+                        .filter(|p| matches!(p.source, crate::state::Source::Aur))
+                        .cloned()
+                        .collect();
+                    if !aur_items.is_empty() {
+                        tracing::debug!(
+                            "[Preflight] Triggering background sandbox resolution for {} AUR packages",
+                            aur_items.len()
+                        );
+                        app.preflight_sandbox_items = Some(aur_items);
+                        app.preflight_sandbox_resolving = true;
+                        // Resolution will happen in background, UI will show loading state
+                        // Results will be synced to preflight modal when they arrive
+                    } else {
+                        // No AUR packages, mark as loaded
+                        *sandbox_loaded = true;
+                    }
+                }
+            }
+            crate::state::PreflightAction::Remove => {
+                // Sandbox is only for install actions
+                *sandbox_loaded = true;
+            }
+        }
+    }
+}
+
 /// What: Handle key events while the Preflight modal is active (install/remove workflows).
 ///
 /// Inputs:
@@ -159,6 +341,7 @@ pub(crate) fn build_file_display_items(
 /// - Always returns `false` so the outer event loop continues processing.
 ///
 /// Details:
+/// - Left/Right/Tab cycle tabs and number keys 1–5 jump to one (see [`switch_preflight_tab`]).
 /// - Supports tab switching, tree expansion, dependency/file navigation, scans, dry-run toggles, and
 ///   command execution across install/remove flows.
 /// - Mutates `app.modal` (and related cached fields) to close the modal, open nested dialogs, or
@@ -205,7 +388,8 @@ pub(crate) fn handle_preflight_key(ke: KeyEvent, app: &mut AppState) -> bool {
                 app.preflight_files_items = None;
                 app.preflight_services_items = None;
                 app.preflight_sandbox_items = None;
-                app.modal = crate::state::Modal::None;
+                app.pending_system_update = None;
+                app.close_modal();
             }
             KeyCode::Enter => {
                 // In Deps tab, Enter toggles expand/collapse; in Files tab, Enter toggles expand/collapse; otherwise closes modal
@@ -265,7 +449,7 @@ pub(crate) fn handle_preflight_key(ke: KeyEvent, app: &mut AppState) -> bool {
                     // Build display items list: (is_header, package_name, Option<(dep_type, dep_name)>)
                     type SandboxDisplayItem = (bool, String, Option<(&'static str, String)>);
                     let mut display_items: Vec<SandboxDisplayItem> = Vec::new();
-                    for item in items.iter() {
+                    for item in crate::logic::sandbox::rank_by_risk(items, sandbox_info) {
                         let is_aur = matches!(item.source, crate::state::Source::Aur);
                         display_items.push((true, item.name.clone(), None));
                         if is_aur
@@ -368,387 +552,34 @@ pub(crate) fn handle_preflight_key(ke: KeyEvent, app: &mut AppState) -> bool {
                     app.preflight_files_items = None;
                     app.preflight_services_items = None;
                     app.preflight_sandbox_items = None;
-                    app.modal = crate::state::Modal::None;
+                    app.pending_system_update = None;
+                    app.close_modal();
                 }
             }
             KeyCode::Left => {
-                *tab = match tab {
+                let prev = match tab {
                     crate::state::PreflightTab::Summary => crate::state::PreflightTab::Services,
                     crate::state::PreflightTab::Deps => crate::state::PreflightTab::Summary,
                     crate::state::PreflightTab::Files => crate::state::PreflightTab::Deps,
                     crate::state::PreflightTab::Services => crate::state::PreflightTab::Files,
                     crate::state::PreflightTab::Sandbox => crate::state::PreflightTab::Services,
                 };
-                // Check for cached dependencies when switching to Deps tab
-                // Auto-resolve if cache is empty
-                if *tab == crate::state::PreflightTab::Deps && dependency_info.is_empty() {
-                    match *action {
-                        crate::state::PreflightAction::Install => {
-                            // Try to use cached dependencies from app state
-                            let item_names: std::collections::HashSet<String> =
-                                items.iter().map(|i| i.name.clone()).collect();
-                            let cached_deps: Vec<crate::state::modal::DependencyInfo> = app
-                                .install_list_deps
-                                .iter()
-                                .filter(|dep| {
-                                    dep.required_by
-                                        .iter()
-                                        .any(|req_by| item_names.contains(req_by))
-                                })
-                                .cloned()
-                                .collect();
-                            if !cached_deps.is_empty() {
-                                *dependency_info = cached_deps;
-                                *dep_selected = 0;
-                            } else {
-                                // No cached deps - trigger background resolution
-                                tracing::debug!(
-                                    "[Preflight] Triggering background dependency resolution for {} packages",
-                                    items.len()
-                                );
-                                app.preflight_deps_items = Some(items.to_vec());
-                                app.preflight_deps_resolving = true;
-                                // Resolution will happen in background, UI will show loading state
-                                // Results will be synced to preflight modal when they arrive
-                            }
-                            app.remove_preflight_summary.clear();
-                        }
-                        crate::state::PreflightAction::Remove => {
-                            // For remove action, reverse deps are computed on-demand
-                            // User can press 'r' to resolve if needed
-                        }
-                    }
-                }
-                // Check for cached files when switching to Files tab
-                if *tab == crate::state::PreflightTab::Files && file_info.is_empty() {
-                    // Try to use cached files from app state
-                    let item_names: std::collections::HashSet<String> =
-                        items.iter().map(|i| i.name.clone()).collect();
-                    let cached_files: Vec<crate::state::modal::PackageFileInfo> = app
-                        .install_list_files
-                        .iter()
-                        .filter(|file_info| item_names.contains(&file_info.name))
-                        .cloned()
-                        .collect();
-                    if !cached_files.is_empty() {
-                        *file_info = cached_files;
-                        *file_selected = 0;
-                    } else {
-                        // No cached files - trigger background resolution
-                        tracing::debug!(
-                            "[Preflight] Triggering background file resolution for {} packages",
-                            items.len()
-                        );
-                        app.preflight_files_items = Some(items.to_vec());
-                        app.preflight_files_resolving = true;
-                        // Resolution will happen in background, UI will show loading state
-                        // Results will be synced to preflight modal when they arrive
-                    }
-                }
-                // Services tab resolution happens in render function for better responsiveness
+                switch_preflight_tab(app, prev);
             }
-            KeyCode::Right => {
-                *tab = match tab {
+            KeyCode::Right | KeyCode::Tab => {
+                // Tab cycles forward like Right
+                let next = match tab {
                     crate::state::PreflightTab::Summary => crate::state::PreflightTab::Deps,
                     crate::state::PreflightTab::Deps => crate::state::PreflightTab::Files,
                     crate::state::PreflightTab::Files => crate::state::PreflightTab::Services,
                     crate::state::PreflightTab::Services => crate::state::PreflightTab::Sandbox,
                     crate::state::PreflightTab::Sandbox => crate::state::PreflightTab::Summary,
                 };
-                // Check for cached dependencies when switching to Deps tab
-                // Auto-resolve if cache is empty
-                if *tab == crate::state::PreflightTab::Deps && dependency_info.is_empty() {
-                    match *action {
-                        crate::state::PreflightAction::Install => {
-                            // Try to use cached dependencies from app state
-                            let item_names: std::collections::HashSet<String> =
-                                items.iter().map(|i| i.name.clone()).collect();
-                            let cached_deps: Vec<crate::state::modal::DependencyInfo> = app
-                                .install_list_deps
-                                .iter()
-                                .filter(|dep| {
-                                    dep.required_by
-                                        .iter()
-                                        .any(|req_by| item_names.contains(req_by))
-                                })
-                                .cloned()
-                                .collect();
-                            if !cached_deps.is_empty() {
-                                tracing::debug!(
-                                    "[Preflight] Using {} cached dependencies when switching to Deps tab",
-                                    cached_deps.len()
-                                );
-                                *dependency_info = cached_deps;
-                                *dep_selected = 0;
-                            } else {
-                                // No cached deps - trigger background resolution
-                                tracing::debug!(
-                                    "[Preflight] Triggering background dependency resolution for {} packages",
-                                    items.len()
-                                );
-                                app.preflight_deps_items = Some(items.to_vec());
-                                app.preflight_deps_resolving = true;
-                                // Resolution will happen in background, UI will show loading state
-                                // Results will be synced to preflight modal when they arrive
-                            }
-                            app.remove_preflight_summary.clear();
-                        }
-                        crate::state::PreflightAction::Remove => {
-                            // For remove action, reverse deps are computed on-demand
-                            // User can press 'r' to resolve if needed
-                        }
-                    }
-                }
-                // Check for cached files when switching to Files tab
-                if *tab == crate::state::PreflightTab::Files && file_info.is_empty() {
-                    // Try to use cached files from app state
-                    let item_names: std::collections::HashSet<String> =
-                        items.iter().map(|i| i.name.clone()).collect();
-                    let cached_files: Vec<crate::state::modal::PackageFileInfo> = app
-                        .install_list_files
-                        .iter()
-                        .filter(|file_info| item_names.contains(&file_info.name))
-                        .cloned()
-                        .collect();
-                    if !cached_files.is_empty() {
-                        *file_info = cached_files;
-                        *file_selected = 0;
-                    } else {
-                        // No cached files - trigger background resolution
-                        tracing::debug!(
-                            "[Preflight] Triggering background file resolution for {} packages",
-                            items.len()
-                        );
-                        app.preflight_files_items = Some(items.to_vec());
-                        app.preflight_files_resolving = true;
-                        // Resolution will happen in background, UI will show loading state
-                        // Results will be synced to preflight modal when they arrive
-                    }
-                }
-                // Check for cached services when switching to Services tab
-                if *tab == crate::state::PreflightTab::Services && service_info.is_empty() {
-                    // Try to use cached services from app state (for install actions)
-                    if matches!(*action, crate::state::PreflightAction::Install)
-                        && !app.services_resolving
-                    {
-                        // Check if cache file exists with matching signature
-                        let cache_exists = if !items.is_empty() {
-                            let signature = crate::app::services_cache::compute_signature(items);
-                            crate::app::services_cache::load_cache(
-                                &app.services_cache_path,
-                                &signature,
-                            )
-                            .is_some()
-                        } else {
-                            false
-                        };
-                        if cache_exists && !app.install_list_services.is_empty() {
-                            *service_info = app.install_list_services.clone();
-                            *service_selected = 0;
-                            *services_loaded = true;
-                        }
-                    }
-                    // If no cached services, user can press 'r' to resolve
-                }
-                // Check for cached sandbox when switching to Sandbox tab
-                if *tab == crate::state::PreflightTab::Sandbox
-                    && sandbox_info.is_empty()
-                    && !*sandbox_loaded
-                {
-                    match *action {
-                        crate::state::PreflightAction::Install => {
-                            // Try to use cached sandbox from app state
-                            let item_names: std::collections::HashSet<String> =
-                                items.iter().map(|i| i.name.clone()).collect();
-                            let cached_sandbox: Vec<crate::logic::sandbox::SandboxInfo> = app
-                                .install_list_sandbox
-                                .iter()
-                                .filter(|s| item_names.contains(&s.package_name))
-                                .cloned()
-                                .collect();
-                            if !cached_sandbox.is_empty() {
-                                *sandbox_info = cached_sandbox;
-                                *sandbox_selected = 0;
-                                *sandbox_loaded = true;
-                            } else {
-                                // No cached sandbox - trigger background resolution
-                                // Only resolve for AUR packages
-                                let aur_items: Vec<_> = items
-                                    .iter()
-                                    .filter(|p| matches!(p.source, crate::state::Source::Aur))
-                                    .cloned()
-                                    .collect();
-                                if !aur_items.is_empty() {
-                                    tracing::debug!(
-                                        "[Preflight] Triggering background sandbox resolution for {} AUR packages",
-                                        aur_items.len()
-                                    );
-                                    app.preflight_sandbox_items = Some(aur_items);
-                                    app.preflight_sandbox_resolving = true;
-                                    // Resolution will happen in background, UI will show loading state
-                                    // Results will be synced to preflight modal when they arrive
-                                } else {
-                                    // No AUR packages, mark as loaded
-                                    *sandbox_loaded = true;
-                                }
-                            }
-                        }
-                        crate::state::PreflightAction::Remove => {
-                            // Sandbox is only for install actions
-                            *sandbox_loaded = true;
-                        }
-                    }
-                }
+                switch_preflight_tab(app, next);
             }
-            KeyCode::Tab => {
-                // Cycle forward through tabs (same as Right)
-                *tab = match tab {
-                    crate::state::PreflightTab::Summary => crate::state::PreflightTab::Deps,
-                    crate::state::PreflightTab::Deps => crate::state::PreflightTab::Files,
-                    crate::state::PreflightTab::Files => crate::state::PreflightTab::Services,
-                    crate::state::PreflightTab::Services => crate::state::PreflightTab::Sandbox,
-                    crate::state::PreflightTab::Sandbox => crate::state::PreflightTab::Summary,
-                };
-                // Check for cached dependencies when switching to Deps tab
-                // Auto-resolve if cache is empty
-                if *tab == crate::state::PreflightTab::Deps && dependency_info.is_empty() {
-                    match *action {
-                        crate::state::PreflightAction::Install => {
-                            // Try to use cached dependencies from app state
-                            let item_names: std::collections::HashSet<String> =
-                                items.iter().map(|i| i.name.clone()).collect();
-                            let cached_deps: Vec<crate::state::modal::DependencyInfo> = app
-                                .install_list_deps
-                                .iter()
-                                .filter(|dep| {
-                                    dep.required_by
-                                        .iter()
-                                        .any(|req_by| item_names.contains(req_by))
-                                })
-                                .cloned()
-                                .collect();
-                            if !cached_deps.is_empty() {
-                                *dependency_info = cached_deps;
-                                *dep_selected = 0;
-                            } else {
-                                // No cached deps - trigger background resolution
-                                tracing::debug!(
-                                    "[Preflight] Triggering background dependency resolution for {} packages",
-                                    items.len()
-                                );
-                                app.preflight_deps_items = Some(items.to_vec());
-                                app.preflight_deps_resolving = true;
-                                // Resolution will happen in background, UI will show loading state
-                                // Results will be synced to preflight modal when they arrive
-                            }
-                            app.remove_preflight_summary.clear();
-                        }
-                        crate::state::PreflightAction::Remove => {
-                            // For remove action, reverse deps are computed on-demand
-                            // User can press 'r' to resolve if needed
-                        }
-                    }
-                }
-                // Check for cached files when switching to Files tab
-                if *tab == crate::state::PreflightTab::Files && file_info.is_empty() {
-                    // Try to use cached files from app state
-                    let item_names: std::collections::HashSet<String> =
-                        items.iter().map(|i| i.name.clone()).collect();
-                    let cached_files: Vec<crate::state::modal::PackageFileInfo> = app
-                        .install_list_files
-                        .iter()
-                        .filter(|file_info| item_names.contains(&file_info.name))
-                        .cloned()
-                        .collect();
-                    if !cached_files.is_empty() {
-                        *file_info = cached_files;
-                        *file_selected = 0;
-                    } else {
-                        // No cached files - trigger background resolution
-                        tracing::debug!(
-                            "[Preflight] Triggering background file resolution for {} packages",
-                            items.len()
-                        );
-                        app.preflight_files_items = Some(items.to_vec());
-                        app.preflight_files_resolving = true;
-                        // Resolution will happen in background, UI will show loading state
-                        // Results will be synced to preflight modal when they arrive
-                    }
-                }
-                // Check for cached services when switching to Services tab
-                if *tab == crate::state::PreflightTab::Services && service_info.is_empty() {
-                    // Try to use cached services from app state (for install actions)
-                    if matches!(*action, crate::state::PreflightAction::Install)
-                        && !app.services_resolving
-                    {
-                        // Check if cache file exists with matching signature
-                        let cache_exists = if !items.is_empty() {
-                            let signature = crate::app::services_cache::compute_signature(items);
-                            crate::app::services_cache::load_cache(
-                                &app.services_cache_path,
-                                &signature,
-                            )
-                            .is_some()
-                        } else {
-                            false
-                        };
-                        if cache_exists && !app.install_list_services.is_empty() {
-                            *service_info = app.install_list_services.clone();
-                            *service_selected = 0;
-                            *services_loaded = true;
-                        }
-                    }
-                    // If no cached services, user can press 'r' to resolve
-                }
-                // Check for cached sandbox when switching to Sandbox tab
-                if *tab == crate::state::PreflightTab::Sandbox
-                    && sandbox_info.is_empty()
-                    && !*sandbox_loaded
-                {
-                    match *action {
-                        crate::state::PreflightAction::Install => {
-                            // Try to use cached sandbox from app state
-                            let item_names: std::collections::HashSet<String> =
-                                items.iter().map(|i| i.name.clone()).collect();
-                            let cached_sandbox: Vec<crate::logic::sandbox::SandboxInfo> = app
-                                .install_list_sandbox
-                                .iter()
-                                .filter(|s| item_names.contains(&s.package_name))
-                                .cloned()
-                                .collect();
-                            if !cached_sandbox.is_empty() {
-                                *sandbox_info = cached_sandbox;
-                                *sandbox_selected = 0;
-                                *sandbox_loaded = true;
-                            } else {
-                                // No cached sandbox - trigger background resolution
-                                // Only resolve for AUR packages
-                                let aur_items: Vec<_> = items
-                                    .iter()
-                                    .filter(|p| matches!(p.source, crate::state::Source::Aur))
-                                    .cloned()
-                                    .collect();
-                                if !aur_items.is_empty() {
-                                    tracing::debug!(
-                                        "[Preflight] Triggering background sandbox resolution for {} AUR packages",
-                                        aur_items.len()
-                                    );
-                                    app.preflight_sandbox_items = Some(aur_items);
-                                    app.preflight_sandbox_resolving = true;
-                                    // Resolution will happen in background, UI will show loading state
-                                    // Results will be synced to preflight modal when they arrive
-                                } else {
-                                    // No AUR packages, mark as loaded
-                                    *sandbox_loaded = true;
-                                }
-                            }
-                        }
-                        crate::state::PreflightAction::Remove => {
-                            // Sandbox is only for install actions
-                            *sandbox_loaded = true;
-                        }
-                    }
-                }
+            KeyCode::Char(c @ '1'..='5') => {
+                // Number keys jump straight to a tab, in the order shown in the tab bar
+                switch_preflight_tab(app, PREFLIGHT_TABS[(c as u8 - b'1') as usize]);
             }
             KeyCode::Up => {
                 if *tab == crate::state::PreflightTab::Deps && !items.is_empty() {
@@ -886,7 +717,7 @@ pub(crate) fn handle_preflight_key(ke: KeyEvent, app: &mut AppState) -> bool {
                     // Build display items list: (is_header, package_name, Option<(dep_type, dep_name)>)
                     type SandboxDisplayItem = (bool, String, Option<(&'static str, String)>);
                     let mut display_items: Vec<SandboxDisplayItem> = Vec::new();
-                    for item in items.iter() {
+                    for item in crate::logic::sandbox::rank_by_risk(items, sandbox_info) {
                         let is_aur = matches!(item.source, crate::state::Source::Aur);
                         display_items.push((true, item.name.clone(), None));
                         if is_aur
@@ -1128,44 +959,15 @@ pub(crate) fn handle_preflight_key(ke: KeyEvent, app: &mut AppState) -> bool {
             KeyCode::Char('f') | KeyCode::Char('F') => {
                 // File database sync (Files tab only)
                 if *tab == crate::state::PreflightTab::Files {
-                    // Use the new ensure_file_db_synced function with force=true
-                    // This will attempt to sync regardless of timestamp
-                    let sync_result = crate::logic::files::ensure_file_db_synced(true, 7);
-                    match sync_result {
-                        Ok(synced) => {
-                            if synced {
-                                app.toast_message = Some(
-                                    "File database sync completed. Files tab will refresh."
-                                        .to_string(),
-                                );
-                            } else {
-                                app.toast_message =
-                                    Some("File database is already fresh.".to_string());
-                            }
-                            app.toast_expires_at =
-                                Some(std::time::Instant::now() + std::time::Duration::from_secs(5));
-                            // Clear file_info to trigger re-resolution after sync completes
-                            *file_info = Vec::new();
-                            *file_selected = 0;
-                        }
-                        Err(e) => {
-                            // Sync failed (likely requires root), launch terminal with sudo
-                            let sync_cmd = "sudo pacman -Fy".to_string();
-                            let cmds = vec![sync_cmd];
-                            std::thread::spawn(move || {
-                                crate::install::spawn_shell_commands_in_terminal(&cmds);
-                            });
-                            app.toast_message = Some(format!(
-                                "File database sync started in terminal (requires root). Error: {}",
-                                e
-                            ));
-                            app.toast_expires_at =
-                                Some(std::time::Instant::now() + std::time::Duration::from_secs(5));
-                            // Clear file_info to trigger re-resolution after sync completes
-                            *file_info = Vec::new();
-                            *file_selected = 0;
-                        }
-                    }
+                    // Runs in the background; the Files tab shows progress and re-resolves when done
+                    let key = if crate::logic::filedb::start_sync() {
+                        "app.toasts.file_db_sync_started"
+                    } else {
+                        "app.toasts.file_db_sync_running"
+                    };
+                    app.toast_message = Some(crate::i18n::t(app, key));
+                    app.toast_expires_at =
+                        Some(std::time::Instant::now() + std::time::Duration::from_secs(5));
                     return false;
                 }
             }
@@ -1230,6 +1032,7 @@ pub(crate) fn handle_preflight_key(ke: KeyEvent, app: &mut AppState) -> bool {
                 let mut removal_names: Option<Vec<String>> = None;
                 let mut removal_mode: Option<crate::state::modal::CascadeMode> = None;
                 let mut install_targets: Option<Vec<PackageItem>> = None;
+                let mut typed_confirm: Option<crate::state::Modal> = None;
 
                 match *action {
                     crate::state::PreflightAction::Install => {
@@ -1261,7 +1064,16 @@ pub(crate) fn handle_preflight_key(ke: KeyEvent, app: &mut AppState) -> bool {
                     app.pending_service_plan.clear();
                 }
 
-                if let Some(mut packages) = install_targets {
+                if let Some(cmds) = app.pending_system_update.take() {
+                    // Reviewing a system upgrade: run the System Update commands, not per-package installs
+                    let to_run: Vec<String> = if app.dry_run {
+                        cmds.iter().map(|c| format!("echo DRY RUN: {c}")).collect()
+                    } else {
+                        cmds
+                    };
+                    crate::install::spawn_shell_commands_in_terminal(&to_run);
+                    close_modal = true;
+                } else if let Some(mut packages) = install_targets {
                     // Add selected optional dependencies as additional packages to install
                     for (_pkg_name, optdeps) in selected_optdepends.iter() {
                         for optdep in optdeps {
@@ -1285,11 +1097,37 @@ pub(crate) fn handle_preflight_key(ke: KeyEvent, app: &mut AppState) -> bool {
                             }
                         }
                     }
-                    crate::install::spawn_install_all(&packages, app.dry_run);
+                    if let Some(guard) = super::utils::confirm_guard(
+                        app,
+                        crate::state::PreflightAction::Install,
+                        packages.len(),
+                    ) {
+                        // Large transaction: hand over to the typed-count confirmation
+                        typed_confirm = Some(crate::state::Modal::ConfirmInstall {
+                            items: packages,
+                            guard: Some(guard),
+                        });
+                    } else {
+                        crate::install::spawn_install_all(&packages, app.dry_run);
+                    }
                     close_modal = true;
                 } else if let Some(names) = removal_names {
                     let mode = removal_mode.unwrap_or(*cascade_mode);
-                    crate::install::spawn_remove_all(&names, app.dry_run, mode);
+                    let remove_items = items.clone();
+                    if let Some(guard) = super::utils::confirm_guard(
+                        app,
+                        crate::state::PreflightAction::Remove,
+                        names.len(),
+                    ) {
+                        // The confirmation removes with the preflight's cascade mode
+                        app.remove_cascade_mode = mode;
+                        typed_confirm = Some(crate::state::Modal::ConfirmRemove {
+                            items: remove_items,
+                            guard: Some(guard),
+                        });
+                    } else {
+                        crate::install::spawn_remove_all(&names, app.dry_run, mode);
+                    }
                     close_modal = true;
                 } else if let Some(count) = blocked_dep_count {
                     let root_list: Vec<String> = app
@@ -1321,7 +1159,11 @@ pub(crate) fn handle_preflight_key(ke: KeyEvent, app: &mut AppState) -> bool {
                     app.preflight_files_items = None;
                     app.preflight_services_items = None;
                     app.preflight_sandbox_items = None;
-                    app.modal = crate::state::Modal::None;
+                    app.pending_system_update = None;
+                    match typed_confirm {
+                        Some(confirm) => app.modal = confirm,
+                        None => app.close_modal(),
+                    }
                 }
             }
             KeyCode::Char('c') => {
@@ -1344,7 +1186,8 @@ pub(crate) fn handle_preflight_key(ke: KeyEvent, app: &mut AppState) -> bool {
                 app.preflight_files_items = None;
                 app.preflight_services_items = None;
                 app.preflight_sandbox_items = None;
-                app.modal = crate::state::Modal::None;
+                app.pending_system_update = None;
+                app.close_modal();
             }
             KeyCode::Char('?') => {
                 // Show Deps tab help when on Deps tab, otherwise show general Preflight help
@@ -1509,6 +1352,8 @@ mod tests {
             unit_name: unit.into(),
             providers: vec!["target".into()],
             is_active: true,
+            active_state: String::new(),
+            enabled_state: String::new(),
             needs_restart: true,
             recommended_decision: ServiceRestartDecision::Restart,
             restart_decision: decision,
@@ -1962,6 +1807,47 @@ mod tests {
         }
     }
 
+    #[test]
+    /// What: Verify that number keys jump to tabs and queue resolution for empty tabs.
+    ///
+    /// Inputs:
+    /// - Preflight on Summary with no cached data; keys `3`, `2`, then `1`.
+    ///
+    /// Output:
+    /// - `3` shows Files and queues file resolution, `2` shows Deps and queues dependency
+    ///   resolution, `1` returns to Summary.
+    ///
+    /// Details:
+    /// - Mirrors the order of `preflight_tab_rects` via `PREFLIGHT_TABS`.
+    fn number_keys_jump_to_tabs_and_queue_resolution() {
+        let mut app = setup_preflight_app(PreflightTab::Summary, Vec::new(), 0, HashSet::new());
+        let current = |app: &AppState| match &app.modal {
+            Modal::Preflight { tab, .. } => *tab,
+            _ => panic!("expected Preflight modal"),
+        };
+
+        handle_preflight_key(
+            KeyEvent::new(KeyCode::Char('3'), KeyModifiers::empty()),
+            &mut app,
+        );
+        assert_eq!(current(&app), PreflightTab::Files);
+        assert!(app.preflight_files_resolving);
+        assert_eq!(app.preflight_files_items.as_ref().map(Vec::len), Some(1));
+
+        handle_preflight_key(
+            KeyEvent::new(KeyCode::Char('2'), KeyModifiers::empty()),
+            &mut app,
+        );
+        assert_eq!(current(&app), PreflightTab::Deps);
+        assert!(app.preflight_deps_items.is_some());
+
+        handle_preflight_key(
+            KeyEvent::new(KeyCode::Char('1'), KeyModifiers::empty()),
+            &mut app,
+        );
+        assert_eq!(current(&app), PreflightTab::Summary);
+    }
+
     #[test]
     /// What: Verify that cached files are correctly loaded when switching to Files tab.
     ///
@@ -2234,4 +2120,20 @@ mod tests {
             panic!("expected Preflight modal");
         }
     }
+
+    #[test]
+    /// What: Ensure closing a system-upgrade review drops its pending update commands.
+    ///
+    /// Inputs:
+    /// - A Preflight opened with `pending_system_update` set, then Esc.
+    ///
+    /// Output:
+    /// - The modal closes and no update commands linger for the next Preflight.
+    fn esc_discards_pending_system_update() {
+        let mut app = setup_preflight_app(PreflightTab::Summary, Vec::new(), 0, HashSet::new());
+        app.pending_system_update = Some(vec!["sudo pacman -Syyu --noconfirm".into()]);
+        handle_preflight_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()), &mut app);
+        assert!(matches!(app.modal, Modal::None));
+        assert!(app.pending_system_update.is_none());
+    }
 }
@@ -1,7 +1,7 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use tokio::sync::mpsc;
 
-use crate::logic::{move_sel_cached, send_query};
+use crate::logic::{flush_query, move_sel_cached, send_query};
 use crate::state::{AppState, PackageItem, QueryInput};
 
 use super::utils::{byte_index_for_char, char_count, refresh_install_details};
@@ -27,6 +27,11 @@ use super::utils::{byte_index_for_char, char_count, refresh_install_details};
 /// - Pane navigation: Left/Right and configured `pane_next` cycle focus across panes and subpanes,
 ///   differing slightly when installed-only mode is active.
 /// - PKGBUILD reload is handled via debounced requests scheduled in the selection logic.
+/// - Up/Down recall past queries when the caret is at the start of the input (see
+///   `recall_history`); otherwise Up/Down move the Results selection in insert mode.
+/// - Multi-select mode (default Alt+M) makes Space mark results instead of adding them; the
+///   add-selected key (default Alt+L) adds every marked result at once.
+/// - Enter first runs a query still waiting out `search_debounce_ms` (see `flush_query`).
 pub fn handle_search_key(
     ke: KeyEvent,
     app: &mut AppState,
@@ -74,6 +79,16 @@ pub fn handle_search_key(
         return false;
     }
 
+    // Multi-select: toggle marking mode / add every marked result (both modes)
+    if matches_any(&km.search_multi_select) {
+        toggle_multi_select(app);
+        return false;
+    }
+    if matches_any(&km.search_add_selected) {
+        add_marked_results(app, add_tx, details_tx);
+        return false;
+    }
+
     // Normal mode: Vim-like navigation without editing input
     if app.search_normal_mode {
         // If any dropdown is open, allow numeric selection 1..9 here as a fallback
@@ -82,8 +97,18 @@ pub fn handle_search_key(
             && ch != '0'
         {
             let idx = (ch as u8 - b'1') as usize;
-            // Config/Lists menu numeric selection (rows 0..5)
+            // Config/Lists menu numeric selection (rows 0..8)
             if app.config_menu_open {
+                if idx == 6 {
+                    super::utils::add_orphans_to_remove_list(app);
+                    app.config_menu_open = false;
+                    return false;
+                }
+                if idx == 7 || idx == 8 {
+                    super::utils::open_profiles_modal(app, idx == 7);
+                    app.config_menu_open = false;
+                    return false;
+                }
                 let settings_path = crate::theme::config_dir().join("settings.conf");
                 let theme_path = crate::theme::config_dir().join("theme.conf");
                 let keybinds_path = crate::theme::config_dir().join("keybinds.conf");
@@ -202,7 +227,10 @@ pub fn handle_search_key(
                                     .join(format!("install_list_{date_str}_fallback.txt"));
                             }
                         };
-                        let body = names.join("\n");
+                        let body = crate::logic::package_list::format_package_list(
+                            &names,
+                            crate::logic::package_list::ListFormat::Plain,
+                        );
                         match std::fs::write(&file_path, body) {
                             Ok(_) => {
                                 app.toast_message = Some(crate::i18n::t_fmt1(
@@ -217,11 +245,13 @@ pub fn handle_search_key(
                             }
                             Err(e) => {
                                 let error_msg = format!("{}", e);
-                                app.toast_message = Some(crate::i18n::t_fmt1(
+                                let msg = crate::i18n::t_fmt1(
                                     app,
                                     "app.toasts.export_failed",
                                     &error_msg,
-                                ));
+                                );
+                                crate::logic::errors::record_error(app, "export", msg.clone());
+                                app.toast_message = Some(msg);
                                 app.toast_expires_at = Some(
                                     std::time::Instant::now() + std::time::Duration::from_secs(5),
                                 );
@@ -295,6 +325,12 @@ pub fn handle_search_key(
                     send_query(app, query_tx);
                 }
             }
+            (KeyCode::Up, _) => {
+                recall_history(app, true, query_tx);
+            }
+            (KeyCode::Down, _) => {
+                recall_history(app, false, query_tx);
+            }
             (KeyCode::Char('j'), _) => move_sel_cached(app, 1, details_tx),
             (KeyCode::Char('k'), _) => move_sel_cached(app, -1, details_tx),
             (KeyCode::Char('d'), KeyModifiers::CONTROL) => move_sel_cached(app, 10, details_tx),
@@ -308,6 +344,7 @@ pub fn handle_search_key(
                     super::utils::refresh_downgrade_details(app, details_tx);
                 }
             }
+            (KeyCode::Char(' '), _) if app.multi_select_mode => toggle_result_mark(app),
             (KeyCode::Char(' '), _) => {
                 if let Some(item) = app.results.get(app.selected).cloned() {
                     if app.installed_only_mode {
@@ -426,6 +463,7 @@ pub fn handle_search_key(
             }
             // Fallback on raw Enter
             (KeyCode::Char('\n') | KeyCode::Enter, _) => {
+                flush_query(app, query_tx);
                 if let Some(item) = app.results.get(app.selected).cloned() {
                     if crate::theme::settings().skip_preflight {
                         crate::install::spawn_install_all(std::slice::from_ref(&item), app.dry_run);
@@ -632,6 +670,7 @@ pub fn handle_search_key(
                 super::utils::refresh_downgrade_details(app, details_tx);
             }
         }
+        (KeyCode::Char(' '), _) if app.multi_select_mode => toggle_result_mark(app),
         (KeyCode::Char(' '), _) => {
             if let Some(item) = app.results.get(app.selected).cloned() {
                 if app.installed_only_mode {
@@ -652,6 +691,7 @@ pub fn handle_search_key(
             send_query(app, query_tx);
         }
         (KeyCode::Char('\n') | KeyCode::Enter, _) => {
+            flush_query(app, query_tx);
             if let Some(item) = app.results.get(app.selected).cloned() {
                 if crate::theme::settings().skip_preflight {
                     crate::install::spawn_install_all(std::slice::from_ref(&item), app.dry_run);
@@ -715,8 +755,13 @@ pub fn handle_search_key(
             app.search_select_anchor = None;
             send_query(app, query_tx);
         }
-        (KeyCode::Up, _) => move_sel_cached(app, -1, details_tx),
-        (KeyCode::Down, _) => move_sel_cached(app, 1, details_tx),
+        // Recall runs in the guard; when it declines, the arrow moves the selection instead
+        (KeyCode::Up, _) if !recall_history(app, true, query_tx) => {
+            move_sel_cached(app, -1, details_tx);
+        }
+        (KeyCode::Down, _) if !recall_history(app, false, query_tx) => {
+            move_sel_cached(app, 1, details_tx);
+        }
         (KeyCode::PageUp, _) => move_sel_cached(app, -10, details_tx),
         (KeyCode::PageDown, _) => move_sel_cached(app, 10, details_tx),
         _ => {}
@@ -724,6 +769,154 @@ pub fn handle_search_key(
     false
 }
 
+/// What: Shell-style Up/Down recall of past queries (`app.recent`) in the Search input.
+///
+/// Inputs:
+/// - `app`: Mutable application state (input, caret, selection, recent queries)
+/// - `older`: `true` for Up (previous query), `false` for Down (next query)
+/// - `query_tx`: Channel to send the recalled query
+///
+/// Output:
+/// - `true` when the key was used for history; `false` when the caller should handle it
+///   (e.g. move the Results selection).
+///
+/// Details:
+/// - Up starts recalling only with the caret at the start of the input and no selection
+///   active; once recalling, Up/Down keep stepping while the input still equals the recalled
+///   entry. Editing a recalled query ends the walk, so the edit becomes a new entry.
+/// - The query being typed when recall starts is stashed and restored by stepping Down past
+///   the newest entry.
+/// - Recalled queries are marked as saved so they are not moved to the front of `recent`
+///   while the user walks through it.
+fn recall_history(
+    app: &mut AppState,
+    older: bool,
+    query_tx: &mpsc::UnboundedSender<QueryInput>,
+) -> bool {
+    let navigating = app
+        .search_history_index
+        .is_some_and(|i| app.recent.get(i).is_some_and(|q| *q == app.input));
+    if !navigating {
+        app.search_history_index = None;
+        app.search_history_stash = None;
+        let at_start = app.search_caret == 0 && app.search_select_anchor.is_none();
+        if !older || !at_start || app.recent.is_empty() {
+            return false;
+        }
+    }
+    let next = match (app.search_history_index, older) {
+        (None, _) => Some(0),
+        (Some(i), true) if i + 1 < app.recent.len() => Some(i + 1),
+        // Already at the oldest entry: keep it
+        (Some(_), true) => return true,
+        (Some(0), false) => None,
+        (Some(i), false) => Some(i - 1),
+    };
+    if app.search_history_index.is_none() {
+        app.search_history_stash = Some(app.input.clone());
+    }
+    app.search_history_index = next;
+    app.input = match next {
+        Some(i) => app.recent[i].clone(),
+        None => app.search_history_stash.take().unwrap_or_default(),
+    };
+    app.search_caret = char_count(&app.input);
+    app.search_select_anchor = None;
+    app.last_input_change = std::time::Instant::now();
+    app.last_saved_value = next.map(|_| app.input.trim().to_string());
+    send_query(app, query_tx);
+    true
+}
+
+/// What: Turn Results multi-select mode on or off.
+///
+/// Inputs:
+/// - `app`: Mutable application state
+///
+/// Output:
+/// - Flips `multi_select_mode` and shows a toast; turning it off drops the marks.
+fn toggle_multi_select(app: &mut AppState) {
+    app.multi_select_mode = !app.multi_select_mode;
+    let key = if app.multi_select_mode {
+        "app.toasts.multi_select_on"
+    } else {
+        app.multi_selected.clear();
+        "app.toasts.multi_select_off"
+    };
+    app.toast_message = Some(crate::i18n::t(app, key));
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+}
+
+/// What: Mark or unmark the selected Results entry for a batch add.
+///
+/// Inputs:
+/// - `app`: Mutable application state (`results`, `selected`, `multi_selected`)
+///
+/// Output:
+/// - Toggles the entry's name in `multi_selected`; no-op without a selection.
+///
+/// Details:
+/// - Marks are keyed by package name so they survive re-sorting and filtering.
+fn toggle_result_mark(app: &mut AppState) {
+    let Some(name) = app.results.get(app.selected).map(|p| p.name.clone()) else {
+        return;
+    };
+    if !app.multi_selected.remove(&name) {
+        app.multi_selected.insert(name);
+    }
+}
+
+/// What: Add every marked Results entry to the list Space would add it to.
+///
+/// Inputs:
+/// - `app`: Mutable application state
+/// - `add_tx`: Channel that feeds `add_to_install_list`
+/// - `details_tx`: Channel to refresh Remove details in installed-only mode
+///
+/// Output:
+/// - Queues the marked packages (Install list, or Remove list in installed-only mode), clears
+///   the marks, leaves multi-select mode, and toasts the count.
+///
+/// Details:
+/// - Packages are added in Results order, then any marked entries hidden by filters.
+/// - With nothing marked only a hint toast is shown.
+fn add_marked_results(
+    app: &mut AppState,
+    add_tx: &mpsc::UnboundedSender<PackageItem>,
+    details_tx: &mpsc::UnboundedSender<PackageItem>,
+) {
+    let mut items: Vec<PackageItem> = Vec::new();
+    for p in app.results.iter().chain(app.all_results.iter()) {
+        if app.multi_selected.contains(&p.name) && !items.iter().any(|it| it.name == p.name) {
+            items.push(p.clone());
+        }
+    }
+    if items.is_empty() {
+        app.toast_message = Some(crate::i18n::t(app, "app.toasts.multi_select_empty"));
+        app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+        return;
+    }
+    let count = items.len();
+    if app.installed_only_mode {
+        for item in items {
+            crate::logic::add_to_remove_list(app, item);
+        }
+        super::utils::refresh_remove_details(app, details_tx);
+    } else {
+        for item in items {
+            let _ = add_tx.send(item);
+        }
+    }
+    app.multi_selected.clear();
+    app.multi_select_mode = false;
+    app.toast_message = Some(crate::i18n::t_fmt1(
+        app,
+        "app.toasts.multi_select_added",
+        count,
+    ));
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -791,6 +984,114 @@ mod tests {
         assert!(qrx.try_recv().ok().is_some());
     }
 
+    #[test]
+    /// What: Ensure Up/Down walk past queries and restore the query being typed.
+    ///
+    /// Inputs:
+    /// - `recent = ["ripgrep", "firefox"]`; Up/Down from an empty input, then from a stashed
+    ///   partial query with the caret at the start, then an edit of a recalled query.
+    ///
+    /// Output:
+    /// - Up steps newest to oldest and stops there, Down walks back to the stashed text, and
+    ///   after an edit Up falls through to Results navigation.
+    fn search_history_recall_with_stash() {
+        let mut app = AppState {
+            recent: vec!["ripgrep".into(), "firefox".into()],
+            ..new_app()
+        };
+        let (qtx, _qrx) = mpsc::unbounded_channel::<QueryInput>();
+
+        assert!(recall_history(&mut app, true, &qtx));
+        assert_eq!(app.input, "ripgrep");
+        assert!(recall_history(&mut app, true, &qtx));
+        assert!(recall_history(&mut app, true, &qtx));
+        assert_eq!(app.input, "firefox");
+        assert!(recall_history(&mut app, false, &qtx));
+        assert!(recall_history(&mut app, false, &qtx));
+        assert_eq!(app.input, "");
+        assert!(app.search_history_index.is_none());
+        assert!(!recall_history(&mut app, false, &qtx));
+
+        // A partial query is kept when recall starts with the caret at the start
+        app.input = "fd".into();
+        app.search_caret = 0;
+        assert!(recall_history(&mut app, true, &qtx));
+        assert_eq!(app.input, "ripgrep");
+        assert!(recall_history(&mut app, false, &qtx));
+        assert_eq!(app.input, "fd");
+
+        // Editing a recalled query ends the walk; the caret is at the end, so Up is not history
+        app.input.clear();
+        app.search_caret = 0;
+        assert!(recall_history(&mut app, true, &qtx));
+        app.input.push('x');
+        app.search_caret = app.input.len();
+        assert!(!recall_history(&mut app, true, &qtx));
+        assert_eq!(app.input, "ripgrepx");
+    }
+
+    #[test]
+    /// What: Ensure multi-select marks survive re-sorting, batch-add in one go, and reset on a new query.
+    ///
+    /// Inputs:
+    /// - Three AUR results; Alt+M, Space on two rows, a reversed result order, then Alt+L.
+    ///
+    /// Output:
+    /// - Marks follow names after the reorder, Alt+L queues exactly the marked packages and
+    ///   leaves the mode, and typing a query drops fresh marks.
+    fn search_multi_select_batch_add() {
+        let item = |name: &str| PackageItem {
+            name: name.into(),
+            version: "1".into(),
+            description: String::new(),
+            source: crate::state::Source::Aur,
+            popularity: None,
+        };
+        let results = vec![item("a"), item("b"), item("c")];
+        let mut app = AppState {
+            all_results: results.clone(),
+            results,
+            ..new_app()
+        };
+        let (qtx, _qrx) = mpsc::unbounded_channel::<QueryInput>();
+        let (dtx, _drx) = mpsc::unbounded_channel::<PackageItem>();
+        let (atx, mut arx) = mpsc::unbounded_channel::<PackageItem>();
+        let (ptx, _prx) = mpsc::unbounded_channel::<PackageItem>();
+        let press = |app: &mut AppState, code, mods| {
+            handle_search_key(KeyEvent::new(code, mods), app, &qtx, &dtx, &atx, &ptx)
+        };
+
+        press(&mut app, KeyCode::Char('m'), KeyModifiers::ALT);
+        assert!(app.multi_select_mode);
+        press(&mut app, KeyCode::Char(' '), KeyModifiers::empty());
+        app.selected = 2;
+        press(&mut app, KeyCode::Char(' '), KeyModifiers::empty());
+        assert!(arx.try_recv().is_err(), "Space must mark, not add");
+        app.results.reverse();
+        app.selected = 1;
+        press(&mut app, KeyCode::Char(' '), KeyModifiers::empty());
+        press(&mut app, KeyCode::Char(' '), KeyModifiers::empty());
+        let mut marked: Vec<&str> = app.multi_selected.iter().map(String::as_str).collect();
+        marked.sort_unstable();
+        assert_eq!(marked, vec!["a", "c"]);
+
+        press(&mut app, KeyCode::Char('l'), KeyModifiers::ALT);
+        let mut added = Vec::new();
+        while let Ok(it) = arx.try_recv() {
+            added.push(it.name);
+        }
+        assert_eq!(added, vec!["c", "a"]);
+        assert!(app.multi_selected.is_empty());
+        assert!(!app.multi_select_mode);
+
+        press(&mut app, KeyCode::Char('m'), KeyModifiers::ALT);
+        press(&mut app, KeyCode::Char(' '), KeyModifiers::empty());
+        assert_eq!(app.multi_selected.len(), 1);
+        press(&mut app, KeyCode::Char('x'), KeyModifiers::empty());
+        assert!(app.multi_selected.is_empty());
+        assert!(app.multi_select_mode);
+    }
+
     #[test]
     /// What: Normal-mode selection commands should set the anchor and adjust the caret within bounds.
     ///
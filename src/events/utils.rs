@@ -36,7 +36,7 @@ pub fn byte_index_for_char(s: &str, ci: usize) -> usize {
 /// Output: No return value; updates `history_state` selection when a match is found
 ///
 /// Details: Searches within the filtered Recent indices and wraps around the list; matching is
-/// case-insensitive against the current pane-find pattern.
+/// case-insensitive against the current pane-find pattern (fuzzy when `fuzzy_find` is enabled).
 pub fn find_in_recent(app: &mut AppState, forward: bool) {
     let Some(pattern) = app.pane_find.clone() else {
         return;
@@ -58,7 +58,7 @@ pub fn find_in_recent(app: &mut AppState, forward: bool) {
         };
         let i = inds[vi];
         if let Some(s) = app.recent.get(i)
-            && s.to_lowercase().contains(&pattern.to_lowercase())
+            && crate::logic::filter::pane_find_match(s, &pattern, app.fuzzy_find).is_some()
         {
             app.history_state.select(Some(vi));
             break;
@@ -72,7 +72,7 @@ pub fn find_in_recent(app: &mut AppState, forward: bool) {
 /// Output: No return value; updates `install_state` selection when a match is found
 ///
 /// Details: Operates on visible indices and tests case-insensitive matches against package name
-/// or description; wraps around the list.
+/// or description (fuzzy when `fuzzy_find` is enabled); wraps around the list.
 pub fn find_in_install(app: &mut AppState, forward: bool) {
     let Some(pattern) = app.pane_find.clone() else {
         return;
@@ -94,10 +94,9 @@ pub fn find_in_install(app: &mut AppState, forward: bool) {
         };
         let i = inds[vi];
         if let Some(p) = app.install_list.get(i)
-            && (p.name.to_lowercase().contains(&pattern.to_lowercase())
-                || p.description
-                    .to_lowercase()
-                    .contains(&pattern.to_lowercase()))
+            && [&p.name, &p.description].into_iter().any(|text| {
+                crate::logic::filter::pane_find_match(text, &pattern, app.fuzzy_find).is_some()
+            })
         {
             app.install_state.select(Some(vi));
             break;
@@ -119,6 +118,7 @@ pub fn refresh_selected_details(
     if let Some(item) = app.results.get(app.selected).cloned() {
         // Reset scroll when package changes
         app.details_scroll = 0;
+        app.details_hscroll = 0;
         if let Some(cached) = app.details_cache.get(&item.name).cloned() {
             app.details = cached;
         } else {
@@ -149,6 +149,7 @@ pub fn refresh_install_details(
     if let Some(item) = app.install_list.get(i).cloned() {
         // Reset scroll when package changes
         app.details_scroll = 0;
+        app.details_hscroll = 0;
         // Focus details on the install selection
         app.details_focus = Some(item.name.clone());
 
@@ -192,6 +193,7 @@ pub fn refresh_remove_details(app: &mut AppState, details_tx: &mpsc::UnboundedSe
     if let Some(item) = app.remove_list.get(vsel).cloned() {
         // Reset scroll when package changes
         app.details_scroll = 0;
+        app.details_hscroll = 0;
         app.details_focus = Some(item.name.clone());
         app.details.name = item.name.clone();
         app.details.version = item.version.clone();
@@ -234,6 +236,7 @@ pub fn refresh_downgrade_details(
     if let Some(item) = app.downgrade_list.get(vsel).cloned() {
         // Reset scroll when package changes
         app.details_scroll = 0;
+        app.details_hscroll = 0;
         app.details_focus = Some(item.name.clone());
         app.details.name = item.name.clone();
         app.details.version = item.version.clone();
@@ -256,6 +259,845 @@ pub fn refresh_downgrade_details(
     }
 }
 
+/// What: Toggle the search scope between name+description and name-only.
+///
+/// Input: `app` mutable application state; `details_tx` channel for details requests
+/// Output: No return value; flips `search_scope`, re-filters Results, and shows a toast
+///
+/// Details: Filtering happens on the already fetched results, so no new query is sent.
+pub fn toggle_search_scope(app: &mut AppState, details_tx: &mpsc::UnboundedSender<PackageItem>) {
+    app.search_scope = match app.search_scope {
+        crate::state::SearchScope::NameAndDescription => crate::state::SearchScope::NameOnly,
+        crate::state::SearchScope::NameOnly => crate::state::SearchScope::NameAndDescription,
+    };
+    crate::logic::apply_filters_and_sort_preserve_selection(app);
+    refresh_selected_details(app, details_tx);
+    let key = match app.search_scope {
+        crate::state::SearchScope::NameOnly => "app.toasts.search_scope_name_only",
+        crate::state::SearchScope::NameAndDescription => "app.toasts.search_scope_name_desc",
+    };
+    app.toast_message = Some(crate::i18n::t(app, key));
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+}
+
+/// What: Open Preflight on the packages a system upgrade would touch.
+///
+/// Input: `app` mutable application state; `upgrades` pending upgrades (non-empty);
+/// `commands` System Update commands to run once the review is confirmed
+/// Output: No return value; opens Preflight (Install action) and stores `commands` in
+/// `pending_system_update`
+///
+/// Details: Deps/Files/Services resolve lazily per tab like any other Preflight, so the Files tab
+/// shows which configs would turn into `.pacnew`. Proceeding runs `commands` instead of
+/// installing the items one by one.
+pub fn open_upgrade_preflight(
+    app: &mut AppState,
+    upgrades: Vec<PackageItem>,
+    commands: Vec<String>,
+) {
+    app.preflight_cancelled
+        .store(false, std::sync::atomic::Ordering::Relaxed);
+    app.preflight_summary_items = Some((upgrades.clone(), crate::state::PreflightAction::Install));
+    app.preflight_summary_resolving = true;
+    app.pending_service_plan.clear();
+    app.pending_system_update = Some(commands);
+    let count = upgrades.len();
+    app.modal = crate::state::Modal::Preflight {
+        header_chips: crate::state::modal::PreflightHeaderChips {
+            package_count: count,
+            download_bytes: 0,
+            install_delta_bytes: 0,
+            aur_count: upgrades
+                .iter()
+                .filter(|p| matches!(p.source, crate::state::Source::Aur))
+                .count(),
+            risk_score: 0,
+            risk_level: crate::state::modal::RiskLevel::Low,
+        },
+        items: upgrades,
+        action: crate::state::PreflightAction::Install,
+        tab: crate::state::PreflightTab::Summary,
+        summary: None,
+        dependency_info: Vec::new(),
+        dep_selected: 0,
+        dep_tree_expanded: std::collections::HashSet::new(),
+        deps_error: None,
+        file_info: Vec::new(),
+        file_selected: 0,
+        file_tree_expanded: std::collections::HashSet::new(),
+        files_error: None,
+        service_info: Vec::new(),
+        service_selected: 0,
+        services_loaded: false,
+        services_error: None,
+        sandbox_info: Vec::new(),
+        sandbox_selected: 0,
+        sandbox_tree_expanded: std::collections::HashSet::new(),
+        sandbox_loaded: false,
+        sandbox_error: None,
+        selected_optdepends: std::collections::HashMap::new(),
+        cascade_mode: app.remove_cascade_mode,
+    };
+    app.toast_message = Some(crate::i18n::t_fmt1(
+        app,
+        "app.toasts.upgrade_review_opened",
+        count,
+    ));
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+}
+
+/// What: Apply the pending upgrades listed for a System Update review.
+///
+/// Input: `app` mutable application state; `upgrades` result of
+/// `logic::upgrades::list_system_upgrades`; `commands` System Update commands from the request
+/// Output: No return value; opens the upgrade Preflight, or shows a toast when nothing is pending
+///
+/// Details: Ignored unless the System Update dialog is still open and waiting, so a result that
+/// arrives after Esc does not reopen anything.
+pub fn finish_upgrades_query(
+    app: &mut AppState,
+    upgrades: Vec<PackageItem>,
+    commands: Vec<String>,
+) {
+    let crate::state::Modal::SystemUpdate { loading, .. } = &mut app.modal else {
+        return;
+    };
+    if !*loading {
+        return;
+    }
+    if upgrades.is_empty() {
+        *loading = false;
+        app.toast_message = Some(crate::i18n::t(app, "app.toasts.no_upgrades"));
+        app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+        return;
+    }
+    open_upgrade_preflight(app, upgrades, commands);
+}
+
+/// What: Toggle the "upgradable only" Results filter.
+///
+/// Input: `app` mutable application state; `details_tx` channel for details requests
+/// Output: No return value; flips `results_filter_upgradable_only`, re-filters Results, and shows a
+/// toast
+///
+/// Details: Applies on top of the repo filters, using the cached `pacman -Q` versions.
+pub fn toggle_upgradable_only(app: &mut AppState, details_tx: &mpsc::UnboundedSender<PackageItem>) {
+    app.results_filter_upgradable_only = !app.results_filter_upgradable_only;
+    crate::logic::apply_filters_and_sort_preserve_selection(app);
+    refresh_selected_details(app, details_tx);
+    let key = if app.results_filter_upgradable_only {
+        "app.toasts.upgradable_only_on"
+    } else {
+        "app.toasts.upgradable_only_off"
+    };
+    app.toast_message = Some(crate::i18n::t(app, key));
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+}
+
+/// What: Switch Results into installed-only mode (explicitly installed packages only).
+///
+/// Input: `app` mutable application state; `details_tx` channel for details requests
+/// Output: No return value; replaces `all_results`, backs up the previous list, and focuses Remove
+///
+/// Details: Official entries come from the index filtered by the explicit set; explicit names not
+/// in the index are represented as AUR (or EOS) items. Also refreshes `installed_packages.txt`.
+pub fn enter_installed_only_mode(
+    app: &mut AppState,
+    details_tx: &mpsc::UnboundedSender<PackageItem>,
+) {
+    app.results_backup_for_toggle = Some(app.all_results.clone());
+    let explicit = crate::index::explicit_names();
+    app.all_results = crate::logic::filter::installed_only_results(Vec::new(), "", &explicit);
+    app.installed_only_mode = true;
+    app.right_pane_focus = crate::state::RightPaneFocus::Remove;
+    crate::logic::apply_filters_and_sort_preserve_selection(app);
+    refresh_selected_details(app, details_tx);
+
+    // Save exported list to config directory
+    let path = crate::theme::config_dir().join("installed_packages.txt");
+    let mut names: Vec<String> = crate::index::explicit_names().into_iter().collect();
+    names.sort();
+    let _ = std::fs::write(path, names.join("\n"));
+}
+
+/// What: Start ranking mirrors in the background from the Options menu.
+///
+/// Input: `app` mutable application state
+/// Output: No return value; shows a progress toast (or a notice when a run is already going)
+///
+/// Details: Uses the `selected_countries` and `mirror_count` settings; the result opens a preview
+/// on a later UI tick.
+pub fn start_mirror_ranking(app: &mut AppState) {
+    let key = if app.mirror_rank_running {
+        "app.toasts.mirror_rank_busy"
+    } else {
+        let prefs = crate::theme::settings();
+        crate::index::spawn_rank_mirrors(prefs.selected_countries.clone(), prefs.mirror_count);
+        app.mirror_rank_running = true;
+        "app.toasts.mirror_rank_started"
+    };
+    app.toast_message = Some(crate::i18n::t(app, key));
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(30));
+}
+
+/// What: Switch to a named theme and remember it as `active_theme`.
+///
+/// Input: `app` mutable application state; `name` theme from `themes/` (empty for `theme.conf`)
+/// Output: No return value; shows a toast with the new theme or the reason it was not loaded
+///
+/// Details: A theme that fails validation leaves the current theme in place.
+pub fn apply_theme(app: &mut AppState, name: &str) {
+    let label = crate::ui::helpers::theme_display_name(app, name);
+    app.toast_message = Some(match crate::theme::switch_theme(name) {
+        Ok(()) => {
+            crate::theme::save_active_theme(name);
+            crate::i18n::t_fmt1(app, "app.toasts.theme_switched", &label)
+        }
+        Err(e) => crate::i18n::t_fmt(app, "app.toasts.theme_switch_failed", &[&label, &e]),
+    });
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(4));
+}
+
+/// What: Tell the user their layout percentages were rescaled (`layout_autoscale`).
+///
+/// Input: `app` mutable application state; `prefs` settings as loaded
+/// Output: `true` when a toast was shown
+///
+/// Details: Shows the values as written and the ones now in use, e.g. `30/60/30 → 25/50/25`.
+pub fn notify_layout_rescaled(app: &mut AppState, prefs: &crate::theme::Settings) -> bool {
+    let Some([l, c, r]) = prefs.layout_rescaled_from else {
+        return false;
+    };
+    let now = format!(
+        "{}/{}/{}",
+        prefs.layout_left_pct, prefs.layout_center_pct, prefs.layout_right_pct
+    );
+    app.toast_message = Some(crate::i18n::t_fmt(
+        app,
+        "app.toasts.layout_rescaled",
+        &[&format!("{l}/{c}/{r}"), &now],
+    ));
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(6));
+    true
+}
+
+/// What: Re-read settings.conf and keybinds.conf and apply them to the running session.
+///
+/// Input: `app` mutable application state
+/// Output: No return value; updates state and shows a toast
+///
+/// Details: Layout sums that do not add up are rescaled or reset exactly as at startup
+/// (the normalization lives in `settings()`). Results are re-sorted/filtered only when the
+/// sort mode or a filter toggle changed, so selection and scroll stay put otherwise. Chord
+/// conflicts in the new key map are reported instead of the plain confirmation.
+pub fn reload_settings(app: &mut AppState) {
+    let prefs = crate::theme::settings();
+    let old_sort = app.sort_mode;
+    let old_filters = crate::logic::filter::results_filter_values(app);
+    app.apply_settings(&prefs);
+    crate::app::apply_global_settings(&prefs);
+    if app.sort_mode != old_sort || crate::logic::filter::results_filter_values(app) != old_filters
+    {
+        crate::logic::apply_filters_and_sort_preserve_selection(app);
+    }
+    let conflicts = crate::theme::find_keymap_conflicts(&app.keymap);
+    if conflicts.is_empty() && notify_layout_rescaled(app, &prefs) {
+        return;
+    }
+    app.toast_message = Some(if conflicts.is_empty() {
+        crate::i18n::t(app, "app.toasts.settings_reloaded")
+    } else {
+        let list = conflicts
+            .iter()
+            .map(|c| format!("{} → {}", c.chord.label(), c.actions.join(", ")))
+            .collect::<Vec<_>>()
+            .join("; ");
+        crate::i18n::t_fmt1(app, "app.toasts.keybind_conflicts", list)
+    });
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(4));
+}
+
+/// What: Switch to the next valid theme in `themes/` (the cycle-theme keybind).
+///
+/// Input: `app` mutable application state
+/// Output: No return value; switches the theme and shows a toast
+///
+/// Details: Cycles theme.conf → built-in high contrast → named themes in name order →
+/// theme.conf; invalid files are skipped and named in the toast.
+pub fn cycle_theme(app: &mut AppState) {
+    let scan = crate::theme::scan_themes(&crate::theme::themes_dir());
+    let current = crate::theme::settings().active_theme;
+    let next = crate::theme::next_theme(current.trim(), &scan.valid);
+    apply_theme(app, &next);
+    append_invalid_themes(app, &scan);
+}
+
+/// What: Check keybinds.conf and show the result from the Options menu.
+///
+/// Input: `app` mutable application state
+/// Output: No return value; opens `Modal::KeybindDiagnostics`
+///
+/// Details: The file is re-read each time, so edits show up without restarting.
+pub fn open_keybind_diagnostics(app: &mut AppState) {
+    app.modal = crate::state::Modal::KeybindDiagnostics {
+        issues: crate::theme::validate_keybinds(),
+        scroll: 0,
+    };
+}
+
+/// What: List leftover `.pacnew`/`.pacsave` files from the Options menu.
+///
+/// Input: `app` mutable application state
+/// Output: No return value; opens `Modal::ConfigLeftovers`
+///
+/// Details: Listing runs unprivileged; merging and removing go through the configured
+/// escalation tool in a terminal. The dialog opens in its loading state and is filled by
+/// [`finish_leftovers_scan`] once the runtime has walked the file system.
+pub fn open_config_leftovers(app: &mut AppState) {
+    app.modal = crate::state::Modal::ConfigLeftovers {
+        entries: Vec::new(),
+        selected: 0,
+        loading: true,
+    };
+    app.leftovers_scan_requested = true;
+}
+
+/// What: Fill the Config leftovers dialog with a finished scan.
+///
+/// Input: `app` mutable application state; `found` result of
+/// `logic::pacnew::scan_system_leftovers`
+/// Output: No return value; replaces the dialog's entries and clears its loading state
+///
+/// Details: Ignored when the dialog was closed (or is not waiting) by the time the scan
+/// finishes; the selection is kept within the new list.
+pub fn finish_leftovers_scan(app: &mut AppState, found: Vec<crate::logic::pacnew::ConfigLeftover>) {
+    if let crate::state::Modal::ConfigLeftovers {
+        entries,
+        selected,
+        loading,
+    } = &mut app.modal
+        && *loading
+    {
+        *entries = found;
+        *selected = (*selected).min(entries.len().saturating_sub(1));
+        *loading = false;
+    }
+}
+
+/// What: Toast the outcome of a Ctrl+Y view-settings copy.
+///
+/// Input: `app` mutable application state; `result` outcome of `util::copy_to_clipboard`
+/// Output: No return value; sets the toast
+///
+/// Details: Failures show the clipboard hint (e.g. which tool to install) a little longer.
+pub fn finish_view_settings_copy(app: &mut AppState, result: Result<(), String>) {
+    let (msg, secs) = match result {
+        Ok(()) => (crate::i18n::t(app, "app.toasts.view_settings_copied"), 3),
+        Err(hint) => (hint, 4),
+    };
+    app.toast_message = Some(msg);
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(secs));
+}
+
+/// What: Fill the Why-installed dialog with traced reverse dependency chains.
+///
+/// Input: `app` mutable application state; `package` package the chains were traced for;
+/// `chains` result of `logic::deps::reverse_why`
+/// Output: No return value; fills the dialog and clears its loading state
+///
+/// Details: Ignored when the dialog was closed or now explains another package.
+pub fn finish_why_installed(app: &mut AppState, package: &str, chains: Vec<Vec<String>>) {
+    if let crate::state::Modal::WhyInstalled {
+        package: shown,
+        chains: slot,
+        loading,
+    } = &mut app.modal
+        && *loading
+        && shown == package
+    {
+        *slot = chains;
+        *loading = false;
+    }
+}
+
+/// What: Open the selected result's package page in the browser.
+///
+/// Input: `app` mutable application state
+/// Output: No return value; opens the page, or toasts when the package's repository has none
+///
+/// Details: Only AUR and Arch repository packages have a page; see [`crate::util::package_page_url`].
+pub fn open_package_page(app: &mut AppState) {
+    let Some(item) = app.results.get(app.selected) else {
+        return;
+    };
+    if let Some(url) = crate::util::package_page_url(&item.name, &item.source) {
+        crate::util::open_url(&url);
+    } else {
+        app.toast_message = Some(crate::i18n::t(app, "app.toasts.no_package_page"));
+        app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+    }
+}
+
+/// What: Preview a finished list import before it is added to the install list.
+///
+/// Input: `app` mutable application state; `summary` resolved packages and skipped names from
+/// the import thread
+/// Output: No return value; opens `Modal::ProfileDiff` in append mode, or toasts when nothing
+/// resolved
+///
+/// Details: Skipped names are reported in a toast alongside the preview.
+pub fn open_import_preview(app: &mut AppState, summary: crate::logic::package_list::ImportSummary) {
+    let skipped = summary.skipped.len();
+    if summary.items.is_empty() {
+        app.toast_message = Some(if skipped == 0 {
+            crate::i18n::t_fmt1(app, "app.toasts.import_done", 0)
+        } else {
+            crate::i18n::t_fmt(
+                app,
+                "app.toasts.import_done_skipped",
+                &[&0, &skipped, &summary.skipped.join(", ")],
+            )
+        });
+    } else {
+        app.modal = crate::state::Modal::ProfileDiff {
+            name: summary.source,
+            items: summary.items,
+            append: true,
+            diff: summary.diff,
+            import: true,
+        };
+        if skipped == 0 {
+            return;
+        }
+        app.toast_message = Some(crate::i18n::t_fmt(
+            app,
+            "app.toasts.import_skipped",
+            &[&skipped, &summary.skipped.join(", ")],
+        ));
+    }
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(8));
+}
+
+/// What: Open the diff of the selected leftover against its config.
+///
+/// Input: `app` mutable application state; `entries`/`selected` the leftover list to diff from
+/// and return to
+/// Output: No return value; opens `Modal::ConfigDiff` (ignored when `selected` is out of range)
+///
+/// Details: Either file being binary or unreadable (e.g. root-only) yields a placeholder
+/// instead of a diff.
+pub fn open_config_diff(
+    app: &mut AppState,
+    entries: Vec<crate::logic::pacnew::ConfigLeftover>,
+    selected: usize,
+) {
+    let Some(leftover) = entries.get(selected).cloned() else {
+        return;
+    };
+    let lines = crate::logic::textdiff::read_text(&leftover.original)
+        .zip(crate::logic::textdiff::read_text(&leftover.path))
+        .map(|(old, new)| crate::logic::textdiff::diff_lines(&old, &new));
+    app.modal = crate::state::Modal::ConfigDiff {
+        leftover,
+        lines,
+        scroll: 0,
+        entries,
+        selected,
+    };
+}
+
+/// What: Open the logs viewer on the newest Pacsea log.
+///
+/// Input: `app` mutable application state
+/// Output: No return value; opens `Modal::LogViewer` following the end of the file
+///
+/// Details: Only the last `logic::logs::TAIL_BYTES` are read; a read error is shown as the
+/// only line.
+pub fn open_log_viewer(app: &mut AppState) {
+    let path = crate::logic::logs::latest_log_file(&crate::theme::logs_dir());
+    let lines = match &path {
+        Some(p) => crate::logic::logs::tail_lines(p, crate::logic::logs::TAIL_BYTES)
+            .unwrap_or_else(|e| vec![format!("{}: {e}", p.display())]),
+        None => Vec::new(),
+    };
+    let min_level = match &app.modal {
+        crate::state::Modal::LogViewer { min_level, .. } => *min_level,
+        _ => None,
+    };
+    app.modal = crate::state::Modal::LogViewer {
+        path,
+        lines,
+        min_level,
+        scroll: 0,
+    };
+}
+
+/// What: Show the errors recorded this session.
+///
+/// Input: `app` mutable application state
+/// Output: No return value; opens `Modal::ErrorLog` at the newest entry
+pub fn open_error_log(app: &mut AppState) {
+    app.modal = crate::state::Modal::ErrorLog { scroll: 0 };
+}
+
+/// What: Typed-count guard for confirming a transaction larger than `confirm_threshold`.
+///
+/// Input: `app` application state; `action` kind of transaction; `count` packages it touches
+/// Output: `Some` guard when the combined install+remove+downgrade count exceeds the threshold
+///
+/// Details: The combined count adds the other queued lists to `count`, so a large pending
+/// removal also guards a small install.
+pub(crate) fn confirm_guard(
+    app: &AppState,
+    action: crate::state::PreflightAction,
+    count: usize,
+) -> Option<crate::state::modal::TypedConfirm> {
+    let others = match action {
+        crate::state::PreflightAction::Install => app.remove_list.len(),
+        crate::state::PreflightAction::Remove => app.install_list.len(),
+    };
+    crate::state::modal::TypedConfirm::for_count(
+        count + others + app.downgrade_list.len(),
+        crate::theme::settings().confirm_threshold,
+    )
+}
+
+/// What: Show the persisted install/remove/downgrade activity log.
+///
+/// Input: `app` mutable application state
+/// Output: No return value; opens `Modal::ActivityLog` at the newest entry
+pub fn open_activity_log(app: &mut AppState) {
+    app.modal = crate::state::Modal::ActivityLog {
+        entries: crate::logic::activity::load(),
+        scroll: 0,
+    };
+}
+
+/// What: Toggle line wrapping in the PKGBUILD viewer (when open) or the Package Info pane.
+///
+/// Input: `app` mutable application state
+/// Output: No return value; flips and persists the pane's wrap flag and shows a toast
+///
+/// Details: Turning wrap on drops the sideways offset so the pane wraps again; the vertical
+/// offset is clamped on the next render since the row count changes.
+pub fn toggle_wrap(app: &mut AppState) {
+    let (pane, on) = if app.pkgb_visible {
+        app.pkgb_wrap = !app.pkgb_wrap;
+        if app.pkgb_wrap {
+            app.pkgb_hscroll = 0;
+        }
+        crate::theme::save_pkgbuild_wrap(app.pkgb_wrap);
+        (crate::i18n::t(app, "app.titles.pkgb"), app.pkgb_wrap)
+    } else {
+        app.details_wrap = !app.details_wrap;
+        if app.details_wrap {
+            app.details_hscroll = 0;
+        }
+        crate::theme::save_details_wrap(app.details_wrap);
+        (
+            crate::i18n::t(app, "app.titles.package_info"),
+            app.details_wrap,
+        )
+    };
+    let key = if on {
+        "app.toasts.wrap_on"
+    } else {
+        "app.toasts.wrap_off"
+    };
+    app.toast_message = Some(crate::i18n::t_fmt1(app, key, pane));
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+}
+
+/// Minimum time between manual refreshes of the installed package caches.
+const INSTALLED_REFRESH_DEBOUNCE: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// What: Re-read the installed and explicitly installed package sets now.
+///
+/// Input: `app` mutable application state
+/// Output: No return value; schedules the refresh and shows a toast
+///
+/// Details: Reuses the post-transaction polling window, so the next tick refreshes both caches
+/// and re-runs the query; an active longer window (e.g. after an install) is left intact. Presses
+/// within `INSTALLED_REFRESH_DEBOUNCE` of the last one only show a toast so pacman is not spammed.
+pub fn refresh_installed_now(app: &mut AppState) {
+    let now = std::time::Instant::now();
+    let key = if app
+        .installed_refresh_requested_at
+        .is_some_and(|t| now.duration_since(t) < INSTALLED_REFRESH_DEBOUNCE)
+    {
+        "app.toasts.installed_refresh_debounced"
+    } else {
+        app.installed_refresh_requested_at = Some(now);
+        let until = now + std::time::Duration::from_secs(2);
+        app.refresh_installed_until =
+            Some(app.refresh_installed_until.map_or(until, |t| t.max(until)));
+        app.next_installed_refresh_at = None;
+        "app.toasts.installed_refreshing"
+    };
+    app.toast_message = Some(crate::i18n::t(app, key));
+    app.toast_expires_at = Some(now + std::time::Duration::from_secs(3));
+}
+
+/// What: React to a terminal resize before the next frame is drawn.
+///
+/// Input: `app` mutable application state
+/// Output: Drops geometry from the old size and re-clamps selections and scroll offsets.
+///
+/// Details: Cancels a pane-splitter drag (its pointer math used the old width) and clears the
+/// pane hit-test rectangles so no click lands on stale geometry; the next frame records fresh
+/// ones, and the renderers clamp `details_scroll`, `help_scroll` and horizontal offsets to the new
+/// area. `pkgb_scroll` and list selections are clamped here since they only depend on content.
+pub fn handle_resize(app: &mut AppState) {
+    app.pane_drag = None;
+    app.results_rect = None;
+    app.results_header_rects.clear();
+    app.details_rect = None;
+    app.pkgb_rect = None;
+    app.recent_rect = None;
+    app.install_rect = None;
+    app.downgrade_rect = None;
+    app.middle_rect = None;
+    app.pane_border_cols.clear();
+    app.help_rect = None;
+    app.news_rect = None;
+    app.news_list_rect = None;
+    app.url_button_rect = None;
+    app.pkgb_button_rect = None;
+    app.package_page_button_rect = None;
+    app.provide_button_rects.clear();
+    app.pkgb_check_button_rect = None;
+    app.pkgb_reload_button_rect = None;
+    app.sort_menu_rect = None;
+    app.options_menu_rect = None;
+    app.panels_menu_rect = None;
+    app.config_menu_rect = None;
+
+    let pkgb_lines = app.pkgb_text.as_deref().map_or(1, |t| t.lines().count());
+    app.pkgb_scroll = app
+        .pkgb_scroll
+        .min(u16::try_from(pkgb_lines.saturating_sub(1)).unwrap_or(u16::MAX));
+    if !app.results.is_empty() {
+        app.selected = app.selected.min(app.results.len() - 1);
+        app.list_state.select(Some(app.selected));
+    }
+    for (state, len) in [
+        (&mut app.install_state, app.install_list.len()),
+        (&mut app.remove_state, app.remove_list.len()),
+        (&mut app.downgrade_state, app.downgrade_list.len()),
+    ] {
+        if let Some(sel) = state.selected() {
+            state.select((len > 0).then(|| sel.min(len - 1)));
+        }
+    }
+}
+
+/// What: Open the file list of the selected Results package.
+///
+/// Input: `app` mutable application state
+/// Output: No return value; opens `Modal::PackageFiles` in its loading state (no-op without a
+/// selection)
+///
+/// Details: `pacman -Ql`/`-Fl` can take a second or more, so the runtime runs
+/// `logic::files::package_file_list` in the background and [`finish_package_files`] fills the
+/// dialog.
+pub fn open_package_files(app: &mut AppState) {
+    let Some(item) = app.results.get(app.selected).cloned() else {
+        return;
+    };
+    app.modal = crate::state::Modal::PackageFiles {
+        package: item.name.clone(),
+        files: Vec::new(),
+        error: None,
+        aur: matches!(item.source, crate::state::Source::Aur),
+        filter: String::new(),
+        scroll: 0,
+        loading: true,
+    };
+    app.package_files_request = Some(item);
+}
+
+/// What: Fill the package files dialog with a finished lookup.
+///
+/// Input: `app` mutable application state; `package` package the lookup ran for; `result`
+/// outcome of `logic::files::package_file_list`
+/// Output: No return value; fills the list or error and clears the loading state
+///
+/// Details: Ignored when the dialog was closed or now shows another package; an error is also
+/// recorded in the error history.
+pub fn finish_package_files(
+    app: &mut AppState,
+    package: &str,
+    result: Result<Vec<String>, String>,
+) {
+    let crate::state::Modal::PackageFiles {
+        package: shown,
+        loading: true,
+        ..
+    } = &app.modal
+    else {
+        return;
+    };
+    if shown != package {
+        return;
+    }
+    let (list, err) = match result {
+        Ok(list) => (list, None),
+        Err(e) => {
+            crate::logic::errors::record_error(app, "files", &e);
+            (Vec::new(), Some(e))
+        }
+    };
+    if let crate::state::Modal::PackageFiles {
+        files,
+        error,
+        loading,
+        ..
+    } = &mut app.modal
+    {
+        *files = list;
+        *error = err;
+        *loading = false;
+    }
+}
+
+/// What: Open the theme picker from the Options menu.
+///
+/// Input: `app` mutable application state
+/// Output: No return value; opens `Modal::ThemePicker` with theme.conf and the built-in
+/// high-contrast preset first and the active theme highlighted
+///
+/// Details: Invalid theme files are left out and reported in a toast.
+pub fn open_theme_picker(app: &mut AppState) {
+    let scan = crate::theme::scan_themes(&crate::theme::themes_dir());
+    let current = crate::theme::settings().active_theme;
+    let names: Vec<String> = [String::new(), crate::theme::HIGH_CONTRAST.to_string()]
+        .into_iter()
+        .chain(scan.valid.iter().cloned())
+        .collect();
+    let selected = names.iter().position(|n| *n == current.trim()).unwrap_or(0);
+    app.modal = crate::state::Modal::ThemePicker { names, selected };
+    app.toast_message = None;
+    append_invalid_themes(app, &scan);
+}
+
+/// What: Add the names of themes that failed validation to the current toast.
+fn append_invalid_themes(app: &mut AppState, scan: &crate::theme::ThemeScan) {
+    if scan.invalid.is_empty() {
+        return;
+    }
+    for (name, err) in &scan.invalid {
+        tracing::warn!(theme = %name, error = %err, "skipping invalid theme");
+    }
+    let names: Vec<&str> = scan.invalid.iter().map(|(n, _)| n.as_str()).collect();
+    let note = crate::i18n::t_fmt1(app, "app.toasts.themes_invalid", names.join(", "));
+    app.toast_message = Some(match app.toast_message.take() {
+        Some(msg) => format!("{msg}  •  {note}"),
+        None => note,
+    });
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(6));
+}
+
+/// What: Open the profile save or load dialog from the Config/Lists menu.
+///
+/// Input: `app` mutable application state; `save` selects the save dialog instead of load
+/// Output: No return value; sets `app.modal` or shows a toast when there is nothing to do
+///
+/// Details: Saving needs a non-empty install list; loading lists the profiles found under
+/// `lists_dir/profiles`.
+pub fn open_profiles_modal(app: &mut AppState, save: bool) {
+    let modal = if save {
+        (!app.install_list.is_empty()).then(|| crate::state::Modal::ProfileSave {
+            input: String::new(),
+        })
+    } else {
+        let names = crate::logic::profiles::list_profiles(&crate::logic::profiles::profiles_dir());
+        (!names.is_empty()).then_some(crate::state::Modal::ProfileLoad { names, selected: 0 })
+    };
+    match modal {
+        Some(modal) => app.modal = modal,
+        None => {
+            let key = if save {
+                "app.toasts.profile_install_list_empty"
+            } else {
+                "app.toasts.profile_none_saved"
+            };
+            app.toast_message = Some(crate::i18n::t(app, key));
+            app.toast_expires_at =
+                Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+        }
+    }
+}
+
+/// What: Ask the runtime to look up orphaned dependencies for the Remove list.
+///
+/// Input: `app` mutable application state
+/// Output: No return value; sets `orphans_requested` and shows a toast while pacman runs
+///
+/// Details: The runtime runs `pacman -Qtdq` off the UI thread and hands the result to
+/// [`finish_orphans`].
+pub fn add_orphans_to_remove_list(app: &mut AppState) {
+    app.orphans_requested = true;
+    app.toast_message = Some(crate::i18n::t(app, "app.toasts.orphans_searching"));
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+}
+
+/// What: Populate the Remove list with orphaned dependencies reported by pacman.
+///
+/// Input: `app` mutable application state; `result` outcome of `index::find_orphans`;
+/// `details_tx` channel for details requests
+/// Output: No return value; adds orphans to `remove_list` and shows a toast with the outcome
+///
+/// Details: Enters installed-only mode when needed so the Remove list is visible, resolves each
+/// orphan against the official index for repo metadata, and falls back to AUR for foreign packages.
+/// A failed lookup is shown and recorded instead of reporting "no orphans".
+pub fn finish_orphans(
+    app: &mut AppState,
+    result: Result<Vec<String>, String>,
+    details_tx: &mpsc::UnboundedSender<PackageItem>,
+) {
+    let orphans = match result {
+        Ok(orphans) => orphans,
+        Err(e) => {
+            let msg = crate::i18n::t_fmt1(app, "app.toasts.orphans_failed", &e);
+            crate::logic::errors::record_error(app, "orphans", msg.clone());
+            app.toast_message = Some(msg);
+            app.toast_expires_at =
+                Some(std::time::Instant::now() + std::time::Duration::from_secs(5));
+            return;
+        }
+    };
+    if orphans.is_empty() {
+        app.toast_message = Some(crate::i18n::t(app, "app.toasts.no_orphans_found"));
+        app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+        return;
+    }
+    if !app.installed_only_mode {
+        enter_installed_only_mode(app, details_tx);
+    }
+    let official: std::collections::HashMap<String, PackageItem> = crate::index::all_official()
+        .into_iter()
+        .map(|p| (p.name.clone(), p))
+        .collect();
+    // Insert in reverse so the Remove list reads alphabetically (add_to_remove_list prepends)
+    for name in orphans.iter().rev() {
+        let item = official.get(name).cloned().unwrap_or_else(|| PackageItem {
+            name: name.clone(),
+            version: String::new(),
+            description: String::new(),
+            source: crate::state::Source::Aur,
+            popularity: None,
+        });
+        crate::logic::add_to_remove_list(app, item);
+    }
+    app.right_pane_focus = crate::state::RightPaneFocus::Remove;
+    app.toast_message = Some(crate::i18n::tr_plural(
+        app,
+        "app.toasts.orphans_added",
+        orphans.len(),
+    ));
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -394,4 +1236,379 @@ mod tests {
         let got = rx.try_recv().ok();
         assert!(got.is_some());
     }
+
+    #[test]
+    /// What: Ensure a resize drops stale geometry and clamps content-bound offsets.
+    ///
+    /// Inputs:
+    /// - Recorded pane rects, an active splitter drag, a PKGBUILD scroll past its 2 lines, and an
+    ///   install selection past the end of a 1-item list.
+    ///
+    /// Output:
+    /// - Rects cleared, drag cancelled, `pkgb_scroll` at 1, and the install selection at 0.
+    fn handle_resize_drops_geometry_and_clamps() {
+        let mut app = new_app();
+        app.results_rect = Some((1, 2, 10, 5));
+        app.help_rect = Some((0, 0, 5, 5));
+        app.pane_drag = Some(crate::logic::layout::PaneBorder::LeftCenter);
+        app.pkgb_text = Some("a\nb".into());
+        app.pkgb_scroll = 40;
+        app.install_list = vec![PackageItem {
+            name: "rg".into(),
+            version: "1".into(),
+            description: String::new(),
+            source: crate::state::Source::Aur,
+            popularity: None,
+        }];
+        app.install_state.select(Some(3));
+
+        handle_resize(&mut app);
+
+        assert!(app.results_rect.is_none() && app.help_rect.is_none());
+        assert!(app.pane_drag.is_none());
+        assert_eq!(app.pkgb_scroll, 1);
+        assert_eq!(app.install_state.selected(), Some(0));
+    }
+
+    #[test]
+    /// What: Ensure reloading settings applies the new file and keeps invalid layouts safe.
+    ///
+    /// Inputs:
+    /// - A temporary HOME whose settings.conf first sets a layout and sort mode, then a zero
+    ///   pane width (color depth pinned so the shared theme stays as tests expect).
+    ///
+    /// Output:
+    /// - The first reload applies both values; the second falls back to the defaults, exactly
+    ///   as startup does.
+    fn reload_settings_applies_sort_and_normalizes_layout() {
+        let _guard = crate::theme::test_mutex().lock().unwrap();
+        let orig_home = std::env::var_os("HOME");
+        let home = tempfile::tempdir().expect("tempdir");
+        let cfg = home.path().join(".config").join("pacsea");
+        std::fs::create_dir_all(&cfg).unwrap();
+        let write = |left: u16| {
+            std::fs::write(
+                cfg.join("settings.conf"),
+                format!(
+                    "layout_left_pct={left}\nlayout_center_pct=60\nlayout_right_pct=20\nsort_mode=aur_popularity\nskip_preflight=false\nforce_color=truecolor\n"
+                ),
+            )
+            .unwrap();
+        };
+        unsafe { std::env::set_var("HOME", home.path()) };
+
+        let mut app = new_app();
+        write(20);
+        reload_settings(&mut app);
+        assert_eq!(
+            app.sort_mode,
+            crate::state::SortMode::AurPopularityThenOfficial
+        );
+        assert_eq!(app.layout_left_pct, 20);
+        assert!(app.toast_message.is_some());
+
+        write(0);
+        reload_settings(&mut app);
+        let defaults = crate::theme::Settings::default();
+        assert_eq!(app.layout_left_pct, defaults.layout_left_pct);
+        assert_eq!(app.sort_mode, defaults.sort_mode);
+
+        unsafe {
+            if let Some(v) = orig_home {
+                std::env::set_var("HOME", v);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+    }
+
+    #[test]
+    /// What: Ensure a listed upgrades result only applies to a System Update dialog still waiting.
+    ///
+    /// Inputs:
+    /// - Results delivered with no dialog open, to a waiting dialog with nothing pending, and to a
+    ///   waiting dialog with one upgrade.
+    ///
+    /// Output:
+    /// - The first is ignored, the second clears the loading state with a toast, and the third
+    ///   opens Preflight carrying the update commands.
+    fn finish_upgrades_query_applies_to_waiting_dialog() {
+        let waiting = || crate::state::Modal::SystemUpdate {
+            do_mirrors: false,
+            do_pacman: true,
+            do_aur: false,
+            do_cache: false,
+            country_idx: 0,
+            countries: Vec::new(),
+            mirror_count: 20,
+            cursor: 0,
+            loading: true,
+        };
+        let item = PackageItem {
+            name: "zzq-linux".into(),
+            version: "6.9.2-1".into(),
+            description: String::new(),
+            source: crate::state::Source::Aur,
+            popularity: None,
+        };
+        let cmds = vec!["sudo pacman -Syyu --noconfirm".to_string()];
+
+        let mut app = new_app();
+        finish_upgrades_query(&mut app, vec![item.clone()], cmds.clone());
+        assert!(matches!(app.modal, crate::state::Modal::None));
+        assert!(app.pending_system_update.is_none());
+
+        app.modal = waiting();
+        finish_upgrades_query(&mut app, Vec::new(), cmds.clone());
+        assert!(matches!(
+            app.modal,
+            crate::state::Modal::SystemUpdate { loading: false, .. }
+        ));
+        assert!(app.toast_message.is_some());
+
+        app.modal = waiting();
+        finish_upgrades_query(&mut app, vec![item], cmds.clone());
+        assert!(matches!(app.modal, crate::state::Modal::Preflight { .. }));
+        assert_eq!(app.pending_system_update, Some(cmds));
+    }
+
+    #[test]
+    /// What: Ensure a finished leftovers scan fills only a dialog that is still waiting.
+    ///
+    /// Inputs:
+    /// - A scan result delivered with no dialog open, then to a freshly opened dialog.
+    ///
+    /// Output:
+    /// - The first is ignored; the second fills the entries and clears the loading state.
+    fn finish_leftovers_scan_fills_waiting_dialog() {
+        let leftover = crate::logic::pacnew::ConfigLeftover {
+            path: "/etc/a.conf.pacnew".into(),
+            original: "/etc/a.conf".into(),
+            kind: crate::logic::pacnew::LeftoverKind::Pacnew,
+        };
+        let mut app = new_app();
+        finish_leftovers_scan(&mut app, vec![leftover.clone()]);
+        assert!(matches!(app.modal, crate::state::Modal::None));
+
+        open_config_leftovers(&mut app);
+        assert!(app.leftovers_scan_requested);
+        assert!(matches!(
+            app.modal,
+            crate::state::Modal::ConfigLeftovers { loading: true, .. }
+        ));
+        finish_leftovers_scan(&mut app, vec![leftover.clone()]);
+        match &app.modal {
+            crate::state::Modal::ConfigLeftovers {
+                entries, loading, ..
+            } => {
+                assert_eq!(entries, &vec![leftover]);
+                assert!(!loading);
+            }
+            other => panic!("unexpected modal {other:?}"),
+        }
+    }
+
+    #[test]
+    /// What: Ensure a finished view-settings copy toasts success or the clipboard hint.
+    ///
+    /// Inputs:
+    /// - A successful copy, then one failing with an install hint.
+    ///
+    /// Output:
+    /// - The localized "copied" toast, then the hint itself.
+    fn finish_view_settings_copy_toasts_outcome() {
+        let mut app = new_app();
+        finish_view_settings_copy(&mut app, Ok(()));
+        assert_eq!(
+            app.toast_message,
+            Some(crate::i18n::t(&app, "app.toasts.view_settings_copied"))
+        );
+        finish_view_settings_copy(&mut app, Err("install wl-clipboard".into()));
+        assert_eq!(app.toast_message.as_deref(), Some("install wl-clipboard"));
+        assert!(app.toast_expires_at.is_some());
+    }
+
+    #[test]
+    /// What: Ensure traced chains fill only a Why-installed dialog still waiting for them.
+    ///
+    /// Inputs:
+    /// - Chains for another package, then for the package the dialog shows.
+    ///
+    /// Output:
+    /// - The first is ignored; the second fills the chains and clears the loading state.
+    fn finish_why_installed_fills_matching_dialog() {
+        let mut app = new_app();
+        app.modal = crate::state::Modal::WhyInstalled {
+            package: "libfoo".into(),
+            chains: Vec::new(),
+            loading: true,
+        };
+        let chains = vec![vec!["libfoo".to_string(), "app".to_string()]];
+        finish_why_installed(&mut app, "other", chains.clone());
+        assert!(matches!(
+            app.modal,
+            crate::state::Modal::WhyInstalled { loading: true, .. }
+        ));
+        finish_why_installed(&mut app, "libfoo", chains.clone());
+        match &app.modal {
+            crate::state::Modal::WhyInstalled {
+                chains: got,
+                loading,
+                ..
+            } => {
+                assert_eq!(got, &chains);
+                assert!(!loading);
+            }
+            other => panic!("unexpected modal {other:?}"),
+        }
+    }
+
+    #[test]
+    /// What: Ensure a finished import opens the installed-state preview instead of adding directly.
+    ///
+    /// Inputs:
+    /// - A summary with one resolved package and one skipped name, then one with nothing resolved.
+    ///
+    /// Output:
+    /// - The first opens an appending import preview and toasts the skipped name, leaving the
+    ///   install list untouched; the second only toasts.
+    fn open_import_preview_shows_diff_before_adding() {
+        let mut app = new_app();
+        let item = crate::state::PackageItem {
+            name: "ripgrep".into(),
+            version: "14-1".into(),
+            description: String::new(),
+            source: crate::state::Source::Official {
+                repo: "extra".into(),
+                arch: "x86_64".into(),
+            },
+            popularity: None,
+        };
+        open_import_preview(
+            &mut app,
+            crate::logic::package_list::ImportSummary {
+                source: "packages.txt".into(),
+                items: vec![item.clone()],
+                diff: crate::logic::lists::InstalledDiff {
+                    to_install: vec![item],
+                    ..Default::default()
+                },
+                skipped: vec!["nope".into()],
+            },
+        );
+        match &app.modal {
+            crate::state::Modal::ProfileDiff {
+                name,
+                items,
+                append,
+                import,
+                ..
+            } => {
+                assert_eq!(name, "packages.txt");
+                assert_eq!(items.len(), 1);
+                assert!(*append && *import);
+            }
+            other => panic!("unexpected modal {other:?}"),
+        }
+        assert!(app.install_list.is_empty());
+        assert!(app.toast_message.is_some());
+
+        app.modal = crate::state::Modal::None;
+        app.toast_message = None;
+        open_import_preview(
+            &mut app,
+            crate::logic::package_list::ImportSummary {
+                source: "empty.txt".into(),
+                items: Vec::new(),
+                diff: crate::logic::lists::InstalledDiff::default(),
+                skipped: Vec::new(),
+            },
+        );
+        assert!(matches!(app.modal, crate::state::Modal::None));
+        assert!(app.toast_message.is_some());
+    }
+
+    #[test]
+    /// What: Ensure the orphan lookup is requested, not run, and that its outcome is reported.
+    ///
+    /// Inputs:
+    /// - The remove-orphans action, then a failed lookup, then an empty one.
+    ///
+    /// Output:
+    /// - The action only sets the request; the failure is recorded and toasted without touching
+    ///   the Remove list; the empty result toasts "no orphans".
+    fn orphans_lookup_runs_in_background_and_reports_failures() {
+        let mut app = new_app();
+        let (details_tx, _details_rx) = mpsc::unbounded_channel::<PackageItem>();
+        add_orphans_to_remove_list(&mut app);
+        assert!(app.orphans_requested);
+        assert!(app.remove_list.is_empty());
+
+        finish_orphans(&mut app, Err("database is locked".into()), &details_tx);
+        assert!(app.remove_list.is_empty());
+        assert_eq!(
+            app.toast_message,
+            Some(crate::i18n::t_fmt1(
+                &app,
+                "app.toasts.orphans_failed",
+                "database is locked"
+            ))
+        );
+        assert_eq!(app.error_log.len(), 1);
+
+        finish_orphans(&mut app, Ok(Vec::new()), &details_tx);
+        assert_eq!(
+            app.toast_message,
+            Some(crate::i18n::t(&app, "app.toasts.no_orphans_found"))
+        );
+    }
+
+    #[test]
+    /// What: Ensure a finished file lookup fills the waiting package files dialog.
+    ///
+    /// Inputs:
+    /// - The dialog opened for a selected result, then a result for another package, an error,
+    ///   and a file list.
+    ///
+    /// Output:
+    /// - Opening requests the lookup; the other package is ignored; the error is shown and
+    ///   recorded; a later list is ignored once the dialog stopped loading.
+    fn finish_package_files_fills_waiting_dialog() {
+        let mut app = new_app();
+        app.results = vec![crate::state::PackageItem {
+            name: "ripgrep".into(),
+            version: "14-1".into(),
+            description: String::new(),
+            source: crate::state::Source::Official {
+                repo: "extra".into(),
+                arch: "x86_64".into(),
+            },
+            popularity: None,
+        }];
+        open_package_files(&mut app);
+        assert_eq!(
+            app.package_files_request.as_ref().map(|p| p.name.as_str()),
+            Some("ripgrep")
+        );
+        finish_package_files(&mut app, "other", Ok(vec!["/usr/bin/other".into()]));
+        assert!(matches!(
+            app.modal,
+            crate::state::Modal::PackageFiles { loading: true, .. }
+        ));
+        finish_package_files(&mut app, "ripgrep", Err("no file database".into()));
+        finish_package_files(&mut app, "ripgrep", Ok(vec!["/usr/bin/rg".into()]));
+        match &app.modal {
+            crate::state::Modal::PackageFiles {
+                files,
+                error,
+                loading,
+                ..
+            } => {
+                assert!(files.is_empty());
+                assert_eq!(error.as_deref(), Some("no file database"));
+                assert!(!loading);
+            }
+            other => panic!("unexpected modal {other:?}"),
+        }
+    }
 }
@@ -0,0 +1,317 @@
+//! HTTP GET layer shared by `sources` and the Windows mirror/index fetchers.
+//!
+//! Requests go through the [`HttpClient`] trait. The default backend shells out to `curl`;
+//! building with the `reqwest-backend` cargo feature switches to an in-process `reqwest`
+//! client instead.
+
+use std::sync::{Arc, RwLock};
+
+use serde_json::Value;
+
+use crate::util::curl_command;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+/// Blocking HTTP GET backend.
+///
+/// Implementations are called from blocking tasks (`spawn_blocking`), never directly on the
+/// async runtime.
+pub trait HttpClient: Send + Sync {
+    /// What: Fetch a URL and return the body as text.
+    ///
+    /// Inputs:
+    /// - `url`: HTTP(S) endpoint.
+    ///
+    /// Output:
+    /// - `Ok(String)` with the UTF-8 body; `Err` on transport failures, HTTP error statuses, or
+    ///   invalid UTF-8.
+    fn get_text(&self, url: &str) -> Result<String>;
+
+    /// What: Fetch a URL and parse the body as JSON.
+    ///
+    /// Inputs:
+    /// - `url`: HTTP(S) endpoint expected to return JSON.
+    ///
+    /// Output:
+    /// - `Ok(Value)` on success; `Err` when the request fails or the body is not valid JSON.
+    fn get_json(&self, url: &str) -> Result<Value> {
+        Ok(serde_json::from_str(&self.get_text(url)?)?)
+    }
+
+    /// What: Fetch several JSON URLs, reusing one connection where the backend can.
+    ///
+    /// Inputs:
+    /// - `urls`: Endpoints to fetch, in order.
+    ///
+    /// Output:
+    /// - One result per URL, in the same order.
+    ///
+    /// Details:
+    /// - The default fetches sequentially through [`HttpClient::get_json`]; pooled clients
+    ///   keep the connection alive between calls on their own.
+    fn get_json_batch(&self, urls: &[String]) -> Vec<Result<Value>> {
+        urls.iter().map(|url| self.get_json(url)).collect()
+    }
+}
+
+/// Backend that runs the system `curl` binary.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CurlClient;
+
+impl HttpClient for CurlClient {
+    /// What: Run `curl` for `url` and return its stdout.
+    ///
+    /// Details:
+    /// - Uses [`curl_command`], so Windows keeps skipping certificate verification (`-k`).
+    /// - A missing binary surfaces as [`crate::util::CurlNotFound`].
+    fn get_text(&self, url: &str) -> Result<String> {
+        let out = curl_command(url, &[])
+            .output()
+            .map_err(crate::util::curl_spawn_error)?;
+        if !out.status.success() {
+            return Err(format!("curl failed for {url}: {:?}", out.status).into());
+        }
+        Ok(String::from_utf8(out.stdout)?)
+    }
+
+    /// What: Fetch all `urls` with a single `curl` process.
+    ///
+    /// Details:
+    /// - Curl keeps the connection (and TLS session) alive across the URLs of one invocation,
+    ///   saving a process spawn and handshake per request.
+    /// - Each body goes to its own file in a scratch directory; a URL whose file is missing
+    ///   failed (`-f` writes nothing on HTTP errors) and is reported individually.
+    fn get_json_batch(&self, urls: &[String]) -> Vec<Result<Value>> {
+        let Some(first) = urls.first() else {
+            return Vec::new();
+        };
+        let dir = std::env::temp_dir().join(format!(
+            "pacsea_curl_batch_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_nanos())
+                .unwrap_or(0)
+        ));
+        if let Err(e) = std::fs::create_dir_all(&dir) {
+            let msg = format!("Failed to create {}: {e}", dir.display());
+            return urls.iter().map(|_| Err(msg.clone().into())).collect();
+        }
+        let body_path = |i: usize| dir.join(i.to_string());
+        let first_out = body_path(0).to_string_lossy().into_owned();
+        let mut curl = curl_command(first, &["-o", &first_out]);
+        for (i, url) in urls.iter().enumerate().skip(1) {
+            curl.args.push("-o".to_string());
+            curl.args.push(body_path(i).to_string_lossy().into_owned());
+            curl.args.push(url.clone());
+        }
+        let results = match curl.output() {
+            Ok(_) => urls
+                .iter()
+                .enumerate()
+                .map(|(i, url)| match std::fs::read_to_string(body_path(i)) {
+                    Ok(body) => Ok(serde_json::from_str(&body)?),
+                    Err(_) => Err(format!("curl failed for {url}").into()),
+                })
+                .collect(),
+            Err(e) => urls
+                .iter()
+                .map(|_| {
+                    Err(crate::util::curl_spawn_error(std::io::Error::new(
+                        e.kind(),
+                        e.to_string(),
+                    )))
+                })
+                .collect(),
+        };
+        let _ = std::fs::remove_dir_all(&dir);
+        results
+    }
+}
+
+/// Backend using an in-process blocking `reqwest` client.
+#[cfg(feature = "reqwest-backend")]
+pub struct ReqwestClient {
+    /// Client shared by every request, so connections are pooled and kept alive, together
+    /// with the (`http`, `https`) proxies it was built for; `reqwest::blocking` must not be
+    /// constructed on the async runtime.
+    client: std::sync::Mutex<Option<(ProxyKey, reqwest::blocking::Client)>>,
+}
+
+/// Resolved (`http`, `https`) proxies a [`ReqwestClient`] client was built with.
+#[cfg(feature = "reqwest-backend")]
+type ProxyKey = (Option<String>, Option<String>);
+
+#[cfg(feature = "reqwest-backend")]
+impl ReqwestClient {
+    /// What: Create a backend whose client is built on first use.
+    pub const fn new() -> Self {
+        Self {
+            client: std::sync::Mutex::new(None),
+        }
+    }
+
+    /// What: Shared client, built with the same TLS and proxy policy as the curl backend.
+    ///
+    /// Details:
+    /// - Windows accepts invalid certificates, mirroring `curl -k` there.
+    /// - Proxies are resolved on every request; the client is rebuilt when they differ from
+    ///   the ones it was built with (e.g. after `settings.conf` changed).
+    fn client(&self) -> Result<reqwest::blocking::Client> {
+        let key: ProxyKey = (
+            crate::util::proxy_for("http://"),
+            crate::util::proxy_for("https://"),
+        );
+        let mut guard = self.client.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((built_for, client)) = guard.as_ref()
+            && *built_for == key
+        {
+            return Ok(client.clone());
+        }
+        let mut builder = reqwest::blocking::Client::builder()
+            .danger_accept_invalid_certs(cfg!(target_os = "windows"));
+        for proxy in crate::util::reqwest_proxies() {
+            builder = builder.proxy(proxy);
+        }
+        let client = builder.build()?;
+        *guard = Some((key, client.clone()));
+        Ok(client)
+    }
+}
+
+#[cfg(feature = "reqwest-backend")]
+impl Default for ReqwestClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "reqwest-backend")]
+impl HttpClient for ReqwestClient {
+    fn get_text(&self, url: &str) -> Result<String> {
+        let resp = self.client()?.get(url).send()?.error_for_status()?;
+        Ok(resp.text()?)
+    }
+}
+
+/// Backend compiled in as the default.
+#[cfg(not(feature = "reqwest-backend"))]
+static DEFAULT_CLIENT: CurlClient = CurlClient;
+
+/// Backend compiled in as the default.
+#[cfg(feature = "reqwest-backend")]
+static DEFAULT_CLIENT: ReqwestClient = ReqwestClient::new();
+
+/// Client installed with [`set_client`], taking precedence over the default backend.
+static OVERRIDE: RwLock<Option<Arc<dyn HttpClient>>> = RwLock::new(None);
+
+/// What: Run `f` with the active HTTP client.
+///
+/// Inputs:
+/// - `f`: Closure performing the request.
+///
+/// Output:
+/// - Whatever `f` returns.
+///
+/// Details:
+/// - Uses the client from [`set_client`] when one is installed, else the backend selected by
+///   the `reqwest-backend` feature.
+pub fn with_client<T>(f: impl FnOnce(&dyn HttpClient) -> T) -> T {
+    let installed = OVERRIDE.read().ok().and_then(|guard| guard.clone());
+    match installed {
+        Some(client) => f(client.as_ref()),
+        None => f(&DEFAULT_CLIENT),
+    }
+}
+
+/// What: Replace the HTTP client used by [`get_json`] and [`get_text`].
+///
+/// Inputs:
+/// - `client`: Client to install, or `None` to restore the compiled-in backend.
+///
+/// Details:
+/// - Intended for in-process mocking in tests; callers must serialize tests that rely on it.
+pub fn set_client(client: Option<Arc<dyn HttpClient>>) {
+    if let Ok(mut guard) = OVERRIDE.write() {
+        *guard = client;
+    }
+}
+
+/// What: Fetch JSON through the active HTTP client.
+pub fn get_json(url: &str) -> Result<Value> {
+    with_client(|client| client.get_json(url))
+}
+
+/// What: Fetch several JSON URLs through the active HTTP client.
+///
+/// Output:
+/// - One result per URL, in order; see [`HttpClient::get_json_batch`].
+pub fn get_json_batch(urls: &[String]) -> Vec<Result<Value>> {
+    with_client(|client| client.get_json_batch(urls))
+}
+
+/// What: Fetch text through the active HTTP client.
+pub fn get_text(url: &str) -> Result<String> {
+    with_client(|client| client.get_text(url))
+}
+
+/// What: Whether requests go through the `curl` binary.
+///
+/// Output:
+/// - `false` when a client was installed with [`set_client`] or the `reqwest-backend`
+///   feature is enabled.
+pub fn uses_curl() -> bool {
+    cfg!(not(feature = "reqwest-backend"))
+        && OVERRIDE.read().map(|guard| guard.is_none()).unwrap_or(true)
+}
+
+#[cfg(not(target_os = "windows"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Mock returning a canned body and recording requested URLs.
+    struct MockClient {
+        body: String,
+        urls: Mutex<Vec<String>>,
+    }
+
+    impl HttpClient for MockClient {
+        fn get_text(&self, url: &str) -> Result<String> {
+            self.urls.lock().unwrap().push(url.to_string());
+            Ok(self.body.clone())
+        }
+    }
+
+    #[test]
+    /// What: Verify sources fetch through an installed mock client instead of curl.
+    ///
+    /// Inputs:
+    /// - A mock returning an AUR RPC info response, then an AUR lookup for one name.
+    ///
+    /// Output:
+    /// - The item parsed from the mock body via the trait's default `get_json`, the RPC URL
+    ///   recorded, and the compiled-in backend restored afterwards.
+    fn http_mock_client_serves_sources() {
+        let _guard = crate::sources::test_mutex().lock().unwrap();
+        // The Windows index fetchers share the client but serialize on the index mutex
+        let _index_guard = crate::index::test_mutex().lock().unwrap();
+        let mock = Arc::new(MockClient {
+            body: r#"{"results":[{"Name":"mock-pkg","Version":"7-1"}]}"#.into(),
+            urls: Mutex::new(Vec::new()),
+        });
+        set_client(Some(mock.clone()));
+        assert!(!uses_curl());
+
+        let items = crate::sources::fetch_aur_items_blocking(&["mock-pkg".to_string()]);
+        set_client(None);
+        let items = items.unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].version, "7-1");
+        let urls = mock.urls.lock().unwrap();
+        assert_eq!(urls.len(), 1);
+        assert!(urls[0].starts_with("https://aur.archlinux.org/rpc/v5/info?"));
+        assert_eq!(uses_curl(), cfg!(not(feature = "reqwest-backend")));
+    }
+}
@@ -0,0 +1,136 @@
+//! Opt-in collector for translation keys missing from the active locale.
+//!
+//! Enabled by setting `PACSEA_I18N_AUDIT` (any value except empty or `0`). When disabled, each
+//! lookup pays only a relaxed atomic load.
+
+use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, Mutex};
+
+/// Whether missing keys are being recorded for this process.
+static AUDIT_ENABLED: LazyLock<AtomicBool> = LazyLock::new(|| {
+    let on = std::env::var("PACSEA_I18N_AUDIT")
+        .map(|v| !v.trim().is_empty() && v.trim() != "0")
+        .unwrap_or(false);
+    AtomicBool::new(on)
+});
+
+/// Sorted unique keys looked up but absent from the active locale.
+static MISSING_KEYS: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+
+/// What: Report whether the missing-key audit is active.
+///
+/// Output:
+/// - `true` when `PACSEA_I18N_AUDIT` enabled collection (or a test switched it on).
+pub fn audit_enabled() -> bool {
+    AUDIT_ENABLED.load(Ordering::Relaxed)
+}
+
+/// What: Record a key that the active locale does not translate.
+///
+/// Inputs:
+/// - `key`: Dot-notation translation key.
+///
+/// Output:
+/// - None; no-op when the audit is disabled.
+pub(crate) fn record_missing(key: &str) {
+    if !audit_enabled() {
+        return;
+    }
+    if let Ok(mut set) = MISSING_KEYS.lock()
+        && !set.contains(key)
+    {
+        set.insert(key.to_string());
+    }
+}
+
+/// What: Drain the recorded missing keys.
+///
+/// Output:
+/// - Sorted, de-duplicated keys collected since the last call.
+pub fn take_missing_keys() -> Vec<String> {
+    MISSING_KEYS
+        .lock()
+        .map(|mut set| std::mem::take(&mut *set).into_iter().collect())
+        .unwrap_or_default()
+}
+
+/// What: Write the collected missing keys to `logs_dir/missing_translations_<locale>.txt`.
+///
+/// Inputs:
+/// - `locale`: Active locale code used in the file name (e.g., `de-DE`).
+///
+/// Output:
+/// - None; does nothing when the audit is disabled or no keys were recorded.
+///
+/// Details:
+/// - Intended to run once on exit; the file is overwritten with one key per line.
+pub fn flush_missing_keys(locale: &str) {
+    if !audit_enabled() {
+        return;
+    }
+    let keys = take_missing_keys();
+    if keys.is_empty() {
+        return;
+    }
+    let locale = if locale.trim().is_empty() {
+        "unknown"
+    } else {
+        locale.trim()
+    };
+    let path = crate::theme::logs_dir().join(format!("missing_translations_{locale}.txt"));
+    match std::fs::write(&path, keys.join("\n") + "\n") {
+        Ok(()) => tracing::info!(
+            path = %path.display(),
+            count = keys.len(),
+            "wrote missing translation keys"
+        ),
+        Err(e) => tracing::warn!(
+            path = %path.display(),
+            error = %e,
+            "failed to write missing translation keys"
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure keys absent from the primary locale are collected once and drained sorted.
+    ///
+    /// Inputs:
+    /// - Audit switched on; lookups for a translated key, a fallback-only key (twice), and an unknown key.
+    ///
+    /// Output:
+    /// - `take_missing_keys` contains the fallback-only and unknown keys once each, not the translated one.
+    ///
+    /// Details:
+    /// - Other tests may record keys concurrently, so assertions check membership and ordering only.
+    fn audit_collects_keys_missing_from_primary_locale() {
+        AUDIT_ENABLED.store(true, Ordering::Relaxed);
+        let mut primary = crate::i18n::TranslationMap::new();
+        primary.insert("audit.test.present".to_string(), "Da".to_string());
+        let mut fallback = crate::i18n::TranslationMap::new();
+        fallback.insert("audit.test.present".to_string(), "Here".to_string());
+        fallback.insert("audit.test.fallback".to_string(), "Fallback".to_string());
+
+        for key in [
+            "audit.test.present",
+            "audit.test.fallback",
+            "audit.test.fallback",
+            "audit.test.unknown",
+        ] {
+            let _ = crate::i18n::translate_with_fallback(key, &primary, &fallback);
+        }
+
+        let keys = take_missing_keys();
+        let ours: Vec<&str> = keys
+            .iter()
+            .map(String::as_str)
+            .filter(|k| k.starts_with("audit.test."))
+            .collect();
+        assert_eq!(ours, vec!["audit.test.fallback", "audit.test.unknown"]);
+    }
+}
@@ -10,6 +10,7 @@
 //! - **Fallback Chain**: Supports locale fallbacks (e.g., `de-CH` -> `de-DE` -> `en-US`)
 //! - **Translation Loading**: Loads YAML locale files from `locales/` directory
 //! - **Translation Lookup**: Provides `t()`, `t_fmt()`, and `t_fmt1()` helpers for translation access
+//! - **Pluralization**: `tr_plural()` picks `key.one`/`key.few`/`key.many`/`key.other` by count
 //!
 //! # Locale Files
 //!
@@ -57,15 +58,21 @@
 //! - Missing locale files fall back to English automatically
 //! - Invalid locale codes in `settings.conf` trigger warnings and fallback to system/default
 //! - Missing translation keys return the key itself (for debugging) and log debug messages
+//! - Set `PACSEA_I18N_AUDIT=1` to collect keys missing from the active locale; they are written to
+//!   `logs/missing_translations_<locale>.txt` on exit
 //! - All errors are logged but do not crash the application
 
+mod audit;
 mod detection;
 mod loader;
+mod plural;
 mod resolver;
 pub mod translations;
 
+pub use audit::{audit_enabled, flush_missing_keys, take_missing_keys};
 pub use detection::detect_system_locale;
 pub use loader::{LocaleLoader, load_locale_file};
+pub use plural::{plural_category, select_plural};
 pub use resolver::{LocaleResolver, resolve_locale};
 pub use translations::{TranslationMap, translate, translate_with_fallback};
 
@@ -191,3 +198,27 @@ pub fn t_fmt(app: &crate::state::AppState, key: &str, args: &[&dyn std::fmt::Dis
 pub fn t_fmt1<T: std::fmt::Display>(app: &crate::state::AppState, key: &str, arg: T) -> String {
     t_fmt(app, key, &[&arg])
 }
+
+/// What: Get the plural form of a translation for a count and substitute the count.
+///
+/// Inputs:
+/// - `app`: AppState containing translation maps and the active locale
+/// - `key`: Base key whose children are plural categories (e.g., `app.toasts.orphans_added`)
+/// - `count`: Count selecting the form; also replaces the first `{}`
+///
+/// Output:
+/// - Formatted translated string, or the key itself if no form exists
+///
+/// Details:
+/// - Falls back to `key.other`, then to the English fallback map (see `select_plural`)
+pub fn tr_plural(app: &crate::state::AppState, key: &str, count: usize) -> String {
+    select_plural(
+        key,
+        count,
+        &app.locale,
+        &app.translations,
+        &app.translations_fallback,
+    )
+    .map(|s| s.replacen("{}", &count.to_string(), 1))
+    .unwrap_or_else(|| key.to_string())
+}
@@ -0,0 +1,167 @@
+//! Plural form selection for count-dependent translations.
+//!
+//! Plural strings are stored as nested YAML maps whose leaves are CLDR category names:
+//!
+//! ```yaml
+//! orphans_added:
+//!   one: "Added {} orphan to Remove List"
+//!   other: "Added {} orphans to Remove List"
+//! ```
+
+use super::translations::TranslationMap;
+
+/// What: Pick the CLDR plural category for a count in a given locale.
+///
+/// Inputs:
+/// - `locale`: Locale code (e.g., `en-US`, `ru-RU`, `pl-PL`).
+/// - `n`: Count being displayed.
+///
+/// Output:
+/// - One of `"one"`, `"few"`, `"many"`, or `"other"`.
+///
+/// Details:
+/// - Covers the integer rules for Germanic/Romance languages, French-style `0/1 -> one`,
+///   East/South Slavic, Polish, and Czech/Slovak; everything else uses the English rule.
+pub fn plural_category(locale: &str, n: usize) -> &'static str {
+    let lang = locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    let (m10, m100) = (n % 10, n % 100);
+    match lang.as_str() {
+        "ru" | "uk" | "be" | "sr" | "hr" | "bs" => {
+            if m10 == 1 && m100 != 11 {
+                "one"
+            } else if (2..=4).contains(&m10) && !(12..=14).contains(&m100) {
+                "few"
+            } else {
+                "many"
+            }
+        }
+        "pl" => {
+            if n == 1 {
+                "one"
+            } else if (2..=4).contains(&m10) && !(12..=14).contains(&m100) {
+                "few"
+            } else {
+                "many"
+            }
+        }
+        "cs" | "sk" => match n {
+            1 => "one",
+            2..=4 => "few",
+            _ => "other",
+        },
+        "fr" => {
+            if n <= 1 {
+                "one"
+            } else {
+                "other"
+            }
+        }
+        _ => {
+            if n == 1 {
+                "one"
+            } else {
+                "other"
+            }
+        }
+    }
+}
+
+/// What: Resolve the plural form of `key` for `count` from primary and fallback maps.
+///
+/// Inputs:
+/// - `key`: Base key without the category suffix (e.g., `app.toasts.orphans_added`).
+/// - `count`: Count that selects the form.
+/// - `locale`: Active locale used for the primary map's plural rules.
+/// - `translations`/`fallback_translations`: Active and fallback (English) maps.
+///
+/// Output:
+/// - `Some(template)` with the chosen form (still containing `{}`), or `None` if neither map
+///   defines any form for `key`.
+///
+/// Details:
+/// - Order: primary `key.<category>`, primary `key.other`, then the same pair in the fallback
+///   map using English rules.
+pub fn select_plural(
+    key: &str,
+    count: usize,
+    locale: &str,
+    translations: &TranslationMap,
+    fallback_translations: &TranslationMap,
+) -> Option<String> {
+    let lookup = |map: &TranslationMap, category: &str| {
+        map.get(&format!("{key}.{category}"))
+            .or_else(|| map.get(&format!("{key}.other")))
+            .cloned()
+    };
+    if let Some(s) = lookup(translations, plural_category(locale, count)) {
+        return Some(s);
+    }
+    crate::i18n::audit::record_missing(key);
+    lookup(fallback_translations, plural_category("en", count))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure plural selection returns distinct singular/plural forms and Slavic categories.
+    ///
+    /// Inputs:
+    /// - An English-style map with `one`/`other`, a Russian-style map with `one`/`few`/`many`,
+    ///   and an empty primary map with an English fallback.
+    ///
+    /// Output:
+    /// - Correct form per count; fallback map used when the primary lacks the key.
+    ///
+    /// Details:
+    /// - Covers the 11/21 and 12/22 edge cases of East Slavic rules.
+    fn select_plural_picks_locale_forms() {
+        let mut en = TranslationMap::new();
+        en.insert("k.pkgs.one".into(), "{} package".into());
+        en.insert("k.pkgs.other".into(), "{} packages".into());
+        let empty = TranslationMap::new();
+        assert_eq!(
+            select_plural("k.pkgs", 1, "en-US", &en, &empty).as_deref(),
+            Some("{} package")
+        );
+        assert_eq!(
+            select_plural("k.pkgs", 3, "en-US", &en, &empty).as_deref(),
+            Some("{} packages")
+        );
+        assert_eq!(
+            select_plural("k.pkgs", 0, "en-US", &en, &empty).as_deref(),
+            Some("{} packages")
+        );
+
+        let mut ru = TranslationMap::new();
+        ru.insert("k.pkgs.one".into(), "{} пакет".into());
+        ru.insert("k.pkgs.few".into(), "{} пакета".into());
+        ru.insert("k.pkgs.many".into(), "{} пакетов".into());
+        for (n, want) in [
+            (1, "{} пакет"),
+            (21, "{} пакет"),
+            (3, "{} пакета"),
+            (22, "{} пакета"),
+            (11, "{} пакетов"),
+            (12, "{} пакетов"),
+            (5, "{} пакетов"),
+        ] {
+            assert_eq!(
+                select_plural("k.pkgs", n, "ru-RU", &ru, &en).as_deref(),
+                Some(want),
+                "n={n}"
+            );
+        }
+
+        assert_eq!(
+            select_plural("k.pkgs", 2, "de-DE", &empty, &en).as_deref(),
+            Some("{} packages")
+        );
+        assert!(select_plural("k.none", 2, "en-US", &en, &en).is_none());
+    }
+}
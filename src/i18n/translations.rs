@@ -36,6 +36,7 @@ pub fn translate(key: &str, translations: &TranslationMap) -> Option<String> {
 /// - Falls back to English if not found
 /// - Returns key itself if neither has translation (for debugging)
 /// - Logs warnings for missing keys (only once per key to avoid spam)
+/// - Records keys absent from the primary map when the `PACSEA_I18N_AUDIT` collector is on
 pub fn translate_with_fallback(
     key: &str,
     translations: &TranslationMap,
@@ -46,6 +47,9 @@ pub fn translate_with_fallback(
         return translation.clone();
     }
 
+    // Primary locale lacks this key; note it for translators when auditing
+    crate::i18n::audit::record_missing(key);
+
     // Try fallback translations
     if let Some(translation) = fallback_translations.get(key) {
         // Log that we're using fallback (only at debug level to avoid spam)
@@ -0,0 +1,150 @@
+use std::collections::{HashMap, HashSet};
+
+use super::OfficialPkg;
+
+/// What: Summarize what an incremental index update changed.
+///
+/// Details:
+/// - Entries are identified by `(repo, name)`, the same key `fetch_official_pkg_names` dedupes on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IndexDiff {
+    /// Existing entries whose version changed.
+    pub changed: usize,
+    /// Entries that were not in the index before.
+    pub added: usize,
+    /// Entries no longer present in the fetched list.
+    pub removed: usize,
+}
+
+impl IndexDiff {
+    /// What: Whether the update left the index untouched.
+    pub fn is_empty(&self) -> bool {
+        self.changed == 0 && self.added == 0 && self.removed == 0
+    }
+}
+
+/// What: Apply freshly fetched entries to the index in place, touching only what changed.
+///
+/// Inputs:
+/// - `current`: Index entries to update (usually the in-memory `OfficialIndex::pkgs`)
+/// - `fetched`: Entries from `pacman -Sl` (name, repo, version set; arch/description empty)
+///
+/// Output:
+/// - Counts of changed, added, and removed entries.
+///
+/// Details:
+/// - Unchanged entries are left as-is, keeping enriched `arch`/`description`.
+/// - A changed entry only takes the new version; other fields are overwritten only when the
+///   fetched value is non-empty.
+/// - New entries are appended and the list is re-sorted by `(repo, name)` to match fetch order.
+pub fn apply_index_diff(current: &mut Vec<OfficialPkg>, fetched: Vec<OfficialPkg>) -> IndexDiff {
+    let mut diff = IndexDiff::default();
+    let fetched_keys: HashSet<(String, String)> = fetched
+        .iter()
+        .map(|p| (p.repo.clone(), p.name.clone()))
+        .collect();
+    let before = current.len();
+    current.retain(|p| fetched_keys.contains(&(p.repo.clone(), p.name.clone())));
+    diff.removed = before - current.len();
+
+    let positions: HashMap<(String, String), usize> = current
+        .iter()
+        .enumerate()
+        .map(|(i, p)| ((p.repo.clone(), p.name.clone()), i))
+        .collect();
+    let mut added: Vec<OfficialPkg> = Vec::new();
+    for p in fetched {
+        match positions.get(&(p.repo.clone(), p.name.clone())) {
+            Some(&i) => {
+                let old = &mut current[i];
+                if !p.version.is_empty() && p.version != old.version {
+                    old.version = p.version;
+                    if !p.arch.is_empty() {
+                        old.arch = p.arch;
+                    }
+                    if !p.description.is_empty() {
+                        old.description = p.description;
+                    }
+                    diff.changed += 1;
+                }
+            }
+            None => added.push(p),
+        }
+    }
+    diff.added = added.len();
+    if !added.is_empty() {
+        current.extend(added);
+        current.sort_by(|a, b| a.repo.cmp(&b.repo).then(a.name.cmp(&b.name)));
+        current.dedup_by(|a, b| a.repo == b.repo && a.name == b.name);
+    }
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// What: Build a test entry.
+    fn pkg(repo: &str, name: &str, version: &str, description: &str) -> OfficialPkg {
+        OfficialPkg {
+            name: name.to_string(),
+            repo: repo.to_string(),
+            arch: if description.is_empty() {
+                String::new()
+            } else {
+                "x86_64".to_string()
+            },
+            version: version.to_string(),
+            description: description.to_string(),
+        }
+    }
+
+    #[test]
+    /// What: Ensure the incremental update counts and applies only real differences.
+    ///
+    /// Inputs:
+    /// - An enriched index of three packages and a fetch where one version moved, one package
+    ///   disappeared, one is new, and `bar` moved from `extra` to `core`.
+    ///
+    /// Output:
+    /// - 1 changed, 2 added (`baz`, `core/bar`), 2 removed (`old`, `extra/bar`); enriched fields
+    ///   kept on updated entries; a second identical apply is empty.
+    fn apply_index_diff_updates_only_changes() {
+        let mut current = vec![
+            pkg("core", "foo", "1.0-1", "Foo tool"),
+            pkg("extra", "bar", "2.0-1", "Bar lib"),
+            pkg("extra", "old", "0.1-1", "Gone"),
+        ];
+        let fetched = vec![
+            pkg("core", "bar", "2.0-1", ""),
+            pkg("core", "foo", "1.1-1", ""),
+            pkg("extra", "baz", "3.0-1", ""),
+        ];
+        let diff = apply_index_diff(&mut current, fetched.clone());
+        assert_eq!(
+            diff,
+            IndexDiff {
+                changed: 1,
+                added: 2,
+                removed: 2
+            }
+        );
+        let keys: Vec<(&str, &str, &str)> = current
+            .iter()
+            .map(|p| (p.repo.as_str(), p.name.as_str(), p.version.as_str()))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                ("core", "bar", "2.0-1"),
+                ("core", "foo", "1.1-1"),
+                ("extra", "baz", "3.0-1")
+            ]
+        );
+        let foo = current.iter().find(|p| p.name == "foo").unwrap();
+        assert_eq!(foo.description, "Foo tool");
+        assert_eq!(foo.arch, "x86_64");
+
+        assert!(apply_index_diff(&mut current, fetched).is_empty());
+    }
+}
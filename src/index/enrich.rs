@@ -37,7 +37,9 @@ pub fn request_enrich_for(
         /// Details:
         /// - Propagates non-zero exit codes and UTF-8 decoding failures as boxed errors.
         fn run_pacman(args: &[&str]) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
-            let out = std::process::Command::new("pacman").args(args).output()?;
+            let out = std::process::Command::new(crate::install::privilege::pacman_bin())
+                .args(args)
+                .output()?;
             if !out.status.success() {
                 return Err(format!("pacman {:?} exited with {:?}", args, out.status).into());
             }
@@ -24,7 +24,7 @@ pub async fn refresh_explicit_cache() {
     /// Details:
     /// - Propagates non-zero exit codes and UTF-8 decoding failures as boxed errors.
     fn run_pacman_qe() -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
-        let out = std::process::Command::new("pacman")
+        let out = std::process::Command::new(crate::install::privilege::pacman_bin())
             .args(["-Qetq"]) // explicitly installed AND not required (leaf), names only
             .output()?;
         if !out.status.success() {
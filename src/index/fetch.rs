@@ -28,7 +28,9 @@ pub async fn fetch_official_pkg_names()
     /// Details:
     /// - Treats non-zero exit statuses and UTF-8 decoding failures as errors to be bubbled up.
     fn run_pacman(args: &[&str]) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
-        let out = std::process::Command::new("pacman").args(args).output()?;
+        let out = std::process::Command::new(crate::install::privilege::pacman_bin())
+            .args(args)
+            .output()?;
         if !out.status.success() {
             return Err(format!("pacman {:?} exited with {:?}", args, out.status).into());
         }
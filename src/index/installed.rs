@@ -1,40 +1,62 @@
 use super::installed_lock;
 
-/// What: Refresh the process-wide cache of installed package names using `pacman -Qq`.
+/// What: Refresh the process-wide cache of installed packages and versions using `pacman -Q`.
 ///
 /// Inputs:
 /// - None (spawns a blocking task to run pacman)
 ///
 /// Output:
-/// - Updates the global installed-name set; ignores errors.
+/// - Updates the global installed-package map; ignores errors.
 ///
 /// Details:
-/// - Parses command stdout into a `HashSet` and swaps it into the shared cache under a write lock.
+/// - Parses `name version` lines into a map and swaps it into the shared cache under a write
+///   lock; a line without a version still marks the package as installed.
 pub async fn refresh_installed_cache() {
-    /// What: Execute `pacman -Qq` and return the list of installed package names.
+    refresh_installed_cache_with(crate::install::privilege::pacman_bin()).await;
+}
+
+/// What: Refresh the installed-package cache from `<pacman> -Q`.
+///
+/// Inputs:
+/// - `pacman`: Binary to run in place of the configured pacman.
+///
+/// Output:
+/// - Updates the global installed-package map; ignores errors.
+pub(crate) async fn refresh_installed_cache_with(pacman: impl Into<std::ffi::OsString>) {
+    /// What: Execute `pacman -Q` and return the installed packages with their versions.
     ///
     /// Inputs:
-    /// - None (command line is fixed to `-Qq`).
+    /// - `pacman`: Binary to run (command line is fixed to `-Q`).
     ///
     /// Output:
     /// - `Ok(String)` with UTF-8 stdout on success; boxed error otherwise.
     ///
     /// Details:
     /// - Treats non-zero exit codes and UTF-8 decoding failures as errors to propagate.
-    fn run_pacman_q() -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
-        let out = std::process::Command::new("pacman")
-            .args(["-Qq"])
-            .output()?;
+    fn run_pacman_q(
+        pacman: &std::ffi::OsStr,
+    ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        let out = std::process::Command::new(pacman).args(["-Q"]).output()?;
         if !out.status.success() {
-            return Err(format!("pacman -Qq exited with {:?}", out.status).into());
+            return Err(format!("pacman -Q exited with {:?}", out.status).into());
         }
         Ok(String::from_utf8(out.stdout)?)
     }
-    if let Ok(Ok(body)) = tokio::task::spawn_blocking(run_pacman_q).await {
-        let set: std::collections::HashSet<String> =
-            body.lines().map(|s| s.trim().to_string()).collect();
+    let pacman = pacman.into();
+    if let Ok(Ok(body)) = tokio::task::spawn_blocking(move || run_pacman_q(&pacman)).await {
+        let map: std::collections::HashMap<String, String> = body
+            .lines()
+            .filter_map(|line| {
+                let mut parts = line.split_whitespace();
+                let name = parts.next()?;
+                Some((
+                    name.to_string(),
+                    parts.next().unwrap_or_default().to_string(),
+                ))
+            })
+            .collect();
         if let Ok(mut g) = installed_lock().write() {
-            *g = set;
+            *g = map;
         }
     }
 }
@@ -53,10 +75,100 @@ pub fn is_installed(name: &str) -> bool {
     installed_lock()
         .read()
         .ok()
-        .map(|s| s.contains(name))
+        .map(|s| s.contains_key(name))
         .unwrap_or(false)
 }
 
+/// What: Installed version of `name` from the cached `pacman -Q` output.
+///
+/// Inputs:
+/// - `name`: Package name
+///
+/// Output:
+/// - `Some(version)` when installed with a known version; `None` when not installed, the
+///   version is unknown, or the cache is unavailable.
+pub fn installed_version(name: &str) -> Option<String> {
+    installed_lock()
+        .read()
+        .ok()
+        .and_then(|s| s.get(name).filter(|v| !v.is_empty()).cloned())
+}
+
+/// Local package database holding one `<name>-<version>/desc` entry per installed package.
+const LOCAL_DB_DIR: &str = "/var/lib/pacman/local";
+
+/// Installed state of a package, used to tell whether a transaction touched it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstallStamp {
+    /// Installed version from the cached `pacman -Q` output.
+    pub version: String,
+    /// Modification time of the package's local database entry; pacman rewrites it on every
+    /// install, so a reinstall of the same version changes it too.
+    pub modified: Option<std::time::SystemTime>,
+}
+
+/// What: Current [`InstallStamp`] of `name`.
+///
+/// Output:
+/// - `None` when the package is not installed (per the cache) or its version is unknown.
+pub fn install_stamp(name: &str) -> Option<InstallStamp> {
+    install_stamp_in(
+        std::path::Path::new(LOCAL_DB_DIR),
+        name,
+        installed_version(name)?,
+    )
+}
+
+/// What: Build an [`InstallStamp`] from a local database directory.
+///
+/// Inputs:
+/// - `local_db`: Directory with `<name>-<version>/desc` entries.
+/// - `name`/`version`: Installed package and version.
+///
+/// Output:
+/// - The stamp; `modified` is `None` when the entry cannot be read.
+fn install_stamp_in(
+    local_db: &std::path::Path,
+    name: &str,
+    version: String,
+) -> Option<InstallStamp> {
+    let modified = std::fs::metadata(local_db.join(format!("{name}-{version}")).join("desc"))
+        .and_then(|m| m.modified())
+        .ok();
+    Some(InstallStamp { version, modified })
+}
+
+/// What: Snapshot the [`InstallStamp`] of each package before a transaction starts.
+///
+/// Output:
+/// - One entry per name; `None` for packages that are not installed yet.
+pub fn install_stamps(names: &[String]) -> std::collections::HashMap<String, Option<InstallStamp>> {
+    names
+        .iter()
+        .map(|name| (name.clone(), install_stamp(name)))
+        .collect()
+}
+
+/// What: Whether every package was (re)installed since `baseline` was taken.
+///
+/// Inputs:
+/// - `names`: Packages of the transaction.
+/// - `baseline`: [`install_stamps`] taken when it started.
+/// - `current`: Lookup of the current stamp (normally [`install_stamp`]).
+///
+/// Output:
+/// - `true` once each package is installed with a stamp that differs from its baseline, so
+///   reinstalls and upgrades of already-installed packages only count after pacman wrote them.
+pub fn installs_finished(
+    names: &[String],
+    baseline: &std::collections::HashMap<String, Option<InstallStamp>>,
+    current: impl Fn(&str) -> Option<InstallStamp>,
+) -> bool {
+    names.iter().all(|name| {
+        current(name).is_some_and(|now| baseline.get(name).and_then(Option::as_ref) != Some(&now))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     /// What: Return false when the cache is empty or the package is missing.
@@ -86,7 +198,7 @@ mod tests {
     /// - Insert `bar` into `INSTALLED_SET` before querying.
     ///
     /// Output:
-    /// - `true` for `bar` and `false` for `baz`.
+    /// - `true` for `bar` and `false` for `baz`; `bar`'s version is reported, `baz` has none.
     ///
     /// Details:
     /// - Exercises both positive and negative membership checks.
@@ -97,10 +209,12 @@ mod tests {
             .unwrap_or_else(|e| e.into_inner());
         if let Ok(mut g) = super::installed_lock().write() {
             g.clear();
-            g.insert("bar".to_string());
+            g.insert("bar".to_string(), "1.2-1".to_string());
         }
         assert!(super::is_installed("bar"));
         assert!(!super::is_installed("baz"));
+        assert_eq!(super::installed_version("bar").as_deref(), Some("1.2-1"));
+        assert_eq!(super::installed_version("baz"), None);
     }
 
     #[cfg(not(target_os = "windows"))]
@@ -155,8 +269,8 @@ mod tests {
         script.push("pacman");
         let body = r#"#!/usr/bin/env bash
 set -e
-if [[ "$1" == "-Qq" ]]; then
-  echo "alpha"
+if [[ "$1" == "-Q" ]]; then
+  echo "alpha 1.0-1"
   echo "beta"
   exit 0
 fi
@@ -182,5 +296,49 @@ exit 1
         assert!(super::is_installed("alpha"));
         assert!(super::is_installed("beta"));
         assert!(!super::is_installed("gamma"));
+        assert_eq!(super::installed_version("alpha").as_deref(), Some("1.0-1"));
+        assert_eq!(super::installed_version("beta"), None);
+    }
+
+    #[test]
+    /// What: Ensure an install only counts as finished once pacman wrote every package.
+    ///
+    /// Inputs:
+    /// - A fresh install, an already-installed package reinstalled at the same version, and a
+    ///   local database entry read through `install_stamp_in`.
+    ///
+    /// Output:
+    /// - Unchanged stamps (the state right after launch) are not finished; a newly installed
+    ///   package and a rewritten database entry are.
+    fn installs_finished_waits_for_changed_stamps() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("old-1.0-1")).unwrap();
+        std::fs::write(dir.path().join("old-1.0-1/desc"), "%NAME%\nold\n").unwrap();
+        let before = super::install_stamp_in(dir.path(), "old", "1.0-1".into()).unwrap();
+        assert!(before.modified.is_some());
+
+        let names = vec!["new".to_string(), "old".to_string()];
+        let baseline: std::collections::HashMap<_, _> = [
+            ("new".to_string(), None),
+            ("old".to_string(), Some(before.clone())),
+        ]
+        .into();
+        let installed = super::InstallStamp {
+            version: "2.0-1".into(),
+            modified: None,
+        };
+        assert!(!super::installs_finished(&names, &baseline, |_| None));
+        assert!(!super::installs_finished(&names, &baseline, |n| match n {
+            "old" => Some(before.clone()),
+            _ => Some(installed.clone()),
+        }));
+        let reinstalled = super::InstallStamp {
+            modified: Some(before.modified.unwrap() + std::time::Duration::from_secs(5)),
+            ..before.clone()
+        };
+        assert!(super::installs_finished(&names, &baseline, |n| match n {
+            "old" => Some(reinstalled.clone()),
+            _ => Some(installed.clone()),
+        }));
     }
 }
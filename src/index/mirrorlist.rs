@@ -0,0 +1,155 @@
+use std::sync::OnceLock;
+
+/// Location of the pacman mirrorlist consulted for download server resolution.
+const PACMAN_MIRRORLIST: &str = "/etc/pacman.d/mirrorlist";
+
+/// Process-wide cache of the first active `Server =` entry from the mirrorlist.
+static ACTIVE_SERVER: OnceLock<Option<String>> = OnceLock::new();
+
+/// What: Extract the active `Server =` templates from a pacman mirrorlist.
+///
+/// Inputs:
+/// - `content`: Raw mirrorlist text (e.g., `/etc/pacman.d/mirrorlist`).
+///
+/// Output:
+/// - Server templates in file order, still containing `$repo`/`$arch` placeholders.
+///
+/// Details:
+/// - Skips commented and blank lines; keys are matched case-insensitively like pacman does.
+pub fn parse_mirrorlist_servers(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .filter_map(|l| {
+            let (key, val) = l.split_once('=')?;
+            if key.trim().eq_ignore_ascii_case("server") {
+                let v = val.trim();
+                (!v.is_empty()).then(|| v.to_string())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// What: Substitute `$repo` and `$arch` in a mirrorlist server template.
+///
+/// Inputs:
+/// - `server`: Template such as `https://mirror.example/$repo/os/$arch`.
+/// - `repo`: Repository name (e.g., `extra`).
+/// - `arch`: Database architecture (e.g., `x86_64`).
+///
+/// Output:
+/// - Concrete repository base URL without a trailing slash.
+///
+/// Details:
+/// - Leaves templates without placeholders untouched apart from trimming trailing slashes.
+pub fn resolve_mirror_server(server: &str, repo: &str, arch: &str) -> String {
+    server
+        .replace("$repo", repo)
+        .replace("$arch", arch)
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// What: Build the URL pacman would download a package file from.
+///
+/// Inputs:
+/// - `server`: Mirrorlist server template.
+/// - `repo`: Repository name of the package.
+/// - `name`: Package name.
+/// - `version`: Full package version (`pkgver-pkgrel`, optionally with epoch).
+/// - `arch`: Package architecture (`x86_64`, `any`, ...).
+///
+/// Output:
+/// - Full URL to the `.pkg.tar.zst` archive on that mirror.
+///
+/// Details:
+/// - Packages built for `any` live in the host architecture's database directory, so `$arch`
+///   resolves to the running architecture for those.
+pub fn package_download_url(
+    server: &str,
+    repo: &str,
+    name: &str,
+    version: &str,
+    arch: &str,
+) -> String {
+    let db_arch = if arch.is_empty() || arch == "any" {
+        std::env::consts::ARCH
+    } else {
+        arch
+    };
+    let base = resolve_mirror_server(server, repo, db_arch);
+    format!("{base}/{name}-{version}-{arch}.pkg.tar.zst")
+}
+
+/// What: Return the first active server template from the system mirrorlist.
+///
+/// Inputs:
+/// - None (reads `/etc/pacman.d/mirrorlist` once per process)
+///
+/// Output:
+/// - `Some(template)` when a server line exists; `None` when the file is missing or empty.
+///
+/// Details:
+/// - pacman tries servers in order, so the first entry is the one a download would hit first.
+pub fn active_mirror_server() -> Option<String> {
+    ACTIVE_SERVER
+        .get_or_init(|| {
+            std::fs::read_to_string(PACMAN_MIRRORLIST)
+                .ok()
+                .and_then(|c| parse_mirrorlist_servers(&c).into_iter().next())
+        })
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    /// What: Ensure only uncommented `Server =` lines are collected from a mirrorlist.
+    ///
+    /// Inputs:
+    /// - Mirrorlist text with comments, a commented server, and two active servers.
+    ///
+    /// Output:
+    /// - The two active templates in order.
+    ///
+    /// Details:
+    /// - Guards the parser against treating `#Server` lines as active mirrors.
+    fn parse_mirrorlist_servers_skips_comments() {
+        let content = "## Worldwide\n#Server = https://off.example/$repo/os/$arch\nServer = https://a.example/$repo/os/$arch\n\nserver=https://b.example/$repo/os/$arch\n";
+        assert_eq!(
+            super::parse_mirrorlist_servers(content),
+            vec![
+                "https://a.example/$repo/os/$arch".to_string(),
+                "https://b.example/$repo/os/$arch".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    /// What: Verify the constructed download URL for a sample mirror and package.
+    ///
+    /// Inputs:
+    /// - Server template with `$repo`/`$arch` placeholders and an `extra` x86_64 package.
+    ///
+    /// Output:
+    /// - URL pointing at the package archive under the resolved repo path.
+    ///
+    /// Details:
+    /// - Also checks that a trailing slash on the template does not produce a double slash.
+    fn package_download_url_substitutes_repo_and_arch() {
+        let url = super::package_download_url(
+            "https://geo.mirror.pkgbuild.com/$repo/os/$arch/",
+            "extra",
+            "ripgrep",
+            "14.1.1-1",
+            "x86_64",
+        );
+        assert_eq!(
+            url,
+            "https://geo.mirror.pkgbuild.com/extra/os/x86_64/ripgrep-14.1.1-1-x86_64.pkg.tar.zst"
+        );
+    }
+}
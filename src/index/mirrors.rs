@@ -8,62 +8,18 @@ use tokio::task;
 /// to build the official package index by querying the public Arch Packages API.
 ///
 /// This module does not depend on `pacman` (which is typically unavailable on
-/// Windows). Instead, it calls out to `curl` to download JSON/text resources.
-/// Windows 10+ systems usually ship with a `curl` binary; if it's not present,
-/// the functions will return an error.
+/// Windows). Instead, it downloads JSON/text resources through [`crate::http`], which
+/// calls out to `curl` by default. Windows 10+ systems usually ship with a `curl`
+/// binary; if it's not present, the functions will return an error.
 ///
 /// Public entrypoints:
 /// - `fetch_mirrors_to_repo_dir(repo_dir)`
 /// - `refresh_official_index_from_arch_api(persist_path, net_err_tx, notify_tx)`
 /// - `refresh_windows_mirrors_and_index(persist_path, repo_dir, net_err_tx, notify_tx)`
 use super::{OfficialPkg, idx, save_to_disk};
-use crate::util::curl_args;
 
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
-/// What: Fetch a JSON payload via `curl` and deserialize it.
-///
-/// Inputs:
-/// - `url`: HTTP(S) endpoint expected to return JSON.
-///
-/// Output:
-/// - `Ok(serde_json::Value)` containing the parsed document; boxed error on failure.
-///
-/// Details:
-/// - Treats non-success exit codes and JSON/UTF-8 parsing failures as errors to propagate.
-/// - On Windows, uses `-k` flag to skip SSL certificate verification.
-fn curl_json(url: &str) -> Result<Value> {
-    let args = curl_args(url, &[]);
-    let out = std::process::Command::new("curl").args(&args).output()?;
-    if !out.status.success() {
-        return Err(format!("curl failed for {url}: {:?}", out.status).into());
-    }
-    let body = String::from_utf8(out.stdout)?;
-    let v: Value = serde_json::from_str(&body)?;
-    Ok(v)
-}
-
-/// What: Fetch a text payload via `curl`.
-///
-/// Inputs:
-/// - `url`: HTTP(S) endpoint expected to return text data.
-///
-/// Output:
-/// - `Ok(String)` containing UTF-8 text on success; boxed error otherwise.
-///
-/// Details:
-/// - Treats non-success exit codes and UTF-8 decoding failures as errors to propagate.
-/// - On Windows, uses `-k` flag to skip SSL certificate verification.
-#[allow(dead_code)]
-fn curl_text(url: &str) -> Result<String> {
-    let args = curl_args(url, &[]);
-    let out = std::process::Command::new("curl").args(&args).output()?;
-    if !out.status.success() {
-        return Err(format!("curl failed for {url}: {:?}", out.status).into());
-    }
-    Ok(String::from_utf8(out.stdout)?)
-}
-
 /// What: Download Arch mirror metadata and render a concise `mirrorlist.txt`.
 ///
 /// Inputs:
@@ -79,7 +35,7 @@ pub async fn fetch_mirrors_to_repo_dir(repo_dir: &Path) -> Result<PathBuf> {
     task::spawn_blocking(move || {
         fs::create_dir_all(&repo_dir)?;
         let status_url = "https://archlinux.org/mirrors/status/json/";
-        let json = curl_json(status_url)?;
+        let json = crate::http::get_json(status_url)?;
 
         // Persist the raw JSON for debugging/inspection
         let mirrors_json_path = repo_dir.join("mirrors.json");
@@ -130,6 +86,57 @@ pub async fn fetch_mirrors_to_repo_dir(repo_dir: &Path) -> Result<PathBuf> {
     .await?
 }
 
+/// Pages requested per `curl` invocation once the API reported the page count.
+const API_PAGE_BATCH: usize = 5;
+
+/// Pause between Arch Packages API requests (or batches) to respect the server's rate limits.
+const API_REQUEST_DELAY: std::time::Duration = std::time::Duration::from_millis(200);
+
+/// What: Append the packages of one Arch Packages API page to `pkgs`.
+///
+/// Inputs:
+/// - `page`: Parsed `packages/search/json` response.
+/// - `repo`, `arch`: Fallbacks for entries missing those fields.
+/// - `pkgs`: Collected packages.
+///
+/// Output:
+/// - Number of results on the page (including skipped nameless ones); `0` marks the end.
+fn push_api_results(page: &Value, repo: &str, arch: &str, pkgs: &mut Vec<OfficialPkg>) -> usize {
+    let results = page
+        .get("results")
+        .and_then(|x| x.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    for obj in results {
+        let field = |key: &str| {
+            obj.get(key)
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string()
+        };
+        let name = field("pkgname");
+        if name.is_empty() {
+            continue;
+        }
+        pkgs.push(OfficialPkg {
+            name,
+            repo: obj
+                .get("repo")
+                .and_then(|v| v.as_str())
+                .unwrap_or(repo)
+                .to_string(),
+            arch: obj
+                .get("arch")
+                .and_then(|v| v.as_str())
+                .unwrap_or(arch)
+                .to_string(),
+            version: field("pkgver"),
+            description: field("pkgdesc"),
+        });
+    }
+    results.len()
+}
+
 /// What: Build the official index via the Arch Packages JSON API and persist it.
 ///
 /// Inputs:
@@ -143,6 +150,10 @@ pub async fn fetch_mirrors_to_repo_dir(repo_dir: &Path) -> Result<PathBuf> {
 /// Details:
 /// - Pages through `core`, `extra`, and `multilib` results, dedupes by `(repo,name)`, and updates
 ///   the in-memory index before persisting.
+/// - When the first page reports `num_pages`, the remaining pages are fetched in batches of
+///   [`API_PAGE_BATCH`] through one `curl` process (or one pooled client with the reqwest
+///   backend); otherwise pages are walked until an empty one. Requests are spaced by
+///   [`API_REQUEST_DELAY`].
 pub async fn refresh_official_index_from_arch_api(
     persist_path: PathBuf,
     net_err_tx: tokio::sync::mpsc::UnboundedSender<String>,
@@ -153,65 +164,52 @@ pub async fn refresh_official_index_from_arch_api(
 
     let res = task::spawn_blocking(move || -> Result<Vec<OfficialPkg>> {
         let mut pkgs: Vec<OfficialPkg> = Vec::new();
+        let limit: usize = 250;
+        let mut requested = false;
+        // Pause before every request but the first to stay under the API rate limit
+        let mut pace = || {
+            if std::mem::replace(&mut requested, true) {
+                std::thread::sleep(API_REQUEST_DELAY);
+            }
+        };
         for repo in repos {
-            let mut page: usize = 1;
-            let limit: usize = 250;
-            loop {
-                let url = format!("https://archlinux.org/packages/search/json/?repo={repo}&arch={arch}&limit={limit}&page={page}");
-                let v = match curl_json(&url) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        // If a page fails, bubble the error up; no partial repo result
-                        return Err(format!("Failed to fetch package list for {repo}: {e}").into());
+            let page_url = |page: usize| {
+                format!("https://archlinux.org/packages/search/json/?repo={repo}&arch={arch}&limit={limit}&page={page}")
+            };
+            // If a page fails, bubble the error up; no partial repo result
+            let fail = |e| format!("Failed to fetch package list for {repo}: {e}");
+            pace();
+            let first = crate::http::get_json(&page_url(1)).map_err(fail)?;
+            if push_api_results(&first, repo, arch, &mut pkgs) == 0 {
+                continue;
+            }
+            match first.get("num_pages").and_then(Value::as_u64) {
+                Some(num_pages) => {
+                    // Page count known up front: fetch the rest in batches over one connection
+                    let urls: Vec<String> = (2..=num_pages as usize).map(page_url).collect();
+                    let per_batch = if crate::http::uses_curl() {
+                        API_PAGE_BATCH
+                    } else {
+                        1
+                    };
+                    for batch in urls.chunks(per_batch) {
+                        pace();
+                        for v in crate::http::get_json_batch(batch) {
+                            push_api_results(&v.map_err(fail)?, repo, arch, &mut pkgs);
+                        }
                     }
-                };
-                let results = v
-                    .get("results")
-                    .and_then(|x| x.as_array())
-                    .cloned()
-                    .unwrap_or_default();
-                if results.is_empty() {
-                    break;
                 }
-                for obj in results {
-                    let name = obj
-                        .get("pkgname")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or_default()
-                        .to_string();
-                    if name.is_empty() {
-                        continue;
+                None => {
+                    let mut page: usize = 2;
+                    loop {
+                        pace();
+                        let v = crate::http::get_json(&page_url(page)).map_err(fail)?;
+                        if push_api_results(&v, repo, arch, &mut pkgs) == 0 {
+                            break;
+                        }
+                        page += 1;
                     }
-                    let version = obj
-                        .get("pkgver")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or_default()
-                        .to_string();
-                    let description = obj
-                        .get("pkgdesc")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or_default()
-                        .to_string();
-                    let arch_val = obj
-                        .get("arch")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or(arch)
-                        .to_string();
-                    let repo_val = obj
-                        .get("repo")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or(repo)
-                        .to_string();
-
-                    pkgs.push(OfficialPkg {
-                        name,
-                        repo: repo_val,
-                        arch: arch_val,
-                        version,
-                        description,
-                    });
                 }
-                page += 1;
             }
         }
         // Sort and dedup by (repo, name)
@@ -275,6 +273,8 @@ pub async fn refresh_windows_mirrors_and_index(
 }
 
 #[cfg(test)]
+// Shims the `curl` binary on PATH, so only meaningful for the curl backend
+#[cfg(not(feature = "reqwest-backend"))]
 #[cfg(not(target_os = "windows"))]
 mod tests {
     use super::*;
@@ -489,6 +489,77 @@ exit 1
         let _ = std::fs::remove_file(&persist_path);
         let _ = std::fs::remove_dir_all(&shim_root);
     }
+
+    #[allow(clippy::await_holding_lock)]
+    #[tokio::test]
+    /// What: Ensure pages beyond the first are fetched in one batched curl call when the API
+    /// reports `num_pages`.
+    ///
+    /// Inputs:
+    /// - A fake `curl` that logs its arguments, answers single URLs on stdout and `-o FILE URL`
+    ///   pairs into files; `core` reports three pages, other repos are empty.
+    ///
+    /// Output:
+    /// - All three `core` packages indexed, with `core` fetched by exactly two curl processes.
+    async fn refresh_official_index_batches_known_pages() {
+        let _guard = crate::index::test_mutex().lock().unwrap();
+        if let Ok(mut g) = super::idx().write() {
+            g.pkgs.clear();
+        }
+        let dir = tempfile::tempdir().unwrap();
+        let calls = dir.path().join("calls.log");
+        let curl = dir.path().join("curl");
+        let script = format!(
+            r#"#!/usr/bin/env bash
+echo "$*" >> '{}'
+shift
+body() {{
+  case "$1" in
+    *repo=core*page=1) echo '{{"num_pages":3,"results":[{{"pkgname":"core-1","repo":"core"}}]}}' ;;
+    *repo=core*page=2) echo '{{"num_pages":3,"results":[{{"pkgname":"core-2","repo":"core"}}]}}' ;;
+    *repo=core*page=3) echo '{{"num_pages":3,"results":[{{"pkgname":"core-3","repo":"core"}}]}}' ;;
+    *) echo '{{"results":[]}}' ;;
+  esac
+}}
+if [[ "$1" == "-o" ]]; then
+  while [[ $# -ge 3 ]]; do body "$3" > "$2"; shift 3; done
+else
+  body "$1"
+fi
+"#,
+            calls.display()
+        );
+        std::fs::write(&curl, script).unwrap();
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&curl, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+        let old_path = std::env::var("PATH").unwrap_or_default();
+        unsafe { std::env::set_var("PATH", format!("{}:{old_path}", dir.path().display())) };
+
+        let (net_err_tx, mut net_err_rx) = mpsc::unbounded_channel::<String>();
+        let (notify_tx, mut notify_rx) = mpsc::unbounded_channel::<()>();
+        let persist_path = dir.path().join("index.json");
+        super::refresh_official_index_from_arch_api(persist_path, net_err_tx, notify_tx).await;
+        unsafe { std::env::set_var("PATH", &old_path) };
+
+        assert!(notify_rx.try_recv().is_ok());
+        assert!(net_err_rx.try_recv().is_err());
+        let mut names: Vec<String> = crate::index::all_official()
+            .into_iter()
+            .map(|p| p.name)
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["core-1", "core-2", "core-3"]);
+        let log = std::fs::read_to_string(&calls).unwrap();
+        let core_calls: Vec<&str> = log.lines().filter(|l| l.contains("repo=core")).collect();
+        assert_eq!(core_calls.len(), 2);
+        assert!(core_calls[1].contains("page=2") && core_calls[1].contains("page=3"));
+
+        if let Ok(mut g) = super::idx().write() {
+            g.pkgs.clear();
+        }
+    }
 }
 
 /// What: Download a repository sync database to disk for offline inspection.
@@ -510,7 +581,7 @@ pub async fn download_sync_db(repo_dir: &Path, repo: &str, arch: &str) -> Result
     let url = format!("{base}/{repo}/os/{arch}/{repo}.db");
     let out_path = repo_dir.join(format!("{repo}-{arch}.db"));
     let out_path_clone = out_path.clone();
-    let body = task::spawn_blocking(move || curl_text(&url)).await??;
+    let body = task::spawn_blocking(move || crate::http::get_text(&url)).await??;
     task::spawn_blocking(move || -> Result<()> {
         fs::create_dir_all(out_path_clone.parent().unwrap_or_else(|| Path::new(".")))?;
         let mut f = fs::File::create(&out_path_clone)?;
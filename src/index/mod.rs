@@ -3,7 +3,7 @@
 //! Split into submodules for maintainability. Public API is re-exported
 //! to remain compatible with previous `crate::index` consumers.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::{OnceLock, RwLock};
 
 /// What: Represent the full collection of official packages maintained in memory.
@@ -47,8 +47,8 @@ pub struct OfficialPkg {
 
 /// Process-wide holder for the official index state.
 static OFFICIAL_INDEX: OnceLock<RwLock<OfficialIndex>> = OnceLock::new();
-/// Process-wide set of installed package names.
-static INSTALLED_SET: OnceLock<RwLock<HashSet<String>>> = OnceLock::new();
+/// Process-wide map of installed package names to their installed versions.
+static INSTALLED_SET: OnceLock<RwLock<HashMap<String, String>>> = OnceLock::new();
 /// Process-wide set of explicitly-installed package names (dependency-free set).
 static EXPLICIT_SET: OnceLock<RwLock<HashSet<String>>> = OnceLock::new();
 
@@ -73,18 +73,18 @@ fn idx() -> &'static RwLock<OfficialIndex> {
     OFFICIAL_INDEX.get_or_init(|| RwLock::new(OfficialIndex { pkgs: Vec::new() }))
 }
 
-/// What: Access the process-wide lock protecting the installed-package name cache.
+/// What: Access the process-wide lock protecting the installed-package cache.
 ///
 /// Inputs:
 /// - None (initializes the `OnceLock` on-demand)
 ///
 /// Output:
-/// - `&'static RwLock<HashSet<String>>` with the cached installed-package names.
+/// - `&'static RwLock<HashMap<String, String>>` mapping installed names to their versions.
 ///
 /// Details:
-/// - Lazily creates the shared `HashSet` the first time it is requested; subsequent calls reuse it.
-fn installed_lock() -> &'static RwLock<HashSet<String>> {
-    INSTALLED_SET.get_or_init(|| RwLock::new(HashSet::new()))
+/// - Lazily creates the shared map the first time it is requested; subsequent calls reuse it.
+fn installed_lock() -> &'static RwLock<HashMap<String, String>> {
+    INSTALLED_SET.get_or_init(|| RwLock::new(HashMap::new()))
 }
 
 /// What: Access the process-wide lock protecting the explicit-package name cache.
@@ -101,26 +101,35 @@ fn explicit_lock() -> &'static RwLock<HashSet<String>> {
     EXPLICIT_SET.get_or_init(|| RwLock::new(HashSet::new()))
 }
 
+mod diff;
 mod enrich;
 mod explicit;
 mod fetch;
 mod installed;
+mod mirrorlist;
+mod orphans;
 mod persist;
 mod query;
+mod rank;
 
 #[cfg(windows)]
 mod mirrors;
 mod update;
 
+pub use diff::*;
 pub use enrich::*;
 pub use explicit::*;
 pub use installed::*;
+pub use mirrorlist::*;
 #[cfg(windows)]
 pub use mirrors::*;
+pub use orphans::*;
 pub use persist::*;
 pub use query::*;
+pub use rank::*;
+pub use update::index_refresh_due;
 #[cfg(not(windows))]
-pub use update::update_in_background;
+pub use update::{refresh_official_index, update_in_background};
 
 #[cfg(test)]
 static TEST_MUTEX: OnceLock<std::sync::Mutex<()>> = OnceLock::new();
@@ -0,0 +1,150 @@
+/// What: List installed packages that were pulled in as dependencies but are no longer required.
+///
+/// Inputs:
+/// - None (runs `pacman -Qtdq` synchronously)
+///
+/// Output:
+/// - `Ok` with the sorted orphan package names (empty when there are none); `Err` with a reason
+///   when pacman cannot be run or fails.
+///
+/// Details:
+/// - `pacman -Qtdq` exits with status 1 and prints nothing when no orphans exist; that case is
+///   treated as "empty, not error".
+/// - A non-zero exit with an error message (e.g. a locked or broken database) is an error.
+/// - Blocks until pacman exits; the UI runs it off the event loop.
+pub fn find_orphans() -> Result<Vec<String>, String> {
+    let out = std::process::Command::new(crate::install::privilege::pacman_bin())
+        .args(["-Qtdq"]) // unrequired (-t) dependencies (-d), names only (-q)
+        .output()
+        .map_err(|e| {
+            tracing::warn!(error = %e, "failed to run pacman -Qtdq");
+            format!("pacman -Qtdq: {e}")
+        })?;
+    let body = String::from_utf8_lossy(&out.stdout);
+    if !out.status.success() {
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        // No orphans: pacman reports a non-zero status without any output
+        if body.trim().is_empty() && stderr.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        tracing::warn!(status = ?out.status, "pacman -Qtdq exited with an error");
+        return Err(if stderr.trim().is_empty() {
+            format!("pacman -Qtdq exited with {}", out.status)
+        } else {
+            stderr.trim().to_string()
+        });
+    }
+    let mut names: Vec<String> = body
+        .lines()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+    names.sort();
+    names.dedup();
+    Ok(names)
+}
+
+#[cfg(all(test, not(target_os = "windows")))]
+mod tests {
+    /// What: Install a fake `pacman` script on PATH for the duration of a test.
+    ///
+    /// Inputs:
+    /// - `body`: Shell script body to write as the fake `pacman`.
+    ///
+    /// Output:
+    /// - Tuple of the temp root (for cleanup) and the original PATH value.
+    ///
+    /// Details:
+    /// - Callers must restore PATH and remove the root directory afterwards.
+    fn install_fake_pacman(body: &str) -> (std::path::PathBuf, String) {
+        use std::os::unix::fs::PermissionsExt;
+        let original_path = std::env::var("PATH").unwrap_or_default();
+        let mut root = std::env::temp_dir();
+        root.push(format!(
+            "pacsea_fake_pacman_qtdq_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let bin = root.join("bin");
+        std::fs::create_dir_all(&bin).unwrap();
+        let script = bin.join("pacman");
+        std::fs::write(&script, body).unwrap();
+        let mut perm = std::fs::metadata(&script).unwrap().permissions();
+        perm.set_mode(0o755);
+        std::fs::set_permissions(&script, perm).unwrap();
+        unsafe {
+            std::env::set_var("PATH", format!("{}:{}", bin.display(), original_path));
+        }
+        (root, original_path)
+    }
+
+    #[test]
+    /// What: Parse orphan names from pacman output.
+    ///
+    /// Inputs:
+    /// - Fake pacman that prints two unsorted names for `-Qtdq`.
+    ///
+    /// Output:
+    /// - Sorted names returned by `find_orphans`.
+    ///
+    /// Details:
+    /// - Restores PATH before asserting so failures do not leak the fake binary.
+    fn find_orphans_parses_pacman_output() {
+        let _guard = crate::index::test_mutex()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let (root, original_path) = install_fake_pacman(
+            "#!/usr/bin/env bash\nif [[ \"$1\" == \"-Qtdq\" ]]; then\n  echo zeta\n  echo alpha\n  exit 0\nfi\nexit 2\n",
+        );
+        let names = super::find_orphans();
+        unsafe { std::env::set_var("PATH", &original_path) };
+        let _ = std::fs::remove_dir_all(&root);
+        assert_eq!(names, Ok(vec!["alpha".to_string(), "zeta".to_string()]));
+    }
+
+    #[test]
+    /// What: Treat pacman's non-zero exit with no output as "no orphans".
+    ///
+    /// Inputs:
+    /// - Fake pacman that prints nothing and exits with status 1.
+    ///
+    /// Output:
+    /// - Empty vector rather than an error.
+    ///
+    /// Details:
+    /// - Mirrors real pacman behaviour when the system has no orphaned dependencies.
+    fn find_orphans_empty_on_no_orphans_exit_code() {
+        let _guard = crate::index::test_mutex()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let (root, original_path) = install_fake_pacman("#!/usr/bin/env bash\nexit 1\n");
+        let names = super::find_orphans();
+        unsafe { std::env::set_var("PATH", &original_path) };
+        let _ = std::fs::remove_dir_all(&root);
+        assert_eq!(names, Ok(Vec::new()));
+    }
+
+    #[test]
+    /// What: Report pacman failures instead of treating them as "no orphans".
+    ///
+    /// Inputs:
+    /// - Fake pacman that prints an error to stderr and exits with status 1.
+    ///
+    /// Output:
+    /// - `Err` carrying pacman's message.
+    fn find_orphans_reports_pacman_errors() {
+        let _guard = crate::index::test_mutex()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let (root, original_path) = install_fake_pacman(
+            "#!/usr/bin/env bash\necho 'error: could not open database' >&2\nexit 1\n",
+        );
+        let names = super::find_orphans();
+        unsafe { std::env::set_var("PATH", &original_path) };
+        let _ = std::fs::remove_dir_all(&root);
+        assert_eq!(names, Err("error: could not open database".to_string()));
+    }
+}
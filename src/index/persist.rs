@@ -1,21 +1,80 @@
 use std::fs;
-use std::path::Path;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use super::{OfficialIndex, idx};
 
+/// Whether `save_to_disk` writes the gzip-compressed variant (`compress_index` setting).
+static COMPRESS_INDEX: AtomicBool = AtomicBool::new(false);
+
+/// Leading bytes of every gzip stream.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// What: Choose whether the official index is persisted gzip-compressed.
+///
+/// Inputs:
+/// - `enabled`: Value of the `compress_index` setting
+///
+/// Details:
+/// - Applied once at startup; affects subsequent `save_to_disk` calls.
+pub fn set_compress_index(enabled: bool) {
+    COMPRESS_INDEX.store(enabled, Ordering::Relaxed);
+}
+
+/// What: Path of the compressed variant of an index file.
+///
+/// Inputs:
+/// - `path`: Plain index path such as `official_index.json`
+///
+/// Output:
+/// - The same path with `.gz` appended (`official_index.json.gz`).
+pub fn compressed_index_path(path: &Path) -> PathBuf {
+    let mut os = path.as_os_str().to_owned();
+    os.push(".gz");
+    PathBuf::from(os)
+}
+
+/// What: Read an index file, transparently decompressing gzip content.
+///
+/// Inputs:
+/// - `path`: File to read
+///
+/// Output:
+/// - The JSON text, or `None` when the file is missing, unreadable, or corrupt.
+///
+/// Details:
+/// - Detects compression by the gzip magic bytes rather than the extension.
+fn read_index_text(path: &Path) -> Option<String> {
+    let bytes = fs::read(path).ok()?;
+    if bytes.starts_with(&GZIP_MAGIC) {
+        let mut text = String::new();
+        flate2::read::GzDecoder::new(bytes.as_slice())
+            .read_to_string(&mut text)
+            .ok()?;
+        Some(text)
+    } else {
+        String::from_utf8(bytes).ok()
+    }
+}
+
 /// What: Load the official index from `path` if a valid JSON exists.
 ///
 /// Inputs:
-/// - `path`: File path to read JSON from
+/// - `path`: Plain JSON index path; its `.gz` sibling is tried first
 ///
 /// Output:
 /// - Replaces the in-memory index on success; ignores errors and leaves it unchanged on failure.
 ///
 /// Details:
+/// - Falls back to the plain file when the `.gz` is absent or unreadable, so indexes written
+///   before `compress_index` was enabled keep loading.
 /// - Silently ignores IO or deserialization failures to keep startup resilient.
 pub fn load_from_disk(path: &Path) {
-    if let Ok(s) = fs::read_to_string(path)
-        && let Ok(new_idx) = serde_json::from_str::<OfficialIndex>(&s)
+    let parsed = [compressed_index_path(path), path.to_path_buf()]
+        .iter()
+        .find_map(|p| serde_json::from_str::<OfficialIndex>(&read_index_text(p)?).ok());
+    if let Some(new_idx) = parsed
         && let Ok(mut guard) = idx().write()
     {
         *guard = new_idx;
@@ -25,21 +84,67 @@ pub fn load_from_disk(path: &Path) {
 /// What: Persist the current official index to `path` as JSON.
 ///
 /// Inputs:
-/// - `path`: File path to write JSON to
+/// - `path`: Plain JSON index path
 ///
 /// Output:
 /// - Writes JSON to disk; errors are ignored to avoid interrupting the UI.
 ///
 /// Details:
+/// - With `compress_index` enabled the data goes to the `.gz` sibling and the plain file is
+///   removed; otherwise the plain file is written and a stale `.gz` removed, so loading never
+///   prefers an outdated copy.
 /// - Serializes under a read lock and drops any write failures to avoid crashing background tasks.
 pub fn save_to_disk(path: &Path) {
-    if let Ok(guard) = idx().read()
-        && let Ok(s) = serde_json::to_string(&*guard)
-    {
-        let _ = fs::write(path, s);
+    save_to_disk_as(path, COMPRESS_INDEX.load(Ordering::Relaxed));
+}
+
+/// What: Persist the current official index, compressed or not.
+///
+/// Inputs:
+/// - `path`: Plain JSON index path
+/// - `compress`: Write the `.gz` sibling instead of the plain file
+fn save_to_disk_as(path: &Path, compress: bool) {
+    let Some(s) = idx()
+        .read()
+        .ok()
+        .and_then(|guard| serde_json::to_string(&*guard).ok())
+    else {
+        return;
+    };
+    let gz_path = compressed_index_path(path);
+    if compress {
+        let mut enc = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        if enc.write_all(s.as_bytes()).is_ok()
+            && let Ok(bytes) = enc.finish()
+            && fs::write(&gz_path, bytes).is_ok()
+        {
+            let _ = fs::remove_file(path);
+        }
+    } else if fs::write(path, s).is_ok() {
+        let _ = fs::remove_file(&gz_path);
     }
 }
 
+/// What: Report when the persisted official index was last written.
+///
+/// Inputs:
+/// - `path`: Index file path (normally `AppState::official_index_path`)
+///
+/// Output:
+/// - Modification time of the `.gz` variant or, if absent, the plain file; `None` when neither
+///   exists or the mtime is unreadable.
+///
+/// Details:
+/// - Read when the index loads or is saved and cached in `AppState::index_modified_at`; the
+///   freshness indicator next to the Arch status label only formats the age from it.
+pub fn index_file_modified(path: &Path) -> Option<std::time::SystemTime> {
+    fs::metadata(compressed_index_path(path))
+        .or_else(|_| fs::metadata(path))
+        .ok()?
+        .modified()
+        .ok()
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -133,4 +238,77 @@ mod tests {
         assert!(body.contains("\"abc\""));
         let _ = std::fs::remove_file(&path);
     }
+
+    #[tokio::test]
+    #[allow(clippy::await_holding_lock)]
+    /// What: Round-trip a small index through compressed save and load.
+    ///
+    /// Inputs:
+    /// - A one-package index saved with compression on, then with it off.
+    ///
+    /// Output:
+    /// - A gzip `.gz` file (plain file removed) that loads back the package; saving uncompressed
+    ///   restores the plain file and drops the `.gz`.
+    ///
+    /// Details:
+    /// - Passes the compression choice directly so the global `compress_index` flag stays off
+    ///   for concurrently running tests.
+    async fn index_compressed_save_and_load_round_trip() {
+        let _guard = crate::index::test_mutex().lock().unwrap();
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("official_index.json");
+        let gz = super::compressed_index_path(&path);
+        std::fs::write(&path, "{\"pkgs\":[]}").expect("write stale plain");
+        let seed = || {
+            if let Ok(mut g) = super::idx().write() {
+                g.pkgs = vec![crate::index::OfficialPkg {
+                    name: "gzpkg".to_string(),
+                    repo: "extra".to_string(),
+                    arch: "x86_64".to_string(),
+                    version: "1.0-1".to_string(),
+                    description: "compressed".to_string(),
+                }];
+            }
+        };
+
+        seed();
+        super::save_to_disk_as(&path, true);
+        let bytes = std::fs::read(&gz).expect("gz written");
+        assert!(bytes.starts_with(&super::GZIP_MAGIC));
+        assert!(!path.exists());
+
+        if let Ok(mut g) = super::idx().write() {
+            g.pkgs.clear();
+        }
+        super::load_from_disk(&path);
+        let names: Vec<String> = crate::index::all_official()
+            .into_iter()
+            .map(|p| p.name)
+            .collect();
+        assert_eq!(names, vec!["gzpkg"]);
+
+        super::save_to_disk_as(&path, false);
+        assert!(path.exists());
+        assert!(!gz.exists());
+    }
+
+    #[test]
+    /// What: Ensure the index write time is read from the file's modification time.
+    ///
+    /// Inputs:
+    /// - A freshly written temp file and a path that does not exist.
+    ///
+    /// Output:
+    /// - A recent mtime for the new file; `None` for the missing one.
+    fn index_file_modified_reads_mtime() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("official_index.json");
+        assert!(super::index_file_modified(&path).is_none());
+        std::fs::write(&path, "{}").expect("write");
+        let modified = super::index_file_modified(&path).expect("mtime of written file");
+        let age = std::time::SystemTime::now()
+            .duration_since(modified)
+            .unwrap_or_default();
+        assert!(age < std::time::Duration::from_secs(60));
+    }
 }
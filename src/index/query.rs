@@ -13,17 +13,22 @@ use super::idx;
 ///
 /// Details:
 /// - Performs a case-insensitive substring match on package names and clones matching entries.
+/// - A `/.../` query matches names against the regex instead (literal body when invalid).
 pub fn search_official(query: &str) -> Vec<PackageItem> {
     let ql = query.trim().to_lowercase();
     if ql.is_empty() {
         return Vec::new();
     }
+    let regex_body = crate::logic::filter::regex_body(query.trim());
     let guard = idx().read().ok();
     let mut items = Vec::new();
     if let Some(g) = guard {
         for p in &g.pkgs {
-            let nl = p.name.to_lowercase();
-            if nl.contains(&ql) {
+            let hit = match regex_body {
+                Some(body) => crate::logic::filter::regex_match(&p.name, body).is_some(),
+                None => p.name.to_lowercase().contains(&ql),
+            };
+            if hit {
                 items.push(PackageItem {
                     name: p.name.clone(),
                     version: p.version.clone(),
@@ -40,6 +45,30 @@ pub fn search_official(query: &str) -> Vec<PackageItem> {
     items
 }
 
+/// What: Look up one official package by exact name.
+///
+/// Inputs:
+/// - `name`: Package name (case-insensitive).
+///
+/// Output:
+/// - The matching entry as a `PackageItem`, or `None` when the index does not contain it.
+pub fn find_official(name: &str) -> Option<PackageItem> {
+    let g = idx().read().ok()?;
+    g.pkgs
+        .iter()
+        .find(|p| p.name.eq_ignore_ascii_case(name))
+        .map(|p| PackageItem {
+            name: p.name.clone(),
+            version: p.version.clone(),
+            description: p.description.clone(),
+            source: Source::Official {
+                repo: p.repo.clone(),
+                arch: p.arch.clone(),
+            },
+            popularity: None,
+        })
+}
+
 /// What: Return the entire official index as a list of `PackageItem`s.
 ///
 /// Inputs:
@@ -0,0 +1,178 @@
+//! Cross-platform mirror ranking: `reflector` on Linux, the Arch mirror status API on Windows.
+
+use std::sync::Mutex;
+
+/// System mirrorlist the ranked result replaces on Linux.
+pub const SYSTEM_MIRRORLIST: &str = "/etc/pacman.d/mirrorlist";
+
+/// Why mirror ranking failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RankError {
+    /// `reflector` is not installed (Linux only).
+    ReflectorMissing,
+    /// The ranking tool or download failed.
+    Failed(String),
+}
+
+/// Ranked mirrorlist ready for preview.
+#[derive(Debug, Clone)]
+pub struct RankedMirrors {
+    /// Full mirrorlist text as it would be written.
+    pub content: String,
+    /// Active `Server =` templates in ranked order.
+    pub servers: Vec<String>,
+}
+
+/// Result of the last background ranking, drained by the UI tick.
+static LAST_RANK: Mutex<Option<Result<RankedMirrors, RankError>>> = Mutex::new(None);
+
+/// What: Build the `reflector` arguments for a ranking run.
+///
+/// Inputs:
+/// - `countries`: Comma-separated country names from `selected_countries`; empty or `Worldwide`
+///   ranks mirrors from every country.
+/// - `count`: Number of mirrors to keep (`mirror_count`).
+///
+/// Output:
+/// - Arguments printing the ranked list to stdout (no `--save`).
+pub fn reflector_args(countries: &str, count: u16) -> Vec<String> {
+    let mut args: Vec<String> = vec![
+        "--protocol".into(),
+        "https".into(),
+        "--sort".into(),
+        "rate".into(),
+        "--latest".into(),
+        count.max(1).to_string(),
+        "--download-timeout".into(),
+        "6".into(),
+    ];
+    let countries = countries.trim();
+    if !countries.is_empty() && !countries.eq_ignore_ascii_case("worldwide") {
+        args.push("--country".into());
+        args.push(countries.to_string());
+    }
+    args
+}
+
+/// What: Rank mirrors and return the resulting mirrorlist without writing it system-wide.
+///
+/// Inputs:
+/// - `countries`: Comma-separated country names (`selected_countries`).
+/// - `count`: Number of mirrors to keep (`mirror_count`).
+///
+/// Output:
+/// - The ranked mirrorlist, or [`RankError::ReflectorMissing`] / [`RankError::Failed`].
+///
+/// Details:
+/// - Linux runs `reflector` with [`reflector_args`]. Windows reuses
+///   [`super::fetch_mirrors_to_repo_dir`], which already writes `repository/mirrorlist.txt`;
+///   the country filter does not apply there.
+pub async fn rank_mirrors(countries: &str, count: u16) -> Result<RankedMirrors, RankError> {
+    #[cfg(not(windows))]
+    let content = {
+        let args = reflector_args(countries, count);
+        let out = tokio::task::spawn_blocking(move || {
+            std::process::Command::new("reflector")
+                .args(&args)
+                .env("LC_ALL", "C")
+                .output()
+        })
+        .await
+        .map_err(|e| RankError::Failed(e.to_string()))?
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => RankError::ReflectorMissing,
+            _ => RankError::Failed(e.to_string()),
+        })?;
+        if !out.status.success() {
+            let err = String::from_utf8_lossy(&out.stderr);
+            return Err(RankError::Failed(
+                err.lines().last().unwrap_or("reflector failed").to_string(),
+            ));
+        }
+        String::from_utf8_lossy(&out.stdout).into_owned()
+    };
+    #[cfg(windows)]
+    let content = {
+        let _ = (countries, count);
+        let repo_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("repository");
+        let path = super::fetch_mirrors_to_repo_dir(&repo_dir)
+            .await
+            .map_err(|e| RankError::Failed(e.to_string()))?;
+        std::fs::read_to_string(path).map_err(|e| RankError::Failed(e.to_string()))?
+    };
+    let servers = super::parse_mirrorlist_servers(&content);
+    if servers.is_empty() {
+        return Err(RankError::Failed("no mirrors returned".to_string()));
+    }
+    Ok(RankedMirrors { content, servers })
+}
+
+/// What: Rank mirrors on a background thread and post the result for the UI.
+///
+/// Inputs:
+/// - `countries`, `count`: Passed to [`rank_mirrors`].
+///
+/// Details:
+/// - The result is picked up with [`take_rank_result`] on the next UI tick.
+pub fn spawn_rank_mirrors(countries: String, count: u16) {
+    std::thread::spawn(move || {
+        let res = match tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+        {
+            Ok(rt) => rt.block_on(rank_mirrors(&countries, count)),
+            Err(e) => Err(RankError::Failed(e.to_string())),
+        };
+        if let Ok(mut g) = LAST_RANK.lock() {
+            *g = Some(res);
+        }
+    });
+}
+
+/// What: Take the finished background ranking, if any.
+pub fn take_rank_result() -> Option<Result<RankedMirrors, RankError>> {
+    LAST_RANK.lock().ok().and_then(|mut g| g.take())
+}
+
+/// What: Shell command that installs a ranked mirrorlist staged at `staged`.
+///
+/// Output:
+/// - Commands run through the configured escalation tool that back up the current list to
+///   `mirrorlist.bak` and copy the new one into place.
+pub fn install_mirrorlist_command(staged: &std::path::Path) -> String {
+    let root = crate::install::privilege::privilege_tool().binary();
+    let staged = crate::install::shell_single_quote(&staged.display().to_string());
+    format!(
+        "{root} cp -- {SYSTEM_MIRRORLIST} {SYSTEM_MIRRORLIST}.bak && {root} install -m 644 -- {staged} {SYSTEM_MIRRORLIST} && echo 'Mirrorlist updated.'"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure reflector arguments honour the country setting and mirror count.
+    ///
+    /// Inputs:
+    /// - `Worldwide` with count 0, then `Germany,France` with count 20.
+    ///
+    /// Output:
+    /// - No `--country` for worldwide (count clamped to 1); the country list passed through
+    ///   otherwise; the install command backs up before copying.
+    fn reflector_args_follow_settings() {
+        let args = reflector_args("Worldwide", 0);
+        assert!(!args.contains(&"--country".to_string()));
+        assert_eq!(args[5], "1");
+        let args = reflector_args(" Germany,France ", 20);
+        assert_eq!(args[5], "20");
+        assert_eq!(&args[args.len() - 2..], ["--country", "Germany,France"]);
+        let cmd = install_mirrorlist_command(std::path::Path::new("/tmp/ranked"));
+        let root = crate::install::privilege::privilege_tool().binary();
+        assert!(cmd.starts_with(&format!(
+            "{root} cp -- /etc/pacman.d/mirrorlist /etc/pacman.d/mirrorlist.bak"
+        )));
+        assert!(cmd.contains(&format!("&& {root} install -m 644 -- ")));
+        assert!(cmd.contains("'/tmp/ranked'"));
+    }
+}
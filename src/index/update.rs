@@ -1,7 +1,7 @@
 #[cfg(not(target_os = "windows"))]
 use super::fetch::fetch_official_pkg_names;
 #[cfg(not(target_os = "windows"))]
-use super::{OfficialPkg, idx, save_to_disk};
+use super::{IndexDiff, apply_index_diff, idx, save_to_disk};
 
 /// What: Spawn a background task to refresh the official index and notify on changes.
 ///
@@ -15,8 +15,8 @@ use super::{OfficialPkg, idx, save_to_disk};
 ///   changes; sends notifications/errors via the provided channels.
 ///
 /// Details:
-/// - Merges new names while preserving previously enriched fields (repo, arch, version, description)
-///   for still-existing packages.
+/// - Updates only changed, added, and removed entries, keeping enriched fields (arch, description)
+///   of existing packages.
 #[cfg(not(target_os = "windows"))]
 pub async fn update_in_background(
     persist_path: std::path::PathBuf,
@@ -24,74 +24,114 @@ pub async fn update_in_background(
     notify_tx: tokio::sync::mpsc::UnboundedSender<()>,
 ) {
     tokio::spawn(async move {
-        tracing::info!("refreshing official index in background");
-        match fetch_official_pkg_names().await {
-            Ok(new_pkgs) => {
-                let new_count = new_pkgs.len();
-                let (different, merged): (bool, Vec<OfficialPkg>) = {
-                    let guard = idx().read().ok();
-                    if let Some(g) = guard {
-                        use std::collections::{HashMap, HashSet};
-                        let old_names: HashSet<String> =
-                            g.pkgs.iter().map(|p| p.name.clone()).collect();
-                        let new_names: HashSet<String> =
-                            new_pkgs.iter().map(|p| p.name.clone()).collect();
-                        let different = old_names != new_names;
-                        // Merge: prefer old/enriched fields when same name exists
-                        let mut old_map: HashMap<String, &OfficialPkg> = HashMap::new();
-                        for p in &g.pkgs {
-                            old_map.insert(p.name.clone(), p);
-                        }
-                        let mut merged = Vec::with_capacity(new_pkgs.len());
-                        for mut p in new_pkgs.into_iter() {
-                            if let Some(old) = old_map.get(&p.name) {
-                                // keep enriched data
-                                p.repo = old.repo.clone();
-                                p.arch = old.arch.clone();
-                                p.version = old.version.clone();
-                                p.description = old.description.clone();
-                            }
-                            merged.push(p);
-                        }
-                        (different, merged)
-                    } else {
-                        (true, new_pkgs)
-                    }
-                };
-                if different {
-                    if let Ok(mut g) = idx().write() {
-                        g.pkgs = merged;
-                    }
-                    save_to_disk(&persist_path);
-                    let _ = notify_tx.send(());
-                    tracing::info!(count = new_count, "official index updated (names changed)");
-                } else {
-                    tracing::debug!(
-                        count = new_count,
-                        "official index up-to-date (no name changes)"
-                    );
-                }
-            }
-            Err(e) => {
-                let _ = net_err_tx.send(format!("Failed to refresh official index: {e}"));
-                tracing::warn!(error = %e, "failed to refresh official index");
+        refresh_official_index(persist_path, net_err_tx, notify_tx).await;
+    });
+}
+
+/// What: Refresh the official index once and notify on changes.
+///
+/// Inputs:
+/// - `persist_path`: File path to persist the updated index JSON
+/// - `net_err_tx`: Channel to send human-readable errors on failure
+/// - `notify_tx`: Channel to notify the UI when the index changed
+///
+/// Output:
+/// - `Some(diff)` when entries were changed, added, or removed; `None` when the index was already
+///   current or the fetch failed. Same side effects as [`update_in_background`].
+///
+/// Details:
+/// - Applies the fetched list incrementally via [`apply_index_diff`], so enriched fields of
+///   unchanged packages survive and the file is rewritten only when something changed.
+/// - Awaitable variant used by the periodic refresh timer to learn when a run is over.
+#[cfg(not(target_os = "windows"))]
+pub async fn refresh_official_index(
+    persist_path: std::path::PathBuf,
+    net_err_tx: tokio::sync::mpsc::UnboundedSender<String>,
+    notify_tx: tokio::sync::mpsc::UnboundedSender<()>,
+) -> Option<IndexDiff> {
+    tracing::info!("refreshing official index in background");
+    match fetch_official_pkg_names().await {
+        Ok(new_pkgs) => {
+            let new_count = new_pkgs.len();
+            let diff = match idx().write() {
+                Ok(mut g) => apply_index_diff(&mut g.pkgs, new_pkgs),
+                Err(_) => return None,
+            };
+            if diff.is_empty() {
+                tracing::debug!(count = new_count, "official index up-to-date (no changes)");
+                return None;
             }
+            save_to_disk(&persist_path);
+            let _ = notify_tx.send(());
+            tracing::info!(
+                count = new_count,
+                changed = diff.changed,
+                added = diff.added,
+                removed = diff.removed,
+                "official index updated"
+            );
+            Some(diff)
         }
-    });
+        Err(e) => {
+            let _ = net_err_tx.send(format!("Failed to refresh official index: {e}"));
+            tracing::warn!(error = %e, "failed to refresh official index");
+            None
+        }
+    }
+}
+
+/// What: Decide whether the periodic official index refresh should start now.
+///
+/// Inputs:
+/// - `last_refresh`: When the previous refresh started (or app startup).
+/// - `now`: Current time.
+/// - `interval_mins`: Configured `index_refresh_interval_mins`; `0` disables refreshing.
+/// - `in_flight`: Whether a refresh is still running.
+///
+/// Output:
+/// - `true` when enabled, idle, and at least `interval_mins` have elapsed.
+pub fn index_refresh_due(
+    last_refresh: std::time::Instant,
+    now: std::time::Instant,
+    interval_mins: u64,
+    in_flight: bool,
+) -> bool {
+    interval_mins > 0
+        && !in_flight
+        && now.saturating_duration_since(last_refresh)
+            >= std::time::Duration::from_secs(interval_mins.saturating_mul(60))
 }
 
 #[cfg(not(target_os = "windows"))]
 #[cfg(test)]
 mod tests {
+    #[test]
+    /// What: Ensure the periodic refresh fires only when enabled, idle, and overdue.
+    ///
+    /// Inputs:
+    /// - A 10-minute interval checked at 9 and 10 minutes, with and without a run in flight,
+    ///   and a zero interval.
+    ///
+    /// Output:
+    /// - Due only at 10 minutes with nothing in flight; never when disabled.
+    fn index_refresh_due_respects_interval_and_in_flight() {
+        let start = std::time::Instant::now();
+        let at = |mins: u64| start + std::time::Duration::from_secs(mins * 60);
+        assert!(!super::index_refresh_due(start, at(9), 10, false));
+        assert!(super::index_refresh_due(start, at(10), 10, false));
+        assert!(!super::index_refresh_due(start, at(10), 10, true));
+        assert!(!super::index_refresh_due(start, at(1000), 0, false));
+    }
+
     #[tokio::test]
     #[allow(clippy::await_holding_lock)]
-    /// What: Merge fetched names while preserving enriched fields and notify on change.
+    /// What: Apply fetched entries incrementally while preserving enriched fields and notify on change.
     ///
     /// Inputs:
     /// - Seed index with enriched entry and stub `pacman -Sl` to add new packages.
     ///
     /// Output:
-    /// - Notification sent, no error emitted, and enriched data retained.
+    /// - Notification sent, no error emitted, enriched data retained, and the new version applied.
     ///
     /// Details:
     /// - Simulates pacman output via PATH override to exercise merge path.
@@ -100,7 +140,7 @@ mod tests {
 
         // Seed current index with enriched fields
         if let Ok(mut g) = super::idx().write() {
-            g.pkgs = vec![super::OfficialPkg {
+            g.pkgs = vec![crate::index::OfficialPkg {
                 name: "foo".to_string(),
                 repo: "core".to_string(),
                 arch: "x86_64".to_string(),
@@ -185,7 +225,7 @@ exit 0
             }
             _ => panic!("expected official"),
         }
-        assert_eq!(foo.version, "0.9"); // preserved from enriched
+        assert_eq!(foo.version, "1.0"); // version bump from -Sl applied incrementally
 
         // Teardown
         unsafe { std::env::set_var("PATH", &old_path) };
@@ -5,61 +5,7 @@ use std::process::Command;
 use crate::state::PackageItem;
 
 #[cfg(not(target_os = "windows"))]
-/// What: Compose the shell snippet that installs AUR packages through an available helper.
-///
-/// Input:
-/// - `flags`: CLI flags forwarded to the chosen AUR helper.
-/// - `n`: Space-separated package names to install.
-///
-/// Output:
-/// - Shell snippet that prefers `paru`, falls back to `yay`, and guides the user through helper bootstrap.
-///
-/// Details:
-/// - Retries with `-Syy` when installation fails and the user agrees.
-/// - Prompts to install an AUR helper if neither `paru` nor `yay` exists.
-fn aur_install_body(flags: &str, n: &str) -> String {
-    format!(
-        "(if command -v paru >/dev/null 2>&1 || sudo pacman -Qi paru >/dev/null 2>&1; then \
-            paru {flags} {n} || (echo; echo 'Install failed.'; \
-                read -rp 'Retry with force database sync (-Syy)? [y/N]: ' ans; \
-                if [ \"$ans\" = \"y\" ] || [ \"$ans\" = \"Y\" ]; then \
-                    paru -Syy && paru {flags} {n}; \
-                fi); \
-          elif command -v yay >/dev/null 2>&1 || sudo pacman -Qi yay >/dev/null 2>&1; then \
-            yay {flags} {n} || (echo; echo 'Install failed.'; \
-                read -rp 'Retry with force database sync (-Syy)? [y/N]: ' ans; \
-                if [ \"$ans\" = \"y\" ] || [ \"$ans\" = \"Y\" ]; then \
-                    yay -Syy && yay {flags} {n}; \
-                fi); \
-          else \
-            echo 'No AUR helper (paru/yay) found.'; echo; \
-            echo 'Choose AUR helper to install:'; \
-            echo '  1) paru'; echo '  2) yay'; echo '  3) cancel'; \
-            read -rp 'Enter 1/2/3: ' choice; \
-            case \"$choice\" in \
-              1) rm -rf paru && git clone https://aur.archlinux.org/paru.git && cd paru && makepkg -si ;; \
-              2) rm -rf yay && git clone https://aur.archlinux.org/yay.git && cd yay && makepkg -si ;; \
-              *) echo 'Cancelled.'; exit 1 ;; \
-            esac; \
-            if command -v paru >/dev/null 2>&1 || sudo pacman -Qi paru >/dev/null 2>&1; then \
-              paru {flags} {n} || (echo; echo 'Install failed.'; \
-                  read -rp 'Retry with force database sync (-Syy)? [y/N]: ' ans; \
-                  if [ \"$ans\" = \"y\" ] || [ \"$ans\" = \"Y\" ]; then \
-                      paru -Syy && paru {flags} {n}; \
-                  fi); \
-            elif command -v yay >/dev/null 2>&1 || sudo pacman -Qi yay >/dev/null 2>&1; then \
-              yay {flags} {n} || (echo; echo 'Install failed.'; \
-                  read -rp 'Retry with force database sync (-Syy)? [y/N]: ' ans; \
-                  if [ \"$ans\" = \"y\" ] || [ \"$ans\" = \"Y\" ]; then \
-                      yay -Syy && yay {flags} {n}; \
-                  fi); \
-            else \
-              echo 'AUR helper installation failed or was cancelled.'; exit 1; \
-            fi; \
-          fi)"
-    )
-}
-
+use super::command::aur_install_body;
 #[cfg(not(target_os = "windows"))]
 use super::logging::log_installed;
 #[cfg(not(target_os = "windows"))]
@@ -76,8 +22,9 @@ use super::utils::{choose_terminal_index_prefer_path, command_on_path, shell_sin
 /// - Launches a terminal (or falls back to `bash`) running the composed install commands.
 ///
 /// Details:
-/// - Official packages are grouped into a single `pacman` invocation
-/// - AUR packages are installed via `paru`/`yay` (prompts to install a helper if missing)
+/// - Official packages are grouped into a single `pacman` invocation that runs first
+/// - AUR packages are installed afterwards via the preferred AUR helper (prompts to install one if missing)
+/// - Dry-run echoes the lines from `command::install_command_lines`, matching the preview modal
 /// - Prefers common terminals (GNOME Console/Terminal, kitty, alacritty, xterm, xfce4-terminal, etc.); falls back to `bash`
 /// - Appends a "hold" tail so the terminal remains open after command completion
 pub fn spawn_install_all(items: &[PackageItem], dry_run: bool) {
@@ -101,38 +48,35 @@ pub fn spawn_install_all(items: &[PackageItem], dry_run: bool) {
     let hold_tail = "; echo; echo 'Finished.'; echo 'Press any key to close...'; read -rn1 -s _ || (echo; echo 'Press Ctrl+C to close'; sleep infinity)";
 
     let cmd_str = if dry_run {
-        if !aur.is_empty() {
-            let all: Vec<String> = items.iter().map(|p| p.name.clone()).collect();
-            format!(
-                "echo DRY RUN: (paru -S --needed --noconfirm {n} || yay -S --needed --noconfirm {n}){hold}",
-                n = all.join(" "),
-                hold = hold_tail
-            )
-        } else if !official.is_empty() {
-            format!(
-                "echo DRY RUN: sudo pacman -S --needed --noconfirm {n}{hold}",
-                n = official.join(" "),
-                hold = hold_tail
-            )
-        } else {
+        let lines =
+            super::command::install_command_lines(items, super::command::detect_aur_helper());
+        if lines.is_empty() {
             format!("echo DRY RUN: nothing to install{hold}", hold = hold_tail)
+        } else {
+            let echoes: Vec<String> = lines
+                .iter()
+                .map(|l| format!("echo DRY RUN: {}", shell_single_quote(l)))
+                .collect();
+            format!("{}{hold}", echoes.join("; "), hold = hold_tail)
         }
-    } else if !aur.is_empty() {
-        let all: Vec<String> = items.iter().map(|p| p.name.clone()).collect();
-        let n = all.join(" ");
-        format!(
-            "{body}{hold}",
-            body = aur_install_body("-S --needed --noconfirm", &n),
-            hold = hold_tail
-        )
-    } else if !official.is_empty() {
-        format!(
-            "(sudo pacman -S --needed --noconfirm {n} || (echo; echo 'Install failed.'; read -rp 'Retry with force database sync (-Syy)? [y/N]: ' ans; if [ \"$ans\" = \"y\" ] || [ \"$ans\" = \"Y\" ]; then sudo pacman -Syy && sudo pacman -S --needed --noconfirm {n}; fi)){hold}",
-            n = official.join(" "),
-            hold = hold_tail
-        )
     } else {
-        format!("echo nothing to install{hold}", hold = hold_tail)
+        // Official packages go through pacman first, AUR packages through the helper after
+        let mut parts: Vec<String> = Vec::new();
+        if !official.is_empty() {
+            parts.push(format!(
+                "({p} -S --needed --noconfirm {n} || (echo; echo 'Install failed.'; read -rp 'Retry with force database sync (-Syy)? [y/N]: ' ans; if [ \"$ans\" = \"y\" ] || [ \"$ans\" = \"Y\" ]; then {p} -Syy && {p} -S --needed --noconfirm {n}; fi))",
+                p = super::privilege::root_pacman(),
+                n = official.join(" ")
+            ));
+        }
+        if !aur.is_empty() {
+            parts.push(aur_install_body("-S --needed --noconfirm", &aur.join(" ")));
+        }
+        if parts.is_empty() {
+            format!("echo nothing to install{hold}", hold = hold_tail)
+        } else {
+            format!("{}{hold}", parts.join(" && "), hold = hold_tail)
+        }
     };
 
     // Prefer GNOME Terminal when running under GNOME desktop
@@ -244,6 +188,10 @@ pub fn spawn_install_all(items: &[PackageItem], dry_run: bool) {
         let res = Command::new("bash").args(["-lc", &cmd_str]).spawn();
         if let Err(e) = res {
             tracing::error!(error = %e, names = %names_vec.join(" "), "failed to spawn bash to run install command");
+            crate::logic::errors::report_error(
+                "install",
+                format!("failed to start bash for install: {e}"),
+            );
         } else {
             tracing::info!(total = items.len(), aur_count = aur.len(), official_count = official.len(), dry_run, names = %names_vec.join(" "), "launched bash for install");
         }
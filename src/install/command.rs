@@ -1,6 +1,8 @@
+use crate::logic::deps::AurHelper;
+use crate::state::modal::CascadeMode;
 use crate::state::{PackageItem, Source};
 
-/// What: Build the common AUR install body that prefers `paru` and falls back to `yay`.
+/// What: Build the common AUR install body that tries the configured helper first.
 ///
 /// Input:
 /// - `flags`: Flag string forwarded to the helper (e.g. `-S --needed`).
@@ -10,24 +12,41 @@ use crate::state::{PackageItem, Source};
 /// - Parenthesised shell snippet `(if ... fi)` without the trailing hold suffix.
 ///
 /// Details:
-/// - Prompts for helper installation when neither `paru` nor `yay` is present.
+/// - Checks helpers in `logic::deps::helper_order` (preferred first, then paru, yay, pikaur,
+///   trizen, aurutils) and runs the first one found.
+/// - Prompts for helper installation (paru or yay) when none is present.
 /// - Offers an interactive retry with `-Syy` after failures.
 pub fn aur_install_body(flags: &str, n: &str) -> String {
-    format!(
-        "(if command -v paru >/dev/null 2>&1 || sudo pacman -Qi paru >/dev/null 2>&1; then \
-            paru {flags} {n} || (echo; echo 'Install failed.'; \
-                read -rp 'Retry with force database sync (-Syy)? [y/N]: ' ans; \
-                if [ \"$ans\" = \"y\" ] || [ \"$ans\" = \"Y\" ]; then \
-                    paru -Syy && paru {flags} {n}; \
-                fi); \
-          elif command -v yay >/dev/null 2>&1 || sudo pacman -Qi yay >/dev/null 2>&1; then \
-            yay {flags} {n} || (echo; echo 'Install failed.'; \
+    let attempt = |h: AurHelper| {
+        let cmd = h.install_command(flags, n);
+        let sync = match h {
+            AurHelper::Aurutils => format!("{} -Syy", super::privilege::root_pacman()),
+            _ => format!("{} -Syy", h.binary()),
+        };
+        format!(
+            "{cmd} || (echo; echo 'Install failed.'; \
                 read -rp 'Retry with force database sync (-Syy)? [y/N]: ' ans; \
                 if [ \"$ans\" = \"y\" ] || [ \"$ans\" = \"Y\" ]; then \
-                    yay -Syy && yay {flags} {n}; \
-                fi); \
+                    {sync} && {cmd}; \
+                fi)"
+        )
+    };
+    let installed = |h: AurHelper| {
+        format!(
+            "command -v {} >/dev/null 2>&1 || {} -Qi {} >/dev/null 2>&1",
+            h.binary(),
+            super::privilege::pacman_bin(),
+            h.name()
+        )
+    };
+    let branches: Vec<String> = crate::logic::deps::helper_order()
+        .into_iter()
+        .map(|h| format!("{}; then {}; ", installed(h), attempt(h)))
+        .collect();
+    format!(
+        "(if {chain}\
           else \
-            echo 'No AUR helper (paru/yay) found.'; echo; \
+            echo 'No AUR helper found.'; echo; \
             echo 'Choose AUR helper to install:'; \
             echo '  1) paru'; echo '  2) yay'; echo '  3) cancel'; \
             read -rp 'Enter 1/2/3: ' choice; \
@@ -36,22 +55,17 @@ pub fn aur_install_body(flags: &str, n: &str) -> String {
               2) rm -rf yay && git clone https://aur.archlinux.org/yay.git && cd yay && makepkg -si ;; \
               *) echo 'Cancelled.'; exit 1 ;; \
             esac; \
-            if command -v paru >/dev/null 2>&1 || sudo pacman -Qi paru >/dev/null 2>&1; then \
-              paru {flags} {n} || (echo; echo 'Install failed.'; \
-                  read -rp 'Retry with force database sync (-Syy)? [y/N]: ' ans; \
-                  if [ \"$ans\" = \"y\" ] || [ \"$ans\" = \"Y\" ]; then \
-                      paru -Syy && paru {flags} {n}; \
-                  fi); \
-            elif command -v yay >/dev/null 2>&1 || sudo pacman -Qi yay >/dev/null 2>&1; then \
-              yay {flags} {n} || (echo; echo 'Install failed.'; \
-                  read -rp 'Retry with force database sync (-Syy)? [y/N]: ' ans; \
-                  if [ \"$ans\" = \"y\" ] || [ \"$ans\" = \"Y\" ]; then \
-                      yay -Syy && yay {flags} {n}; \
-                  fi); \
+            if {paru_ok}; then {paru}; \
+            elif {yay_ok}; then {yay}; \
             else \
               echo 'AUR helper installation failed or was cancelled.'; exit 1; \
             fi; \
-          fi)"
+          fi)",
+        chain = branches.join("elif "),
+        paru_ok = installed(AurHelper::Paru),
+        paru = attempt(AurHelper::Paru),
+        yay_ok = installed(AurHelper::Yay),
+        yay = attempt(AurHelper::Yay),
     )
 }
 
@@ -59,7 +73,8 @@ pub fn aur_install_body(flags: &str, n: &str) -> String {
 ///
 /// Input:
 /// - `item`: Package to install (official via pacman, AUR via helper).
-/// - `password`: Optional sudo password; when present, wires `sudo -S` with a pipe.
+/// - `password`: Optional sudo password; when present and `sudo` is the configured tool, wires
+///   `sudo -S` with a pipe.
 /// - `dry_run`: When `true`, prints the command instead of executing.
 ///
 /// Output:
@@ -69,6 +84,7 @@ pub fn aur_install_body(flags: &str, n: &str) -> String {
 /// - Detects already-installed packages to offer a reinstall prompt.
 /// - Adds a hold tail so spawned terminals remain open after completion.
 /// - Ensures pacman retries with `-Syy` when the user confirms after failure.
+/// - Uses the configured pacman binary and privilege tool (see [`super::privilege`]).
 pub fn build_install_command(
     item: &PackageItem,
     password: Option<&str>,
@@ -77,42 +93,29 @@ pub fn build_install_command(
     match &item.source {
         Source::Official { .. } => {
             let reinstall = crate::index::is_installed(&item.name);
+            let pacman = super::privilege::pacman_bin();
             let base_cmd = if reinstall {
-                format!("pacman -S --noconfirm {}", item.name)
+                format!("{pacman} -S --noconfirm {}", item.name)
             } else {
-                format!("pacman -S --needed --noconfirm {}", item.name)
+                format!("{pacman} -S --needed --noconfirm {}", item.name)
             };
             let hold_tail = "; echo; echo 'Finished.'; echo 'Press any key to close...'; read -rn1 -s _ || (echo; echo 'Press Ctrl+C to close'; sleep infinity)";
             if dry_run {
-                let bash = format!("echo DRY RUN: sudo {base_cmd}{hold_tail}");
+                let tool = super::privilege::privilege_tool().binary();
+                let bash = format!("echo DRY RUN: {tool} {base_cmd}{hold_tail}");
                 return (bash, true);
             }
-            let pass = password.unwrap_or("");
-            if pass.is_empty() {
-                let bash = if reinstall {
-                    format!(
-                        "(read -rp 'Package is already installed. Reinstall? [y/N]: ' ans; if [ \"$ans\" = \"y\" ] || [ \"$ans\" = \"Y\" ]; then sudo {base_cmd} || (echo; echo 'Install failed.'; read -rp 'Retry with force database sync (-Syy)? [y/N]: ' ans; if [ \"$ans\" = \"y\" ] || [ \"$ans\" = \"Y\" ]; then sudo pacman -Syy && sudo {base_cmd}; fi); else echo 'Reinstall cancelled.'; fi){hold_tail}"
-                    )
-                } else {
-                    format!(
-                        "(sudo {base_cmd} || (echo; echo 'Install failed.'; read -rp 'Retry with force database sync (-Syy)? [y/N]: ' ans; if [ \"$ans\" = \"y\" ] || [ \"$ans\" = \"Y\" ]; then sudo pacman -Syy && sudo {base_cmd}; fi)){hold_tail}"
-                    )
-                };
-                (bash, true)
+            let esc = super::privilege::escalation_prefix(password.unwrap_or(""));
+            let bash = if reinstall {
+                format!(
+                    "(read -rp 'Package is already installed. Reinstall? [y/N]: ' ans; if [ \"$ans\" = \"y\" ] || [ \"$ans\" = \"Y\" ]; then {esc} {base_cmd} || (echo; echo 'Install failed.'; read -rp 'Retry with force database sync (-Syy)? [y/N]: ' ans; if [ \"$ans\" = \"y\" ] || [ \"$ans\" = \"Y\" ]; then {esc} {pacman} -Syy && {esc} {base_cmd}; fi); else echo 'Reinstall cancelled.'; fi){hold_tail}"
+                )
             } else {
-                let escaped = pass.replace('\'', "'\"'\"'\''");
-                let pipe = format!("echo '{escaped}' | ");
-                let bash = if reinstall {
-                    format!(
-                        "(read -rp 'Package is already installed. Reinstall? [y/N]: ' ans; if [ \"$ans\" = \"y\" ] || [ \"$ans\" = \"Y\" ]; then {pipe}sudo -S {base_cmd} || (echo; echo 'Install failed.'; read -rp 'Retry with force database sync (-Syy)? [y/N]: ' ans; if [ \"$ans\" = \"y\" ] || [ \"$ans\" = \"Y\" ]; then {pipe}sudo -S pacman -Syy && {pipe}sudo -S {base_cmd}; fi); else echo 'Reinstall cancelled.'; fi){hold_tail}"
-                    )
-                } else {
-                    format!(
-                        "({pipe}sudo -S {base_cmd} || (echo; echo 'Install failed.'; read -rp 'Retry with force database sync (-Syy)? [y/N]: ' ans; if [ \"$ans\" = \"y\" ] || [ \"$ans\" = \"Y\" ]; then {pipe}sudo -S pacman -Syy && {pipe}sudo -S {base_cmd}; fi)){hold_tail}"
-                    )
-                };
-                (bash, true)
-            }
+                format!(
+                    "({esc} {base_cmd} || (echo; echo 'Install failed.'; read -rp 'Retry with force database sync (-Syy)? [y/N]: ' ans; if [ \"$ans\" = \"y\" ] || [ \"$ans\" = \"Y\" ]; then {esc} {pacman} -Syy && {esc} {base_cmd}; fi)){hold_tail}"
+                )
+            };
+            (bash, true)
         }
         Source::Aur => {
             let hold_tail = "; echo; echo 'Press any key to close...'; read -rn1 -s _ || (echo; echo 'Press Ctrl+C to close'; sleep infinity)";
@@ -123,11 +126,11 @@ pub fn build_install_command(
                 "-S --needed --noconfirm"
             };
             let aur_cmd = if dry_run {
+                let cmd = detect_aur_helper().install_command(flags, &item.name);
                 format!(
-                    "echo DRY RUN: paru {flags} {n} || yay {flags} {n}{hold}",
-                    n = item.name,
-                    hold = hold_tail,
-                    flags = flags
+                    "echo DRY RUN: {cmd}{hold}",
+                    cmd = super::utils::shell_single_quote(&cmd),
+                    hold = hold_tail
                 )
             } else if reinstall {
                 format!(
@@ -149,6 +152,104 @@ pub fn build_install_command(
     }
 }
 
+/// What: Pick the AUR helper a batch install would run.
+///
+/// Output:
+/// - The first installed helper in preference order; the preferred (or default `paru`) helper
+///   when none is installed (the install script offers to bootstrap one).
+pub fn detect_aur_helper() -> AurHelper {
+    crate::logic::deps::available_helpers()
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| crate::logic::deps::helper_order()[0])
+}
+
+/// What: Build the command lines a batch install runs, split by source.
+///
+/// Inputs:
+/// - `items`: Packages to install.
+/// - `aur_helper`: Helper used for AUR packages (see [`detect_aur_helper`]).
+///
+/// Output:
+/// - One `sudo pacman -S` line for official packages followed by one helper line for AUR
+///   packages; either is omitted when that side is empty.
+///
+/// Details:
+/// - This is the core of what `spawn_install_all` executes (minus retry prompts), so the
+///   dry-run preview shows exactly what would run.
+pub fn install_command_lines(items: &[PackageItem], aur_helper: AurHelper) -> Vec<String> {
+    let (official, aur): (Vec<&PackageItem>, Vec<&PackageItem>) = items
+        .iter()
+        .partition(|p| matches!(p.source, Source::Official { .. }));
+    let join = |v: &[&PackageItem]| {
+        v.iter()
+            .map(|p| p.name.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let mut lines = Vec::new();
+    if !official.is_empty() {
+        lines.push(format!(
+            "{} -S --needed --noconfirm {}",
+            super::privilege::root_pacman(),
+            join(&official)
+        ));
+    }
+    if !aur.is_empty() {
+        lines.push(aur_helper.install_command("-S --needed --noconfirm", &join(&aur)));
+    }
+    lines
+}
+
+/// What: Build the pacman command line that removes `names`.
+///
+/// Output:
+/// - `sudo pacman <flag> --noconfirm <names>` with the flag taken from `cascade_mode`.
+pub fn remove_command_line(names: &[String], cascade_mode: CascadeMode) -> String {
+    format!(
+        "{} {} --noconfirm {}",
+        super::privilege::root_pacman(),
+        cascade_mode.flag(),
+        names.join(" ")
+    )
+}
+
+/// What: Build the command lines for the downgrade list.
+///
+/// Inputs:
+/// - `paths`: Cached package archives picked for specific versions.
+/// - `names`: Packages without a picked version, handed to the `downgrade` tool.
+///
+/// Output:
+/// - A privileged `pacman -U` line and/or a `downgrade` line, in execution order.
+///
+/// Details:
+/// - Each archive path is single-quoted, so spaces or shell metacharacters stay literal.
+/// - The `downgrade` line checks for the tool first and prints an install hint without it.
+/// - Used both for the real run and the dry-run/preview, so the user approves what runs.
+pub fn downgrade_command_lines(paths: &[String], names: &[String]) -> Vec<String> {
+    let mut lines = Vec::new();
+    if !paths.is_empty() {
+        let quoted: Vec<String> = paths
+            .iter()
+            .map(|p| super::utils::shell_single_quote(p))
+            .collect();
+        lines.push(format!(
+            "{} -U {}",
+            super::privilege::root_pacman(),
+            quoted.join(" ")
+        ));
+    }
+    if !names.is_empty() {
+        lines.push(format!(
+            "((command -v downgrade >/dev/null 2>&1) || {} -Qi downgrade >/dev/null 2>&1) && downgrade {} || echo 'downgrade tool not found. Install \"downgrade\" from AUR.'",
+            super::privilege::pacman_bin(),
+            names.join(" ")
+        ));
+    }
+    lines
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -224,6 +325,54 @@ mod tests {
 
         let (cmd2, uses_sudo2) = build_install_command(&pkg, None, true);
         assert!(!uses_sudo2);
-        assert!(cmd2.starts_with("echo DRY RUN: paru -S --needed --noconfirm yay-bin"));
+        assert!(cmd2.starts_with("echo DRY RUN: '"));
+        assert!(cmd2.contains(" -S --needed --noconfirm yay-bin"));
+    }
+
+    #[test]
+    /// What: Ensure preview lines split official and AUR packages and follow the list modes.
+    ///
+    /// Inputs:
+    /// - A mixed install list, a cascade removal, and a downgrade with one picked archive.
+    ///
+    /// Output:
+    /// - Separate pacman/helper install lines, the `-Rs` removal line, and `-U` before
+    ///   `downgrade`; empty inputs produce no lines.
+    fn command_lines_split_by_source() {
+        let item = |name: &str, source: Source| PackageItem {
+            name: name.into(),
+            version: String::new(),
+            description: String::new(),
+            source,
+            popularity: None,
+        };
+        let official = Source::Official {
+            repo: "extra".into(),
+            arch: "x86_64".into(),
+        };
+        let items = vec![
+            item("ripgrep", official.clone()),
+            item("yay-bin", Source::Aur),
+            item("fd", official),
+        ];
+        assert_eq!(
+            install_command_lines(&items, AurHelper::Yay),
+            vec![
+                "sudo pacman -S --needed --noconfirm ripgrep fd".to_string(),
+                "yay -S --needed --noconfirm yay-bin".to_string(),
+            ]
+        );
+        assert!(install_command_lines(&[], AurHelper::Paru).is_empty());
+        assert_eq!(
+            remove_command_line(&["a".into(), "b".into()], CascadeMode::Cascade),
+            "sudo pacman -Rs --noconfirm a b"
+        );
+        assert_eq!(
+            downgrade_command_lines(&["/c/a b.pkg.tar.zst".into()], &["b".into()]),
+            vec![
+                "sudo pacman -U '/c/a b.pkg.tar.zst'".to_string(),
+                "((command -v downgrade >/dev/null 2>&1) || pacman -Qi downgrade >/dev/null 2>&1) && downgrade b || echo 'downgrade tool not found. Install \"downgrade\" from AUR.'".to_string(),
+            ]
+        );
     }
 }
@@ -0,0 +1,145 @@
+//! User-configured command run after a successful install.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Name passed as `$0` to the hook so `"$@"` holds exactly the package names.
+const HOOK_ARG0: &str = "pacsea-post-install";
+
+/// What: Arguments for `bash` that run `hook` with `names` as positional parameters.
+///
+/// Inputs:
+/// - `hook`: Shell command from `post_install_hook`.
+/// - `names`: Installed package names.
+///
+/// Output:
+/// - `["-lc", "<hook> \"$@\"", "pacsea-post-install", names...]`.
+///
+/// Details:
+/// - Names are passed as separate argv entries rather than spliced into the script, so they
+///   never need quoting.
+fn hook_args(hook: &str, names: &[String]) -> Vec<String> {
+    let mut args = vec![
+        "-lc".to_string(),
+        format!("{} \"$@\"", hook.trim()),
+        HOOK_ARG0.to_string(),
+    ];
+    args.extend(names.iter().cloned());
+    args
+}
+
+/// What: Run the post-install hook and append its output to `log_path`.
+///
+/// Inputs:
+/// - `hook`: Shell command; empty or whitespace-only does nothing.
+/// - `names`: Installed package names passed as arguments.
+/// - `log_path`: Log file to append to.
+///
+/// Output:
+/// - `Ok(Some(log_path))` when the hook exited successfully, `Ok(None)` when no hook is set,
+///   `Err` with a short reason when it could not run or exited non-zero.
+fn run_hook_logged(
+    hook: &str,
+    names: &[String],
+    log_path: &Path,
+) -> Result<Option<PathBuf>, String> {
+    if hook.trim().is_empty() {
+        return Ok(None);
+    }
+    let output = Command::new("bash")
+        .args(hook_args(hook, names))
+        .output()
+        .map_err(|e| format!("failed to spawn bash: {e}"))?;
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .ok();
+    let write_log = || -> std::io::Result<()> {
+        if let Some(parent) = log_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut f = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(log_path)?;
+        writeln!(
+            f,
+            "== {} {} {} ({})",
+            crate::util::ts_to_date(now),
+            hook.trim(),
+            names.join(" "),
+            output.status
+        )?;
+        f.write_all(&output.stdout)?;
+        f.write_all(&output.stderr)?;
+        Ok(())
+    };
+    if let Err(e) = write_log() {
+        tracing::warn!(error = %e, path = %log_path.display(), "failed to write post-install hook log");
+    }
+    if output.status.success() {
+        Ok(Some(log_path.to_path_buf()))
+    } else {
+        Err(match output.status.code() {
+            Some(code) => format!("exit code {code}"),
+            None => output.status.to_string(),
+        })
+    }
+}
+
+/// What: Run the configured post-install hook for packages that were just installed.
+///
+/// Inputs:
+/// - `hook`: Value of `post_install_hook`.
+/// - `names`: Installed package names.
+///
+/// Output:
+/// - Same as the logged runner: `Ok(Some(log))` on success, `Ok(None)` when disabled, `Err`
+///   on failure.
+///
+/// Details:
+/// - Blocks until the hook exits; callers run it off the UI thread.
+/// - Runs through `bash -lc` like the install terminals, so the user's login environment
+///   applies. Output goes to `logs_dir/post_install_hook.log`.
+/// - Callers must not invoke it for dry-run installs.
+pub fn run_post_install_hook(hook: &str, names: &[String]) -> Result<Option<PathBuf>, String> {
+    let mut path = crate::theme::logs_dir();
+    path.push("post_install_hook.log");
+    run_hook_logged(hook, names, &path)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure the hook receives package names as arguments and its output is logged.
+    ///
+    /// Inputs:
+    /// - A hook printing its arguments, a failing hook, and an empty hook, logged to a temp file.
+    ///
+    /// Output:
+    /// - Names reach `"$@"` unsplit, failures report the exit code, and an empty hook is a no-op.
+    fn hook_runs_with_names_and_logs_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = dir.path().join("hook.log");
+        let names = vec!["ripgrep".to_string(), "fd".to_string()];
+
+        assert_eq!(
+            hook_args("echo", &names),
+            vec!["-lc", "echo \"$@\"", HOOK_ARG0, "ripgrep", "fd"]
+        );
+
+        let ok = run_hook_logged("printf '<%s>'", &names, &log).unwrap();
+        assert_eq!(ok.as_deref(), Some(log.as_path()));
+        let body = std::fs::read_to_string(&log).unwrap();
+        assert!(body.contains("<ripgrep><fd>"), "{body}");
+
+        assert_eq!(
+            run_hook_logged("exit 3;", &names, &log),
+            Err("exit code 3".to_string())
+        );
+        assert_eq!(run_hook_logged("  ", &names, &log), Ok(None));
+    }
+}
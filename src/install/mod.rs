@@ -5,6 +5,7 @@
 
 mod batch;
 pub mod command;
+mod hook;
 mod logging;
 mod remove;
 mod scan;
@@ -13,8 +14,10 @@ mod single;
 mod utils;
 
 pub use batch::spawn_install_all;
+pub use hook::run_post_install_hook;
 pub use logging::log_removed;
 mod patterns;
+pub mod privilege;
 pub use remove::spawn_remove_all;
 
 #[cfg(not(target_os = "windows"))]
@@ -24,9 +27,11 @@ pub use remove::spawn_remove_all;
 /// Input:
 /// - `pkg`: Package name passed to the scanner.
 /// - `do_clamav`/`do_trivy`/`do_semgrep`/`do_shellcheck`/`do_virustotal`/`do_custom`/`do_sleuth`: Toggles for optional scan stages.
+/// - `force_rescan`: Ignore cached verdicts for an unchanged PKGBUILD.
 ///
 /// Output:
-/// - Spawns a terminal executing the scan workflow defined in `scan::spawn_aur_scan_for_with_config`.
+/// - Spawns a terminal executing the scan workflow defined in `scan::spawn_aur_scan_for_with_config`
+///   and returns its progress handle.
 ///
 /// Details:
 /// - Loads `pattern.conf`, publishes severity regexes via environment variables, and lets the scan module honour them.
@@ -40,7 +45,8 @@ pub fn spawn_aur_scan_for_with_config(
     do_virustotal: bool,
     do_custom: bool,
     do_sleuth: bool,
-) {
+    force_rescan: bool,
+) -> crate::state::modal::ScanProgress {
     // Load configurable suspicious patterns (pattern.conf), override defaults via env vars
     let sets = crate::install::patterns::load();
     unsafe {
@@ -66,8 +72,10 @@ pub fn spawn_aur_scan_for_with_config(
         do_virustotal,
         do_custom,
         do_sleuth,
-    );
+        force_rescan,
+    )
 }
+pub use scan::poll_scans;
 pub use shell::spawn_shell_commands_in_terminal;
 pub use single::spawn_install;
-pub use utils::command_on_path;
+pub use utils::{command_on_path, shell_single_quote};
@@ -0,0 +1,146 @@
+//! Configurable pacman binary and privilege-escalation tool used to build commands.
+
+use std::sync::RwLock;
+
+/// Tool used to run pacman as root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PrivilegeTool {
+    /// `sudo` (default); the only tool that can read a password from stdin (`-S`).
+    #[default]
+    Sudo,
+    /// OpenBSD-style `doas`.
+    Doas,
+    /// systemd's `run0`.
+    Run0,
+}
+
+impl PrivilegeTool {
+    /// What: Parse a `privilege_escalation` setting value (case-insensitive).
+    ///
+    /// Output:
+    /// - The matching tool, or `None` for unknown names.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "sudo" => Some(Self::Sudo),
+            "doas" => Some(Self::Doas),
+            "run0" => Some(Self::Run0),
+            _ => None,
+        }
+    }
+
+    /// What: Executable name of the tool.
+    pub fn binary(self) -> &'static str {
+        match self {
+            Self::Sudo => "sudo",
+            Self::Doas => "doas",
+            Self::Run0 => "run0",
+        }
+    }
+
+    /// What: Flag that makes the tool fail instead of prompting for a password.
+    ///
+    /// Details:
+    /// - Used by background jobs that have no terminal to prompt on (e.g. the file-DB sync).
+    pub fn non_interactive_flag(self) -> &'static str {
+        match self {
+            Self::Sudo | Self::Doas => "-n",
+            Self::Run0 => "--no-ask-password",
+        }
+    }
+}
+
+/// Configured pacman binary; empty means `pacman`.
+static PACMAN_BINARY: RwLock<String> = RwLock::new(String::new());
+
+/// Configured privilege-escalation tool.
+static PRIVILEGE_TOOL: RwLock<PrivilegeTool> = RwLock::new(PrivilegeTool::Sudo);
+
+/// What: Set the pacman binary used for every pacman invocation (`pacman_binary` setting).
+///
+/// Details:
+/// - Blank values fall back to `pacman`.
+pub fn set_pacman_binary(bin: &str) {
+    if let Ok(mut g) = PACMAN_BINARY.write() {
+        *g = bin.trim().to_string();
+    }
+}
+
+/// What: Pacman binary to run (e.g. `pacman` or `pacman-static`); it must support the full
+/// pacman CLI, including the `-Q`/`-Qi`/`-F` queries.
+pub fn pacman_bin() -> String {
+    PACMAN_BINARY
+        .read()
+        .ok()
+        .map(|g| g.clone())
+        .filter(|b| !b.is_empty())
+        .unwrap_or_else(|| "pacman".to_string())
+}
+
+/// What: Set the privilege-escalation tool (`privilege_escalation` setting).
+pub fn set_privilege_tool(tool: PrivilegeTool) {
+    if let Ok(mut g) = PRIVILEGE_TOOL.write() {
+        *g = tool;
+    }
+}
+
+/// What: Currently configured privilege-escalation tool.
+pub fn privilege_tool() -> PrivilegeTool {
+    PRIVILEGE_TOOL.read().map(|g| *g).unwrap_or_default()
+}
+
+/// What: Command prefix that runs pacman as root, e.g. `sudo pacman`.
+///
+/// Details:
+/// - Only for state-changing operations; read-only queries (`-Q`, `-Si`, `-Ql`, `-F`) run
+///   [`pacman_bin`] directly.
+pub fn root_pacman() -> String {
+    format!("{} {}", privilege_tool().binary(), pacman_bin())
+}
+
+/// What: Escalation prefix for a command run from a generated shell script.
+///
+/// Inputs:
+/// - `password`: Password collected by the UI; empty when none was asked for.
+///
+/// Output:
+/// - `echo '<pw>' | sudo -S` when `sudo` is configured and a password is given; otherwise just
+///   the tool name (doas and run0 prompt on their own).
+pub fn escalation_prefix(password: &str) -> String {
+    let tool = privilege_tool();
+    if tool == PrivilegeTool::Sudo && !password.is_empty() {
+        let escaped = password.replace('\'', "'\"'\"'\''");
+        format!("echo '{escaped}' | sudo -S")
+    } else {
+        tool.binary().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure tool names parse and the password pipe is only used with sudo.
+    ///
+    /// Inputs:
+    /// - Tool names in mixed case; a password with the default tool.
+    ///
+    /// Output:
+    /// - Known names map to tools, unknown ones to `None`; the default prefix pipes the password.
+    fn privilege_tool_parsing_and_prefix() {
+        assert_eq!(
+            PrivilegeTool::from_name(" DOAS "),
+            Some(PrivilegeTool::Doas)
+        );
+        assert_eq!(PrivilegeTool::from_name("run0"), Some(PrivilegeTool::Run0));
+        assert_eq!(PrivilegeTool::from_name("su"), None);
+        assert_eq!(escalation_prefix(""), "sudo");
+        assert_eq!(escalation_prefix("pw"), "echo 'pw' | sudo -S");
+        assert_eq!(root_pacman(), "sudo pacman");
+        assert_eq!(PrivilegeTool::Doas.non_interactive_flag(), "-n");
+        assert_eq!(
+            PrivilegeTool::Run0.non_interactive_flag(),
+            "--no-ask-password"
+        );
+    }
+}
@@ -29,14 +29,16 @@ pub fn spawn_remove_all(names: &[String], dry_run: bool, cascade_mode: CascadeMo
     let hold_tail = "; echo; echo 'Finished.'; echo 'Press any key to close...'; read -rn1 -s _ || (echo; echo 'Press Ctrl+C to close'; sleep infinity)";
     let cmd_str = if dry_run {
         format!(
-            "echo DRY RUN: sudo pacman {flag} --noconfirm {n}{hold}",
+            "echo DRY RUN: {p} {flag} --noconfirm {n}{hold}",
+            p = super::privilege::root_pacman(),
             flag = flag,
             n = names.join(" "),
             hold = hold_tail
         )
     } else {
         format!(
-            "sudo pacman {flag} --noconfirm {n}{hold}",
+            "{p} {flag} --noconfirm {n}{hold}",
+            p = super::privilege::root_pacman(),
             flag = flag,
             n = names.join(" "),
             hold = hold_tail
@@ -167,6 +169,10 @@ pub fn spawn_remove_all(names: &[String], dry_run: bool, cascade_mode: CascadeMo
         let res = Command::new("bash").args(["-lc", &cmd_str]).spawn();
         if let Err(e) = res {
             tracing::error!(error = %e, names = %names_str, "failed to spawn bash to run removal command");
+            crate::logic::errors::report_error(
+                "remove",
+                format!("failed to start bash for removal: {e}"),
+            );
         } else {
             tracing::info!(
                 names = %names_str,
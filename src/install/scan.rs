@@ -10,10 +10,131 @@ Output:
 Details:
 - Steps: clone AUR repo; run `makepkg -o`; run optional scanners (ClamAV, Trivy, Semgrep); optional VirusTotal hash lookups when VT_API_KEY is present
 - Semgrep is not installed automatically; if missing, a warning is printed and the scan is skipped
-- VirusTotal lookups are hash-based; unknown files may report "no report found"
+- VirusTotal lookups are hash-based and cached per sha256 for a week (force rescan skips the cache); unknown files may report "no report found", and quota errors are retried with backoff
 - Working directory is a temporary directory printed to the terminal and preserved for inspection
 */
 
+use crate::state::modal::{ScanPhase, ScanProgress};
+
+/// Shell step computing the scan cache key and restoring cached verdicts on a hit.
+///
+/// Details:
+/// - The key hashes PKGBUILD, `.SRCINFO`, `*.install`, downloaded source files, VCS source
+///   heads, the first line of every scanner's `--version` output, and the `PACSEA_SCAN_DO_*` /
+///   `PACSEA_PATTERNS_*` settings, so edits, tool upgrades, and changed toggles all miss.
+/// - Sets `PACSEA_SCAN_CACHED=1` on a hit unless `PACSEA_SCAN_FORCE=1`.
+#[cfg(not(target_os = "windows"))]
+const SCAN_CACHE_LOOKUP: &str = r#"export PACSEA_SCAN_CACHED=0; scan_key=''; if [ -n "${PACSEA_SCAN_CACHE_DIR:-}" ] && [ -f PKGBUILD ]; then
+  scan_key=$( {
+    echo 'pacsea-scan-cache-v1';
+    for f in PKGBUILD .SRCINFO ./*.install; do [ -f "$f" ] && sha256sum "$f"; done;
+    find . -maxdepth 1 -type f ! -name '.pacsea_*' ! -name PKGBUILD ! -name .SRCINFO ! -name '*.install' -exec sha256sum {} + 2>/dev/null | sort;
+    for d in ./*/; do [ -d "$d" ] && git -C "$d" rev-parse HEAD 2>/dev/null; done;
+    for t in clamscan trivy semgrep shellcheck aur-sleuth; do command -v "$t" >/dev/null 2>&1 && echo "$t: $("$t" --version 2>/dev/null | head -n1)"; done;
+    env | grep -E '^PACSEA_(SCAN_DO|PATTERNS)_' | sort;
+  } | sha256sum | cut -d' ' -f1 );
+  scan_cache_file="$PACSEA_SCAN_CACHE_DIR/$scan_key.json";
+  if [ "${PACSEA_SCAN_FORCE:-0}" = 1 ]; then
+    echo 'Force rescan: ignoring cached scan results.';
+  elif [ -f "$scan_cache_file" ]; then
+    sed -n 's/^    "\(\.pacsea_[A-Za-z0-9_.]*\)": "\([A-Za-z0-9+\/=]*\)"$/\1 \2/p' "$scan_cache_file" | while read -r name data; do printf '%s' "$data" | base64 -d > "./$name"; done;
+    created=$(sed -n 's/^  "created": "\(.*\)",$/\1/p' "$scan_cache_file");
+    echo -e "\033[1;36m[♻] Using cached scan results from ${created:-an earlier scan} (PKGBUILD, sources, and scanners unchanged).\033[0m";
+    echo 'Choose force rescan in the scan dialog to run the scanners again.';
+    export PACSEA_SCAN_CACHED=1;
+  fi;
+fi"#;
+
+/// Shell step storing this scan's verdict files as `<scan_key>.json` in the scan cache.
+///
+/// Details:
+/// - Each `.pacsea_*` artifact (except the debug log) is stored base64-encoded on its own line so
+///   [`SCAN_CACHE_LOOKUP`] can restore it with `sed`; the file is written atomically.
+#[cfg(not(target_os = "windows"))]
+const SCAN_CACHE_STORE: &str = r#"if [ "${PACSEA_SCAN_CACHED:-0}" != 1 ] && [ -n "${scan_key:-}" ]; then
+  mkdir -p "$PACSEA_SCAN_CACHE_DIR" && {
+    echo '{';
+    echo "  \"key\": \"$scan_key\",";
+    echo "  \"created\": \"$(date -Iseconds)\",";
+    echo '  "files": {';
+    first=1;
+    for f in ./.pacsea_*; do
+      [ -f "$f" ] || continue; [ "$f" = ./.pacsea_debug.log ] && continue;
+      [ "$first" = 1 ] || echo ',';
+      printf '    "%s": "%s"\n' "${f#./}" "$(base64 -w0 < "$f")";
+      first=0;
+    done;
+    echo '  }';
+    echo '}';
+  } > "$scan_cache_file.tmp" && mv -f "$scan_cache_file.tmp" "$scan_cache_file" && echo "Scan results cached ($scan_key)." || true;
+fi"#;
+
+/// What: Turn the sequential scanner sections of a scan script into concurrent background jobs.
+///
+/// Inputs:
+/// - `cmds`: Scanner commands; each section starts with an `echo '--- <name> ---'` header.
+///
+/// Output:
+/// - Commands before the first header unchanged (shared exports), followed by one command that
+///   starts every section as its own job and prints each job's output as soon as it finishes.
+///
+/// Details:
+/// - Job output is buffered in `./.pacsea-job-<n>.log` (outside the `.pacsea_*` artifact glob) so
+///   sections never interleave; a `[✔]` line with the elapsed time follows each one.
+/// - Ctrl+C, closing the terminal, or a `SIGTERM` to the runner (sent when the Preflight that
+///   started the scan closes) kills every running job at once.
+/// - Progress is appended to `$PACSEA_SCAN_STATUS` when set, one line per event: `start <pid>
+///   <count>`, `done <secs> <name>`, then `end` or `cancelled`; see [`read_scan_status`].
+/// - VirusTotal stays a single job whose requests are paced by `PACSEA_VT_INTERVAL_SECS`.
+#[cfg(not(target_os = "windows"))]
+fn parallelize_scanners(cmds: Vec<String>) -> Vec<String> {
+    let mut prelude: Vec<String> = Vec::new();
+    let mut sections: Vec<(String, Vec<String>)> = Vec::new();
+    for cmd in cmds {
+        if let Some(name) = cmd
+            .strip_prefix("echo '--- ")
+            .and_then(|rest| rest.strip_suffix(" ---'"))
+        {
+            sections.push((name.to_string(), vec![cmd]));
+        } else if let Some((_, body)) = sections.last_mut() {
+            body.push(cmd);
+        } else {
+            prelude.push(cmd);
+        }
+    }
+    if sections.is_empty() {
+        return prelude;
+    }
+    let n = sections.len();
+    let mut runner = format!(
+        "( scan_st=\"${{PACSEA_SCAN_STATUS:-/dev/null}}\"; \
+         trap 'kill $(jobs -p) 2>/dev/null; echo cancelled >> \"$scan_st\"; echo; echo \"Scan cancelled.\"; exit 130' INT TERM HUP; \
+         echo \"start $BASHPID {n}\" >> \"$scan_st\"; scan_t0=$(date +%s); pids=(); names=(); "
+    );
+    for (i, (name, body)) in sections.iter().enumerate() {
+        runner.push_str(&format!(
+            "{{ {}; }} > ./.pacsea-job-{i}.log 2>&1 & pids[{i}]=$!; names[{i}]={}; ",
+            body.join(" && "),
+            super::utils::shell_single_quote(name)
+        ));
+    }
+    runner.push_str(&format!(
+        "echo \"Running {n} scanners in parallel…\"; left={n}; \
+         while [ \"$left\" -gt 0 ]; do \
+         for i in \"${{!pids[@]}}\"; do \
+         if ! kill -0 \"${{pids[$i]}}\" 2>/dev/null; then \
+         wait \"${{pids[$i]}}\" || true; cat \"./.pacsea-job-$i.log\"; rm -f \"./.pacsea-job-$i.log\"; \
+         scan_secs=$(( $(date +%s) - scan_t0 )); echo \"done $scan_secs ${{names[$i]}}\" >> \"$scan_st\"; \
+         echo -e \"\\033[1;32m[✔] ${{names[$i]}} finished after ${{scan_secs}}s\\033[0m\"; \
+         unset \"pids[$i]\"; left=$((left-1)); \
+         fi; done; \
+         if [ \"$left\" -gt 0 ]; then sleep 0.5; fi; \
+         done; echo end >> \"$scan_st\" )"
+    ));
+    prelude.push(runner);
+    prelude
+}
+
 #[cfg(not(target_os = "windows"))]
 /// What: Assemble the shell command sequence used to scan an AUR package in a temporary workspace.
 ///
@@ -38,7 +159,7 @@ fn build_scan_cmds_for_pkg(pkg: &str) -> Vec<String> {
     cmds.push("echo \"Pacsea: scanning AUR package '$pkg'\"".to_string());
     cmds.push("echo \"Working directory: $work\"".to_string());
     cmds.push("cd \"$work\" && { export PACSEA_DEBUG_LOG=\"$(pwd)/.pacsea_debug.log\"; exec > >(tee -a \"$PACSEA_DEBUG_LOG\") 2>&1; exec 9>>\"$PACSEA_DEBUG_LOG\"; export BASH_XTRACEFD=9; set -x; echo \"Pacsea debug: $(date) start scan for '$pkg' in $PWD\"; trap 'code=$?; echo; echo \"Pacsea debug: exit code=$code\"; echo \"Log: $PACSEA_DEBUG_LOG\"; echo \"Press any key to close...\"; read -rn1 -s _' EXIT; }".to_string());
-    cmds.push("if command -v git >/dev/null 2>&1 || sudo pacman -Qi git >/dev/null 2>&1; then :; else echo 'git not found. Cannot clone AUR repo.'; false; fi".to_string());
+    cmds.push("if command -v git >/dev/null 2>&1 || pacman -Qi git >/dev/null 2>&1; then :; else echo 'git not found. Cannot clone AUR repo.'; false; fi".to_string());
 
     // 1) Fetch PKGBUILD via AUR helper first; fallback to git clone
     cmds.push("echo 'Fetching PKGBUILD via AUR helper (-G)…'".to_string());
@@ -104,6 +225,10 @@ fn build_scan_cmds_for_pkg(pkg: &str) -> Vec<String> {
         fi; \
     }) || echo 'makepkg -o failed or partially completed; continuing'".to_string());
 
+    // 2b) Reuse cached verdicts when PKGBUILD, sources, and scanner versions are unchanged
+    cmds.push(SCAN_CACHE_LOOKUP.to_string());
+    let scanners_start = cmds.len();
+
     // 3) ClamAV scan
     cmds.push("if [ -z \"${PACSEA_PATTERNS_CRIT:-}\" ]; then export PACSEA_PATTERNS_CRIT='/dev/(tcp|udp)/|bash -i *>& *[^ ]*/dev/(tcp|udp)/[0-9]+|exec [0-9]{2,}<>/dev/(tcp|udp)/|rm -rf[[:space:]]+/|dd if=/dev/zero of=/dev/sd[a-z]|[>]{1,2}[[:space:]]*/dev/sd[a-z]|: *\\(\\) *\\{ *: *\\| *: *& *\\};:|/etc/sudoers([[:space:]>]|$)|echo .*[>]{2}.*(/etc/sudoers|/root/.ssh/authorized_keys)|/etc/ld\\.so\\.preload|LD_PRELOAD=|authorized_keys.*[>]{2}|ssh-rsa [A-Za-z0-9+/=]+.*[>]{2}.*authorized_keys|curl .*(169\\.254\\.169\\.254)'; fi".to_string());
     cmds.push("if [ -z \"${PACSEA_PATTERNS_HIGH:-}\" ]; then export PACSEA_PATTERNS_HIGH='eval|base64 -d|wget .*(sh|bash|dash|ksh|zsh)([^A-Za-z]|$)|curl .*(sh|bash|dash|ksh|zsh)([^A-Za-z]|$)|sudo[[:space:]]|chattr[[:space:]]|useradd|adduser|groupadd|systemctl|service[[:space:]]|crontab|/etc/cron\\.|[>]{2}.*(\\.bashrc|\\.bash_profile|/etc/profile|\\.zshrc)|cat[[:space:]]+/etc/shadow|cat[[:space:]]+~/.ssh/id_rsa|cat[[:space:]]+~/.bash_history|systemctl stop (auditd|rsyslog)|service (auditd|rsyslog) stop|scp .*@|curl -F|nc[[:space:]].*<|tar -czv?f|zip -r'; fi".to_string());
@@ -111,16 +236,16 @@ fn build_scan_cmds_for_pkg(pkg: &str) -> Vec<String> {
     cmds.push("if [ -z \"${PACSEA_PATTERNS_LOW:-}\" ]; then export PACSEA_PATTERNS_LOW='http_proxy=|https_proxy=|ALL_PROXY=|yes[[:space:]]+> */dev/null *&|ulimit -n [0-9]{5,}'; fi".to_string());
     cmds.push("echo '--- ClamAV scan (optional) ---'".to_string());
     cmds.push("echo -e '\\033[1;34m[🔍] ClamAV scan (optional)\\033[0m'".to_string());
-    cmds.push("(if [ \"${PACSEA_SCAN_DO_CLAMAV:-1}\" = \"1\" ]; then ((command -v clamscan >/dev/null 2>&1 || sudo pacman -Qi clamav >/dev/null 2>&1) && { if find /var/lib/clamav -maxdepth 1 -type f \\( -name '*.cvd' -o -name '*.cld' \\) 2>/dev/null | grep -q .; then clamscan -r . | tee ./.pacsea_scan_clamav.txt; else echo 'ClamAV found but no signature database in /var/lib/clamav'; echo 'Tip: run: sudo freshclam  (or start the updater: sudo systemctl start clamav-freshclam)'; fi; } || echo 'ClamAV (clamscan) encountered an error; skipping') || echo 'ClamAV not found; skipping'; else echo 'ClamAV: skipped by config'; fi)".to_string());
+    cmds.push("(if [ \"${PACSEA_SCAN_DO_CLAMAV:-1}\" = \"1\" ]; then ((command -v clamscan >/dev/null 2>&1 || pacman -Qi clamav >/dev/null 2>&1) && { if find /var/lib/clamav -maxdepth 1 -type f \\( -name '*.cvd' -o -name '*.cld' \\) 2>/dev/null | grep -q .; then clamscan -r . | tee ./.pacsea_scan_clamav.txt; else echo 'ClamAV found but no signature database in /var/lib/clamav'; echo 'Tip: run: sudo freshclam  (or start the updater: sudo systemctl start clamav-freshclam)'; fi; } || echo 'ClamAV (clamscan) encountered an error; skipping') || echo 'ClamAV not found; skipping'; else echo 'ClamAV: skipped by config'; fi)".to_string());
     // 4) Trivy filesystem scan
     cmds.push("echo '--- Trivy filesystem scan (optional) ---'".to_string());
     cmds.push("echo -e '\\033[1;34m[🧰] Trivy filesystem scan (optional)\\033[0m'".to_string());
-    cmds.push("(if [ \"${PACSEA_SCAN_DO_TRIVY:-1}\" = \"1\" ]; then ((command -v trivy >/dev/null 2>&1 || sudo pacman -Qi trivy >/dev/null 2>&1) && (trivy fs --quiet --format json . > ./.pacsea_scan_trivy.json || trivy fs --quiet . | tee ./.pacsea_scan_trivy.txt) || echo 'Trivy not found or failed; skipping'); else echo 'Trivy: skipped by config'; fi)".to_string());
+    cmds.push("(if [ \"${PACSEA_SCAN_DO_TRIVY:-1}\" = \"1\" ]; then ((command -v trivy >/dev/null 2>&1 || pacman -Qi trivy >/dev/null 2>&1) && (trivy fs --quiet --format json . > ./.pacsea_scan_trivy.json || trivy fs --quiet . | tee ./.pacsea_scan_trivy.txt) || echo 'Trivy not found or failed; skipping'); else echo 'Trivy: skipped by config'; fi)".to_string());
 
     // 5) Semgrep static analysis
     cmds.push("echo '--- Semgrep static analysis (optional) ---'".to_string());
     cmds.push("echo -e '\\033[1;34m[🧪] Semgrep static analysis (optional)\\033[0m'".to_string());
-    cmds.push("(if [ \"${PACSEA_SCAN_DO_SEMGREP:-1}\" = \"1\" ]; then ((command -v semgrep >/dev/null 2>&1 || sudo pacman -Qi semgrep >/dev/null 2>&1) && (semgrep --config=auto --json . > ./.pacsea_scan_semgrep.json || semgrep --config=auto . | tee ./.pacsea_scan_semgrep.txt) || echo 'Semgrep not found; skipping'); else echo 'Semgrep: skipped by config'; fi)".to_string());
+    cmds.push("(if [ \"${PACSEA_SCAN_DO_SEMGREP:-1}\" = \"1\" ]; then ((command -v semgrep >/dev/null 2>&1 || pacman -Qi semgrep >/dev/null 2>&1) && (semgrep --config=auto --json . > ./.pacsea_scan_semgrep.json || semgrep --config=auto . | tee ./.pacsea_scan_semgrep.txt) || echo 'Semgrep not found; skipping'); else echo 'Semgrep: skipped by config'; fi)".to_string());
 
     // 6) VirusTotal hash lookups
     // 6) ShellCheck lint (PKGBUILD and *.install) and Risk evaluation
@@ -130,7 +255,7 @@ fn build_scan_cmds_for_pkg(pkg: &str) -> Vec<String> {
     cmds.push("(if [ \"${PACSEA_SCAN_DO_SLEUTH:-1}\" = \"1\" ]; then A_SLEUTH=\"$(command -v aur-sleuth 2>/dev/null || true)\"; if [ -z \"$A_SLEUTH\" ] && [ -x \"${HOME}/.local/bin/aur-sleuth\" ]; then A_SLEUTH=\"${HOME}/.local/bin/aur-sleuth\"; fi; if [ -z \"$A_SLEUTH\" ] && [ -x \"/usr/local/bin/aur-sleuth\" ]; then A_SLEUTH=\"/usr/local/bin/aur-sleuth\"; fi; if [ -z \"$A_SLEUTH\" ] && [ -x \"/usr/bin/aur-sleuth\" ]; then A_SLEUTH=\"/usr/bin/aur-sleuth\"; fi; if [ -n \"$A_SLEUTH\" ]; then cfg=\"${XDG_CONFIG_HOME:-$HOME/.config}/pacsea/settings.conf\"; if [ -f \"$cfg\" ]; then get_key() { awk -F= -v k=\"$1\" 'tolower($0) ~ \"^[[:space:]]*\"k\"[[:space:]]*=\" {sub(/#.*/,\"\",$2); gsub(/^[[:space:]]+|[[:space:]]+$/,\"\",$2); print $2; exit }' \"$cfg\"; }; HP=$(get_key http_proxy); [ -n \"$HP\" ] && export http_proxy=\"$HP\"; XP=$(get_key https_proxy); [ -n \"$XP\" ] && export https_proxy=\"$XP\"; AP=$(get_key all_proxy); [ -n \"$AP\" ] && export ALL_PROXY=\"$AP\"; NP=$(get_key no_proxy); [ -n \"$NP\" ] && export NO_PROXY=\"$NP\"; CAB=$(get_key requests_ca_bundle); [ -n \"$CAB\" ] && export REQUESTS_CA_BUNDLE=\"$CAB\"; SCF=$(get_key ssl_cert_file); [ -n \"$SCF\" ] && export SSL_CERT_FILE=\"$SCF\"; CCB=$(get_key curl_ca_bundle); [ -n \"$CCB\" ] && export CURL_CA_BUNDLE=\"$CCB\"; PIPIDX=$(get_key pip_index_url); [ -n \"$PIPIDX\" ] && export PIP_INDEX_URL=\"$PIPIDX\"; PIPEX=$(get_key pip_extra_index_url); [ -n \"$PIPEX\" ] && export PIP_EXTRA_INDEX_URL=\"$PIPEX\"; PIPTH=$(get_key pip_trusted_host); [ -n \"$PIPTH\" ] && export PIP_TRUSTED_HOST=\"$PIPTH\"; UVCA=$(get_key uv_http_ca_certs); [ -n \"$UVCA\" ] && export UV_HTTP_CA_CERTS=\"$UVCA\"; fi; \"$A_SLEUTH\" --output plain --pkgdir . | tee ./.pacsea_sleuth.txt || echo 'aur-sleuth failed; see output above'; else echo 'aur-sleuth not found (checked PATH, ~/.local/bin, /usr/local/bin, /usr/bin)'; fi; else echo 'aur-sleuth: skipped by config'; fi)".to_string());
     cmds.push("echo '--- ShellCheck lint (optional) ---'".to_string());
     cmds.push("echo -e '\\033[1;34m[🧹] ShellCheck lint (optional)\\033[0m'".to_string());
-    cmds.push("(if [ \"${PACSEA_SCAN_DO_SHELLCHECK:-1}\" = \"1\" ]; then if command -v shellcheck >/dev/null 2>&1 || sudo pacman -Qi shellcheck >/dev/null 2>&1; then if [ -f PKGBUILD ]; then echo \"[shellcheck] Analyzing: PKGBUILD (bash, -e SC2034)\"; (shellcheck -s bash -x -e SC2034 -f json PKGBUILD > ./.pacsea_shellcheck_pkgbuild.json || shellcheck -s bash -x -e SC2034 PKGBUILD | tee ./.pacsea_shellcheck_pkgbuild.txt || true); fi; inst_files=(); while IFS= read -r -d '' f; do inst_files+=(\"$f\"); done < <(find . -maxdepth 1 -type f -name \"*.install\" -print0); if [ \"${#inst_files[@]}\" -gt 0 ]; then echo \"[shellcheck] Analyzing: ${inst_files[*]} (bash)\"; (shellcheck -s bash -x -f json \"${inst_files[@]}\" > ./.pacsea_shellcheck_install.json || shellcheck -s bash -x \"${inst_files[@]}\" | tee ./.pacsea_shellcheck_install.txt || true); fi; else echo 'ShellCheck not found; skipping'; fi; else echo 'ShellCheck: skipped by config'; fi)".to_string());
+    cmds.push("(if [ \"${PACSEA_SCAN_DO_SHELLCHECK:-1}\" = \"1\" ]; then if command -v shellcheck >/dev/null 2>&1 || pacman -Qi shellcheck >/dev/null 2>&1; then if [ -f PKGBUILD ]; then echo \"[shellcheck] Analyzing: PKGBUILD (bash, -e SC2034)\"; (shellcheck -s bash -x -e SC2034 -f json PKGBUILD > ./.pacsea_shellcheck_pkgbuild.json || shellcheck -s bash -x -e SC2034 PKGBUILD | tee ./.pacsea_shellcheck_pkgbuild.txt || true); fi; inst_files=(); while IFS= read -r -d '' f; do inst_files+=(\"$f\"); done < <(find . -maxdepth 1 -type f -name \"*.install\" -print0); if [ \"${#inst_files[@]}\" -gt 0 ]; then echo \"[shellcheck] Analyzing: ${inst_files[*]} (bash)\"; (shellcheck -s bash -x -f json \"${inst_files[@]}\" > ./.pacsea_shellcheck_install.json || shellcheck -s bash -x \"${inst_files[@]}\" | tee ./.pacsea_shellcheck_install.txt || true); fi; else echo 'ShellCheck not found; skipping'; fi; else echo 'ShellCheck: skipped by config'; fi)".to_string());
     cmds.push("(if [ \"${PACSEA_SCAN_DO_SHELLCHECK:-1}\" = \"1\" ]; then echo -e '\\033[1;33m[⚠️ ] Risk evaluation (PKGBUILD/.install)\\033[0m'; ({ sc_err=0; sc_warn=0; sc_info=0; sc_err=$((sc_err + $(cat ./.pacsea_shellcheck_pkgbuild.json ./.pacsea_shellcheck_install.json 2>/dev/null | grep -o '\"level\":\"error\"' | wc -l))); sc_warn=$((sc_warn + $(cat ./.pacsea_shellcheck_pkgbuild.json ./.pacsea_shellcheck_install.json 2>/dev/null | grep -o '\"level\":\"warning\"' | wc -l))); sc_info=$((sc_info + $(cat ./.pacsea_shellcheck_pkgbuild.json ./.pacsea_shellcheck_install.json 2>/dev/null | grep -o '\"level\":\"info\"' | wc -l))); sc_err=$((sc_err + $(cat ./.pacsea_shellcheck_pkgbuild.txt ./.pacsea_shellcheck_install.txt 2>/dev/null | grep -oi 'error:' | wc -l))); sc_warn=$((sc_warn + $(cat ./.pacsea_shellcheck_pkgbuild.txt ./.pacsea_shellcheck_install.txt 2>/dev/null | grep -oi 'warning:' | wc -l))); if [ -f PKGBUILD ]; then pkgrisk=$(grep -Eoi 'curl|wget|bash -c|sudo|chown|chmod|mktemp|systemctl|useradd|groupadd|nc\\s|socat|/tmp/' PKGBUILD | wc -l); else pkgrisk=0; fi; if ls ./*.install >/dev/null 2>&1; then inst_risk=$(grep -Eoi 'post_install|pre_install|post_upgrade|pre_upgrade|systemctl|useradd|groupadd|chown|chmod|sudo|service|adduser' ./*.install | wc -l); else inst_risk=0; fi; risk=$((sc_err*5 + sc_warn*2 + sc_info + pkgrisk*3 + inst_risk*4)); tier='LOW'; if [ \"$risk\" -ge 60 ]; then tier='CRITICAL'; elif [ \"$risk\" -ge 40 ]; then tier='HIGH'; elif [ \"$risk\" -ge 20 ]; then tier='MEDIUM'; fi; { echo \"SC_ERRORS=$sc_err\"; echo \"SC_WARNINGS=$sc_warn\"; echo \"SC_INFO=$sc_info\"; echo \"PKGBUILD_HEURISTICS=$pkgrisk\"; echo \"INSTALL_HEURISTICS=$inst_risk\"; echo \"RISK_SCORE=$risk\"; echo \"RISK_TIER=$tier\"; } > ./.pacsea_shellcheck_risk.txt; echo \"Risk score: $risk ($tier)\"; } || echo 'Risk evaluation encountered an error; skipping'); else echo 'Risk Evaluation: skipped (ShellCheck disabled)'; fi)".to_string());
     // Custom suspicious patterns scan (optional)
     cmds.push("echo '--- Custom suspicious patterns scan (optional) ---'".to_string());
@@ -213,7 +338,7 @@ fi)"#.to_string());
             "fi; ",
             "if [ -n \"${VT_API_KEY:-}\" ]; then ",
             "  files=$(find . -type f \\( -name 'PKGBUILD' -o -path './src/*' -o -name '*.patch' -o -name '*.diff' \\) 2>/dev/null); ",
-            "  vt_total=0; vt_known=0; vt_unknown=0; vt_mal_sum=0; vt_sus_sum=0; vt_har_sum=0; vt_und_sum=0; ",
+            "  vt_total=0; vt_requests=0; vt_known=0; vt_unknown=0; vt_mal_sum=0; vt_sus_sum=0; vt_har_sum=0; vt_und_sum=0; ",
             "  : > ./.pacsea_scan_vt.txt; ",
             "  if [ -z \"$files\" ]; then ",
             "    echo 'No files to hash (PKGBUILD/src)'; ",
@@ -224,8 +349,28 @@ fi)"#.to_string());
             "        echo \"File: $f\" | tee -a ./.pacsea_scan_vt.txt; ",
             "        echo \"SHA256: $h\" | tee -a ./.pacsea_scan_vt.txt; ",
             "        vt_total=$((vt_total+1)); ",
-            "        resp=$(curl -s -H \"x-apikey: $VT_API_KEY\" \"https://www.virustotal.com/api/v3/files/$h\"); ",
-            "        if echo \"$resp\" | grep -q '\"error\"'; then ",
+            "        vt_cache=''; if [ -n \"${PACSEA_SCAN_CACHE_DIR:-}\" ]; then vt_cache=\"$PACSEA_SCAN_CACHE_DIR/vt/$h.json\"; fi; ",
+            "        if [ -n \"$vt_cache\" ] && [ \"${PACSEA_SCAN_FORCE:-0}\" != 1 ] && [ -s \"$vt_cache\" ] && [ -n \"$(find \"$vt_cache\" -mmin -10080 2>/dev/null)\" ]; then ",
+            "          resp=$(cat \"$vt_cache\"); ",
+            "          echo 'VT: using cached verdict' | tee -a ./.pacsea_scan_vt.txt; ",
+            "        else ",
+            "          vt_wait=\"${PACSEA_VT_INTERVAL_SECS:-15}\"; ",
+            "          if [ \"$vt_requests\" -gt 0 ] && [ \"$vt_wait\" -gt 0 ]; then sleep \"$vt_wait\"; fi; ",
+            "          vt_requests=$((vt_requests+1)); vt_backoff=60; ",
+            "          for vt_try in 1 2 3 4; do ",
+            "            resp=$(curl -s -H \"x-apikey: $VT_API_KEY\" \"https://www.virustotal.com/api/v3/files/$h\"); ",
+            "            if [ \"$vt_try\" -lt 4 ] && echo \"$resp\" | grep -q 'QuotaExceededError'; then ",
+            "              echo \"VT: rate limited, retrying in ${vt_backoff}s…\"; sleep \"$vt_backoff\"; vt_backoff=$((vt_backoff*2)); ",
+            "            else break; fi; ",
+            "          done; ",
+            "          if [ -n \"$vt_cache\" ] && [ -n \"$resp\" ] && ! echo \"$resp\" | grep -q '\"error\"'; then ",
+            "            mkdir -p \"${vt_cache%/*}\" && printf '%s' \"$resp\" > \"$vt_cache\"; ",
+            "          fi; ",
+            "        fi; ",
+            "        if echo \"$resp\" | grep -q 'QuotaExceededError'; then ",
+            "          echo 'VT: quota still exceeded after retries; lookup skipped' | tee -a ./.pacsea_scan_vt.txt; ",
+            "          vt_unknown=$((vt_unknown+1)); ",
+            "        elif echo \"$resp\" | grep -q '\"error\"'; then ",
             "          echo 'VT: No report found' | tee -a ./.pacsea_scan_vt.txt; ",
             "          vt_unknown=$((vt_unknown+1)); ",
             "        else ",
@@ -264,6 +409,13 @@ fi)"#.to_string());
         .to_string(),
     );
 
+    // Scanners run concurrently, and only on a cache miss (or a forced rescan)
+    let scanners = parallelize_scanners(cmds.split_off(scanners_start));
+    cmds.push(format!(
+        "if [ \"${{PACSEA_SCAN_CACHED:-0}}\" != 1 ]; then {{ {}; }}; else echo cached >> \"${{PACSEA_SCAN_STATUS:-/dev/null}}\"; fi",
+        scanners.join(" && ")
+    ));
+
     // 7) Final note with working directory for manual inspection
     cmds.push("echo".to_string());
     cmds.push("echo '--- Summary ---'".to_string());
@@ -452,6 +604,7 @@ else
 fi"#
             .to_string(),
     );
+    cmds.push(SCAN_CACHE_STORE.to_string());
     cmds.push("echo".to_string());
     cmds.push("echo \"Pacsea: scan finished. Working directory preserved: $work\"".to_string());
     cmds.push("echo -e \"\\033[1;32m[✔] Pacsea: scan finished.\\033[0m Working directory preserved: $work\"".to_string());
@@ -481,20 +634,22 @@ fn build_scan_cmds_in_dir(target_dir: &str) -> Vec<String> {
     cmds.push("echo \"Pacsea: scanning directory in-place: '$target_dir'\"".to_string());
     cmds.push("cd \"$target_dir\" && { export PACSEA_DEBUG_LOG=\"$(pwd)/.pacsea_debug.log\"; exec > >(tee -a \"$PACSEA_DEBUG_LOG\") 2>&1; exec 9>>\"$PACSEA_DEBUG_LOG\"; export BASH_XTRACEFD=9; set -x; echo \"Pacsea debug: $(date) start in-place scan for '$target_dir' in $PWD\"; trap 'code=$?; echo; echo \"Pacsea debug: exit code=$code\"; echo \"Log: $PACSEA_DEBUG_LOG\"; echo \"Press any key to close...\"; read -rn1 -s _' EXIT; }".to_string());
 
+    let scanners_start = cmds.len();
+
     // 1) ClamAV scan (optional)
     cmds.push("echo '--- ClamAV scan (optional) ---'".to_string());
     cmds.push("echo -e '\\033[1;34m[🔍] ClamAV scan (optional)\\033[0m'".to_string());
-    cmds.push("(if [ \"${PACSEA_SCAN_DO_CLAMAV:-1}\" = \"1\" ]; then ((command -v clamscan >/dev/null 2>&1 || sudo pacman -Qi clamav >/dev/null 2>&1) && { if find /var/lib/clamav -maxdepth 1 -type f \\( -name '*.cvd' -o -name '*.cld' \\) 2>/dev/null | grep -q .; then clamscan -r . | tee ./.pacsea_scan_clamav.txt; else echo 'ClamAV found but no signature database in /var/lib/clamav'; echo 'Tip: run: sudo freshclam  (or start the updater: sudo systemctl start clamav-freshclam)'; fi; } || echo 'ClamAV (clamscan) encountered an error; skipping') || echo 'ClamAV not found; skipping'; else echo 'ClamAV: skipped by config'; fi)".to_string());
+    cmds.push("(if [ \"${PACSEA_SCAN_DO_CLAMAV:-1}\" = \"1\" ]; then ((command -v clamscan >/dev/null 2>&1 || pacman -Qi clamav >/dev/null 2>&1) && { if find /var/lib/clamav -maxdepth 1 -type f \\( -name '*.cvd' -o -name '*.cld' \\) 2>/dev/null | grep -q .; then clamscan -r . | tee ./.pacsea_scan_clamav.txt; else echo 'ClamAV found but no signature database in /var/lib/clamav'; echo 'Tip: run: sudo freshclam  (or start the updater: sudo systemctl start clamav-freshclam)'; fi; } || echo 'ClamAV (clamscan) encountered an error; skipping') || echo 'ClamAV not found; skipping'; else echo 'ClamAV: skipped by config'; fi)".to_string());
 
     // 2) Trivy filesystem scan (optional)
     cmds.push("echo '--- Trivy filesystem scan (optional) ---'".to_string());
     cmds.push("echo -e '\\033[1;34m[🧰] Trivy filesystem scan (optional)\\033[0m'".to_string());
-    cmds.push("(if [ \"${PACSEA_SCAN_DO_TRIVY:-1}\" = \"1\" ]; then ((command -v trivy >/dev/null 2>&1 || sudo pacman -Qi trivy >/dev/null 2>&1) && (trivy fs --quiet --format json . > ./.pacsea_scan_trivy.json || trivy fs --quiet . | tee ./.pacsea_scan_trivy.txt) || echo 'Trivy not found or failed; skipping'); else echo 'Trivy: skipped by config'; fi)".to_string());
+    cmds.push("(if [ \"${PACSEA_SCAN_DO_TRIVY:-1}\" = \"1\" ]; then ((command -v trivy >/dev/null 2>&1 || pacman -Qi trivy >/dev/null 2>&1) && (trivy fs --quiet --format json . > ./.pacsea_scan_trivy.json || trivy fs --quiet . | tee ./.pacsea_scan_trivy.txt) || echo 'Trivy not found or failed; skipping'); else echo 'Trivy: skipped by config'; fi)".to_string());
 
     // 3) Semgrep static analysis (optional)
     cmds.push("echo '--- Semgrep static analysis (optional) ---'".to_string());
     cmds.push("echo -e '\\033[1;34m[🧪] Semgrep static analysis (optional)\\033[0m'".to_string());
-    cmds.push("(if [ \"${PACSEA_SCAN_DO_SEMGREP:-1}\" = \"1\" ]; then ((command -v semgrep >/dev/null 2>&1 || sudo pacman -Qi semgrep >/dev/null 2>&1) && (semgrep --config=auto --json . > ./.pacsea_scan_semgrep.json || semgrep --config=auto . | tee ./.pacsea_scan_semgrep.txt) || echo 'Semgrep not found; skipping'); else echo 'Semgrep: skipped by config'; fi)".to_string());
+    cmds.push("(if [ \"${PACSEA_SCAN_DO_SEMGREP:-1}\" = \"1\" ]; then ((command -v semgrep >/dev/null 2>&1 || pacman -Qi semgrep >/dev/null 2>&1) && (semgrep --config=auto --json . > ./.pacsea_scan_semgrep.json || semgrep --config=auto . | tee ./.pacsea_scan_semgrep.txt) || echo 'Semgrep not found; skipping'); else echo 'Semgrep: skipped by config'; fi)".to_string());
 
     // 4) aur-sleuth audit (optional)
     cmds.push("echo '--- aur-sleuth audit (optional) ---'".to_string());
@@ -504,7 +659,7 @@ fn build_scan_cmds_in_dir(target_dir: &str) -> Vec<String> {
     // 5) ShellCheck lint + Risk eval (optional)
     cmds.push("echo '--- ShellCheck lint (optional) ---'".to_string());
     cmds.push("echo -e '\\033[1;34m[🧹] ShellCheck lint (optional)\\033[0m'".to_string());
-    cmds.push("(if [ \"${PACSEA_SCAN_DO_SHELLCHECK:-1}\" = \"1\" ]; then if command -v shellcheck >/dev/null 2>&1 || sudo pacman -Qi shellcheck >/dev/null 2>&1; then if [ -f PKGBUILD ]; then echo \"[shellcheck] Analyzing: PKGBUILD (bash, -e SC2034)\"; (shellcheck -s bash -x -e SC2034 -f json PKGBUILD > ./.pacsea_shellcheck_pkgbuild.json || shellcheck -s bash -x -e SC2034 PKGBUILD | tee ./.pacsea_shellcheck_pkgbuild.txt || true); fi; inst_files=(); while IFS= read -r -d '' f; do inst_files+=(\"$f\"); done < <(find . -maxdepth 1 -type f -name \"*.install\" -print0); if [ \"${#inst_files[@]}\" -gt 0 ]; then echo \"[shellcheck] Analyzing: ${inst_files[*]} (bash)\"; (shellcheck -s bash -x -f json \"${inst_files[@]}\" > ./.pacsea_shellcheck_install.json || shellcheck -s bash -x \"${inst_files[@]}\" | tee ./.pacsea_shellcheck_install.txt || true); fi; else echo 'ShellCheck not found; skipping'; fi; else echo 'ShellCheck: skipped by config'; fi)".to_string());
+    cmds.push("(if [ \"${PACSEA_SCAN_DO_SHELLCHECK:-1}\" = \"1\" ]; then if command -v shellcheck >/dev/null 2>&1 || pacman -Qi shellcheck >/dev/null 2>&1; then if [ -f PKGBUILD ]; then echo \"[shellcheck] Analyzing: PKGBUILD (bash, -e SC2034)\"; (shellcheck -s bash -x -e SC2034 -f json PKGBUILD > ./.pacsea_shellcheck_pkgbuild.json || shellcheck -s bash -x -e SC2034 PKGBUILD | tee ./.pacsea_shellcheck_pkgbuild.txt || true); fi; inst_files=(); while IFS= read -r -d '' f; do inst_files+=(\"$f\"); done < <(find . -maxdepth 1 -type f -name \"*.install\" -print0); if [ \"${#inst_files[@]}\" -gt 0 ]; then echo \"[shellcheck] Analyzing: ${inst_files[*]} (bash)\"; (shellcheck -s bash -x -f json \"${inst_files[@]}\" > ./.pacsea_shellcheck_install.json || shellcheck -s bash -x \"${inst_files[@]}\" | tee ./.pacsea_shellcheck_install.txt || true); fi; else echo 'ShellCheck not found; skipping'; fi; else echo 'ShellCheck: skipped by config'; fi)".to_string());
     cmds.push("(if [ \"${PACSEA_SCAN_DO_SHELLCHECK:-1}\" = \"1\" ]; then echo -e '\\033[1;33m[⚠️ ] Risk evaluation (PKGBUILD/.install)\\033[0m'; ({ sc_err=0; sc_warn=0; sc_info=0; sc_err=$((sc_err + $(cat ./.pacsea_shellcheck_pkgbuild.json ./.pacsea_shellcheck_install.json 2>/dev/null | grep -o '\"level\":\"error\"' | wc -l))); sc_warn=$((sc_warn + $(cat ./.pacsea_shellcheck_pkgbuild.json ./.pacsea_shellcheck_install.json 2>/dev/null | grep -o '\"level\":\"warning\"' | wc -l))); sc_info=$((sc_info + $(cat ./.pacsea_shellcheck_pkgbuild.json ./.pacsea_shellcheck_install.json 2>/dev/null | grep -o '\"level\":\"info\"' | wc -l))); sc_err=$((sc_err + $(cat ./.pacsea_shellcheck_pkgbuild.txt ./.pacsea_shellcheck_install.txt 2>/dev/null | grep -oi 'error:' | wc -l))); sc_warn=$((sc_warn + $(cat ./.pacsea_shellcheck_pkgbuild.txt ./.pacsea_shellcheck_install.txt 2>/dev/null | grep -oi 'warning:' | wc -l))); if [ -f PKGBUILD ]; then pkgrisk=$(grep -Eoi 'curl|wget|bash -c|sudo|chown|chmod|mktemp|systemctl|useradd|groupadd|nc\\s|socat|/tmp/' PKGBUILD | wc -l); else pkgrisk=0; fi; if ls ./*.install >/dev/null 2>&1; then inst_risk=$(grep -Eoi 'post_install|pre_install|post_upgrade|pre_upgrade|systemctl|useradd|groupadd|chown|chmod|sudo|service|adduser' ./*.install | wc -l); else inst_risk=0; fi; risk=$((sc_err*5 + sc_warn*2 + sc_info + pkgrisk*3 + inst_risk*4)); tier='LOW'; if [ \"$risk\" -ge 60 ]; then tier='CRITICAL'; elif [ \"$risk\" -ge 40 ]; then tier='HIGH'; elif [ \"$risk\" -ge 20 ]; then tier='MEDIUM'; fi; { echo \"SC_ERRORS=$sc_err\"; echo \"SC_WARNINGS=$sc_warn\"; echo \"SC_INFO=$sc_info\"; echo \"PKGBUILD_HEURISTICS=$pkgrisk\"; echo \"INSTALL_HEURISTICS=$inst_risk\"; echo \"RISK_SCORE=$risk\"; echo \"RISK_TIER=$tier\"; } > ./.pacsea_shellcheck_risk.txt; echo \"Risk score: $risk ($tier)\"; } || echo 'Risk evaluation encountered an error; skipping'); else echo 'Risk Evaluation: skipped (ShellCheck disabled)'; fi)".to_string());
 
     // 6) Custom suspicious patterns (optional)
@@ -584,7 +739,7 @@ fi)"#.to_string());
             "fi; ",
             "if [ -n \"${VT_API_KEY:-}\" ]; then ",
             "  files=$(find . -type f \\( -name 'PKGBUILD' -o -path './src/*' -o -name '*.patch' -o -name '*.diff' \\) 2>/dev/null); ",
-            "  vt_total=0; vt_known=0; vt_unknown=0; vt_mal_sum=0; vt_sus_sum=0; vt_har_sum=0; vt_und_sum=0; ",
+            "  vt_total=0; vt_requests=0; vt_known=0; vt_unknown=0; vt_mal_sum=0; vt_sus_sum=0; vt_har_sum=0; vt_und_sum=0; ",
             "  : > ./.pacsea_scan_vt.txt; ",
             "  if [ -z \"$files\" ]; then ",
             "    echo 'No files to hash (PKGBUILD/src)'; ",
@@ -595,8 +750,28 @@ fi)"#.to_string());
             "        echo \"File: $f\" | tee -a ./.pacsea_scan_vt.txt; ",
             "        echo \"SHA256: $h\" | tee -a ./.pacsea_scan_vt.txt; ",
             "        vt_total=$((vt_total+1)); ",
-            "        resp=$(curl -s -H \"x-apikey: $VT_API_KEY\" \"https://www.virustotal.com/api/v3/files/$h\"); ",
-            "        if echo \"$resp\" | grep -q '\"error\"'; then ",
+            "        vt_cache=''; if [ -n \"${PACSEA_SCAN_CACHE_DIR:-}\" ]; then vt_cache=\"$PACSEA_SCAN_CACHE_DIR/vt/$h.json\"; fi; ",
+            "        if [ -n \"$vt_cache\" ] && [ \"${PACSEA_SCAN_FORCE:-0}\" != 1 ] && [ -s \"$vt_cache\" ] && [ -n \"$(find \"$vt_cache\" -mmin -10080 2>/dev/null)\" ]; then ",
+            "          resp=$(cat \"$vt_cache\"); ",
+            "          echo 'VT: using cached verdict' | tee -a ./.pacsea_scan_vt.txt; ",
+            "        else ",
+            "          vt_wait=\"${PACSEA_VT_INTERVAL_SECS:-15}\"; ",
+            "          if [ \"$vt_requests\" -gt 0 ] && [ \"$vt_wait\" -gt 0 ]; then sleep \"$vt_wait\"; fi; ",
+            "          vt_requests=$((vt_requests+1)); vt_backoff=60; ",
+            "          for vt_try in 1 2 3 4; do ",
+            "            resp=$(curl -s -H \"x-apikey: $VT_API_KEY\" \"https://www.virustotal.com/api/v3/files/$h\"); ",
+            "            if [ \"$vt_try\" -lt 4 ] && echo \"$resp\" | grep -q 'QuotaExceededError'; then ",
+            "              echo \"VT: rate limited, retrying in ${vt_backoff}s…\"; sleep \"$vt_backoff\"; vt_backoff=$((vt_backoff*2)); ",
+            "            else break; fi; ",
+            "          done; ",
+            "          if [ -n \"$vt_cache\" ] && [ -n \"$resp\" ] && ! echo \"$resp\" | grep -q '\"error\"'; then ",
+            "            mkdir -p \"${vt_cache%/*}\" && printf '%s' \"$resp\" > \"$vt_cache\"; ",
+            "          fi; ",
+            "        fi; ",
+            "        if echo \"$resp\" | grep -q 'QuotaExceededError'; then ",
+            "          echo 'VT: quota still exceeded after retries; lookup skipped' | tee -a ./.pacsea_scan_vt.txt; ",
+            "          vt_unknown=$((vt_unknown+1)); ",
+            "        elif echo \"$resp\" | grep -q '\"error\"'; then ",
             "          echo 'VT: No report found' | tee -a ./.pacsea_scan_vt.txt; ",
             "          vt_unknown=$((vt_unknown+1)); ",
             "        else ",
@@ -635,6 +810,10 @@ fi)"#.to_string());
         .to_string(),
     );
 
+    // Scanners run concurrently
+    let scanners = parallelize_scanners(cmds.split_off(scanners_start));
+    cmds.extend(scanners);
+
     // 8) Summary and completion
     cmds.push("echo".to_string());
     cmds.push("echo '--- Summary ---'".to_string());
@@ -644,6 +823,81 @@ fi)"#.to_string());
     cmds
 }
 
+/// What: Directory holding cached scan verdicts (`lists_dir/scan_cache/<hash>.json`).
+pub fn scan_cache_dir() -> std::path::PathBuf {
+    crate::theme::lists_dir().join("scan_cache")
+}
+
+/// What: Update a scan's progress from the lines in its status file.
+///
+/// Inputs:
+/// - `progress`: Scan to update.
+/// - `status`: Status file contents written by the scan script.
+///
+/// Output:
+/// - None; `pid`, `total`, `finished`, and `phase` reflect every line read so far.
+///
+/// Details:
+/// - The file is re-read from the start on every poll, so the finished list is rebuilt rather
+///   than appended to; unknown lines are ignored.
+pub fn read_scan_status(progress: &mut ScanProgress, status: &str) {
+    progress.finished.clear();
+    for line in status.lines() {
+        let mut parts = line.splitn(3, ' ');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some("start"), Some(pid), Some(total)) => {
+                progress.pid = pid.parse().ok();
+                progress.total = total.trim().parse().unwrap_or(0);
+                progress.phase = ScanPhase::Running;
+            }
+            (Some("done"), Some(secs), Some(name)) => {
+                progress
+                    .finished
+                    .push((name.trim().to_string(), secs.parse().unwrap_or(0)));
+            }
+            (Some("end"), None, None) => progress.phase = ScanPhase::Finished,
+            (Some("cached"), None, None) => progress.phase = ScanPhase::Cached,
+            (Some("cancelled"), None, None) => progress.phase = ScanPhase::Cancelled,
+            _ => {}
+        }
+    }
+}
+
+/// What: Refresh terminal scans started from Preflight and stop them once it closes.
+///
+/// Inputs:
+/// - `scans`: Tracked scans (`AppState::scan_progress`).
+/// - `cancelled`: `AppState::preflight_cancelled`; set when the Preflight was closed.
+///
+/// Output:
+/// - None; updates each scan from its status file, or empties `scans` when cancelled.
+///
+/// Details:
+/// - Cancelling sends `SIGTERM` to each runner still scanning; its trap kills the scanner jobs
+///   and the terminal reports the scan as cancelled. Status files are removed once dropped.
+pub fn poll_scans(scans: &mut Vec<ScanProgress>, cancelled: bool) {
+    for scan in scans.iter_mut() {
+        if let Ok(status) = std::fs::read_to_string(&scan.status_path) {
+            read_scan_status(scan, &status);
+        }
+    }
+    if !cancelled {
+        return;
+    }
+    for scan in scans.drain(..) {
+        if let (ScanPhase::Running, Some(pid)) = (scan.phase, scan.pid) {
+            tracing::info!(package = %scan.package, pid, "Stopping scanners (Preflight closed)");
+            let _ = std::process::Command::new("kill")
+                .args(["-TERM", &pid.to_string()])
+                .stdin(std::process::Stdio::null())
+                .stdout(std::process::Stdio::null())
+                .stderr(std::process::Stdio::null())
+                .status();
+        }
+        let _ = std::fs::remove_file(&scan.status_path);
+    }
+}
+
 /// What: Launch a terminal that performs an AUR package scan for a given package name
 ///
 /// Input:
@@ -652,11 +906,18 @@ fi)"#.to_string());
 ///
 /// Output:
 /// - Spawns a terminal that runs the scan pipeline and writes artifacts under a temporary working directory.
+/// - Returns the scan's progress handle; [`poll_scans`] follows it through the status file the
+///   script writes (`PACSEA_SCAN_STATUS`).
 ///
 /// Details:
 /// - Clones `https://aur.archlinux.org/<pkg>.git` and runs `makepkg -o` (download sources only).
 /// - Optionally runs ClamAV, Trivy filesystem, and Semgrep scans.
 /// - Performs VirusTotal hash lookups for PKGBUILD/src files when `VT_API_KEY` is provided via environment or Pacsea settings.
+/// - Enabled scanners run concurrently; VirusTotal requests are spaced to stay within
+///   `virustotal_requests_per_minute`, retried with exponential backoff when the quota is hit,
+///   and skipped for files whose verdict is cached under `scan_cache/vt/<sha256>.json`.
+/// - Reuses verdicts from [`scan_cache_dir`] when the PKGBUILD, sources, and scanner versions are
+///   unchanged; `force_rescan` bypasses the cache (fresh results still refresh it).
 #[cfg(not(target_os = "windows"))]
 #[allow(clippy::too_many_arguments)]
 pub fn spawn_aur_scan_for_with_config(
@@ -668,9 +929,31 @@ pub fn spawn_aur_scan_for_with_config(
     do_virustotal: bool,
     do_custom: bool,
     do_sleuth: bool,
-) {
+    force_rescan: bool,
+) -> ScanProgress {
+    let status_path = std::env::temp_dir().join(format!(
+        "pacsea_scan_status_{}_{}_{}",
+        std::process::id(),
+        pkg,
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or(0)
+    ));
     // Prepend environment exports so subsequent steps honor the selection
     let mut cmds: Vec<String> = Vec::new();
+    cmds.push(format!(
+        "export PACSEA_SCAN_STATUS={}",
+        super::utils::shell_single_quote(&status_path.display().to_string())
+    ));
+    cmds.push(format!(
+        "export PACSEA_SCAN_CACHE_DIR={}",
+        super::utils::shell_single_quote(&scan_cache_dir().display().to_string())
+    ));
+    cmds.push(format!(
+        "export PACSEA_SCAN_FORCE={}",
+        if force_rescan { "1" } else { "0" }
+    ));
     cmds.push(format!(
         "export PACSEA_SCAN_DO_CLAMAV={}",
         if do_clamav { "1" } else { "0" }
@@ -695,6 +978,12 @@ pub fn spawn_aur_scan_for_with_config(
         "export PACSEA_SCAN_DO_CUSTOM={}",
         if do_custom { "1" } else { "0" }
     ));
+    // Pace VirusTotal lookups to the configured per-minute quota (0 = unlimited)
+    let vt_rpm = crate::theme::settings().virustotal_requests_per_minute;
+    cmds.push(format!(
+        "export PACSEA_VT_INTERVAL_SECS={}",
+        60u32.checked_div(vt_rpm).unwrap_or(0)
+    ));
     // Export aur-sleuth toggle from UI/config
     cmds.push(format!(
         "export PACSEA_SCAN_DO_SLEUTH={}",
@@ -708,6 +997,11 @@ pub fn spawn_aur_scan_for_with_config(
     // Append the scan pipeline commands
     cmds.extend(build_scan_cmds_for_pkg(pkg));
     super::shell::spawn_shell_commands_in_terminal_with_hold(&cmds, false);
+    ScanProgress {
+        package: pkg.to_string(),
+        status_path,
+        ..ScanProgress::default()
+    }
 }
 
 #[cfg(all(test, not(target_os = "windows")))]
@@ -763,6 +1057,55 @@ mod tests {
             joined.contains("Pacsea: scan finished. Working directory preserved: $work"),
             "should print final working directory note"
         );
+        let lookup = joined.find("scan_cache_file=").expect("cache lookup step");
+        let clamav = joined.find("--- ClamAV scan (optional) ---").unwrap();
+        let store = joined
+            .find("Scan results cached")
+            .expect("cache store step");
+        let summary = joined.find("echo '--- Summary ---'").unwrap();
+        assert!(
+            lookup < clamav && summary < store,
+            "cache lookup should precede scanners and storing should follow the summary"
+        );
+        assert!(
+            joined.contains("if [ \"${PACSEA_SCAN_CACHED:-0}\" != 1 ]; then { "),
+            "scanners should be skipped on a cache hit"
+        );
+        assert!(
+            joined.contains("--version"),
+            "scanner versions should be part of the cache key"
+        );
+        assert!(
+            joined.contains(
+                "./.pacsea-job-0.log 2>&1 & pids[0]=$!; names[0]='ClamAV scan (optional)'"
+            ),
+            "scanners should start as background jobs"
+        );
+        assert!(
+            joined.contains("kill -0") && joined.contains("trap 'kill $(jobs -p)"),
+            "finished jobs should be reported and cancellation should stop all of them"
+        );
+        assert!(
+            joined.contains("echo \"start $BASHPID ")
+                && joined.contains("echo \"done $scan_secs ${names[$i]}\" >> \"$scan_st\"")
+                && joined.contains("echo cancelled >> \"$scan_st\"")
+                && joined.contains("else echo cached >> "),
+            "scanner progress should be written to the status file"
+        );
+        assert!(
+            joined.contains("sleep \"$vt_wait\""),
+            "VirusTotal requests should be paced"
+        );
+        assert!(
+            joined.contains("$PACSEA_SCAN_CACHE_DIR/vt/$h.json")
+                && joined.contains("VT: rate limited, retrying in ${vt_backoff}s"),
+            "VirusTotal verdicts should be cached by sha256 and quota errors retried"
+        );
+        assert!(
+            joined.contains("[ \"${PACSEA_SCAN_FORCE:-0}\" != 1 ] && [ -s \"$vt_cache\" ]")
+                && joined.contains("find \"$vt_cache\" -mmin -10080"),
+            "cached VirusTotal verdicts should expire and be skipped on force rescan"
+        );
     }
 
     #[test]
@@ -813,4 +1156,54 @@ mod tests {
             "should include final completion echo"
         );
     }
+
+    #[test]
+    /// What: Ensure status lines from the scan script drive the tracked progress.
+    ///
+    /// Inputs:
+    /// - A status file with a start line and two finished scanners, then the same file ending
+    ///   with `end`; a scan whose Preflight was closed.
+    ///
+    /// Output:
+    /// - Pid, count, and finished scanners parsed in order with the right phase; cancelling
+    ///   drops the tracked scan and its status file.
+    fn scan_status_lines_update_progress() {
+        use crate::state::modal::{ScanPhase, ScanProgress};
+        let mut progress = ScanProgress {
+            package: "foobar".into(),
+            ..ScanProgress::default()
+        };
+        let running = "start 4242 3\ndone 4 ShellCheck on PKGBUILD/.install\ndone 12 Trivy filesystem scan (optional)\n";
+        super::read_scan_status(&mut progress, running);
+        assert_eq!(progress.pid, Some(4242));
+        assert_eq!(progress.total, 3);
+        assert_eq!(progress.phase, ScanPhase::Running);
+        assert_eq!(
+            progress.finished,
+            vec![
+                ("ShellCheck on PKGBUILD/.install".to_string(), 4),
+                ("Trivy filesystem scan (optional)".to_string(), 12),
+            ]
+        );
+        super::read_scan_status(
+            &mut progress,
+            &format!("{running}done 30 ClamAV scan (optional)\nend\n"),
+        );
+        assert_eq!(progress.finished.len(), 3);
+        assert_eq!(progress.phase, ScanPhase::Finished);
+
+        let dir = tempfile::tempdir().unwrap();
+        let status_path = dir.path().join("status");
+        std::fs::write(&status_path, "cached\n").unwrap();
+        let mut scans = vec![ScanProgress {
+            package: "foobar".into(),
+            status_path: status_path.clone(),
+            ..ScanProgress::default()
+        }];
+        super::poll_scans(&mut scans, false);
+        assert_eq!(scans[0].phase, ScanPhase::Cached);
+        super::poll_scans(&mut scans, true);
+        assert!(scans.is_empty());
+        assert!(!status_path.exists());
+    }
 }
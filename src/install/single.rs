@@ -123,6 +123,10 @@ pub fn spawn_install(item: &PackageItem, password: Option<&str>, dry_run: bool)
         let res = Command::new("bash").args(["-lc", &cmd_str]).spawn();
         if let Err(e) = res {
             tracing::error!(error = %e, names = %item.name, "failed to spawn bash to run install command");
+            crate::logic::errors::report_error(
+                "install",
+                format!("failed to start bash for install: {e}"),
+            );
         } else {
             tracing::info!(names = %item.name, total = 1, aur_count = (src == "aur") as usize, official_count = (src == "official") as usize, dry_run, "launched bash for install");
         }
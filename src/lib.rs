@@ -3,6 +3,7 @@
 pub mod app;
 
 pub mod events;
+pub mod http;
 pub mod i18n;
 pub mod index;
 pub mod install;
@@ -0,0 +1,137 @@
+//! Persisted log of completed installs, removals, and downgrades.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Most entries kept on disk; the oldest are dropped first.
+pub const ACTIVITY_LOG_CAPACITY: usize = 500;
+
+/// Kind of package operation recorded in the activity log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ActivityKind {
+    /// Packages were installed.
+    Install,
+    /// Packages were removed.
+    Remove,
+    /// Packages were downgraded.
+    Downgrade,
+}
+
+/// One completed operation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityEntry {
+    /// Unix time the operation was recorded.
+    pub at: i64,
+    /// What happened.
+    pub kind: ActivityKind,
+    /// Packages involved.
+    pub packages: Vec<String>,
+}
+
+/// What: Location of the activity log.
+///
+/// Output:
+/// - `<lists_dir>/activity_log.json`.
+pub fn activity_log_path() -> PathBuf {
+    crate::theme::lists_dir().join("activity_log.json")
+}
+
+/// What: Read the activity log, oldest entry first.
+///
+/// Output:
+/// - The recorded entries; empty when the file is missing or unreadable.
+pub fn load() -> Vec<ActivityEntry> {
+    load_from(&activity_log_path())
+}
+
+/// What: Record a completed operation.
+///
+/// Inputs:
+/// - `kind`: Operation kind.
+/// - `names`: Packages involved; nothing is written when empty.
+pub fn append(kind: ActivityKind, names: &[String]) {
+    let at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    append_to(&activity_log_path(), kind, names, at);
+}
+
+/// What: Delete every entry from the activity log.
+pub fn clear() {
+    let path = activity_log_path();
+    if path.exists()
+        && let Err(e) = std::fs::remove_file(&path)
+    {
+        tracing::warn!(path = %path.display(), error = %e, "Failed to clear activity log");
+    }
+}
+
+/// What: Read the activity log at `path`.
+fn load_from(path: &Path) -> Vec<ActivityEntry> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+/// What: Append an entry to the log at `path`, trimming it to [`ACTIVITY_LOG_CAPACITY`].
+///
+/// Inputs:
+/// - `path`: Log file.
+/// - `kind`: Operation kind.
+/// - `names`: Packages involved; nothing is written when empty.
+/// - `at`: Unix time of the operation.
+fn append_to(path: &Path, kind: ActivityKind, names: &[String], at: i64) {
+    if names.is_empty() {
+        return;
+    }
+    let mut entries = load_from(path);
+    entries.push(ActivityEntry {
+        at,
+        kind,
+        packages: names.to_vec(),
+    });
+    if entries.len() > ACTIVITY_LOG_CAPACITY {
+        entries.drain(..entries.len() - ACTIVITY_LOG_CAPACITY);
+    }
+    match serde_json::to_string(&entries) {
+        Ok(s) => {
+            if let Err(e) = std::fs::write(path, s) {
+                tracing::warn!(path = %path.display(), error = %e, "Failed to write activity log");
+            }
+        }
+        Err(e) => tracing::warn!(error = %e, "Failed to serialize activity log"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Verify entries are appended in order and the log is capped.
+    ///
+    /// Inputs:
+    /// - An empty append, then more entries than the capacity allows.
+    ///
+    /// Output:
+    /// - Empty appends write nothing; only the newest `ACTIVITY_LOG_CAPACITY` entries remain.
+    fn activity_append_skips_empty_and_caps_length() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("activity_log.json");
+        append_to(&path, ActivityKind::Install, &[], 1);
+        assert!(!path.exists());
+
+        for i in 0..(ACTIVITY_LOG_CAPACITY as i64 + 3) {
+            append_to(&path, ActivityKind::Remove, &[format!("pkg{i}")], i);
+        }
+        let entries = load_from(&path);
+        assert_eq!(entries.len(), ACTIVITY_LOG_CAPACITY);
+        assert_eq!(entries[0].at, 3);
+        assert_eq!(entries[0].packages, vec!["pkg3".to_string()]);
+        assert_eq!(entries.last().map(|e| e.kind), Some(ActivityKind::Remove));
+    }
+}
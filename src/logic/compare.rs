@@ -0,0 +1,227 @@
+//! Field-by-field comparison of two packages for the compare modal.
+
+use crate::state::PackageDetails;
+
+/// Package fields shown in the compare modal, in display order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareField {
+    /// Full version string.
+    Version,
+    /// Repository (or `AUR`).
+    Repository,
+    /// License list.
+    Licenses,
+    /// Download size in bytes.
+    DownloadSize,
+    /// Installed size in bytes.
+    InstallSize,
+    /// Dependencies present on only one side.
+    Depends,
+    /// Number of dependencies both packages share.
+    SharedDepends,
+    /// Optional dependencies present on only one side.
+    OptDepends,
+    /// Provided names present on only one side.
+    Provides,
+    /// Conflicts present on only one side.
+    Conflicts,
+}
+
+/// One side of a compared field, formatted by the renderer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompareValue {
+    /// Plain text value.
+    Text(String),
+    /// Size in bytes; `None` when unknown (e.g. AUR packages).
+    Size(Option<u64>),
+    /// List of entries; empty renders as a dash.
+    List(Vec<String>),
+}
+
+/// One row of the compare modal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompareRow {
+    /// Field being compared.
+    pub field: CompareField,
+    /// Value for slot A.
+    pub left: CompareValue,
+    /// Value for slot B.
+    pub right: CompareValue,
+    /// Whether the two sides differ (highlighted in the modal).
+    pub differs: bool,
+}
+
+/// What: Strip a version constraint from a dependency spec (`glibc>=2.38` -> `glibc`).
+fn dep_name(spec: &str) -> &str {
+    spec.split(['<', '>', '=', ':'])
+        .next()
+        .unwrap_or(spec)
+        .trim()
+}
+
+/// What: Split two dependency-style lists into entries unique to each side.
+///
+/// Output:
+/// - `(only_a, only_b, shared)` where entries are matched by name, ignoring version constraints;
+///   unique entries keep their original spelling and order.
+fn split_lists(a: &[String], b: &[String]) -> (Vec<String>, Vec<String>, usize) {
+    let names_a: std::collections::HashSet<&str> = a.iter().map(|s| dep_name(s)).collect();
+    let names_b: std::collections::HashSet<&str> = b.iter().map(|s| dep_name(s)).collect();
+    let only_a = a
+        .iter()
+        .filter(|s| !names_b.contains(dep_name(s)))
+        .cloned()
+        .collect();
+    let only_b = b
+        .iter()
+        .filter(|s| !names_a.contains(dep_name(s)))
+        .cloned()
+        .collect();
+    (only_a, only_b, names_a.intersection(&names_b).count())
+}
+
+/// What: Build a row whose sides differ when the values are unequal.
+fn plain_row(field: CompareField, left: CompareValue, right: CompareValue) -> CompareRow {
+    let differs = left != right;
+    CompareRow {
+        field,
+        left,
+        right,
+        differs,
+    }
+}
+
+/// What: Build a row listing the entries unique to each side.
+fn unique_row(field: CompareField, a: &[String], b: &[String]) -> CompareRow {
+    let (only_a, only_b, _) = split_lists(a, b);
+    let differs = !only_a.is_empty() || !only_b.is_empty();
+    CompareRow {
+        field,
+        left: CompareValue::List(only_a),
+        right: CompareValue::List(only_b),
+        differs,
+    }
+}
+
+/// What: Compare the key fields of two packages.
+///
+/// Inputs:
+/// - `a`: Details stored in compare slot A.
+/// - `b`: Details stored in compare slot B.
+///
+/// Output:
+/// - One row per [`CompareField`] in declaration order.
+///
+/// Details:
+/// - List fields show only the entries missing from the other side, matched by name so
+///   `glibc>=2.38` and `glibc` count as the same dependency; licenses are compared as-is.
+pub fn compare_details(a: &PackageDetails, b: &PackageDetails) -> Vec<CompareRow> {
+    let (_, _, shared) = split_lists(&a.depends, &b.depends);
+    let shared = CompareValue::Text(shared.to_string());
+    vec![
+        plain_row(
+            CompareField::Version,
+            CompareValue::Text(a.version.clone()),
+            CompareValue::Text(b.version.clone()),
+        ),
+        plain_row(
+            CompareField::Repository,
+            CompareValue::Text(a.repository.clone()),
+            CompareValue::Text(b.repository.clone()),
+        ),
+        plain_row(
+            CompareField::Licenses,
+            CompareValue::List(a.licenses.clone()),
+            CompareValue::List(b.licenses.clone()),
+        ),
+        plain_row(
+            CompareField::DownloadSize,
+            CompareValue::Size(a.download_size),
+            CompareValue::Size(b.download_size),
+        ),
+        plain_row(
+            CompareField::InstallSize,
+            CompareValue::Size(a.install_size),
+            CompareValue::Size(b.install_size),
+        ),
+        unique_row(CompareField::Depends, &a.depends, &b.depends),
+        CompareRow {
+            field: CompareField::SharedDepends,
+            left: shared.clone(),
+            right: shared,
+            differs: false,
+        },
+        unique_row(CompareField::OptDepends, &a.opt_depends, &b.opt_depends),
+        unique_row(CompareField::Provides, &a.provides, &b.provides),
+        unique_row(CompareField::Conflicts, &a.conflicts, &b.conflicts),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure two packages produce the expected field-by-field diff.
+    ///
+    /// Inputs:
+    /// - `ripgrep` and `ripgrep-git` sharing `gcc-libs`/`pcre2` (one with a version constraint)
+    ///   but differing in version, repository, sizes, and one dependency each.
+    ///
+    /// Output:
+    /// - Rows in display order with `differs` set only where the sides disagree, and dependency
+    ///   rows listing just the entries unique to each side.
+    fn compare_details_diffs_key_fields() {
+        let a = PackageDetails {
+            name: "ripgrep".into(),
+            version: "14.1.0-1".into(),
+            repository: "extra".into(),
+            licenses: vec!["MIT".into(), "Unlicense".into()],
+            depends: vec!["gcc-libs".into(), "pcre2>=10.40".into(), "glibc".into()],
+            download_size: Some(1_500_000),
+            install_size: Some(4_000_000),
+            conflicts: vec!["ripgrep-git".into()],
+            ..Default::default()
+        };
+        let b = PackageDetails {
+            name: "ripgrep-git".into(),
+            version: "14.1.0.r12-1".into(),
+            repository: "AUR".into(),
+            licenses: vec!["MIT".into(), "Unlicense".into()],
+            depends: vec!["gcc-libs".into(), "pcre2".into(), "zlib".into()],
+            download_size: None,
+            install_size: Some(4_000_000),
+            conflicts: vec!["ripgrep".into()],
+            ..Default::default()
+        };
+        let rows = compare_details(&a, &b);
+        let fields: Vec<CompareField> = rows.iter().map(|r| r.field).collect();
+        assert_eq!(
+            fields,
+            vec![
+                CompareField::Version,
+                CompareField::Repository,
+                CompareField::Licenses,
+                CompareField::DownloadSize,
+                CompareField::InstallSize,
+                CompareField::Depends,
+                CompareField::SharedDepends,
+                CompareField::OptDepends,
+                CompareField::Provides,
+                CompareField::Conflicts,
+            ]
+        );
+        let differs: Vec<bool> = rows.iter().map(|r| r.differs).collect();
+        assert_eq!(
+            differs,
+            vec![
+                true, true, false, true, false, true, false, false, false, true
+            ]
+        );
+        assert_eq!(rows[3].right, CompareValue::Size(None));
+        assert_eq!(rows[5].left, CompareValue::List(vec!["glibc".into()]));
+        assert_eq!(rows[5].right, CompareValue::List(vec!["zlib".into()]));
+        assert_eq!(rows[6].left, CompareValue::Text("2".into()));
+        assert_eq!(rows[7].left, CompareValue::List(Vec::new()));
+    }
+}
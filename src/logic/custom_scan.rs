@@ -0,0 +1,218 @@
+//! User-defined PKGBUILD scanner (`custom_scan_command`) run during the sandbox analysis.
+
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// Time a custom scanner may run before it is killed.
+pub const CUSTOM_SCAN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Output kept from a custom scanner; anything beyond is cut off.
+const MAX_OUTPUT_BYTES: usize = 8 * 1024;
+
+/// What: Outcome of running the custom scanner on one PKGBUILD.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CustomScanResult {
+    /// Exit code (`None` when killed by a signal or the timeout)
+    pub exit_code: Option<i32>,
+    /// Whether the scanner was killed after [`CUSTOM_SCAN_TIMEOUT`]
+    pub timed_out: bool,
+    /// Combined stdout and stderr, trimmed
+    pub output: String,
+    /// Whether `output` was cut at the size limit
+    pub truncated: bool,
+}
+
+impl CustomScanResult {
+    /// What: Whether the result should be shown as a warning (non-zero exit or timeout).
+    pub fn is_warning(&self) -> bool {
+        self.timed_out || self.exit_code != Some(0)
+    }
+}
+
+/// What: Single-quote a path for the shell.
+fn quote(path: &Path) -> String {
+    format!("'{}'", path.display().to_string().replace('\'', "'\\''"))
+}
+
+/// What: Fill in the `{pkgbuild}` and `{srcdir}` placeholders of a command template.
+///
+/// Inputs:
+/// - `template`: `custom_scan_command` setting.
+/// - `pkgbuild`: Path of the PKGBUILD to scan.
+/// - `srcdir`: Directory containing it.
+///
+/// Output:
+/// - The command line with both placeholders replaced by shell-quoted paths.
+pub fn expand_template(template: &str, pkgbuild: &Path, srcdir: &Path) -> String {
+    template
+        .replace("{pkgbuild}", &quote(pkgbuild))
+        .replace("{srcdir}", &quote(srcdir))
+}
+
+/// What: Cut scanner output to [`MAX_OUTPUT_BYTES`] on a character boundary.
+///
+/// Output:
+/// - The trimmed text and whether anything was dropped.
+fn truncate_output(raw: &str) -> (String, bool) {
+    let text = raw.trim_end();
+    if text.len() <= MAX_OUTPUT_BYTES {
+        return (text.to_string(), false);
+    }
+    let mut end = MAX_OUTPUT_BYTES;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    (text[..end].to_string(), true)
+}
+
+/// What: Run the custom scanner against a PKGBUILD.
+///
+/// Inputs:
+/// - `template`: `custom_scan_command` setting.
+/// - `package`: Package name, used for the scratch directory name.
+/// - `pkgbuild_text`: PKGBUILD contents.
+/// - `timeout`: Time limit before the scanner is killed.
+///
+/// Output:
+/// - The exit status and captured output, or an error when the scratch files or the shell
+///   could not be set up.
+///
+/// Details:
+/// - The PKGBUILD is written to a scratch directory that serves as `{srcdir}`; sources are not
+///   downloaded. The command runs via `bash -lc`, like `post_install_hook` and the install
+///   terminals, so templates behave the same whatever the user's login shell is; the scratch
+///   directory is the working directory.
+/// - Output is written to a file rather than a pipe so a chatty scanner cannot block.
+pub fn run_custom_scan(
+    template: &str,
+    package: &str,
+    pkgbuild_text: &str,
+    timeout: Duration,
+) -> Result<CustomScanResult, String> {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or_default();
+    let dir = std::env::temp_dir().join(format!(
+        "pacsea-custom-scan-{}-{package}-{nanos}",
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let result = run_in_dir(template, &dir, pkgbuild_text, timeout);
+    let _ = std::fs::remove_dir_all(&dir);
+    result
+}
+
+/// What: Body of [`run_custom_scan`] once the scratch directory exists.
+///
+/// Details:
+/// - On Unix the shell leads its own process group, so a timeout kills the scanner and every
+///   process it started, not just the `bash -lc` wrapper.
+fn run_in_dir(
+    template: &str,
+    dir: &Path,
+    pkgbuild_text: &str,
+    timeout: Duration,
+) -> Result<CustomScanResult, String> {
+    let pkgbuild = dir.join("PKGBUILD");
+    std::fs::write(&pkgbuild, pkgbuild_text).map_err(|e| e.to_string())?;
+    let log_path = dir.join(".pacsea-custom-scan.log");
+    let log = std::fs::File::create(&log_path).map_err(|e| e.to_string())?;
+    let log_err = log.try_clone().map_err(|e| e.to_string())?;
+    let mut cmd = Command::new("bash");
+    cmd.arg("-lc")
+        .arg(expand_template(template, &pkgbuild, dir))
+        .current_dir(dir)
+        .stdin(Stdio::null())
+        .stdout(log)
+        .stderr(log_err);
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(&mut cmd, 0);
+    let mut child = cmd.spawn().map_err(|e| e.to_string())?;
+    let start = Instant::now();
+    let (exit_code, timed_out) = loop {
+        if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+            break (status.code(), false);
+        }
+        if start.elapsed() >= timeout {
+            // The group id equals the shell's pid (`process_group(0)`)
+            #[cfg(unix)]
+            let _ = Command::new("kill")
+                .args(["-KILL", "--", &format!("-{}", child.id())])
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status();
+            let _ = child.kill();
+            let _ = child.wait();
+            break (None, true);
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    };
+    let raw = std::fs::read(&log_path).unwrap_or_default();
+    let (output, truncated) = truncate_output(&String::from_utf8_lossy(&raw));
+    Ok(CustomScanResult {
+        exit_code,
+        timed_out,
+        output,
+        truncated,
+    })
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure placeholders expand, exit codes are captured, and slow scanners time out.
+    ///
+    /// Inputs:
+    /// - A template using both placeholders; a failing command; a `sleep` past the timeout.
+    ///
+    /// Output:
+    /// - Quoted paths in the command, the PKGBUILD content echoed back with exit 0, exit 3 as
+    ///   a warning, and a timed-out result without exit code.
+    fn custom_scan_runs_template_with_timeout() {
+        let cmd = expand_template(
+            "lint {pkgbuild} --dir {srcdir}",
+            Path::new("/tmp/it's/PKGBUILD"),
+            Path::new("/tmp/it's"),
+        );
+        assert_eq!(cmd, "lint '/tmp/it'\\''s/PKGBUILD' --dir '/tmp/it'\\''s'");
+
+        let ok = run_custom_scan(
+            "cat {pkgbuild}",
+            "demo",
+            "pkgname=demo\n",
+            CUSTOM_SCAN_TIMEOUT,
+        )
+        .unwrap();
+        assert_eq!(ok.exit_code, Some(0));
+        // A login shell may print profile noise before the command output
+        assert!(ok.output.ends_with("pkgname=demo"));
+        assert!(!ok.is_warning());
+
+        let failed = run_custom_scan("echo bad; exit 3", "demo", "", CUSTOM_SCAN_TIMEOUT).unwrap();
+        assert_eq!(failed.exit_code, Some(3));
+        assert!(failed.is_warning());
+
+        let slow = run_custom_scan("sleep 5", "demo", "", Duration::from_millis(200)).unwrap();
+        assert!(slow.timed_out && slow.exit_code.is_none() && slow.is_warning());
+
+        // A timeout also kills what the scanner started in the background
+        let pid_dir = tempfile::tempdir().unwrap();
+        let pid_file = pid_dir.path().join("pid");
+        let template = format!("sleep 30 & echo $! > '{}'; wait", pid_file.display());
+        let spawned = run_custom_scan(&template, "demo", "", Duration::from_secs(5)).unwrap();
+        assert!(spawned.timed_out);
+        let pid = std::fs::read_to_string(&pid_file).unwrap();
+        std::thread::sleep(Duration::from_millis(100));
+        let state = std::fs::read_to_string(format!("/proc/{}/stat", pid.trim()));
+        // Gone, or a zombie waiting for a reaper in minimal containers
+        assert!(state.is_err() || state.unwrap().contains(") Z "));
+
+        let (text, cut) = truncate_output(&"é".repeat(MAX_OUTPUT_BYTES));
+        assert!(cut && text.len() <= MAX_OUTPUT_BYTES);
+    }
+}
@@ -1,6 +1,8 @@
 //! Dependency resolution and analysis for preflight checks.
 
 mod aur;
+mod helper;
+mod parallel;
 mod parse;
 mod query;
 mod resolve;
@@ -12,16 +14,21 @@ mod utils;
 
 use crate::state::modal::{DependencyInfo, DependencyStatus};
 use crate::state::types::{PackageItem, Source};
-use parse::parse_dep_spec;
-use query::get_upgradable_packages;
+pub(crate) use parse::parse_dep_spec;
+pub(crate) use query::get_upgradable_packages;
 use resolve::{batch_fetch_official_deps, fetch_package_conflicts, resolve_package_deps};
 use source::{determine_dependency_source, is_system_package};
 use status::determine_status;
 use std::collections::{HashMap, HashSet};
 use utils::dependency_priority;
 
+pub use helper::{
+    AurHelper, aur_file_list_output, available_helpers, helper_order, probe_aur_helpers,
+    set_preferred_aur_helper,
+};
+pub use parallel::set_resolve_concurrency;
 pub use query::{get_installed_packages, get_provided_packages, is_package_installed_or_provided};
-pub use reverse::resolve_reverse_dependencies;
+pub use reverse::{resolve_reverse_dependencies, reverse_why};
 pub use status::{get_installed_version, version_satisfies};
 
 /// What: Resolve dependencies for the requested install set while consolidating duplicates.
@@ -36,7 +43,48 @@ pub use status::{get_installed_version, version_satisfies};
 /// - Resolves ONLY direct dependencies (non-recursive) for each package in the list.
 /// - Merges duplicates by name, retaining the most severe status across all requesters.
 /// - Populates `depends_on` and `required_by` relationships to reflect dependency relationships.
+/// - AUR packages are skipped when `aur_disabled` is set.
 pub fn resolve_dependencies(items: &[PackageItem]) -> Vec<DependencyInfo> {
+    resolve_dependencies_cancellable(items, &std::sync::atomic::AtomicBool::new(false))
+}
+
+/// What: [`resolve_dependencies`] that stops starting new lookups once `cancel` is set.
+///
+/// Inputs:
+/// - `items`: Packages to analyse.
+/// - `cancel`: Usually `AppState::preflight_cancelled`; set when the Preflight modal closes.
+///
+/// Output:
+/// - The merged dependency list, or an empty list when cancelled mid-way.
+///
+/// Details:
+/// - Per-package lookups run on up to `deps_resolve_concurrency` threads (AUR lookups capped at
+///   [`parallel::AUR_RESOLVE_CONCURRENCY`]); merging happens in input order so the result is the
+///   same as a sequential run.
+pub fn resolve_dependencies_cancellable(
+    items: &[PackageItem],
+    cancel: &std::sync::atomic::AtomicBool,
+) -> Vec<DependencyInfo> {
+    resolve_dependencies_with_progress(items, cancel, &|_, _| {})
+}
+
+/// What: [`resolve_dependencies_cancellable`] that reports how many packages are done.
+///
+/// Inputs:
+/// - `items`: Packages to analyse.
+/// - `cancel`: Stops new lookups once set.
+/// - `progress`: Called with `(done, total)` when lookups start and after each package; may be
+///   called from the resolver's worker threads.
+///
+/// Output:
+/// - Same as [`resolve_dependencies_cancellable`].
+pub fn resolve_dependencies_with_progress(
+    items: &[PackageItem],
+    cancel: &std::sync::atomic::AtomicBool,
+    progress: &(dyn Fn(usize, usize) + Sync),
+) -> Vec<DependencyInfo> {
+    let items_kept = crate::sources::without_aur_if_disabled(items);
+    let items = &*items_kept;
     let _span = tracing::info_span!(
         "resolve_dependencies",
         stage = "dependencies",
@@ -171,58 +219,39 @@ pub fn resolve_dependencies(items: &[PackageItem]) -> Vec<DependencyInfo> {
 
     // Resolve ONLY direct dependencies (non-recursive)
     // This is faster and avoids resolving transitive dependencies which can be slow and error-prone
-    for item in items {
-        let name = item.name.clone();
-        let source = item.source.clone();
-
-        tracing::debug!(
-            "Resolving direct dependencies for {} (source: {:?})",
-            name,
-            source
-        );
+    // Lookups run on a bounded pool; results are merged below in install-list order
+    let done = std::sync::atomic::AtomicUsize::new(0);
+    progress(0, items.len());
+    let resolved = parallel::map_bounded(
+        items,
+        parallel::resolve_concurrency(),
+        parallel::AUR_RESOLVE_CONCURRENCY,
+        |item| matches!(item.source, Source::Aur),
+        cancel,
+        |item| {
+            let result = resolve_direct(
+                item,
+                &batched_deps_cache,
+                &installed,
+                &provided,
+                &upgradable,
+            );
+            let finished = done.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
+            progress(finished, items.len());
+            result
+        },
+    );
+    if cancel.load(std::sync::atomic::Ordering::Relaxed) {
+        tracing::info!("[Deps] Resolution cancelled; discarding partial results");
+        return Vec::new();
+    }
 
-        // Check if we have batched results for this official package
-        let source_clone = source.clone();
-        let use_batched = matches!(source_clone, Source::Official { repo, .. } if repo != "local")
-            && batched_deps_cache.contains_key(name.as_str());
-
-        match if use_batched {
-            // Use batched dependency list
-            let dep_names = batched_deps_cache
-                .get(name.as_str())
-                .cloned()
-                .unwrap_or_default();
-            let mut deps = Vec::new();
-            for dep_spec in dep_names {
-                let (pkg_name, version_req) = parse_dep_spec(&dep_spec);
-                if pkg_name == name {
-                    continue;
-                }
-                if pkg_name.ends_with(".so")
-                    || pkg_name.contains(".so.")
-                    || pkg_name.contains(".so=")
-                {
-                    continue;
-                }
-                let status =
-                    determine_status(&pkg_name, &version_req, &installed, &provided, &upgradable);
-                let (dep_source, is_core) = determine_dependency_source(&pkg_name, &installed);
-                let is_system = is_core || is_system_package(&pkg_name);
-                deps.push(DependencyInfo {
-                    name: pkg_name,
-                    version: version_req,
-                    status,
-                    source: dep_source,
-                    required_by: vec![name.clone()],
-                    depends_on: Vec::new(),
-                    is_core,
-                    is_system,
-                });
-            }
-            Ok(deps)
-        } else {
-            resolve_package_deps(&name, &source, &installed, &provided, &upgradable)
-        } {
+    for (item, result) in items.iter().zip(resolved) {
+        let name = item.name.clone();
+        let Some(result) = result else {
+            continue;
+        };
+        match result {
             Ok(mut resolved_deps) => {
                 tracing::debug!("  Found {} dependencies for {}", resolved_deps.len(), name);
 
@@ -327,3 +356,59 @@ pub fn resolve_dependencies(items: &[PackageItem]) -> Vec<DependencyInfo> {
     );
     result
 }
+
+/// What: Resolve the direct dependencies of one install-list entry.
+///
+/// Inputs:
+/// - `item`: Package to resolve.
+/// - `batched`: `pacman -Si` results fetched up front for official packages.
+/// - `installed`/`provided`/`upgradable`: Local package sets used for status.
+///
+/// Output:
+/// - Direct dependencies with `required_by` set to the package, or an error message.
+///
+/// Details:
+/// - Official packages with batched results skip the per-package lookup.
+fn resolve_direct(
+    item: &PackageItem,
+    batched: &HashMap<String, Vec<String>>,
+    installed: &HashSet<String>,
+    provided: &HashSet<String>,
+    upgradable: &HashSet<String>,
+) -> Result<Vec<DependencyInfo>, String> {
+    let name = &item.name;
+    tracing::debug!(
+        "Resolving direct dependencies for {} (source: {:?})",
+        name,
+        item.source
+    );
+    let use_batched = matches!(&item.source, Source::Official { repo, .. } if repo != "local")
+        && batched.contains_key(name.as_str());
+    if !use_batched {
+        return resolve_package_deps(name, &item.source, installed, provided, upgradable);
+    }
+    let mut deps = Vec::new();
+    for dep_spec in batched.get(name.as_str()).cloned().unwrap_or_default() {
+        let (pkg_name, version_req) = parse_dep_spec(&dep_spec);
+        if pkg_name == *name {
+            continue;
+        }
+        if pkg_name.ends_with(".so") || pkg_name.contains(".so.") || pkg_name.contains(".so=") {
+            continue;
+        }
+        let status = determine_status(&pkg_name, &version_req, installed, provided, upgradable);
+        let (dep_source, is_core) = determine_dependency_source(&pkg_name, installed);
+        let is_system = is_core || is_system_package(&pkg_name);
+        deps.push(DependencyInfo {
+            name: pkg_name,
+            version: version_req,
+            status,
+            source: dep_source,
+            required_by: vec![name.clone()],
+            depends_on: Vec::new(),
+            is_core,
+            is_system,
+        });
+    }
+    Ok(deps)
+}
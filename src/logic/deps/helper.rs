@@ -0,0 +1,275 @@
+//! AUR helper abstraction shared by dependency resolution, file queries, and installs.
+
+use std::process::{Command, Stdio};
+use std::sync::RwLock;
+
+/// AUR helpers Pacsea knows how to drive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AurHelper {
+    /// `paru` (default).
+    Paru,
+    /// `yay`.
+    Yay,
+    /// `pikaur`.
+    Pikaur,
+    /// `trizen`.
+    Trizen,
+    /// `aurutils` (`aur` binary; builds into a local repository).
+    Aurutils,
+}
+
+impl AurHelper {
+    /// Fallback order after the preferred helper.
+    pub const ALL: [AurHelper; 5] = [
+        AurHelper::Paru,
+        AurHelper::Yay,
+        AurHelper::Pikaur,
+        AurHelper::Trizen,
+        AurHelper::Aurutils,
+    ];
+
+    /// Name used in `settings.conf` and the package providing the helper.
+    pub const fn name(self) -> &'static str {
+        match self {
+            AurHelper::Paru => "paru",
+            AurHelper::Yay => "yay",
+            AurHelper::Pikaur => "pikaur",
+            AurHelper::Trizen => "trizen",
+            AurHelper::Aurutils => "aurutils",
+        }
+    }
+
+    /// Executable invoked for this helper.
+    pub const fn binary(self) -> &'static str {
+        match self {
+            AurHelper::Aurutils => "aur",
+            other => other.name(),
+        }
+    }
+
+    /// What: Parse a helper name as written in `settings.conf`.
+    ///
+    /// Output:
+    /// - The matching helper (case-insensitive; `aur` also selects aurutils), or `None`.
+    pub fn from_name(s: &str) -> Option<Self> {
+        let s = s.trim().to_ascii_lowercase();
+        if s == "aur" {
+            return Some(AurHelper::Aurutils);
+        }
+        Self::ALL.into_iter().find(|h| h.name() == s)
+    }
+
+    /// What: Arguments that print pacman-style `-Si` info for an AUR package.
+    ///
+    /// Output:
+    /// - `None` for aurutils, which has no `-Si` equivalent; callers fall back to `.SRCINFO`.
+    pub fn info_args(self, pkg: &str) -> Option<Vec<String>> {
+        match self {
+            AurHelper::Aurutils => None,
+            _ => Some(vec!["-Si".to_string(), pkg.to_string()]),
+        }
+    }
+
+    /// What: Arguments that list the files of an AUR package (`-Fl`).
+    ///
+    /// Output:
+    /// - `None` for helpers that pass `-F` straight to pacman (pikaur, trizen) or lack it
+    ///   (aurutils); file-list queries skip those helpers.
+    pub fn file_list_args(self, pkg: &str) -> Option<Vec<String>> {
+        match self {
+            AurHelper::Paru | AurHelper::Yay => Some(vec!["-Fl".to_string(), pkg.to_string()]),
+            _ => None,
+        }
+    }
+
+    /// What: Arguments that list pending AUR upgrades in `-Qu` format (`-Qua`).
+    ///
+    /// Output:
+    /// - `None` for helpers without an AUR-only upgrade query (pikaur, trizen, aurutils); the
+    ///   upgrade review skips those helpers.
+    pub fn upgrade_query_args(self) -> Option<&'static [&'static str]> {
+        match self {
+            AurHelper::Paru | AurHelper::Yay => Some(&["-Qua"]),
+            _ => None,
+        }
+    }
+
+    /// What: Shell command that installs `names` with this helper.
+    ///
+    /// Inputs:
+    /// - `flags`: pacman-style flags (e.g. `-S --needed --noconfirm`).
+    /// - `names`: Space-separated package names.
+    ///
+    /// Details:
+    /// - aurutils builds into its local repository with `aur sync`, then installs from it with
+    ///   pacman using the same flags, through the configured escalation tool and pacman binary.
+    pub fn install_command(self, flags: &str, names: &str) -> String {
+        match self {
+            AurHelper::Aurutils => {
+                format!(
+                    "aur sync --noconfirm --noview {names} && {} {flags} {names}",
+                    crate::install::privilege::root_pacman()
+                )
+            }
+            _ => format!("{} {flags} {names}", self.binary()),
+        }
+    }
+
+    /// What: Whether the helper's executable runs.
+    ///
+    /// Details:
+    /// - Answered from the cache filled by [`probe_aur_helpers`]; probes directly only until
+    ///   that first probe finished.
+    pub fn is_available(self) -> bool {
+        match AVAILABLE.read().ok().and_then(|g| g.clone()) {
+            Some(found) => found.contains(&self),
+            None => self.probe(),
+        }
+    }
+
+    /// What: Probe `<binary> --version`; only the spawn result matters, not the exit status.
+    fn probe(self) -> bool {
+        Command::new(self.binary())
+            .args(["--version"])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .output()
+            .is_ok()
+    }
+}
+
+/// Helpers whose executable ran at the last [`probe_aur_helpers`]; `None` until it finished.
+static AVAILABLE: RwLock<Option<Vec<AurHelper>>> = RwLock::new(None);
+
+/// What: Probe every known helper and cache which ones are installed.
+///
+/// Details:
+/// - Spawns one `--version` per helper, so it blocks; the runtime runs it off the UI thread at
+///   startup and again after an install finished (which may have bootstrapped a helper).
+pub fn probe_aur_helpers() {
+    let found: Vec<AurHelper> = AurHelper::ALL.into_iter().filter(|h| h.probe()).collect();
+    tracing::debug!(?found, "probed AUR helpers");
+    if let Ok(mut g) = AVAILABLE.write() {
+        *g = Some(found);
+    }
+}
+
+/// Helper configured via `preferred_aur_helper`; `None` keeps the default order.
+static PREFERRED: RwLock<Option<AurHelper>> = RwLock::new(None);
+
+/// What: Set the helper tried first by resolution, file queries, and installs.
+///
+/// Inputs:
+/// - `helper`: Configured helper, or `None` for the default order (paru, yay, ...).
+pub fn set_preferred_aur_helper(helper: Option<AurHelper>) {
+    if let Ok(mut g) = PREFERRED.write() {
+        *g = helper;
+    }
+}
+
+/// What: Known helpers with the preferred one first.
+///
+/// Output:
+/// - [`AurHelper::ALL`] reordered so the configured helper leads; not filtered by availability.
+pub fn helper_order() -> Vec<AurHelper> {
+    order_with(PREFERRED.read().ok().and_then(|g| *g))
+}
+
+/// What: [`AurHelper::ALL`] with `preferred` moved to the front.
+fn order_with(preferred: Option<AurHelper>) -> Vec<AurHelper> {
+    let mut order: Vec<AurHelper> = preferred.into_iter().collect();
+    order.extend(AurHelper::ALL.into_iter().filter(|h| Some(*h) != preferred));
+    order
+}
+
+/// What: Installed helpers in preference order.
+///
+/// Output:
+/// - Helpers from [`helper_order`] whose executable can be spawned.
+pub fn available_helpers() -> Vec<AurHelper> {
+    helper_order()
+        .into_iter()
+        .filter(|h| h.is_available())
+        .collect()
+}
+
+/// What: Run `-Fl` for an AUR package with the first helper that supports it.
+///
+/// Inputs:
+/// - `pkg`: AUR package name.
+///
+/// Output:
+/// - The helper used and its raw `<pkg> <path>` output, or `None` when no installed helper
+///   supports `-Fl` or none returned anything.
+///
+/// Details:
+/// - Helpers without file-list support are skipped without being probed.
+pub fn aur_file_list_output(pkg: &str) -> Option<(AurHelper, String)> {
+    for helper in helper_order() {
+        let Some(args) = helper.file_list_args(pkg) else {
+            continue;
+        };
+        if !helper.is_available() {
+            continue;
+        }
+        tracing::debug!("Trying {} -Fl {}", helper.binary(), pkg);
+        if let Ok(output) = Command::new(helper.binary())
+            .args(&args)
+            .env("LC_ALL", "C")
+            .env("LANG", "C")
+            .output()
+            && output.status.success()
+        {
+            let text = String::from_utf8_lossy(&output.stdout).into_owned();
+            if !text.trim().is_empty() {
+                return Some((helper, text));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure the preferred helper leads the order and per-helper invocations differ.
+    ///
+    /// Inputs:
+    /// - Order computed with pikaur preferred and with no preference.
+    ///
+    /// Output:
+    /// - pikaur first followed by the remaining helpers; `-Fl` only for paru/yay, no `-Si` for
+    ///   aurutils, and aurutils installs through `aur sync` plus pacman.
+    fn helper_order_and_invocations() {
+        assert_eq!(AurHelper::from_name(" Trizen "), Some(AurHelper::Trizen));
+        assert_eq!(AurHelper::from_name("aur"), Some(AurHelper::Aurutils));
+        assert_eq!(AurHelper::from_name("pamac"), None);
+
+        assert_eq!(
+            order_with(Some(AurHelper::Pikaur)),
+            vec![
+                AurHelper::Pikaur,
+                AurHelper::Paru,
+                AurHelper::Yay,
+                AurHelper::Trizen,
+                AurHelper::Aurutils,
+            ]
+        );
+        assert_eq!(order_with(None), AurHelper::ALL.to_vec());
+
+        assert!(AurHelper::Yay.file_list_args("x").is_some());
+        assert!(AurHelper::Pikaur.file_list_args("x").is_none());
+        assert!(AurHelper::Aurutils.info_args("x").is_none());
+        assert_eq!(
+            AurHelper::Aurutils.install_command("-S --needed", "a b"),
+            format!(
+                "aur sync --noconfirm --noview a b && {} -S --needed a b",
+                crate::install::privilege::root_pacman()
+            )
+        );
+        assert_eq!(AurHelper::Trizen.install_command("-S", "a"), "trizen -S a");
+    }
+}
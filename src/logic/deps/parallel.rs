@@ -0,0 +1,146 @@
+//! Bounded worker pool for per-package dependency lookups.
+
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Upper bound on concurrent AUR-bound lookups, independent of the configured concurrency,
+/// so large install lists do not hammer the AUR RPC/cgit endpoints.
+pub const AUR_RESOLVE_CONCURRENCY: usize = 2;
+
+/// Worker count set from `deps_resolve_concurrency`.
+static RESOLVE_CONCURRENCY: AtomicUsize = AtomicUsize::new(4);
+
+/// What: Set how many packages the dependency resolver handles at once.
+///
+/// Inputs:
+/// - `n`: Worker count; `0` is treated as `1` (sequential).
+pub fn set_resolve_concurrency(n: usize) {
+    RESOLVE_CONCURRENCY.store(n.max(1), Ordering::Relaxed);
+}
+
+/// What: Current resolver worker count.
+pub fn resolve_concurrency() -> usize {
+    RESOLVE_CONCURRENCY.load(Ordering::Relaxed)
+}
+
+/// What: Apply `f` to every item on a bounded pool of scoped threads.
+///
+/// Inputs:
+/// - `items`: Work items.
+/// - `workers`: Maximum concurrent calls of `f`.
+/// - `aur_workers`: Maximum concurrent calls for items where `is_aur` is true.
+/// - `is_aur`: Classifies AUR-bound items.
+/// - `cancel`: Checked before each item; once set no new items start.
+/// - `f`: Per-item work.
+///
+/// Output:
+/// - One slot per item, in input order; `None` for items skipped after cancellation.
+///
+/// Details:
+/// - Only the first `aur_workers` workers take AUR items (before official ones), so results are
+///   deterministic in order regardless of scheduling. With one worker everything runs on the
+///   calling thread.
+pub fn map_bounded<T, R, C, F>(
+    items: &[T],
+    workers: usize,
+    aur_workers: usize,
+    is_aur: C,
+    cancel: &AtomicBool,
+    f: F,
+) -> Vec<Option<R>>
+where
+    T: Sync,
+    R: Send,
+    C: Fn(&T) -> bool,
+    F: Fn(&T) -> R + Sync,
+{
+    let (aur, official): (Vec<usize>, Vec<usize>) =
+        (0..items.len()).partition(|&i| is_aur(&items[i]));
+    let workers = workers.max(1).min(items.len().max(1));
+    if workers == 1 {
+        return items
+            .iter()
+            .map(|item| (!cancel.load(Ordering::Relaxed)).then(|| f(item)))
+            .collect();
+    }
+    let aur_workers = aur_workers.max(1).min(workers);
+    let results: Vec<Mutex<Option<R>>> = items.iter().map(|_| Mutex::new(None)).collect();
+    let next_aur = AtomicUsize::new(0);
+    let next_official = AtomicUsize::new(0);
+    std::thread::scope(|scope| {
+        for worker in 0..workers {
+            let queues: Vec<(&[usize], &AtomicUsize)> = if worker < aur_workers {
+                vec![(&aur, &next_aur), (&official, &next_official)]
+            } else {
+                vec![(&official, &next_official)]
+            };
+            let (results, f) = (&results, &f);
+            scope.spawn(move || {
+                for (queue, next) in queues {
+                    loop {
+                        if cancel.load(Ordering::Relaxed) {
+                            return;
+                        }
+                        let Some(&idx) = queue.get(next.fetch_add(1, Ordering::Relaxed)) else {
+                            break;
+                        };
+                        let out = f(&items[idx]);
+                        if let Ok(mut slot) = results[idx].lock() {
+                            *slot = Some(out);
+                        }
+                    }
+                }
+            });
+        }
+    });
+    results
+        .into_iter()
+        .map(|slot| slot.into_inner().ok().flatten())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure the pool keeps input order, caps AUR concurrency, and honours cancellation.
+    ///
+    /// Inputs:
+    /// - 12 items (every third AUR-bound) mapped on 4 workers with an AUR cap of 2, then a
+    ///   pre-cancelled run.
+    ///
+    /// Output:
+    /// - Results in input order, never more than 2 AUR items in flight, and all `None` when
+    ///   cancelled up front.
+    fn map_bounded_orders_caps_and_cancels() {
+        let items: Vec<usize> = (0..12).collect();
+        let in_flight_aur = AtomicUsize::new(0);
+        let max_aur = AtomicUsize::new(0);
+        let cancel = AtomicBool::new(false);
+        let out = map_bounded(
+            &items,
+            4,
+            2,
+            |i| i % 3 == 0,
+            &cancel,
+            |&i| {
+                if i % 3 == 0 {
+                    let now = in_flight_aur.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_aur.fetch_max(now, Ordering::SeqCst);
+                    std::thread::sleep(std::time::Duration::from_millis(5));
+                    in_flight_aur.fetch_sub(1, Ordering::SeqCst);
+                }
+                i * 10
+            },
+        );
+        assert_eq!(out, items.iter().map(|i| Some(i * 10)).collect::<Vec<_>>());
+        assert!(max_aur.load(Ordering::SeqCst) <= 2);
+
+        cancel.store(true, Ordering::Relaxed);
+        let out = map_bounded(&items, 4, 2, |_| false, &cancel, |&i| i);
+        assert!(out.iter().all(Option::is_none));
+        let out = map_bounded(&items, 1, 1, |_| false, &cancel, |&i| i);
+        assert!(out.iter().all(Option::is_none));
+    }
+}
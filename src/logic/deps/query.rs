@@ -16,7 +16,7 @@ use std::process::{Command, Stdio};
 /// - Gracefully handles command failures by returning an empty set to avoid blocking dependency checks.
 pub(crate) fn get_upgradable_packages() -> HashSet<String> {
     tracing::debug!("Running: pacman -Qu");
-    let output = Command::new("pacman")
+    let output = Command::new(crate::install::privilege::pacman_bin())
         .args(["-Qu"])
         .env("LC_ALL", "C")
         .env("LANG", "C")
@@ -74,7 +74,7 @@ pub(crate) fn get_upgradable_packages() -> HashSet<String> {
 /// - Uses pacman's quiet format to obtain trimmed names and logs errors where available for diagnostics.
 pub fn get_installed_packages() -> HashSet<String> {
     tracing::debug!("Running: pacman -Qq");
-    let output = Command::new("pacman")
+    let output = Command::new(crate::install::privilege::pacman_bin())
         .args(["-Qq"])
         .env("LC_ALL", "C")
         .env("LANG", "C")
@@ -127,7 +127,7 @@ pub fn get_installed_packages() -> HashSet<String> {
 fn check_if_provided(name: &str, _installed: &HashSet<String>) -> Option<String> {
     // Use pacman -Qqo to check which package provides this name
     // This is efficient - pacman does the lookup internally
-    let output = Command::new("pacman")
+    let output = Command::new(crate::install::privilege::pacman_bin())
         .args(["-Qqo", name])
         .env("LC_ALL", "C")
         .env("LANG", "C")
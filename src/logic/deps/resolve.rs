@@ -27,7 +27,7 @@ pub(crate) fn batch_fetch_official_deps(names: &[&str]) -> HashMap<String, Vec<S
     for chunk in names.chunks(BATCH_SIZE) {
         let mut args = vec!["-Si"];
         args.extend(chunk.iter().copied());
-        match Command::new("pacman")
+        match Command::new(crate::install::privilege::pacman_bin())
             .args(&args)
             .env("LC_ALL", "C")
             .env("LANG", "C")
@@ -107,7 +107,7 @@ pub(crate) fn resolve_package_deps(
             // Handle local packages specially - use pacman -Qi instead of -Si
             if repo == "local" {
                 tracing::debug!("Running: pacman -Qi {} (local package)", name);
-                let output = Command::new("pacman")
+                let output = Command::new(crate::install::privilege::pacman_bin())
                     .args(["-Qi", name])
                     .env("LC_ALL", "C")
                     .env("LANG", "C")
@@ -182,7 +182,7 @@ pub(crate) fn resolve_package_deps(
             // Note: pacman -Si doesn't need repo prefix - it will find the package in any repo
             // Using repo prefix can cause failures if repo is incorrect (e.g., core package marked as extra)
             tracing::debug!("Running: pacman -Si {} (repo: {})", name, repo);
-            let output = Command::new("pacman")
+            let output = Command::new(crate::install::privilege::pacman_bin())
                 .args(["-Si", name])
                 .env("LC_ALL", "C")
                 .env("LANG", "C")
@@ -256,210 +256,98 @@ pub(crate) fn resolve_package_deps(
             // For AUR packages, first verify it actually exists in AUR before trying to resolve
             // This prevents unnecessary API calls for binaries/scripts that aren't packages
             // Quick check: if pacman -Si failed, it's likely not a real package
-            // We'll still try AUR but only if paru/yay is available (faster than API)
+            // We'll still try AUR but only if an AUR helper is available (faster than API)
             tracing::debug!(
                 "Attempting to resolve AUR package: {} (will skip if not found)",
                 name
             );
 
-            // Check if paru exists
-            let has_paru = Command::new("paru")
-                .args(["--version"])
-                .stdin(Stdio::null())
-                .stdout(Stdio::null())
-                .stderr(Stdio::null())
-                .output()
-                .is_ok();
-
-            // Check if yay exists
-            let has_yay = Command::new("yay")
-                .args(["--version"])
-                .stdin(Stdio::null())
-                .stdout(Stdio::null())
-                .stderr(Stdio::null())
-                .output()
-                .is_ok();
-
-            // Try paru/yay first, but fall back to API if they fail
+            // Try installed helpers in preference order, but fall back to .SRCINFO if they fail
             // Use -Si to get all dependencies (similar to pacman -Si)
             let mut used_helper = false;
 
-            if has_paru {
-                tracing::debug!("Trying paru -Si {} for dependency resolution", name);
-                match Command::new("paru")
-                    .args(["-Si", name])
+            for helper in super::helper::available_helpers() {
+                let Some(args) = helper.info_args(name) else {
+                    continue;
+                };
+                let bin = helper.binary();
+                tracing::debug!("Trying {} -Si {} for dependency resolution", bin, name);
+                let Ok(output) = Command::new(bin)
+                    .args(&args)
                     .env("LC_ALL", "C")
                     .env("LANG", "C")
                     .stdin(Stdio::null())
                     .stdout(Stdio::piped())
                     .stderr(Stdio::piped())
                     .output()
-                {
-                    Ok(output) => {
-                        if output.status.success() {
-                            let text = String::from_utf8_lossy(&output.stdout);
-                            tracing::debug!("paru -Si {} output ({} bytes)", name, text.len());
-                            let dep_names = parse_pacman_si_deps(&text);
-                            // Note: paru -Si only returns runtime dependencies (depends), not makedepends/checkdepends
-                            // We'll still fetch .SRCINFO later to get build-time dependencies
-                            if !dep_names.is_empty() {
-                                tracing::info!(
-                                    "Using paru to resolve runtime dependencies for {} (will fetch .SRCINFO for build-time deps)",
-                                    name
-                                );
-                                used_helper = true;
-                                for dep_spec in dep_names {
-                                    let (pkg_name, version_req) = parse_dep_spec(&dep_spec);
-                                    // Skip if this dependency is the package itself
-                                    if pkg_name == name {
-                                        tracing::debug!(
-                                            "Skipping self-reference: {} == {}",
-                                            pkg_name,
-                                            name
-                                        );
-                                        continue;
-                                    }
-                                    // Filter out .so files (virtual packages)
-                                    if pkg_name.ends_with(".so")
-                                        || pkg_name.contains(".so.")
-                                        || pkg_name.contains(".so=")
-                                    {
-                                        tracing::debug!(
-                                            "Filtering out virtual package: {}",
-                                            pkg_name
-                                        );
-                                        continue;
-                                    }
-
-                                    let status = determine_status(
-                                        &pkg_name,
-                                        &version_req,
-                                        installed,
-                                        provided,
-                                        upgradable,
-                                    );
-                                    let (source, is_core) =
-                                        determine_dependency_source(&pkg_name, installed);
-                                    let is_system = is_core || is_system_package(&pkg_name);
-
-                                    deps.push(DependencyInfo {
-                                        name: pkg_name,
-                                        version: version_req,
-                                        status,
-                                        source,
-                                        required_by: vec![name.to_string()],
-                                        depends_on: Vec::new(),
-                                        is_core,
-                                        is_system,
-                                    });
-                                }
-                            }
-                        } else {
-                            let stderr = String::from_utf8_lossy(&output.stderr);
-                            tracing::debug!(
-                                "paru -Si {} failed (will try yay or API): {}",
-                                name,
-                                stderr.trim()
-                            );
-                        }
-                    }
-                    Err(_) => {
-                        // paru not available, continue to try yay or API
-                    }
+                else {
+                    continue;
+                };
+                if !output.status.success() {
+                    let stderr = String::from_utf8_lossy(&output.stderr);
+                    tracing::debug!(
+                        "{} -Si {} failed (will try the next helper): {}",
+                        bin,
+                        name,
+                        stderr.trim()
+                    );
+                    continue;
                 }
-            }
-
-            if !used_helper && has_yay {
-                tracing::debug!("Trying yay -Si {} for dependency resolution", name);
-                match Command::new("yay")
-                    .args(["-Si", name])
-                    .env("LC_ALL", "C")
-                    .env("LANG", "C")
-                    .stdin(Stdio::null())
-                    .stdout(Stdio::piped())
-                    .stderr(Stdio::piped())
-                    .output()
-                {
-                    Ok(output) => {
-                        if output.status.success() {
-                            let text = String::from_utf8_lossy(&output.stdout);
-                            tracing::debug!("yay -Si {} output ({} bytes)", name, text.len());
-                            let dep_names = parse_pacman_si_deps(&text);
-                            // Note: yay -Si only returns runtime dependencies (depends), not makedepends/checkdepends
-                            // We'll still fetch .SRCINFO later to get build-time dependencies
-                            if !dep_names.is_empty() {
-                                tracing::info!(
-                                    "Using yay to resolve runtime dependencies for {} (will fetch .SRCINFO for build-time deps)",
-                                    name
-                                );
-                                used_helper = true;
-                                for dep_spec in dep_names {
-                                    let (pkg_name, version_req) = parse_dep_spec(&dep_spec);
-                                    // Skip if this dependency is the package itself
-                                    if pkg_name == name {
-                                        tracing::debug!(
-                                            "Skipping self-reference: {} == {}",
-                                            pkg_name,
-                                            name
-                                        );
-                                        continue;
-                                    }
-                                    // Filter out .so files (virtual packages)
-                                    if pkg_name.ends_with(".so")
-                                        || pkg_name.contains(".so.")
-                                        || pkg_name.contains(".so=")
-                                    {
-                                        tracing::debug!(
-                                            "Filtering out virtual package: {}",
-                                            pkg_name
-                                        );
-                                        continue;
-                                    }
-
-                                    let status = determine_status(
-                                        &pkg_name,
-                                        &version_req,
-                                        installed,
-                                        provided,
-                                        upgradable,
-                                    );
-                                    let (source, is_core) =
-                                        determine_dependency_source(&pkg_name, installed);
-                                    let is_system = is_core || is_system_package(&pkg_name);
-
-                                    deps.push(DependencyInfo {
-                                        name: pkg_name,
-                                        version: version_req,
-                                        status,
-                                        source,
-                                        required_by: vec![name.to_string()],
-                                        depends_on: Vec::new(),
-                                        is_core,
-                                        is_system,
-                                    });
-                                }
-                            }
-                        } else {
-                            let stderr = String::from_utf8_lossy(&output.stderr);
-                            tracing::debug!(
-                                "yay -Si {} failed (will use API): {}",
-                                name,
-                                stderr.trim()
-                            );
-                        }
+                let text = String::from_utf8_lossy(&output.stdout);
+                tracing::debug!("{} -Si {} output ({} bytes)", bin, name, text.len());
+                let dep_names = parse_pacman_si_deps(&text);
+                // Note: -Si only returns runtime dependencies (depends), not makedepends/checkdepends
+                // We'll still fetch .SRCINFO later to get build-time dependencies
+                if dep_names.is_empty() {
+                    continue;
+                }
+                tracing::info!(
+                    "Using {} to resolve runtime dependencies for {} (will fetch .SRCINFO for build-time deps)",
+                    bin,
+                    name
+                );
+                used_helper = true;
+                for dep_spec in dep_names {
+                    let (pkg_name, version_req) = parse_dep_spec(&dep_spec);
+                    // Skip if this dependency is the package itself
+                    if pkg_name == name {
+                        tracing::debug!("Skipping self-reference: {} == {}", pkg_name, name);
+                        continue;
                     }
-                    Err(_) => {
-                        // yay not available, continue to API fallback
+                    // Filter out .so files (virtual packages)
+                    if pkg_name.ends_with(".so")
+                        || pkg_name.contains(".so.")
+                        || pkg_name.contains(".so=")
+                    {
+                        tracing::debug!("Filtering out virtual package: {}", pkg_name);
+                        continue;
                     }
+
+                    let status =
+                        determine_status(&pkg_name, &version_req, installed, provided, upgradable);
+                    let (source, is_core) = determine_dependency_source(&pkg_name, installed);
+                    let is_system = is_core || is_system_package(&pkg_name);
+
+                    deps.push(DependencyInfo {
+                        name: pkg_name,
+                        version: version_req,
+                        status,
+                        source,
+                        required_by: vec![name.to_string()],
+                        depends_on: Vec::new(),
+                        is_core,
+                        is_system,
+                    });
                 }
+                break;
             }
 
-            // Skip AUR API fallback - if paru/yay failed, the package likely doesn't exist
+            // Skip AUR API fallback - if every helper failed, the package likely doesn't exist
             // This prevents unnecessary API calls for binaries/scripts that aren't packages
             // The dependency will be marked as Missing by the status determination logic
             if !used_helper {
                 tracing::debug!(
-                    "Skipping AUR API for {} - paru/yay failed or not available (likely not a real package)",
+                    "Skipping AUR API for {} - AUR helpers failed or not available (likely not a real package)",
                     name
                 );
                 // Return empty deps - the dependency will be marked as Missing
@@ -467,9 +355,9 @@ pub(crate) fn resolve_package_deps(
             }
 
             // Always try to fetch and parse .SRCINFO to get makedepends/checkdepends and enhance dependency list
-            // This is critical because paru/yay -Si only returns runtime dependencies (depends),
+            // This is critical because helper -Si only returns runtime dependencies (depends),
             // not build-time dependencies (makedepends/checkdepends)
-            // Even if paru/yay succeeded, we still need .SRCINFO for complete dependency information
+            // Even if a helper succeeded, we still need .SRCINFO for complete dependency information
             match fetch_srcinfo(name) {
                 Ok(srcinfo_text) => {
                     tracing::debug!("Successfully fetched .SRCINFO for {}", name);
@@ -565,14 +453,14 @@ pub(crate) fn resolve_package_deps(
 ///
 /// Details:
 /// - For official packages, uses `pacman -Si` to get conflicts.
-/// - For AUR packages, tries paru/yay first, then falls back to .SRCINFO.
+/// - For AUR packages, tries the installed AUR helpers in preference order, then falls back to .SRCINFO.
 pub(crate) fn fetch_package_conflicts(name: &str, source: &Source) -> Vec<String> {
     match source {
         Source::Official { repo, .. } => {
             // Handle local packages specially - use pacman -Qi instead of -Si
             if repo == "local" {
                 tracing::debug!("Running: pacman -Qi {} (local package, conflicts)", name);
-                if let Ok(output) = Command::new("pacman")
+                if let Ok(output) = Command::new(crate::install::privilege::pacman_bin())
                     .args(["-Qi", name])
                     .env("LC_ALL", "C")
                     .env("LANG", "C")
@@ -590,7 +478,7 @@ pub(crate) fn fetch_package_conflicts(name: &str, source: &Source) -> Vec<String
 
             // Use pacman -Si to get conflicts
             tracing::debug!("Running: pacman -Si {} (conflicts)", name);
-            if let Ok(output) = Command::new("pacman")
+            if let Ok(output) = Command::new(crate::install::privilege::pacman_bin())
                 .args(["-Si", name])
                 .env("LC_ALL", "C")
                 .env("LANG", "C")
@@ -606,47 +494,14 @@ pub(crate) fn fetch_package_conflicts(name: &str, source: &Source) -> Vec<String
             Vec::new()
         }
         Source::Aur => {
-            // Try paru/yay first
-            let has_paru = Command::new("paru")
-                .args(["--version"])
-                .stdin(Stdio::null())
-                .stdout(Stdio::null())
-                .stderr(Stdio::null())
-                .output()
-                .is_ok();
-
-            let has_yay = Command::new("yay")
-                .args(["--version"])
-                .stdin(Stdio::null())
-                .stdout(Stdio::null())
-                .stderr(Stdio::null())
-                .output()
-                .is_ok();
-
-            if has_paru {
-                tracing::debug!("Trying paru -Si {} for conflicts", name);
-                if let Ok(output) = Command::new("paru")
-                    .args(["-Si", name])
-                    .env("LC_ALL", "C")
-                    .env("LANG", "C")
-                    .stdin(Stdio::null())
-                    .stdout(Stdio::piped())
-                    .stderr(Stdio::piped())
-                    .output()
-                    && output.status.success()
-                {
-                    let text = String::from_utf8_lossy(&output.stdout);
-                    let conflicts = parse_pacman_si_conflicts(&text);
-                    if !conflicts.is_empty() {
-                        return conflicts;
-                    }
-                }
-            }
-
-            if has_yay {
-                tracing::debug!("Trying yay -Si {} for conflicts", name);
-                if let Ok(output) = Command::new("yay")
-                    .args(["-Si", name])
+            // Try installed helpers in preference order first
+            for helper in super::helper::available_helpers() {
+                let Some(args) = helper.info_args(name) else {
+                    continue;
+                };
+                tracing::debug!("Trying {} -Si {} for conflicts", helper.binary(), name);
+                if let Ok(output) = Command::new(helper.binary())
+                    .args(&args)
                     .env("LC_ALL", "C")
                     .env("LANG", "C")
                     .stdin(Stdio::null())
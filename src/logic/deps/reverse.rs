@@ -470,7 +470,7 @@ fn convert_entry(name: String, entry: AggregatedEntry) -> DependencyInfo {
 /// - Parses key-value fields such as repository, groups, and required-by lists for downstream processing.
 fn fetch_pkg_info(name: &str) -> Result<PkgInfo, String> {
     tracing::debug!("Running: pacman -Qi {}", name);
-    let output = Command::new("pacman")
+    let output = Command::new(crate::install::privilege::pacman_bin())
         .args(["-Qi", name])
         .env("LC_ALL", "C")
         .env("LANG", "C")
@@ -574,6 +574,108 @@ fn split_ws_or_none(field: Option<&String>) -> Vec<String> {
     }
 }
 
+/// Longest chain (in packages, including both ends) explored by [`reverse_why`].
+const WHY_MAX_DEPTH: usize = 8;
+/// Maximum number of chains returned by [`reverse_why`].
+const WHY_MAX_CHAINS: usize = 20;
+
+/// What: Explain why an installed package is present.
+///
+/// Inputs:
+/// - `name`: Installed package to explain.
+///
+/// Output:
+/// - Shortest chains `[name, dependent, ..., explicit]` from the package up to each explicitly
+///   installed package that (transitively) requires it; `[name]` alone when it is explicit
+///   itself. Empty when nothing explicit needs it (an orphan) or it is not installed.
+///
+/// Details:
+/// - Reads every installed package's "Required By" from one `pacman -Qi` run instead of one
+///   call per package, and treats packages in the explicit set (or marked "Explicitly
+///   installed") as roots. Mirrors `pactree -r` but stops at explicit packages.
+pub fn reverse_why(name: &str) -> Vec<Vec<String>> {
+    let output = Command::new(crate::install::privilege::pacman_bin())
+        .args(["-Qi"])
+        .env("LC_ALL", "C")
+        .env("LANG", "C")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .output();
+    let Ok(output) = output else {
+        return Vec::new();
+    };
+    let text = String::from_utf8_lossy(&output.stdout);
+    let mut required_by: HashMap<String, Vec<String>> = HashMap::new();
+    let mut explicit = crate::index::explicit_names();
+    for record in text.split("\n\n") {
+        let map = parse_key_value_output(record);
+        let Some(pkg) = map.get("Name").cloned() else {
+            continue;
+        };
+        if map
+            .get("Install Reason")
+            .is_some_and(|r| r.to_lowercase().contains("explicit"))
+        {
+            explicit.insert(pkg.clone());
+        }
+        required_by.insert(pkg, split_ws_or_none(map.get("Required By")));
+    }
+    if !required_by.contains_key(name) {
+        return Vec::new();
+    }
+    reverse_why_in(name, &explicit, &required_by)
+}
+
+/// What: Breadth-first walk of the "Required By" graph from `name` to explicit packages.
+///
+/// Inputs:
+/// - `name`: Starting package.
+/// - `explicit`: Explicitly installed package names (chain roots).
+/// - `required_by`: Reverse dependency edges per installed package.
+///
+/// Output:
+/// - One shortest chain per reachable explicit package, sorted by length then name, capped at
+///   [`WHY_MAX_CHAINS`] chains of at most [`WHY_MAX_DEPTH`] packages.
+///
+/// Details:
+/// - Each package is visited once, so chains are deduplicated and cycles cannot loop. The walk
+///   continues past explicit packages since another explicit package may depend on them.
+fn reverse_why_in(
+    name: &str,
+    explicit: &HashSet<String>,
+    required_by: &HashMap<String, Vec<String>>,
+) -> Vec<Vec<String>> {
+    let mut parent: HashMap<String, String> = HashMap::new();
+    let mut queue: VecDeque<(String, usize)> = VecDeque::from([(name.to_string(), 1)]);
+    let mut seen: HashSet<String> = HashSet::from([name.to_string()]);
+    let mut chains: Vec<Vec<String>> = Vec::new();
+    while let Some((pkg, depth)) = queue.pop_front() {
+        if explicit.contains(&pkg) {
+            let mut chain = vec![pkg.clone()];
+            let mut cur = &pkg;
+            while let Some(p) = parent.get(cur) {
+                chain.push(p.clone());
+                cur = p;
+            }
+            chain.reverse();
+            chains.push(chain);
+        }
+        if depth >= WHY_MAX_DEPTH {
+            continue;
+        }
+        for dependent in required_by.get(&pkg).into_iter().flatten() {
+            if seen.insert(dependent.clone()) {
+                parent.insert(dependent.clone(), pkg.clone());
+                queue.push_back((dependent.clone(), depth + 1));
+            }
+        }
+    }
+    chains.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+    chains.truncate(WHY_MAX_CHAINS);
+    chains
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -726,4 +828,53 @@ mod tests {
         assert_eq!(list, vec!["foo", "bar"]);
         assert!(split_ws_or_none(None).is_empty());
     }
+
+    #[test]
+    /// What: Ensure the "why installed" walk returns deduplicated shortest chains to explicit roots.
+    ///
+    /// Inputs:
+    /// - `libz` required by `curl` and `openssl`; `curl` required by explicit `git` and by
+    ///   `pacman`; `openssl` required by `curl` (a second path); `pacman` explicit; a cycle
+    ///   between `a` and `b`; a 10-deep chain.
+    ///
+    /// Output:
+    /// - `[libz, curl, git]` and `[libz, curl, pacman]` once each; `[git]` for an explicit
+    ///   package; cycles terminate; roots beyond the depth cap are not reported.
+    fn reverse_why_finds_shortest_chains() {
+        let edges = |pairs: &[(&str, &[&str])]| -> HashMap<String, Vec<String>> {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.iter().map(|s| s.to_string()).collect()))
+                .collect()
+        };
+        let graph = edges(&[
+            ("libz", &["curl", "openssl"]),
+            ("openssl", &["curl"]),
+            ("curl", &["git", "pacman"]),
+            ("a", &["b"]),
+            ("b", &["a"]),
+        ]);
+        let explicit: HashSet<String> = ["git", "pacman"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            reverse_why_in("libz", &explicit, &graph),
+            vec![
+                vec!["libz".to_string(), "curl".into(), "git".into()],
+                vec!["libz".to_string(), "curl".into(), "pacman".into()],
+            ]
+        );
+        assert_eq!(
+            reverse_why_in("git", &explicit, &graph),
+            vec![vec!["git".to_string()]]
+        );
+        assert!(reverse_why_in("a", &explicit, &graph).is_empty());
+
+        let mut deep: HashMap<String, Vec<String>> = HashMap::new();
+        for i in 0..10 {
+            deep.insert(format!("p{i}"), vec![format!("p{}", i + 1)]);
+        }
+        let root: HashSet<String> = HashSet::from(["p10".to_string()]);
+        assert!(reverse_why_in("p0", &root, &deep).is_empty());
+        let near: HashSet<String> = HashSet::from(["p3".to_string()]);
+        assert_eq!(reverse_why_in("p0", &near, &deep)[0].len(), 4);
+    }
 }
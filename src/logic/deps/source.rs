@@ -23,7 +23,7 @@ pub(crate) fn determine_dependency_source(
     if !installed.contains(name) {
         // Not installed - check if it exists in official repos first
         // Only default to AUR if it's not found in official repos
-        let output = Command::new("pacman")
+        let output = Command::new(crate::install::privilege::pacman_bin())
             .args(["-Si", name])
             .env("LC_ALL", "C")
             .env("LANG", "C")
@@ -75,7 +75,7 @@ pub(crate) fn determine_dependency_source(
     }
 
     // Package is installed - check which repository it came from
-    let output = Command::new("pacman")
+    let output = Command::new(crate::install::privilege::pacman_bin())
         .args(["-Qi", name])
         .env("LC_ALL", "C")
         .env("LANG", "C")
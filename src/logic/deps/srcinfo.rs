@@ -1,7 +1,6 @@
 //! Parser for AUR .SRCINFO files.
 
-use crate::util::{curl_args, percent_encode};
-use std::process::Command;
+use crate::util::{curl_command, percent_encode};
 
 /// What: Fetch .SRCINFO content for an AUR package.
 ///
@@ -21,9 +20,7 @@ pub(crate) fn fetch_srcinfo(name: &str) -> Result<String, String> {
     tracing::debug!("Fetching .SRCINFO from: {}", url);
 
     // Add timeout to prevent hanging (10 seconds)
-    let args = curl_args(&url, &["--max-time", "10"]);
-    let output = Command::new("curl")
-        .args(&args)
+    let output = curl_command(&url, &["--max-time", "10"])
         .output()
         .map_err(|e| format!("curl failed: {}", e))?;
 
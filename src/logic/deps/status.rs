@@ -100,7 +100,7 @@ pub(crate) fn determine_status(
 /// Details:
 /// - Strips revision suffixes (e.g., `-1`) so comparisons focus on the base semantic version.
 pub(crate) fn get_available_version(name: &str) -> Option<String> {
-    let output = Command::new("pacman")
+    let output = Command::new(crate::install::privilege::pacman_bin())
         .args(["-Si", name])
         .env("LC_ALL", "C")
         .env("LANG", "C")
@@ -139,7 +139,7 @@ pub(crate) fn get_available_version(name: &str) -> Option<String> {
 /// Details:
 /// - Normalizes versions by removing revision suffixes to facilitate requirement comparisons.
 pub fn get_installed_version(name: &str) -> Result<String, String> {
-    let output = Command::new("pacman")
+    let output = Command::new(crate::install::privilege::pacman_bin())
         .args(["-Q", name])
         .env("LC_ALL", "C")
         .env("LANG", "C")
@@ -0,0 +1,136 @@
+//! Discovery of cached package versions for the downgrade picker.
+
+use std::path::{Path, PathBuf};
+
+/// Default pacman package cache directory.
+pub const PACMAN_CACHE_DIR: &str = "/var/cache/pacman/pkg";
+
+/// A package archive found in the pacman cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedPackage {
+    /// Full version (`[epoch:]pkgver-pkgrel`) parsed from the file name.
+    pub version: String,
+    /// Absolute path of the archive, passed to `pacman -U`.
+    pub path: PathBuf,
+}
+
+/// What: Extract the version of `name` from a pacman cache file name.
+///
+/// Inputs:
+/// - `name`: Package name to match exactly.
+/// - `file_name`: Cache entry such as `ripgrep-14.1.0-1-x86_64.pkg.tar.zst`.
+///
+/// Output:
+/// - `Some("14.1.0-1")` when the file is an archive of `name`; `None` otherwise.
+///
+/// Details:
+/// - Signature files (`.sig`) are ignored.
+/// - Since `pkgver` and `pkgrel` never contain `-`, exactly one dash must remain after stripping
+///   the name and architecture; this keeps `foo` from matching `foo-bar-1.0-1-any`.
+pub fn parse_cached_version(name: &str, file_name: &str) -> Option<String> {
+    if file_name.ends_with(".sig") {
+        return None;
+    }
+    let rest = file_name.strip_prefix(name)?.strip_prefix('-')?;
+    let stem = &rest[..rest.find(".pkg.tar")?];
+    let (version, arch) = stem.rsplit_once('-')?;
+    if arch.is_empty() || version.matches('-').count() != 1 {
+        return None;
+    }
+    let (pkgver, pkgrel) = version.split_once('-')?;
+    if pkgver.is_empty() || pkgrel.is_empty() {
+        return None;
+    }
+    Some(version.to_string())
+}
+
+/// What: List cached archives of `name` found in `dir`, newest version first.
+///
+/// Inputs:
+/// - `dir`: Cache directory to scan.
+/// - `name`: Package name.
+///
+/// Output:
+/// - One entry per version (duplicates with other compressions are dropped); empty when the
+///   directory is missing or unreadable.
+pub fn cached_versions_in(dir: &Path, name: &str) -> Vec<CachedPackage> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut out: Vec<CachedPackage> = entries
+        .flatten()
+        .filter_map(|e| {
+            let file_name = e.file_name();
+            parse_cached_version(name, &file_name.to_string_lossy()).map(|version| CachedPackage {
+                version,
+                path: e.path(),
+            })
+        })
+        .collect();
+    out.sort_by(|a, b| {
+        crate::logic::preflight::compare_versions(&b.version, &a.version)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    out.dedup_by(|a, b| a.version == b.version);
+    out
+}
+
+/// What: List cached archives of `name` in the default pacman cache.
+///
+/// Inputs:
+/// - `name`: Package name.
+///
+/// Output:
+/// - Same as `cached_versions_in` for `PACMAN_CACHE_DIR`.
+pub fn cached_versions(name: &str) -> Vec<CachedPackage> {
+    cached_versions_in(Path::new(PACMAN_CACHE_DIR), name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure cache file names parse into versions and are listed newest first.
+    ///
+    /// Inputs:
+    /// - A temp cache with two ripgrep versions (one in two compressions), a signature,
+    ///   and an archive of a package whose name has `ripgrep` as prefix.
+    ///
+    /// Output:
+    /// - Two distinct versions in descending order; unrelated files ignored.
+    ///
+    /// Details:
+    /// - Also checks epoch versions parse verbatim.
+    fn cached_versions_parse_and_sort() {
+        assert_eq!(
+            parse_cached_version("ripgrep", "ripgrep-14.1.0-1-x86_64.pkg.tar.zst").as_deref(),
+            Some("14.1.0-1")
+        );
+        assert_eq!(
+            parse_cached_version("vim", "vim-2:9.1.0-2-x86_64.pkg.tar.xz").as_deref(),
+            Some("2:9.1.0-2")
+        );
+        assert!(parse_cached_version("ripgrep", "ripgrep-all-0.10-1-x86_64.pkg.tar.zst").is_none());
+        assert!(
+            parse_cached_version("ripgrep", "ripgrep-14.1.0-1-x86_64.pkg.tar.zst.sig").is_none()
+        );
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        for f in [
+            "ripgrep-13.0.0-3-x86_64.pkg.tar.zst",
+            "ripgrep-14.1.0-1-x86_64.pkg.tar.zst",
+            "ripgrep-14.1.0-1-x86_64.pkg.tar.xz",
+            "ripgrep-14.1.0-1-x86_64.pkg.tar.zst.sig",
+            "ripgrep-all-0.10-1-x86_64.pkg.tar.zst",
+        ] {
+            std::fs::write(dir.path().join(f), b"").expect("write");
+        }
+        let versions: Vec<String> = cached_versions_in(dir.path(), "ripgrep")
+            .into_iter()
+            .map(|c| c.version)
+            .collect();
+        assert_eq!(versions, vec!["14.1.0-1", "13.0.0-3"]);
+        assert!(cached_versions_in(&dir.path().join("missing"), "ripgrep").is_empty());
+    }
+}
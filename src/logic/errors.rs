@@ -0,0 +1,122 @@
+//! Session error history shown in the errors modal.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use crate::state::AppState;
+
+/// Most errors kept; older ones are dropped first.
+pub const ERROR_LOG_CAPACITY: usize = 50;
+
+/// One failure recorded during the session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorEntry {
+    /// Area that failed (`network`, `install`, `remove`, `mirrors`, …).
+    pub source: &'static str,
+    /// Message as shown to the user.
+    pub message: String,
+    /// Unix time of the latest occurrence.
+    pub at: i64,
+    /// How many identical errors arrived back to back.
+    pub count: u32,
+}
+
+/// Errors reported from background threads, waiting for the UI loop to collect them.
+static REPORTED: Mutex<Vec<(&'static str, String)>> = Mutex::new(Vec::new());
+
+/// What: Current Unix time in seconds.
+fn now_secs() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// What: Append an error to a bounded history.
+///
+/// Inputs:
+/// - `log`: History, oldest first.
+/// - `source`: Area that failed.
+/// - `message`: Error text.
+/// - `at`: Unix time of the error.
+///
+/// Details:
+/// - An error identical to the newest entry bumps its count and time instead of adding a row.
+pub fn push_error(log: &mut VecDeque<ErrorEntry>, source: &'static str, message: String, at: i64) {
+    if let Some(last) = log.back_mut()
+        && last.source == source
+        && last.message == message
+    {
+        last.count += 1;
+        last.at = at;
+        return;
+    }
+    if log.len() >= ERROR_LOG_CAPACITY {
+        log.pop_front();
+    }
+    log.push_back(ErrorEntry {
+        source,
+        message,
+        at,
+        count: 1,
+    });
+}
+
+/// What: Record an error in the session history from the UI thread.
+pub fn record_error(app: &mut AppState, source: &'static str, message: impl Into<String>) {
+    push_error(&mut app.error_log, source, message.into(), now_secs());
+}
+
+/// What: Report an error from a background thread; the UI loop picks it up on the next tick.
+pub fn report_error(source: &'static str, message: impl Into<String>) {
+    if let Ok(mut pending) = REPORTED.lock() {
+        pending.push((source, message.into()));
+    }
+}
+
+/// What: Move errors reported from background threads into the session history.
+pub fn collect_reported(app: &mut AppState) {
+    let pending = REPORTED
+        .lock()
+        .map(|mut p| std::mem::take(&mut *p))
+        .unwrap_or_default();
+    for (source, message) in pending {
+        record_error(app, source, message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure repeated errors collapse into one counted row and the history stays bounded.
+    ///
+    /// Inputs:
+    /// - The same error three times, a different one, then more than the capacity.
+    ///
+    /// Output:
+    /// - A count of 3 with the latest time, a separate row for the other error, and at most
+    ///   `ERROR_LOG_CAPACITY` rows keeping the newest.
+    fn push_error_dedupes_and_bounds() {
+        let mut log = VecDeque::new();
+        for at in 1..=3 {
+            push_error(&mut log, "network", "AUR search unavailable".into(), at);
+        }
+        push_error(&mut log, "install", "AUR search unavailable".into(), 4);
+        assert_eq!(log.len(), 2);
+        assert_eq!(log[0].count, 3);
+        assert_eq!(log[0].at, 3);
+        assert_eq!(log[1].source, "install");
+
+        for i in 0..ERROR_LOG_CAPACITY {
+            push_error(&mut log, "network", format!("e{i}"), 10);
+        }
+        assert_eq!(log.len(), ERROR_LOG_CAPACITY);
+        assert_eq!(
+            log.back().unwrap().message,
+            format!("e{}", ERROR_LOG_CAPACITY - 1)
+        );
+        assert_eq!(log[0].message, "e0");
+    }
+}
@@ -0,0 +1,273 @@
+//! Pacman file database (`-F`) syncing with privilege escalation and a user-local fallback.
+
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::SystemTime;
+
+/// System pacman database directory (the default `--dbpath`).
+const SYSTEM_DB_DIR: &str = "/var/lib/pacman";
+
+/// Age in days after which a file database counts as stale.
+pub const STALE_DAYS: u64 = 7;
+
+/// State of the most recent file database sync.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum SyncStatus {
+    /// No sync has been started this session.
+    #[default]
+    Idle,
+    /// A sync is running; holds the latest progress line from pacman.
+    Running(String),
+    /// The last sync succeeded; `user_db` is true when it went to the user-local copy.
+    Synced { user_db: bool },
+    /// The last sync failed with this message.
+    Failed(String),
+}
+
+/// Current sync state, shared with the UI thread.
+static STATUS: Mutex<SyncStatus> = Mutex::new(SyncStatus::Idle);
+
+/// Set when a background sync finishes; consumed by the UI tick.
+static FINISHED: AtomicBool = AtomicBool::new(false);
+
+/// What: Directory of the user-writable file database used when root syncing is unavailable.
+///
+/// Output:
+/// - `<lists_dir>/filedb`; pacman keeps its `sync/*.files` below it.
+pub fn user_db_dir() -> PathBuf {
+    crate::theme::lists_dir().join("filedb")
+}
+
+/// What: Newest modification time among the `.files` databases under a pacman `--dbpath`.
+///
+/// Inputs:
+/// - `db_dir`: Database root containing a `sync/` directory.
+///
+/// Output:
+/// - The latest `SystemTime`, or `None` when no `.files` database exists.
+pub fn files_db_timestamp(db_dir: &Path) -> Option<SystemTime> {
+    std::fs::read_dir(db_dir.join("sync"))
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|s| s.to_str()) == Some("files"))
+        .filter_map(|path| std::fs::metadata(&path).ok()?.modified().ok())
+        .max()
+}
+
+/// What: Timestamp of the system file database.
+pub fn system_db_timestamp() -> Option<SystemTime> {
+    files_db_timestamp(Path::new(SYSTEM_DB_DIR))
+}
+
+/// What: Decide whether `-F` queries should read the user-local database.
+///
+/// Inputs:
+/// - `system`: Timestamp of the system database, if any.
+/// - `user`: Timestamp of the user-local database.
+/// - `now`: Reference time for staleness.
+///
+/// Output:
+/// - `true` when the system database is missing, or stale and older than the user copy.
+fn prefer_user_db(system: Option<SystemTime>, user: SystemTime, now: SystemTime) -> bool {
+    match system {
+        None => true,
+        Some(sys) => {
+            let age_days = now.duration_since(sys).map(|d| d.as_secs() / 86400);
+            user > sys && age_days.is_ok_and(|days| days >= STALE_DAYS)
+        }
+    }
+}
+
+/// What: Database path `-F` queries should use instead of the system default.
+///
+/// Output:
+/// - `Some(user_db_dir())` when the user-local copy is preferred, else `None`.
+pub fn query_dbpath() -> Option<PathBuf> {
+    let dir = user_db_dir();
+    let user = files_db_timestamp(&dir)?;
+    prefer_user_db(system_db_timestamp(), user, SystemTime::now()).then_some(dir)
+}
+
+/// What: Newest timestamp across the system and user-local file databases.
+pub fn effective_timestamp() -> Option<SystemTime> {
+    system_db_timestamp().max(files_db_timestamp(&user_db_dir()))
+}
+
+/// What: Build a `pacman -F…` query command pointed at the preferred database.
+///
+/// Inputs:
+/// - `args`: Pacman arguments, e.g. `["-Fl", "extra/foo"]`.
+///
+/// Output:
+/// - A `Command` with `--dbpath` added when the user-local database is preferred and the
+///   locale forced to `C` for stable parsing.
+pub fn files_query_command(args: &[&str]) -> Command {
+    let mut cmd = Command::new(crate::install::privilege::pacman_bin());
+    cmd.args(args).env("LC_ALL", "C").env("LANG", "C");
+    if let Some(db) = query_dbpath() {
+        cmd.arg("--dbpath").arg(db);
+    }
+    cmd
+}
+
+/// What: Current sync state for display.
+pub fn sync_status() -> SyncStatus {
+    STATUS.lock().map(|s| s.clone()).unwrap_or_default()
+}
+
+/// What: Replace the shared sync state.
+fn set_status(status: SyncStatus) {
+    if let Ok(mut s) = STATUS.lock() {
+        *s = status;
+    }
+}
+
+/// What: Report whether a background sync finished since the last call, clearing the flag.
+pub fn take_sync_finished() -> bool {
+    FINISHED.swap(false, Ordering::Relaxed)
+}
+
+/// What: Start a file database sync on a background thread.
+///
+/// Output:
+/// - `false` when a sync is already running; `true` when one was started.
+///
+/// Details:
+/// - Progress and the outcome are published through [`sync_status`]; failures also land in
+///   the error history.
+pub fn start_sync() -> bool {
+    if let Ok(mut s) = STATUS.lock() {
+        if matches!(*s, SyncStatus::Running(_)) {
+            return false;
+        }
+        *s = SyncStatus::Running(String::new());
+    }
+    std::thread::spawn(|| {
+        if let Err(e) = sync_now() {
+            crate::logic::errors::report_error("files", e);
+        }
+        FINISHED.store(true, Ordering::Relaxed);
+    });
+    true
+}
+
+/// What: Sync the file database, blocking until done.
+///
+/// Output:
+/// - `Ok(true)` when the user-local copy was synced, `Ok(false)` for the system database,
+///   or the combined error of both attempts.
+///
+/// Details:
+/// - First runs `pacman -Fy` through the configured escalation tool in non-interactive mode,
+///   so it only succeeds with cached credentials or a no-password rule.
+/// - When that is declined or unavailable, syncs into [`user_db_dir`] with `--dbpath` under
+///   `fakeroot` (pacman refuses `-Fy` as a regular user otherwise).
+pub fn sync_now() -> Result<bool, String> {
+    set_status(SyncStatus::Running(String::new()));
+    let tool = crate::install::privilege::privilege_tool();
+    let mut root = Command::new(tool.binary());
+    root.arg(tool.non_interactive_flag())
+        .arg(crate::install::privilege::pacman_bin())
+        .arg("-Fy");
+    let root_err = match run_with_progress(root) {
+        Ok(()) => {
+            set_status(SyncStatus::Synced { user_db: false });
+            return Ok(false);
+        }
+        Err(e) => e,
+    };
+    tracing::info!("System file database sync unavailable ({root_err}); trying user-local copy");
+    let result = sync_user_db().map_err(|e| format!("{root_err}; {e}"));
+    match &result {
+        Ok(()) => set_status(SyncStatus::Synced { user_db: true }),
+        Err(e) => set_status(SyncStatus::Failed(e.clone())),
+    }
+    result.map(|()| true)
+}
+
+/// What: Sync the user-local file database via `fakeroot pacman -Fy --dbpath`.
+fn sync_user_db() -> Result<(), String> {
+    let fakeroot = which::which("fakeroot")
+        .map_err(|_| "fakeroot is not installed for a user-local sync".to_string())?;
+    let dir = user_db_dir();
+    std::fs::create_dir_all(dir.join("sync"))
+        .map_err(|e| format!("Failed to create {}: {e}", dir.display()))?;
+    // pacman expects a local database next to the sync one; reuse the system one read-only.
+    #[cfg(unix)]
+    {
+        let local = dir.join("local");
+        if !local.exists() {
+            let _ = std::os::unix::fs::symlink(Path::new(SYSTEM_DB_DIR).join("local"), &local);
+        }
+    }
+    let mut cmd = Command::new(fakeroot);
+    cmd.arg("--")
+        .arg(crate::install::privilege::pacman_bin())
+        .arg("-Fy")
+        .arg("--dbpath")
+        .arg(&dir)
+        .args(["--logfile", "/dev/null"]);
+    run_with_progress(cmd)
+}
+
+/// What: Run a sync command, publishing each output line as progress.
+///
+/// Output:
+/// - `Ok(())` on success; otherwise the last line pacman printed (or the spawn error).
+fn run_with_progress(mut cmd: Command) -> Result<(), String> {
+    let mut child = cmd
+        .env("LC_ALL", "C")
+        .env("LANG", "C")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to run {:?}: {e}", cmd.get_program()))?;
+    if let Some(out) = child.stdout.take() {
+        for line in BufReader::new(out).lines().map_while(Result::ok) {
+            let line = line.trim().to_string();
+            if !line.is_empty() {
+                set_status(SyncStatus::Running(line));
+            }
+        }
+    }
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("File database sync failed: {e}"))?;
+    if output.status.success() {
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let last = stderr.lines().rev().find(|l| !l.trim().is_empty());
+    Err(last
+        .unwrap_or("file database sync failed")
+        .trim()
+        .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    /// What: Verify when queries switch to the user-local file database.
+    ///
+    /// Inputs:
+    /// - Missing, stale, and fresh system timestamps against a newer user copy.
+    ///
+    /// Output:
+    /// - The user copy wins only when the system database is missing or stale and older.
+    fn filedb_prefers_user_db_only_when_system_is_stale() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(100 * 86400);
+        let day = Duration::from_secs(86400);
+        assert!(prefer_user_db(None, now - day, now));
+        assert!(prefer_user_db(Some(now - 10 * day), now - day, now));
+        assert!(!prefer_user_db(Some(now - 2 * day), now - day, now));
+        assert!(!prefer_user_db(Some(now - 10 * day), now - 20 * day, now));
+    }
+}
@@ -2,51 +2,24 @@
 
 use crate::state::modal::{FileChange, FileChangeType, PackageFileInfo};
 use crate::state::types::{PackageItem, Source};
-use crate::util::{curl_args, percent_encode};
+use crate::util::{curl_command, percent_encode};
 use std::collections::{HashMap, HashSet};
-use std::path::Path;
 use std::process::Command;
 use std::time::SystemTime;
 
-/// What: Retrieve the most recent modification timestamp of the pacman sync database.
+/// What: Retrieve the most recent modification timestamp of the pacman file database.
 ///
 /// Inputs:
-/// - (none): Reads metadata from `/var/lib/pacman/sync` on the local filesystem.
+/// - (none): Reads metadata from `/var/lib/pacman/sync` and the user-local file database.
 ///
 /// Output:
 /// - Returns the latest `SystemTime` seen among `.files` databases, or `None` if unavailable.
 ///
 /// Details:
 /// - Inspects only files ending with the `.files` extension to match pacman's file list databases.
+/// - Counts the user-local copy (see [`crate::logic::filedb`]) since queries fall back to it.
 pub fn get_file_db_sync_timestamp() -> Option<SystemTime> {
-    // Check modification time of pacman sync database files
-    // The sync database files are in /var/lib/pacman/sync/
-    let sync_dir = Path::new("/var/lib/pacman/sync");
-
-    if !sync_dir.exists() {
-        tracing::debug!("Pacman sync directory does not exist");
-        return None;
-    }
-
-    // Get the most recent modification time from any .files database
-    let mut latest_time: Option<SystemTime> = None;
-
-    if let Ok(entries) = std::fs::read_dir(sync_dir) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            // Look for .files database files (e.g., core.files, extra.files)
-            if path.extension().and_then(|s| s.to_str()) == Some("files")
-                && let Ok(metadata) = std::fs::metadata(&path)
-                && let Ok(modified) = metadata.modified()
-            {
-                latest_time = Some(latest_time.map_or(modified, |prev| {
-                    if modified > prev { modified } else { prev }
-                }));
-            }
-        }
-    }
-
-    latest_time
+    crate::logic::filedb::effective_timestamp()
 }
 
 /// What: Summarize sync database staleness with age, formatted date, and UI color bucket.
@@ -74,16 +47,24 @@ pub fn get_file_db_sync_info() -> Option<(u64, String, u8)> {
             .map(|d| d.as_secs() as i64),
     );
 
-    // Determine color category
-    let color_category = if age_days < 7 {
-        0 // Green (< week)
+    Some((age_days, date_str, age_color_category(age_days)))
+}
+
+/// What: Bucket a database/index age into the freshness color categories.
+///
+/// Inputs:
+/// - `age_days`: Age in whole days.
+///
+/// Output:
+/// - `0` green (< week), `1` yellow (< month), `2` red (>= month).
+pub fn age_color_category(age_days: u64) -> u8 {
+    if age_days < 7 {
+        0
     } else if age_days < 30 {
-        1 // Yellow (< month)
+        1
     } else {
-        2 // Red (>= month)
-    };
-
-    Some((age_days, date_str, color_category))
+        2
+    }
 }
 
 /// What: Determine file-level changes for a set of packages under a specific preflight action.
@@ -97,10 +78,30 @@ pub fn get_file_db_sync_info() -> Option<(u64, String, u8)> {
 ///
 /// Details:
 /// - Invokes pacman commands to compare remote and installed file lists while preserving package order.
+/// - AUR packages are skipped when `aur_disabled` is set.
 pub fn resolve_file_changes(
     items: &[PackageItem],
     action: crate::state::modal::PreflightAction,
 ) -> Vec<PackageFileInfo> {
+    resolve_file_changes_with_progress(items, action, &|_, _| {})
+}
+
+/// What: [`resolve_file_changes`] that reports how many packages are done.
+///
+/// Inputs:
+/// - `items`: Package descriptors under consideration.
+/// - `action`: Preflight action (install or remove).
+/// - `progress`: Called with `(done, total)` before the first package and after each one.
+///
+/// Output:
+/// - Same as [`resolve_file_changes`].
+pub fn resolve_file_changes_with_progress(
+    items: &[PackageItem],
+    action: crate::state::modal::PreflightAction,
+    progress: &dyn Fn(usize, usize),
+) -> Vec<PackageFileInfo> {
+    let items_kept = crate::sources::without_aur_if_disabled(items);
+    let items = &*items_kept;
     let _span = tracing::info_span!(
         "resolve_file_changes",
         stage = "files",
@@ -151,9 +152,11 @@ pub fn resolve_file_changes(
     };
 
     let mut results = Vec::new();
+    progress(0, items.len());
 
     for (idx, item) in items.iter().enumerate() {
         tracing::info!(
+            item_index = idx + 1,
             "[{}/{}] Resolving files for package: {} ({:?})",
             idx + 1,
             items.len(),
@@ -203,6 +206,7 @@ pub fn resolve_file_changes(
                 });
             }
         }
+        progress(idx + 1, items.len());
     }
 
     let elapsed = start_time.elapsed();
@@ -247,6 +251,7 @@ pub fn is_file_db_stale(max_age_days: u64) -> Option<bool> {
 /// Details:
 /// - Checks timestamp first if `force` is false, only syncing when stale.
 /// - Intended to reduce false negatives when later querying remote file lists.
+/// - Syncs through [`crate::logic::filedb::sync_now`]: escalated first, user-local otherwise.
 pub fn ensure_file_db_synced(force: bool, max_age_days: u64) -> Result<bool, String> {
     // Check if we need to sync
     if !force {
@@ -267,22 +272,7 @@ pub fn ensure_file_db_synced(force: bool, max_age_days: u64) -> Result<bool, Str
         tracing::debug!("Force syncing pacman file database...");
     }
 
-    let output = Command::new("pacman")
-        .args(["-Fy"])
-        .env("LC_ALL", "C")
-        .env("LANG", "C")
-        .output()
-        .map_err(|e| format!("Failed to execute pacman -Fy: {}", e))?;
-
-    if output.status.success() {
-        tracing::debug!("File database sync successful");
-        Ok(true)
-    } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        let error_msg = format!("File database sync failed: {}", stderr);
-        tracing::warn!("{}", error_msg);
-        Err(error_msg)
-    }
+    crate::logic::filedb::sync_now().map(|_| true)
 }
 
 /// What: Dispatch to the correct file resolution routine based on preflight action.
@@ -352,12 +342,7 @@ fn batch_get_remote_file_lists(packages: &[(&str, &Source)]) -> HashMap<String,
             let mut args = vec!["-Fl"];
             args.extend(specs.iter().map(|s| s.as_str()));
 
-            match Command::new("pacman")
-                .args(&args)
-                .env("LC_ALL", "C")
-                .env("LANG", "C")
-                .output()
-            {
+            match crate::logic::filedb::files_query_command(&args).output() {
                 Ok(output) if output.status.success() => {
                     let text = String::from_utf8_lossy(&output.stdout);
                     // Parse pacman -Fl output: format is "<pkg> <path>"
@@ -595,10 +580,7 @@ fn get_remote_file_list(name: &str, source: &Source) -> Result<Vec<String>, Stri
                 format!("{}/{}", repo, name)
             };
 
-            let output = Command::new("pacman")
-                .args(["-Fl", &spec])
-                .env("LC_ALL", "C")
-                .env("LANG", "C")
+            let output = crate::logic::filedb::files_query_command(&["-Fl", &spec])
                 .output()
                 .map_err(|e| {
                     tracing::error!("Failed to execute pacman -Fl {}: {}", spec, e);
@@ -650,58 +632,25 @@ fn get_remote_file_list(name: &str, source: &Source) -> Result<Vec<String>, Stri
                 return Ok(installed_files);
             }
 
-            // Try to use paru/yay -Fl if available (works for cached AUR packages)
-            let has_paru = Command::new("paru").args(["--version"]).output().is_ok();
-            let has_yay = Command::new("yay").args(["--version"]).output().is_ok();
-
-            if has_paru {
-                tracing::debug!("Trying paru -Fl {} for AUR package file list", name);
-                if let Ok(output) = Command::new("paru")
-                    .args(["-Fl", name])
-                    .env("LC_ALL", "C")
-                    .env("LANG", "C")
-                    .output()
-                    && output.status.success()
-                {
-                    let text = String::from_utf8_lossy(&output.stdout);
-                    let mut files = Vec::new();
-                    for line in text.lines() {
-                        if let Some((_pkg, path)) = line.split_once(' ') {
-                            files.push(path.to_string());
-                        }
-                    }
-                    if !files.is_empty() {
-                        tracing::debug!("Found {} files from paru -Fl for {}", files.len(), name);
-                        return Ok(files);
-                    }
-                }
-            }
-
-            if has_yay {
-                tracing::debug!("Trying yay -Fl {} for AUR package file list", name);
-                if let Ok(output) = Command::new("yay")
-                    .args(["-Fl", name])
-                    .env("LC_ALL", "C")
-                    .env("LANG", "C")
-                    .output()
-                    && output.status.success()
-                {
-                    let text = String::from_utf8_lossy(&output.stdout);
-                    let mut files = Vec::new();
-                    for line in text.lines() {
-                        if let Some((_pkg, path)) = line.split_once(' ') {
-                            files.push(path.to_string());
-                        }
-                    }
-                    if !files.is_empty() {
-                        tracing::debug!("Found {} files from yay -Fl for {}", files.len(), name);
-                        return Ok(files);
-                    }
+            // Try helpers that support -Fl (works for cached AUR packages); others are skipped
+            if let Some((helper, text)) = crate::logic::deps::aur_file_list_output(name) {
+                let files: Vec<String> = text
+                    .lines()
+                    .filter_map(|line| line.split_once(' ').map(|(_pkg, path)| path.to_string()))
+                    .collect();
+                if !files.is_empty() {
+                    tracing::debug!(
+                        "Found {} files from {} -Fl for {}",
+                        files.len(),
+                        helper.binary(),
+                        name
+                    );
+                    return Ok(files);
                 }
             }
 
             // Fallback: try to parse PKGBUILD to extract install paths
-            match fetch_pkgbuild_sync(name) {
+            match fetch_pkgbuild_sync(name, None) {
                 Ok(pkgbuild) => {
                     let files = parse_install_paths_from_pkgbuild(&pkgbuild, name);
                     if !files.is_empty() {
@@ -740,7 +689,7 @@ fn get_remote_file_list(name: &str, source: &Source) -> Result<Vec<String>, Stri
 /// - Logs errors if the command fails for reasons other than the package being absent.
 pub fn get_installed_file_list(name: &str) -> Result<Vec<String>, String> {
     tracing::debug!("Running: pacman -Ql {}", name);
-    let output = Command::new("pacman")
+    let output = Command::new(crate::install::privilege::pacman_bin())
         .args(["-Ql", name])
         .env("LC_ALL", "C")
         .env("LANG", "C")
@@ -780,6 +729,46 @@ pub fn get_installed_file_list(name: &str) -> Result<Vec<String>, String> {
     Ok(files)
 }
 
+/// What: List the files a package installs, for the quick file lookup modal.
+///
+/// Inputs:
+/// - `item`: Package to look up.
+///
+/// Output:
+/// - File paths (directories end with `/`), or an error when pacman fails.
+///
+/// Details:
+/// - Installed packages use `pacman -Ql` so the list matches the disk; everything else goes
+///   through the same remote lookup Preflight uses (`pacman -Fl`, AUR helpers, PKGBUILD parsing).
+/// - An empty list usually means the file database is not synced (official) or the AUR package
+///   was never built.
+pub fn package_file_list(item: &PackageItem) -> Result<Vec<String>, String> {
+    if crate::index::is_installed(&item.name) {
+        let files = get_installed_file_list(&item.name)?;
+        if !files.is_empty() {
+            return Ok(files);
+        }
+    }
+    get_remote_file_list(&item.name, &item.source)
+}
+
+/// What: Case-insensitive substring filter over a file list.
+///
+/// Inputs:
+/// - `files`: Paths to filter.
+/// - `filter`: Text typed by the user; empty keeps everything.
+///
+/// Output:
+/// - Matching paths in their original order.
+pub fn filter_file_list<'a>(files: &'a [String], filter: &str) -> Vec<&'a str> {
+    let needle = filter.trim().to_lowercase();
+    files
+        .iter()
+        .map(String::as_str)
+        .filter(|path| needle.is_empty() || path.to_lowercase().contains(&needle))
+        .collect()
+}
+
 /// What: Identify files marked for backup handling during install or removal operations.
 ///
 /// Inputs:
@@ -808,7 +797,7 @@ fn get_backup_files(name: &str, source: &Source) -> Result<Vec<String>, String>
     match source {
         Source::Official { .. } => {
             // Try to fetch PKGBUILD and parse backup array
-            match fetch_pkgbuild_sync(name) {
+            match fetch_pkgbuild_sync(name, None) {
                 Ok(pkgbuild) => {
                     let backup_files = parse_backup_from_pkgbuild(&pkgbuild);
                     if !backup_files.is_empty() {
@@ -845,7 +834,7 @@ fn get_backup_files(name: &str, source: &Source) -> Result<Vec<String>, String>
                 }
             }
             // Fallback to PKGBUILD if .SRCINFO failed
-            match fetch_pkgbuild_sync(name) {
+            match fetch_pkgbuild_sync(name, None) {
                 Ok(pkgbuild) => {
                     let backup_files = parse_backup_from_pkgbuild(&pkgbuild);
                     if !backup_files.is_empty() {
@@ -878,7 +867,7 @@ fn get_backup_files(name: &str, source: &Source) -> Result<Vec<String>, String>
 /// - Parses the `Backup Files` section, handling wrapped lines to ensure complete coverage.
 fn get_backup_files_from_installed(name: &str) -> Result<Vec<String>, String> {
     tracing::debug!("Running: pacman -Qii {}", name);
-    let output = Command::new("pacman")
+    let output = Command::new(crate::install::privilege::pacman_bin())
         .args(["-Qii", name])
         .env("LC_ALL", "C")
         .env("LANG", "C")
@@ -946,13 +935,39 @@ fn get_backup_files_from_installed(name: &str) -> Result<Vec<String>, String> {
 ///
 /// Inputs:
 /// - `name`: Package name.
+/// - `version`: Version the PKGBUILD must belong to; `None` accepts any cached version.
 ///
 /// Output:
 /// - Returns PKGBUILD content as a string, or an error if fetch fails.
 ///
 /// Details:
-/// - Uses curl to fetch PKGBUILD from AUR or official GitLab repos.
-pub fn fetch_pkgbuild_sync(name: &str) -> Result<String, String> {
+/// - Serves a fresh entry from the on-disk PKGBUILD cache before downloading. With a version,
+///   only an entry stored for that version is served (as in `sources::fetch_pkgbuild_fast`), so
+///   security scans never see a PKGBUILD older than the package; an empty version skips the
+///   cache read.
+/// - Uses curl to fetch PKGBUILD from AUR or official GitLab repos and caches the result.
+pub fn fetch_pkgbuild_sync(name: &str, version: Option<&str>) -> Result<String, String> {
+    let cached = match version {
+        None => crate::sources::pkgbuild_cache::load(name, None),
+        Some("") => None,
+        Some(v) => crate::sources::pkgbuild_cache::load(name, Some(v)),
+    };
+    if let Some(text) = cached {
+        return Ok(text);
+    }
+    let text = fetch_pkgbuild_remote_sync(name)?;
+    crate::sources::pkgbuild_cache::store(name, version.unwrap_or_default(), &text);
+    Ok(text)
+}
+
+/// What: Download PKGBUILD content synchronously, bypassing the cache.
+///
+/// Inputs:
+/// - `name`: Package name.
+///
+/// Output:
+/// - PKGBUILD text from AUR, GitLab `main`, or GitLab `master`; error if all fail.
+fn fetch_pkgbuild_remote_sync(name: &str) -> Result<String, String> {
     // Try AUR first (works for both AUR and official packages via AUR mirror)
     let url_aur = format!(
         "https://aur.archlinux.org/cgit/aur.git/plain/PKGBUILD?h={}",
@@ -960,8 +975,7 @@ pub fn fetch_pkgbuild_sync(name: &str) -> Result<String, String> {
     );
     tracing::debug!("Fetching PKGBUILD from AUR: {}", url_aur);
 
-    let args = curl_args(&url_aur, &[]);
-    let output = Command::new("curl").args(&args).output();
+    let output = curl_command(&url_aur, &[]).output();
 
     match output {
         Ok(output) if output.status.success() => {
@@ -980,8 +994,7 @@ pub fn fetch_pkgbuild_sync(name: &str) -> Result<String, String> {
     );
     tracing::debug!("Fetching PKGBUILD from GitLab main: {}", url_main);
 
-    let args = curl_args(&url_main, &[]);
-    let output = Command::new("curl").args(&args).output();
+    let output = curl_command(&url_main, &[]).output();
 
     match output {
         Ok(output) if output.status.success() => {
@@ -1000,9 +1013,7 @@ pub fn fetch_pkgbuild_sync(name: &str) -> Result<String, String> {
     );
     tracing::debug!("Fetching PKGBUILD from GitLab master: {}", url_master);
 
-    let args = curl_args(&url_master, &[]);
-    let output = Command::new("curl")
-        .args(&args)
+    let output = curl_command(&url_master, &[])
         .output()
         .map_err(|e| format!("curl failed: {}", e))?;
 
@@ -1038,9 +1049,7 @@ fn fetch_srcinfo_sync(name: &str) -> Result<String, String> {
     );
     tracing::debug!("Fetching .SRCINFO from: {}", url);
 
-    let args = curl_args(&url, &[]);
-    let output = Command::new("curl")
-        .args(&args)
+    let output = curl_command(&url, &[])
         .output()
         .map_err(|e| format!("curl failed: {}", e))?;
 
@@ -1071,11 +1080,27 @@ fn fetch_srcinfo_sync(name: &str) -> Result<String, String> {
 /// - Parses bash array syntax: `backup=('file1' 'file2' '/etc/config')`
 /// - Handles single-line and multi-line array definitions.
 fn parse_backup_from_pkgbuild(pkgbuild: &str) -> Vec<String> {
-    let mut backup_files = Vec::new();
+    backup_array_lines(pkgbuild)
+        .into_iter()
+        .flat_map(|(_, files)| files)
+        .collect()
+}
+
+/// What: Locate the lines of the `backup=()` array and the entries each line contributes.
+///
+/// Inputs:
+/// - `pkgbuild`: Raw PKGBUILD file content.
+///
+/// Output:
+/// - `(line_index, entries)` for every array line (declaration, members, closing line), in order.
+///
+/// Details:
+/// - Backs [`parse_backup_from_pkgbuild`]; comment and empty lines inside the array are skipped.
+fn backup_array_lines(pkgbuild: &str) -> Vec<(usize, Vec<String>)> {
+    let mut lines_out = Vec::new();
     let mut in_backup_array = false;
-    let mut current_line = String::new();
 
-    for line in pkgbuild.lines() {
+    for (idx, line) in pkgbuild.lines().enumerate() {
         let line = line.trim();
 
         // Skip comments and empty lines
@@ -1083,10 +1108,10 @@ fn parse_backup_from_pkgbuild(pkgbuild: &str) -> Vec<String> {
             continue;
         }
 
+        let mut backup_files = Vec::new();
         // Look for backup= array declaration
         if line.starts_with("backup=") || line.starts_with("backup =") {
             in_backup_array = true;
-            current_line = line.to_string();
 
             // Check if array is on single line: backup=('file1' 'file2')
             if let Some(start) = line.find('(')
@@ -1095,7 +1120,6 @@ fn parse_backup_from_pkgbuild(pkgbuild: &str) -> Vec<String> {
                 let array_content = &line[start + 1..end];
                 parse_backup_array_content(array_content, &mut backup_files);
                 in_backup_array = false;
-                current_line.clear();
             } else if line.contains('(') {
                 // Multi-line array starting
                 if let Some(start) = line.find('(') {
@@ -1104,26 +1128,22 @@ fn parse_backup_from_pkgbuild(pkgbuild: &str) -> Vec<String> {
                 }
             }
         } else if in_backup_array {
-            // Continuation of multi-line array
-            current_line.push(' ');
-            current_line.push_str(line);
-
-            // Check if array ends
-            if line.contains(')') {
-                if let Some(end) = line.rfind(')') {
-                    let remaining = &line[..end];
-                    parse_backup_array_content(remaining, &mut backup_files);
-                }
+            // Continuation of multi-line array; check if array ends
+            if let Some(end) = line.rfind(')') {
+                let remaining = &line[..end];
+                parse_backup_array_content(remaining, &mut backup_files);
                 in_backup_array = false;
-                current_line.clear();
             } else {
                 // Still in array, parse this line
                 parse_backup_array_content(line, &mut backup_files);
             }
+        } else {
+            continue;
         }
+        lines_out.push((idx, backup_files));
     }
 
-    backup_files
+    lines_out
 }
 
 /// What: Parse backup array content (handles quoted strings).
@@ -1222,11 +1242,41 @@ fn parse_backup_from_srcinfo(srcinfo: &str) -> Vec<String> {
 /// - Extracts paths from `package()` functions that use `install` commands.
 /// - This is a best-effort heuristic and may not capture all files.
 pub fn parse_install_paths_from_pkgbuild(pkgbuild: &str, pkgname: &str) -> Vec<String> {
+    let mut files: Vec<String> = install_path_lines(pkgbuild)
+        .into_iter()
+        .map(|(_, path)| path)
+        .collect();
+
+    // Remove duplicates and sort
+    files.sort();
+    files.dedup();
+
+    // If we didn't find any files, try to infer common paths based on package name
+    if files.is_empty() {
+        // Common default paths for AUR packages
+        files.push(format!("/usr/bin/{}", pkgname));
+        files.push(format!("/usr/share/{}", pkgname));
+    }
+
+    files
+}
+
+/// What: Locate `install`/`cp` lines inside `package()` that write below `$pkgdir`.
+///
+/// Inputs:
+/// - `pkgbuild`: Raw PKGBUILD file content.
+///
+/// Output:
+/// - `(line_index, destination)` per matching line, in file order.
+///
+/// Details:
+/// - Backs [`parse_install_paths_from_pkgbuild`] (which adds sorting and name-based fallbacks).
+fn install_path_lines(pkgbuild: &str) -> Vec<(usize, String)> {
     let mut files = Vec::new();
     let mut in_package_function = false;
     let mut package_function_depth = 0;
 
-    for line in pkgbuild.lines() {
+    for (idx, line) in pkgbuild.lines().enumerate() {
         let trimmed = line.trim();
 
         // Skip comments and empty lines
@@ -1289,7 +1339,7 @@ pub fn parse_install_paths_from_pkgbuild(pkgbuild: &str, pkgname: &str) -> Vec<S
                         path.remove(0);
                     }
                     if !path.is_empty() {
-                        files.push(format!("/{}", path));
+                        files.push((idx, format!("/{}", path)));
                     }
                 }
             } else if trimmed.contains("cp") && trimmed.contains("$pkgdir") {
@@ -1312,25 +1362,144 @@ pub fn parse_install_paths_from_pkgbuild(pkgbuild: &str, pkgname: &str) -> Vec<S
                         path.remove(0);
                     }
                     if !path.is_empty() {
-                        files.push(format!("/{}", path));
+                        files.push((idx, format!("/{}", path)));
                     }
                 }
             }
         }
     }
 
-    // Remove duplicates and sort
-    files.sort();
-    files.dedup();
+    files
+}
 
-    // If we didn't find any files, try to infer common paths based on package name
-    if files.is_empty() {
-        // Common default paths for AUR packages
-        files.push(format!("/usr/bin/{}", pkgname));
-        files.push(format!("/usr/share/{}", pkgname));
+/// Audit-relevant kinds of PKGBUILD lines surfaced in the PKGBUILD viewer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PkgbuildMark {
+    /// `install`/`cp` into `$pkgdir` inside `package()`.
+    InstallPath,
+    /// Member line of the `backup=()` array.
+    Backup,
+}
+
+/// What: Mark PKGBUILD lines that install files or declare backup entries.
+///
+/// Inputs:
+/// - `pkgbuild`: Raw PKGBUILD file content.
+///
+/// Output:
+/// - `(line_index, mark)` sorted by line; a line matching both kinds is reported as
+///   [`PkgbuildMark::InstallPath`].
+///
+/// Details:
+/// - Uses the same scans as [`parse_install_paths_from_pkgbuild`] and the backup parser, so every
+///   line of a multi-line `backup=()` array is marked.
+pub fn pkgbuild_line_marks(pkgbuild: &str) -> Vec<(usize, PkgbuildMark)> {
+    let mut marks = std::collections::BTreeMap::new();
+    for (idx, _) in install_path_lines(pkgbuild) {
+        marks.insert(idx, PkgbuildMark::InstallPath);
+    }
+    for (idx, _) in backup_array_lines(pkgbuild) {
+        marks.entry(idx).or_insert(PkgbuildMark::Backup);
     }
+    marks.into_iter().collect()
+}
 
-    files
+/// What: Find the next marked line after the current PKGBUILD scroll position.
+///
+/// Inputs:
+/// - `marks`: Output of [`pkgbuild_line_marks`].
+/// - `after`: Current top line (`pkgb_scroll`).
+///
+/// Output:
+/// - The first marked line below `after`, wrapping to the first mark; `None` without marks.
+pub fn next_marked_line(marks: &[(usize, PkgbuildMark)], after: usize) -> Option<usize> {
+    marks
+        .iter()
+        .map(|(idx, _)| *idx)
+        .find(|&idx| idx > after)
+        .or_else(|| marks.first().map(|(idx, _)| *idx))
+}
+
+/// A path that more than one package in the install list would install.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileCollision {
+    /// Absolute path claimed more than once.
+    pub path: String,
+    /// Packages claiming the path, in install-list order.
+    pub packages: Vec<String>,
+}
+
+/// What: Find paths that two or more packages in the install list would both install.
+///
+/// Inputs:
+/// - `infos`: Per-package file deltas from `resolve_file_changes`.
+///
+/// Output:
+/// - Collisions sorted by path; empty when every path has a single owner.
+///
+/// Details:
+/// - Only new/changed files count (removals cannot collide); directories (trailing `/`) are
+///   shared legitimately and skipped. pacman aborts the whole transaction on such conflicts.
+pub fn detect_file_collisions(infos: &[PackageFileInfo]) -> Vec<FileCollision> {
+    let mut owners: std::collections::BTreeMap<&str, Vec<&str>> = std::collections::BTreeMap::new();
+    for info in infos {
+        for file in &info.files {
+            if matches!(file.change_type, FileChangeType::Removed) || file.path.ends_with('/') {
+                continue;
+            }
+            let owner = if file.package.is_empty() {
+                info.name.as_str()
+            } else {
+                file.package.as_str()
+            };
+            let entry = owners.entry(file.path.as_str()).or_default();
+            if !entry.contains(&owner) {
+                entry.push(owner);
+            }
+        }
+    }
+    owners
+        .into_iter()
+        .filter(|(_, pkgs)| pkgs.len() > 1)
+        .map(|(path, pkgs)| FileCollision {
+            path: path.to_string(),
+            packages: pkgs.into_iter().map(str::to_string).collect(),
+        })
+        .collect()
+}
+
+/// What: Recompute the file collisions shown in the Preflight Files tab when its data changed.
+///
+/// Inputs:
+/// - `app`: Application state; reads the open Preflight modal.
+///
+/// Output:
+/// - Updates `app.preflight_file_collisions`; empty unless an install Preflight is open.
+///
+/// Details:
+/// - The runtime calls this before drawing; [`detect_file_collisions`] only reruns when the
+///   action, the packages, or their file counts changed since the last call.
+pub fn refresh_file_collisions(app: &mut crate::state::AppState) {
+    use std::hash::{Hash, Hasher};
+    let infos = match &app.modal {
+        crate::state::Modal::Preflight {
+            action: crate::state::PreflightAction::Install,
+            file_info,
+            ..
+        } => Some(file_info),
+        _ => None,
+    };
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    for info in infos.into_iter().flatten() {
+        info.name.hash(&mut hasher);
+        info.files.len().hash(&mut hasher);
+    }
+    let key = infos.map(|_| hasher.finish());
+    if key == app.preflight_file_collisions_key {
+        return;
+    }
+    app.preflight_file_collisions = infos.map(|i| detect_file_collisions(i)).unwrap_or_default();
+    app.preflight_file_collisions_key = key;
 }
 
 #[cfg(all(test, unix))]
@@ -1415,6 +1584,46 @@ backup=(
         assert!(backup_files.contains(&"/etc/more.conf".to_string()));
     }
 
+    #[test]
+    /// What: Ensure line marks cover `$pkgdir` installs and every line of a multi-line backup array.
+    ///
+    /// Inputs:
+    /// - A PKGBUILD with a three-line `backup=()` array (plus a comment inside) and a `package()`
+    ///   body with one `install` into `$pkgdir` and one unrelated command.
+    ///
+    /// Output:
+    /// - Backup marks on the declaration, member and closing lines; an install mark on the
+    ///   `install` line only; `next_marked_line` walks the marks and wraps around.
+    fn test_pkgbuild_line_marks() {
+        let pkgbuild = "pkgname=test\n\
+backup=('/etc/a.conf'\n\
+  # keep\n\
+  '/etc/b.conf'\n\
+)\n\
+package() {\n\
+  cd \"$srcdir\"\n\
+  install -Dm644 a.conf \"$pkgdir/etc/a.conf\"\n\
+}\n";
+        assert_eq!(
+            pkgbuild_line_marks(pkgbuild),
+            vec![
+                (1, PkgbuildMark::Backup),
+                (3, PkgbuildMark::Backup),
+                (4, PkgbuildMark::Backup),
+                (7, PkgbuildMark::InstallPath),
+            ]
+        );
+        assert_eq!(
+            parse_backup_from_pkgbuild(pkgbuild),
+            vec!["/etc/a.conf", "/etc/b.conf"]
+        );
+        let marks = pkgbuild_line_marks(pkgbuild);
+        assert_eq!(next_marked_line(&marks, 0), Some(1));
+        assert_eq!(next_marked_line(&marks, 4), Some(7));
+        assert_eq!(next_marked_line(&marks, 7), Some(1));
+        assert_eq!(next_marked_line(&[], 0), None);
+    }
+
     #[test]
     fn test_parse_backup_from_srcinfo() {
         let srcinfo = r#"
@@ -1584,4 +1793,83 @@ exit 1
         assert!(!regular_entry.is_config);
         assert!(!regular_entry.predicted_pacsave);
     }
+
+    #[test]
+    /// What: Ensure paths shared by two packages in the install list are reported as collisions.
+    ///
+    /// Inputs:
+    /// - `tool-a` and `tool-b` both installing `/usr/bin/tool` and the `/usr/bin/` directory,
+    ///   plus a removal of `/usr/lib/x` from one and a new `/usr/lib/x` in the other.
+    ///
+    /// Output:
+    /// - Exactly one collision, `/usr/bin/tool` owned by `tool-a` and `tool-b`.
+    fn detect_file_collisions_flags_shared_paths() {
+        let change = |path: &str, change_type: FileChangeType, package: &str| FileChange {
+            path: path.into(),
+            change_type,
+            package: package.into(),
+            is_config: false,
+            predicted_pacnew: false,
+            predicted_pacsave: false,
+        };
+        let info = |name: &str, files: Vec<FileChange>| PackageFileInfo {
+            name: name.into(),
+            total_count: files.len(),
+            new_count: files.len(),
+            changed_count: 0,
+            removed_count: 0,
+            config_count: 0,
+            pacnew_candidates: 0,
+            pacsave_candidates: 0,
+            files,
+        };
+        let infos = vec![
+            info(
+                "tool-a",
+                vec![
+                    change("/usr/bin/", FileChangeType::New, "tool-a"),
+                    change("/usr/bin/tool", FileChangeType::New, "tool-a"),
+                    change("/usr/lib/x", FileChangeType::Removed, "tool-a"),
+                ],
+            ),
+            info(
+                "tool-b",
+                vec![
+                    change("/usr/bin/", FileChangeType::New, "tool-b"),
+                    change("/usr/bin/tool", FileChangeType::Changed, "tool-b"),
+                    change("/usr/lib/x", FileChangeType::New, "tool-b"),
+                ],
+            ),
+        ];
+        assert_eq!(
+            detect_file_collisions(&infos),
+            vec![FileCollision {
+                path: "/usr/bin/tool".into(),
+                packages: vec!["tool-a".into(), "tool-b".into()],
+            }]
+        );
+        assert!(detect_file_collisions(&infos[..1]).is_empty());
+    }
+
+    #[test]
+    /// What: Ensure the file list filter is a case-insensitive substring match.
+    ///
+    /// Inputs:
+    /// - Three paths filtered by an empty string, `"BIN"`, and a non-matching needle.
+    ///
+    /// Output:
+    /// - Everything, the two `bin` paths in order, and nothing.
+    fn filter_file_list_matches_substrings() {
+        let files = vec![
+            "/usr/bin/".to_string(),
+            "/usr/bin/rg".to_string(),
+            "/usr/share/man/man1/rg.1.gz".to_string(),
+        ];
+        assert_eq!(filter_file_list(&files, "").len(), 3);
+        assert_eq!(
+            filter_file_list(&files, " BIN "),
+            vec!["/usr/bin/", "/usr/bin/rg"]
+        );
+        assert!(filter_file_list(&files, "zzz").is_empty());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::state::{AppState, PackageItem, Source};
+use crate::state::{AppState, PackageItem, SearchScope, Source};
 
 #[inline]
 /// What: Conditionally push a `PackageItem` into the filtered results buffer.
@@ -29,14 +29,24 @@ fn return_if_true(cond: bool, it: PackageItem, out: &mut Vec<PackageItem>) {
 ///
 /// Details:
 /// - Unknown official repos are included only when all official filters are enabled.
+/// - In [`SearchScope::NameOnly`] results matching the query only through their description are
+///   dropped.
 /// - Selection is restored by name when present; otherwise clamped or cleared if list is empty.
 pub fn apply_filters_and_sort_preserve_selection(app: &mut AppState) {
     // Capture previous selected name to preserve when possible
     let prev_name = app.results.get(app.selected).map(|p| p.name.clone());
 
     // Filter from all_results into results based on toggles
+    let query = app.input.trim().to_string();
     let mut filtered: Vec<PackageItem> = Vec::with_capacity(app.all_results.len());
     for it in app.all_results.iter().cloned() {
+        if !in_search_scope(&it, &query, app.search_scope) {
+            continue;
+        }
+        // Narrows the repo toggles below instead of replacing them
+        if app.results_filter_upgradable_only && !is_upgradable(&it) {
+            continue;
+        }
         let include = match &it.source {
             Source::Aur => app.results_filter_show_aur,
             Source::Official { repo, .. } => {
@@ -82,6 +92,390 @@ pub fn apply_filters_and_sort_preserve_selection(app: &mut AppState) {
     }
 }
 
+/// What: Check whether `available` is newer than the `installed` version.
+///
+/// Inputs:
+/// - `available`: Version offered by the repo index or the AUR.
+/// - `installed`: Version reported by `pacman -Q`.
+///
+/// Output:
+/// - `true` when both are known and `available` compares greater.
+pub fn is_newer_version(available: &str, installed: &str) -> bool {
+    !available.is_empty()
+        && !installed.is_empty()
+        && crate::logic::preflight::vercmp(available, installed) == std::cmp::Ordering::Greater
+}
+
+/// What: Check whether a result is installed in an older version than the one offered.
+///
+/// Details:
+/// - Uses the cached `pacman -Q` versions, so AUR results compare against the locally built
+///   package the same way official ones do.
+pub fn is_upgradable(item: &PackageItem) -> bool {
+    crate::index::installed_version(&item.name)
+        .is_some_and(|installed| is_newer_version(&item.version, &installed))
+}
+
+/// What: Check whether a result satisfies the active search scope for a query.
+///
+/// Inputs:
+/// - `item`: Candidate result.
+/// - `query`: Trimmed search input (compared case-insensitively).
+/// - `scope`: Active [`SearchScope`].
+///
+/// Output:
+/// - `true` for name+description scope or an empty query; in name-only scope, `true` only when
+///   every whitespace-separated term matches the name (`match_rank` below 3).
+///
+/// Details:
+/// - A `/.../` query is matched as a regex against the name (and the description unless in
+///   name-only scope), falling back to a literal match when the regex is invalid.
+pub fn in_search_scope(item: &PackageItem, query: &str, scope: SearchScope) -> bool {
+    if let Some(body) = regex_body(query) {
+        return regex_match(&item.name, body).is_some()
+            || (scope == SearchScope::NameAndDescription
+                && regex_match(&item.description, body).is_some());
+    }
+    match scope {
+        SearchScope::NameAndDescription => true,
+        SearchScope::NameOnly => query
+            .to_lowercase()
+            .split_whitespace()
+            .all(|term| crate::util::match_rank(&item.name, term) < 3),
+    }
+}
+
+/// What: Restrict incoming search results to explicitly installed packages (installed-only mode).
+///
+/// Inputs:
+/// - `incoming`: Results returned for the current query.
+/// - `query`: Current search input.
+/// - `explicit`: Names of explicitly installed packages.
+///
+/// Output:
+/// - For an empty query, every explicitly installed package (official index entries, with
+///   unknown names shown as AUR or EOS); otherwise `incoming` intersected with `explicit`.
+pub fn installed_only_results(
+    mut incoming: Vec<PackageItem>,
+    query: &str,
+    explicit: &std::collections::HashSet<String>,
+) -> Vec<PackageItem> {
+    if !query.trim().is_empty() {
+        incoming.retain(|p| explicit.contains(&p.name));
+        return incoming;
+    }
+    let mut items: Vec<PackageItem> = crate::index::all_official()
+        .into_iter()
+        .filter(|p| explicit.contains(&p.name))
+        .collect();
+    let official_names: std::collections::HashSet<String> =
+        items.iter().map(|p| p.name.clone()).collect();
+    for name in explicit {
+        if !official_names.contains(name) {
+            // If name indicates EOS, classify as official EOS
+            let source = if crate::index::is_eos_name(name) {
+                Source::Official {
+                    repo: "EOS".to_string(),
+                    arch: String::new(),
+                }
+            } else {
+                Source::Aur
+            };
+            items.push(PackageItem {
+                name: name.clone(),
+                version: String::new(),
+                description: String::new(),
+                source,
+                popularity: None,
+            });
+        }
+    }
+    items
+}
+
+/// Score bonus that keeps every contiguous substring hit above any scattered fuzzy hit.
+const SUBSTRING_BONUS: i64 = 1_000_000;
+
+/// What: Score `text` against `pattern` with fzf-style subsequence matching.
+///
+/// Inputs:
+/// - `text`: Candidate string (package name, description, or recent query).
+/// - `pattern`: Find pattern; compared case-insensitively.
+///
+/// Output:
+/// - `Some((score, positions))` where `positions` are char indices of the matched characters in
+///   `text`, or `None` when `pattern` is not a subsequence of `text`. Higher scores rank first.
+///
+/// Details:
+/// - An empty pattern matches everything with score 0 and no positions.
+/// - Contiguous substring hits get `SUBSTRING_BONUS` (earlier starts and shorter texts score
+///   higher), so they always rank above scattered matches.
+/// - Scattered hits earn points per character plus bonuses for consecutive runs and word starts,
+///   minus the gaps between matched characters.
+pub fn fuzzy_score(text: &str, pattern: &str) -> Option<(i64, Vec<usize>)> {
+    let lower = |s: &str| -> Vec<char> {
+        s.chars()
+            .map(|c| c.to_lowercase().next().unwrap_or(c))
+            .collect()
+    };
+    let t = lower(text);
+    let p = lower(pattern);
+    if p.is_empty() {
+        return Some((0, Vec::new()));
+    }
+    if p.len() > t.len() {
+        return None;
+    }
+    if let Some(start) = t.windows(p.len()).position(|w| w == p.as_slice()) {
+        let score = SUBSTRING_BONUS - 10 * start as i64 - (t.len() - p.len()) as i64;
+        return Some((score, (start..start + p.len()).collect()));
+    }
+    let mut positions = Vec::with_capacity(p.len());
+    let mut score = 0i64;
+    let mut prev: Option<usize> = None;
+    for (ti, &c) in t.iter().enumerate() {
+        if positions.len() == p.len() {
+            break;
+        }
+        if c != p[positions.len()] {
+            continue;
+        }
+        score += 16;
+        if ti == 0 || !t[ti - 1].is_alphanumeric() {
+            score += 8;
+        }
+        match prev {
+            Some(q) if q + 1 == ti => score += 12,
+            Some(q) => score -= (ti - q - 1) as i64,
+            None => {}
+        }
+        positions.push(ti);
+        prev = Some(ti);
+    }
+    (positions.len() == p.len()).then_some((score, positions))
+}
+
+/// Compiled-program size cap for find/filter regexes; larger patterns count as invalid.
+const REGEX_SIZE_LIMIT: usize = 1 << 20;
+
+/// Last compiled find/filter regex keyed by its source (`None` when it failed to compile).
+static REGEX_CACHE: std::sync::Mutex<Option<(String, Option<regex::Regex>)>> =
+    std::sync::Mutex::new(None);
+
+/// What: Extract the regex source from a pattern wrapped in slashes.
+///
+/// Inputs:
+/// - `pattern`: Find or search pattern such as `/^lib.*-dev$/`.
+///
+/// Output:
+/// - `Some("^lib.*-dev$")` for slash-wrapped patterns with a non-empty body; `None` otherwise.
+pub fn regex_body(pattern: &str) -> Option<&str> {
+    pattern
+        .strip_prefix('/')?
+        .strip_suffix('/')
+        .filter(|body| !body.is_empty())
+}
+
+/// What: Compile a find/filter regex, reusing the last compilation for the same source.
+///
+/// Inputs:
+/// - `body`: Regex source without the surrounding slashes.
+///
+/// Output:
+/// - Case-insensitive `Regex`, or `None` when the source is invalid or exceeds the size cap.
+///
+/// Details:
+/// - Uses the `regex` crate, whose automata-based engine matches in linear time, so hostile
+///   patterns cannot trigger catastrophic backtracking; `REGEX_SIZE_LIMIT` bounds compile cost.
+/// - Compiles lazily on first use; rendering calls this per row, hence the one-entry cache.
+pub fn cached_regex(body: &str) -> Option<regex::Regex> {
+    let mut cache = REGEX_CACHE.lock().ok()?;
+    if let Some((src, re)) = cache.as_ref()
+        && src == body
+    {
+        return re.clone();
+    }
+    let re = regex::RegexBuilder::new(body)
+        .case_insensitive(true)
+        .size_limit(REGEX_SIZE_LIMIT)
+        .build()
+        .ok();
+    *cache = Some((body.to_string(), re.clone()));
+    re
+}
+
+/// What: Whether `pattern` is slash-wrapped but not a valid regex.
+///
+/// Output:
+/// - `true` when matching falls back to a literal substring search of the body.
+pub fn is_invalid_regex(pattern: &str) -> bool {
+    regex_body(pattern).is_some_and(|body| cached_regex(body).is_none())
+}
+
+/// What: Match `text` against a slash-wrapped regex pattern.
+///
+/// Inputs:
+/// - `text`: Candidate string.
+/// - `body`: Regex source without slashes.
+///
+/// Output:
+/// - `Some((score, positions))` for the leftmost match (char positions of the matched span);
+///   invalid regexes fall back to a literal case-insensitive substring match of `body`.
+pub fn regex_match(text: &str, body: &str) -> Option<(i64, Vec<usize>)> {
+    let Some(re) = cached_regex(body) else {
+        return fuzzy_score(text, body)
+            .filter(|(_, positions)| positions.windows(2).all(|w| w[1] == w[0] + 1));
+    };
+    let m = re.find(text)?;
+    let start = text[..m.start()].chars().count();
+    let len = m.as_str().chars().count();
+    let total = text.chars().count();
+    let score = SUBSTRING_BONUS - 10 * start as i64 - (total - len) as i64;
+    Some((score, (start..start + len).collect()))
+}
+
+/// What: Match `text` against the pane-find pattern using substring, fuzzy, or regex rules.
+///
+/// Inputs:
+/// - `text`: Candidate string.
+/// - `pattern`: Pane-find pattern; `/.../` is treated as a regex.
+/// - `fuzzy`: When `true`, scattered subsequence hits also match (`fuzzy_find` setting).
+///
+/// Output:
+/// - `Some((score, positions))` as in `fuzzy_score`, or `None` when `text` does not match.
+pub fn pane_find_match(text: &str, pattern: &str, fuzzy: bool) -> Option<(i64, Vec<usize>)> {
+    if let Some(body) = regex_body(pattern) {
+        return regex_match(text, body);
+    }
+    // Substring hits are exactly the contiguous ones; scattered hits need fuzzy mode
+    fuzzy_score(text, pattern)
+        .filter(|(_, positions)| fuzzy || positions.windows(2).all(|w| w[1] == w[0] + 1))
+}
+
+/// Settings keys for every Results filter toggle, in the order they are emitted.
+pub const RESULTS_FILTER_KEYS: [&str; 15] = [
+    "results_filter_show_aur",
+    "results_filter_show_core",
+    "results_filter_show_extra",
+    "results_filter_show_multilib",
+    "results_filter_show_eos",
+    "results_filter_show_cachyos",
+    "results_filter_show_artix",
+    "results_filter_show_artix_omniverse",
+    "results_filter_show_artix_universe",
+    "results_filter_show_artix_lib32",
+    "results_filter_show_artix_galaxy",
+    "results_filter_show_artix_world",
+    "results_filter_show_artix_system",
+    "results_filter_show_manjaro",
+    "results_filter_upgradable_only",
+];
+
+/// What: Map a Results filter settings key to the matching `AppState` toggle.
+///
+/// Inputs:
+/// - `app`: Mutable application state holding the filter toggles.
+/// - `key`: One of `RESULTS_FILTER_KEYS`.
+///
+/// Output:
+/// - Mutable reference to the toggle, or `None` for unknown keys.
+fn results_filter_flag<'a>(app: &'a mut AppState, key: &str) -> Option<&'a mut bool> {
+    Some(match key {
+        "results_filter_show_aur" => &mut app.results_filter_show_aur,
+        "results_filter_show_core" => &mut app.results_filter_show_core,
+        "results_filter_show_extra" => &mut app.results_filter_show_extra,
+        "results_filter_show_multilib" => &mut app.results_filter_show_multilib,
+        "results_filter_show_eos" => &mut app.results_filter_show_eos,
+        "results_filter_show_cachyos" => &mut app.results_filter_show_cachyos,
+        "results_filter_show_artix" => &mut app.results_filter_show_artix,
+        "results_filter_show_artix_omniverse" => &mut app.results_filter_show_artix_omniverse,
+        "results_filter_show_artix_universe" => &mut app.results_filter_show_artix_universe,
+        "results_filter_show_artix_lib32" => &mut app.results_filter_show_artix_lib32,
+        "results_filter_show_artix_galaxy" => &mut app.results_filter_show_artix_galaxy,
+        "results_filter_show_artix_world" => &mut app.results_filter_show_artix_world,
+        "results_filter_show_artix_system" => &mut app.results_filter_show_artix_system,
+        "results_filter_show_manjaro" => &mut app.results_filter_show_manjaro,
+        "results_filter_upgradable_only" => &mut app.results_filter_upgradable_only,
+        _ => return None,
+    })
+}
+
+/// What: Set a Results filter toggle by its settings key.
+///
+/// Inputs:
+/// - `app`: Mutable application state.
+/// - `key`: One of `RESULTS_FILTER_KEYS`.
+/// - `on`: New toggle value.
+///
+/// Output:
+/// - `true` when `key` was recognised and applied; `false` otherwise.
+pub fn set_results_filter(app: &mut AppState, key: &str, on: bool) -> bool {
+    match results_filter_flag(app, key) {
+        Some(flag) => {
+            *flag = on;
+            true
+        }
+        None => false,
+    }
+}
+
+/// What: Snapshot every Results filter toggle paired with its settings key.
+///
+/// Inputs:
+/// - `app`: Application state holding the filter toggles.
+///
+/// Output:
+/// - `(key, enabled)` pairs in `RESULTS_FILTER_KEYS` order.
+pub fn results_filter_values(app: &AppState) -> [(&'static str, bool); 15] {
+    [
+        (RESULTS_FILTER_KEYS[0], app.results_filter_show_aur),
+        (RESULTS_FILTER_KEYS[1], app.results_filter_show_core),
+        (RESULTS_FILTER_KEYS[2], app.results_filter_show_extra),
+        (RESULTS_FILTER_KEYS[3], app.results_filter_show_multilib),
+        (RESULTS_FILTER_KEYS[4], app.results_filter_show_eos),
+        (RESULTS_FILTER_KEYS[5], app.results_filter_show_cachyos),
+        (RESULTS_FILTER_KEYS[6], app.results_filter_show_artix),
+        (
+            RESULTS_FILTER_KEYS[7],
+            app.results_filter_show_artix_omniverse,
+        ),
+        (
+            RESULTS_FILTER_KEYS[8],
+            app.results_filter_show_artix_universe,
+        ),
+        (RESULTS_FILTER_KEYS[9], app.results_filter_show_artix_lib32),
+        (
+            RESULTS_FILTER_KEYS[10],
+            app.results_filter_show_artix_galaxy,
+        ),
+        (RESULTS_FILTER_KEYS[11], app.results_filter_show_artix_world),
+        (
+            RESULTS_FILTER_KEYS[12],
+            app.results_filter_show_artix_system,
+        ),
+        (RESULTS_FILTER_KEYS[13], app.results_filter_show_manjaro),
+        (RESULTS_FILTER_KEYS[14], app.results_filter_upgradable_only),
+    ]
+}
+
+/// What: Render the current Results filters and sort mode as settings.conf lines.
+///
+/// Inputs:
+/// - `app`: Application state providing filter toggles and `sort_mode`.
+///
+/// Output:
+/// - Newline-terminated `key = value` lines suitable for pasting into settings.conf.
+///
+/// Details:
+/// - Always emits every filter key so the pasted block reproduces the view exactly.
+pub fn view_settings_lines(app: &AppState) -> String {
+    let mut out = format!("sort_mode = {}\n", app.sort_mode.as_config_key());
+    for (key, on) in results_filter_values(app) {
+        out.push_str(&format!("{key} = {on}\n"));
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,6 +493,70 @@ mod tests {
         }
     }
 
+    #[test]
+    /// What: Ensure slash-wrapped patterns match as regexes and invalid ones fall back to literals.
+    ///
+    /// Inputs:
+    /// - `/^lib.*-dev$/` against several names, an invalid `/lib(/` pattern, and `/`-only input.
+    ///
+    /// Output:
+    /// - Regex hits with the matched span as positions; literal fallback flagged as invalid;
+    ///   non-wrapped patterns keep substring semantics.
+    fn pane_find_match_supports_regex_patterns() {
+        assert_eq!(regex_body("/^lib.*-dev$/"), Some("^lib.*-dev$"));
+        assert_eq!(regex_body("//"), None);
+        assert_eq!(regex_body("lib"), None);
+
+        let pat = "/^lib.*-dev$/";
+        let (_, positions) = pane_find_match("libfoo-dev", pat, false).expect("regex hit");
+        assert_eq!(positions, (0..10).collect::<Vec<_>>());
+        assert!(pane_find_match("LIBFOO-DEV", pat, false).is_some());
+        assert!(pane_find_match("glibc-dev-tools", pat, false).is_none());
+        assert!(!is_invalid_regex(pat));
+
+        assert!(is_invalid_regex("/lib(/"));
+        assert!(pane_find_match("xlib(yz", "/lib(/", false).is_some());
+        assert!(pane_find_match("library", "/lib(/", false).is_none());
+
+        let dev = item_official("libfoo-dev", "extra");
+        let other = item_official("foo", "extra");
+        assert!(in_search_scope(&dev, pat, SearchScope::NameOnly));
+        assert!(!in_search_scope(
+            &other,
+            pat,
+            SearchScope::NameAndDescription
+        ));
+    }
+
+    #[test]
+    /// What: Ensure name-only scope drops results that match the query only by description.
+    ///
+    /// Inputs:
+    /// - Query `editor` over `editorconfig-core-c`, `vim` (described as an editor), and `gedit`.
+    ///
+    /// Output:
+    /// - Name+description keeps all three; name-only keeps just `editorconfig-core-c`.
+    fn search_scope_name_only_excludes_description_matches() {
+        let mut app = AppState {
+            input: "Editor".into(),
+            ..Default::default()
+        };
+        let mut vim = item_official("vim", "extra");
+        vim.description = "Vi Improved, a highly configurable text editor".into();
+        app.all_results = vec![
+            item_official("editorconfig-core-c", "extra"),
+            vim,
+            item_official("gedit", "extra"),
+        ];
+        apply_filters_and_sort_preserve_selection(&mut app);
+        assert_eq!(app.results.len(), 3);
+
+        app.search_scope = SearchScope::NameOnly;
+        apply_filters_and_sort_preserve_selection(&mut app);
+        let names: Vec<&str> = app.results.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["editorconfig-core-c"]);
+    }
+
     #[test]
     /// What: Ensure repo/AUR filters include only enabled repositories while keeping selection stable.
     ///
@@ -239,4 +697,136 @@ mod tests {
             _ => false,
         }));
     }
+
+    #[cfg(not(target_os = "windows"))]
+    #[allow(clippy::await_holding_lock)]
+    #[tokio::test]
+    /// What: Ensure the upgradable-only filter keeps outdated installs and composes with repo toggles.
+    ///
+    /// Inputs:
+    /// - A fake `pacman -Q` reporting `vim 9.0-1`, `bash 5.2-1`, and `yay 12.0-1`; results with
+    ///   newer `vim`/`yay`, same-version `bash`, and uninstalled `zsh`; AUR hidden afterwards.
+    ///
+    /// Output:
+    /// - `vim` and `yay` listed with the filter on, only `vim` once the AUR toggle is off, and
+    ///   every result back with the filter off.
+    async fn upgradable_only_filter_composes_with_repo_toggles() {
+        let _guard = crate::index::test_mutex()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let dir = tempfile::tempdir().unwrap();
+        let pacman = dir.path().join("pacman");
+        std::fs::write(
+            &pacman,
+            "#!/bin/sh\necho 'vim 9.0-1'\necho 'bash 5.2-1'\necho 'yay 12.0-1'\n",
+        )
+        .unwrap();
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&pacman, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+        crate::index::refresh_installed_cache_with(&pacman).await;
+
+        let mut vim = item_official("vim", "extra");
+        vim.version = "9.1-1".into();
+        let mut bash = item_official("bash", "core");
+        bash.version = "5.2-1".into();
+        let zsh = item_official("zsh", "extra");
+        let yay = PackageItem {
+            name: "yay".into(),
+            version: "12.3-1".into(),
+            description: String::new(),
+            source: Source::Aur,
+            popularity: None,
+        };
+        let mut app = AppState {
+            all_results: vec![vim, bash, zsh, yay],
+            results_filter_upgradable_only: true,
+            ..Default::default()
+        };
+        let names = |app: &AppState| {
+            let mut v: Vec<String> = app.results.iter().map(|p| p.name.clone()).collect();
+            v.sort();
+            v
+        };
+        apply_filters_and_sort_preserve_selection(&mut app);
+        assert_eq!(names(&app), vec!["vim", "yay"]);
+        app.results_filter_show_aur = false;
+        apply_filters_and_sort_preserve_selection(&mut app);
+        assert_eq!(names(&app), vec!["vim"]);
+        app.results_filter_upgradable_only = false;
+        apply_filters_and_sort_preserve_selection(&mut app);
+        assert_eq!(names(&app), vec!["bash", "vim", "zsh"]);
+    }
+
+    #[test]
+    /// What: Ensure emitted view settings mirror `AppState` and round-trip through the settings loader.
+    ///
+    /// Inputs:
+    /// - App with a mix of enabled/disabled filters and a non-default sort mode.
+    ///
+    /// Output:
+    /// - Parsed `Settings` carry the same sort mode, and applying its filters to a fresh
+    ///   state reproduces every toggle.
+    ///
+    /// Details:
+    /// - Uses the same parser as settings.conf loading so the copied lines stay paste-compatible.
+    fn view_settings_lines_round_trip_through_settings_loader() {
+        let mut app = AppState {
+            sort_mode: crate::state::SortMode::AurPopularityThenOfficial,
+            ..Default::default()
+        };
+        app.results_filter_show_aur = false;
+        app.results_filter_show_multilib = false;
+        app.results_filter_show_eos = true;
+        app.results_filter_show_manjaro = false;
+
+        let lines = view_settings_lines(&app);
+        assert!(lines.starts_with("sort_mode = aur_popularity\n"));
+        assert!(lines.contains("results_filter_show_aur = false\n"));
+        assert!(lines.contains("results_filter_show_core = true\n"));
+
+        let mut parsed = crate::theme::Settings::default();
+        crate::theme::apply_settings_content(&mut parsed, &lines);
+        assert_eq!(parsed.sort_mode, app.sort_mode);
+        assert_eq!(parsed.results_filters.len(), RESULTS_FILTER_KEYS.len());
+
+        let mut restored = AppState::default();
+        for (key, on) in results_filter_values(&app) {
+            set_results_filter(&mut restored, key, !on);
+        }
+        for (key, on) in &parsed.results_filters {
+            assert!(set_results_filter(&mut restored, key, *on));
+        }
+        assert_eq!(
+            results_filter_values(&restored),
+            results_filter_values(&app)
+        );
+    }
+
+    #[test]
+    /// What: Ensure fuzzy matching finds subsequences and ranks substring hits above scattered ones.
+    ///
+    /// Inputs:
+    /// - Pattern `rpg` against `ripgrep`, pattern `grep` against `ripgrep` and `git-remote-gcrypt`,
+    ///   an empty pattern, and a non-subsequence pattern.
+    ///
+    /// Output:
+    /// - Subsequence matches with highlight positions; substring outranks scattered; empty matches all.
+    ///
+    /// Details:
+    /// - Substring-only mode (`fuzzy = false`) rejects scattered hits but keeps substring ones.
+    fn fuzzy_score_matches_subsequences_and_ranks_substrings_first() {
+        let (_, pos) = fuzzy_score("ripgrep", "rpg").expect("rpg is a subsequence of ripgrep");
+        assert_eq!(pos, vec![0, 2, 3]);
+        let (sub, sub_pos) = fuzzy_score("ripgrep", "grep").expect("substring");
+        assert_eq!(sub_pos, vec![3, 4, 5, 6]);
+        let (scattered, _) = fuzzy_score("git-remote-gcrypt", "grep").expect("scattered");
+        assert!(sub > scattered);
+        assert_eq!(fuzzy_score("anything", ""), Some((0, Vec::new())));
+        assert!(fuzzy_score("ripgrep", "xyz").is_none());
+        assert!(pane_find_match("ripgrep", "RPG", true).is_some());
+        assert!(pane_find_match("ripgrep", "rpg", false).is_none());
+        assert!(pane_find_match("ripgrep", "GRE", false).is_some());
+    }
 }
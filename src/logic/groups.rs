@@ -0,0 +1,103 @@
+//! pacman package groups (`pacman -Sgg`) used to expand groups added to the install list.
+
+use std::collections::HashMap;
+use std::process::Command;
+use std::sync::RwLock;
+
+/// Group name -> member packages, loaded on first use.
+static GROUPS: RwLock<Option<HashMap<String, Vec<String>>>> = RwLock::new(None);
+
+/// What: Parse `pacman -Sgg` output (`<group> <package>` per line).
+///
+/// Output:
+/// - Map from group name to its members in listed order.
+fn parse_groups(output: &str) -> HashMap<String, Vec<String>> {
+    let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+    for line in output.lines() {
+        if let Some((group, pkg)) = line.trim().split_once(char::is_whitespace) {
+            let pkg = pkg.trim();
+            if !pkg.is_empty() {
+                groups
+                    .entry(group.to_string())
+                    .or_default()
+                    .push(pkg.to_string());
+            }
+        }
+    }
+    groups
+}
+
+/// What: Replace the cached group table (used after a sync or in tests).
+///
+/// Inputs:
+/// - `groups`: Group name to member packages.
+pub fn set_groups(groups: HashMap<String, Vec<String>>) {
+    if let Ok(mut g) = GROUPS.write() {
+        *g = Some(groups);
+    }
+}
+
+/// What: Member packages of a sync-database group.
+///
+/// Inputs:
+/// - `name`: Candidate group name.
+///
+/// Output:
+/// - Members sorted by name; empty when `name` is not a group (or pacman is unavailable).
+///
+/// Details:
+/// - The first successful `pacman -Sgg` run caches every group, so repeated adds (e.g. an
+///   import) do not spawn pacman per name. A failed run is not cached and is retried next time.
+pub fn group_members(name: &str) -> Vec<String> {
+    let cached = GROUPS.read().map(|g| g.is_some()).unwrap_or(false);
+    if !cached {
+        let output = Command::new(crate::install::privilege::pacman_bin())
+            .args(["-Sgg"])
+            .env("LC_ALL", "C")
+            .env("LANG", "C")
+            .output();
+        match output {
+            Ok(o) if o.status.success() => {
+                let groups = parse_groups(&String::from_utf8_lossy(&o.stdout));
+                tracing::debug!(count = groups.len(), "loaded pacman groups");
+                set_groups(groups);
+            }
+            Ok(o) => {
+                tracing::warn!(status = ?o.status, "pacman -Sgg failed; groups not cached");
+                return Vec::new();
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "failed to run pacman -Sgg; groups not cached");
+                return Vec::new();
+            }
+        }
+    }
+    let mut members = GROUPS
+        .read()
+        .ok()
+        .and_then(|g| g.as_ref().and_then(|m| m.get(name).cloned()))
+        .unwrap_or_default();
+    members.sort();
+    members
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure `pacman -Sgg` output is grouped by group name.
+    ///
+    /// Inputs:
+    /// - Lines for two groups plus a blank and a malformed line.
+    ///
+    /// Output:
+    /// - Each group maps to its members in order; malformed lines are ignored.
+    fn parse_groups_collects_members() {
+        let out = "xorg xorg-server\nxorg xorg-xinit\n\nlonely\nkde-applications dolphin\n";
+        let groups = parse_groups(out);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups["xorg"], vec!["xorg-server", "xorg-xinit"]);
+        assert_eq!(groups["kde-applications"], vec!["dolphin"]);
+    }
+}
@@ -0,0 +1,222 @@
+//! Bounded undo/redo history for the install, remove, and downgrade lists.
+
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use crate::state::{AppState, PackageItem};
+
+/// Maximum number of undo steps kept.
+pub const HISTORY_DEPTH: usize = 50;
+
+/// Same-kind changes closer together than this are merged into one undo step.
+const COALESCE_WINDOW: Duration = Duration::from_millis(500);
+
+/// Kind of list change, used to coalesce bursts (e.g. a batch import) into one undo step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListOp {
+    /// Entries added to a list.
+    Add,
+    /// Single entries (or a group) removed from a list.
+    Remove,
+    /// A whole list (or all lists) cleared; never coalesced.
+    Clear,
+}
+
+/// Contents of the three editable lists at one point in time.
+#[derive(Debug, Clone, Default)]
+pub struct ListSnapshot {
+    /// Install list entries.
+    pub install: Vec<PackageItem>,
+    /// Remove list entries.
+    pub remove: Vec<PackageItem>,
+    /// Downgrade list entries.
+    pub downgrade: Vec<PackageItem>,
+    /// Group marks of expanded install-list entries.
+    pub groups: HashMap<String, String>,
+}
+
+impl ListSnapshot {
+    /// What: Copy the current lists out of `app`.
+    pub fn capture(app: &AppState) -> Self {
+        Self {
+            install: app.install_list.clone(),
+            remove: app.remove_list.clone(),
+            downgrade: app.downgrade_list.clone(),
+            groups: app.install_group_of.clone(),
+        }
+    }
+
+    /// What: Put this snapshot back into `app`.
+    ///
+    /// Details:
+    /// - Selections jump to the first entry (or none), the install list is marked dirty for
+    ///   persistence, and install-list resolution caches are dropped like any other edit.
+    pub fn restore(self, app: &mut AppState) {
+        app.install_list = self.install;
+        app.remove_list = self.remove;
+        app.downgrade_list = self.downgrade;
+        app.install_group_of = self.groups;
+        for (state, len) in [
+            (&mut app.install_state, app.install_list.len()),
+            (&mut app.remove_state, app.remove_list.len()),
+            (&mut app.downgrade_state, app.downgrade_list.len()),
+        ] {
+            state.select((len > 0).then_some(0));
+        }
+        app.install_dirty = true;
+        app.install_list_deps.clear();
+        app.install_list_files.clear();
+        app.deps_resolving = false;
+        app.files_resolving = false;
+    }
+}
+
+/// Undo and redo stacks of list snapshots.
+#[derive(Debug, Default)]
+pub struct ListHistory {
+    /// States before each recorded change, oldest first.
+    undo: VecDeque<ListSnapshot>,
+    /// States undone since the last recorded change, most recent last.
+    redo: Vec<ListSnapshot>,
+    /// Kind and time of the last recorded change, for coalescing.
+    last: Option<(ListOp, Instant)>,
+}
+
+impl ListHistory {
+    /// What: Record the state from before a change.
+    ///
+    /// Inputs:
+    /// - `before`: Lists as they were before the change.
+    /// - `op`: Kind of change.
+    /// - `now`: Time of the change.
+    ///
+    /// Details:
+    /// - Any new change drops the redo stack. An `Add`/`Remove` following the same kind within
+    ///   the coalesce window extends the previous step instead of adding one. The oldest step is
+    ///   dropped beyond [`HISTORY_DEPTH`].
+    pub fn record(&mut self, before: ListSnapshot, op: ListOp, now: Instant) {
+        self.redo.clear();
+        let coalesce = op != ListOp::Clear
+            && !self.undo.is_empty()
+            && self
+                .last
+                .is_some_and(|(prev, at)| prev == op && now.duration_since(at) < COALESCE_WINDOW);
+        self.last = Some((op, now));
+        if coalesce {
+            return;
+        }
+        self.undo.push_back(before);
+        if self.undo.len() > HISTORY_DEPTH {
+            self.undo.pop_front();
+        }
+    }
+
+    /// What: Whether there is a change to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// What: Whether there is an undone change to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// What: Step back one change.
+    ///
+    /// Inputs:
+    /// - `current`: Lists as they are now (kept for redo).
+    ///
+    /// Output:
+    /// - The state to restore, or `None` when there is nothing to undo.
+    pub fn undo(&mut self, current: ListSnapshot) -> Option<ListSnapshot> {
+        let prev = self.undo.pop_back()?;
+        self.redo.push(current);
+        self.last = None;
+        Some(prev)
+    }
+
+    /// What: Re-apply the most recently undone change.
+    ///
+    /// Inputs:
+    /// - `current`: Lists as they are now (kept for undo).
+    ///
+    /// Output:
+    /// - The state to restore, or `None` when there is nothing to redo.
+    pub fn redo(&mut self, current: ListSnapshot) -> Option<ListSnapshot> {
+        let next = self.redo.pop()?;
+        self.undo.push_back(current);
+        self.last = None;
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// What: Snapshot with the given install-list names.
+    fn snap(names: &[&str]) -> ListSnapshot {
+        ListSnapshot {
+            install: names
+                .iter()
+                .map(|n| PackageItem {
+                    name: (*n).to_string(),
+                    version: String::new(),
+                    description: String::new(),
+                    source: crate::state::Source::Aur,
+                    popularity: None,
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    /// What: Install-list names of a restored snapshot, for comparisons.
+    fn names(s: Option<ListSnapshot>) -> Option<Vec<String>> {
+        s.map(|s| s.install.into_iter().map(|p| p.name).collect())
+    }
+
+    #[test]
+    /// What: Ensure history coalesces bursts, caps its depth, and undoes/redoes in order.
+    ///
+    /// Inputs:
+    /// - Two adds within the coalesce window, a clear, then undo/redo; and 60 spaced-out adds.
+    ///
+    /// Output:
+    /// - Undo of the clear restores the full list, the burst undoes as one step, redo replays,
+    ///   and at most [`HISTORY_DEPTH`] steps are kept.
+    fn history_coalesces_caps_and_round_trips() {
+        let mut h = ListHistory::default();
+        let t = Instant::now();
+        h.record(snap(&[]), ListOp::Add, t);
+        h.record(snap(&["a"]), ListOp::Add, t + Duration::from_millis(100));
+        h.record(
+            snap(&["a", "b"]),
+            ListOp::Clear,
+            t + Duration::from_millis(200),
+        );
+
+        assert_eq!(
+            names(h.undo(snap(&[]))),
+            Some(vec!["a".to_string(), "b".to_string()])
+        );
+        assert_eq!(names(h.undo(snap(&["a", "b"]))), Some(Vec::new()));
+        assert!(h.undo(snap(&[])).is_none());
+        assert_eq!(
+            names(h.redo(snap(&[]))),
+            Some(vec!["a".to_string(), "b".to_string()])
+        );
+        assert_eq!(names(h.redo(snap(&["a", "b"]))), Some(Vec::new()));
+        assert!(h.redo(snap(&[])).is_none());
+
+        let mut h = ListHistory::default();
+        for i in 0..60u64 {
+            h.record(snap(&[]), ListOp::Add, t + Duration::from_secs(i));
+        }
+        let mut steps = 0;
+        while h.undo(snap(&[])).is_some() {
+            steps += 1;
+        }
+        assert_eq!(steps, HISTORY_DEPTH);
+    }
+}
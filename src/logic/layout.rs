@@ -0,0 +1,106 @@
+//! Middle-row pane splitter: turning a border drag into layout percentages.
+
+/// Smallest width (percent) a pane can be dragged to, so it never disappears.
+pub const MIN_PANE_PCT: u16 = 5;
+
+/// Draggable vertical border in the middle row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaneBorder {
+    /// Between Recent (left) and Search (center).
+    LeftCenter,
+    /// Between Search (center) and Install/Remove (right).
+    CenterRight,
+}
+
+/// What: Compute layout percentages after dragging a middle-row border.
+///
+/// Inputs:
+/// - `pcts`: Current `(left, center, right)` percentages; must sum to 100.
+/// - `border`: Border being dragged.
+/// - `at_pct`: Pointer position as a percentage of the middle row width (0–100).
+/// - `right_scale`: `(num, den)` factor applied to the stored right width when drawn (installed-only
+///   mode widens it by 3/2); `(1, 1)` otherwise.
+///
+/// Output:
+/// - New `(left, center, right)` that still sum to 100 with every pane at least [`MIN_PANE_PCT`].
+///
+/// Details:
+/// - Only the two panes next to the border change; the third keeps its stored width.
+/// - `at_pct` is clamped, so dragging past a neighbour stops at its minimum width.
+pub fn drag_split(
+    pcts: (u16, u16, u16),
+    border: PaneBorder,
+    at_pct: u16,
+    right_scale: (u16, u16),
+) -> (u16, u16, u16) {
+    let (left, _, right) = pcts;
+    match border {
+        PaneBorder::LeftCenter => {
+            let max_left = 100u16.saturating_sub(right + MIN_PANE_PCT);
+            let left = at_pct.clamp(MIN_PANE_PCT, max_left.max(MIN_PANE_PCT));
+            (left, 100 - left - right, right)
+        }
+        PaneBorder::CenterRight => {
+            let (num, den) = right_scale;
+            let drawn = 100u16.saturating_sub(at_pct);
+            let stored = (u32::from(drawn) * u32::from(den) / u32::from(num.max(1))) as u16;
+            let max_right = 100u16.saturating_sub(left + MIN_PANE_PCT);
+            let right = stored.clamp(MIN_PANE_PCT, max_right.max(MIN_PANE_PCT));
+            (left, 100 - left - right, right)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure border drags keep the 100% sum and the per-pane minimum.
+    ///
+    /// Inputs:
+    /// - The default 20/60/20 split dragged to normal positions, past both neighbours, and with the
+    ///   installed-only 3/2 right scale.
+    ///
+    /// Output:
+    /// - Expected splits; extreme drags stop at `MIN_PANE_PCT`; the scaled drag stores 2/3 of the
+    ///   drawn right width.
+    fn drag_split_keeps_sum_and_minimum() {
+        let base = (20, 60, 20);
+        assert_eq!(
+            drag_split(base, PaneBorder::LeftCenter, 30, (1, 1)),
+            (30, 50, 20)
+        );
+        assert_eq!(
+            drag_split(base, PaneBorder::CenterRight, 70, (1, 1)),
+            (20, 50, 30)
+        );
+        assert_eq!(
+            drag_split(base, PaneBorder::LeftCenter, 0, (1, 1)),
+            (5, 75, 20)
+        );
+        assert_eq!(
+            drag_split(base, PaneBorder::LeftCenter, 99, (1, 1)),
+            (75, 5, 20)
+        );
+        assert_eq!(
+            drag_split(base, PaneBorder::CenterRight, 100, (1, 1)),
+            (20, 75, 5)
+        );
+        assert_eq!(
+            drag_split(base, PaneBorder::CenterRight, 10, (1, 1)),
+            (20, 5, 75)
+        );
+        assert_eq!(
+            drag_split(base, PaneBorder::CenterRight, 70, (3, 2)),
+            (20, 60, 20)
+        );
+        for at in 0..=100 {
+            for border in [PaneBorder::LeftCenter, PaneBorder::CenterRight] {
+                let (l, c, r) = drag_split(base, border, at, (3, 2));
+                assert_eq!(l + c + r, 100);
+                assert!(l >= MIN_PANE_PCT && c >= MIN_PANE_PCT && r >= MIN_PANE_PCT);
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use crate::state::{AppState, PackageItem};
+use crate::logic::history::{ListOp, ListSnapshot};
+use crate::state::{AppState, Modal, PackageItem, PreflightAction};
 
 /// What: Add a `PackageItem` to the install list if it is not already present.
 ///
@@ -8,9 +9,13 @@ use crate::state::{AppState, PackageItem};
 ///
 /// Output:
 /// - Inserts at the front on success, marks list dirty, and selects index 0; no-op on dedup.
+///   A pacman group opens a `GroupExpand` confirmation instead of being added.
 ///
 /// Details:
 /// - Updates `last_install_change` to support UI throttling of follow-up actions.
+/// - Groups added while a `GroupExpand` prompt is open are queued on it; when another modal is
+///   open the name is added as-is (pacman installs groups too).
+/// - Expanded members and names the user chose to keep as a package skip group detection.
 pub fn add_to_install_list(app: &mut AppState, item: PackageItem) {
     if app
         .install_list
@@ -19,6 +24,52 @@ pub fn add_to_install_list(app: &mut AppState, item: PackageItem) {
     {
         return;
     }
+    let key = item.name.to_lowercase();
+    let members =
+        if app.install_group_of.contains_key(&key) || app.install_group_as_package.contains(&key) {
+            Vec::new()
+        } else {
+            crate::logic::groups::group_members(&item.name)
+        };
+    if !members.is_empty() {
+        match &mut app.modal {
+            Modal::GroupExpand { queued, .. } => {
+                queued.push(item);
+                return;
+            }
+            Modal::None => {
+                let is_package = crate::index::find_official(&item.name).is_some();
+                app.modal = Modal::GroupExpand {
+                    item,
+                    members,
+                    is_package,
+                    queued: Vec::new(),
+                };
+                return;
+            }
+            _ => {}
+        }
+    }
+    add_to_install_list_as_is(app, item);
+}
+
+/// What: Add an item to the install list without group detection.
+///
+/// Inputs:
+/// - `app`: Mutable application state
+/// - `item`: Package to add
+///
+/// Output:
+/// - Same as [`add_to_install_list`] for a plain package.
+pub fn add_to_install_list_as_is(app: &mut AppState, item: PackageItem) {
+    if app
+        .install_list
+        .iter()
+        .any(|p| p.name.eq_ignore_ascii_case(&item.name))
+    {
+        return;
+    }
+    record_list_change(app, ListOp::Add);
     app.install_list.insert(0, item);
     app.install_dirty = true;
     app.last_install_change = Some(std::time::Instant::now());
@@ -26,6 +77,300 @@ pub fn add_to_install_list(app: &mut AppState, item: PackageItem) {
     app.install_state.select(Some(0));
 }
 
+/// What: Build install-list entries for a group's members and mark them as belonging to it.
+///
+/// Inputs:
+/// - `app`: Application state receiving the group marks
+/// - `group`: Group name
+/// - `members`: Member package names
+///
+/// Output:
+/// - One item per member not already in the install list, resolved from the official index
+///   when possible.
+///
+/// Details:
+/// - Callers enqueue the items (e.g. via the add channel) so the usual resolution runs; the marks
+///   let [`remove_install_group`] drop the whole group later.
+/// - Members the user already added (individually or through another group) are neither
+///   returned nor marked, so removing this group leaves them alone.
+pub fn group_member_items(app: &mut AppState, group: &str, members: &[String]) -> Vec<PackageItem> {
+    members
+        .iter()
+        .filter(|name| {
+            !app.install_list
+                .iter()
+                .any(|p| p.name.eq_ignore_ascii_case(name))
+        })
+        .map(|name| {
+            app.install_group_of
+                .entry(name.to_lowercase())
+                .or_insert_with(|| group.to_string());
+            crate::index::find_official(name).unwrap_or_else(|| PackageItem {
+                name: name.clone(),
+                version: String::new(),
+                description: String::new(),
+                source: crate::state::Source::Official {
+                    repo: String::new(),
+                    arch: String::new(),
+                },
+                popularity: None,
+            })
+        })
+        .collect()
+}
+
+/// What: Remove every install-list entry that was expanded from `group`.
+///
+/// Inputs:
+/// - `app`: Mutable application state
+/// - `group`: Group name
+///
+/// Output:
+/// - Number of entries removed.
+pub fn remove_install_group(app: &mut AppState, group: &str) -> usize {
+    if app.install_group_of.values().any(|g| g == group) {
+        record_list_change(app, ListOp::Remove);
+    }
+    let before = app.install_list.len();
+    let marks = &app.install_group_of;
+    app.install_list
+        .retain(|p| marks.get(&p.name.to_lowercase()).is_none_or(|g| g != group));
+    app.install_group_of.retain(|_, g| g != group);
+    let removed = before - app.install_list.len();
+    if removed > 0 {
+        app.install_dirty = true;
+        app.install_list_deps.clear();
+        app.install_list_files.clear();
+        app.deps_resolving = false;
+        app.files_resolving = false;
+        if app.install_list.is_empty() {
+            app.install_state.select(None);
+        } else {
+            app.install_state.select(Some(0));
+        }
+    }
+    removed
+}
+
+/// What: Empty the install, remove, and downgrade lists at once.
+///
+/// Inputs:
+/// - `app`: Mutable application state
+///
+/// Output:
+/// - Total number of entries removed across the three lists.
+///
+/// Details:
+/// - Resets all three selections and the install-list caches, and marks the install list dirty
+///   so the empty list is persisted. Pane focus is left alone, so installed-only mode keeps its
+///   split panes and simply shows them empty.
+pub fn clear_all_lists(app: &mut AppState) -> usize {
+    let cleared = app.install_list.len() + app.remove_list.len() + app.downgrade_list.len();
+    if cleared > 0 {
+        record_list_change(app, ListOp::Clear);
+    }
+    if !app.install_list.is_empty() {
+        app.install_dirty = true;
+    }
+    app.install_list.clear();
+    app.remove_list.clear();
+    app.downgrade_list.clear();
+    app.install_state.select(None);
+    app.remove_state.select(None);
+    app.downgrade_state.select(None);
+    app.install_group_of.clear();
+    app.install_list_deps.clear();
+    app.install_list_files.clear();
+    app.deps_resolving = false;
+    app.files_resolving = false;
+    cleared
+}
+
+/// What: Load saved entries (e.g. a profile) into the install list.
+///
+/// Inputs:
+/// - `app`: Mutable application state
+/// - `items`: Entries in saved order
+/// - `append`: Keep the current entries and add the new ones instead of replacing the list
+///
+/// Output:
+/// - Number of entries that ended up newly in the list.
+///
+/// Details:
+/// - Replacing is one undoable step; appending skips duplicates like any other add. Either way
+///   the saved order is kept at the top of the list.
+pub fn load_into_install_list(app: &mut AppState, items: Vec<PackageItem>, append: bool) -> usize {
+    if append {
+        let before = app.install_list.len();
+        for item in items.into_iter().rev() {
+            add_to_install_list_as_is(app, item);
+        }
+        return app.install_list.len() - before;
+    }
+    record_list_change(app, ListOp::Clear);
+    let mut seen = std::collections::HashSet::new();
+    app.install_list = items
+        .into_iter()
+        .filter(|p| seen.insert(p.name.to_lowercase()))
+        .collect();
+    app.install_group_of.clear();
+    app.install_list_deps.clear();
+    app.install_list_files.clear();
+    app.deps_resolving = false;
+    app.files_resolving = false;
+    app.install_dirty = true;
+    app.last_install_change = Some(std::time::Instant::now());
+    app.install_state
+        .select((!app.install_list.is_empty()).then_some(0));
+    app.install_list.len()
+}
+
+/// Packages of a list partitioned by how they relate to the installed system.
+#[derive(Debug, Clone, Default)]
+pub struct InstalledDiff {
+    /// Not installed; installing is a fresh install.
+    pub to_install: Vec<PackageItem>,
+    /// Installed at an older version than listed, paired with the installed version.
+    pub to_upgrade: Vec<(PackageItem, String)>,
+    /// Installed at the listed (or a newer/unknown) version; installing is redundant.
+    pub already_installed: Vec<PackageItem>,
+    /// Installed packages a removal list would remove.
+    pub to_remove: Vec<PackageItem>,
+    /// Packages a removal list names that are not installed.
+    pub not_installed: Vec<PackageItem>,
+}
+
+/// What: Partition a package list against the installed packages.
+///
+/// Inputs:
+/// - `list`: Packages to check (e.g. a loaded profile or imported list)
+/// - `action`: Whether the list is meant for installing or removing
+///
+/// Output:
+/// - The [`InstalledDiff`] buckets, each in list order.
+///
+/// Details:
+/// - Membership comes from the installed-name cache; installed versions are queried with a single
+///   `pacman -Q` for the installed names only.
+pub fn diff_against_installed(list: &[PackageItem], action: PreflightAction) -> InstalledDiff {
+    let installed: Vec<&str> = list
+        .iter()
+        .map(|p| p.name.as_str())
+        .filter(|n| crate::index::is_installed(n))
+        .collect();
+    let versions = if installed.is_empty() || action == PreflightAction::Remove {
+        std::collections::HashMap::new()
+    } else {
+        installed_versions(&installed)
+    };
+    diff_with(list, action, |name| {
+        installed
+            .contains(&name)
+            .then(|| versions.get(name).cloned().unwrap_or_default())
+    })
+}
+
+/// What: Installed versions of `names` as reported by `pacman -Q`.
+fn installed_versions(names: &[&str]) -> std::collections::HashMap<String, String> {
+    let Ok(out) = std::process::Command::new(crate::install::privilege::pacman_bin())
+        .arg("-Q")
+        .args(names)
+        .env("LC_ALL", "C")
+        .output()
+    else {
+        return std::collections::HashMap::new();
+    };
+    String::from_utf8_lossy(&out.stdout)
+        .lines()
+        .filter_map(|line| {
+            let (name, version) = line.split_once(' ')?;
+            Some((name.to_string(), version.trim().to_string()))
+        })
+        .collect()
+}
+
+/// What: Partition `list` given a lookup of installed versions.
+///
+/// Inputs:
+/// - `list`: Packages to check
+/// - `action`: Install or remove
+/// - `installed`: `None` when a package is not installed, else its version (empty when unknown)
+///
+/// Details:
+/// - A listed version newer than the installed one (common for AUR packages) counts as an
+///   upgrade; an empty listed or installed version counts as already installed.
+fn diff_with(
+    list: &[PackageItem],
+    action: PreflightAction,
+    installed: impl Fn(&str) -> Option<String>,
+) -> InstalledDiff {
+    let mut diff = InstalledDiff::default();
+    for item in list {
+        match (installed(&item.name), action) {
+            (None, PreflightAction::Install) => diff.to_install.push(item.clone()),
+            (None, PreflightAction::Remove) => diff.not_installed.push(item.clone()),
+            (Some(_), PreflightAction::Remove) => diff.to_remove.push(item.clone()),
+            (Some(current), PreflightAction::Install) => {
+                let newer = !item.version.is_empty()
+                    && !current.is_empty()
+                    && crate::logic::preflight::vercmp(&item.version, &current)
+                        == std::cmp::Ordering::Greater;
+                if newer {
+                    diff.to_upgrade.push((item.clone(), current));
+                } else {
+                    diff.already_installed.push(item.clone());
+                }
+            }
+        }
+    }
+    diff
+}
+
+/// What: Record the current lists in the undo history before a change.
+///
+/// Inputs:
+/// - `app`: Application state about to be mutated
+/// - `op`: Kind of change, used to coalesce bursts into one undo step
+///
+/// Details:
+/// - Call right before mutating `install_list`, `remove_list`, or `downgrade_list` on behalf of
+///   the user; the adders in this module already do.
+pub fn record_list_change(app: &mut AppState, op: ListOp) {
+    let before = ListSnapshot::capture(app);
+    app.list_history
+        .record(before, op, std::time::Instant::now());
+}
+
+/// What: Restore the lists as they were before the last recorded change.
+///
+/// Output:
+/// - `true` when a change was undone; `false` when the history is empty.
+pub fn undo_list_change(app: &mut AppState) -> bool {
+    let current = ListSnapshot::capture(app);
+    match app.list_history.undo(current) {
+        Some(prev) => {
+            prev.restore(app);
+            true
+        }
+        None => false,
+    }
+}
+
+/// What: Re-apply the most recently undone list change.
+///
+/// Output:
+/// - `true` when a change was redone; `false` when there is nothing to redo.
+pub fn redo_list_change(app: &mut AppState) -> bool {
+    let current = ListSnapshot::capture(app);
+    match app.list_history.redo(current) {
+        Some(next) => {
+            next.restore(app);
+            true
+        }
+        None => false,
+    }
+}
+
 /// What: Add a `PackageItem` to the remove list if it is not already present.
 ///
 /// Inputs:
@@ -45,6 +390,7 @@ pub fn add_to_remove_list(app: &mut AppState, item: PackageItem) {
     {
         return;
     }
+    record_list_change(app, ListOp::Add);
     app.remove_list.insert(0, item);
     app.remove_state.select(Some(0));
 }
@@ -52,7 +398,7 @@ pub fn add_to_remove_list(app: &mut AppState, item: PackageItem) {
 /// What: Add a `PackageItem` to the downgrade list if it is not already present.
 ///
 /// Inputs:
-/// - `app`: Mutable application state (downgrade_list and selection)
+/// - `app`: Mutable application state (downgrade_list, selection, and picked versions)
 /// - `item`: Package to add
 ///
 /// Output:
@@ -68,6 +414,9 @@ pub fn add_to_downgrade_list(app: &mut AppState, item: PackageItem) {
     {
         return;
     }
+    record_list_change(app, ListOp::Add);
+    // A version picked for an earlier entry of the same package no longer applies
+    app.downgrade_targets.remove(&item.name);
     app.downgrade_list.insert(0, item);
     app.downgrade_state.select(Some(0));
 }
@@ -152,4 +501,187 @@ mod tests {
         assert_eq!(app.downgrade_list.len(), 1);
         assert_eq!(app.downgrade_state.selected(), Some(0));
     }
+
+    #[test]
+    /// What: Ensure adding a group opens the expansion prompt and expanded members can be removed
+    ///   together.
+    ///
+    /// Inputs:
+    /// - A cached group `pacsea-test-grp` with two members, added twice (second time queued), then
+    ///   its members inserted and the group removed.
+    ///
+    /// Output:
+    /// - A `GroupExpand` modal with sorted members and one queued item; a member added before
+    ///   the expansion stays unmarked, and removal drops only the marked members.
+    fn group_add_prompts_and_removes_together() {
+        crate::logic::groups::set_groups(std::collections::HashMap::from([(
+            "pacsea-test-grp".to_string(),
+            vec!["tool-b".to_string(), "tool-a".to_string()],
+        )]));
+        let mut app = AppState {
+            ..Default::default()
+        };
+        add_to_install_list(&mut app, item_official("pacsea-test-grp", ""));
+        add_to_install_list(&mut app, item_official("pacsea-test-grp", ""));
+        match &app.modal {
+            Modal::GroupExpand {
+                members, queued, ..
+            } => {
+                assert_eq!(members, &vec!["tool-a".to_string(), "tool-b".to_string()]);
+                assert_eq!(queued.len(), 1);
+            }
+            other => panic!("expected GroupExpand, got {other:?}"),
+        }
+        assert!(app.install_list.is_empty());
+
+        add_to_install_list_as_is(&mut app, item_official("other", "extra"));
+        add_to_install_list_as_is(&mut app, item_official("tool-a", "extra"));
+        for it in group_member_items(
+            &mut app,
+            "pacsea-test-grp",
+            &["tool-a".into(), "tool-b".into(), "tool-c".into()],
+        ) {
+            add_to_install_list_as_is(&mut app, it);
+        }
+        assert_eq!(app.install_list.len(), 4);
+        assert!(!app.install_group_of.contains_key("tool-a"));
+        assert_eq!(remove_install_group(&mut app, "pacsea-test-grp"), 2);
+        let mut left: Vec<&str> = app.install_list.iter().map(|p| p.name.as_str()).collect();
+        left.sort_unstable();
+        assert_eq!(left, ["other", "tool-a"]);
+        assert!(app.install_group_of.is_empty());
+    }
+
+    #[test]
+    /// What: Ensure clearing all lists empties every list and resets selections.
+    ///
+    /// Inputs:
+    /// - One entry in each of the install, remove, and downgrade lists.
+    ///
+    /// Output:
+    /// - Three entries cleared, all lists empty with no selection, and the install list dirty.
+    fn clear_all_lists_empties_every_list() {
+        let mut app = AppState {
+            ..Default::default()
+        };
+        add_to_install_list(&mut app, item_official("a", "core"));
+        add_to_remove_list(&mut app, item_official("b", "extra"));
+        add_to_downgrade_list(&mut app, item_official("c", "extra"));
+        app.install_dirty = false;
+        assert_eq!(clear_all_lists(&mut app), 3);
+        assert!(app.install_list.is_empty());
+        assert!(app.remove_list.is_empty());
+        assert!(app.downgrade_list.is_empty());
+        assert_eq!(app.install_state.selected(), None);
+        assert_eq!(app.remove_state.selected(), None);
+        assert_eq!(app.downgrade_state.selected(), None);
+        assert!(app.install_dirty);
+    }
+
+    #[test]
+    /// What: Ensure undo after clearing all lists restores every list, and redo clears again.
+    ///
+    /// Inputs:
+    /// - Entries in the install and remove lists, then `clear_all_lists`.
+    ///
+    /// Output:
+    /// - Undo restores both lists with a selection; redo empties them again.
+    fn undo_restores_lists_after_clear() {
+        let mut app = AppState {
+            ..Default::default()
+        };
+        add_to_install_list(&mut app, item_official("a", "core"));
+        add_to_install_list(&mut app, item_official("b", "core"));
+        add_to_remove_list(&mut app, item_official("c", "extra"));
+        clear_all_lists(&mut app);
+        assert!(undo_list_change(&mut app));
+        assert_eq!(app.install_list.len(), 2);
+        assert_eq!(app.remove_list.len(), 1);
+        assert_eq!(app.install_state.selected(), Some(0));
+        assert!(redo_list_change(&mut app));
+        assert!(app.install_list.is_empty() && app.remove_list.is_empty());
+        assert!(!redo_list_change(&mut app));
+    }
+
+    #[test]
+    /// What: Ensure loading entries replaces or appends while keeping saved order.
+    ///
+    /// Inputs:
+    /// - An install list with `x`, then `[a, b]` loaded by replace and `[b, c]` by append.
+    ///
+    /// Output:
+    /// - Replace yields `[a, b]`; append adds only `c` on top; undo restores the replaced list.
+    fn load_into_install_list_replaces_or_appends() {
+        let mut app = AppState {
+            ..Default::default()
+        };
+        add_to_install_list(&mut app, item_official("x", "core"));
+        let loaded = load_into_install_list(
+            &mut app,
+            vec![item_official("a", "core"), item_official("b", "core")],
+            false,
+        );
+        assert_eq!(loaded, 2);
+        let names: Vec<&str> = app.install_list.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "b"]);
+        let loaded = load_into_install_list(
+            &mut app,
+            vec![item_official("b", "core"), item_official("c", "core")],
+            true,
+        );
+        assert_eq!(loaded, 1);
+        let names: Vec<&str> = app.install_list.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["c", "a", "b"]);
+        assert!(undo_list_change(&mut app));
+        assert_eq!(app.install_list.len(), 2);
+        assert!(undo_list_change(&mut app));
+        assert_eq!(app.install_list.len(), 1);
+        assert_eq!(app.install_list[0].name, "x");
+    }
+
+    #[test]
+    /// What: Ensure a list is partitioned into fresh installs, upgrades, redundant entries, and
+    ///   removal buckets.
+    ///
+    /// Inputs:
+    /// - Five packages where `b` is installed older, `c` installed at the same version, `d`
+    ///   installed with an unknown version, and `e` listed with a higher epoch than installed;
+    ///   diffed for install and for removal.
+    ///
+    /// Output:
+    /// - Install: `a` fresh, `b` upgrade from 0.9, `e` upgrade from 2.0-1, `c`/`d` already
+    ///   installed. Remove: `b`/`c`/`d`/`e` to remove, `a` not installed.
+    fn diff_with_partitions_by_installed_state() {
+        let list = vec![
+            item_official("a", "core"),
+            item_official("b", "core"),
+            item_official("c", "core"),
+            item_official("d", "core"),
+            PackageItem {
+                version: "1:1.0-1".into(),
+                ..item_official("e", "core")
+            },
+        ];
+        let lookup = |name: &str| match name {
+            "b" => Some("0.9".to_string()),
+            "e" => Some("2.0-1".to_string()),
+            "c" => Some("1.0".to_string()),
+            "d" => Some(String::new()),
+            _ => None,
+        };
+        let names = |v: &[PackageItem]| v.iter().map(|p| p.name.clone()).collect::<Vec<_>>();
+        let diff = diff_with(&list, PreflightAction::Install, lookup);
+        assert_eq!(names(&diff.to_install), vec!["a"]);
+        let upgrades: Vec<(&str, &str)> = diff
+            .to_upgrade
+            .iter()
+            .map(|(p, current)| (p.name.as_str(), current.as_str()))
+            .collect();
+        assert_eq!(upgrades, vec![("b", "0.9"), ("e", "2.0-1")]);
+        assert_eq!(names(&diff.already_installed), vec!["c", "d"]);
+        let diff = diff_with(&list, PreflightAction::Remove, lookup);
+        assert_eq!(names(&diff.to_remove), vec!["b", "c", "d", "e"]);
+        assert_eq!(names(&diff.not_installed), vec!["a"]);
+        assert!(diff.to_install.is_empty());
+    }
 }
@@ -0,0 +1,151 @@
+//! Tail of Pacsea's own tracing log for the in-app logs viewer.
+
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use tracing::Level;
+
+/// How much of the end of the log file the viewer reads.
+pub const TAIL_BYTES: u64 = 64 * 1024;
+
+/// What: Most recently written Pacsea log in `dir`.
+///
+/// Output:
+/// - The newest `pacsea.log*` file by modification time; `None` when there is none.
+///
+/// Details:
+/// - Matching the prefix picks up rotated files (`pacsea.log.1`, …) if the active file was just
+///   rotated away.
+pub fn latest_log_file(dir: &Path) -> Option<PathBuf> {
+    std::fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .filter(|e| e.file_name().to_string_lossy().starts_with("pacsea.log"))
+        .filter_map(|e| {
+            let meta = e.metadata().ok()?;
+            meta.is_file().then(|| (meta.modified().ok(), e.path()))
+        })
+        .max_by(|a, b| a.0.cmp(&b.0).then_with(|| b.1.cmp(&a.1)))
+        .map(|(_, path)| path)
+}
+
+/// What: Read the last lines of a file.
+///
+/// Inputs:
+/// - `path`: File to read.
+/// - `max_bytes`: How many bytes from the end to read at most.
+///
+/// Output:
+/// - Complete lines within the last `max_bytes`, oldest first, or the I/O error.
+///
+/// Details:
+/// - When the read starts mid-file the first, partial line is dropped. Invalid UTF-8 is replaced.
+pub fn tail_lines(path: &Path, max_bytes: u64) -> std::io::Result<Vec<String>> {
+    let mut file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+    let start = len.saturating_sub(max_bytes);
+    file.seek(SeekFrom::Start(start))?;
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf)?;
+    let text = String::from_utf8_lossy(&buf);
+    let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+    if start > 0 && !lines.is_empty() {
+        lines.remove(0);
+    }
+    Ok(lines)
+}
+
+/// What: Level of a formatted tracing line (`<time>  INFO message`).
+///
+/// Output:
+/// - The first level word within the first few tokens; `None` for continuation lines.
+pub fn line_level(line: &str) -> Option<Level> {
+    line.split_whitespace().take(3).find_map(|tok| match tok {
+        "ERROR" => Some(Level::ERROR),
+        "WARN" => Some(Level::WARN),
+        "INFO" => Some(Level::INFO),
+        "DEBUG" => Some(Level::DEBUG),
+        "TRACE" => Some(Level::TRACE),
+        _ => None,
+    })
+}
+
+/// What: Lines at or above a severity.
+///
+/// Inputs:
+/// - `lines`: Log lines, oldest first.
+/// - `min`: Least severe level kept (`INFO` keeps info, warn, error); `None` keeps all.
+///
+/// Output:
+/// - Matching lines; lines without a level (wrapped output, backtraces) follow the line before.
+pub fn filter_by_level(lines: &[String], min: Option<Level>) -> Vec<&str> {
+    let Some(min) = min else {
+        return lines.iter().map(String::as_str).collect();
+    };
+    let mut keep = false;
+    lines
+        .iter()
+        .filter(|line| {
+            if let Some(level) = line_level(line) {
+                // tracing orders levels by verbosity: ERROR < WARN < INFO < DEBUG < TRACE
+                keep = level <= min;
+            }
+            keep
+        })
+        .map(String::as_str)
+        .collect()
+}
+
+/// What: Next level filter in the viewer's cycle: all → info → warn → error → all.
+pub fn next_level_filter(current: Option<Level>) -> Option<Level> {
+    match current {
+        None => Some(Level::INFO),
+        Some(Level::INFO) => Some(Level::WARN),
+        Some(Level::WARN) => Some(Level::ERROR),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure the tail drops the partial first line and the level filter keeps continuations.
+    ///
+    /// Inputs:
+    /// - A log with info, warn (plus a continuation line), and error entries, read in full and
+    ///   with a small byte limit.
+    ///
+    /// Output:
+    /// - The full read returns every line, the short read only whole trailing lines, and the
+    ///   warn filter keeps warn, its continuation, and error.
+    fn tail_and_filter_log_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pacsea.log");
+        let text = "2026-01-01 10:00:00  INFO started\n\
+                    2026-01-01 10:00:01  WARN slow mirror\n\
+                    \x20 caused by: timeout\n\
+                    2026-01-01 10:00:02 ERROR search failed\n";
+        std::fs::write(&path, text).unwrap();
+        std::fs::write(dir.path().join("install_log.log"), "x").unwrap();
+        assert_eq!(latest_log_file(dir.path()), Some(path.clone()));
+
+        let all = tail_lines(&path, TAIL_BYTES).unwrap();
+        assert_eq!(all.len(), 4);
+        let short = tail_lines(&path, 45).unwrap();
+        assert_eq!(short, vec!["2026-01-01 10:00:02 ERROR search failed"]);
+
+        assert_eq!(filter_by_level(&all, None).len(), 4);
+        assert_eq!(
+            filter_by_level(&all, Some(Level::WARN)),
+            vec![
+                "2026-01-01 10:00:01  WARN slow mirror",
+                "  caused by: timeout",
+                "2026-01-01 10:00:02 ERROR search failed",
+            ]
+        );
+        assert_eq!(filter_by_level(&all, Some(Level::ERROR)).len(), 1);
+        assert_eq!(next_level_filter(Some(Level::ERROR)), None);
+    }
+}
@@ -1,29 +1,46 @@
 //! Core non-UI logic split into modular submodules.
 
+pub mod activity;
+pub mod compare;
+pub mod custom_scan;
 pub mod deps;
 pub mod distro;
+pub mod downgrade;
+pub mod errors;
+pub mod filedb;
 pub mod files;
 pub mod filter;
 pub mod gating;
+pub mod groups;
+pub mod history;
+pub mod layout;
 pub mod lists;
+pub mod logs;
+pub mod package_list;
+pub mod pacman_log;
+pub mod pacnew;
 pub mod prefetch;
 pub mod preflight;
+pub mod profiles;
 pub mod query;
 pub mod sandbox;
 pub mod selection;
 pub mod services;
 pub mod sort;
+pub mod stage_trace;
 pub mod summary;
+pub mod textdiff;
+pub mod upgrades;
 
 // Re-export public APIs to preserve existing import paths (crate::logic::...)
 pub use filter::apply_filters_and_sort_preserve_selection;
 pub use gating::{is_allowed, set_allowed_only_selected, set_allowed_ring};
 pub use lists::{add_to_downgrade_list, add_to_install_list, add_to_remove_list};
 pub use prefetch::ring_prefetch_from_selected;
-pub use query::send_query;
+pub use query::{flush_query, send_query};
 pub use selection::move_sel_cached;
 pub use services::resolve_service_impacts;
-pub use sort::sort_results_preserve_selection;
+pub use sort::{sort_by_header, sort_results_preserve_selection};
 
 #[cfg(test)]
 static TEST_MUTEX: std::sync::OnceLock<std::sync::Mutex<()>> = std::sync::OnceLock::new();
@@ -0,0 +1,243 @@
+//! Import/export of install lists as plain `packages.txt` files or JSON.
+
+use std::path::Path;
+use std::sync::Mutex;
+
+use crate::state::{PackageItem, Source};
+
+/// On-disk format of an exported/imported package list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListFormat {
+    /// One package name per line; `#` starts a comment.
+    Plain,
+    /// JSON array of names, or of objects with a `name` field (e.g. Pacsea's own install list).
+    Json,
+}
+
+impl ListFormat {
+    /// What: Pick the format from a file extension (`.json` is JSON, anything else plain).
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => ListFormat::Json,
+            _ => ListFormat::Plain,
+        }
+    }
+}
+
+/// What: Extract package names from a list file.
+///
+/// Inputs:
+/// - `body`: File contents.
+/// - `format`: Format detected from the file name.
+///
+/// Output:
+/// - Names in file order with case-insensitive duplicates removed.
+///
+/// Details:
+/// - Plain: blank lines and `#` comments (whole-line or trailing) are ignored, and only the
+///   first word of a line is used, so `pacman -Q` output (`name version`) imports as-is.
+/// - JSON: accepts `["a", "b"]` or `[{"name": "a", ...}]`; invalid JSON yields no names.
+pub fn parse_package_list(body: &str, format: ListFormat) -> Vec<String> {
+    let raw: Vec<String> = match format {
+        ListFormat::Plain => body
+            .lines()
+            .filter_map(|line| {
+                let line = line.split('#').next().unwrap_or_default();
+                line.split_whitespace().next().map(str::to_string)
+            })
+            .collect(),
+        ListFormat::Json => serde_json::from_str::<serde_json::Value>(body)
+            .ok()
+            .and_then(|v| v.as_array().cloned())
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|entry| {
+                entry
+                    .as_str()
+                    .or_else(|| entry.get("name").and_then(|n| n.as_str()))
+                    .map(|n| n.trim().to_string())
+            })
+            .filter(|n| !n.is_empty())
+            .collect(),
+    };
+    let mut seen = std::collections::HashSet::new();
+    raw.into_iter()
+        .filter(|n| seen.insert(n.to_lowercase()))
+        .collect()
+}
+
+/// What: Render package names in the given list format.
+///
+/// Output:
+/// - Plain: a `#` header line followed by one name per line; JSON: a pretty-printed array.
+pub fn format_package_list(names: &[String], format: ListFormat) -> String {
+    match format {
+        ListFormat::Plain => {
+            let mut body = String::from("# Pacsea install list (one package per line)\n");
+            for n in names {
+                body.push_str(n);
+                body.push('\n');
+            }
+            body
+        }
+        ListFormat::Json => serde_json::to_string_pretty(names).unwrap_or_else(|_| "[]".into()),
+    }
+}
+
+/// What: Resolve imported names to install-list entries.
+///
+/// Inputs:
+/// - `names`: Names parsed from the list file.
+///
+/// Output:
+/// - `(resolved, unresolved)`: items in input order with repo/version/description filled in,
+///   and the names found neither in the official index nor the AUR.
+///
+/// Details:
+/// - Blocking: names missing from the official index are looked up with one batched AUR RPC
+///   request. If that request fails, those names are reported as unresolved.
+pub fn resolve_imported_names(names: &[String]) -> (Vec<PackageItem>, Vec<String>) {
+    let official: std::collections::HashMap<String, PackageItem> = crate::index::all_official()
+        .into_iter()
+        .map(|it| (it.name.to_lowercase(), it))
+        .collect();
+    let missing: Vec<String> = names
+        .iter()
+        .filter(|n| !official.contains_key(&n.to_lowercase()))
+        .cloned()
+        .collect();
+    let aur = if missing.is_empty() {
+        Vec::new()
+    } else {
+        crate::sources::fetch_aur_items_blocking(&missing).unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "import: AUR lookup failed");
+            Vec::new()
+        })
+    };
+    resolve_with(names, &official, aur)
+}
+
+/// What: Match names against official entries first, then AUR results.
+fn resolve_with(
+    names: &[String],
+    official: &std::collections::HashMap<String, PackageItem>,
+    aur: Vec<PackageItem>,
+) -> (Vec<PackageItem>, Vec<String>) {
+    let aur: std::collections::HashMap<String, PackageItem> = aur
+        .into_iter()
+        .filter(|it| matches!(it.source, Source::Aur))
+        .map(|it| (it.name.to_lowercase(), it))
+        .collect();
+    let mut resolved = Vec::new();
+    let mut unresolved = Vec::new();
+    for name in names {
+        let key = name.to_lowercase();
+        match official.get(&key).or_else(|| aur.get(&key)) {
+            Some(item) => resolved.push(item.clone()),
+            None => unresolved.push(name.clone()),
+        }
+    }
+    (resolved, unresolved)
+}
+
+/// Outcome of the last background import, waiting to be previewed by the UI.
+#[derive(Debug, Clone)]
+pub struct ImportSummary {
+    /// File name of the imported list, used as the preview title.
+    pub source: String,
+    /// Resolved packages, in list order.
+    pub items: Vec<crate::state::PackageItem>,
+    /// `items` partitioned against the installed packages.
+    pub diff: crate::logic::lists::InstalledDiff,
+    /// Names that could not be resolved and were skipped.
+    pub skipped: Vec<String>,
+}
+
+/// Summary posted by the import thread and picked up by the UI tick.
+static LAST_IMPORT: Mutex<Option<ImportSummary>> = Mutex::new(None);
+
+/// What: Record the outcome of a finished import for the UI to report.
+pub fn post_import_summary(summary: ImportSummary) {
+    if let Ok(mut g) = LAST_IMPORT.lock() {
+        *g = Some(summary);
+    }
+}
+
+/// What: Take the pending import summary, if any.
+pub fn take_import_summary() -> Option<ImportSummary> {
+    LAST_IMPORT.lock().ok().and_then(|mut g| g.take())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure plain and JSON lists parse, round-trip, and resolve with unknown names skipped.
+    ///
+    /// Inputs:
+    /// - A `packages.txt` with comments, blank lines, a `pacman -Q` style line, and a duplicate;
+    ///   a JSON list of objects; resolution against one official and one AUR entry.
+    ///
+    /// Output:
+    /// - Names in order without comments/duplicates, format chosen by extension, and the unknown
+    ///   name reported as unresolved.
+    fn parse_format_and_resolve_lists() {
+        let txt = "# my packages\nripgrep\n\n  fd 9.0.0-1  # finder\nRipgrep\nyay-bin\nnope\n";
+        let names = parse_package_list(txt, ListFormat::Plain);
+        assert_eq!(names, vec!["ripgrep", "fd", "yay-bin", "nope"]);
+        assert_eq!(
+            parse_package_list(
+                &format_package_list(&names, ListFormat::Plain),
+                ListFormat::Plain
+            ),
+            names
+        );
+        assert_eq!(
+            parse_package_list(r#"[{"name":"fd","version":"1"},"bat"]"#, ListFormat::Json),
+            vec!["fd", "bat"]
+        );
+        assert_eq!(
+            parse_package_list(
+                &format_package_list(&names, ListFormat::Json),
+                ListFormat::Json
+            ),
+            names
+        );
+        assert!(parse_package_list("not json", ListFormat::Json).is_empty());
+        assert_eq!(
+            ListFormat::from_path(Path::new("/tmp/list.JSON")),
+            ListFormat::Json
+        );
+        assert_eq!(
+            ListFormat::from_path(Path::new("packages.txt")),
+            ListFormat::Plain
+        );
+
+        let item = |name: &str, source: Source| PackageItem {
+            name: name.into(),
+            version: "1".into(),
+            description: String::new(),
+            source,
+            popularity: None,
+        };
+        let official = std::collections::HashMap::from([(
+            "ripgrep".to_string(),
+            item(
+                "ripgrep",
+                Source::Official {
+                    repo: "extra".into(),
+                    arch: "x86_64".into(),
+                },
+            ),
+        )]);
+        let (resolved, unresolved) = resolve_with(
+            &["ripgrep".into(), "yay-bin".into(), "nope".into()],
+            &official,
+            vec![item("yay-bin", Source::Aur)],
+        );
+        let resolved: Vec<&str> = resolved.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(resolved, vec!["ripgrep", "yay-bin"]);
+        assert_eq!(unresolved, vec!["nope"]);
+    }
+}
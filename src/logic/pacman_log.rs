@@ -0,0 +1,433 @@
+//! Per-package install history parsed from `/var/log/pacman.log` and its rotated copies.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Live pacman log.
+const PACMAN_LOG: &str = "/var/log/pacman.log";
+
+/// Minimum time between checks of the live log for new lines.
+const RECHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Kind of ALPM transaction step recorded in the log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryAction {
+    /// `installed <name> (<version>)`
+    Installed,
+    /// `upgraded <name> (<old> -> <new>)`
+    Upgraded,
+    /// `downgraded <name> (<old> -> <new>)`
+    Downgraded,
+    /// `reinstalled <name> (<version>)`
+    Reinstalled,
+    /// `removed <name> (<version>)`
+    Removed,
+}
+
+/// One logged transaction step for a package.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryEvent {
+    /// Local time as written by pacman, normalized to `YYYY-MM-DD HH:MM`.
+    pub at: String,
+    /// What happened.
+    pub action: HistoryAction,
+    /// Version text from the log (`1.0-1` or `1.0-1 -> 1.1-1`).
+    pub version: String,
+}
+
+/// Condensed history shown in the details pane.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HistorySummary {
+    /// Time of the earliest install still covered by the logs.
+    pub first_installed: Option<String>,
+    /// Time of the most recent upgrade.
+    pub last_upgraded: Option<String>,
+    /// Number of upgrades logged.
+    pub upgrades: usize,
+}
+
+impl HistorySummary {
+    /// What: Fold one event into the summary; events must arrive oldest first.
+    fn apply(&mut self, event: HistoryEvent) {
+        match event.action {
+            HistoryAction::Installed => {
+                self.first_installed.get_or_insert(event.at);
+            }
+            HistoryAction::Upgraded => {
+                self.last_upgraded = Some(event.at);
+                self.upgrades += 1;
+            }
+            HistoryAction::Downgraded | HistoryAction::Reinstalled | HistoryAction::Removed => {}
+        }
+    }
+}
+
+/// Summaries of every package seen in the logs, plus how far the live log was read.
+struct LogIndex {
+    /// Summary per package name.
+    summaries: HashMap<String, HistorySummary>,
+    /// Bytes of the live log already folded in (up to the last complete line).
+    live_offset: u64,
+}
+
+/// Index shared with the UI; `None` until the first scan finishes.
+static INDEX: Mutex<Option<LogIndex>> = Mutex::new(None);
+
+/// Set while a background scan runs.
+static SCANNING: AtomicBool = AtomicBool::new(false);
+
+/// Last time the live log was checked for growth.
+static LAST_CHECK: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// What: Parse one pacman log line into a package event.
+///
+/// Inputs:
+/// - `line`: Log line, e.g. `[2024-01-02T10:11:12+0100] [ALPM] upgraded foo (1.0-1 -> 1.1-1)`.
+///
+/// Output:
+/// - `(package, event)` for ALPM install/upgrade/downgrade/reinstall/remove lines, else `None`.
+///
+/// Details:
+/// - Accepts both the ISO timestamps of current pacman and the older `[YYYY-MM-DD HH:MM]` form.
+pub fn parse_line(line: &str) -> Option<(&str, HistoryEvent)> {
+    let (ts, rest) = line.strip_prefix('[')?.split_once("] ")?;
+    let rest = rest.strip_prefix("[ALPM] ")?;
+    let (verb, rest) = rest.split_once(' ')?;
+    let action = match verb {
+        "installed" => HistoryAction::Installed,
+        "upgraded" => HistoryAction::Upgraded,
+        "downgraded" => HistoryAction::Downgraded,
+        "reinstalled" => HistoryAction::Reinstalled,
+        "removed" => HistoryAction::Removed,
+        _ => return None,
+    };
+    let (name, version) = rest.split_once(" (")?;
+    let version = version.trim_end().strip_suffix(')')?;
+    let at: String = ts.replacen('T', " ", 1).chars().take(16).collect();
+    Some((
+        name,
+        HistoryEvent {
+            at,
+            action,
+            version: version.to_string(),
+        },
+    ))
+}
+
+/// What: Feed every parsable line of a log to `on_event`.
+///
+/// Inputs:
+/// - `reader`: Log contents.
+/// - `needle`: When set, lines not containing it are skipped before parsing.
+/// - `on_event`: Called with each package event, oldest first.
+///
+/// Output:
+/// - Bytes consumed up to and including the last complete line.
+///
+/// Details:
+/// - Reads raw bytes so scriptlet output with invalid UTF-8 does not stop the scan.
+fn scan<R: Read>(
+    reader: R,
+    needle: Option<&str>,
+    mut on_event: impl FnMut(&str, HistoryEvent),
+) -> u64 {
+    let mut reader = BufReader::with_capacity(64 * 1024, reader);
+    let mut buf = Vec::new();
+    let mut consumed = 0u64;
+    loop {
+        buf.clear();
+        match reader.read_until(b'\n', &mut buf) {
+            Ok(0) | Err(_) => break,
+            Ok(n) => {
+                if buf.last() != Some(&b'\n') {
+                    break;
+                }
+                consumed += n as u64;
+            }
+        }
+        let Ok(line) = std::str::from_utf8(&buf) else {
+            continue;
+        };
+        if needle.is_some_and(|n| !line.contains(n)) {
+            continue;
+        }
+        if let Some((name, event)) = parse_line(line.trim_end()) {
+            on_event(name, event);
+        }
+    }
+    consumed
+}
+
+/// What: Open a log file, transparently decompressing `.gz` copies.
+fn open_log(path: &Path) -> Option<Box<dyn Read>> {
+    let file = File::open(path).ok()?;
+    if path.extension().and_then(|e| e.to_str()) == Some("gz") {
+        Some(Box::new(flate2::read::MultiGzDecoder::new(file)))
+    } else {
+        Some(Box::new(file))
+    }
+}
+
+/// What: Rotated copies of a log next to it, oldest first.
+///
+/// Inputs:
+/// - `live`: Path of the live log.
+///
+/// Output:
+/// - Siblings named `<log>.N`, `<log>-DATE`, optionally `.gz`, ordered by modification time.
+fn rotated_logs(live: &Path) -> Vec<PathBuf> {
+    let (Some(dir), Some(base)) = (live.parent(), live.file_name().and_then(|n| n.to_str())) else {
+        return Vec::new();
+    };
+    let mut found: Vec<(std::time::SystemTime, PathBuf)> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| {
+            entry.file_name().to_str().is_some_and(|n| {
+                n.len() > base.len()
+                    && n.starts_with(base)
+                    && matches!(n.as_bytes()[base.len()], b'.' | b'-')
+            })
+        })
+        .filter_map(|entry| {
+            let modified = entry.metadata().ok()?.modified().ok()?;
+            Some((modified, entry.path()))
+        })
+        .collect();
+    found.sort();
+    found.into_iter().map(|(_, path)| path).collect()
+}
+
+/// What: Every logged event of one package across a log and its rotated copies.
+fn history_in(live: &Path, name: &str) -> Vec<HistoryEvent> {
+    let mut events = Vec::new();
+    for path in rotated_logs(live)
+        .iter()
+        .map(PathBuf::as_path)
+        .chain([live])
+    {
+        if let Some(reader) = open_log(path) {
+            scan(reader, Some(name), |pkg, event| {
+                if pkg == name {
+                    events.push(event);
+                }
+            });
+        }
+    }
+    events
+}
+
+/// What: Timestamped install/upgrade/downgrade/reinstall/remove events of a package.
+///
+/// Inputs:
+/// - `name`: Package name.
+///
+/// Output:
+/// - Events oldest first, read from `/var/log/pacman.log` and its rotated (optionally
+///   gzip-compressed) copies; empty when the logs are missing or unreadable.
+pub fn package_history(name: &str) -> Vec<HistoryEvent> {
+    history_in(Path::new(PACMAN_LOG), name)
+}
+
+/// What: Summarize every package across a log and its rotated copies.
+fn build_index(live: &Path) -> LogIndex {
+    let mut summaries: HashMap<String, HistorySummary> = HashMap::new();
+    let mut fold = |pkg: &str, event: HistoryEvent| {
+        summaries.entry(pkg.to_string()).or_default().apply(event);
+    };
+    for path in rotated_logs(live) {
+        if let Some(reader) = open_log(&path) {
+            scan(reader, None, &mut fold);
+        }
+    }
+    let live_offset = File::open(live)
+        .map(|file| scan(file, None, &mut fold))
+        .unwrap_or(0);
+    LogIndex {
+        summaries,
+        live_offset,
+    }
+}
+
+/// What: Read events appended to the live log past `offset`.
+///
+/// Output:
+/// - The new events and the bytes they span, or `None` when the log shrank (rotated or
+///   truncated) and needs a full rebuild.
+fn read_appended(live: &Path, offset: u64) -> Option<(Vec<(String, HistoryEvent)>, u64)> {
+    let mut events = Vec::new();
+    let Ok(mut file) = File::open(live) else {
+        return Some((events, 0));
+    };
+    let len = file.metadata().map(|m| m.len()).unwrap_or(0);
+    if len < offset {
+        return None;
+    }
+    if len == offset || file.seek(SeekFrom::Start(offset)).is_err() {
+        return Some((events, 0));
+    }
+    let consumed = scan(file, None, |pkg, event| {
+        events.push((pkg.to_string(), event))
+    });
+    Some((events, consumed))
+}
+
+/// What: Start a background scan when the index is missing or the live log may have grown.
+///
+/// Details:
+/// - Checks at most every [`RECHECK_INTERVAL`]; later scans only read the appended tail
+///   unless the log was rotated.
+fn refresh_index() {
+    if let Ok(mut last) = LAST_CHECK.lock() {
+        if last.is_some_and(|t| t.elapsed() < RECHECK_INTERVAL) {
+            return;
+        }
+        *last = Some(Instant::now());
+    }
+    if SCANNING.swap(true, Ordering::AcqRel) {
+        return;
+    }
+    std::thread::spawn(|| {
+        let live = Path::new(PACMAN_LOG);
+        // Scan outside the lock so the details pane keeps showing the previous index
+        let offset = INDEX
+            .lock()
+            .ok()
+            .and_then(|guard| guard.as_ref().map(|index| index.live_offset));
+        match offset.and_then(|offset| read_appended(live, offset)) {
+            Some((events, consumed)) => {
+                if let Ok(mut guard) = INDEX.lock()
+                    && let Some(index) = guard.as_mut()
+                {
+                    for (pkg, event) in events {
+                        index.summaries.entry(pkg).or_default().apply(event);
+                    }
+                    index.live_offset += consumed;
+                }
+            }
+            None => {
+                let index = build_index(live);
+                if let Ok(mut guard) = INDEX.lock() {
+                    *guard = Some(index);
+                }
+            }
+        }
+        SCANNING.store(false, Ordering::Release);
+    });
+}
+
+/// What: Cached history summary of a package for the details pane.
+///
+/// Inputs:
+/// - `name`: Package name.
+///
+/// Output:
+/// - The summary once the background scan has indexed the logs; `None` while scanning or when
+///   the package never appears in them.
+pub fn history_summary(name: &str) -> Option<HistorySummary> {
+    refresh_index();
+    INDEX.lock().ok()?.as_ref()?.summaries.get(name).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    /// What: Verify ALPM lines in both timestamp formats parse and other lines are ignored.
+    ///
+    /// Inputs:
+    /// - Current and legacy install/upgrade lines, a transaction marker, and a hook message.
+    ///
+    /// Output:
+    /// - Package events with normalized times; `None` for non-package lines.
+    fn pacman_log_parse_line_formats() {
+        let (name, ev) =
+            parse_line("[2024-01-02T10:11:12+0100] [ALPM] upgraded foo (1.0-1 -> 1.1-1)").unwrap();
+        assert_eq!(name, "foo");
+        assert_eq!(ev.at, "2024-01-02 10:11");
+        assert_eq!(ev.action, HistoryAction::Upgraded);
+        assert_eq!(ev.version, "1.0-1 -> 1.1-1");
+        let (name, ev) = parse_line("[2019-03-04 05:06] [ALPM] installed lib32-bar (2)").unwrap();
+        assert_eq!((name, ev.at.as_str()), ("lib32-bar", "2019-03-04 05:06"));
+        assert_eq!(ev.action, HistoryAction::Installed);
+        assert!(parse_line("[2024-01-02T10:11:12+0100] [ALPM] transaction started").is_none());
+        assert!(
+            parse_line("[2024-01-02T10:11:12+0100] [PACMAN] Running 'pacman -S foo'").is_none()
+        );
+        assert!(
+            parse_line("[2024-01-02T10:11:12+0100] [ALPM-SCRIPTLET] installed x (1)").is_none()
+        );
+    }
+
+    #[test]
+    /// What: Verify history spans gzip-rotated and live logs in order and appended lines fold in.
+    ///
+    /// Inputs:
+    /// - A compressed rotated log with the install, a live log with an upgrade, then an
+    ///   appended second upgrade.
+    ///
+    /// Output:
+    /// - Events oldest first; the summary keeps the first install and tracks the latest upgrade.
+    fn pacman_log_history_across_rotated_logs() {
+        let dir = tempfile::tempdir().unwrap();
+        let live = dir.path().join("pacman.log");
+        let rotated = dir.path().join("pacman.log.1.gz");
+        let mut gz = flate2::write::GzEncoder::new(
+            File::create(&rotated).unwrap(),
+            flate2::Compression::default(),
+        );
+        gz.write_all(b"[2023-05-01T09:00:00+0000] [ALPM] installed foo (1.0-1)\n")
+            .unwrap();
+        gz.finish().unwrap();
+        let old = std::time::SystemTime::now() - Duration::from_secs(3600);
+        File::options()
+            .write(true)
+            .open(&rotated)
+            .unwrap()
+            .set_modified(old)
+            .unwrap();
+        std::fs::write(
+            &live,
+            "[2024-01-02T10:11:12+0000] [ALPM] upgraded foo (1.0-1 -> 1.1-1)\n\
+             \u{0}garbage line\n\
+             [2024-01-02T10:11:13+0000] [ALPM] installed foobar (3-1)\n",
+        )
+        .unwrap();
+
+        let events = history_in(&live, "foo");
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].action, HistoryAction::Installed);
+        assert_eq!(events[1].action, HistoryAction::Upgraded);
+
+        let mut index = build_index(&live);
+        let foo = index.summaries.get("foo").cloned().unwrap();
+        assert_eq!(foo.first_installed.as_deref(), Some("2023-05-01 09:00"));
+        assert_eq!(foo.upgrades, 1);
+
+        let mut f = File::options().append(true).open(&live).unwrap();
+        f.write_all(b"[2024-02-03T08:00:00+0000] [ALPM] upgraded foo (1.1-1 -> 1.2-1)\n[2024-02")
+            .unwrap();
+        let (events, consumed) = read_appended(&live, index.live_offset).unwrap();
+        for (pkg, event) in events {
+            index.summaries.entry(pkg).or_default().apply(event);
+        }
+        index.live_offset += consumed;
+        let foo = index.summaries.get("foo").cloned().unwrap();
+        assert_eq!(foo.last_upgraded.as_deref(), Some("2024-02-03 08:00"));
+        assert_eq!(foo.upgrades, 2);
+        assert_eq!(
+            index.live_offset,
+            std::fs::metadata(&live).unwrap().len() - "[2024-02".len() as u64
+        );
+        // A log shorter than the recorded offset was rotated and needs a rebuild
+        assert!(read_appended(&live, index.live_offset + 1024).is_none());
+    }
+}
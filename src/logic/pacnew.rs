@@ -0,0 +1,231 @@
+//! Leftover `.pacnew`/`.pacsave` files found on disk after upgrades and removals.
+
+use std::path::{Path, PathBuf};
+
+/// Directories scanned for leftovers; pacman only writes them next to backed-up config files.
+pub const SCAN_ROOTS: [&str; 3] = ["/etc", "/boot", "/opt"];
+
+/// Which kind of leftover pacman wrote.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeftoverKind {
+    /// New packaged config kept aside because the installed one was modified.
+    Pacnew,
+    /// Modified config kept after its package was removed or stopped shipping it.
+    Pacsave,
+}
+
+impl LeftoverKind {
+    /// What: File suffix pacman uses for this kind, including the dot.
+    pub fn suffix(self) -> &'static str {
+        match self {
+            LeftoverKind::Pacnew => ".pacnew",
+            LeftoverKind::Pacsave => ".pacsave",
+        }
+    }
+}
+
+/// A `.pacnew` or `.pacsave` file and the config it belongs to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigLeftover {
+    /// Path of the leftover itself.
+    pub path: PathBuf,
+    /// Live config the leftover shadows (the path without the suffix; may not exist).
+    pub original: PathBuf,
+    /// Whether this is a `.pacnew` or a `.pacsave`.
+    pub kind: LeftoverKind,
+}
+
+/// What: Classify a path as a leftover.
+///
+/// Output:
+/// - The leftover with its original path; `None` for other files.
+///
+/// Details:
+/// - Numbered `.pacsave.N` files (written when an older `.pacsave` already exists) count too.
+fn classify(path: &Path) -> Option<ConfigLeftover> {
+    let name = path.file_name()?.to_str()?;
+    let trimmed = name.trim_end_matches(|c: char| c.is_ascii_digit());
+    let stem_end = [LeftoverKind::Pacnew, LeftoverKind::Pacsave]
+        .into_iter()
+        .find_map(|kind| {
+            let base = if kind == LeftoverKind::Pacsave {
+                trimmed.strip_suffix('.').unwrap_or(trimmed)
+            } else {
+                name
+            };
+            base.strip_suffix(kind.suffix())
+                .filter(|stem| !stem.is_empty())
+                .map(|stem| (stem.len(), kind))
+        });
+    let (len, kind) = stem_end?;
+    Some(ConfigLeftover {
+        path: path.to_path_buf(),
+        original: path.with_file_name(&name[..len]),
+        kind,
+    })
+}
+
+/// What: Find leftovers below the given roots.
+///
+/// Inputs:
+/// - `roots`: Directories to walk (usually [`SCAN_ROOTS`]).
+///
+/// Output:
+/// - Leftovers sorted by path.
+///
+/// Details:
+/// - Runs unprivileged: directories that cannot be read are skipped, and symlinks are not
+///   followed so the walk stays inside the roots.
+pub fn scan_leftovers(roots: &[&Path]) -> Vec<ConfigLeftover> {
+    let mut found = Vec::new();
+    let mut stack: Vec<PathBuf> = roots.iter().map(|r| r.to_path_buf()).collect();
+    while let Some(dir) = stack.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            let path = entry.path();
+            if file_type.is_dir() {
+                stack.push(path);
+            } else if file_type.is_file()
+                && let Some(leftover) = classify(&path)
+            {
+                found.push(leftover);
+            }
+        }
+    }
+    found.sort_by(|a, b| a.path.cmp(&b.path));
+    found
+}
+
+/// What: Find leftovers in the default locations.
+///
+/// Inputs:
+/// - None; walks [`SCAN_ROOTS`].
+///
+/// Output:
+/// - Leftovers sorted by path, as returned by [`scan_leftovers`].
+///
+/// Details:
+/// - Blocks for the whole walk, which can take seconds on a cold cache; the UI runs it in
+///   `spawn_blocking` and fills the dialog when it finishes.
+pub fn scan_system_leftovers() -> Vec<ConfigLeftover> {
+    let roots: Vec<&Path> = SCAN_ROOTS.iter().map(Path::new).collect();
+    scan_leftovers(&roots)
+}
+
+/// What: Shell command merging a leftover into its config in a terminal editor.
+///
+/// Output:
+/// - `<escalation> <tool> -d '<original>' '<leftover>'` using the first of nvim/vim found, falling
+///   back to a paged `diff -u` when neither is installed.
+///
+/// Details:
+/// - Uses the configured `privilege_escalation` tool since both files are usually root-owned.
+pub fn merge_command(leftover: &ConfigLeftover) -> String {
+    let esc = crate::install::privilege::privilege_tool().binary();
+    let quote = |p: &Path| crate::install::shell_single_quote(&p.display().to_string());
+    let (orig, new) = (quote(&leftover.original), quote(&leftover.path));
+    format!(
+        "if command -v nvim >/dev/null 2>&1; then {esc} nvim -d {orig} {new}; \
+         elif command -v vim >/dev/null 2>&1; then {esc} vim -d {orig} {new}; \
+         else diff -u {orig} {new} | less; fi"
+    )
+}
+
+/// What: Shell command deleting a leftover after confirming in the terminal.
+///
+/// Inputs:
+/// - `leftover`: Leftover to delete.
+///
+/// Output:
+/// - `<escalation> rm -i -- '<leftover>'`.
+///
+/// Details:
+/// - Uses the configured `privilege_escalation` tool since leftovers are usually root-owned;
+///   `rm -i` asks before deleting, so a stray keypress in the dialog removes nothing.
+pub fn remove_command(leftover: &ConfigLeftover) -> String {
+    format!(
+        "{} rm -i -- {}",
+        crate::install::privilege::privilege_tool().binary(),
+        crate::install::shell_single_quote(&leftover.path.display().to_string())
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure the scan finds pacnew/pacsave files at any depth and maps them to their configs.
+    ///
+    /// Inputs:
+    /// - A tree with `a.conf.pacnew`, `sub/b.conf.pacsave`, `sub/c.pacsave.1`, an unrelated file,
+    ///   and a bare `.pacnew`.
+    ///
+    /// Output:
+    /// - Three leftovers sorted by path with the suffix stripped for `original`; the merge and
+    ///   remove commands quote the paths and escalate.
+    fn scan_finds_leftovers_recursively() {
+        let dir = tempfile::tempdir().unwrap();
+        let sub = dir.path().join("sub");
+        std::fs::create_dir_all(&sub).unwrap();
+        for path in [
+            dir.path().join("a.conf.pacnew"),
+            dir.path().join("a.conf"),
+            dir.path().join(".pacnew"),
+            sub.join("b.conf.pacsave"),
+            sub.join("c.pacsave.1"),
+        ] {
+            std::fs::write(path, "x").unwrap();
+        }
+        let found = scan_leftovers(&[dir.path()]);
+        let rel: Vec<(String, String, LeftoverKind)> = found
+            .iter()
+            .map(|l| {
+                (
+                    l.path
+                        .strip_prefix(dir.path())
+                        .unwrap()
+                        .display()
+                        .to_string(),
+                    l.original
+                        .strip_prefix(dir.path())
+                        .unwrap()
+                        .display()
+                        .to_string(),
+                    l.kind,
+                )
+            })
+            .collect();
+        assert_eq!(
+            rel,
+            vec![
+                (
+                    "a.conf.pacnew".to_string(),
+                    "a.conf".to_string(),
+                    LeftoverKind::Pacnew
+                ),
+                (
+                    "sub/b.conf.pacsave".to_string(),
+                    "sub/b.conf".to_string(),
+                    LeftoverKind::Pacsave
+                ),
+                (
+                    "sub/c.pacsave.1".to_string(),
+                    "sub/c".to_string(),
+                    LeftoverKind::Pacsave
+                ),
+            ]
+        );
+        assert!(scan_leftovers(&[&dir.path().join("missing")]).is_empty());
+
+        let cmd = remove_command(&found[0]);
+        assert!(cmd.starts_with("sudo rm -i -- '"));
+        assert!(cmd.ends_with("a.conf.pacnew'"));
+        assert!(merge_command(&found[0]).contains("sudo nvim -d '"));
+    }
+}
@@ -6,7 +6,8 @@
 //! logic can be exercised in isolation.
 
 use crate::state::modal::{
-    PreflightAction, PreflightHeaderChips, PreflightPackageSummary, PreflightSummaryData, RiskLevel,
+    PreflightAction, PreflightHeaderChips, PreflightPackageSummary, PreflightSummaryData,
+    ResolvedTransaction, RiskLevel,
 };
 use crate::state::types::{PackageItem, Source};
 use std::cmp::Ordering;
@@ -420,6 +421,8 @@ pub fn compute_preflight_summary_with_runner<R: CommandRunner>(
         service_restart_units,
         summary_warnings,
         summary_notes,
+        resolved_transaction: matches!(action, PreflightAction::Install)
+            .then(|| resolve_transaction(runner, items)),
     };
 
     let header = PreflightHeaderChips {
@@ -443,6 +446,67 @@ pub fn compute_preflight_summary_with_runner<R: CommandRunner>(
     PreflightSummaryOutcome { summary, header }
 }
 
+/// What: Ask pacman for the exact install transaction via `pacman -S --print`.
+///
+/// Inputs:
+/// - `runner`: Command executor.
+/// - `items`: Packages selected for installation.
+///
+/// Output:
+/// - [`ResolvedTransaction`] listing pacman's targets, the dependencies it pulls in beyond the
+///   selection, and the AUR targets it cannot simulate.
+///
+/// Details:
+/// - `--print` only reads the sync databases, so it runs unprivileged; when they are missing or
+///   a target cannot be resolved, `error` is set and the caller keeps Pacsea's own plan.
+/// - AUR helpers build before installing and cannot simulate without doing so, so AUR targets
+///   are reported as unresolved instead of being passed to the helper.
+fn resolve_transaction<R: CommandRunner>(runner: &R, items: &[PackageItem]) -> ResolvedTransaction {
+    let mut resolved = ResolvedTransaction::default();
+    let mut specs: Vec<String> = Vec::new();
+    for item in items {
+        match &item.source {
+            Source::Aur => resolved.aur_unresolved.push(item.name.clone()),
+            Source::Official { repo, .. } if !repo.is_empty() => {
+                specs.push(format!("{repo}/{}", item.name));
+            }
+            Source::Official { .. } => specs.push(item.name.clone()),
+        }
+    }
+    if specs.is_empty() {
+        return resolved;
+    }
+    let mut args = vec!["-S", "--print", "--print-format", "%n %v"];
+    args.extend(specs.iter().map(String::as_str));
+    match runner.run(&crate::install::privilege::pacman_bin(), &args) {
+        Ok(output) => {
+            resolved.targets = output
+                .lines()
+                .filter_map(|line| {
+                    let mut parts = line.split_whitespace();
+                    match (parts.next(), parts.next(), parts.next()) {
+                        (Some(name), Some(version), None) => {
+                            Some((name.to_string(), version.to_string()))
+                        }
+                        _ => None,
+                    }
+                })
+                .collect();
+            resolved.extra = resolved
+                .targets
+                .iter()
+                .filter(|(name, _)| !items.iter().any(|item| item.name == *name))
+                .map(|(name, _)| name.clone())
+                .collect();
+        }
+        Err(err) => {
+            tracing::debug!("Preflight summary: pacman --print failed: {err}");
+            resolved.error = Some(err.to_string());
+        }
+    }
+    resolved
+}
+
 /// What: Extract remote download/install sizes for an official package via
 /// `pacman -Si`.
 ///
@@ -466,7 +530,7 @@ fn fetch_official_metadata<R: CommandRunner>(
     expected_version: &str,
 ) -> Result<OfficialMetadata, CommandError> {
     let spec = format!("{repo}/{name}");
-    let output = runner.run("pacman", &["-Si", &spec])?;
+    let output = runner.run(&crate::install::privilege::pacman_bin(), &["-Si", &spec])?;
     let fields = parse_pacman_key_values(&output);
 
     if let Some(version) = fields.get("Version")
@@ -509,7 +573,7 @@ fn fetch_installed_version<R: CommandRunner>(
     runner: &R,
     name: &str,
 ) -> Result<String, CommandError> {
-    let output = runner.run("pacman", &["-Q", name])?;
+    let output = runner.run(&crate::install::privilege::pacman_bin(), &["-Q", name])?;
     let mut parts = output.split_whitespace();
     let _pkg_name = parts.next();
     parts
@@ -534,7 +598,7 @@ fn fetch_installed_version<R: CommandRunner>(
 /// Details:
 /// - Parses the `Installed Size` field using [`parse_size_to_bytes`].
 fn fetch_installed_size<R: CommandRunner>(runner: &R, name: &str) -> Result<u64, CommandError> {
-    let output = runner.run("pacman", &["-Qi", name])?;
+    let output = runner.run(&crate::install::privilege::pacman_bin(), &["-Qi", name])?;
     let fields = parse_pacman_key_values(&output);
     fields
         .get("Installed Size")
@@ -640,7 +704,7 @@ fn parse_size_to_bytes(raw: &str) -> Option<u64> {
 /// Details:
 /// - Splits on `.` and `-`, comparing numeric segments when possible and
 ///   falling back to lexicographical comparison.
-fn compare_versions(a: &str, b: &str) -> Ordering {
+pub(crate) fn compare_versions(a: &str, b: &str) -> Ordering {
     let a_parts: Vec<&str> = a.split(['.', '-']).collect();
     let b_parts: Vec<&str> = b.split(['.', '-']).collect();
     let len = a_parts.len().max(b_parts.len());
@@ -664,6 +728,145 @@ fn compare_versions(a: &str, b: &str) -> Ordering {
     Ordering::Equal
 }
 
+/// What: Compare two full package versions the way `vercmp`/libalpm does.
+///
+/// Inputs:
+/// - `a`: Left-hand `[epoch:]pkgver[-pkgrel]` string.
+/// - `b`: Right-hand `[epoch:]pkgver[-pkgrel]` string.
+///
+/// Output:
+/// - `Ordering` of `a` relative to `b`.
+///
+/// Details:
+/// - Compares the epoch first (missing means `0`), then `pkgver` with [`rpmvercmp`], then
+///   `pkgrel` only when both sides carry one.
+pub(crate) fn vercmp(a: &str, b: &str) -> Ordering {
+    if a == b {
+        return Ordering::Equal;
+    }
+    let (epoch_a, ver_a, rel_a) = split_evr(a);
+    let (epoch_b, ver_b, rel_b) = split_evr(b);
+    rpmvercmp(epoch_a, epoch_b)
+        .then_with(|| rpmvercmp(ver_a, ver_b))
+        .then_with(|| match (rel_a, rel_b) {
+            (Some(x), Some(y)) => rpmvercmp(x, y),
+            _ => Ordering::Equal,
+        })
+}
+
+/// What: Split a package version into epoch, pkgver and pkgrel.
+///
+/// Inputs:
+/// - `evr`: Version string such as `1:2.0-3`.
+///
+/// Output:
+/// - `(epoch, pkgver, pkgrel)`; the epoch defaults to `"0"` and pkgrel is `None` when absent.
+///
+/// Details:
+/// - Mirrors libalpm's `parseEVR`: the epoch is the leading digits before `:`, and pkgrel is
+///   whatever follows the last `-` after it.
+fn split_evr(evr: &str) -> (&str, &str, Option<&str>) {
+    let digits = evr.bytes().take_while(u8::is_ascii_digit).count();
+    let (epoch, rest) = match evr[digits..].strip_prefix(':') {
+        Some(rest) if digits > 0 => (&evr[..digits], rest),
+        Some(rest) => ("0", rest),
+        None => ("0", evr),
+    };
+    match rest.rfind('-') {
+        Some(idx) => (epoch, &rest[..idx], Some(&rest[idx + 1..])),
+        None => (epoch, rest, None),
+    }
+}
+
+/// What: Compare two version fragments with rpm's segment rules.
+///
+/// Inputs:
+/// - `a`: Left-hand fragment (epoch, pkgver or pkgrel).
+/// - `b`: Right-hand fragment.
+///
+/// Output:
+/// - `Ordering` of `a` relative to `b`.
+///
+/// Details:
+/// - Walks alternating runs of digits and letters, skipping separators; numeric runs compare
+///   by value and beat alpha runs, alpha runs compare bytewise.
+/// - When one side runs out, a trailing alpha run is older (`1.0rc1 < 1.0`) and any other
+///   remainder is newer (`1.0.1 > 1.0`).
+fn rpmvercmp(a: &str, b: &str) -> Ordering {
+    if a == b {
+        return Ordering::Equal;
+    }
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        let (sep_a, sep_b) = (i, j);
+        while i < a.len() && !a[i].is_ascii_alphanumeric() {
+            i += 1;
+        }
+        while j < b.len() && !b[j].is_ascii_alphanumeric() {
+            j += 1;
+        }
+        if i >= a.len() || j >= b.len() {
+            break;
+        }
+        if i - sep_a != j - sep_b {
+            return (i - sep_a).cmp(&(j - sep_b));
+        }
+        let is_num = a[i].is_ascii_digit();
+        let run = |s: &[u8], start: usize| {
+            let len = s[start..]
+                .iter()
+                .take_while(|c| {
+                    if is_num {
+                        c.is_ascii_digit()
+                    } else {
+                        c.is_ascii_alphabetic()
+                    }
+                })
+                .count();
+            start + len
+        };
+        let (end_a, end_b) = (run(a, i), run(b, j));
+        if end_b == j {
+            // Numeric segments are always newer than alpha ones
+            return if is_num {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            };
+        }
+        let (mut seg_a, mut seg_b) = (&a[i..end_a], &b[j..end_b]);
+        if is_num {
+            while seg_a.first() == Some(&b'0') {
+                seg_a = &seg_a[1..];
+            }
+            while seg_b.first() == Some(&b'0') {
+                seg_b = &seg_b[1..];
+            }
+            match seg_a.len().cmp(&seg_b.len()) {
+                Ordering::Equal => {}
+                ord => return ord,
+            }
+        }
+        match seg_a.cmp(seg_b) {
+            Ordering::Equal => {}
+            ord => return ord,
+        }
+        i = end_a;
+        j = end_b;
+    }
+    if i >= a.len() && j >= b.len() {
+        return Ordering::Equal;
+    }
+    let a_rest_alpha = a.get(i).is_some_and(u8::is_ascii_alphabetic);
+    let b_rest_alpha = b.get(j).is_some_and(u8::is_ascii_alphabetic);
+    if (i >= a.len() && !b_rest_alpha) || a_rest_alpha {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    }
+}
+
 /// What: Determine whether `new` constitutes a major version bump relative to
 /// `old`.
 ///
@@ -722,7 +925,7 @@ fn batch_fetch_installed_versions<R: CommandRunner>(
         let names: Vec<&str> = chunk.iter().map(|i| i.name.as_str()).collect();
         let mut args = vec!["-Q"];
         args.extend(names.iter().copied());
-        match runner.run("pacman", &args) {
+        match runner.run(&crate::install::privilege::pacman_bin(), &args) {
             Ok(output) => {
                 // Parse output: each line is "name version"
                 let mut version_map = std::collections::HashMap::new();
@@ -781,7 +984,7 @@ fn batch_fetch_installed_sizes<R: CommandRunner>(
         let names: Vec<&str> = chunk.iter().map(|i| i.name.as_str()).collect();
         let mut args = vec!["-Qi"];
         args.extend(names.iter().copied());
-        match runner.run("pacman", &args) {
+        match runner.run(&crate::install::privilege::pacman_bin(), &args) {
             Ok(output) => {
                 // Parse multi-package output: packages are separated by blank lines
                 let mut package_blocks = Vec::new();
@@ -996,4 +1199,73 @@ mod tests {
         );
         assert_eq!(outcome.header.aur_count, 1);
     }
+
+    #[test]
+    /// What: Verify `pacman --print` results surface extra dependencies and failures.
+    ///
+    /// Inputs:
+    /// - An official and an AUR package, with a `--print` response adding a dependency; then
+    ///   the same official package with `--print` failing.
+    ///
+    /// Output:
+    /// - The dependency is reported as extra and the AUR package as unresolved; a failure sets
+    ///   the error while leaving Pacsea's own package list intact.
+    fn summary_resolves_transaction_with_pacman_print() {
+        let official = PackageItem {
+            name: "foo".into(),
+            version: "1-1".into(),
+            description: String::new(),
+            source: Source::Official {
+                repo: "extra".into(),
+                arch: "x86_64".into(),
+            },
+            popularity: None,
+        };
+        let aur = PackageItem {
+            name: "bar-git".into(),
+            version: "r1".into(),
+            description: String::new(),
+            source: Source::Aur,
+            popularity: None,
+        };
+        let mut responses = HashMap::new();
+        responses.insert(
+            (
+                "pacman".into(),
+                ["-S", "--print", "--print-format", "%n %v", "extra/foo"]
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect(),
+            ),
+            Ok("libfoo 2.0-1\nfoo 1-1\n".to_string()),
+        );
+        let runner = MockRunner::with(responses);
+        let outcome = compute_preflight_summary_with_runner(
+            &[official.clone(), aur],
+            PreflightAction::Install,
+            &runner,
+        );
+        let resolved = outcome.summary.resolved_transaction.unwrap();
+        assert_eq!(resolved.targets.len(), 2);
+        assert_eq!(resolved.extra, vec!["libfoo".to_string()]);
+        assert_eq!(resolved.aur_unresolved, vec!["bar-git".to_string()]);
+        assert!(resolved.error.is_none());
+
+        let outcome = compute_preflight_summary_with_runner(
+            std::slice::from_ref(&official),
+            PreflightAction::Install,
+            &MockRunner::default(),
+        );
+        let resolved = outcome.summary.resolved_transaction.unwrap();
+        assert!(resolved.error.is_some());
+        assert!(resolved.targets.is_empty());
+        assert_eq!(outcome.summary.packages.len(), 1);
+
+        let outcome = compute_preflight_summary_with_runner(
+            &[official],
+            PreflightAction::Remove,
+            &MockRunner::default(),
+        );
+        assert!(outcome.summary.resolved_transaction.is_none());
+    }
 }
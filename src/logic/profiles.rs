@@ -0,0 +1,127 @@
+//! Named install-list profiles stored as `lists_dir/profiles/<name>.json`.
+
+use std::path::{Path, PathBuf};
+
+use crate::state::PackageItem;
+
+/// What: Directory holding saved profiles.
+pub fn profiles_dir() -> PathBuf {
+    crate::theme::lists_dir().join("profiles")
+}
+
+/// What: Turn a user-entered profile name into a safe file stem.
+///
+/// Inputs:
+/// - `raw`: Name as typed.
+///
+/// Output:
+/// - The sanitized name, or `None` when nothing usable remains.
+///
+/// Details:
+/// - Keeps ASCII letters, digits, `-`, `_`, and `.`; whitespace becomes `-` and everything else
+///   (including path separators) is dropped. Leading dots are stripped so names cannot be hidden
+///   files or `..`.
+pub fn sanitize_profile_name(raw: &str) -> Option<String> {
+    let name: String = raw
+        .trim()
+        .chars()
+        .filter_map(|c| match c {
+            c if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') => Some(c),
+            c if c.is_whitespace() => Some('-'),
+            _ => None,
+        })
+        .collect();
+    let name = name.trim_start_matches('.');
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// What: Save `items` as profile `name` under `dir`.
+///
+/// Inputs:
+/// - `dir`: Profiles directory (created if missing).
+/// - `name`: Profile name as typed; sanitized before use.
+/// - `items`: Install-list entries to store.
+///
+/// Output:
+/// - Path written, or an error message for an empty name or I/O failure.
+///
+/// Details:
+/// - Uses the same JSON format as `install_list.json`; an existing profile is overwritten.
+pub fn save_profile(dir: &Path, name: &str, items: &[PackageItem]) -> Result<PathBuf, String> {
+    let name = sanitize_profile_name(name).ok_or_else(|| "empty profile name".to_string())?;
+    std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    let path = dir.join(format!("{name}.json"));
+    let body = serde_json::to_string(items).map_err(|e| e.to_string())?;
+    std::fs::write(&path, body).map_err(|e| e.to_string())?;
+    Ok(path)
+}
+
+/// What: Names of the profiles saved under `dir`, sorted.
+pub fn list_profiles(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|e| {
+            let path = e.path();
+            (path.extension().is_some_and(|x| x == "json"))
+                .then(|| path.file_stem()?.to_str().map(str::to_string))
+                .flatten()
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+/// What: Read profile `name` from `dir`.
+///
+/// Output:
+/// - The stored entries, or an error message when the file is missing or malformed.
+pub fn load_profile(dir: &Path, name: &str) -> Result<Vec<PackageItem>, String> {
+    let body =
+        std::fs::read_to_string(dir.join(format!("{name}.json"))).map_err(|e| e.to_string())?;
+    serde_json::from_str(&body).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure profile names are sanitized and profiles round-trip through disk.
+    ///
+    /// Inputs:
+    /// - Names with spaces, separators, and leading dots; one entry saved as "my dev".
+    ///
+    /// Output:
+    /// - Unsafe characters dropped, empty names rejected, and the saved entry listed and loaded.
+    fn profiles_sanitize_and_round_trip() {
+        assert_eq!(sanitize_profile_name(" my dev "), Some("my-dev".into()));
+        assert_eq!(
+            sanitize_profile_name("../etc/passwd"),
+            Some("etcpasswd".into())
+        );
+        assert_eq!(sanitize_profile_name("  "), None);
+        assert_eq!(sanitize_profile_name("/.."), None);
+
+        let dir = tempfile::tempdir().unwrap();
+        let item = PackageItem {
+            name: "ripgrep".into(),
+            version: "14.1.0-1".into(),
+            description: String::new(),
+            source: crate::state::Source::Official {
+                repo: "extra".into(),
+                arch: "x86_64".into(),
+            },
+            popularity: None,
+        };
+        assert!(save_profile(dir.path(), "", std::slice::from_ref(&item)).is_err());
+        let path = save_profile(dir.path(), "my dev", std::slice::from_ref(&item)).unwrap();
+        assert!(path.ends_with("my-dev.json"));
+        assert_eq!(list_profiles(dir.path()), vec!["my-dev".to_string()]);
+        let loaded = load_profile(dir.path(), "my-dev").unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].name, "ripgrep");
+        assert!(load_profile(dir.path(), "missing").is_err());
+    }
+}
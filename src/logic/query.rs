@@ -13,13 +13,50 @@ use crate::state::AppState;
 ///
 /// Details:
 /// - The id allows correlating responses so the UI can discard stale results.
+/// - Results marked in multi-select mode are dropped: they belong to the previous query.
+/// - The worker waits `search_debounce` for a newer query before running this one.
 pub fn send_query(app: &mut AppState, query_tx: &mpsc::UnboundedSender<crate::state::QueryInput>) {
+    let debounce = app.search_debounce;
+    send_query_with(app, query_tx, debounce);
+}
+
+/// What: Run a query that is still waiting out the debounce right away.
+///
+/// Inputs:
+/// - `app`: Mutable application state (input, last keystroke time, query ids)
+/// - `query_tx`: Channel to send the `QueryInput`
+///
+/// Output:
+/// - `true` when the current input was re-sent without debounce; `false` when the last
+///   keystroke is older than `search_debounce` (its query already ran).
+///
+/// Details:
+/// - Used on Enter so a long `search_debounce_ms` never leaves the results behind the input.
+pub fn flush_query(
+    app: &mut AppState,
+    query_tx: &mpsc::UnboundedSender<crate::state::QueryInput>,
+) -> bool {
+    if app.last_input_change.elapsed() >= app.search_debounce {
+        return false;
+    }
+    send_query_with(app, query_tx, std::time::Duration::ZERO);
+    true
+}
+
+/// What: Send the current input with a fresh id and the given debounce.
+fn send_query_with(
+    app: &mut AppState,
+    query_tx: &mpsc::UnboundedSender<crate::state::QueryInput>,
+    debounce: std::time::Duration,
+) {
     let id = app.next_query_id;
     app.next_query_id += 1;
     app.latest_query_id = id;
+    app.multi_selected.clear();
     let _ = query_tx.send(crate::state::QueryInput {
         id,
         text: app.input.clone(),
+        debounce,
     });
 }
 
@@ -54,4 +91,35 @@ mod tests {
         assert_eq!(q.id, app.latest_query_id);
         assert_eq!(q.text, "hello");
     }
+
+    #[test]
+    /// What: Ensure Enter-style flushing only re-sends a query that is still debouncing.
+    ///
+    /// Inputs:
+    /// - A 10 s debounce with a fresh keystroke, then a keystroke older than the debounce.
+    ///
+    /// Output:
+    /// - The first flush sends the input with a zero debounce; the second sends nothing.
+    fn flush_query_skips_settled_input() {
+        let mut app = AppState {
+            input: "fire".into(),
+            search_debounce: std::time::Duration::from_secs(10),
+            last_input_change: std::time::Instant::now(),
+            ..Default::default()
+        };
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        send_query(&mut app, &tx);
+        assert_eq!(
+            rx.try_recv().unwrap().debounce,
+            std::time::Duration::from_secs(10)
+        );
+        assert!(flush_query(&mut app, &tx));
+        let q = rx.try_recv().unwrap();
+        assert_eq!((q.id, q.text.as_str()), (app.latest_query_id, "fire"));
+        assert!(q.debounce.is_zero());
+
+        app.last_input_change = std::time::Instant::now() - std::time::Duration::from_secs(11);
+        assert!(!flush_query(&mut app, &tx));
+        assert!(rx.try_recv().is_err());
+    }
 }
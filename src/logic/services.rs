@@ -8,6 +8,10 @@ use crate::state::modal::{ServiceImpact, ServiceRestartDecision};
 use crate::state::types::Source;
 use crate::state::{PackageItem, PreflightAction};
 
+mod init;
+
+pub use init::{InitSystem, detect_init_system};
+
 /// What: Resolve systemd service impacts for the selected transaction items.
 ///
 /// Inputs:
@@ -18,8 +22,11 @@ use crate::state::{PackageItem, PreflightAction};
 /// - Vector of `ServiceImpact` entries representing impacted systemd units.
 ///
 /// Details:
-/// - Inspects `pacman -Fl` output for each package to find shipped unit files.
-/// - Determines which units are currently active via `systemctl list-units`.
+/// - Inspects `pacman -Fl` output for each package to find shipped service files.
+/// - Detects the init system (systemd, OpenRC, runit, s6) and routes service discovery and
+///   state queries to it; shipped service files are matched against that init's layout.
+/// - Determines which units are currently active (`systemctl list-units` on systemd).
+/// - Records each unit's current active/enabled state for the restart preview.
 /// - Heuristically detects binaries that impact active units, even without unit files.
 /// - Computes a recommended restart decision; defaults to defer when the unit
 ///   is inactive or the action is a removal.
@@ -34,11 +41,12 @@ pub fn resolve_service_impacts(
     )
     .entered();
     let start_time = std::time::Instant::now();
+    let init = detect_init_system();
     let mut unit_to_providers: BTreeMap<String, Vec<String>> = BTreeMap::new();
 
     // First pass: collect units shipped by packages
     for item in items {
-        match collect_service_units_for_package(&item.name, &item.source) {
+        match collect_service_units_for_package(init, &item.name, &item.source) {
             Ok(units) => {
                 for unit in units {
                     let providers = unit_to_providers.entry(unit).or_default();
@@ -66,7 +74,7 @@ pub fn resolve_service_impacts(
         }
     }
 
-    let active_units = fetch_active_units().unwrap_or_else(|err| {
+    let active_units = fetch_active_units(init).unwrap_or_else(|err| {
         tracing::warn!("Unable to query active services: {}", err);
         BTreeSet::new()
     });
@@ -75,7 +83,7 @@ pub fn resolve_service_impacts(
     if matches!(action, PreflightAction::Install) && !active_units.is_empty() {
         // Get ExecStart paths for all active services
         let active_service_binaries =
-            fetch_active_service_binaries(&active_units).unwrap_or_default();
+            fetch_active_service_binaries(init, &active_units).unwrap_or_default();
 
         // For each package, check if any of its binaries match active service binaries
         for item in items {
@@ -122,11 +130,17 @@ pub fn resolve_service_impacts(
         }
     }
 
+    let unit_names: Vec<String> = unit_to_providers.keys().cloned().collect();
+    let unit_states = init::fetch_unit_states(init, &unit_names);
+
     let results: Vec<ServiceImpact> = unit_to_providers
         .into_iter()
         .map(|(unit_name, mut providers)| {
             providers.sort();
-            let is_active = active_units.contains(&unit_name);
+            let (active_state, enabled_state) =
+                unit_states.get(&unit_name).cloned().unwrap_or_default();
+            let is_active =
+                active_units.contains(&unit_name) || init::is_running_state(&active_state);
             let needs_restart = matches!(action, PreflightAction::Install) && is_active;
             let recommended_decision = if needs_restart {
                 ServiceRestartDecision::Restart
@@ -138,6 +152,8 @@ pub fn resolve_service_impacts(
                 unit_name,
                 providers,
                 is_active,
+                active_state,
+                enabled_state,
                 needs_restart,
                 recommended_decision,
                 restart_decision: recommended_decision,
@@ -160,28 +176,26 @@ pub fn resolve_service_impacts(
 /// What: Collect service unit filenames shipped by a specific package.
 ///
 /// Inputs:
+/// - `init`: Detected init system, selecting which service layout to look for.
 /// - `package`: Package name for which to inspect the remote file list.
 ///
 /// Output:
-/// - Vector of unit filenames (e.g., `sshd.service`). Empty when the package
-///   ships no systemd units.
+/// - Vector of service names (e.g., `sshd.service`, or `sshd` on OpenRC/runit/s6). Empty when
+///   the package ships no services for `init`.
 ///
 /// Details:
-/// - Executes `pacman -Fl <package>` and filters paths under the standard
-///   systemd directories.
+/// - Executes `pacman -Fl <package>` and filters paths under the init system's service
+///   directories.
 fn collect_service_units_for_package(
+    init: InitSystem,
     package: &str,
     source: &Source,
 ) -> Result<Vec<String>, String> {
     match source {
         Source::Official { .. } => {
             // Use pacman -Fl for official packages
-            let output = run_command(
-                "pacman",
-                &["-Fl", package],
-                &format!("pacman -Fl {}", package),
-            )?;
-            let units = extract_service_units_from_file_list(&output, package);
+            let output = run_file_list(package)?;
+            let units = extract_service_units_from_file_list(init, &output, package);
             Ok(units)
         }
         Source::Aur => {
@@ -195,7 +209,7 @@ fn collect_service_units_for_package(
                     .map(|f| format!("{} {}", package, f))
                     .collect::<Vec<_>>()
                     .join("\n");
-                let units = extract_service_units_from_file_list(&file_list, package);
+                let units = extract_service_units_from_file_list(init, &file_list, package);
                 if !units.is_empty() {
                     tracing::debug!(
                         "Found {} service units from installed AUR package {}",
@@ -206,51 +220,17 @@ fn collect_service_units_for_package(
                 }
             }
 
-            // Try to use paru/yay -Fl if available (works for cached AUR packages)
-            let has_paru = Command::new("paru").args(["--version"]).output().is_ok();
-            let has_yay = Command::new("yay").args(["--version"]).output().is_ok();
-
-            if has_paru {
-                tracing::debug!("Trying paru -Fl {} for AUR package service units", package);
-                if let Ok(output) = Command::new("paru")
-                    .args(["-Fl", package])
-                    .env("LC_ALL", "C")
-                    .env("LANG", "C")
-                    .output()
-                    && output.status.success()
-                {
-                    let text = String::from_utf8_lossy(&output.stdout);
-                    let units = extract_service_units_from_file_list(&text, package);
-                    if !units.is_empty() {
-                        tracing::debug!(
-                            "Found {} service units from paru -Fl for {}",
-                            units.len(),
-                            package
-                        );
-                        return Ok(units);
-                    }
-                }
-            }
-
-            if has_yay {
-                tracing::debug!("Trying yay -Fl {} for AUR package service units", package);
-                if let Ok(output) = Command::new("yay")
-                    .args(["-Fl", package])
-                    .env("LC_ALL", "C")
-                    .env("LANG", "C")
-                    .output()
-                    && output.status.success()
-                {
-                    let text = String::from_utf8_lossy(&output.stdout);
-                    let units = extract_service_units_from_file_list(&text, package);
-                    if !units.is_empty() {
-                        tracing::debug!(
-                            "Found {} service units from yay -Fl for {}",
-                            units.len(),
-                            package
-                        );
-                        return Ok(units);
-                    }
+            // Try helpers that support -Fl (works for cached AUR packages); others are skipped
+            if let Some((helper, text)) = crate::logic::deps::aur_file_list_output(package) {
+                let units = extract_service_units_from_file_list(init, &text, package);
+                if !units.is_empty() {
+                    tracing::debug!(
+                        "Found {} service units from {} -Fl for {}",
+                        units.len(),
+                        helper.binary(),
+                        package
+                    );
+                    return Ok(units);
                 }
             }
 
@@ -265,6 +245,23 @@ fn collect_service_units_for_package(
     }
 }
 
+/// What: Run `pacman -Fl <package>` against the preferred file database.
+///
+/// Details:
+/// - Goes through [`crate::logic::filedb::files_query_command`] so a user-local database is
+///   used when the system one is stale.
+fn run_file_list(package: &str) -> Result<String, String> {
+    let display = format!("pacman -Fl {package}");
+    let output = crate::logic::filedb::files_query_command(&["-Fl", package])
+        .output()
+        .map_err(|err| format!("failed to spawn `{display}`: {err}"))?;
+    if !output.status.success() {
+        return Err(format!("`{display}` exited with status {}", output.status));
+    }
+    String::from_utf8(output.stdout)
+        .map_err(|err| format!("`{display}` produced invalid UTF-8: {err}"))
+}
+
 /// What: Execute a command and capture stdout as UTF-8.
 ///
 /// Inputs:
@@ -294,20 +291,26 @@ fn run_command(program: &str, args: &[&str], display: &str) -> Result<String, St
         .map_err(|err| format!("`{}` produced invalid UTF-8: {}", display, err))
 }
 
-/// What: Extract unit filenames from `pacman -Fl` output.
+/// What: Extract service names from `pacman -Fl` output.
 ///
 /// Inputs:
+/// - `init`: Init system whose service layout to match.
 /// - `file_list`: Raw `pacman -Fl` stdout.
 /// - `package`: Package name used to filter unrelated entries in the output.
 ///
 /// Output:
-/// - Vector of unit filenames sorted in discovery order.
+/// - Vector of service names sorted in discovery order.
 ///
 /// Details:
-/// - Recognises units residing under `/usr/lib/systemd/system/` or the legacy
-///   `/lib/systemd/system/` prefixes.
-/// - Discards duplicate unit entries while preserving discovery order.
-fn extract_service_units_from_file_list(file_list: &str, package: &str) -> Vec<String> {
+/// - systemd units reside under `/usr/lib/systemd/system/` or the legacy `/lib/systemd/system/`
+///   prefixes; OpenRC scripts under `/etc/init.d/`; runit and s6 service directories under
+///   `/etc/runit/sv/` and `/etc/s6/sv/`.
+/// - Discards duplicate entries while preserving discovery order.
+fn extract_service_units_from_file_list(
+    init: InitSystem,
+    file_list: &str,
+    package: &str,
+) -> Vec<String> {
     let mut seen = HashSet::new();
     let mut units = Vec::new();
 
@@ -321,53 +324,32 @@ fn extract_service_units_from_file_list(file_list: &str, package: &str) -> Vec<S
         }
 
         let path = raw_path.strip_suffix('/').unwrap_or(raw_path);
-        if !is_service_path(path) {
-            continue;
-        }
-
-        if let Some(file_name) = Path::new(path)
-            .file_name()
-            .and_then(|name| name.to_str())
-            .map(|s| s.to_string())
-            .filter(|name| seen.insert(name.clone()))
+        if let Some(name) =
+            init::service_name_for_path(init, path).filter(|name| seen.insert(name.clone()))
         {
-            units.push(file_name);
+            units.push(name);
         }
     }
 
     units
 }
 
-/// What: Determine whether a path refers to a systemd service unit file.
+/// What: Fetch the set of currently active services.
 ///
 /// Inputs:
-/// - `path`: File path extracted from `pacman -Fl`.
+/// - `init`: Detected init system.
 ///
 /// Output:
-/// - `true` when the path resides under a known systemd unit directory and
-///   ends with `.service`; otherwise `false`.
+/// - `BTreeSet` containing service names (e.g., `sshd.service`, or `sshd` on non-systemd
+///   inits). Errors when the init system cannot be queried.
 ///
 /// Details:
-/// - Supports both `/usr/lib/systemd/system` and `/lib/systemd/system` roots.
-fn is_service_path(path: &str) -> bool {
-    const PREFIXES: [&str; 2] = ["/usr/lib/systemd/system/", "/lib/systemd/system/"];
-    PREFIXES
-        .iter()
-        .any(|prefix| path.starts_with(prefix) && path.ends_with(".service"))
-}
-
-/// What: Fetch the set of currently active systemd services.
-///
-/// Inputs:
-/// - None.
-///
-/// Output:
-/// - `BTreeSet` containing unit names (e.g., `sshd.service`). Errors when the
-///   `systemctl` command fails.
-///
-/// Details:
-/// - Runs `systemctl list-units --type=service --no-legend --state=active`.
-fn fetch_active_units() -> Result<BTreeSet<String>, String> {
+/// - systemd (and unknown inits) run `systemctl list-units --type=service --no-legend
+///   --state=active`; OpenRC, runit, and s6 use their own status tools.
+fn fetch_active_units(init: InitSystem) -> Result<BTreeSet<String>, String> {
+    if !matches!(init, InitSystem::Systemd | InitSystem::Unknown) {
+        return init::fetch_running_services(init);
+    }
     let output = run_command(
         "systemctl",
         &[
@@ -406,9 +388,10 @@ fn parse_active_units(systemctl_output: &str) -> BTreeSet<String> {
         .collect()
 }
 
-/// What: Fetch ExecStart binary paths for active systemd services.
+/// What: Fetch the binary paths started by active services.
 ///
 /// Inputs:
+/// - `init`: Detected init system.
 /// - `active_units`: Set of active unit names.
 ///
 /// Output:
@@ -417,9 +400,14 @@ fn parse_active_units(systemctl_output: &str) -> BTreeSet<String> {
 /// Details:
 /// - Uses `systemctl show` to get ExecStart paths for each active service.
 /// - Parses ExecStart to extract binary paths (handles paths with arguments).
+/// - On OpenRC/runit/s6 the service scripts are read instead (`command=` or `exec` lines).
 fn fetch_active_service_binaries(
+    init: InitSystem,
     active_units: &BTreeSet<String>,
 ) -> Result<BTreeMap<String, Vec<String>>, String> {
+    if !matches!(init, InitSystem::Systemd | InitSystem::Unknown) {
+        return Ok(init::script_binaries(init, active_units));
+    }
     let mut unit_to_binaries: BTreeMap<String, Vec<String>> = BTreeMap::new();
 
     for unit in active_units {
@@ -509,11 +497,7 @@ fn collect_binaries_for_package(package: &str, source: &Source) -> Result<Vec<St
     match source {
         Source::Official { .. } => {
             // Use pacman -Fl for official packages
-            let output = run_command(
-                "pacman",
-                &["-Fl", package],
-                &format!("pacman -Fl {}", package),
-            )?;
+            let output = run_file_list(package)?;
             let binaries = extract_binaries_from_file_list(&output, package);
             Ok(binaries)
         }
@@ -541,56 +525,22 @@ fn collect_binaries_for_package(package: &str, source: &Source) -> Result<Vec<St
                 }
             }
 
-            // Try to use paru/yay -Fl if available (works for cached AUR packages)
-            let has_paru = Command::new("paru").args(["--version"]).output().is_ok();
-            let has_yay = Command::new("yay").args(["--version"]).output().is_ok();
-
-            if has_paru {
-                tracing::debug!("Trying paru -Fl {} for AUR package binaries", package);
-                if let Ok(output) = Command::new("paru")
-                    .args(["-Fl", package])
-                    .env("LC_ALL", "C")
-                    .env("LANG", "C")
-                    .output()
-                    && output.status.success()
-                {
-                    let text = String::from_utf8_lossy(&output.stdout);
-                    let binaries = extract_binaries_from_file_list(&text, package);
-                    if !binaries.is_empty() {
-                        tracing::debug!(
-                            "Found {} binaries from paru -Fl for {}",
-                            binaries.len(),
-                            package
-                        );
-                        return Ok(binaries);
-                    }
-                }
-            }
-
-            if has_yay {
-                tracing::debug!("Trying yay -Fl {} for AUR package binaries", package);
-                if let Ok(output) = Command::new("yay")
-                    .args(["-Fl", package])
-                    .env("LC_ALL", "C")
-                    .env("LANG", "C")
-                    .output()
-                    && output.status.success()
-                {
-                    let text = String::from_utf8_lossy(&output.stdout);
-                    let binaries = extract_binaries_from_file_list(&text, package);
-                    if !binaries.is_empty() {
-                        tracing::debug!(
-                            "Found {} binaries from yay -Fl for {}",
-                            binaries.len(),
-                            package
-                        );
-                        return Ok(binaries);
-                    }
+            // Try helpers that support -Fl (works for cached AUR packages); others are skipped
+            if let Some((helper, text)) = crate::logic::deps::aur_file_list_output(package) {
+                let binaries = extract_binaries_from_file_list(&text, package);
+                if !binaries.is_empty() {
+                    tracing::debug!(
+                        "Found {} binaries from {} -Fl for {}",
+                        binaries.len(),
+                        helper.binary(),
+                        package
+                    );
+                    return Ok(binaries);
                 }
             }
 
             // Fallback: try to parse PKGBUILD to extract install paths
-            match crate::logic::files::fetch_pkgbuild_sync(package) {
+            match crate::logic::files::fetch_pkgbuild_sync(package, None) {
                 Ok(pkgbuild) => {
                     let files =
                         crate::logic::files::parse_install_paths_from_pkgbuild(&pkgbuild, package);
@@ -712,7 +662,7 @@ mockpkg /lib/systemd/system/legacy.service
 mockpkg /usr/bin/mock
 otherpkg /usr/lib/systemd/system/other.service
 ";
-        let units = extract_service_units_from_file_list(output, "mockpkg");
+        let units = extract_service_units_from_file_list(InitSystem::Systemd, output, "mockpkg");
         assert_eq!(
             units,
             vec!["example.service".to_string(), "legacy.service".to_string()]
@@ -738,7 +688,7 @@ mockpkg /usr/lib/systemd/system/alpha.service/
 mockpkg /usr/lib/systemd/system/gamma.service
 mockpkg /usr/lib/systemd/system/beta.service/
 ";
-        let units = extract_service_units_from_file_list(output, "mockpkg");
+        let units = extract_service_units_from_file_list(InitSystem::Systemd, output, "mockpkg");
         assert_eq!(
             units,
             vec![
@@ -790,6 +740,8 @@ dbus.socket                 loaded active running D-Bus Socket
             unit_name: "example.service".into(),
             providers: vec!["pkg".into()],
             is_active: false,
+            active_state: String::new(),
+            enabled_state: String::new(),
             needs_restart: false,
             recommended_decision: ServiceRestartDecision::Defer,
             restart_decision: ServiceRestartDecision::Defer,
@@ -0,0 +1,592 @@
+//! Init-system detection and per-backend service queries (systemd, OpenRC, runit, s6).
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use std::process::Command;
+use std::sync::OnceLock;
+
+/// OpenRC init scripts.
+const OPENRC_INIT_DIR: &str = "/etc/init.d";
+/// runit service definitions shipped by packages.
+const RUNIT_SV_DIR: &str = "/etc/runit/sv";
+/// runit services enabled in the running runsvdir (Artix layout).
+const RUNIT_ENABLED_DIR: &str = "/run/runit/service";
+/// s6 service definitions shipped by packages.
+const S6_SV_DIR: &str = "/etc/s6/sv";
+/// s6-rc bundle Artix enables services in.
+const S6_ENABLED_DIR: &str = "/etc/s6/adminsv/default/contents.d";
+
+/// Init system managing services on this machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InitSystem {
+    /// systemd (Arch default).
+    Systemd,
+    /// OpenRC (Artix).
+    OpenRc,
+    /// runit (Artix, Void).
+    Runit,
+    /// s6 / s6-rc (Artix).
+    S6,
+    /// Anything else; service states are not queried.
+    Unknown,
+}
+
+impl InitSystem {
+    /// Display name shown in the Services tab header.
+    pub const fn name(self) -> &'static str {
+        match self {
+            InitSystem::Systemd => "systemd",
+            InitSystem::OpenRc => "OpenRC",
+            InitSystem::Runit => "runit",
+            InitSystem::S6 => "s6",
+            InitSystem::Unknown => "unknown",
+        }
+    }
+}
+
+/// What: Detect which init system runs the machine.
+///
+/// Output:
+/// - The detected backend; computed once per process.
+///
+/// Details:
+/// - Checks the runtime directories each init creates at boot, so an installed-but-unused
+///   `systemctl` (e.g. from a `-compat` package) does not win over the real init.
+pub fn detect_init_system() -> InitSystem {
+    static INIT: OnceLock<InitSystem> = OnceLock::new();
+    *INIT.get_or_init(|| {
+        let init = detect_with(|p| Path::new(p).is_dir());
+        tracing::debug!("Detected init system: {}", init.name());
+        init
+    })
+}
+
+/// What: Detection order over runtime directories, with the filesystem check injected.
+fn detect_with(is_dir: impl Fn(&str) -> bool) -> InitSystem {
+    if is_dir("/run/systemd/system") {
+        InitSystem::Systemd
+    } else if is_dir("/run/openrc") {
+        InitSystem::OpenRc
+    } else if is_dir("/run/runit") || is_dir("/etc/runit/runsvdir") {
+        InitSystem::Runit
+    } else if is_dir("/run/s6-rc") || is_dir("/run/s6") {
+        InitSystem::S6
+    } else {
+        InitSystem::Unknown
+    }
+}
+
+/// What: Map a packaged file path to the service it defines for `init`.
+///
+/// Inputs:
+/// - `init`: Backend whose layout to match.
+/// - `path`: Path from a package file list, without trailing `/`.
+///
+/// Output:
+/// - `sshd.service` for systemd units, the bare service name for OpenRC scripts
+///   (`/etc/init.d/sshd`) and runit/s6 service directories (`/etc/runit/sv/sshd/run`); `None`
+///   otherwise.
+///
+/// Details:
+/// - `Unknown` uses the systemd layout, matching Arch packaging.
+pub fn service_name_for_path(init: InitSystem, path: &str) -> Option<String> {
+    let first_component = |prefix: &str| {
+        path.strip_prefix(prefix)
+            .and_then(|rest| rest.strip_prefix('/'))
+            .and_then(|rest| rest.split('/').next())
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+    };
+    match init {
+        InitSystem::Systemd | InitSystem::Unknown => {
+            const PREFIXES: [&str; 2] = ["/usr/lib/systemd/system/", "/lib/systemd/system/"];
+            PREFIXES
+                .iter()
+                .filter_map(|prefix| path.strip_prefix(prefix))
+                .find(|name| !name.contains('/') && name.ends_with(".service"))
+                .map(str::to_string)
+        }
+        InitSystem::OpenRc => first_component(OPENRC_INIT_DIR)
+            .filter(|name| path.len() == OPENRC_INIT_DIR.len() + 1 + name.len()),
+        InitSystem::Runit => first_component(RUNIT_SV_DIR),
+        InitSystem::S6 => first_component(S6_SV_DIR),
+    }
+}
+
+/// What: Run a query command under the C locale, keeping stdout regardless of exit status.
+///
+/// Details:
+/// - Status queries (`systemctl is-active`, `sv status`, ...) exit non-zero when a service is
+///   down, so only spawn failures are treated as errors.
+fn query(program: &str, args: &[&str], env: &[(&str, &str)]) -> Option<String> {
+    match Command::new(program)
+        .args(args)
+        .envs(env.iter().copied())
+        .env("LC_ALL", "C")
+        .env("LANG", "C")
+        .output()
+    {
+        Ok(o) => Some(String::from_utf8_lossy(&o.stdout).into_owned()),
+        Err(err) => {
+            tracing::debug!("Failed to run {}: {}", program, err);
+            None
+        }
+    }
+}
+
+/// What: Names of entries in a directory (empty when it cannot be read).
+fn dir_names(dir: &str) -> Vec<String> {
+    std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|e| e.file_name().to_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// What: Fetch currently running services on a non-systemd init.
+///
+/// Inputs:
+/// - `init`: OpenRC, runit, or s6 (systemd is handled by `systemctl list-units` in the caller).
+///
+/// Output:
+/// - Names of running services, or an error when the backend cannot be queried.
+///
+/// Details:
+/// - OpenRC: `started` entries of `rc-status --all`.
+/// - runit: enabled services in `/run/runit/service` whose `sv status` reports `run`.
+/// - s6: `s6-rc -a list`.
+pub fn fetch_running_services(init: InitSystem) -> Result<BTreeSet<String>, String> {
+    match init {
+        InitSystem::OpenRc => {
+            let out = query("rc-status", &["--all"], &[]).ok_or("failed to run rc-status")?;
+            Ok(parse_rc_status(&out)
+                .into_iter()
+                .filter(|(_, (state, _))| state == "started")
+                .map(|(name, _)| name)
+                .collect())
+        }
+        InitSystem::Runit => {
+            let enabled = dir_names(RUNIT_ENABLED_DIR);
+            Ok(runit_states(&enabled)
+                .into_iter()
+                .filter(|(_, state)| state == "run")
+                .map(|(name, _)| name)
+                .collect())
+        }
+        InitSystem::S6 => {
+            let out = query("s6-rc", &["-a", "list"], &[]).ok_or("failed to run s6-rc")?;
+            Ok(out
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty())
+                .map(str::to_string)
+                .collect())
+        }
+        InitSystem::Systemd | InitSystem::Unknown => Err(format!(
+            "no service backend for init system {}",
+            init.name()
+        )),
+    }
+}
+
+/// What: `sv status` state (`run`, `down`, `fail`, ...) for each enabled runit service.
+fn runit_states(names: &[String]) -> BTreeMap<String, String> {
+    if names.is_empty() {
+        return BTreeMap::new();
+    }
+    let mut args = vec!["status"];
+    args.extend(names.iter().map(String::as_str));
+    query("sv", &args, &[("SVDIR", RUNIT_ENABLED_DIR)])
+        .map(|out| parse_sv_status(&out))
+        .unwrap_or_default()
+}
+
+/// What: Binaries started by each service's init script on a non-systemd init.
+///
+/// Inputs:
+/// - `init`: OpenRC, runit, or s6.
+/// - `services`: Service names to inspect.
+///
+/// Output:
+/// - Map from service name to absolute binary paths found in its script.
+///
+/// Details:
+/// - OpenRC reads `command=` from `/etc/init.d/<name>`; runit and s6 read the `run` script in
+///   the service directory.
+pub fn script_binaries(
+    init: InitSystem,
+    services: &BTreeSet<String>,
+) -> BTreeMap<String, Vec<String>> {
+    services
+        .iter()
+        .filter_map(|name| {
+            let (path, openrc) = match init {
+                InitSystem::OpenRc => (format!("{OPENRC_INIT_DIR}/{name}"), true),
+                InitSystem::Runit => (format!("{RUNIT_SV_DIR}/{name}/run"), false),
+                InitSystem::S6 => (format!("{S6_SV_DIR}/{name}/run"), false),
+                InitSystem::Systemd | InitSystem::Unknown => return None,
+            };
+            let script = std::fs::read_to_string(&path).ok()?;
+            let binaries = if openrc {
+                parse_openrc_command(&script)
+            } else {
+                parse_run_script(&script)
+            };
+            (!binaries.is_empty()).then(|| (name.clone(), binaries))
+        })
+        .collect()
+}
+
+/// What: Query the current active/enabled state of the given services.
+///
+/// Inputs:
+/// - `init`: Detected init system.
+/// - `units`: Service names as produced by [`service_name_for_path`].
+///
+/// Output:
+/// - Map from service name to `(active_state, enabled_state)`; services the init system does
+///   not report are absent.
+///
+/// Details:
+/// - systemd: one `systemctl is-active` call for all units (it prints a state even for unknown
+///   units) and one `systemctl is-enabled` per unit, since a unit without a unit file stops a
+///   batched `is-enabled` and would shift the remaining states; such units read `not-found`.
+/// - OpenRC: `rc-service <name> status` per script found in `/etc/init.d`, with the runlevel
+///   from `rc-status --all` as the enablement.
+/// - runit: `sv status`; enabled when linked into `/run/runit/service`.
+/// - s6: membership in `s6-rc -a list`; enabled when in the `default` bundle.
+pub fn fetch_unit_states(init: InitSystem, units: &[String]) -> BTreeMap<String, (String, String)> {
+    if units.is_empty() {
+        return BTreeMap::new();
+    }
+    match init {
+        InitSystem::Systemd => {
+            let mut args = vec!["is-active"];
+            args.extend(units.iter().map(String::as_str));
+            let active = query("systemctl", &args, &[])
+                .map(|out| parse_systemctl_states(&out))
+                .unwrap_or_default();
+            let enabled: Vec<String> = units
+                .iter()
+                .map_while(|unit| {
+                    query("systemctl", &["is-enabled", unit], &[]).map(|out| {
+                        parse_systemctl_states(&out)
+                            .into_iter()
+                            .next()
+                            .unwrap_or_else(|| "not-found".to_string())
+                    })
+                })
+                .collect();
+            units
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i < active.len() || *i < enabled.len())
+                .map(|(i, unit)| {
+                    let active = active.get(i).cloned().unwrap_or_default();
+                    let enabled = enabled.get(i).cloned().unwrap_or_default();
+                    (unit.clone(), (active, enabled))
+                })
+                .collect()
+        }
+        InitSystem::OpenRc => {
+            let runlevels = query("rc-status", &["--all"], &[])
+                .map(|out| parse_rc_status(&out))
+                .unwrap_or_default();
+            let scripts: BTreeSet<String> = dir_names(OPENRC_INIT_DIR).into_iter().collect();
+            units
+                .iter()
+                .filter(|name| scripts.contains(*name))
+                .map(|name| {
+                    let active = query("rc-service", &[name, "status"], &[])
+                        .and_then(|out| parse_rc_service_status(&out))
+                        .unwrap_or_default();
+                    let enabled = runlevels
+                        .get(name)
+                        .map(|(_, level)| level.clone())
+                        .unwrap_or_else(|| "disabled".to_string());
+                    (name.clone(), (active, enabled))
+                })
+                .collect()
+        }
+        InitSystem::Runit => {
+            let enabled: BTreeSet<String> = dir_names(RUNIT_ENABLED_DIR).into_iter().collect();
+            let linked: Vec<String> = units
+                .iter()
+                .filter(|u| enabled.contains(*u))
+                .cloned()
+                .collect();
+            let states = runit_states(&linked);
+            units
+                .iter()
+                .map(|name| {
+                    let (active, enabled) = match states.get(name) {
+                        Some(state) => (state.clone(), "enabled"),
+                        None if enabled.contains(name) => (String::new(), "enabled"),
+                        None => ("down".to_string(), "disabled"),
+                    };
+                    (name.clone(), (active, enabled.to_string()))
+                })
+                .collect()
+        }
+        InitSystem::S6 => {
+            let running = fetch_running_services(init).unwrap_or_default();
+            let enabled: BTreeSet<String> = dir_names(S6_ENABLED_DIR).into_iter().collect();
+            units
+                .iter()
+                .map(|name| {
+                    let active = if running.contains(name) {
+                        "active"
+                    } else {
+                        "inactive"
+                    };
+                    let on = if enabled.contains(name) {
+                        "enabled"
+                    } else {
+                        "disabled"
+                    };
+                    (name.clone(), (active.to_string(), on.to_string()))
+                })
+                .collect()
+        }
+        InitSystem::Unknown => BTreeMap::new(),
+    }
+}
+
+/// What: Whether a raw active state means the service is running on any backend.
+pub fn is_running_state(state: &str) -> bool {
+    matches!(state, "active" | "started" | "run")
+}
+
+/// What: Split `systemctl is-active`/`is-enabled` output into one state per queried unit.
+///
+/// Output:
+/// - Trimmed, non-empty lines in query order.
+fn parse_systemctl_states(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// What: Parse `rc-status --all` into per-service states.
+///
+/// Inputs:
+/// - `output`: Raw stdout, grouped under `Runlevel: <name>` / `Dynamic Runlevel: <name>` headers
+///   with service lines like ` sshd   [  started  ]`.
+///
+/// Output:
+/// - Map from service name to `(state, runlevel)`; services only seen under a dynamic runlevel
+///   (hotplugged/needed/manual) get `disabled` as their enablement.
+///
+/// Details:
+/// - A service listed in several runlevels keeps the first static runlevel it appears in.
+fn parse_rc_status(output: &str) -> BTreeMap<String, (String, String)> {
+    let mut states: BTreeMap<String, (String, String)> = BTreeMap::new();
+    let mut runlevel: Option<String> = None;
+    for line in output.lines() {
+        if let Some(level) = line.strip_prefix("Runlevel:") {
+            runlevel = Some(level.trim().to_string());
+            continue;
+        }
+        if line.starts_with("Dynamic Runlevel:") {
+            runlevel = None;
+            continue;
+        }
+        let Some((name, rest)) = line.trim().split_once(char::is_whitespace) else {
+            continue;
+        };
+        let state = rest
+            .trim()
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .trim();
+        if state.is_empty() {
+            continue;
+        }
+        let entry = states
+            .entry(name.to_string())
+            .or_insert_with(|| (state.to_string(), "disabled".to_string()));
+        if entry.1 == "disabled"
+            && let Some(level) = &runlevel
+        {
+            entry.1 = level.clone();
+        }
+    }
+    states
+}
+
+/// What: Extract the state from `rc-service <name> status` (` * status: started`).
+fn parse_rc_service_status(output: &str) -> Option<String> {
+    output.lines().find_map(|line| {
+        line.trim_start_matches([' ', '*'])
+            .strip_prefix("status:")
+            .map(|s| s.trim().to_string())
+    })
+}
+
+/// What: Parse `sv status` lines (`run: sshd: (pid 412) 3600s`) into per-service states.
+///
+/// Details:
+/// - Service names are reduced to their last path component, since `sv` echoes whatever form
+///   it was given; `warning:` lines for missing services are skipped.
+fn parse_sv_status(output: &str) -> BTreeMap<String, String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, ':');
+            let state = parts.next()?.trim();
+            let name = parts.next()?.trim();
+            if state == "warning" || name.is_empty() {
+                return None;
+            }
+            let name = name.rsplit('/').next().unwrap_or(name);
+            Some((name.to_string(), state.to_string()))
+        })
+        .collect()
+}
+
+/// What: Absolute path of `command=` in an OpenRC init script.
+fn parse_openrc_command(script: &str) -> Vec<String> {
+    script
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("command="))
+        .map(|value| value.trim().trim_matches(['"', '\'']))
+        .filter(|value| value.starts_with('/'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// What: Binaries `exec`'d by a runit/s6 `run` script.
+///
+/// Details:
+/// - Takes the first absolute path on `exec` lines (so `exec chpst -u x /usr/bin/foo` yields
+///   `/usr/bin/foo`), or the first token of lines that start with an absolute path (execline).
+fn parse_run_script(script: &str) -> Vec<String> {
+    let mut binaries = Vec::new();
+    for line in script.lines().map(str::trim) {
+        if line.starts_with('#') {
+            continue;
+        }
+        let mut tokens = line.split_whitespace();
+        let found = match tokens.next() {
+            Some("exec") => tokens.find(|t| t.starts_with('/')),
+            Some(first) if first.starts_with('/') => Some(first),
+            _ => None,
+        };
+        if let Some(bin) = found
+            && !binaries.iter().any(|b| b == bin)
+        {
+            binaries.push(bin.to_string());
+        }
+    }
+    binaries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure detection order and per-backend service paths.
+    ///
+    /// Inputs:
+    /// - Simulated runtime directories for each init and packaged file paths for each layout.
+    ///
+    /// Output:
+    /// - systemd wins when present, then OpenRC, runit, s6; each backend only recognises its own
+    ///   service layout.
+    fn detection_and_service_paths_per_backend() {
+        assert_eq!(
+            detect_with(|p| p == "/run/systemd/system" || p == "/run/openrc"),
+            InitSystem::Systemd
+        );
+        assert_eq!(detect_with(|p| p == "/run/openrc"), InitSystem::OpenRc);
+        assert_eq!(detect_with(|p| p == "/run/runit"), InitSystem::Runit);
+        assert_eq!(detect_with(|p| p == "/run/s6-rc"), InitSystem::S6);
+        assert_eq!(detect_with(|_| false), InitSystem::Unknown);
+
+        let name = service_name_for_path;
+        assert_eq!(
+            name(InitSystem::Systemd, "/usr/lib/systemd/system/sshd.service").as_deref(),
+            Some("sshd.service")
+        );
+        assert_eq!(name(InitSystem::Systemd, "/etc/init.d/sshd"), None);
+        assert_eq!(
+            name(InitSystem::OpenRc, "/etc/init.d/sshd").as_deref(),
+            Some("sshd")
+        );
+        assert_eq!(name(InitSystem::OpenRc, "/etc/init.d"), None);
+        assert_eq!(
+            name(InitSystem::Runit, "/etc/runit/sv/sshd/run").as_deref(),
+            Some("sshd")
+        );
+        assert_eq!(
+            name(InitSystem::S6, "/etc/s6/sv/sshd-srv").as_deref(),
+            Some("sshd-srv")
+        );
+        assert_eq!(name(InitSystem::Runit, "/etc/runit/sv"), None);
+    }
+
+    #[test]
+    /// What: Ensure backend status outputs and scripts are parsed.
+    ///
+    /// Inputs:
+    /// - `systemctl is-active`, `rc-status --all`, `rc-service status`, and `sv status` samples,
+    ///   plus an OpenRC script and a runit `run` script.
+    ///
+    /// Output:
+    /// - Per-service states, OpenRC runlevels (or `disabled` for dynamic-only services), and the
+    ///   binaries each script starts.
+    fn parse_backend_outputs() {
+        assert_eq!(
+            parse_systemctl_states("active\ninactive\n\nfailed\n"),
+            vec!["active", "inactive", "failed"]
+        );
+        let rc = "\
+Runlevel: default
+ sshd                                                      [  started  ]
+ cronie                                                    [  stopped  ]
+Runlevel: boot
+ hwclock                                                   [  started  ]
+Dynamic Runlevel: hotplugged
+Dynamic Runlevel: manual
+ nginx                                                     [  started  ]
+ cronie                                                    [  stopped  ]
+";
+        let states = parse_rc_status(rc);
+        let get = |name: &str| states.get(name).map(|(a, e)| (a.as_str(), e.as_str()));
+        assert_eq!(get("sshd"), Some(("started", "default")));
+        assert_eq!(get("hwclock"), Some(("started", "boot")));
+        assert_eq!(get("nginx"), Some(("started", "disabled")));
+        assert_eq!(get("cronie"), Some(("stopped", "default")));
+        assert_eq!(
+            parse_rc_service_status(" * status: started\n").as_deref(),
+            Some("started")
+        );
+
+        let sv = parse_sv_status(
+            "run: sshd: (pid 412) 3600s\ndown: /run/runit/service/cronie: 5s, normally up\nwarning: x: unable to open x/supervise/ok\n",
+        );
+        assert_eq!(sv.get("sshd").map(String::as_str), Some("run"));
+        assert_eq!(sv.get("cronie").map(String::as_str), Some("down"));
+        assert_eq!(sv.len(), 2);
+
+        assert_eq!(
+            parse_openrc_command(
+                "#!/sbin/openrc-run\ncommand=\"/usr/bin/sshd\"\npidfile=/run/sshd.pid\n"
+            ),
+            vec!["/usr/bin/sshd"]
+        );
+        assert_eq!(
+            parse_run_script(
+                "#!/bin/sh\n# comment\nssh-keygen -A\nexec chpst -u root /usr/bin/sshd -D\n"
+            ),
+            vec!["/usr/bin/sshd"]
+        );
+    }
+}
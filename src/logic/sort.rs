@@ -10,6 +10,8 @@ use crate::state::{AppState, SortMode, Source};
 ///
 /// Details:
 /// - Supports multiple sort strategies, including repo ordering, AUR popularity, and match ranking heuristics.
+/// - With `fuzzy_find` enabled, `BestMatches` ranks by fuzzy score so `rpg` surfaces `ripgrep`.
+/// - `sort_descending` reverses the finished order.
 pub fn sort_results_preserve_selection(app: &mut AppState) {
     if app.results.is_empty() {
         return;
@@ -55,11 +57,23 @@ pub fn sort_results_preserve_selection(app: &mut AppState) {
         SortMode::BestMatches => {
             // Compute simple match rank based on current input; lower is better
             let ql = app.input.trim().to_lowercase();
+            let fuzzy = app.fuzzy_find;
             app.results.sort_by(|a, b| {
-                let ra = crate::util::match_rank(&a.name, &ql);
-                let rb = crate::util::match_rank(&b.name, &ql);
-                if ra != rb {
-                    return ra.cmp(&rb);
+                if fuzzy {
+                    // Higher fuzzy score first; non-matches sink to the bottom
+                    let score = |name: &str| {
+                        crate::logic::filter::fuzzy_score(name, &ql).map_or(i64::MIN, |(s, _)| s)
+                    };
+                    let (fa, fb) = (score(&a.name), score(&b.name));
+                    if fa != fb {
+                        return fb.cmp(&fa);
+                    }
+                } else {
+                    let ra = crate::util::match_rank(&a.name, &ql);
+                    let rb = crate::util::match_rank(&b.name, &ql);
+                    if ra != rb {
+                        return ra.cmp(&rb);
+                    }
                 }
                 // Tiebreak: keep pacman repo order first to keep layout familiar
                 let oa = crate::util::repo_order(&a.source);
@@ -71,6 +85,9 @@ pub fn sort_results_preserve_selection(app: &mut AppState) {
             });
         }
     }
+    if app.sort_descending {
+        app.results.reverse();
+    }
     if let Some(name) = prev_name {
         if let Some(pos) = app.results.iter().position(|p| p.name == name) {
             app.selected = pos;
@@ -82,6 +99,25 @@ pub fn sort_results_preserve_selection(app: &mut AppState) {
     }
 }
 
+/// What: Apply a click on a Results column header.
+///
+/// Inputs:
+/// - `app`: Mutable application state (results, sort mode and direction)
+/// - `mode`: Sort mode bound to the clicked header cell
+///
+/// Output:
+/// - Switches to `mode` in ascending order, or flips the direction when `mode` is already active,
+///   then re-sorts preserving the selection.
+pub fn sort_by_header(app: &mut AppState, mode: SortMode) {
+    if app.sort_mode == mode {
+        app.sort_descending = !app.sort_descending;
+    } else {
+        app.sort_mode = mode;
+        app.sort_descending = false;
+    }
+    sort_results_preserve_selection(app);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -213,4 +249,40 @@ mod tests {
         let names: Vec<String> = app.results.iter().map(|p| p.name.clone()).collect();
         assert_eq!(names, vec!["aurA", "aurB", "z_off", "a_off"]);
     }
+
+    #[test]
+    /// What: Ensure header clicks switch sort modes ascending and flip direction on repeat clicks.
+    ///
+    /// Inputs:
+    /// - Official items from two repos and the query `c`; header clicks on `RepoThenName` twice,
+    ///   then `BestMatches`.
+    ///
+    /// Output:
+    /// - Ascending repo order, then the exact reverse, then the exact match first (rest in repo
+    ///   order) after switching to relevance ascending.
+    fn sort_by_header_toggles_direction() {
+        let mut app = AppState {
+            sort_mode: SortMode::BestMatches,
+            input: "c".into(),
+            ..Default::default()
+        };
+        app.results = vec![
+            item_official("b", "extra"),
+            item_official("a", "core"),
+            item_official("c", "extra"),
+        ];
+        let names = |app: &AppState| -> Vec<String> {
+            app.results.iter().map(|p| p.name.clone()).collect()
+        };
+        sort_by_header(&mut app, SortMode::RepoThenName);
+        assert!(!app.sort_descending);
+        assert_eq!(names(&app), vec!["a", "b", "c"]);
+        sort_by_header(&mut app, SortMode::RepoThenName);
+        assert!(app.sort_descending);
+        assert_eq!(names(&app), vec!["c", "b", "a"]);
+        sort_by_header(&mut app, SortMode::BestMatches);
+        assert_eq!(app.sort_mode, SortMode::BestMatches);
+        assert!(!app.sort_descending);
+        assert_eq!(names(&app), vec!["c", "a", "b"]);
+    }
 }
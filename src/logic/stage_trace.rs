@@ -0,0 +1,165 @@
+//! Tracing layer that tracks active resolution stages for the status line.
+//!
+//! Resolver spans carry `stage` and `item_count` fields (e.g. `resolve_file_changes` with
+//! `stage = "files"`); events inside them may carry `item_index` to report progress. The
+//! [`StageLayer`] mirrors those fields into a process-wide list read by [`active_stages`].
+
+use std::sync::Mutex;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Progress of one open resolver span.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StageProgress {
+    /// Value of the span's `stage` field (e.g. `files`).
+    pub stage: String,
+    /// Highest `item_index` reported by events inside the span.
+    pub done: u64,
+    /// Value of the span's `item_count` field.
+    pub total: u64,
+}
+
+/// Open stage spans in creation order, keyed by span id.
+static ACTIVE: Mutex<Vec<(u64, StageProgress)>> = Mutex::new(Vec::new());
+
+/// Collects the stage-related fields of a span or event.
+#[derive(Default)]
+struct StageFields {
+    stage: Option<String>,
+    item_count: Option<u64>,
+    item_index: Option<u64>,
+}
+
+impl Visit for StageFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "stage" {
+            self.stage = Some(value.to_string());
+        }
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        match field.name() {
+            "item_count" => self.item_count = Some(value),
+            "item_index" => self.item_index = Some(value),
+            _ => {}
+        }
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        if let Ok(v) = u64::try_from(value) {
+            self.record_u64(field, v);
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+}
+
+/// Layer recording resolver spans that declare a `stage` field.
+pub struct StageLayer;
+
+impl<S> Layer<S> for StageLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, _ctx: Context<'_, S>) {
+        let mut fields = StageFields::default();
+        attrs.record(&mut fields);
+        let Some(stage) = fields.stage else {
+            return;
+        };
+        if let Ok(mut active) = ACTIVE.lock() {
+            active.push((
+                id.into_u64(),
+                StageProgress {
+                    stage,
+                    done: 0,
+                    total: fields.item_count.unwrap_or(0),
+                },
+            ));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut fields = StageFields::default();
+        event.record(&mut fields);
+        let Some(index) = fields.item_index else {
+            return;
+        };
+        let Some(scope) = ctx.event_scope(event) else {
+            return;
+        };
+        if let Ok(mut active) = ACTIVE.lock() {
+            for span in scope {
+                let id = span.id().into_u64();
+                if let Some((_, progress)) = active.iter_mut().find(|(sid, _)| *sid == id) {
+                    progress.done = progress.done.max(index);
+                    break;
+                }
+            }
+        }
+    }
+
+    fn on_close(&self, id: Id, _ctx: Context<'_, S>) {
+        if let Ok(mut active) = ACTIVE.lock() {
+            active.retain(|(sid, _)| *sid != id.into_u64());
+        }
+    }
+}
+
+/// What: Snapshot the resolver stages currently running.
+///
+/// Output:
+/// - Open stage spans in the order they started; empty when idle or when the layer is not
+///   installed.
+pub fn active_stages() -> Vec<StageProgress> {
+    ACTIVE
+        .lock()
+        .map(|active| active.iter().map(|(_, p)| p.clone()).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    /// What: Ensure stage spans are tracked with progress and dropped when closed.
+    ///
+    /// Inputs:
+    /// - A `files` span over 12 items emitting `item_index = 3`, and a span without `stage`.
+    ///
+    /// Output:
+    /// - `files 3/12` while the span is open; nothing after it closes.
+    ///
+    /// Details:
+    /// - Uses a thread-local default subscriber so other tests are unaffected.
+    fn stage_layer_tracks_open_spans() {
+        let subscriber = tracing_subscriber::registry().with(StageLayer);
+        tracing::subscriber::with_default(subscriber, || {
+            let span =
+                tracing::info_span!("resolve_file_changes", stage = "files", item_count = 12)
+                    .entered();
+            let plain = tracing::info_span!("unrelated", item_count = 1).entered();
+            tracing::info!(item_index = 3, "resolving");
+            let ours: Vec<StageProgress> = active_stages()
+                .into_iter()
+                .filter(|p| p.stage == "files")
+                .collect();
+            assert_eq!(
+                ours,
+                vec![StageProgress {
+                    stage: "files".into(),
+                    done: 3,
+                    total: 12
+                }]
+            );
+            drop(plain);
+            drop(span);
+            assert!(!active_stages().iter().any(|p| p.stage == "files"));
+        });
+    }
+}
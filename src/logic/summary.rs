@@ -31,7 +31,9 @@ pub struct PostSummaryData {
 /// Details:
 /// - Used internally by summary helpers to keep command invocation boilerplate centralized.
 fn run_pacman(args: &[&str]) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
-    let out = std::process::Command::new("pacman").args(args).output()?;
+    let out = std::process::Command::new(crate::install::privilege::pacman_bin())
+        .args(args)
+        .output()?;
     if !out.status.success() {
         return Err(format!("pacman {:?} exited with {:?}", args, out.status).into());
     }
@@ -0,0 +1,150 @@
+//! Line diff between two text files, used to preview a config against its `.pacnew`.
+
+use std::path::Path;
+
+/// One line of a unified diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine {
+    /// Present in both files.
+    Same(String),
+    /// Only in the old file.
+    Removed(String),
+    /// Only in the new file.
+    Added(String),
+}
+
+/// Above this many cells in the LCS table the changed middle is shown as a block replace.
+const MAX_TABLE_CELLS: usize = 4_000_000;
+
+/// What: Read a file as text for diffing.
+///
+/// Output:
+/// - The contents; `None` when the file cannot be read, is not UTF-8, or contains NUL bytes.
+pub fn read_text(path: &Path) -> Option<String> {
+    let bytes = std::fs::read(path).ok()?;
+    if bytes.contains(&0) {
+        return None;
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// What: Diff two texts line by line.
+///
+/// Inputs:
+/// - `old`: Original text (the live config).
+/// - `new`: Replacement text (the `.pacnew`).
+///
+/// Output:
+/// - Every line of both texts in order, tagged as same, removed, or added; removals come before
+///   additions within a changed block.
+///
+/// Details:
+/// - Common leading and trailing lines are matched first; the rest uses a longest common
+///   subsequence table. Very large changed regions skip the table and show all old lines
+///   removed, then all new lines added.
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+    let prefix = a.iter().zip(&b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (mid_a, mid_b) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+
+    let mut out: Vec<DiffLine> = a[..prefix]
+        .iter()
+        .map(|l| DiffLine::Same((*l).to_string()))
+        .collect();
+    if mid_a.len().saturating_mul(mid_b.len()) > MAX_TABLE_CELLS {
+        out.extend(mid_a.iter().map(|l| DiffLine::Removed((*l).to_string())));
+        out.extend(mid_b.iter().map(|l| DiffLine::Added((*l).to_string())));
+    } else {
+        out.extend(lcs_diff(mid_a, mid_b));
+    }
+    out.extend(
+        a[a.len() - suffix..]
+            .iter()
+            .map(|l| DiffLine::Same((*l).to_string())),
+    );
+    out
+}
+
+/// What: Diff two line slices with a longest common subsequence table.
+fn lcs_diff(a: &[&str], b: &[&str]) -> Vec<DiffLine> {
+    let cols = b.len() + 1;
+    // table[i * cols + j] = LCS length of a[i..] and b[j..]
+    let mut table = vec![0u32; (a.len() + 1) * cols];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            table[i * cols + j] = if a[i] == b[j] {
+                table[(i + 1) * cols + j + 1] + 1
+            } else {
+                table[(i + 1) * cols + j].max(table[i * cols + j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut out = Vec::with_capacity(a.len().max(b.len()));
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            out.push(DiffLine::Same(a[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if table[(i + 1) * cols + j] >= table[i * cols + j + 1] {
+            out.push(DiffLine::Removed(a[i].to_string()));
+            i += 1;
+        } else {
+            out.push(DiffLine::Added(b[j].to_string()));
+            j += 1;
+        }
+    }
+    out.extend(a[i..].iter().map(|l| DiffLine::Removed((*l).to_string())));
+    out.extend(b[j..].iter().map(|l| DiffLine::Added((*l).to_string())));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure the diff keeps shared lines and marks edits, and binary files are refused.
+    ///
+    /// Inputs:
+    /// - A config with one changed and one appended line; a file containing a NUL byte.
+    ///
+    /// Output:
+    /// - Same/removed/added lines in order, an empty diff for identical texts, and `None` when
+    ///   reading the binary or a missing file.
+    fn diff_lines_marks_changes_and_skips_binary() {
+        let old = "# header\nPort 22\nUsePAM yes\n";
+        let new = "# header\nPort 2222\nUsePAM yes\nX11Forwarding no\n";
+        assert_eq!(
+            diff_lines(old, new),
+            vec![
+                DiffLine::Same("# header".into()),
+                DiffLine::Removed("Port 22".into()),
+                DiffLine::Added("Port 2222".into()),
+                DiffLine::Same("UsePAM yes".into()),
+                DiffLine::Added("X11Forwarding no".into()),
+            ]
+        );
+        assert!(
+            diff_lines(old, old)
+                .iter()
+                .all(|l| matches!(l, DiffLine::Same(_)))
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        let bin = dir.path().join("blob");
+        std::fs::write(&bin, b"abc\0def").unwrap();
+        assert_eq!(read_text(&bin), None);
+        assert_eq!(read_text(&dir.path().join("missing")), None);
+        let txt = dir.path().join("a.conf");
+        std::fs::write(&txt, old).unwrap();
+        assert_eq!(read_text(&txt).as_deref(), Some(old));
+    }
+}
@@ -0,0 +1,148 @@
+//! Pending system upgrades (`pacman -Qu`, AUR helper `-Qua`) reviewed in Preflight before `-Syu`.
+
+use std::process::{Command, Stdio};
+
+use crate::state::{PackageItem, Source};
+
+/// What: Parse `-Qu` style output (`<name> <old> -> <new>` per line).
+///
+/// Inputs:
+/// - `output`: Command stdout.
+/// - `aur`: Whether the lines come from an AUR helper (`paru -Qua` / `yay -Qua`).
+///
+/// Output:
+/// - One item per upgrade carrying the new version; official items take repo and description
+///   from the index when it knows the package.
+///
+/// Details:
+/// - Lines marked `[ignored]` (packages in `IgnorePkg`) are skipped; `-Syu` leaves them alone.
+pub fn parse_upgrade_lines(output: &str, aur: bool) -> Vec<PackageItem> {
+    output
+        .lines()
+        .filter(|line| !line.contains("[ignored]"))
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let name = parts.next()?;
+            let version = parts
+                .skip_while(|p| *p != "->")
+                .nth(1)
+                .unwrap_or_default()
+                .to_string();
+            if aur {
+                return Some(PackageItem {
+                    name: name.to_string(),
+                    version,
+                    description: String::new(),
+                    source: Source::Aur,
+                    popularity: None,
+                });
+            }
+            let mut item = crate::index::find_official(name).unwrap_or_else(|| PackageItem {
+                name: name.to_string(),
+                version: String::new(),
+                description: String::new(),
+                source: Source::Official {
+                    repo: String::new(),
+                    arch: String::new(),
+                },
+                popularity: None,
+            });
+            if !version.is_empty() {
+                item.version = version;
+            }
+            Some(item)
+        })
+        .collect()
+}
+
+/// What: Run a `-Qu` style query and return its stdout.
+///
+/// Output:
+/// - Stdout, or an empty string when the command is missing or reports nothing (pacman exits
+///   with 1 when there are no upgrades).
+fn run_upgrade_query(program: &str, args: &[&str]) -> String {
+    tracing::debug!("Running: {} {}", program, args.join(" "));
+    Command::new(program)
+        .args(args)
+        .env("LC_ALL", "C")
+        .env("LANG", "C")
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|out| out.status.success())
+        .map(|out| String::from_utf8_lossy(&out.stdout).into_owned())
+        .unwrap_or_default()
+}
+
+/// What: Packages a system upgrade would touch.
+///
+/// Inputs:
+/// - `official`: Include repo upgrades from `pacman -Qu`.
+/// - `aur`: Include AUR upgrades from the helper's `-Qua`.
+///
+/// Output:
+/// - Official upgrades first, then AUR ones; empty when nothing is pending.
+///
+/// Details:
+/// - `pacman -Qu` reads the local sync databases, so the list reflects the last sync; `-Syyu`
+///   may find a few more packages after refreshing them.
+/// - Runs the configured pacman binary; blocks for the duration of both queries, so callers run
+///   it off the UI thread.
+/// - AUR upgrades come from the first installed helper in `preferred_aur_helper` order that has
+///   an upgrade query, and are skipped when there is none.
+pub fn list_system_upgrades(official: bool, aur: bool) -> Vec<PackageItem> {
+    let mut items = Vec::new();
+    if official {
+        let pacman = crate::install::privilege::pacman_bin();
+        items.extend(parse_upgrade_lines(
+            &run_upgrade_query(&pacman, &["-Qu"]),
+            false,
+        ));
+    }
+    if aur
+        && let Some((helper, args)) = crate::logic::deps::helper_order()
+            .into_iter()
+            .filter_map(|h| h.upgrade_query_args().map(|args| (h, args)))
+            .find(|(h, _)| h.is_available())
+    {
+        let out = run_upgrade_query(helper.binary(), args);
+        for item in parse_upgrade_lines(&out, true) {
+            if !items.iter().any(|p| p.name == item.name) {
+                items.push(item);
+            }
+        }
+    }
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure `-Qu` lines become upgrade items with the new version.
+    ///
+    /// Inputs:
+    /// - Official output with an ignored package and a bare name; AUR helper output.
+    ///
+    /// Output:
+    /// - The ignored line dropped, versions taken after `->`, and AUR lines tagged as AUR.
+    fn parse_upgrade_lines_reads_new_versions() {
+        let official = parse_upgrade_lines(
+            "zzq-linux 6.9.1-1 -> 6.9.2-1\nzzq-vim 9.1-1 -> 9.1-2 [ignored]\nzzq-bare\n",
+            false,
+        );
+        let names: Vec<&str> = official.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["zzq-linux", "zzq-bare"]);
+        assert_eq!(official[0].version, "6.9.2-1");
+        assert!(matches!(official[0].source, Source::Official { .. }));
+        assert!(official[1].version.is_empty());
+
+        let aur = parse_upgrade_lines("yay-bin 12.3.0-1 -> 12.3.5-1\n", true);
+        assert_eq!(aur.len(), 1);
+        assert_eq!(aur[0].version, "12.3.5-1");
+        assert!(matches!(aur[0].source, Source::Aur));
+        assert!(parse_upgrade_lines("", false).is_empty());
+    }
+}
@@ -1,9 +1,11 @@
 //! Pacsea binary entrypoint kept minimal. The full runtime lives in `app`.
 
 use clap::Parser;
+use pacsea::logic::stage_trace::StageLayer;
 use pacsea::{app, theme, util};
 use std::sync::OnceLock;
 use std::{fmt, time::SystemTime};
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 struct PacseaTimer;
 
@@ -43,7 +45,8 @@ struct Args {
     #[arg(long)]
     no_color: bool,
 
-    /// [Not yet implemented] Specify the configuration directory (default: ~/.config/pacsea)
+    /// Use this directory for all config, list, and log files (default: ~/.config/pacsea;
+    /// also read from PACSEA_CONFIG_DIR)
     #[arg(long)]
     config_dir: Option<String>,
 
@@ -88,6 +91,20 @@ struct Args {
 async fn main() {
     let args = Args::parse();
 
+    // Isolated config base (--config-dir / PACSEA_CONFIG_DIR); must be set before anything
+    // touches config_dir(), including the log file below
+    let config_dir_override = args.config_dir.clone().or_else(|| {
+        std::env::var("PACSEA_CONFIG_DIR")
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+    });
+    if let Some(dir) = config_dir_override
+        && let Err(e) = theme::set_config_dir_override(std::path::Path::new(&dir))
+    {
+        eprintln!("pacsea: {e}");
+        std::process::exit(2);
+    }
+
     // Determine log level (verbose flag overrides log_level)
     // PACSEA_PREFLIGHT_TRACE=1 enables TRACE level for detailed preflight timing
     let log_level = if args.verbose {
@@ -119,6 +136,8 @@ async fn main() {
                     .with_ansi(false) // Always disable ANSI for file output
                     .with_writer(non_blocking)
                     .with_timer(PacseaTimer)
+                    .finish()
+                    .with(StageLayer)
                     .init();
                 let _ = LOG_GUARD.set(guard);
                 tracing::info!(path = %log_path.display(), "logging initialized");
@@ -132,6 +151,8 @@ async fn main() {
                     .with_target(false)
                     .with_ansi(!args.no_color)
                     .with_timer(PacseaTimer)
+                    .finish()
+                    .with(StageLayer)
                     .init();
                 tracing::warn!(error = %e, "failed to open log file; using stderr");
             }
@@ -1,6 +1,6 @@
 use serde_json::Value;
 
-use crate::state::{PackageDetails, PackageItem, Source};
+use crate::state::{AurInfo, PackageDetails, PackageItem, Source};
 use crate::util::{arrs, s, ss, u64_of};
 
 type Result<T> = super::Result<T>;
@@ -19,7 +19,7 @@ fn pacman_si(repo: &str, name: &str) -> Result<PackageDetails> {
     } else {
         format!("{repo}/{name}")
     };
-    let out = std::process::Command::new("pacman")
+    let out = std::process::Command::new(crate::install::privilege::pacman_bin())
         .env("LC_ALL", "C")
         .env("LANG", "C")
         .args(["-Si", &spec])
@@ -138,6 +138,7 @@ fn pacman_si(repo: &str, name: &str) -> Result<PackageDetails> {
         owner: map.get("Packager").cloned().unwrap_or_default(),
         build_date: map.get("Build Date").cloned().unwrap_or_default(),
         popularity: None,
+        aur: None,
     };
     Ok(pd)
 }
@@ -207,6 +208,52 @@ pub async fn fetch_details(item: PackageItem) -> Result<PackageDetails> {
     }
 }
 
+/// What: Look up several AUR packages by exact name in one RPC `info` request.
+///
+/// Inputs:
+/// - `names`: Package names to look up.
+///
+/// Output:
+/// - `Ok` with a `PackageItem` for every name the AUR knows (unknown names are simply absent);
+///   `Err` when the request fails.
+///
+/// Details:
+/// - Blocking (runs curl); names are sent as `arg[]` parameters in chunks of 100 to keep URLs
+///   short. Returns nothing when the AUR is disabled.
+pub fn fetch_aur_items_blocking(names: &[String]) -> Result<Vec<PackageItem>> {
+    if super::aur_disabled() {
+        return Ok(Vec::new());
+    }
+    let mut items = Vec::new();
+    for chunk in names.chunks(100) {
+        let query: Vec<String> = chunk
+            .iter()
+            .map(|n| format!("arg[]={}", crate::util::percent_encode(n)))
+            .collect();
+        let url = format!("https://aur.archlinux.org/rpc/v5/info?{}", query.join("&"));
+        let v = super::get_json(&url)?;
+        for obj in v
+            .get("results")
+            .and_then(|x| x.as_array())
+            .into_iter()
+            .flatten()
+        {
+            let name = s(obj, "Name");
+            if name.is_empty() {
+                continue;
+            }
+            items.push(PackageItem {
+                name,
+                version: s(obj, "Version"),
+                description: s(obj, "Description"),
+                source: Source::Aur,
+                popularity: obj.get("Popularity").and_then(|v| v.as_f64()),
+            });
+        }
+    }
+    Ok(items)
+}
+
 /// Fetch AUR package details via the AUR RPC API.
 ///
 /// Inputs: `item` with `Source::Aur`.
@@ -217,19 +264,44 @@ pub async fn fetch_aur_details(item: PackageItem) -> Result<PackageDetails> {
         "https://aur.archlinux.org/rpc/v5/info?arg={}",
         crate::util::percent_encode(&item.name)
     );
-    let v = tokio::task::spawn_blocking(move || super::curl_json(&url)).await??;
+    let v = tokio::task::spawn_blocking(move || super::get_json(&url)).await??;
     let arr = v
         .get("results")
         .and_then(|x| x.as_array())
         .cloned()
         .unwrap_or_default();
     let obj = arr.first().cloned().unwrap_or(Value::Null);
+    Ok(parse_aur_details(&obj, &item))
+}
 
-    let version0 = s(&obj, "Version");
-    let description0 = s(&obj, "Description");
+/// What: Convert one AUR RPC `info` result into `PackageDetails`.
+///
+/// Inputs:
+/// - `obj`: JSON object from the RPC `results` array (`Null` when the AUR returned nothing).
+/// - `item`: Package being looked up; supplies version/description fallbacks.
+///
+/// Output:
+/// - Details labelled `AUR`, with popularity and the [`AurInfo`] trust signals filled in.
+///
+/// Details:
+/// - A missing or `null` `Maintainer` marks the package as orphaned.
+/// - `OutOfDate`, `FirstSubmitted` and `LastModified` are only kept when they are positive
+///   timestamps; `null` or `0` mean "not set".
+fn parse_aur_details(obj: &Value, item: &PackageItem) -> PackageDetails {
+    let timestamp = |key: &str| obj.get(key).and_then(Value::as_i64).filter(|ts| *ts > 0);
+    let version0 = s(obj, "Version");
+    let description0 = s(obj, "Description");
     let popularity0 = obj.get("Popularity").and_then(|v| v.as_f64());
+    let maintainer = Some(s(obj, "Maintainer")).filter(|m| !m.trim().is_empty());
+    let aur = (!obj.is_null()).then(|| AurInfo {
+        votes: u64_of(obj, &["NumVotes"]).unwrap_or(0),
+        maintainer: maintainer.clone(),
+        first_submitted: timestamp("FirstSubmitted"),
+        last_modified: timestamp("LastModified"),
+        out_of_date: timestamp("OutOfDate"),
+    });
 
-    let d = PackageDetails {
+    PackageDetails {
         repository: "AUR".into(),
         name: item.name.clone(),
         version: if version0.is_empty() {
@@ -243,23 +315,23 @@ pub async fn fetch_aur_details(item: PackageItem) -> Result<PackageDetails> {
             description0
         },
         architecture: "any".into(),
-        url: s(&obj, "URL"),
-        licenses: arrs(&obj, &["License", "Licenses"]),
-        groups: arrs(&obj, &["Groups"]),
-        provides: arrs(&obj, &["Provides"]),
-        depends: arrs(&obj, &["Depends"]),
-        opt_depends: arrs(&obj, &["OptDepends"]),
+        url: s(obj, "URL"),
+        licenses: arrs(obj, &["License", "Licenses"]),
+        groups: arrs(obj, &["Groups"]),
+        provides: arrs(obj, &["Provides"]),
+        depends: arrs(obj, &["Depends"]),
+        opt_depends: arrs(obj, &["OptDepends"]),
         required_by: vec![],
         optional_for: vec![],
-        conflicts: arrs(&obj, &["Conflicts"]),
-        replaces: arrs(&obj, &["Replaces"]),
+        conflicts: arrs(obj, &["Conflicts"]),
+        replaces: arrs(obj, &["Replaces"]),
         download_size: None,
         install_size: None,
-        owner: s(&obj, "Maintainer"),
-        build_date: crate::util::ts_to_date(obj.get("LastModified").and_then(|v| v.as_i64())),
+        owner: maintainer.unwrap_or_default(),
+        build_date: crate::util::ts_to_date(timestamp("LastModified")),
         popularity: popularity0,
-    };
-    Ok(d)
+        aur,
+    }
 }
 
 /// Fetch official repository package details via pacman JSON endpoints.
@@ -314,7 +386,7 @@ pub async fn fetch_official_details(
             );
             if let Ok(Ok(val)) = tokio::task::spawn_blocking({
                 let url = url.clone();
-                move || super::curl_json(&url)
+                move || super::get_json(&url)
             })
             .await
             {
@@ -349,6 +421,7 @@ pub async fn fetch_official_details(
             owner: ss(obj, &["packager", "Packager"]).unwrap_or_default(),
             build_date: ss(obj, &["build_date", "BuildDate"]).unwrap_or_default(),
             popularity: None,
+            aur: None,
         };
         return Ok(d);
     }
@@ -402,6 +475,7 @@ mod tests {
                 owner: ss(obj, &["packager", "Packager"]).unwrap_or_default(),
                 build_date: ss(obj, &["build_date", "BuildDate"]).unwrap_or_default(),
                 popularity: None,
+                aur: None,
             }
         }
         let v: serde_json::Value = serde_json::json!({
@@ -460,47 +534,6 @@ mod tests {
     /// Details:
     /// - Validates interplay between helper functions and fallback assignments for missing fields.
     fn sources_details_parse_aur_json_defaults_and_popularity() {
-        fn parse_aur_from_json(
-            obj: &serde_json::Value,
-            item: &crate::state::PackageItem,
-        ) -> crate::state::PackageDetails {
-            use crate::util::{arrs, s};
-            let version0 = s(obj, "Version");
-            let description0 = s(obj, "Description");
-            let popularity0 = obj.get("Popularity").and_then(|v| v.as_f64());
-            crate::state::PackageDetails {
-                repository: "AUR".into(),
-                name: item.name.clone(),
-                version: if version0.is_empty() {
-                    item.version.clone()
-                } else {
-                    version0
-                },
-                description: if description0.is_empty() {
-                    item.description.clone()
-                } else {
-                    description0
-                },
-                architecture: "any".into(),
-                url: s(obj, "URL"),
-                licenses: arrs(obj, &["License", "Licenses"]),
-                groups: arrs(obj, &["Groups"]),
-                provides: arrs(obj, &["Provides"]),
-                depends: arrs(obj, &["Depends"]),
-                opt_depends: arrs(obj, &["OptDepends"]),
-                required_by: vec![],
-                optional_for: vec![],
-                conflicts: arrs(obj, &["Conflicts"]),
-                replaces: arrs(obj, &["Replaces"]),
-                download_size: None,
-                install_size: None,
-                owner: s(obj, "Maintainer"),
-                build_date: crate::util::ts_to_date(
-                    obj.get("LastModified").and_then(|v| v.as_i64()),
-                ),
-                popularity: popularity0,
-            }
-        }
         let obj: serde_json::Value = serde_json::json!({
             "Version": "1.2.3",
             "Description": "cool",
@@ -514,7 +547,7 @@ mod tests {
             source: crate::state::Source::Aur,
             popularity: None,
         };
-        let d = parse_aur_from_json(&obj, &item);
+        let d = super::parse_aur_details(&obj, &item);
         assert_eq!(d.repository, "AUR");
         assert_eq!(d.name, "ripgrep-git");
         assert_eq!(d.version, "1.2.3");
@@ -523,4 +556,51 @@ mod tests {
         assert_eq!(d.url, "https://aur.example/ripgrep");
         assert_eq!(d.popularity, Some(std::f64::consts::PI));
     }
+
+    #[test]
+    /// What: Ensure AUR votes, maintainer and timestamps land in `AurInfo`, including orphans.
+    ///
+    /// Inputs:
+    /// - A maintained package with votes and an `OutOfDate` flag, and an orphaned one with a
+    ///   `null` maintainer and `OutOfDate` of `0`.
+    ///
+    /// Output:
+    /// - Votes/maintainer/timestamps are copied; the orphan has no maintainer, an empty owner and no
+    ///   out-of-date flag; an empty result yields no `AurInfo`.
+    fn sources_details_parse_aur_trust_signals() {
+        let item = crate::state::PackageItem {
+            name: "foo".into(),
+            version: String::new(),
+            description: String::new(),
+            source: crate::state::Source::Aur,
+            popularity: None,
+        };
+        let kept = serde_json::json!({
+            "NumVotes": 42,
+            "Maintainer": "alice",
+            "FirstSubmitted": 1_600_000_000i64,
+            "LastModified": 1_700_000_000i64,
+            "OutOfDate": 1_710_000_000i64
+        });
+        let aur = super::parse_aur_details(&kept, &item).aur.unwrap();
+        assert_eq!(aur.votes, 42);
+        assert_eq!(aur.maintainer.as_deref(), Some("alice"));
+        assert_eq!(aur.first_submitted, Some(1_600_000_000));
+        assert_eq!(aur.last_modified, Some(1_700_000_000));
+        assert_eq!(aur.out_of_date, Some(1_710_000_000));
+
+        let orphan = serde_json::json!({ "Maintainer": null, "OutOfDate": 0 });
+        let d = super::parse_aur_details(&orphan, &item);
+        let aur = d.aur.unwrap();
+        assert_eq!(aur.maintainer, None);
+        assert_eq!(aur.out_of_date, None);
+        assert_eq!(aur.votes, 0);
+        assert!(d.owner.is_empty());
+
+        assert!(
+            super::parse_aur_details(&serde_json::Value::Null, &item)
+                .aur
+                .is_none()
+        );
+    }
 }
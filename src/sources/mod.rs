@@ -1,58 +1,89 @@
 //! Network and system data retrieval module split into submodules.
 
-use crate::util::curl_args;
 use serde_json::Value;
 
 mod details;
 mod news;
 mod pkgbuild;
+pub mod pkgbuild_cache;
 mod search;
 pub mod status;
 
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
-/// What: Fetch JSON from a URL using curl and parse into `serde_json::Value`
+/// Whether the AUR is disabled for this process (`aur_disabled` setting).
+static AUR_DISABLED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+/// What: Enable or disable all AUR access for this process.
 ///
-/// Input: `url` HTTP(S) to request
-/// Output: `Ok(Value)` on success; `Err` if curl fails or the response is not valid JSON
+/// Inputs:
+/// - `disabled`: Value of the `aur_disabled` setting.
+pub fn set_aur_disabled(disabled: bool) {
+    AUR_DISABLED.store(disabled, std::sync::atomic::Ordering::Relaxed);
+}
+
+/// What: Report whether AUR access is disabled.
+///
+/// Output:
+/// - `true` when `aur_disabled` is set; AUR searches and resolution are skipped.
+pub fn aur_disabled() -> bool {
+    AUR_DISABLED.load(std::sync::atomic::Ordering::Relaxed)
+}
+
+/// What: Drop AUR packages from `items` when the AUR is disabled.
 ///
-/// Details: Executes curl with appropriate flags and parses the UTF-8 body with `serde_json`.
-/// On Windows, uses `-k` flag to skip SSL certificate verification.
-fn curl_json(url: &str) -> Result<Value> {
-    let args = curl_args(url, &[]);
-    let out = std::process::Command::new("curl").args(&args).output()?;
-    if !out.status.success() {
-        return Err(format!("curl failed: {:?}", out.status).into());
+/// Inputs:
+/// - `items`: Packages queued for a resolution stage.
+///
+/// Output:
+/// - `items` unchanged (borrowed) when the AUR is enabled; otherwise only official packages.
+pub fn without_aur_if_disabled(
+    items: &[crate::state::PackageItem],
+) -> std::borrow::Cow<'_, [crate::state::PackageItem]> {
+    if aur_disabled() {
+        std::borrow::Cow::Owned(
+            items
+                .iter()
+                .filter(|i| !matches!(i.source, crate::state::Source::Aur))
+                .cloned()
+                .collect(),
+        )
+    } else {
+        std::borrow::Cow::Borrowed(items)
     }
-    let body = String::from_utf8(out.stdout)?;
-    let v: Value = serde_json::from_str(&body)?;
-    Ok(v)
 }
 
-/// What: Fetch plain text from a URL using curl
+/// What: Fetch JSON from a URL through the configured HTTP backend
+///
+/// Input: `url` HTTP(S) to request
+/// Output: `Ok(Value)` on success; `Err` if the request fails or the response is not valid JSON
+/// ([`crate::util::CurlNotFound`] when the curl backend is used and curl is not installed)
+///
+/// Details: Delegates to [`crate::http::get_json`].
+fn get_json(url: &str) -> Result<Value> {
+    crate::http::get_json(url)
+}
+
+/// What: Fetch plain text from a URL through the configured HTTP backend
 ///
 /// Input:
 /// - `url` to request
 ///
 /// Output:
-/// - `Ok(String)` with response body; `Err` if curl or UTF-8 decoding fails
+/// - `Ok(String)` with response body; `Err` if the request or UTF-8 decoding fails
 ///
 /// Details:
-/// - Executes curl with appropriate flags and returns the raw body as a `String`.
-/// - On Windows, uses `-k` flag to skip SSL certificate verification.
-fn curl_text(url: &str) -> Result<String> {
-    let args = curl_args(url, &[]);
-    let out = std::process::Command::new("curl").args(&args).output()?;
-    if !out.status.success() {
-        return Err(format!("curl failed: {:?}", out.status).into());
-    }
-    Ok(String::from_utf8(out.stdout)?)
+/// - Delegates to [`crate::http::get_text`].
+fn get_text(url: &str) -> Result<String> {
+    crate::http::get_text(url)
 }
 
-pub use details::fetch_details;
+pub use details::{fetch_aur_items_blocking, fetch_details};
 pub use news::fetch_arch_news;
 pub use pkgbuild::fetch_pkgbuild_fast;
-pub use search::fetch_all_with_errors;
+pub use search::{
+    cancel_searches_before, fetch_all_with_errors, fetch_all_with_errors_for, search_sync_dbs,
+};
 pub use status::fetch_arch_status_text;
 
 #[cfg(not(target_os = "windows"))]
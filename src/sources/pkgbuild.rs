@@ -10,14 +10,38 @@ type Result<T> = super::Result<T>;
 ///
 /// Output:
 /// - `Ok(String)` with PKGBUILD text when available; `Err` on network or lookup failure.
+///
+/// Details:
+/// - Served from the on-disk PKGBUILD cache when a fresh entry for this name and version exists;
+///   successful downloads are written back to it.
 pub async fn fetch_pkgbuild_fast(item: &PackageItem) -> Result<String> {
+    if let Some(txt) = super::pkgbuild_cache::load(&item.name, Some(&item.version)) {
+        return Ok(txt);
+    }
+    let txt = fetch_pkgbuild_remote(item).await?;
+    let (name, version, body) = (item.name.clone(), item.version.clone(), txt.clone());
+    let _ = tokio::task::spawn_blocking(move || {
+        super::pkgbuild_cache::store(&name, &version, &body);
+    })
+    .await;
+    Ok(txt)
+}
+
+/// What: Download PKGBUILD content from AUR cgit or the official GitLab packaging repos.
+///
+/// Inputs:
+/// - `item`: Package whose PKGBUILD should be retrieved.
+///
+/// Output:
+/// - `Ok(String)` with PKGBUILD text; `Err` on network or lookup failure.
+async fn fetch_pkgbuild_remote(item: &PackageItem) -> Result<String> {
     match &item.source {
         Source::Aur => {
             let url = format!(
                 "https://aur.archlinux.org/cgit/aur.git/plain/PKGBUILD?h={}",
                 percent_encode(&item.name)
             );
-            let res = tokio::task::spawn_blocking(move || super::curl_text(&url)).await??;
+            let res = tokio::task::spawn_blocking(move || super::get_text(&url)).await??;
             Ok(res)
         }
         Source::Official { .. } => {
@@ -28,7 +52,7 @@ pub async fn fetch_pkgbuild_fast(item: &PackageItem) -> Result<String> {
             );
             if let Ok(Ok(txt)) = tokio::task::spawn_blocking({
                 let u = url_main.clone();
-                move || super::curl_text(&u)
+                move || super::get_text(&u)
             })
             .await
             {
@@ -38,7 +62,7 @@ pub async fn fetch_pkgbuild_fast(item: &PackageItem) -> Result<String> {
                 "https://gitlab.archlinux.org/archlinux/packaging/packages/{}/-/raw/master/PKGBUILD",
                 percent_encode(&name)
             );
-            let txt = tokio::task::spawn_blocking(move || super::curl_text(&url_master)).await??;
+            let txt = tokio::task::spawn_blocking(move || super::get_text(&url_master)).await??;
             Ok(txt)
         }
     }
@@ -46,6 +70,8 @@ pub async fn fetch_pkgbuild_fast(item: &PackageItem) -> Result<String> {
 
 #[cfg(not(target_os = "windows"))]
 #[cfg(test)]
+// Shims the `curl` binary on PATH, so only meaningful for the curl backend
+#[cfg(not(feature = "reqwest-backend"))]
 mod tests {
     use super::*;
 
@@ -0,0 +1,232 @@
+//! On-disk cache of fetched PKGBUILDs under `lists_dir/pkgbuild_cache/`.
+//!
+//! Each package is stored as `<name>.pkgbuild` plus a `<name>.meta` JSON sidecar recording the
+//! version and fetch time. Both files are written to a unique temporary name and renamed into
+//! place, so concurrent fetches of the same package never leave a torn or mixed entry behind.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Freshness window in seconds; `0` (the value before settings are applied) disables the cache.
+static TTL_SECS: AtomicU64 = AtomicU64::new(0);
+
+/// Per-process counter making temporary file names unique across threads.
+static TMP_SEQ: AtomicU64 = AtomicU64::new(0);
+
+/// Sidecar metadata stored next to each cached PKGBUILD.
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheMeta {
+    /// Package version the PKGBUILD was fetched for (empty when unknown).
+    #[serde(default)]
+    version: String,
+    /// Fetch time in seconds since the Unix epoch.
+    fetched_at: u64,
+    /// Byte length of the PKGBUILD file, used to detect a body/sidecar mismatch.
+    len: usize,
+}
+
+/// What: Configure how long cached PKGBUILDs stay fresh.
+///
+/// Inputs:
+/// - `secs`: TTL from `pkgbuild_cache_ttl_secs`; `0` disables reads and writes.
+pub fn set_ttl_secs(secs: u64) {
+    TTL_SECS.store(secs, Ordering::Relaxed);
+}
+
+/// What: Directory holding cached PKGBUILDs.
+///
+/// Output:
+/// - `lists_dir/pkgbuild_cache` (not created by this call).
+pub fn cache_dir() -> PathBuf {
+    crate::theme::lists_dir().join("pkgbuild_cache")
+}
+
+/// What: Current time in whole seconds since the Unix epoch.
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// What: Map a package name to a safe file stem.
+///
+/// Details:
+/// - Package names are already filename-safe; path separators are replaced defensively.
+fn file_stem(name: &str) -> String {
+    name.replace(['/', '\\'], "_")
+}
+
+/// What: Serve a cached PKGBUILD when it is fresh enough.
+///
+/// Inputs:
+/// - `name`: Package name.
+/// - `version`: Expected version; `None` or empty accepts any cached version.
+///
+/// Output:
+/// - `Some(text)` on a fresh hit; `None` when disabled, missing, stale, or mismatched.
+pub fn load(name: &str, version: Option<&str>) -> Option<String> {
+    let ttl = TTL_SECS.load(Ordering::Relaxed);
+    if ttl == 0 {
+        return None;
+    }
+    load_in(&cache_dir(), name, version, ttl, now_secs())
+}
+
+/// What: Store a freshly fetched PKGBUILD.
+///
+/// Inputs:
+/// - `name`: Package name.
+/// - `version`: Version the text belongs to (empty when unknown).
+/// - `text`: PKGBUILD content.
+///
+/// Output:
+/// - None; failures are logged and otherwise ignored since the cache is best-effort.
+pub fn store(name: &str, version: &str, text: &str) {
+    if TTL_SECS.load(Ordering::Relaxed) == 0 {
+        return;
+    }
+    if let Err(e) = store_in(&cache_dir(), name, version, text, now_secs()) {
+        tracing::debug!(package = name, error = %e, "failed to cache PKGBUILD");
+    }
+}
+
+/// What: Drop the cached PKGBUILD of a package so the next fetch hits the network.
+///
+/// Inputs:
+/// - `name`: Package name.
+///
+/// Details:
+/// - Used by the PKGBUILD reload button to force a fresh download.
+pub fn invalidate(name: &str) {
+    let dir = cache_dir();
+    let stem = file_stem(name);
+    let _ = std::fs::remove_file(dir.join(format!("{stem}.meta")));
+    let _ = std::fs::remove_file(dir.join(format!("{stem}.pkgbuild")));
+}
+
+/// What: Cache lookup against an explicit directory and clock.
+///
+/// Inputs:
+/// - `dir`: Cache directory.
+/// - `name`/`version`: As in [`load`].
+/// - `ttl_secs`: Freshness window.
+/// - `now`: Current time in seconds since the epoch.
+///
+/// Output:
+/// - `Some(text)` when the sidecar is younger than `ttl_secs`, the versions agree, and the
+///   body length matches the sidecar.
+fn load_in(
+    dir: &Path,
+    name: &str,
+    version: Option<&str>,
+    ttl_secs: u64,
+    now: u64,
+) -> Option<String> {
+    let stem = file_stem(name);
+    let meta_raw = std::fs::read_to_string(dir.join(format!("{stem}.meta"))).ok()?;
+    let meta: CacheMeta = serde_json::from_str(&meta_raw).ok()?;
+    if now.saturating_sub(meta.fetched_at) >= ttl_secs {
+        return None;
+    }
+    if let Some(v) = version.filter(|v| !v.is_empty())
+        && !meta.version.is_empty()
+        && meta.version != v
+    {
+        return None;
+    }
+    let text = std::fs::read_to_string(dir.join(format!("{stem}.pkgbuild"))).ok()?;
+    (text.len() == meta.len).then_some(text)
+}
+
+/// What: Write `contents` to `path` via a uniquely named temporary file and a rename.
+fn write_atomic(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let seq = TMP_SEQ.fetch_add(1, Ordering::Relaxed);
+    let file_name = path
+        .file_name()
+        .map(|f| f.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let tmp = path.with_file_name(format!(".{file_name}.{}.{seq}.tmp", std::process::id()));
+    std::fs::write(&tmp, contents)?;
+    std::fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = std::fs::remove_file(&tmp);
+    })
+}
+
+/// What: Cache write against an explicit directory and clock.
+///
+/// Inputs:
+/// - `dir`: Cache directory (created if missing).
+/// - `name`/`version`/`text`: As in [`store`].
+/// - `now`: Fetch time recorded in the sidecar.
+///
+/// Output:
+/// - `Ok(())` when both files were written.
+///
+/// Details:
+/// - The body is renamed into place before the sidecar; a reader that sees the new sidecar with
+///   an older body rejects the entry through the length check.
+fn store_in(dir: &Path, name: &str, version: &str, text: &str, now: u64) -> std::io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    let stem = file_stem(name);
+    write_atomic(&dir.join(format!("{stem}.pkgbuild")), text.as_bytes())?;
+    let meta = CacheMeta {
+        version: version.to_string(),
+        fetched_at: now,
+        len: text.len(),
+    };
+    let meta_json = serde_json::to_string(&meta).map_err(std::io::Error::other)?;
+    write_atomic(&dir.join(format!("{stem}.meta")), meta_json.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure cached PKGBUILDs are served only while fresh and for the matching version.
+    ///
+    /// Inputs:
+    /// - An entry for `ripgrep` 14.1.0-1 stored at t=1000 in a temp directory.
+    ///
+    /// Output:
+    /// - Hits within the TTL for the same or unknown version; misses when stale, for another
+    ///   version, or when the body no longer matches the sidecar length.
+    ///
+    /// Details:
+    /// - Also stores from several threads at once and checks the entry stays consistent.
+    fn pkgbuild_cache_respects_ttl_version_and_length() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let text = "pkgname=ripgrep\npkgver=14.1.0\n";
+        store_in(dir.path(), "ripgrep", "14.1.0-1", text, 1000).expect("store");
+
+        let hit = |version, now| load_in(dir.path(), "ripgrep", version, 60, now);
+        assert_eq!(hit(Some("14.1.0-1"), 1030).as_deref(), Some(text));
+        assert_eq!(hit(None, 1059).as_deref(), Some(text));
+        assert!(hit(Some("14.1.0-1"), 1060).is_none());
+        assert!(hit(Some("15.0.0-1"), 1030).is_none());
+        assert!(load_in(dir.path(), "missing", None, 60, 1030).is_none());
+
+        std::fs::write(dir.path().join("ripgrep.pkgbuild"), "truncated").expect("write");
+        assert!(hit(None, 1030).is_none());
+
+        std::thread::scope(|s| {
+            for i in 0..8 {
+                let path = dir.path();
+                s.spawn(move || {
+                    let body = format!("pkgname=ripgrep\npkgrel={i}\n");
+                    store_in(path, "ripgrep", "", &body, 2000).expect("store");
+                });
+            }
+        });
+        let cached = load_in(dir.path(), "ripgrep", None, 60, 2001).expect("consistent entry");
+        assert!(cached.starts_with("pkgname=ripgrep\npkgrel="));
+        let leftovers = std::fs::read_dir(dir.path())
+            .expect("read_dir")
+            .flatten()
+            .filter(|e| e.file_name().to_string_lossy().ends_with(".tmp"))
+            .count();
+        assert_eq!(leftovers, 0);
+    }
+}
@@ -1,5 +1,87 @@
+use std::io::Read;
+use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+
 use crate::state::{PackageItem, Source};
-use crate::util::{percent_encode, s};
+use crate::util::{curl_command, percent_encode, s};
+
+/// Shared handle to a running search `curl`; `None` once it finished or was killed.
+type ChildSlot = Arc<Mutex<Option<Child>>>;
+
+/// Search `curl` processes still running, with the query id that started each.
+static IN_FLIGHT: Mutex<Vec<(u64, ChildSlot)>> = Mutex::new(Vec::new());
+
+/// What: Lock the in-flight registry, recovering it if a holder panicked.
+fn in_flight() -> std::sync::MutexGuard<'static, Vec<(u64, ChildSlot)>> {
+    IN_FLIGHT.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// What: Kill search requests started for queries older than `id`.
+///
+/// Input:
+/// - `id` query id of the search that supersedes the running ones
+///
+/// Output:
+/// - Number of `curl` processes killed
+///
+/// Details:
+/// - Killed children are reaped here; their fetches return no items and no errors, so a
+///   superseded search never raises a network alert.
+pub fn cancel_searches_before(id: u64) -> usize {
+    let stale: Vec<ChildSlot> = {
+        let mut reg = in_flight();
+        let (old, keep): (Vec<_>, Vec<_>) = reg.drain(..).partition(|(qid, _)| *qid < id);
+        *reg = keep;
+        old.into_iter().map(|(_, slot)| slot).collect()
+    };
+    let mut killed = 0;
+    for slot in stale {
+        let mut guard = slot.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(mut child) = guard.take() {
+            let _ = child.kill();
+            let _ = child.wait();
+            killed += 1;
+        }
+    }
+    killed
+}
+
+/// What: Fetch JSON with `curl` while registering the process under a query id.
+///
+/// Input:
+/// - `url` to request; `id` query id used by [`cancel_searches_before`]; `curl` program to run
+///
+/// Output:
+/// - `Ok(Some(Value))` on success, `Ok(None)` when the request was cancelled, `Err` when curl
+///   fails or the body is not valid JSON
+///
+/// Details:
+/// - Stdout is read without holding the child lock so a cancel can kill the process mid-read.
+fn curl_json_tracked(url: &str, id: u64, curl: &std::path::Path) -> super::Result<Option<Value>> {
+    let mut child = curl_command(url, &[])
+        .program(curl)
+        .spawn(Stdio::piped())
+        .map_err(crate::util::curl_spawn_error)?;
+    let stdout = child.stdout.take();
+    let slot: ChildSlot = Arc::new(Mutex::new(Some(child)));
+    in_flight().push((id, Arc::clone(&slot)));
+    let mut body = String::new();
+    let read = stdout.map(|mut out| out.read_to_string(&mut body));
+    in_flight().retain(|(_, other)| !Arc::ptr_eq(other, &slot));
+    let Some(mut child) = slot.lock().unwrap_or_else(|e| e.into_inner()).take() else {
+        return Ok(None);
+    };
+    let status = child.wait()?;
+    if !status.success() {
+        return Err(format!("curl failed: {status:?}").into());
+    }
+    if let Some(Err(e)) = read {
+        return Err(e.into());
+    }
+    Ok(Some(serde_json::from_str(&body)?))
+}
 
 /// What: Fetch search results from AUR and return items along with any error messages.
 ///
@@ -11,16 +93,47 @@ use crate::util::{percent_encode, s};
 ///
 /// Details:
 /// - Percent-encodes the query and calls the AUR RPC v5 search endpoint in a blocking task, maps up to 200 results into `PackageItem`s, and collects any network/parse failures as error strings.
+/// - Returns no items and no errors without any request when `aur_disabled` is set.
 pub async fn fetch_all_with_errors(query: String) -> (Vec<PackageItem>, Vec<String>) {
+    fetch_search(query, None).await
+}
+
+/// What: Like [`fetch_all_with_errors`], but cancellable through [`cancel_searches_before`].
+///
+/// Input:
+/// - `query` raw query string to search; `id` query id of this search
+///
+/// Output:
+/// - Tuple `(items, errors)`; both empty when a newer query killed the request
+pub async fn fetch_all_with_errors_for(query: String, id: u64) -> (Vec<PackageItem>, Vec<String>) {
+    fetch_search(query, Some(id)).await
+}
+
+/// What: Run the AUR search, tracking the `curl` process when `id` is given.
+///
+/// Details:
+/// - Other HTTP backends cannot be killed mid-request; their superseded results are still
+///   dropped by the query id check in the event loop.
+async fn fetch_search(query: String, id: Option<u64>) -> (Vec<PackageItem>, Vec<String>) {
+    if super::aur_disabled() {
+        return (Vec::new(), Vec::new());
+    }
     let q = percent_encode(query.trim());
     let aur_url = format!("https://aur.archlinux.org/rpc/v5/search?by=name&arg={q}");
 
     let mut items: Vec<PackageItem> = Vec::new();
 
-    let ret = tokio::task::spawn_blocking(move || super::curl_json(&aur_url)).await;
+    let ret = tokio::task::spawn_blocking(move || match id {
+        Some(id) if crate::http::uses_curl() => {
+            curl_json_tracked(&aur_url, id, std::path::Path::new("curl"))
+        }
+        _ => super::get_json(&aur_url).map(Some),
+    })
+    .await;
     let mut errors = Vec::new();
     match ret {
-        Ok(Ok(resp)) => {
+        Ok(Ok(None)) => {}
+        Ok(Ok(Some(resp))) => {
             if let Some(arr) = resp.get("results").and_then(|v| v.as_array()) {
                 for pkg in arr.iter().take(200) {
                     let name = s(pkg, "Name");
@@ -47,9 +160,109 @@ pub async fn fetch_all_with_errors(query: String) -> (Vec<PackageItem>, Vec<Stri
     (items, errors)
 }
 
+/// What: Parse `pacman -Ss` output into official package items.
+///
+/// Inputs:
+/// - `text`: Output such as `core/linux 6.9.1-1 [installed]` followed by an indented description.
+///
+/// Output:
+/// - One item per `repo/name version` header, with the description from the next line.
+fn parse_pacman_ss(text: &str) -> Vec<PackageItem> {
+    let mut items: Vec<PackageItem> = Vec::new();
+    for line in text.lines() {
+        if line.starts_with(char::is_whitespace) {
+            if let Some(last) = items.last_mut()
+                && last.description.is_empty()
+            {
+                last.description = line.trim().to_string();
+            }
+            continue;
+        }
+        let mut parts = line.split_whitespace();
+        let (Some(full), Some(version)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        let Some((repo, name)) = full.split_once('/') else {
+            continue;
+        };
+        items.push(PackageItem {
+            name: name.to_string(),
+            version: version.to_string(),
+            description: String::new(),
+            source: Source::Official {
+                repo: repo.to_string(),
+                arch: String::new(),
+            },
+            popularity: None,
+        });
+    }
+    items
+}
+
+/// What: Search the local sync databases with `pacman -Ss`, without any network access.
+///
+/// Input:
+/// - `query` raw query string
+///
+/// Output:
+/// - Official packages whose name contains the query (case-insensitive); empty when pacman is
+///   unavailable or fails
+///
+/// Details:
+/// - Used as a fallback when the official index is empty or the web search failed. The query is
+///   regex-escaped and matches are narrowed to names, like the index search.
+pub fn search_sync_dbs(query: &str) -> Vec<PackageItem> {
+    let ql = query.trim().to_lowercase();
+    if ql.is_empty() {
+        return Vec::new();
+    }
+    let out = Command::new(crate::install::privilege::pacman_bin())
+        .args(["-Ss", &regex::escape(&ql)])
+        .env("LC_ALL", "C")
+        .env("LANG", "C")
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output();
+    match out {
+        Ok(out) if out.status.success() => parse_pacman_ss(&String::from_utf8_lossy(&out.stdout))
+            .into_iter()
+            .filter(|p| p.name.to_lowercase().contains(&ql))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod local_tests {
+    use super::*;
+
+    #[test]
+    /// What: Verify `pacman -Ss` output parses into official items with descriptions.
+    ///
+    /// Inputs:
+    /// - Two entries, one with an `[installed]` marker and group list, plus a blank line.
+    ///
+    /// Output:
+    /// - Both items with repo, version, and description; markers are ignored.
+    fn search_parse_pacman_ss_output() {
+        let text = "core/linux 6.9.1.arch1-1 (base) [installed]\n    The Linux kernel\n\nextra/linux-docs 6.9.1.arch1-1\n    Documentation\n";
+        let items = parse_pacman_ss(text);
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].name, "linux");
+        assert_eq!(items[0].version, "6.9.1.arch1-1");
+        assert_eq!(items[0].description, "The Linux kernel");
+        assert!(matches!(&items[1].source, Source::Official { repo, .. } if repo == "extra"));
+        assert_eq!(items[1].description, "Documentation");
+    }
+}
+
 #[cfg(not(target_os = "windows"))]
 #[cfg(test)]
+// Shims the `curl` binary on PATH, so only meaningful for the curl backend
+#[cfg(not(feature = "reqwest-backend"))]
 mod tests {
+    use crate::state::{PackageItem, Source};
+
     #[tokio::test]
     #[allow(clippy::await_holding_lock)]
     async fn search_returns_items_on_success_and_error_on_failure() {
@@ -106,4 +319,109 @@ fi
         unsafe { std::env::set_var("PATH", &old_path) };
         let _ = std::fs::remove_dir_all(&root);
     }
+
+    #[tokio::test]
+    #[allow(clippy::await_holding_lock)]
+    /// What: Ensure a newer query kills a running search without reporting an error.
+    ///
+    /// Inputs:
+    /// - A fake `curl` that sleeps for 10 seconds, a request started as query 1, then
+    ///   `cancel_searches_before(2)` once it is registered.
+    ///
+    /// Output:
+    /// - One process killed, the request reporting a cancellation (no body, no error) well before
+    ///   the sleep ends, and nothing left registered.
+    ///
+    /// Details:
+    /// - The shim is passed as the program to run, leaving PATH alone; it `exec`s `sleep` so
+    ///   killing the child also closes its stdout.
+    async fn search_cancelled_by_newer_query_is_silent() {
+        let _guard = crate::sources::test_mutex().lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let curl = dir.path().join("curl");
+        std::fs::write(&curl, "#!/bin/sh\nexec sleep 10\n").unwrap();
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&curl, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        let started = std::time::Instant::now();
+        let search = tokio::task::spawn_blocking(move || {
+            super::curl_json_tracked("https://aur.archlinux.org/rpc/v5/search", 1, &curl)
+        });
+        while super::in_flight().is_empty() && started.elapsed().as_secs() < 5 {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert_eq!(super::cancel_searches_before(1), 0);
+        assert_eq!(super::cancel_searches_before(2), 1);
+        let res = search.await.unwrap();
+
+        assert!(matches!(res, Ok(None)));
+        assert!(started.elapsed().as_secs() < 5);
+        assert!(super::in_flight().is_empty());
+    }
+
+    #[tokio::test]
+    #[allow(clippy::await_holding_lock)]
+    /// What: Ensure no AUR request is made and only official packages remain when the AUR is disabled.
+    ///
+    /// Inputs:
+    /// - `aur_disabled` set, a fake `curl` on PATH that records each invocation, and a mixed
+    ///   official/AUR package list.
+    ///
+    /// Output:
+    /// - Empty AUR results without errors, no `curl` call, and only the official item kept.
+    ///
+    /// Details:
+    /// - Resets the flag before releasing the shared sources test mutex.
+    async fn search_skips_aur_when_disabled() {
+        let _guard = crate::sources::test_mutex().lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let marker = dir.path().join("curl_called");
+        let curl = dir.path().join("curl");
+        std::fs::write(
+            &curl,
+            format!(
+                "#!/bin/sh\ntouch '{}'\necho '{{\"results\":[{{\"Name\":\"yay\"}}]}}'\n",
+                marker.display()
+            ),
+        )
+        .unwrap();
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&curl, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+        let old_path = std::env::var("PATH").unwrap_or_default();
+        unsafe { std::env::set_var("PATH", format!("{}:{old_path}", dir.path().display())) };
+        crate::sources::set_aur_disabled(true);
+
+        let (items, errs) = super::fetch_all_with_errors("yay".into()).await;
+        let item = |name: &str, source: Source| PackageItem {
+            name: name.into(),
+            version: "1".into(),
+            description: String::new(),
+            source,
+            popularity: None,
+        };
+        let mixed = vec![
+            item(
+                "ripgrep",
+                Source::Official {
+                    repo: "extra".into(),
+                    arch: "x86_64".into(),
+                },
+            ),
+            item("yay", Source::Aur),
+        ];
+        let kept = crate::sources::without_aur_if_disabled(&mixed).into_owned();
+
+        crate::sources::set_aur_disabled(false);
+        unsafe { std::env::set_var("PATH", &old_path) };
+
+        assert!(items.is_empty());
+        assert!(errs.is_empty());
+        assert!(!marker.exists());
+        assert_eq!(kept.len(), 1);
+        assert!(matches!(kept[0].source, Source::Official { .. }));
+    }
 }
@@ -12,14 +12,14 @@ type Result<T> = super::Result<T>;
 pub async fn fetch_arch_status_text() -> Result<(String, ArchStatusColor)> {
     // 1) Prefer the official Statuspage API (reliable for active incidents and component states)
     let api_url = "https://status.archlinux.org/api/v2/summary.json";
-    let api_result = tokio::task::spawn_blocking(move || super::curl_json(api_url)).await;
+    let api_result = tokio::task::spawn_blocking(move || super::get_json(api_url)).await;
 
     if let Ok(Ok(v)) = api_result {
         let (mut text, mut color, suffix) = parse_status_api_summary(&v);
 
         // Always fetch HTML to check the visual indicator (rect color/beam) which may differ from API status
         if let Ok(Ok(html)) =
-            tokio::task::spawn_blocking(|| super::curl_text("https://status.archlinux.org")).await
+            tokio::task::spawn_blocking(|| super::get_text("https://status.archlinux.org")).await
         {
             // Extract today's AUR uptime percentage (best-effort)
             let aur_pct_opt = extract_aur_today_percent(&html);
@@ -96,7 +96,7 @@ pub async fn fetch_arch_status_text() -> Result<(String, ArchStatusColor)> {
     // 2) Try the UptimeRobot API endpoint (the actual API the status page uses)
     let uptimerobot_api_url = "https://status.archlinux.org/api/getMonitorList/vmM5ruWEAB";
     let uptimerobot_result =
-        tokio::task::spawn_blocking(move || super::curl_json(uptimerobot_api_url)).await;
+        tokio::task::spawn_blocking(move || super::get_json(uptimerobot_api_url)).await;
 
     if let Ok(Ok(v)) = uptimerobot_result
         && let Some((text, color)) = parse_uptimerobot_api(&v)
@@ -106,7 +106,7 @@ pub async fn fetch_arch_status_text() -> Result<(String, ArchStatusColor)> {
 
     // 3) Fallback: use the existing HTML parser + banner heuristic if APIs are unavailable
     let url = "https://status.archlinux.org";
-    let body = tokio::task::spawn_blocking(move || super::curl_text(url)).await??;
+    let body = tokio::task::spawn_blocking(move || super::get_text(url)).await??;
 
     // Skip AUR homepage keyword heuristic to avoid false outage flags
 
@@ -5,7 +5,7 @@ use std::{collections::HashMap, path::PathBuf, time::Instant};
 
 use crate::state::modal::{CascadeMode, Modal, PreflightAction, ServiceImpact};
 use crate::state::types::{
-    ArchStatusColor, Focus, PackageDetails, PackageItem, RightPaneFocus, SortMode,
+    ArchStatusColor, Focus, PackageDetails, PackageItem, RightPaneFocus, SearchScope, SortMode,
 };
 use crate::theme::KeyMap;
 
@@ -34,6 +34,9 @@ pub struct AppState {
     pub modal: Modal,
     /// Previous modal state (used to restore when closing help/alert modals).
     pub previous_modal: Option<Modal>,
+    /// Most recently closed modal, reopened by the `reopen_modal` keybind.
+    /// Kept apart from `previous_modal` so nested-modal restores never resurrect a stale dialog.
+    pub last_closed_modal: Option<Modal>,
     /// If `true`, show install steps without executing side effects.
     pub dry_run: bool,
     // Recent searches
@@ -47,6 +50,14 @@ pub struct AppState {
     pub last_input_change: Instant,
     /// Last value persisted for the input field, to avoid redundant writes.
     pub last_saved_value: Option<String>,
+    /// Index into `recent` of the query recalled with Up/Down in the Search input, if any.
+    pub search_history_index: Option<usize>,
+    /// Query being typed before history recall started; restored when stepping past the newest entry.
+    pub search_history_stash: Option<String>,
+    /// Whether the add key marks Results entries for a batch add instead of adding them.
+    pub multi_select_mode: bool,
+    /// Names of Results entries marked in multi-select mode; kept across re-sorts, dropped on a new query.
+    pub multi_selected: std::collections::HashSet<String>,
     // Persisted recent searches
     /// Path where recent searches are persisted as JSON.
     pub recent_path: PathBuf,
@@ -58,6 +69,8 @@ pub struct AppState {
     pub latest_query_id: u64,
     /// Next query identifier to allocate.
     pub next_query_id: u64,
+    /// Whether the displayed results include the local `pacman -Ss` fallback.
+    pub search_local_fallback: bool,
     // Details cache
     /// Cache of details keyed by package name.
     pub details_cache: HashMap<String, PackageDetails>,
@@ -87,6 +100,8 @@ pub struct AppState {
     pub downgrade_list: Vec<PackageItem>,
     /// List selection state for the Downgrade pane.
     pub downgrade_state: ListState,
+    /// Cached archives chosen in the version picker, keyed by Downgrade list package name.
+    pub downgrade_targets: HashMap<String, crate::logic::downgrade::CachedPackage>,
     // Persisted install list
     /// Path where the install list is persisted as JSON.
     pub install_path: PathBuf,
@@ -94,6 +109,12 @@ pub struct AppState {
     pub install_dirty: bool,
     /// Timestamp of the most recent change to the install list for throttling disk writes.
     pub last_install_change: Option<Instant>,
+    /// Minimum interval between disk writes of persisted lists and caches.
+    pub persist_throttle: std::time::Duration,
+    /// Quiet time after the last keystroke before the search worker runs a query.
+    pub search_debounce: std::time::Duration,
+    /// Time of the last disk write per persisted file, keyed by a short label (e.g. `recent`).
+    pub persist_last_write: HashMap<&'static str, Instant>,
 
     // Visibility toggles for middle row panes
     /// Whether the Recent pane is visible in the middle row.
@@ -102,10 +123,36 @@ pub struct AppState {
     pub show_install_pane: bool,
     /// Whether to show the keybindings footer in the details pane.
     pub show_keybinds_footer: bool,
+    /// Compact layout preference (`compact_mode` setting): `None` = automatic on small terminals.
+    pub compact_mode: Option<bool>,
+    /// Whether the last frame used the compact layout (resolved from `compact_mode` and size).
+    pub compact_active: bool,
+    /// Whether Package Info shows the mirror URL an official package would download from.
+    pub show_download_server: bool,
+    /// Whether the Results status line adds index freshness, upgradable and queued counts.
+    pub status_verbose: bool,
+    /// Whether the Results status line lists active resolution stages (tracing spans).
+    pub status_spans: bool,
+    /// Package details stored in compare slot A.
+    pub compare_slot_a: Option<PackageDetails>,
+    /// Package details stored in compare slot B.
+    pub compare_slot_b: Option<PackageDetails>,
+    /// Package whose homepage should open once its details arrive (set by `open_homepage`).
+    pub pending_homepage_for: Option<String>,
+    /// Files database age in days and sync date, captured when the verbose status line is enabled.
+    pub status_db_sync: Option<(u64, String)>,
+    /// Number of upgradable packages, captured when the verbose status line is enabled.
+    pub status_upgradable: Option<usize>,
+    /// Chords typed so far toward a multi-key binding (e.g., the first `g` of `g g`).
+    pub pending_key_sequence: Vec<crate::theme::KeyChord>,
+    /// When `pending_key_sequence` last grew; prefixes older than the sequence timeout are dropped.
+    pub pending_key_sequence_at: Option<Instant>,
 
     // In-pane search (for Recent/Install panes)
     /// Optional, transient find pattern used by pane-local search ("/").
     pub pane_find: Option<String>,
+    /// Whether pane find matches fuzzy subsequences and ranks hits by score.
+    pub fuzzy_find: bool,
 
     /// Whether Search pane is in Normal mode (Vim-like navigation) instead of Insert mode.
     pub search_normal_mode: bool,
@@ -121,10 +168,17 @@ pub struct AppState {
     // Official package index persistence
     /// Path to the persisted official package index used for fast offline lookups.
     pub official_index_path: PathBuf,
+    /// When the persisted index was last written; refreshed on load and after each save so the
+    /// status bar does not stat the file every frame.
+    pub index_modified_at: Option<std::time::SystemTime>,
 
     // Loading indicator for official index generation
     /// Whether the application is currently generating the official index.
     pub loading_index: bool,
+    /// Minutes between automatic official index refreshes (`0` disables them).
+    pub index_refresh_interval_mins: u64,
+    /// When the official index was last refreshed (startup counts as a refresh).
+    pub last_index_refresh: Instant,
 
     // Track which package’s details the UI is focused on
     /// Name of the package whose details are being emphasized in the UI, if any.
@@ -157,12 +211,20 @@ pub struct AppState {
     pub arch_status_text: String,
     /// Clickable rectangle for the status label (x, y, w, h).
     pub arch_status_rect: Option<(u16, u16, u16, u16)>,
+    /// Clickable rectangle for the official index freshness indicator (x, y, w, h).
+    pub index_age_rect: Option<(u16, u16, u16, u16)>,
+    /// Set by clicking the index freshness indicator; the tick loop starts a refresh when idle.
+    pub index_refresh_requested: bool,
     /// Optional status color indicator (e.g., operational vs. current incident).
     pub arch_status_color: ArchStatusColor,
 
     // Clickable PKGBUILD button rectangle and viewer state
     /// Rectangle of the clickable "Show PKGBUILD" in terminal cell coordinates.
     pub pkgb_button_rect: Option<(u16, u16, u16, u16)>,
+    /// Rectangle of the clickable "Open package page" line in Package Info.
+    pub package_page_button_rect: Option<(u16, u16, u16, u16)>,
+    /// Clickable entries of the Provides line in Package Info: (provide spec, rect).
+    pub provide_button_rects: Vec<(String, (u16, u16, u16, u16))>,
     /// Rectangle of the clickable "Copy PKGBUILD" button in PKGBUILD title.
     pub pkgb_check_button_rect: Option<(u16, u16, u16, u16)>,
     /// Rectangle of the clickable "Reload PKGBUILD" button in PKGBUILD title.
@@ -179,6 +241,14 @@ pub struct AppState {
     pub pkgb_reload_requested_for: Option<String>,
     /// Scroll offset (lines) for the PKGBUILD viewer.
     pub pkgb_scroll: u16,
+    /// Whether the PKGBUILD viewer applies bash syntax highlighting.
+    pub pkgb_highlight: bool,
+    /// Whether the PKGBUILD viewer wraps long lines (`pkgbuild_wrap` setting).
+    pub pkgb_wrap: bool,
+    /// Columns the PKGBUILD viewer is scrolled to the right; lines are not wrapped while > 0.
+    pub pkgb_hscroll: u16,
+    /// Largest useful `pkgb_hscroll` at the last render.
+    pub pkgb_hscroll_max: u16,
     /// Content rectangle of the PKGBUILD viewer (x, y, w, h) when visible.
     pub pkgb_rect: Option<(u16, u16, u16, u16)>,
 
@@ -187,11 +257,19 @@ pub struct AppState {
     pub toast_message: Option<String>,
     /// Deadline (Instant) after which the toast is automatically hidden.
     pub toast_expires_at: Option<Instant>,
+    /// Errors seen this session, oldest first (bounded; see `logic::errors`).
+    pub error_log: std::collections::VecDeque<crate::logic::errors::ErrorEntry>,
 
     // User settings loaded at startup
     pub layout_left_pct: u16,
     pub layout_center_pct: u16,
     pub layout_right_pct: u16,
+    /// Middle row rectangle at the last render (x, y, w, h), used to map splitter drags to percentages.
+    pub middle_rect: Option<(u16, u16, u16, u16)>,
+    /// Draggable middle-row borders and the columns they occupy at the last render.
+    pub pane_border_cols: Vec<(crate::logic::layout::PaneBorder, u16)>,
+    /// Border being dragged with the mouse, if any.
+    pub pane_drag: Option<crate::logic::layout::PaneBorder>,
     /// Resolved key bindings from user settings
     pub keymap: KeyMap,
     // Internationalization (i18n)
@@ -209,6 +287,16 @@ pub struct AppState {
     pub details_rect: Option<(u16, u16, u16, u16)>,
     /// Scroll offset (lines) for the Package Info details pane.
     pub details_scroll: u16,
+    /// Columns the Package Info pane is scrolled to the right; lines are not wrapped while > 0.
+    pub details_hscroll: u16,
+    /// Largest useful `details_hscroll` at the last render (longest visible line minus pane width).
+    pub details_hscroll_max: u16,
+    /// Whether the Package Info pane wraps long lines (`details_wrap` setting).
+    pub details_wrap: bool,
+    /// Lines (Results: rows) moved per mouse-wheel notch (`mouse_scroll_lines` setting).
+    pub mouse_scroll_lines: u16,
+    /// Whether the mouse wheel direction is inverted (`mouse_natural_scroll` setting).
+    pub mouse_natural_scroll: bool,
     /// Inner content rectangle of the Recent pane list (x, y, w, h).
     pub recent_rect: Option<(u16, u16, u16, u16)>,
     /// Inner content rectangle of the Install pane list (x, y, w, h).
@@ -243,12 +331,18 @@ pub struct AppState {
     // Results sorting UI
     /// Current sort mode for results.
     pub sort_mode: SortMode,
+    /// Whether the active sort order is reversed (repeated click on its Results column header).
+    pub sort_descending: bool,
+    /// Whether searches match names only or names and descriptions (Options menu toggle).
+    pub search_scope: SearchScope,
     /// Whether the sort dropdown is currently visible.
     pub sort_menu_open: bool,
     /// Clickable rectangle for the sort button in the Results title (x, y, w, h).
     pub sort_button_rect: Option<(u16, u16, u16, u16)>,
     /// Inner content rectangle of the sort dropdown menu when visible (x, y, w, h).
     pub sort_menu_rect: Option<(u16, u16, u16, u16)>,
+    /// Clickable Results column header cells with the sort mode each applies (x, y, w, h).
+    pub results_header_rects: Vec<(SortMode, (u16, u16, u16, u16))>,
     /// Deadline after which the sort dropdown auto-closes.
     pub sort_menu_auto_close_at: Option<Instant>,
 
@@ -318,6 +412,8 @@ pub struct AppState {
     pub results_filter_show_artix_system: bool,
     /// Whether to include packages labeled as `manjaro` in the Results view.
     pub results_filter_show_manjaro: bool,
+    /// Whether Results only lists installed packages with a newer version available.
+    pub results_filter_upgradable_only: bool,
     /// Clickable rectangle for the AUR filter toggle in the Results title (x, y, w, h).
     pub results_filter_aur_rect: Option<(u16, u16, u16, u16)>,
     /// Clickable rectangle for the core filter toggle in the Results title (x, y, w, h).
@@ -352,10 +448,16 @@ pub struct AppState {
     pub refresh_installed_until: Option<Instant>,
     /// Next scheduled time to poll caches while `refresh_installed_until` is active.
     pub next_installed_refresh_at: Option<Instant>,
+    /// When the installed caches were last refreshed on request, for debouncing the keybind.
+    pub installed_refresh_requested_at: Option<Instant>,
 
     // Pending installs to detect completion and clear Install list
     /// Names of packages we just triggered to install; when all appear installed, clear Install list.
     pub pending_install_names: Option<Vec<String>>,
+    /// Installed state of `pending_install_names` when the install started; completion needs
+    /// every stamp to change, so reinstalls and upgrades are not reported before pacman ran.
+    pub pending_install_baseline:
+        std::collections::HashMap<String, Option<crate::index::InstallStamp>>,
 
     // Pending removals to detect completion and log
     /// Names of packages we just triggered to remove; when all disappear, append to removed log.
@@ -378,6 +480,20 @@ pub struct AppState {
     // File resolution cache for install list
     /// Cached resolved file changes for the current install list (updated in background).
     pub install_list_files: Vec<crate::state::modal::PackageFileInfo>,
+    /// Paths several packages in the open install Preflight would install; kept current by
+    /// `logic::files::refresh_file_collisions`.
+    pub preflight_file_collisions: Vec<crate::logic::files::FileCollision>,
+    /// Fingerprint of the Preflight file data `preflight_file_collisions` was computed from;
+    /// `None` when no install Preflight was open.
+    pub preflight_file_collisions_key: Option<u64>,
+    /// Group each install-list entry was expanded from, keyed by lowercase package name.
+    pub install_group_of: std::collections::HashMap<String, String>,
+    /// Lowercase names the user chose to add as a package although they also name a group.
+    pub install_group_as_package: std::collections::HashSet<String>,
+    /// Undo/redo history of the install, remove, and downgrade lists.
+    pub list_history: crate::logic::history::ListHistory,
+    /// Whether a background mirror ranking is running.
+    pub mirror_rank_running: bool,
     /// Whether file resolution is currently in progress.
     pub files_resolving: bool,
     /// Path where the file cache is persisted as JSON.
@@ -404,6 +520,26 @@ pub struct AppState {
     pub services_pending_signature: Option<(PreflightAction, Vec<String>)>,
     /// Service restart decisions captured during the Preflight Services tab.
     pub pending_service_plan: Vec<ServiceImpact>,
+    /// System Update commands run when the open Preflight reviews a system upgrade.
+    pub pending_system_update: Option<Vec<String>>,
+    /// Upgrades query requested from the System Update dialog: `(official, aur, commands)`;
+    /// taken by the runtime, which lists the upgrades off the UI thread.
+    pub upgrades_query_request: Option<(bool, bool, Vec<String>)>,
+    /// A `.pacnew`/`.pacsave` scan was requested for the Config leftovers dialog; the runtime
+    /// walks the file system off the UI thread.
+    pub leftovers_scan_requested: bool,
+    /// Filter/sort settings lines queued by Ctrl+Y; taken by the runtime, which copies them to
+    /// the clipboard off the UI thread.
+    pub view_settings_copy_request: Option<String>,
+    /// Package whose reverse dependency chains the Why-installed dialog is waiting for; taken
+    /// by the runtime, which runs `pacman -Qi` off the UI thread.
+    pub why_installed_request: Option<String>,
+    /// Package whose file list the package files dialog is waiting for; taken by the runtime,
+    /// which runs `pacman -Ql`/`-Fl` off the UI thread.
+    pub package_files_request: Option<PackageItem>,
+    /// Set by the "remove orphans" action; taken by the runtime, which runs `pacman -Qtdq` off
+    /// the UI thread.
+    pub orphans_requested: bool,
 
     // Sandbox analysis cache for install list
     /// Cached resolved sandbox information for the current install list (updated in background).
@@ -432,12 +568,89 @@ pub struct AppState {
     pub preflight_deps_resolving: bool,
     /// Whether preflight file resolution is in progress.
     pub preflight_files_resolving: bool,
+    /// Packages done/total of the running dependency resolution, for the Deps tab progress bar.
+    pub preflight_deps_progress: Option<(usize, usize)>,
+    /// Packages done/total of the running file resolution, for the Files tab progress bar.
+    pub preflight_files_progress: Option<(usize, usize)>,
     /// Whether preflight service resolution is in progress.
     pub preflight_services_resolving: bool,
     /// Whether preflight sandbox resolution is in progress.
     pub preflight_sandbox_resolving: bool,
     /// Cancellation flag for preflight operations (set to true when modal closes).
     pub preflight_cancelled: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    /// Terminal scans started from the open Preflight; stopped when it closes.
+    pub scan_progress: Vec<crate::state::modal::ScanProgress>,
+}
+
+impl AppState {
+    /// What: Close the active modal, remembering it for the reopen keybind.
+    ///
+    /// Output:
+    /// - `modal` is `Modal::None`; the closed modal (moved, not cloned) is kept in
+    ///   `last_closed_modal` unless it was the command palette or nothing was open.
+    pub fn close_modal(&mut self) {
+        let closed = std::mem::take(&mut self.modal);
+        if !matches!(closed, Modal::None | Modal::CommandPalette { .. }) {
+            self.last_closed_modal = Some(closed);
+        }
+    }
+
+    /// What: Apply user preferences from settings.conf/keybinds.conf to a freshly built state.
+    ///
+    /// Inputs:
+    /// - `prefs`: Parsed settings.
+    ///
+    /// Output:
+    /// - Updates layout, keymap, sort/filter defaults, pane visibility, and installed-only mode.
+    ///
+    /// Details:
+    /// - With `installed_only_default`, the app starts in installed-only mode focused on the
+    ///   Remove list; incoming results are then restricted to explicitly installed packages.
+    pub fn apply_startup_settings(&mut self, prefs: &crate::theme::Settings) {
+        self.apply_settings(prefs);
+        if prefs.installed_only_default {
+            self.installed_only_mode = true;
+            self.right_pane_focus = crate::state::RightPaneFocus::Remove;
+        }
+    }
+
+    /// What: Apply the preferences that may change while the app runs.
+    ///
+    /// Inputs:
+    /// - `prefs`: Parsed settings.
+    ///
+    /// Output:
+    /// - Updates layout, keymap, sort/filter toggles, markers, pane visibility, and input tuning.
+    ///
+    /// Details:
+    /// - Shared by startup and the reload-settings keybind; startup-only choices such as
+    ///   `installed_only_default` stay in [`AppState::apply_startup_settings`].
+    pub fn apply_settings(&mut self, prefs: &crate::theme::Settings) {
+        self.layout_left_pct = prefs.layout_left_pct;
+        self.layout_center_pct = prefs.layout_center_pct;
+        self.layout_right_pct = prefs.layout_right_pct;
+        self.keymap = prefs.keymap.clone();
+        self.sort_mode = prefs.sort_mode;
+        for (key, on) in &prefs.results_filters {
+            crate::logic::filter::set_results_filter(self, key, *on);
+        }
+        self.package_marker = prefs.package_marker;
+        // Apply initial visibility for middle row panes from settings
+        self.show_recent_pane = prefs.show_recent_pane;
+        self.show_install_pane = prefs.show_install_pane;
+        // Apply initial keybind footer visibility (default true if not present)
+        self.show_keybinds_footer = prefs.show_keybinds_footer;
+        self.compact_mode = prefs.compact_mode;
+        self.fuzzy_find = prefs.fuzzy_find;
+        self.pkgb_highlight = prefs.pkgbuild_highlight;
+        self.pkgb_wrap = prefs.pkgbuild_wrap;
+        self.details_wrap = prefs.details_wrap;
+        self.mouse_scroll_lines = prefs.mouse_scroll_lines;
+        self.mouse_natural_scroll = prefs.mouse_natural_scroll;
+        self.persist_throttle = std::time::Duration::from_millis(prefs.persist_throttle_ms);
+        self.search_debounce = std::time::Duration::from_millis(prefs.search_debounce_ms);
+        self.index_refresh_interval_mins = prefs.index_refresh_interval_mins;
+    }
 }
 
 impl Default for AppState {
@@ -454,18 +667,24 @@ impl Default for AppState {
             list_state: ListState::default(),
             modal: Modal::None,
             previous_modal: None,
+            last_closed_modal: None,
             dry_run: false,
             recent: Vec::new(),
             history_state: ListState::default(),
             focus: Focus::Search,
             last_input_change: Instant::now(),
             last_saved_value: None,
+            search_history_index: None,
+            search_history_stash: None,
+            multi_select_mode: false,
+            multi_selected: std::collections::HashSet::new(),
             // Persisted recent searches (lists dir under config)
             recent_path: crate::theme::lists_dir().join("recent_searches.json"),
             recent_dirty: false,
 
             latest_query_id: 0,
             next_query_id: 1,
+            search_local_fallback: false,
             details_cache: HashMap::new(),
             // Details cache (lists dir under config)
             cache_path: crate::theme::lists_dir().join("details_cache.json"),
@@ -482,17 +701,34 @@ impl Default for AppState {
             remove_state: ListState::default(),
             downgrade_list: Vec::new(),
             downgrade_state: ListState::default(),
+            downgrade_targets: HashMap::new(),
             // Install list (lists dir under config)
             install_path: crate::theme::lists_dir().join("install_list.json"),
             install_dirty: false,
             last_install_change: None,
+            persist_throttle: std::time::Duration::from_millis(1000),
+            search_debounce: std::time::Duration::from_millis(250),
+            persist_last_write: HashMap::new(),
 
             // Middle row panes visible by default
             show_recent_pane: true,
             show_install_pane: true,
             show_keybinds_footer: true,
+            compact_mode: None,
+            compact_active: false,
+            show_download_server: false,
+            status_verbose: false,
+            status_spans: false,
+            compare_slot_a: None,
+            compare_slot_b: None,
+            pending_homepage_for: None,
+            status_db_sync: None,
+            status_upgradable: None,
+            pending_key_sequence: Vec::new(),
+            pending_key_sequence_at: None,
 
             pane_find: None,
+            fuzzy_find: false,
 
             // Search input mode
             search_normal_mode: false,
@@ -501,8 +737,11 @@ impl Default for AppState {
 
             // Official index (lists dir under config)
             official_index_path: crate::theme::lists_dir().join("official_index.json"),
+            index_modified_at: None,
 
             loading_index: false,
+            index_refresh_interval_mins: 0,
+            last_index_refresh: Instant::now(),
 
             details_focus: None,
 
@@ -515,8 +754,12 @@ impl Default for AppState {
             install_export_rect: None,
             arch_status_text: "Arch Status: loading…".to_string(),
             arch_status_rect: None,
+            index_age_rect: None,
+            index_refresh_requested: false,
             arch_status_color: ArchStatusColor::None,
             pkgb_button_rect: None,
+            package_page_button_rect: None,
+            provide_button_rects: Vec::new(),
             pkgb_check_button_rect: None,
             pkgb_reload_button_rect: None,
             pkgb_visible: false,
@@ -525,14 +768,22 @@ impl Default for AppState {
             pkgb_reload_requested_at: None,
             pkgb_reload_requested_for: None,
             pkgb_scroll: 0,
+            pkgb_highlight: true,
+            pkgb_wrap: true,
+            pkgb_hscroll: 0,
+            pkgb_hscroll_max: 0,
             pkgb_rect: None,
 
             toast_message: None,
             toast_expires_at: None,
+            error_log: std::collections::VecDeque::new(),
 
             layout_left_pct: 20,
             layout_center_pct: 60,
             layout_right_pct: 20,
+            middle_rect: None,
+            pane_border_cols: Vec::new(),
+            pane_drag: None,
             keymap: crate::theme::Settings::default().keymap,
             locale: "en-US".to_string(),
             translations: std::collections::HashMap::new(),
@@ -541,6 +792,11 @@ impl Default for AppState {
             results_rect: None,
             details_rect: None,
             details_scroll: 0,
+            details_hscroll: 0,
+            details_hscroll_max: 0,
+            details_wrap: true,
+            mouse_scroll_lines: 1,
+            mouse_natural_scroll: false,
             recent_rect: None,
             install_rect: None,
             downgrade_rect: None,
@@ -560,9 +816,12 @@ impl Default for AppState {
 
             // Sorting
             sort_mode: SortMode::RepoThenName,
+            sort_descending: false,
+            search_scope: SearchScope::default(),
             sort_menu_open: false,
             sort_button_rect: None,
             sort_menu_rect: None,
+            results_header_rects: Vec::new(),
             sort_menu_auto_close_at: None,
 
             // Options dropdown (top-right of Results)
@@ -601,6 +860,7 @@ impl Default for AppState {
             results_filter_show_artix_world: true,
             results_filter_show_artix_system: true,
             results_filter_show_manjaro: true,
+            results_filter_upgradable_only: false,
             results_filter_aur_rect: None,
             results_filter_core_rect: None,
             results_filter_extra_rect: None,
@@ -619,9 +879,11 @@ impl Default for AppState {
             // Package mutation cache refresh state (inactive by default)
             refresh_installed_until: None,
             next_installed_refresh_at: None,
+            installed_refresh_requested_at: None,
 
             // Pending install tracking
             pending_install_names: None,
+            pending_install_baseline: std::collections::HashMap::new(),
             pending_remove_names: None,
             install_list_deps: Vec::new(),
             remove_preflight_summary: Vec::new(),
@@ -632,6 +894,12 @@ impl Default for AppState {
             deps_cache_dirty: false,
 
             install_list_files: Vec::new(),
+            preflight_file_collisions: Vec::new(),
+            preflight_file_collisions_key: None,
+            install_group_of: std::collections::HashMap::new(),
+            install_group_as_package: std::collections::HashSet::new(),
+            list_history: crate::logic::history::ListHistory::default(),
+            mirror_rank_running: false,
             files_resolving: false,
             // File cache (lists dir under config)
             files_cache_path: crate::theme::lists_dir().join("file_cache.json"),
@@ -647,6 +915,13 @@ impl Default for AppState {
             next_service_request_id: 1,
             services_pending_signature: None,
             pending_service_plan: Vec::new(),
+            pending_system_update: None,
+            upgrades_query_request: None,
+            leftovers_scan_requested: false,
+            view_settings_copy_request: None,
+            why_installed_request: None,
+            package_files_request: None,
+            orphans_requested: false,
 
             install_list_sandbox: Vec::new(),
             sandbox_resolving: false,
@@ -661,9 +936,12 @@ impl Default for AppState {
             preflight_summary_resolving: false,
             preflight_deps_resolving: false,
             preflight_files_resolving: false,
+            preflight_deps_progress: None,
+            preflight_files_progress: None,
             preflight_services_resolving: false,
             preflight_sandbox_resolving: false,
             preflight_cancelled: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            scan_progress: Vec::new(),
         }
     }
 }
@@ -717,4 +995,54 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    /// What: Ensure `installed_only_default` starts the app in installed-only mode with filtered results.
+    ///
+    /// Inputs:
+    /// - Settings with `installed_only_default = true`; first query results containing one
+    ///   explicitly installed and one uninstalled package.
+    ///
+    /// Output:
+    /// - `installed_only_mode` is set, focus moves to the Remove list, and the first rendered
+    ///   Results contain only the installed package.
+    ///
+    /// Details:
+    /// - Mirrors the runtime path: settings applied at startup, then incoming results restricted.
+    fn installed_only_default_initializes_mode_and_filters_results() {
+        let prefs = crate::theme::Settings {
+            installed_only_default: true,
+            ..Default::default()
+        };
+        let mut app = super::AppState::default();
+        assert!(!app.installed_only_mode);
+        app.apply_startup_settings(&prefs);
+        assert!(app.installed_only_mode);
+        assert!(matches!(
+            app.right_pane_focus,
+            crate::state::RightPaneFocus::Remove
+        ));
+
+        let item = |name: &str| crate::state::PackageItem {
+            name: name.to_string(),
+            version: "1".into(),
+            description: String::new(),
+            source: crate::state::Source::Aur,
+            popularity: None,
+        };
+        let explicit: std::collections::HashSet<String> = ["kept".to_string()].into();
+        app.input = "k".into();
+        app.all_results = crate::logic::filter::installed_only_results(
+            vec![item("kept"), item("dropped")],
+            &app.input,
+            &explicit,
+        );
+        crate::logic::apply_filters_and_sort_preserve_selection(&mut app);
+        let names: Vec<&str> = app.results.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["kept"]);
+
+        let mut default_app = super::AppState::default();
+        default_app.apply_startup_settings(&crate::theme::Settings::default());
+        assert!(!default_app.installed_only_mode);
+    }
 }
@@ -11,8 +11,8 @@ pub mod types;
 pub use app_state::AppState;
 pub use modal::{Modal, PreflightAction, PreflightTab};
 pub use types::{
-    ArchStatusColor, Focus, NewsItem, PackageDetails, PackageItem, QueryInput, RightPaneFocus,
-    SearchResults, SortMode, Source,
+    ArchStatusColor, AurInfo, Focus, NewsItem, PackageDetails, PackageItem, QueryInput,
+    RightPaneFocus, SearchResults, SearchScope, SortMode, Source,
 };
 
 #[cfg(test)]
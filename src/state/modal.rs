@@ -34,6 +34,50 @@ pub enum PreflightTab {
     Sandbox,
 }
 
+/// Incremental progress reported by a background preflight resolver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResolveProgress {
+    /// Tab whose data is being resolved (`Deps` or `Files`).
+    pub stage: PreflightTab,
+    /// Packages finished so far.
+    pub done: usize,
+    /// Packages being resolved.
+    pub total: usize,
+}
+
+/// Phase of a terminal AUR scan started from Preflight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScanPhase {
+    /// Cloning and fetching sources; the scanners have not started yet.
+    #[default]
+    Preparing,
+    /// Scanners are running concurrently.
+    Running,
+    /// Every scanner finished.
+    Finished,
+    /// Verdicts were restored from the scan cache; no scanner ran.
+    Cached,
+    /// The scanners were stopped (Ctrl+C in the terminal or Preflight closed).
+    Cancelled,
+}
+
+/// Live progress of a terminal AUR scan, shown in the Preflight Sandbox tab.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ScanProgress {
+    /// Package being scanned.
+    pub package: String,
+    /// Status file the scan script appends to (see `install::scan`).
+    pub status_path: std::path::PathBuf,
+    /// Process running the scanner jobs, once they have started.
+    pub pid: Option<u32>,
+    /// Number of scanners started.
+    pub total: usize,
+    /// Finished scanners with their elapsed seconds, in completion order.
+    pub finished: Vec<(String, u64)>,
+    /// Current phase.
+    pub phase: ScanPhase,
+}
+
 /// Removal cascade strategy for pacman operations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CascadeMode {
@@ -178,6 +222,14 @@ pub struct ServiceImpact {
     pub providers: Vec<String>,
     /// Whether the unit is active (`systemctl is-active == active`).
     pub is_active: bool,
+    /// Raw activity state reported by the init system (`active`, `failed`, `started`, ...);
+    /// empty when it could not be queried.
+    #[serde(default)]
+    pub active_state: String,
+    /// Raw enablement state (`enabled`, `disabled`, `static`, or the OpenRC runlevel); empty when
+    /// unknown.
+    #[serde(default)]
+    pub enabled_state: String,
     /// Whether a restart is recommended because files/configs will change.
     pub needs_restart: bool,
     /// Resolver-suggested action prior to user adjustments.
@@ -334,6 +386,19 @@ pub struct PreflightPackageSummary {
     pub notes: Vec<String>,
 }
 
+/// Transaction pacman itself resolved with `pacman -S --print` before an install.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ResolvedTransaction {
+    /// `(name, version)` of every package pacman would install, in pacman's order.
+    pub targets: Vec<(String, String)>,
+    /// Targets pacman pulls in that are not part of the selection (dependencies).
+    pub extra: Vec<String>,
+    /// AUR targets, which the helper builds later and pacman cannot simulate.
+    pub aur_unresolved: Vec<String>,
+    /// Why `--print` failed (e.g. missing sync databases); the UI falls back to Pacsea's plan.
+    pub error: Option<String>,
+}
+
 /// What: Comprehensive dataset backing the Preflight Summary tab.
 ///
 /// Inputs:
@@ -371,6 +436,40 @@ pub struct PreflightSummaryData {
     /// Free-form warnings assembled by the summary planner to highlight notable risks.
     pub summary_warnings: Vec<String>,
     pub summary_notes: Vec<String>,
+    /// Ground-truth install transaction from `pacman --print`; `None` for removals.
+    #[serde(default)]
+    pub resolved_transaction: Option<ResolvedTransaction>,
+}
+
+/// Extra typed confirmation required before a transaction above `confirm_threshold`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypedConfirm {
+    /// Combined install, remove, and downgrade count the user has to type.
+    pub count: usize,
+    /// Digits typed so far.
+    pub typed: String,
+}
+
+impl TypedConfirm {
+    /// What: Build the guard for a transaction of `count` operations.
+    ///
+    /// Inputs:
+    /// - `count`: Combined install, remove, and downgrade count.
+    /// - `threshold`: Configured `confirm_threshold`; `0` disables the guard.
+    ///
+    /// Output:
+    /// - `Some` when `count` exceeds a non-zero threshold, else `None`.
+    pub fn for_count(count: usize, threshold: usize) -> Option<Self> {
+        (threshold > 0 && count > threshold).then(|| Self {
+            count,
+            typed: String::new(),
+        })
+    }
+
+    /// What: Whether the typed digits match the required count.
+    pub fn satisfied(&self) -> bool {
+        self.typed == self.count.to_string()
+    }
 }
 
 /// What: Captures all dialog state for the various modal overlays presented in
@@ -389,8 +488,11 @@ pub enum Modal {
     /// Informational alert with a non-interactive message.
     Alert { message: String },
     /// Confirmation dialog for installing the given items.
-    #[allow(dead_code)]
-    ConfirmInstall { items: Vec<PackageItem> },
+    ConfirmInstall {
+        items: Vec<PackageItem>,
+        /// Typed count required before Enter proceeds, when above `confirm_threshold`.
+        guard: Option<TypedConfirm>,
+    },
     /// Preflight summary before executing any action.
     Preflight {
         items: Vec<PackageItem>,
@@ -464,8 +566,11 @@ pub enum Modal {
     /// Help overlay with keybindings. Non-interactive; dismissed with Esc/Enter.
     Help,
     /// Confirmation dialog for removing the given items.
-    #[allow(dead_code)]
-    ConfirmRemove { items: Vec<PackageItem> },
+    ConfirmRemove {
+        items: Vec<PackageItem>,
+        /// Typed count required before Enter proceeds, when above `confirm_threshold`.
+        guard: Option<TypedConfirm>,
+    },
     /// System update dialog with multi-select options and optional country.
     SystemUpdate {
         /// Whether to update Arch mirrors using reflector.
@@ -484,6 +589,8 @@ pub enum Modal {
         mirror_count: u16,
         /// Cursor row in the dialog (0..=4)
         cursor: usize,
+        /// Pending upgrades are being listed for review; keys other than Esc are ignored.
+        loading: bool,
     },
     /// Arch Linux News: list of recent items with selection.
     News {
@@ -529,10 +636,198 @@ pub enum Modal {
     },
     /// Information dialog explaining the Import file format.
     ImportHelp,
+    /// Picker listing versions of a Downgrade list package found in the pacman cache.
+    DowngradeVersions {
+        /// Package being downgraded.
+        package: String,
+        /// Currently installed version, marked in the list.
+        installed: String,
+        /// Cached archives, newest first (empty when the cache has none).
+        versions: Vec<crate::logic::downgrade::CachedPackage>,
+        /// Selected row index.
+        selected: usize,
+    },
+    /// Side-by-side diff of the packages stored in compare slots A and B.
+    PackageCompare {
+        /// Package name in slot A.
+        left: String,
+        /// Package name in slot B.
+        right: String,
+        /// Compared fields in display order.
+        rows: Vec<crate::logic::compare::CompareRow>,
+    },
+    /// Exact command lines the focused list would run; nothing is executed.
+    CommandPreview {
+        /// Command lines in execution order.
+        lines: Vec<String>,
+    },
+    /// Reverse dependency chains explaining why an installed package is present.
+    WhyInstalled {
+        /// Package being explained.
+        package: String,
+        /// Chains from the package up to an explicitly installed package.
+        chains: Vec<Vec<String>>,
+        /// `chains` are still being traced in the background.
+        loading: bool,
+    },
+    /// Prompt for a profile name to save the install list under.
+    ProfileSave {
+        /// Profile name typed so far.
+        input: String,
+    },
+    /// Picker for a saved profile to load into the install list.
+    ProfileLoad {
+        /// Saved profile names, sorted.
+        names: Vec<String>,
+        /// Highlighted row in `names`.
+        selected: usize,
+    },
+    /// Picker for the active theme (`theme.conf` or a file in `themes/`).
+    ThemePicker {
+        /// Theme names; the empty first entry stands for `theme.conf`.
+        names: Vec<String>,
+        /// Highlighted row in `names`.
+        selected: usize,
+    },
+    /// Fuzzy-searchable list of every global action (`events::Action`).
+    CommandPalette {
+        /// Filter text.
+        query: String,
+        /// Highlighted row among the matches.
+        selected: usize,
+    },
+    /// Problems found in `keybinds.conf` (unparseable chords, conflicting bindings).
+    KeybindDiagnostics {
+        /// `(line_number, reason)` pairs from `theme::validate_keybinds`.
+        issues: Vec<(usize, String)>,
+        /// First issue shown.
+        scroll: usize,
+    },
+    /// Leftover `.pacnew`/`.pacsave` files found under `/etc` and friends.
+    ConfigLeftovers {
+        /// Leftovers from `logic::pacnew::scan_system_leftovers`, sorted by path.
+        entries: Vec<crate::logic::pacnew::ConfigLeftover>,
+        /// Highlighted row in `entries`.
+        selected: usize,
+        /// The scan is still walking the file system; `entries` is empty until it finishes.
+        loading: bool,
+    },
+    /// Line diff of a config file against its `.pacnew`/`.pacsave`, opened from `ConfigLeftovers`.
+    ConfigDiff {
+        /// Leftover being compared with its original.
+        leftover: crate::logic::pacnew::ConfigLeftover,
+        /// Diff lines; `None` when either file is binary or unreadable.
+        lines: Option<Vec<crate::logic::textdiff::DiffLine>>,
+        /// First line shown.
+        scroll: u16,
+        /// Leftover list to return to on close.
+        entries: Vec<crate::logic::pacnew::ConfigLeftover>,
+        /// Selected row in `entries` to restore.
+        selected: usize,
+    },
+    /// Tail of Pacsea's own log file (`logs_dir()/pacsea.log`).
+    LogViewer {
+        /// Log file shown; `None` when no log was found.
+        path: Option<std::path::PathBuf>,
+        /// Last lines of the file, oldest first (unfiltered).
+        lines: Vec<String>,
+        /// Least severe level shown; `None` shows every line.
+        min_level: Option<tracing::Level>,
+        /// Lines scrolled up from the end; 0 follows the tail.
+        scroll: usize,
+    },
+    /// Errors recorded this session (`AppState::error_log`), newest first.
+    ErrorLog {
+        /// First entry shown.
+        scroll: usize,
+    },
+    /// Persisted install/remove/downgrade history (`activity_log.json`), newest first.
+    ActivityLog {
+        /// Entries as loaded from disk, oldest first.
+        entries: Vec<crate::logic::activity::ActivityEntry>,
+        /// First entry shown.
+        scroll: usize,
+    },
+    /// Files a package installs (`pacman -Ql` when installed, else `pacman -Fl`), with a filter.
+    PackageFiles {
+        /// Package whose files are listed.
+        package: String,
+        /// Every path, in pacman's order; empty when nothing could be resolved.
+        files: Vec<String>,
+        /// Lookup error, shown instead of the list.
+        error: Option<String>,
+        /// Whether the package is from the AUR (changes the empty-list guidance).
+        aur: bool,
+        /// Case-insensitive substring filter typed by the user.
+        filter: String,
+        /// First matching path shown.
+        scroll: usize,
+        /// `files` are still being read in the background.
+        loading: bool,
+    },
+    /// Breakdown of a profile or imported list against installed packages, shown before it is loaded.
+    ProfileDiff {
+        /// Profile name, or the file name of an imported list.
+        name: String,
+        /// Entries stored in the profile, in saved order.
+        items: Vec<PackageItem>,
+        /// Append to the install list instead of replacing it.
+        append: bool,
+        /// Entries partitioned by installed state.
+        diff: crate::logic::lists::InstalledDiff,
+        /// Previewing an imported list rather than a saved profile.
+        import: bool,
+    },
+    /// Preview of a freshly ranked mirrorlist, written on confirmation.
+    MirrorRankPreview {
+        /// Active server templates in ranked order.
+        servers: Vec<String>,
+        /// Where the ranked list was staged.
+        staged: std::path::PathBuf,
+    },
+    /// Confirmation before clearing the install, remove, and downgrade lists together.
+    ConfirmClearLists {
+        /// Entries currently in the install list.
+        install: usize,
+        /// Entries currently in the remove list.
+        remove: usize,
+        /// Entries currently in the downgrade list.
+        downgrade: usize,
+    },
+    /// Confirmation before expanding a pacman group added to the install list.
+    GroupExpand {
+        /// Item that was added (the group name).
+        item: PackageItem,
+        /// Group member package names.
+        members: Vec<String>,
+        /// Whether the name is also a package, so the user must pick a meaning.
+        is_package: bool,
+        /// Further groups added while this prompt was open, asked about next.
+        queued: Vec<PackageItem>,
+    },
 }
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    /// What: Verify the typed-count guard only applies above a non-zero threshold.
+    ///
+    /// Inputs:
+    /// - Counts at, above, and with a disabled threshold; typed digits.
+    ///
+    /// Output:
+    /// - A guard exists only for `count > threshold > 0` and is satisfied by the exact count.
+    fn typed_confirm_threshold_and_match() {
+        assert!(super::TypedConfirm::for_count(10, 10).is_none());
+        assert!(super::TypedConfirm::for_count(500, 0).is_none());
+        let mut guard = super::TypedConfirm::for_count(11, 10).unwrap();
+        assert!(!guard.satisfied());
+        guard.typed = "011".into();
+        assert!(!guard.satisfied());
+        guard.typed = "11".into();
+        assert!(guard.satisfied());
+    }
+
     #[test]
     /// What: Confirm each `Modal` variant can be constructed and the `Default` implementation returns `Modal::None`.
     ///
@@ -550,9 +845,15 @@ mod tests {
         let _ = super::Modal::Alert {
             message: "hi".into(),
         };
-        let _ = super::Modal::ConfirmInstall { items: Vec::new() };
+        let _ = super::Modal::ConfirmInstall {
+            items: Vec::new(),
+            guard: None,
+        };
         let _ = super::Modal::Help;
-        let _ = super::Modal::ConfirmRemove { items: Vec::new() };
+        let _ = super::Modal::ConfirmRemove {
+            items: Vec::new(),
+            guard: super::TypedConfirm::for_count(3, 2),
+        };
         let _ = super::Modal::SystemUpdate {
             do_mirrors: true,
             do_pacman: true,
@@ -562,6 +863,7 @@ mod tests {
             countries: vec!["US".into()],
             mirror_count: 20,
             cursor: 0,
+            loading: false,
         };
         let _ = super::Modal::News {
             items: Vec::new(),
@@ -577,6 +879,106 @@ mod tests {
             cursor: 0,
         };
         let _ = super::Modal::ImportHelp;
+        let _ = super::Modal::DowngradeVersions {
+            package: "pkg".into(),
+            installed: "2-1".into(),
+            versions: Vec::new(),
+            selected: 0,
+        };
+        let _ = super::Modal::PackageCompare {
+            left: "a".into(),
+            right: "b".into(),
+            rows: Vec::new(),
+        };
+        let _ = super::Modal::CommandPreview { lines: Vec::new() };
+        let _ = super::Modal::WhyInstalled {
+            package: "a".into(),
+            chains: Vec::new(),
+            loading: false,
+        };
+        let _ = super::Modal::ProfileSave {
+            input: String::new(),
+        };
+        let _ = super::Modal::ProfileLoad {
+            names: Vec::new(),
+            selected: 0,
+        };
+        let _ = super::Modal::ThemePicker {
+            names: Vec::new(),
+            selected: 0,
+        };
+        let _ = super::Modal::CommandPalette {
+            query: String::new(),
+            selected: 0,
+        };
+        let _ = super::Modal::KeybindDiagnostics {
+            issues: Vec::new(),
+            scroll: 0,
+        };
+        let _ = super::Modal::ConfigLeftovers {
+            entries: Vec::new(),
+            selected: 0,
+            loading: false,
+        };
+        let _ = super::Modal::ConfigDiff {
+            leftover: crate::logic::pacnew::ConfigLeftover {
+                path: "/etc/a.conf.pacnew".into(),
+                original: "/etc/a.conf".into(),
+                kind: crate::logic::pacnew::LeftoverKind::Pacnew,
+            },
+            lines: None,
+            scroll: 0,
+            entries: Vec::new(),
+            selected: 0,
+        };
+        let _ = super::Modal::LogViewer {
+            path: None,
+            lines: Vec::new(),
+            min_level: None,
+            scroll: 0,
+        };
+        let _ = super::Modal::ErrorLog { scroll: 0 };
+        let _ = super::Modal::ActivityLog {
+            entries: Vec::new(),
+            scroll: 0,
+        };
+        let _ = super::Modal::PackageFiles {
+            package: "a".into(),
+            files: Vec::new(),
+            error: None,
+            aur: false,
+            filter: String::new(),
+            scroll: 0,
+            loading: false,
+        };
+        let _ = super::Modal::ProfileDiff {
+            name: "p".into(),
+            items: Vec::new(),
+            append: false,
+            diff: crate::logic::lists::InstalledDiff::default(),
+            import: false,
+        };
+        let _ = super::Modal::MirrorRankPreview {
+            servers: Vec::new(),
+            staged: std::path::PathBuf::new(),
+        };
+        let _ = super::Modal::ConfirmClearLists {
+            install: 1,
+            remove: 0,
+            downgrade: 0,
+        };
+        let _ = super::Modal::GroupExpand {
+            item: crate::state::PackageItem {
+                name: "g".into(),
+                version: String::new(),
+                description: String::new(),
+                source: crate::state::Source::Aur,
+                popularity: None,
+            },
+            members: Vec::new(),
+            is_package: false,
+            queued: Vec::new(),
+        };
         let _ = super::Modal::Preflight {
             items: Vec::new(),
             action: super::PreflightAction::Install,
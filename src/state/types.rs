@@ -88,6 +88,24 @@ pub struct PackageDetails {
     /// AUR popularity score when available (AUR only).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub popularity: Option<f64>,
+    /// Votes, maintainer and timestamps from the AUR RPC (AUR only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aur: Option<AurInfo>,
+}
+
+/// AUR-specific trust signals for a package, as reported by the AUR RPC `info` endpoint.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AurInfo {
+    /// Number of user votes.
+    pub votes: u64,
+    /// Current maintainer; `None` when the package is orphaned.
+    pub maintainer: Option<String>,
+    /// Unix timestamp of the first submission.
+    pub first_submitted: Option<i64>,
+    /// Unix timestamp of the last update.
+    pub last_modified: Option<i64>,
+    /// Unix timestamp since which the package is flagged out-of-date; `None` when not flagged.
+    pub out_of_date: Option<i64>,
 }
 
 /// Search query sent to the background search worker.
@@ -97,6 +115,9 @@ pub struct QueryInput {
     pub id: u64,
     /// Raw query text entered by the user.
     pub text: String,
+    /// Quiet time the worker waits for a newer query before running this one; zero runs it
+    /// right away.
+    pub debounce: std::time::Duration,
 }
 
 /// Results corresponding to a prior [`QueryInput`].
@@ -106,6 +127,9 @@ pub struct SearchResults {
     pub id: u64,
     /// Matching packages in rank order.
     pub items: Vec<PackageItem>,
+    /// Whether the local sync databases (`pacman -Ss`) filled in because the index was empty or
+    /// the web search failed.
+    pub local_fallback: bool,
 }
 
 /// Sorting mode for the Results list.
@@ -152,6 +176,16 @@ impl SortMode {
     }
 }
 
+/// Which package fields a search query must match in the Results list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchScope {
+    /// Default: keep whatever the sources returned (name or description matches).
+    #[default]
+    NameAndDescription,
+    /// Keep only results whose name contains every query term.
+    NameOnly,
+}
+
 #[cfg(test)]
 mod tests {
     use super::SortMode;
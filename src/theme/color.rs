@@ -0,0 +1,264 @@
+//! Terminal color-depth detection and theme color quantization.
+
+use std::sync::RwLock;
+
+use ratatui::style::Color;
+
+use super::types::Theme;
+
+/// Colors the terminal can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSupport {
+    /// 24-bit RGB; theme colors are used as-is.
+    TrueColor,
+    /// The xterm 256-color palette.
+    Ansi256,
+    /// The 16 basic ANSI colors.
+    Ansi16,
+    /// No colors (`NO_COLOR`, `TERM=dumb`, or `force_color = none`).
+    None,
+}
+
+impl ColorSupport {
+    /// What: Parse a `force_color` setting value.
+    ///
+    /// Output:
+    /// - The forced depth, or `None` for `auto` and unknown values (detect instead).
+    pub fn from_setting(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "truecolor" | "24bit" | "true" => Some(Self::TrueColor),
+            "256" | "ansi256" => Some(Self::Ansi256),
+            "16" | "ansi16" | "basic" => Some(Self::Ansi16),
+            "none" | "off" | "no" => Some(Self::None),
+            _ => None,
+        }
+    }
+}
+
+/// Color depth used for rendering; set once at startup.
+static COLOR_SUPPORT: RwLock<ColorSupport> = RwLock::new(ColorSupport::TrueColor);
+
+/// What: Set the color depth used when handing out theme colors.
+pub fn set_color_support(support: ColorSupport) {
+    if let Ok(mut g) = COLOR_SUPPORT.write() {
+        *g = support;
+    }
+}
+
+/// What: Color depth used when handing out theme colors.
+pub fn color_support() -> ColorSupport {
+    COLOR_SUPPORT
+        .read()
+        .map(|g| *g)
+        .unwrap_or(ColorSupport::TrueColor)
+}
+
+/// What: Detect the terminal's color depth from its environment.
+///
+/// Inputs:
+/// - `no_color`: Value of `NO_COLOR`.
+/// - `colorterm`: Value of `COLORTERM`.
+/// - `term`: Value of `TERM`.
+///
+/// Output:
+/// - `None` for a non-empty `NO_COLOR` or `TERM=dumb`; truecolor for `COLORTERM=truecolor|24bit`
+///   or when `TERM` is unset (e.g. Windows consoles); 256 colors for `*256color*` terminals; 16
+///   colors for the Linux console and other basic terminals.
+pub fn detect_color_support(
+    no_color: Option<&str>,
+    colorterm: Option<&str>,
+    term: Option<&str>,
+) -> ColorSupport {
+    if no_color.is_some_and(|v| !v.is_empty()) {
+        return ColorSupport::None;
+    }
+    if colorterm.is_some_and(|c| matches!(c.to_ascii_lowercase().as_str(), "truecolor" | "24bit")) {
+        return ColorSupport::TrueColor;
+    }
+    let Some(term) = term.filter(|t| !t.is_empty()) else {
+        return ColorSupport::TrueColor;
+    };
+    let term = term.to_ascii_lowercase();
+    if term == "dumb" {
+        ColorSupport::None
+    } else if term.contains("256color") || term.contains("direct") {
+        ColorSupport::Ansi256
+    } else if term == "linux" || term == "ansi" || term.starts_with("vt") || term == "cons25" {
+        ColorSupport::Ansi16
+    } else {
+        ColorSupport::Ansi256
+    }
+}
+
+/// What: Resolve the color depth from the `force_color` setting and the environment.
+pub fn resolve_color_support(force_color: &str) -> ColorSupport {
+    ColorSupport::from_setting(force_color).unwrap_or_else(|| {
+        detect_color_support(
+            std::env::var("NO_COLOR").ok().as_deref(),
+            std::env::var("COLORTERM").ok().as_deref(),
+            std::env::var("TERM").ok().as_deref(),
+        )
+    })
+}
+
+/// What: Nearest xterm 256-color palette index for an RGB color.
+///
+/// Details:
+/// - Compares the closest entry of the 6×6×6 cube with the closest grey ramp entry and keeps
+///   whichever is nearer; the first 16 (terminal-defined) entries are never used.
+pub fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    let nearest_level = |v: u8| -> usize {
+        LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, l)| (i32::from(**l) - i32::from(v)).abs())
+            .map_or(0, |(i, _)| i)
+    };
+    let (ri, gi, bi) = (nearest_level(r), nearest_level(g), nearest_level(b));
+    let cube = (LEVELS[ri], LEVELS[gi], LEVELS[bi]);
+    let cube_idx = 16 + 36 * ri as u8 + 6 * gi as u8 + bi as u8;
+    let avg = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let grey_step = (avg.saturating_sub(3) / 10).min(23) as u8;
+    let grey = 8 + 10 * grey_step;
+    let grey_idx = 232 + grey_step;
+    if distance((r, g, b), (grey, grey, grey)) < distance((r, g, b), cube) {
+        grey_idx
+    } else {
+        cube_idx
+    }
+}
+
+/// What: Nearest of the 16 basic ANSI colors for an RGB color.
+pub fn rgb_to_ansi16(r: u8, g: u8, b: u8) -> Color {
+    const BASIC: [(Color, (u8, u8, u8)); 16] = [
+        (Color::Black, (0, 0, 0)),
+        (Color::Red, (205, 0, 0)),
+        (Color::Green, (0, 205, 0)),
+        (Color::Yellow, (205, 205, 0)),
+        (Color::Blue, (0, 0, 238)),
+        (Color::Magenta, (205, 0, 205)),
+        (Color::Cyan, (0, 205, 205)),
+        (Color::Gray, (229, 229, 229)),
+        (Color::DarkGray, (127, 127, 127)),
+        (Color::LightRed, (255, 0, 0)),
+        (Color::LightGreen, (0, 255, 0)),
+        (Color::LightYellow, (255, 255, 0)),
+        (Color::LightBlue, (92, 92, 255)),
+        (Color::LightMagenta, (255, 0, 255)),
+        (Color::LightCyan, (0, 255, 255)),
+        (Color::White, (255, 255, 255)),
+    ];
+    BASIC
+        .iter()
+        .min_by_key(|(_, rgb)| distance((r, g, b), *rgb))
+        .map_or(Color::Reset, |(c, _)| *c)
+}
+
+/// What: Squared RGB distance between two colors.
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| (i32::from(x) - i32::from(y)).unsigned_abs().pow(2);
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+/// What: Map one color to what the terminal can show.
+///
+/// Output:
+/// - RGB colors quantized for 256/16-color terminals, every color `Reset` for no-color
+///   terminals, and anything else unchanged.
+pub fn adapt_color(color: Color, support: ColorSupport) -> Color {
+    match (support, color) {
+        (ColorSupport::None, _) => Color::Reset,
+        (ColorSupport::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(rgb_to_ansi256(r, g, b)),
+        (ColorSupport::Ansi16, Color::Rgb(r, g, b)) => rgb_to_ansi16(r, g, b),
+        _ => color,
+    }
+}
+
+/// What: Map every theme color with [`adapt_color`].
+pub fn adapt_theme(theme: Theme, support: ColorSupport) -> Theme {
+    if support == ColorSupport::TrueColor {
+        return theme;
+    }
+    let a = |c: Color| adapt_color(c, support);
+    Theme {
+        base: a(theme.base),
+        mantle: a(theme.mantle),
+        crust: a(theme.crust),
+        surface1: a(theme.surface1),
+        surface2: a(theme.surface2),
+        overlay1: a(theme.overlay1),
+        overlay2: a(theme.overlay2),
+        text: a(theme.text),
+        subtext0: a(theme.subtext0),
+        subtext1: a(theme.subtext1),
+        sapphire: a(theme.sapphire),
+        mauve: a(theme.mauve),
+        green: a(theme.green),
+        yellow: a(theme.yellow),
+        red: a(theme.red),
+        lavender: a(theme.lavender),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure detection honours NO_COLOR, COLORTERM, and TERM, and colors quantize sensibly.
+    ///
+    /// Inputs:
+    /// - Environment combinations; pure, grey, and palette RGB values.
+    ///
+    /// Output:
+    /// - Expected depths, exact palette indices for cube and grey colors, and `Reset` with no color.
+    fn color_support_detection_and_quantization() {
+        assert_eq!(
+            detect_color_support(Some("1"), Some("truecolor"), Some("xterm-256color")),
+            ColorSupport::None
+        );
+        assert_eq!(
+            detect_color_support(Some(""), Some("truecolor"), Some("screen")),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(
+            detect_color_support(None, None, Some("tmux-256color")),
+            ColorSupport::Ansi256
+        );
+        assert_eq!(
+            detect_color_support(None, None, Some("linux")),
+            ColorSupport::Ansi16
+        );
+        assert_eq!(
+            detect_color_support(None, None, Some("dumb")),
+            ColorSupport::None
+        );
+        assert_eq!(
+            detect_color_support(None, None, None),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(ColorSupport::from_setting("auto"), None);
+        assert_eq!(
+            ColorSupport::from_setting(" 256 "),
+            Some(ColorSupport::Ansi256)
+        );
+
+        assert_eq!(rgb_to_ansi256(255, 0, 0), 196);
+        assert_eq!(rgb_to_ansi256(0, 0, 0), 16);
+        assert_eq!(rgb_to_ansi256(128, 128, 128), 244);
+        assert_eq!(rgb_to_ansi16(250, 10, 10), Color::LightRed);
+        assert_eq!(
+            adapt_color(Color::Rgb(255, 0, 0), ColorSupport::Ansi256),
+            Color::Indexed(196)
+        );
+        assert_eq!(
+            adapt_color(Color::Rgb(1, 2, 3), ColorSupport::None),
+            Color::Reset
+        );
+        assert_eq!(
+            adapt_color(Color::Rgb(1, 2, 3), ColorSupport::TrueColor),
+            Color::Rgb(1, 2, 3)
+        );
+    }
+}
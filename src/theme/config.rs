@@ -16,6 +16,8 @@ mod theme_loader;
 mod tests;
 
 // Re-export skeleton constants (only THEME_SKELETON_CONTENT is used externally)
+#[cfg(test)]
+pub(crate) use skeletons::KEYBINDS_SKELETON_CONTENT;
 pub(crate) use skeletons::THEME_SKELETON_CONTENT;
 
 // Re-export theme loading functions
@@ -23,10 +25,11 @@ pub(crate) use theme_loader::{load_theme_from_file, try_load_theme_with_diagnost
 
 // Re-export settings save functions
 pub use settings_save::{
-    save_mirror_count, save_scan_do_clamav, save_scan_do_custom, save_scan_do_semgrep,
-    save_scan_do_shellcheck, save_scan_do_sleuth, save_scan_do_trivy, save_scan_do_virustotal,
-    save_selected_countries, save_show_install_pane, save_show_keybinds_footer,
-    save_show_recent_pane, save_sort_mode, save_virustotal_api_key,
+    save_active_theme, save_details_wrap, save_layout_pcts, save_mirror_count, save_pkgbuild_wrap,
+    save_scan_do_clamav, save_scan_do_custom, save_scan_do_semgrep, save_scan_do_shellcheck,
+    save_scan_do_sleuth, save_scan_do_trivy, save_scan_do_virustotal, save_selected_countries,
+    save_show_install_pane, save_show_keybinds_footer, save_show_recent_pane, save_sort_mode,
+    save_virustotal_api_key,
 };
 
 // Re-export settings ensure/migration functions
@@ -1,11 +1,10 @@
 use std::collections::HashSet;
 use std::fs;
-use std::path::Path;
 
 use crate::theme::config::skeletons::{
     KEYBINDS_SKELETON_CONTENT, SETTINGS_SKELETON_CONTENT, THEME_SKELETON_CONTENT,
 };
-use crate::theme::paths::{config_dir, resolve_settings_config_path};
+use crate::theme::paths::{config_dir, settings_config_target};
 use crate::theme::types::Settings;
 
 /// What: Ensure all expected settings keys exist in `settings.conf`, appending defaults as needed.
@@ -22,21 +21,15 @@ use crate::theme::types::Settings;
 pub fn ensure_settings_keys_present(prefs: &Settings) {
     // Always resolve to HOME/XDG path similar to save_sort_mode
     // This ensures we always have a path, even if the file doesn't exist yet
-    let p = resolve_settings_config_path().or_else(|| {
-        std::env::var("XDG_CONFIG_HOME")
-            .ok()
-            .map(std::path::PathBuf::from)
-            .or_else(|| {
-                std::env::var("HOME")
-                    .ok()
-                    .map(|h| Path::new(&h).join(".config"))
-            })
-            .map(|base| base.join("pacsea").join("settings.conf"))
-    });
+    let p = settings_config_target();
     let Some(p) = p else {
         // This should never happen (HOME should always be set), but if it does, we can't proceed
         return;
     };
+    // Defaults come from the key = value skeleton; a settings.toml is left as the user wrote it
+    if crate::theme::paths::is_toml_settings(&p) {
+        return;
+    }
 
     // Ensure directory exists
     if let Some(dir) = p.parent() {
@@ -80,7 +73,7 @@ pub fn ensure_settings_keys_present(prefs: &Settings) {
         }
     }
     // Desired keys and their values from prefs
-    let pairs: [(&str, String); 17] = [
+    let pairs: [(&str, String); 46] = [
         ("layout_left_pct", prefs.layout_left_pct.to_string()),
         ("layout_center_pct", prefs.layout_center_pct.to_string()),
         ("layout_right_pct", prefs.layout_right_pct.to_string()),
@@ -137,6 +130,61 @@ pub fn ensure_settings_keys_present(prefs: &Settings) {
             }
             .to_string(),
         ),
+        (
+            "installed_only_default",
+            prefs.installed_only_default.to_string(),
+        ),
+        ("fuzzy_find", prefs.fuzzy_find.to_string()),
+        (
+            "pkgbuild_cache_ttl_secs",
+            prefs.pkgbuild_cache_ttl_secs.to_string(),
+        ),
+        ("pkgbuild_highlight", prefs.pkgbuild_highlight.to_string()),
+        ("persist_throttle_ms", prefs.persist_throttle_ms.to_string()),
+        (
+            "index_refresh_interval_mins",
+            prefs.index_refresh_interval_mins.to_string(),
+        ),
+        ("aur_disabled", prefs.aur_disabled.to_string()),
+        ("compress_index", prefs.compress_index.to_string()),
+        ("preferred_aur_helper", prefs.preferred_aur_helper.clone()),
+        (
+            "deps_resolve_concurrency",
+            prefs.deps_resolve_concurrency.to_string(),
+        ),
+        ("post_install_hook", prefs.post_install_hook.clone()),
+        ("pacman_binary", prefs.pacman_binary.clone()),
+        ("privilege_escalation", prefs.privilege_escalation.clone()),
+        (
+            "virustotal_requests_per_minute",
+            prefs.virustotal_requests_per_minute.to_string(),
+        ),
+        ("custom_scan_command", prefs.custom_scan_command.clone()),
+        ("theme_auto_reload", prefs.theme_auto_reload.to_string()),
+        ("active_theme", prefs.active_theme.clone()),
+        ("force_color", prefs.force_color.clone()),
+        ("search_debounce_ms", prefs.search_debounce_ms.to_string()),
+        ("details_wrap", prefs.details_wrap.to_string()),
+        ("pkgbuild_wrap", prefs.pkgbuild_wrap.to_string()),
+        ("clipboard_osc52", prefs.clipboard_osc52.to_string()),
+        ("mouse_scroll_lines", prefs.mouse_scroll_lines.to_string()),
+        (
+            "mouse_natural_scroll",
+            prefs.mouse_natural_scroll.to_string(),
+        ),
+        (
+            "compact_mode",
+            match prefs.compact_mode {
+                Some(true) => "on",
+                Some(false) => "off",
+                None => "auto",
+            }
+            .to_string(),
+        ),
+        ("layout_autoscale", prefs.layout_autoscale.to_string()),
+        ("confirm_threshold", prefs.confirm_threshold.to_string()),
+        ("http_proxy", prefs.http_proxy.clone()),
+        ("https_proxy", prefs.https_proxy.clone()),
         ("locale", prefs.locale.clone()),
     ];
     let mut appended_any = false;
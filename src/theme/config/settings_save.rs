@@ -1,8 +1,25 @@
 use std::fs;
-use std::path::Path;
 
 use crate::theme::config::skeletons::SETTINGS_SKELETON_CONTENT;
-use crate::theme::paths::resolve_settings_config_path;
+use crate::theme::paths::{is_toml_settings, settings_config_target};
+
+/// What: Format a `key = value` line for the active settings file.
+///
+/// Inputs:
+/// - `path`: Settings file being written.
+/// - `key`, `value`: Setting to write.
+///
+/// Output:
+/// - The line; in `settings.toml`, values other than booleans and integers are quoted.
+fn setting_line(path: &std::path::Path, key: &str, value: &str) -> String {
+    let plain = value == "true" || value == "false" || value.parse::<i64>().is_ok();
+    if is_toml_settings(path) && !plain {
+        let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+        format!("{key} = \"{escaped}\"")
+    } else {
+        format!("{key} = {value}")
+    }
+}
 
 /// What: Persist the user-selected sort mode into `settings.conf` (or legacy `pacsea.conf`).
 ///
@@ -16,17 +33,7 @@ use crate::theme::paths::resolve_settings_config_path;
 /// - Ensures the target file exists by seeding from the skeleton when missing.
 /// - Replaces existing `sort_mode`/`results_sort` entries while preserving comments.
 pub fn save_sort_mode(sm: crate::state::SortMode) {
-    let path = resolve_settings_config_path().or_else(|| {
-        std::env::var("XDG_CONFIG_HOME")
-            .ok()
-            .map(std::path::PathBuf::from)
-            .or_else(|| {
-                std::env::var("HOME")
-                    .ok()
-                    .map(|h| Path::new(&h).join(".config"))
-            })
-            .map(|base| base.join("pacsea").join("settings.conf"))
-    });
+    let path = settings_config_target();
     let Some(p) = path else {
         return;
     };
@@ -48,6 +55,9 @@ pub fn save_sort_mode(sm: crate::state::SortMode) {
         } else {
             Vec::new()
         }
+    } else if is_toml_settings(&p) {
+        // The skeleton is key = value text, not TOML
+        Vec::new()
     } else {
         // File doesn't exist or is empty - start with skeleton
         SETTINGS_SKELETON_CONTENT
@@ -65,7 +75,7 @@ pub fn save_sort_mode(sm: crate::state::SortMode) {
             let (kraw, _) = trimmed.split_at(eq);
             let key = kraw.trim().to_lowercase().replace(['.', '-', ' '], "_");
             if key == "sort_mode" || key == "results_sort" {
-                *line = format!("sort_mode = {}", sm.as_config_key());
+                *line = setting_line(&p, "sort_mode", sm.as_config_key());
                 replaced = true;
             }
         }
@@ -74,10 +84,10 @@ pub fn save_sort_mode(sm: crate::state::SortMode) {
         if let Some(dir) = p.parent() {
             let _ = fs::create_dir_all(dir);
         }
-        lines.push(format!("sort_mode = {}", sm.as_config_key()));
+        lines.push(setting_line(&p, "sort_mode", sm.as_config_key()));
     }
     let new_content = if lines.is_empty() {
-        format!("sort_mode = {}\n", sm.as_config_key())
+        format!("{}\n", setting_line(&p, "sort_mode", sm.as_config_key()))
     } else {
         lines.join("\n")
     };
@@ -97,17 +107,7 @@ pub fn save_sort_mode(sm: crate::state::SortMode) {
 /// - Creates the configuration file from the skeleton when it is missing or empty.
 /// - Rewrites existing entries in place; otherwise appends the new key at the end.
 fn save_boolean_key(key_norm: &str, value: bool) {
-    let path = resolve_settings_config_path().or_else(|| {
-        std::env::var("XDG_CONFIG_HOME")
-            .ok()
-            .map(std::path::PathBuf::from)
-            .or_else(|| {
-                std::env::var("HOME")
-                    .ok()
-                    .map(|h| Path::new(&h).join(".config"))
-            })
-            .map(|base| base.join("pacsea").join("settings.conf"))
-    });
+    let path = settings_config_target();
     let Some(p) = path else {
         return;
     };
@@ -129,6 +129,9 @@ fn save_boolean_key(key_norm: &str, value: bool) {
         } else {
             Vec::new()
         }
+    } else if is_toml_settings(&p) {
+        // The skeleton is key = value text, not TOML
+        Vec::new()
     } else {
         // File doesn't exist or is empty - start with skeleton
         SETTINGS_SKELETON_CONTENT
@@ -182,17 +185,7 @@ fn save_boolean_key(key_norm: &str, value: bool) {
 /// - Bootstraps the configuration file from the skeleton if necessary.
 /// - Updates the existing key in place or appends a new line when absent.
 fn save_string_key(key_norm: &str, value: &str) {
-    let path = resolve_settings_config_path().or_else(|| {
-        std::env::var("XDG_CONFIG_HOME")
-            .ok()
-            .map(std::path::PathBuf::from)
-            .or_else(|| {
-                std::env::var("HOME")
-                    .ok()
-                    .map(|h| Path::new(&h).join(".config"))
-            })
-            .map(|base| base.join("pacsea").join("settings.conf"))
-    });
+    let path = settings_config_target();
     let Some(p) = path else {
         return;
     };
@@ -214,6 +207,9 @@ fn save_string_key(key_norm: &str, value: &str) {
         } else {
             Vec::new()
         }
+    } else if is_toml_settings(&p) {
+        // The skeleton is key = value text, not TOML
+        Vec::new()
     } else {
         // File doesn't exist or is empty - start with skeleton
         SETTINGS_SKELETON_CONTENT
@@ -231,7 +227,7 @@ fn save_string_key(key_norm: &str, value: &str) {
             let (kraw, _) = trimmed.split_at(eq);
             let key = kraw.trim().to_lowercase().replace(['.', '-', ' '], "_");
             if key == key_norm {
-                *line = format!("{key_norm} = {value}");
+                *line = setting_line(&p, key_norm, value);
                 replaced = true;
             }
         }
@@ -240,10 +236,10 @@ fn save_string_key(key_norm: &str, value: &str) {
         if let Some(dir) = p.parent() {
             let _ = fs::create_dir_all(dir);
         }
-        lines.push(format!("{key_norm} = {value}"));
+        lines.push(setting_line(&p, key_norm, value));
     }
     let new_content = if lines.is_empty() {
-        format!("{key_norm} = {value}\n")
+        format!("{}\n", setting_line(&p, key_norm, value))
     } else {
         lines.join("\n")
     };
@@ -289,6 +285,32 @@ pub fn save_show_install_pane(value: bool) {
 pub fn save_show_keybinds_footer(value: bool) {
     save_boolean_key("show_keybinds_footer", value)
 }
+/// What: Persist whether the Package Info pane wraps long lines.
+///
+/// Inputs:
+/// - `value`: New `details_wrap` flag.
+///
+/// Output:
+/// - None.
+///
+/// Details:
+/// - Delegates to `save_boolean_key("details_wrap", value)`.
+pub fn save_details_wrap(value: bool) {
+    save_boolean_key("details_wrap", value)
+}
+/// What: Persist whether the PKGBUILD viewer wraps long lines.
+///
+/// Inputs:
+/// - `value`: New `pkgbuild_wrap` flag.
+///
+/// Output:
+/// - None.
+///
+/// Details:
+/// - Delegates to `save_boolean_key("pkgbuild_wrap", value)`.
+pub fn save_pkgbuild_wrap(value: bool) {
+    save_boolean_key("pkgbuild_wrap", value)
+}
 
 /// What: Persist the comma-separated list of preferred mirror countries.
 ///
@@ -317,6 +339,22 @@ pub fn save_mirror_count(value: u16) {
     save_string_key("mirror_count", &value.to_string())
 }
 
+/// What: Persist the middle-row layout percentages.
+///
+/// Inputs:
+/// - `left`, `center`, `right`: Pane widths in percent; the caller guarantees they sum to 100.
+///
+/// Output:
+/// - None.
+///
+/// Details:
+/// - Delegates to `save_string_key` for `layout_left_pct`, `layout_center_pct` and `layout_right_pct`.
+pub fn save_layout_pcts(left: u16, center: u16, right: u16) {
+    save_string_key("layout_left_pct", &left.to_string());
+    save_string_key("layout_center_pct", &center.to_string());
+    save_string_key("layout_right_pct", &right.to_string());
+}
+
 /// What: Persist the VirusTotal API key used for scanning packages.
 ///
 /// Inputs:
@@ -331,6 +369,20 @@ pub fn save_virustotal_api_key(value: &str) {
     save_string_key("virustotal_api_key", value)
 }
 
+/// What: Persist the active named theme.
+///
+/// Inputs:
+/// - `value`: Theme name from `themes/` (empty for `theme.conf`).
+///
+/// Output:
+/// - None.
+///
+/// Details:
+/// - Delegates to `save_string_key("active_theme", ...)`.
+pub fn save_active_theme(value: &str) {
+    save_string_key("active_theme", value)
+}
+
 /// What: Persist the ClamAV scan toggle.
 ///
 /// Inputs:
@@ -162,25 +162,109 @@ pub(crate) const SETTINGS_SKELETON_CONTENT: &str = "# Pacsea settings configurat
 layout_left_pct = 20\n\
 layout_center_pct = 60\n\
 layout_right_pct = 20\n\
+# Rescale the three percentages proportionally when they do not sum to 100 (false: reset all settings to defaults)\n\
+layout_autoscale = true\n\
 # Default dry-run behavior when starting the app (overridden by --dry-run)\n\
 app_dry_run_default = false\n\
 # Middle row visibility (default true)\n\
 show_recent_pane = true\n\
 show_install_pane = true\n\
 show_keybinds_footer = true\n\
+# Compact layout for small terminals: auto (below 80 columns or 20 rows) | on | off\n\
+# Hides the keybinds footer and Results column headers and stacks panes vertically\n\
+compact_mode = auto\n\
+# Start in installed-only mode (Results show only explicitly installed packages)\n\
+installed_only_default = false\n\
+\n\
+# In-pane find (\"/\" in Recent/Install): fuzzy subsequence matching ranked by score (\"rpg\" finds \"ripgrep\")\n\
+fuzzy_find = false\n\
+# Milliseconds to wait after the last keystroke before searching (raise on slow links to save AUR requests; 0 = search on every keystroke; Enter always searches right away)\n\
+search_debounce_ms = 250\n\
+\n\
+# Mouse wheel\n\
+# Lines (Results: rows) moved per wheel notch in Results, Package Info, PKGBUILD and Help (1-20)\n\
+mouse_scroll_lines = 1\n\
+# Invert the wheel direction (natural/touchpad-style scrolling)\n\
+mouse_natural_scroll = false\n\
+\n\
+# Theme\n\
+# Reload theme.conf automatically when it is saved (invalid edits keep the current theme)\n\
+theme_auto_reload = false\n\
+# Active theme: name of a file in themes/ without .conf, or @high-contrast for the built-in preset (empty = theme.conf). Set by the theme picker / cycle key\n\
+active_theme = \n\
+# Color depth: auto (detect from COLORTERM/TERM; NO_COLOR disables colors) | truecolor | 256 | 16 | none\n\
+# Theme colors are mapped to the nearest palette entry when truecolor is unavailable\n\
+force_color = auto\n\
 \n\
 # Results sorting\n\
 # Allowed values: alphabetical | aur_popularity | best_matches\n\
 sort_mode = best_matches\n\
 \n\
+# Results filters (optional; all enabled when omitted)\n\
+# Keys: results_filter_show_<aur|core|extra|multilib|eos|cachyos|manjaro|artix|artix_*> = true|false\n\
+# results_filter_upgradable_only = true lists only installed packages with a newer version (off when omitted)\n\
+# Tip: CTRL+Y copies the current filters and sort as lines you can paste here\n\
+\n\
 # Clipboard\n\
 # Text appended when copying PKGBUILD to the clipboard\n\
 clipboard_suffix = Check PKGBUILD and source for suspicious and malicious activities\n\
+# Copy through the terminal with OSC 52 escape sequences (reaches the local clipboard over SSH).\n\
+# Also used automatically in SSH sessions without wl-copy/xclip. Payloads over ~75 KB are truncated.\n\
+clipboard_osc52 = false\n\
+\n\
+# PKGBUILD viewer\n\
+# Seconds a fetched PKGBUILD is reused from lists/pkgbuild_cache before downloading again (0 disables the cache)\n\
+pkgbuild_cache_ttl_secs = 3600\n\
+# Bash syntax highlighting in the PKGBUILD viewer (false shows plain text)\n\
+pkgbuild_highlight = true\n\
+# Wrap long lines in the Package Info pane and PKGBUILD viewer (false: scroll sideways with\n\
+# keybind_details_scroll_left/right; keybind_toggle_wrap flips the PKGBUILD viewer when open, else Package Info)\n\
+details_wrap = true\n\
+pkgbuild_wrap = true\n\
+\n\
+# Persistence\n\
+# Minimum milliseconds between disk writes of the install list and caches (raise to reduce SSD writes; pending changes are always written on exit)\n\
+persist_throttle_ms = 1000\n\
+\n\
+# Official index\n\
+# Refresh the official package index in the background every N minutes while Pacsea runs (0 = only at startup)\n\
+index_refresh_interval_mins = 0\n\
+# Store the index gzip-compressed as official_index.json.gz (smaller file, faster on slow disks)\n\
+compress_index = false\n\
+\n\
+# AUR\n\
+# Disable the AUR entirely (no AUR search results, no [AUR] filter, no AUR dependency/file/sandbox checks)\n\
+aur_disabled = false\n\
+# AUR helper tried first: paru | yay | pikaur | trizen | aurutils (empty = paru, then yay, pikaur, trizen, aurutils)\n\
+preferred_aur_helper = \n\
+\n\
+# Dependency resolution\n\
+# Packages resolved in parallel for the Deps tab (1 = sequential; AUR lookups never use more than 2)\n\
+deps_resolve_concurrency = 4\n\
+\n\
+# Network\n\
+# Proxy for AUR/Arch API requests, e.g. http://proxy:3128 or user:pass@proxy:3128 (empty = HTTP_PROXY / HTTPS_PROXY from the environment)\n\
+http_proxy = \n\
+https_proxy = \n\
+\n\
+# Package manager\n\
+# Pacman binary used for all pacman calls, queries included; must support the full pacman CLI (e.g. pacman-static)\n\
+pacman_binary = pacman\n\
+# Privilege escalation for installs/removals: sudo | doas | run0 (read-only queries never escalate)\n\
+privilege_escalation = sudo\n\
 \n\
 # Preflight modal / safety confirmation\n\
 # When true, Pacsea will bypass the Preflight confirmation modal and execute install/remove/downgrade actions immediately.\n\
 # Recommended to keep this false for safety unless you understand the risks of executing package operations directly.\n\
 skip_preflight = false\n\
+# Require typing the operation count to confirm transactions larger than this (0 = disabled)\n\
+confirm_threshold = 0\n\
+\n\
+# Post-install hook\n\
+# Command run after a successful (non dry-run) install. It runs with your shell environment via\n\
+# `bash -lc`, and the installed package names are passed as arguments (\"$@\").\n\
+# Output is written to logs/post_install_hook.log. Empty = disabled. Example: notify-send Installed\n\
+post_install_hook = \n\
 \n\
 # Mirrors\n\
 # Select one or more countries (comma-separated). Example: \"Switzerland, Germany, Austria\"\n\
@@ -272,6 +356,10 @@ scan_do_shellcheck = true\n\
 scan_do_virustotal = true\n\
 scan_do_custom = true\n\
 scan_do_sleuth = true\n\
+# Custom scanner run on each AUR PKGBUILD in the Sandbox tab (used when scan_do_custom = true).\n\
+# Runs through bash -lc; {pkgbuild} and {srcdir} are replaced with quoted paths. Non-zero exit = warning.\n\
+# Example: custom_scan_command = shellcheck -s bash {pkgbuild}\n\
+custom_scan_command = \n\
 \n\
 # News\n\
 # Symbols for read/unread indicators in the News popup\n\
@@ -281,6 +369,8 @@ news_unread_symbol = ∘\n\
 # VirusTotal\n\
 # API key used for VirusTotal scans (optional)\n\
 virustotal_api_key = \n\
+# Lookups per minute; scans pace VirusTotal requests to stay under the API quota (public API: 4, 0 = unlimited)\n\
+virustotal_requests_per_minute = 4\n\
 \n\
 # Terminal\n\
 # Preferred terminal emulator binary (optional): e.g., alacritty, kitty, gnome-terminal\n\
@@ -309,8 +399,13 @@ keybind_help = F1\n\
 # Alternative help shortcut\n\
 keybind_help = ?\n\
 keybind_reload_theme = CTRL+R\n\
+# Re-read settings.conf and keybinds.conf without restarting\n\
+keybind_reload_settings = F5\n\
+# Cycle through themes in the themes/ directory (theme.conf first)\n\
+keybind_cycle_theme = ALT+T\n\
 keybind_exit = CTRL+Q\n\
 keybind_show_pkgbuild = CTRL+X\n\
+keybind_pkgbuild_next_mark = ALT+N\n\
 \n\
 # GLOBAL — Pane switching\n\
 keybind_pane_left = Left\n\
@@ -318,6 +413,52 @@ keybind_pane_right = Right\n\
 keybind_pane_next = Tab\n\
 # GLOBAL — Sorting\n\
 keybind_change_sort = BackTab\n\
+# GLOBAL — Debugging\n\
+keybind_toggle_download_server = CTRL+S\n\
+# GLOBAL — Share view (filters + sort as settings.conf lines)\n\
+keybind_copy_view_settings = CTRL+Y\n\
+# LISTS — Jump to first/last entry (space-separated keys form a sequence, e.g. g g)\n\
+keybind_goto_top = g g\n\
+keybind_goto_bottom = G\n\
+# GLOBAL — Reopen the last closed dialog\n\
+keybind_reopen_modal = CTRL+O\n\
+# GLOBAL — Verbose status line (index freshness, upgradable and queued counts)\n\
+keybind_toggle_status_verbose = CTRL+T\n\
+# GLOBAL — Active resolution stages (tracing spans) in the status line\n\
+keybind_toggle_status_spans = F12\n\
+# GLOBAL — Store the selected package in compare slot A / B (B opens the side-by-side diff)\n\
+keybind_compare_slot_a = ALT+A\n\
+keybind_compare_slot_b = ALT+B\n\
+# GLOBAL — Open the selected package's upstream homepage\n\
+keybind_open_homepage = ALT+H\n\
+# GLOBAL — Open the selected package's AUR / archlinux.org package page\n\
+keybind_open_package_page = ALT+O\n\
+# GLOBAL — Preview the exact pacman/AUR commands for the focused list\n\
+keybind_preview_commands = ALT+P\n\
+# GLOBAL — Show why the selected package is installed (reverse dependency chains)\n\
+keybind_why_installed = ALT+W\n\
+# GLOBAL — List the files the selected package installs (pacman -Fl / -Ql), with a filter\n\
+keybind_show_files = ALT+V\n\
+# GLOBAL — Clear the install, remove, and downgrade lists (asks for confirmation)\n\
+keybind_clear_all_lists = ALT+C\n\
+# GLOBAL — Undo / redo changes to the install, remove, and downgrade lists\n\
+keybind_undo = CTRL+Z\n\
+keybind_redo = ALT+Z\n\
+# GLOBAL — Command palette: search and run any action\n\
+keybind_command_palette = CTRL+P\n\
+# GLOBAL — Logs viewer: tail Pacsea's own log with a level filter\n\
+keybind_open_logs = CTRL+L\n\
+# GLOBAL — Scroll long Package Info lines sideways (the PKGBUILD viewer instead while it is open)\n\
+keybind_details_scroll_left = ALT+Left\n\
+keybind_details_scroll_right = ALT+Right\n\
+# GLOBAL — Toggle line wrapping (PKGBUILD viewer when open, else Package Info)\n\
+keybind_toggle_wrap = ALT+R\n\
+# GLOBAL — Re-read installed packages now (after installing or removing in another terminal)\n\
+keybind_refresh_installed = ALT+I\n\
+# GLOBAL — Open Preflight directly on a tab (Search: selected result, Install: the list)\n\
+keybind_preflight_tab_deps = ALT+D\n\
+keybind_preflight_tab_files = ALT+F\n\
+keybind_preflight_tab_services = ALT+S\n\
 \n\
 # SEARCH — Navigation\n\
 keybind_search_move_up = Up\n\
@@ -328,6 +469,8 @@ keybind_search_page_down = PgDn\n\
 # SEARCH — Actions\n\
 keybind_search_add = Space\n\
 keybind_search_install = Enter\n\
+keybind_search_multi_select = ALT+M\n\
+keybind_search_add_selected = ALT+L\n\
 \n\
 # SEARCH — Focus/Edit\n\
 keybind_search_focus_left = Left\n\
@@ -380,6 +523,10 @@ keybind_install_confirm = Enter\n\
 keybind_install_remove = Del\n\
 keybind_install_remove = d\n\
 keybind_install_clear = Shift+Del\n\
+# Install list: remove all members of the selected entry's package group\n\
+keybind_install_remove_group = Shift+D\n\
+# Downgrade list: choose a version from the pacman cache\n\
+keybind_install_pick_version = v\n\
 \n\
 # INSTALL — Find/Focus\n\
 keybind_install_find = /\n\
@@ -0,0 +1,337 @@
+//! Validation of `keybinds.conf` and the resolved key map (Keybinds diagnostics, startup warnings).
+
+use std::collections::HashMap;
+
+use super::parsing::{parse_key_chord, parse_key_sequence, strip_inline_comment};
+use super::paths::resolve_keybinds_config_path;
+use super::types::{KeyChord, KeyMap};
+
+/// Actions whose value is a key sequence (e.g. `g g`) rather than a single chord.
+const SEQUENCE_ACTIONS: [&str; 2] = ["keybind_goto_top", "keybind_goto_bottom"];
+
+/// Modifier names understood by `parse_key_chord`.
+const MODIFIERS: [&str; 7] = ["CTRL", "CONTROL", "ALT", "SHIFT", "SUPER", "META", "WIN"];
+
+/// What: Context an action is dispatched in; only bindings within the same context can collide.
+///
+/// Inputs:
+/// - `action`: Action name, with or without the `keybind_` prefix.
+///
+/// Output:
+/// - `"global"`, `"search"`, `"recent"`, `"install"`, or `"news"`.
+///
+/// Details:
+/// - Global bindings are checked before pane handlers, and pane handlers only run for the
+///   focused pane, so e.g. `keybind_search_move_up` and `keybind_recent_move_up` may share a key.
+fn action_scope(action: &str) -> &'static str {
+    let name = action.trim_start_matches("keybind_");
+    [
+        ("search_", "search"),
+        ("recent_", "recent"),
+        ("install_", "install"),
+        ("news_", "news"),
+    ]
+    .into_iter()
+    .find(|(prefix, _)| name.starts_with(prefix))
+    .map_or("global", |(_, scope)| scope)
+}
+
+/// A chord bound to several actions of the same context in the resolved [`KeyMap`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeymapConflict {
+    /// Context the actions share (see `action_scope`).
+    pub context: &'static str,
+    /// The shared chord.
+    pub chord: KeyChord,
+    /// Actions bound to it, in [`KeyMap`] field order.
+    pub actions: Vec<&'static str>,
+}
+
+/// What: Single-chord actions of a [`KeyMap`] with their field names.
+///
+/// Details:
+/// - Key sequences (`goto_top`, `goto_bottom`) are left out; they start with plain letters that
+///   only act as a prefix.
+fn keymap_actions(km: &KeyMap) -> Vec<(&'static str, &Vec<KeyChord>)> {
+    vec![
+        ("help_overlay", &km.help_overlay),
+        ("reload_theme", &km.reload_theme),
+        ("reload_settings", &km.reload_settings),
+        ("cycle_theme", &km.cycle_theme),
+        ("exit", &km.exit),
+        ("show_pkgbuild", &km.show_pkgbuild),
+        ("pkgbuild_next_mark", &km.pkgbuild_next_mark),
+        ("change_sort", &km.change_sort),
+        ("toggle_download_server", &km.toggle_download_server),
+        ("copy_view_settings", &km.copy_view_settings),
+        ("reopen_modal", &km.reopen_modal),
+        ("toggle_status_verbose", &km.toggle_status_verbose),
+        ("toggle_status_spans", &km.toggle_status_spans),
+        ("compare_slot_a", &km.compare_slot_a),
+        ("compare_slot_b", &km.compare_slot_b),
+        ("open_homepage", &km.open_homepage),
+        ("open_package_page", &km.open_package_page),
+        ("preview_commands", &km.preview_commands),
+        ("why_installed", &km.why_installed),
+        ("show_files", &km.show_files),
+        ("clear_all_lists", &km.clear_all_lists),
+        ("undo", &km.undo),
+        ("redo", &km.redo),
+        ("command_palette", &km.command_palette),
+        ("open_logs", &km.open_logs),
+        ("details_scroll_left", &km.details_scroll_left),
+        ("details_scroll_right", &km.details_scroll_right),
+        ("toggle_wrap", &km.toggle_wrap),
+        ("refresh_installed", &km.refresh_installed),
+        ("preflight_tab_deps", &km.preflight_tab_deps),
+        ("preflight_tab_files", &km.preflight_tab_files),
+        ("preflight_tab_services", &km.preflight_tab_services),
+        ("pane_next", &km.pane_next),
+        ("pane_left", &km.pane_left),
+        ("pane_right", &km.pane_right),
+        ("config_menu_toggle", &km.config_menu_toggle),
+        ("options_menu_toggle", &km.options_menu_toggle),
+        ("panels_menu_toggle", &km.panels_menu_toggle),
+        ("search_move_up", &km.search_move_up),
+        ("search_move_down", &km.search_move_down),
+        ("search_page_up", &km.search_page_up),
+        ("search_page_down", &km.search_page_down),
+        ("search_add", &km.search_add),
+        ("search_install", &km.search_install),
+        ("search_multi_select", &km.search_multi_select),
+        ("search_add_selected", &km.search_add_selected),
+        ("search_focus_left", &km.search_focus_left),
+        ("search_focus_right", &km.search_focus_right),
+        ("search_backspace", &km.search_backspace),
+        ("search_normal_toggle", &km.search_normal_toggle),
+        ("search_normal_insert", &km.search_normal_insert),
+        ("search_normal_select_left", &km.search_normal_select_left),
+        ("search_normal_select_right", &km.search_normal_select_right),
+        ("search_normal_delete", &km.search_normal_delete),
+        ("search_normal_clear", &km.search_normal_clear),
+        ("search_normal_open_status", &km.search_normal_open_status),
+        ("search_normal_import", &km.search_normal_import),
+        ("search_normal_export", &km.search_normal_export),
+        ("recent_move_up", &km.recent_move_up),
+        ("recent_move_down", &km.recent_move_down),
+        ("recent_find", &km.recent_find),
+        ("recent_use", &km.recent_use),
+        ("recent_add", &km.recent_add),
+        ("recent_to_search", &km.recent_to_search),
+        ("recent_focus_right", &km.recent_focus_right),
+        ("recent_remove", &km.recent_remove),
+        ("recent_clear", &km.recent_clear),
+        ("install_move_up", &km.install_move_up),
+        ("install_move_down", &km.install_move_down),
+        ("install_confirm", &km.install_confirm),
+        ("install_remove", &km.install_remove),
+        ("install_clear", &km.install_clear),
+        ("install_remove_group", &km.install_remove_group),
+        ("install_pick_version", &km.install_pick_version),
+        ("install_find", &km.install_find),
+        ("install_to_search", &km.install_to_search),
+        ("install_focus_left", &km.install_focus_left),
+        ("news_mark_read", &km.news_mark_read),
+        ("news_mark_all_read", &km.news_mark_all_read),
+    ]
+}
+
+/// What: Find chords that trigger more than one action in the same context.
+///
+/// Inputs:
+/// - `km`: Resolved key map (defaults merged with `keybinds.conf`).
+///
+/// Output:
+/// - One entry per colliding chord, in [`KeyMap`] field order of its first action; empty when
+///   every chord is unambiguous.
+///
+/// Details:
+/// - The event dispatcher runs whichever handler matches first, so the later actions of a
+///   conflict can never be triggered.
+pub fn find_keymap_conflicts(km: &KeyMap) -> Vec<KeymapConflict> {
+    let mut conflicts: Vec<KeymapConflict> = Vec::new();
+    for (action, chords) in keymap_actions(km) {
+        let context = action_scope(action);
+        for chord in chords {
+            match conflicts
+                .iter_mut()
+                .find(|c| c.context == context && c.chord == *chord)
+            {
+                Some(c) if !c.actions.contains(&action) => c.actions.push(action),
+                Some(_) => {}
+                None => conflicts.push(KeymapConflict {
+                    context,
+                    chord: *chord,
+                    actions: vec![action],
+                }),
+            }
+        }
+    }
+    conflicts.retain(|c| c.actions.len() > 1);
+    conflicts
+}
+
+/// What: Explain why a chord spec does not parse, or `None` when it does.
+///
+/// Details:
+/// - Also rejects specs with more than one key (e.g. a misspelt modifier such as `CTLR+R`),
+///   which `parse_key_chord` would otherwise read as just the last key.
+fn chord_error(spec: &str) -> Option<String> {
+    if spec.is_empty() {
+        return Some("no key given".to_string());
+    }
+    let keys: Vec<&str> = spec
+        .split('+')
+        .map(str::trim)
+        .filter(|p| !p.is_empty() && !MODIFIERS.contains(&p.to_ascii_uppercase().as_str()))
+        .collect();
+    if keys.len() > 1 {
+        return Some(format!(
+            "'{spec}' has more than one key ({}); check the modifier spelling",
+            keys.join(", ")
+        ));
+    }
+    if parse_key_chord(spec).is_none() {
+        return Some(format!("'{spec}' is not a valid key chord"));
+    }
+    None
+}
+
+/// What: Check `keybinds.conf` content for bindings the settings loader would ignore or that
+/// collide.
+///
+/// Inputs:
+/// - `content`: File contents.
+///
+/// Output:
+/// - `(line_number, reason)` pairs (1-based lines) in file order; empty when every binding is
+///   usable.
+///
+/// Details:
+/// - Reports `keybind_*` lines without `=`, values that fail to parse, and a chord bound to two
+///   different actions in the same pane (the later line is reported).
+/// - Non-keybind lines are skipped so the legacy combined `pacsea.conf` can be checked too.
+pub fn validate_keybinds_content(content: &str) -> Vec<(usize, String)> {
+    let mut issues = Vec::new();
+    let mut seen: HashMap<(&'static str, Vec<KeyChord>), (String, usize)> = HashMap::new();
+    for (idx, line) in content.lines().enumerate() {
+        let line_no = idx + 1;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with("//") {
+            continue;
+        }
+        let Some((raw_key, raw_val)) = trimmed.split_once('=') else {
+            if trimmed.to_lowercase().starts_with("keybind_") {
+                issues.push((line_no, format!("'{trimmed}' is missing '= <key>'")));
+            }
+            continue;
+        };
+        let action = raw_key.trim().to_lowercase().replace(['.', '-', ' '], "_");
+        if !action.starts_with("keybind_") {
+            continue;
+        }
+        let val = strip_inline_comment(raw_val.trim());
+        let chords = if SEQUENCE_ACTIONS.contains(&action.as_str()) {
+            match parse_key_sequence(val) {
+                Some(seq) => seq,
+                None => {
+                    issues.push((line_no, format!("'{val}' is not a valid key sequence")));
+                    continue;
+                }
+            }
+        } else {
+            if let Some(reason) = chord_error(val) {
+                issues.push((line_no, reason));
+                continue;
+            }
+            parse_key_chord(val).into_iter().collect()
+        };
+        let scope = action_scope(&action);
+        match seen.get(&(scope, chords.clone())) {
+            Some((other, other_line)) if *other != action => issues.push((
+                line_no,
+                format!("'{val}' is already bound to {other} on line {other_line}"),
+            )),
+            Some(_) => {}
+            None => {
+                seen.insert((scope, chords), (action, line_no));
+            }
+        }
+    }
+    issues
+}
+
+/// What: Validate the active `keybinds.conf`.
+///
+/// Output:
+/// - Problems as `(line_number, reason)`; empty when the file is clean or absent.
+pub fn validate_keybinds() -> Vec<(usize, String)> {
+    resolve_keybinds_config_path()
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .map(|content| validate_keybinds_content(&content))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure bad chords and cross-action conflicts are reported with line numbers.
+    ///
+    /// Inputs:
+    /// - The shipped keybinds skeleton, then a file with a misspelt modifier, a bogus key, a
+    ///   missing `=`, a bad sequence, and two actions sharing `CTRL+R`.
+    ///
+    /// Output:
+    /// - No issues for the skeleton; one issue per bad line for the second file, while the same
+    ///   key in different panes is accepted.
+    fn keybind_check_reports_invalid_and_conflicting_lines() {
+        assert_eq!(
+            validate_keybinds_content(super::super::config::KEYBINDS_SKELETON_CONTENT),
+            Vec::new()
+        );
+
+        let content = "# comment\n\
+keybind_exit = CTLR+Q\n\
+keybind_undo = Hyper\n\
+keybind_redo\n\
+keybind_goto_top = g Nope\n\
+keybind_reload_theme = CTRL+R\n\
+keybind_undo = ctrl+r # oops\n\
+keybind_search_move_up = k\n\
+keybind_recent_move_up = k\n\
+layout_left_pct = 20\n";
+        let issues = validate_keybinds_content(content);
+        let lines: Vec<usize> = issues.iter().map(|(l, _)| *l).collect();
+        assert_eq!(lines, vec![2, 3, 4, 5, 7]);
+        assert!(issues[0].1.contains("modifier"));
+        assert!(issues[4].1.contains("keybind_reload_theme on line 6"));
+    }
+
+    #[test]
+    /// What: Ensure resolved key maps are checked for collisions within a context only.
+    ///
+    /// Inputs:
+    /// - The default key map; one with `undo` moved onto `reload_theme`'s chord; one where a
+    ///   Recent binding reuses a Search key.
+    ///
+    /// Output:
+    /// - No conflicts for the defaults and the cross-pane overlap; one global conflict naming
+    ///   both actions otherwise.
+    fn keybind_check_finds_keymap_conflicts() {
+        let defaults = KeyMap::default();
+        assert_eq!(find_keymap_conflicts(&defaults), Vec::new());
+
+        let mut km = defaults.clone();
+        km.undo = km.reload_theme.clone();
+        let conflicts = find_keymap_conflicts(&km);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].context, "global");
+        assert_eq!(conflicts[0].actions, vec!["reload_theme", "undo"]);
+
+        let mut km = defaults.clone();
+        km.recent_find = km.search_page_up.clone();
+        assert!(find_keymap_conflicts(&km).is_empty());
+    }
+}
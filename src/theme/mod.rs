@@ -3,24 +3,36 @@
 //! Split from a monolithic file into submodules for maintainability. Public
 //! re-exports keep the `crate::theme::*` API stable.
 
+mod color;
 mod config;
+mod keybind_check;
+mod named;
 mod parsing;
 mod paths;
 mod settings;
 mod store;
 mod types;
+mod watch;
 
+pub use color::{ColorSupport, resolve_color_support, set_color_support};
 pub use config::{
-    ensure_settings_keys_present, maybe_migrate_legacy_confs, save_mirror_count,
-    save_scan_do_clamav, save_scan_do_custom, save_scan_do_semgrep, save_scan_do_shellcheck,
-    save_scan_do_sleuth, save_scan_do_trivy, save_scan_do_virustotal, save_selected_countries,
-    save_show_install_pane, save_show_keybinds_footer, save_show_recent_pane, save_sort_mode,
-    save_virustotal_api_key,
+    ensure_settings_keys_present, maybe_migrate_legacy_confs, save_active_theme, save_details_wrap,
+    save_layout_pcts, save_mirror_count, save_pkgbuild_wrap, save_scan_do_clamav,
+    save_scan_do_custom, save_scan_do_semgrep, save_scan_do_shellcheck, save_scan_do_sleuth,
+    save_scan_do_trivy, save_scan_do_virustotal, save_selected_countries, save_show_install_pane,
+    save_show_keybinds_footer, save_show_recent_pane, save_sort_mode, save_virustotal_api_key,
 };
-pub use paths::{config_dir, lists_dir, logs_dir};
+pub use keybind_check::{
+    KeymapConflict, find_keymap_conflicts, validate_keybinds, validate_keybinds_content,
+};
+pub use named::{HIGH_CONTRAST, ThemeScan, next_theme, scan_themes, switch_theme, themes_dir};
+pub use paths::{config_dir, lists_dir, logs_dir, set_config_dir_override};
+#[cfg(test)]
+pub(crate) use settings::apply_settings_content;
 pub use settings::settings;
-pub use store::{reload_theme, theme};
-pub use types::{KeyChord, KeyMap, PackageMarker, Settings, Theme};
+pub use store::{active_theme_path, reload_theme, theme};
+pub use types::{HIGH_CONTRAST_THEME, KeyChord, KeyMap, PackageMarker, Settings, Theme};
+pub use watch::{start_theme_watcher, take_theme_changed};
 
 #[cfg(test)]
 static TEST_MUTEX: std::sync::OnceLock<std::sync::Mutex<()>> = std::sync::OnceLock::new();
@@ -0,0 +1,126 @@
+//! Named themes stored as `config_dir/themes/<name>.conf`.
+
+use std::path::{Path, PathBuf};
+
+use super::config::try_load_theme_with_diagnostics;
+use super::paths::config_dir;
+
+/// Name of the built-in high-contrast preset (the `@` keeps it apart from file names).
+pub const HIGH_CONTRAST: &str = "@high-contrast";
+
+/// What: Directory holding named themes.
+pub fn themes_dir() -> PathBuf {
+    config_dir().join("themes")
+}
+
+/// What: Named themes found in a themes directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThemeScan {
+    /// Names (file stems) of themes that load cleanly, sorted.
+    pub valid: Vec<String>,
+    /// Themes that failed validation, with the reason.
+    pub invalid: Vec<(String, String)>,
+}
+
+/// What: List and validate the `*.conf` themes in `dir`.
+///
+/// Output:
+/// - Valid and invalid theme names, each sorted; an empty scan when `dir` does not exist.
+pub fn scan_themes(dir: &Path) -> ThemeScan {
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.extension().is_some_and(|x| x == "conf"))
+        .collect();
+    paths.sort();
+    let mut scan = ThemeScan::default();
+    for path in paths {
+        let Some(name) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        match try_load_theme_with_diagnostics(&path) {
+            Ok(_) => scan.valid.push(name.to_string()),
+            Err(e) => scan.invalid.push((name.to_string(), e)),
+        }
+    }
+    scan
+}
+
+/// What: Theme that follows `current` when cycling.
+///
+/// Inputs:
+/// - `current`: Active theme name (empty for the default `theme.conf`).
+/// - `valid`: Valid named themes, sorted.
+///
+/// Output:
+/// - The next name in the order default (empty), [`HIGH_CONTRAST`], then `valid`, wrapping
+///   back to the default.
+pub fn next_theme(current: &str, valid: &[String]) -> String {
+    let order: Vec<&str> = ["", HIGH_CONTRAST]
+        .into_iter()
+        .chain(valid.iter().map(String::as_str))
+        .collect();
+    let pos = order.iter().position(|n| *n == current).unwrap_or(0);
+    order[(pos + 1) % order.len()].to_string()
+}
+
+/// What: Show the named theme `name` (empty for the default `theme.conf`).
+///
+/// Output:
+/// - `Ok(())` when switched; `Err` with the validation message otherwise (the current theme
+///   stays).
+///
+/// Details:
+/// - [`HIGH_CONTRAST`] selects the built-in preset without touching the filesystem.
+pub fn switch_theme(name: &str) -> Result<(), String> {
+    if name == HIGH_CONTRAST {
+        super::store::use_builtin_theme(super::types::HIGH_CONTRAST_THEME)
+    } else if name.is_empty() {
+        super::store::load_theme_from(None)
+    } else {
+        let path = themes_dir().join(format!("{name}.conf"));
+        if !path.is_file() {
+            return Err(format!(
+                "theme '{name}' not found in {}",
+                themes_dir().display()
+            ));
+        }
+        super::store::load_theme_from(Some(&path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure invalid themes are reported and cycling wraps through the default.
+    ///
+    /// Inputs:
+    /// - A themes dir with the skeleton as `dark.conf`, a broken `bad.conf`, and a non-`.conf` file.
+    ///
+    /// Output:
+    /// - `dark` listed as valid, `bad` as invalid; cycling goes default → high contrast → dark →
+    ///   default.
+    fn named_themes_scan_and_cycle() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("dark.conf"),
+            super::super::config::THEME_SKELETON_CONTENT,
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("bad.conf"), "base = nonsense\n").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "x").unwrap();
+        let scan = scan_themes(dir.path());
+        assert_eq!(scan.valid, vec!["dark".to_string()]);
+        assert_eq!(scan.invalid.len(), 1);
+        assert_eq!(scan.invalid[0].0, "bad");
+        assert_eq!(next_theme("", &scan.valid), HIGH_CONTRAST);
+        assert_eq!(next_theme(HIGH_CONTRAST, &scan.valid), "dark");
+        assert_eq!(next_theme("dark", &scan.valid), "");
+        assert_eq!(next_theme("gone", &scan.valid), HIGH_CONTRAST);
+        assert!(scan_themes(&dir.path().join("missing")).valid.is_empty());
+    }
+}
@@ -91,6 +91,43 @@ pub(crate) fn parse_key_chord(spec: &str) -> Option<KeyChord> {
     Some(KeyChord { code, mods })
 }
 
+/// What: Parse a space-separated key sequence such as "g g" or "Ctrl+W h" into chords.
+///
+/// Inputs:
+/// - `spec`: Sequence specification; each whitespace-separated token is one chord.
+///
+/// Output:
+/// - `Some(Vec<KeyChord>)` when every chord parses; `None` if any token is invalid or the spec is empty.
+///
+/// Details:
+/// - Spaces around `+` are tolerated, so "Ctrl + R" is still a single chord.
+/// - A bare uppercase letter (e.g., "G") is read as `Shift+g`, matching what terminals send.
+pub(crate) fn parse_key_sequence(spec: &str) -> Option<Vec<KeyChord>> {
+    let mut tokens: Vec<String> = Vec::new();
+    for word in spec.split_whitespace() {
+        match tokens.last_mut() {
+            Some(prev) if prev.ends_with('+') || word.starts_with('+') => prev.push_str(word),
+            _ => tokens.push(word.to_string()),
+        }
+    }
+    if tokens.is_empty() {
+        return None;
+    }
+    tokens
+        .iter()
+        .map(|t| {
+            let mut chars = t.chars();
+            match (chars.next(), chars.next()) {
+                (Some(ch), None) if ch.is_ascii_uppercase() => Some(KeyChord {
+                    code: KeyCode::Char(ch.to_ascii_lowercase()),
+                    mods: KeyModifiers::SHIFT,
+                }),
+                _ => parse_key_chord(t),
+            }
+        })
+        .collect()
+}
+
 /// What: Parse a color literal from configuration text into a [`Color`].
 ///
 /// Inputs:
@@ -362,6 +399,36 @@ mod tests {
         assert!(bt.mods.is_empty());
     }
 
+    #[test]
+    /// What: Ensure key sequences split on whitespace while keeping modifier chords intact.
+    ///
+    /// Inputs:
+    /// - Specs `g g`, `G`, `Ctrl + W h`, and an invalid token sequence.
+    ///
+    /// Output:
+    /// - Two-chord, Shift-chord, and modifier+plain sequences; `None` for invalid input.
+    ///
+    /// Details:
+    /// - Covers the spacing tolerance around `+` so existing single-chord specs still parse.
+    fn parsing_key_sequence() {
+        let gg = parse_key_sequence("g g").unwrap();
+        assert_eq!(gg.len(), 2);
+        assert!(
+            gg.iter()
+                .all(|c| c.code == KeyCode::Char('g') && c.mods.is_empty())
+        );
+        let big_g = parse_key_sequence("G").unwrap();
+        assert_eq!(big_g[0].code, KeyCode::Char('g'));
+        assert_eq!(big_g[0].mods, KeyModifiers::SHIFT);
+        let cw = parse_key_sequence("Ctrl + W h").unwrap();
+        assert_eq!(cw.len(), 2);
+        assert_eq!(cw[0].code, KeyCode::Char('w'));
+        assert!(cw[0].mods.contains(KeyModifiers::CONTROL));
+        assert_eq!(cw[1].code, KeyCode::Char('h'));
+        assert!(parse_key_sequence("g nope").is_none());
+        assert!(parse_key_sequence("   ").is_none());
+    }
+
     #[test]
     /// What: Validate colour parsing and mapping helpers used by theme configuration.
     ///
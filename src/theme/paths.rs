@@ -1,5 +1,96 @@
 use std::env;
 use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+/// Base directory from `--config-dir` / `PACSEA_CONFIG_DIR`; replaces every HOME/XDG lookup.
+static CONFIG_DIR_OVERRIDE: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// What: Prepare a directory to serve as the Pacsea config base.
+///
+/// Inputs:
+/// - `dir`: Requested base directory (relative paths resolve against the working directory).
+///
+/// Output:
+/// - The absolute directory on success, or a message naming the path and the failure.
+///
+/// Details:
+/// - Creates the directory with its `lists` and `logs` subdirectories, then writes and removes
+///   a probe file so a read-only location fails up front instead of on the first save.
+fn prepare_config_dir(dir: &Path) -> Result<PathBuf, String> {
+    let dir = std::path::absolute(dir).unwrap_or_else(|_| dir.to_path_buf());
+    for sub in [dir.clone(), dir.join("lists"), dir.join("logs")] {
+        std::fs::create_dir_all(&sub)
+            .map_err(|e| format!("cannot create config directory {}: {e}", sub.display()))?;
+    }
+    let probe = dir.join(".pacsea_write_test");
+    std::fs::write(&probe, b"")
+        .map_err(|e| format!("config directory {} is not writable: {e}", dir.display()))?;
+    let _ = std::fs::remove_file(&probe);
+    Ok(dir)
+}
+
+/// What: Use `dir` as the base for all config, list, and log files (`--config-dir`).
+///
+/// Inputs:
+/// - `dir`: Requested base directory.
+///
+/// Output:
+/// - The absolute directory now in use, or an error when it cannot be created or written.
+pub fn set_config_dir_override(dir: &Path) -> Result<PathBuf, String> {
+    let dir = prepare_config_dir(dir)?;
+    if let Ok(mut g) = CONFIG_DIR_OVERRIDE.write() {
+        *g = Some(dir.clone());
+    }
+    Ok(dir)
+}
+
+/// What: Active `--config-dir` override, if any.
+fn config_dir_override() -> Option<PathBuf> {
+    CONFIG_DIR_OVERRIDE.read().ok().and_then(|g| g.clone())
+}
+
+/// What: Directories searched for config files, most preferred first.
+///
+/// Inputs:
+/// - `override_dir`: Active `--config-dir`, which replaces the HOME/XDG bases.
+/// - `home`, `xdg_config`: Values of `HOME` and `XDG_CONFIG_HOME`.
+fn config_bases(
+    override_dir: Option<&Path>,
+    home: Option<&str>,
+    xdg_config: Option<&str>,
+) -> Vec<PathBuf> {
+    match override_dir {
+        Some(dir) => vec![dir.to_path_buf()],
+        None => home
+            .map(|h| Path::new(h).join(".config").join("pacsea"))
+            .into_iter()
+            .chain(xdg_config.map(|x| Path::new(x).join("pacsea")))
+            .collect(),
+    }
+}
+
+/// What: Bases for the current environment.
+fn current_config_bases() -> Vec<PathBuf> {
+    let home = env::var("HOME").ok();
+    let xdg_config = env::var("XDG_CONFIG_HOME").ok();
+    config_bases(
+        config_dir_override().as_deref(),
+        home.as_deref(),
+        xdg_config.as_deref(),
+    )
+}
+
+/// What: First existing `<base>/<file>`, falling back to the legacy `<base>/pacsea.conf`.
+fn resolve_config_file(file: &str) -> Option<PathBuf> {
+    current_config_bases()
+        .iter()
+        .flat_map(|b| [b.join(file), b.join("pacsea.conf")]) // second is legacy
+        .find(|p| p.is_file())
+}
+
+/// Set once the "both settings files exist" notice has been logged.
+static LEGACY_SETTINGS_NOTED: std::sync::atomic::AtomicBool =
+    std::sync::atomic::AtomicBool::new(false);
 
 /// What: Locate the active theme configuration file, considering modern and legacy layouts.
 ///
@@ -11,21 +102,9 @@ use std::path::{Path, PathBuf};
 ///
 /// Details:
 /// - Prefers `$HOME/.config/pacsea/theme.conf`, then legacy `pacsea.conf`, and repeats for XDG paths.
+/// - With `--config-dir`, only that directory is searched.
 pub(crate) fn resolve_theme_config_path() -> Option<PathBuf> {
-    let home = env::var("HOME").ok();
-    let xdg_config = env::var("XDG_CONFIG_HOME").ok();
-    let mut candidates: Vec<PathBuf> = Vec::new();
-    if let Some(h) = home.as_deref() {
-        let base = Path::new(h).join(".config").join("pacsea");
-        candidates.push(base.join("theme.conf"));
-        candidates.push(base.join("pacsea.conf")); // legacy
-    }
-    if let Some(xdg) = xdg_config.as_deref() {
-        let x = Path::new(xdg).join("pacsea");
-        candidates.push(x.join("theme.conf"));
-        candidates.push(x.join("pacsea.conf")); // legacy
-    }
-    candidates.into_iter().find(|p| p.is_file())
+    resolve_config_file("theme.conf")
 }
 
 /// What: Locate the active settings configuration file, prioritizing the split layout.
@@ -38,21 +117,31 @@ pub(crate) fn resolve_theme_config_path() -> Option<PathBuf> {
 ///
 /// Details:
 /// - Searches `$HOME` and `XDG_CONFIG_HOME` for `settings.conf`, then falls back to `pacsea.conf`.
+/// - A `settings.toml` in any searched directory wins over both (logged once when both exist).
 pub(crate) fn resolve_settings_config_path() -> Option<PathBuf> {
-    let home = env::var("HOME").ok();
-    let xdg_config = env::var("XDG_CONFIG_HOME").ok();
-    let mut candidates: Vec<PathBuf> = Vec::new();
-    if let Some(h) = home.as_deref() {
-        let base = Path::new(h).join(".config").join("pacsea");
-        candidates.push(base.join("settings.conf"));
-        candidates.push(base.join("pacsea.conf")); // legacy
-    }
-    if let Some(xdg) = xdg_config.as_deref() {
-        let x = Path::new(xdg).join("pacsea");
-        candidates.push(x.join("settings.conf"));
-        candidates.push(x.join("pacsea.conf")); // legacy
+    let toml = current_config_bases()
+        .into_iter()
+        .map(|b| b.join("settings.toml"))
+        .find(|p| p.is_file());
+    let legacy = resolve_config_file("settings.conf");
+    match (toml, legacy) {
+        (Some(toml), Some(legacy)) => {
+            if !LEGACY_SETTINGS_NOTED.swap(true, std::sync::atomic::Ordering::Relaxed) {
+                tracing::info!(
+                    toml = %toml.display(),
+                    ignored = %legacy.display(),
+                    "settings.toml found; ignoring the key = value settings file"
+                );
+            }
+            Some(toml)
+        }
+        (toml, legacy) => toml.or(legacy),
     }
-    candidates.into_iter().find(|p| p.is_file())
+}
+
+/// What: Whether a resolved settings file uses the TOML format (by extension).
+pub(crate) fn is_toml_settings(path: &Path) -> bool {
+    path.extension().and_then(|e| e.to_str()) == Some("toml")
 }
 
 /// What: Locate the keybindings configuration file for Pacsea.
@@ -66,20 +155,25 @@ pub(crate) fn resolve_settings_config_path() -> Option<PathBuf> {
 /// Details:
 /// - Checks both `$HOME/.config/pacsea/keybinds.conf` and the legacy `pacsea.conf`, mirrored for XDG.
 pub(crate) fn resolve_keybinds_config_path() -> Option<PathBuf> {
-    let home = env::var("HOME").ok();
-    let xdg_config = env::var("XDG_CONFIG_HOME").ok();
-    let mut candidates: Vec<PathBuf> = Vec::new();
-    if let Some(h) = home.as_deref() {
-        let base = Path::new(h).join(".config").join("pacsea");
-        candidates.push(base.join("keybinds.conf"));
-        candidates.push(base.join("pacsea.conf")); // legacy
-    }
-    if let Some(xdg) = xdg_config.as_deref() {
-        let x = Path::new(xdg).join("pacsea");
-        candidates.push(x.join("keybinds.conf"));
-        candidates.push(x.join("pacsea.conf")); // legacy
-    }
-    candidates.into_iter().find(|p| p.is_file())
+    resolve_config_file("keybinds.conf")
+}
+
+/// What: Settings file to write to, even when none exists yet.
+///
+/// Output:
+/// - The resolved `settings.conf`, else `<config-dir>/settings.conf` with an override, else the
+///   XDG (or `$HOME/.config`) location.
+pub(crate) fn settings_config_target() -> Option<PathBuf> {
+    resolve_settings_config_path().or_else(|| {
+        if let Some(dir) = config_dir_override() {
+            return Some(dir.join("settings.conf"));
+        }
+        env::var("XDG_CONFIG_HOME")
+            .ok()
+            .map(PathBuf::from)
+            .or_else(|| env::var("HOME").ok().map(|h| Path::new(&h).join(".config")))
+            .map(|base| base.join("pacsea").join("settings.conf"))
+    })
 }
 
 /// What: Resolve an XDG base directory, falling back to `$HOME` with provided segments.
@@ -154,8 +248,13 @@ fn home_config_dir() -> Option<PathBuf> {
 /// - `PathBuf` pointing to the Pacsea config directory.
 ///
 /// Details:
+/// - A `--config-dir` override wins outright.
 /// - Prefers `$HOME/.config/pacsea`, falling back to `XDG_CONFIG_HOME/pacsea` when necessary.
 pub fn config_dir() -> PathBuf {
+    if let Some(dir) = config_dir_override() {
+        let _ = std::fs::create_dir_all(&dir);
+        return dir;
+    }
     // Prefer HOME ~/.config/pacsea first
     if let Some(dir) = home_config_dir() {
         return dir;
@@ -203,6 +302,39 @@ pub fn lists_dir() -> PathBuf {
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    /// What: Ensure a config-dir override replaces the HOME/XDG bases and is validated.
+    ///
+    /// Inputs:
+    /// - Base lists with and without an override; a fresh directory and a path below a file.
+    ///
+    /// Output:
+    /// - The override yields only itself; the fresh directory gets its subtree and the path
+    ///   below a file is rejected.
+    fn paths_config_dir_override_candidates_and_validation() {
+        let with = super::config_bases(
+            Some(std::path::Path::new("/tmp/iso")),
+            Some("/home/u"),
+            None,
+        );
+        assert_eq!(with, vec![std::path::PathBuf::from("/tmp/iso")]);
+        let without = super::config_bases(None, Some("/home/u"), Some("/xdg"));
+        assert_eq!(
+            without,
+            vec![
+                std::path::PathBuf::from("/home/u/.config/pacsea"),
+                std::path::PathBuf::from("/xdg/pacsea"),
+            ]
+        );
+
+        let base = tempfile::tempdir().expect("tempdir");
+        let dir = super::prepare_config_dir(&base.path().join("a").join("b")).expect("prepare");
+        assert!(dir.join("lists").is_dir() && dir.join("logs").is_dir());
+        let file = base.path().join("plain");
+        std::fs::write(&file, "x").unwrap();
+        assert!(super::prepare_config_dir(&file.join("sub")).is_err());
+    }
+
     #[test]
     /// What: Verify path helpers resolve under the Pacsea config directory rooted at `HOME`.
     ///
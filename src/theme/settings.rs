@@ -1,14 +1,395 @@
-use std::env;
 use std::fs;
 use std::io::Write;
-use std::path::{Path, PathBuf};
 
 // no longer writing skeleton here
-use super::parsing::{parse_key_chord, strip_inline_comment};
-use super::paths::{resolve_keybinds_config_path, resolve_settings_config_path};
+use super::parsing::{parse_key_chord, parse_key_sequence, strip_inline_comment};
+use super::paths::{resolve_keybinds_config_path, settings_config_target};
 // Repo-local config is disabled; always use HOME/XDG.
 use super::types::{PackageMarker, Settings};
 
+/// What: Apply `key = value` lines from settings.conf content onto an existing `Settings`.
+///
+/// Inputs:
+/// - `out`: Settings value to update in place.
+/// - `content`: Raw settings.conf text.
+///
+/// Output:
+/// - `true` when a `skip_preflight` key was present in `content`.
+///
+/// Details:
+/// - Unknown keys and unparsable values are ignored so older/newer configs load cleanly.
+/// - Keybinds are not read here; they live in keybinds.conf.
+pub(crate) fn apply_settings_content(out: &mut Settings, content: &str) -> bool {
+    let mut saw_skip_preflight = false;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with("//") {
+            continue;
+        }
+        if !trimmed.contains('=') {
+            continue;
+        }
+        let mut parts = trimmed.splitn(2, '=');
+        let raw_key = parts.next().unwrap_or("");
+        let key = raw_key.trim().to_lowercase().replace(['.', '-', ' '], "_");
+        let val_raw = parts.next().unwrap_or("").trim();
+        let val = strip_inline_comment(val_raw);
+        saw_skip_preflight |= apply_setting(out, &key, val).skip_preflight;
+    }
+    saw_skip_preflight
+}
+
+/// What: Apply a `settings.toml` document onto an existing `Settings`.
+///
+/// Inputs:
+/// - `out`: Settings value to update in place.
+/// - `content`: Raw TOML text.
+///
+/// Output:
+/// - `Ok(true)` when a `skip_preflight` key was present; `Err` with the parser message when the
+///   document is not valid TOML (nothing is applied then).
+///
+/// Details:
+/// - Accepts the same keys as settings.conf; a table name prefixes its keys, so `left_pct`
+///   under `[layout]` is `layout_left_pct`.
+/// - Arrays become comma-separated lists (e.g. `selected_countries = ["Germany", "France"]`).
+/// - Unknown keys and values of the wrong type are logged and skipped.
+pub(crate) fn apply_settings_toml(out: &mut Settings, content: &str) -> Result<bool, String> {
+    let table: toml::Table = toml::from_str(content).map_err(|e| e.to_string())?;
+    let mut entries = Vec::new();
+    flatten_toml(&table, "", &mut entries);
+    let mut saw_skip_preflight = false;
+    for (raw_key, val) in entries {
+        let key = raw_key.trim().to_lowercase().replace(['.', '-', ' '], "_");
+        let outcome = apply_setting(out, &key, &val);
+        if outcome.unknown {
+            tracing::warn!(key = %key, "settings.toml: unknown setting ignored");
+        } else if outcome.invalid {
+            tracing::warn!(key = %key, value = %val, "settings.toml: value has the wrong type");
+        }
+        saw_skip_preflight |= outcome.skip_preflight;
+    }
+    Ok(saw_skip_preflight)
+}
+
+/// What: Collect `(key, value text)` pairs from a TOML table, descending into sub-tables.
+///
+/// Details:
+/// - Keys inside a table are prefixed with the table path joined by `_` (`[scan] do_clamav`
+///   becomes `scan_do_clamav`).
+fn flatten_toml(table: &toml::Table, prefix: &str, entries: &mut Vec<(String, String)>) {
+    for (key, value) in table {
+        let full = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}_{key}")
+        };
+        match value {
+            toml::Value::Table(sub) => flatten_toml(sub, &full, entries),
+            other => entries.push((full, toml_value_text(other))),
+        }
+    }
+}
+
+/// What: Render a scalar or array TOML value as the text settings.conf would hold.
+fn toml_value_text(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        toml::Value::Integer(i) => i.to_string(),
+        toml::Value::Float(f) => f.to_string(),
+        toml::Value::Boolean(b) => b.to_string(),
+        toml::Value::Datetime(d) => d.to_string(),
+        toml::Value::Array(items) => items
+            .iter()
+            .map(toml_value_text)
+            .collect::<Vec<_>>()
+            .join(", "),
+        toml::Value::Table(_) => String::new(),
+    }
+}
+
+/// How [`apply_setting`] handled one key.
+#[derive(Debug, Default, Clone, Copy)]
+struct SettingOutcome {
+    /// The key is a `skip_preflight` alias.
+    skip_preflight: bool,
+    /// The key is not a known setting and was ignored.
+    unknown: bool,
+    /// The value does not fit the setting's type; numbers and choices keep their previous value,
+    /// flags read as `false`.
+    invalid: bool,
+}
+
+/// What: Apply one normalized setting onto `out`.
+///
+/// Inputs:
+/// - `out`: Settings value to update in place.
+/// - `key`: Lowercase, underscore-separated key.
+/// - `val`: Raw value text (no inline comment).
+///
+/// Output:
+/// - A [`SettingOutcome`] telling whether the key was a `skip_preflight` alias, unknown, or had
+///   a value of the wrong type.
+///
+/// Details:
+/// - Shared by the `key = value` and TOML loaders so both accept the same keys and values.
+fn apply_setting(out: &mut Settings, key: &str, val: &str) -> SettingOutcome {
+    let mut outcome = SettingOutcome::default();
+    match key {
+        "layout_left_pct" => {
+            if let Ok(v) = val.parse::<u16>() {
+                out.layout_left_pct = v;
+            } else {
+                outcome.invalid = true;
+            }
+        }
+        "layout_center_pct" => {
+            if let Ok(v) = val.parse::<u16>() {
+                out.layout_center_pct = v;
+            } else {
+                outcome.invalid = true;
+            }
+        }
+        "layout_right_pct" => {
+            if let Ok(v) = val.parse::<u16>() {
+                out.layout_right_pct = v;
+            } else {
+                outcome.invalid = true;
+            }
+        }
+        "app_dry_run_default" => {
+            out.app_dry_run_default = flag(val, &mut outcome.invalid);
+        }
+        "sort_mode" | "results_sort" => {
+            if let Some(sm) = crate::state::SortMode::from_config_key(val) {
+                out.sort_mode = sm;
+            } else {
+                outcome.invalid = true;
+            }
+        }
+        "clipboard_suffix" | "copy_suffix" => {
+            out.clipboard_suffix = val.to_string();
+        }
+        "show_recent_pane" | "recent_visible" => {
+            out.show_recent_pane = flag(val, &mut outcome.invalid);
+        }
+        "show_install_pane" | "install_visible" | "show_install_list" => {
+            out.show_install_pane = flag(val, &mut outcome.invalid);
+        }
+        "show_keybinds_footer" | "keybinds_visible" => {
+            out.show_keybinds_footer = flag(val, &mut outcome.invalid);
+        }
+        "installed_only_default" | "start_installed_only" => {
+            out.installed_only_default = flag(val, &mut outcome.invalid);
+        }
+        "fuzzy_find" | "pane_find_fuzzy" => {
+            out.fuzzy_find = flag(val, &mut outcome.invalid);
+        }
+        "pkgbuild_cache_ttl_secs" => {
+            if let Ok(v) = val.parse::<u64>() {
+                out.pkgbuild_cache_ttl_secs = v;
+            } else {
+                outcome.invalid = true;
+            }
+        }
+        "pkgbuild_highlight" => {
+            out.pkgbuild_highlight = flag(val, &mut outcome.invalid);
+        }
+        "persist_throttle_ms" => {
+            if let Ok(v) = val.parse::<u64>() {
+                out.persist_throttle_ms = v;
+            } else {
+                outcome.invalid = true;
+            }
+        }
+        "index_refresh_interval_mins" => {
+            if let Ok(v) = val.parse::<u64>() {
+                out.index_refresh_interval_mins = v;
+            } else {
+                outcome.invalid = true;
+            }
+        }
+        "aur_disabled" | "disable_aur" => {
+            out.aur_disabled = flag(val, &mut outcome.invalid);
+        }
+        "preferred_aur_helper" | "aur_helper" => {
+            out.preferred_aur_helper = val.to_string();
+        }
+        "compress_index" | "gzip_index" => {
+            out.compress_index = flag(val, &mut outcome.invalid);
+        }
+        "deps_resolve_concurrency" | "resolve_concurrency" => {
+            if let Ok(v) = val.parse::<usize>() {
+                out.deps_resolve_concurrency = v;
+            } else {
+                outcome.invalid = true;
+            }
+        }
+        "post_install_hook" => {
+            out.post_install_hook = val.to_string();
+        }
+        "pacman_binary" => {
+            out.pacman_binary = val.to_string();
+        }
+        "privilege_escalation" => {
+            out.privilege_escalation = val.to_string();
+        }
+        "virustotal_requests_per_minute" => {
+            if let Ok(v) = val.parse::<u32>() {
+                out.virustotal_requests_per_minute = v;
+            } else {
+                outcome.invalid = true;
+            }
+        }
+        "custom_scan_command" => {
+            out.custom_scan_command = val.to_string();
+        }
+        "theme_auto_reload" => {
+            out.theme_auto_reload = flag(val, &mut outcome.invalid);
+        }
+        "active_theme" => {
+            out.active_theme = val.to_string();
+        }
+        "force_color" => {
+            out.force_color = val.to_string();
+        }
+        "search_debounce_ms" => {
+            if let Ok(v) = val.parse::<u64>() {
+                out.search_debounce_ms = v;
+            } else {
+                outcome.invalid = true;
+            }
+        }
+        "details_wrap" => {
+            out.details_wrap = flag(val, &mut outcome.invalid);
+        }
+        "pkgbuild_wrap" => {
+            out.pkgbuild_wrap = flag(val, &mut outcome.invalid);
+        }
+        "clipboard_osc52" => {
+            out.clipboard_osc52 = flag(val, &mut outcome.invalid);
+        }
+        "mouse_scroll_lines" => {
+            if let Ok(v) = val.parse::<u16>() {
+                out.mouse_scroll_lines = v.clamp(1, 20);
+            } else {
+                outcome.invalid = true;
+            }
+        }
+        "mouse_natural_scroll" => {
+            out.mouse_natural_scroll = flag(val, &mut outcome.invalid);
+        }
+        "compact_mode" => {
+            out.compact_mode = match val.to_ascii_lowercase().as_str() {
+                "on" | "true" | "1" | "yes" => Some(true),
+                "off" | "false" | "0" | "no" => Some(false),
+                _ => None,
+            };
+        }
+        "layout_autoscale" => {
+            out.layout_autoscale = flag(val, &mut outcome.invalid);
+        }
+        "confirm_threshold" => {
+            if let Ok(v) = val.parse::<usize>() {
+                out.confirm_threshold = v;
+            } else {
+                outcome.invalid = true;
+            }
+        }
+        "http_proxy" => {
+            out.http_proxy = val.to_string();
+        }
+        "https_proxy" => {
+            out.https_proxy = val.to_string();
+        }
+        "selected_countries" | "countries" | "country" => {
+            // Accept comma-separated list; trimming occurs in normalization
+            out.selected_countries = val.to_string();
+        }
+        "mirror_count" | "mirrors" => {
+            if let Ok(v) = val.parse::<u16>() {
+                out.mirror_count = v;
+            } else {
+                outcome.invalid = true;
+            }
+        }
+        "virustotal_api_key" | "vt_api_key" | "virustotal" => {
+            // VirusTotal API key; stored as-is and trimmed later
+            out.virustotal_api_key = val.to_string();
+        }
+        "scan_do_clamav" => {
+            out.scan_do_clamav = flag(val, &mut outcome.invalid);
+        }
+        "scan_do_trivy" => {
+            out.scan_do_trivy = flag(val, &mut outcome.invalid);
+        }
+        "scan_do_semgrep" => {
+            out.scan_do_semgrep = flag(val, &mut outcome.invalid);
+        }
+        "scan_do_shellcheck" => {
+            out.scan_do_shellcheck = flag(val, &mut outcome.invalid);
+        }
+        "scan_do_virustotal" => {
+            out.scan_do_virustotal = flag(val, &mut outcome.invalid);
+        }
+        "scan_do_custom" => {
+            out.scan_do_custom = flag(val, &mut outcome.invalid);
+        }
+        "scan_do_sleuth" => {
+            out.scan_do_sleuth = flag(val, &mut outcome.invalid);
+        }
+        "news_read_symbol" | "news_read_mark" => {
+            out.news_read_symbol = val.to_string();
+        }
+        "news_unread_symbol" | "news_unread_mark" => {
+            out.news_unread_symbol = val.to_string();
+        }
+        "preferred_terminal" | "terminal_preferred" | "terminal" => {
+            out.preferred_terminal = val.to_string();
+        }
+        "package_marker" => {
+            let lv = val.to_ascii_lowercase();
+            out.package_marker = match lv.as_str() {
+                "full" | "full_line" | "line" | "color_line" | "color" => PackageMarker::FullLine,
+                "end" | "suffix" => PackageMarker::End,
+                "front" | "start" | "prefix" | "" => PackageMarker::Front,
+                _ => PackageMarker::Front,
+            };
+        }
+        "skip_preflight" | "preflight_skip" | "bypass_preflight" => {
+            outcome.skip_preflight = true;
+            out.skip_preflight = flag(val, &mut outcome.invalid);
+        }
+        "locale" | "language" => {
+            out.locale = val.trim().to_string();
+        }
+        k if crate::logic::filter::RESULTS_FILTER_KEYS.contains(&k) => {
+            let on = flag(val, &mut outcome.invalid);
+            out.results_filters.retain(|(existing, _)| existing != k);
+            out.results_filters.push((k.to_string(), on));
+        }
+        // Note: we intentionally ignore keybind_* in settings.conf now; keybinds load below
+        _ => outcome.unknown = true,
+    }
+    outcome
+}
+
+/// What: Read a boolean setting value.
+///
+/// Output:
+/// - `true` for `true`/`1`/`yes`/`on` (any case); `false` otherwise, setting `invalid` when the
+///   text is not `false`/`0`/`no`/`off` either.
+fn flag(val: &str, invalid: &mut bool) -> bool {
+    match val.to_ascii_lowercase().as_str() {
+        "true" | "1" | "yes" | "on" => true,
+        "false" | "0" | "no" | "off" => false,
+        _ => {
+            *invalid = true;
+            false
+        }
+    }
+}
+
 /// What: Load user settings and keybinds from config files under HOME/XDG.
 ///
 /// Inputs:
@@ -19,146 +400,20 @@ use super::types::{PackageMarker, Settings};
 pub fn settings() -> Settings {
     let mut out = Settings::default();
     // Load settings from settings.conf (or legacy pacsea.conf)
-    let settings_path = resolve_settings_config_path().or_else(|| {
-        env::var("XDG_CONFIG_HOME")
-            .ok()
-            .map(PathBuf::from)
-            .or_else(|| env::var("HOME").ok().map(|h| Path::new(&h).join(".config")))
-            .map(|base| base.join("pacsea").join("settings.conf"))
-    });
+    let settings_path = settings_config_target();
     if let Some(p) = settings_path.as_ref()
         && let Ok(content) = fs::read_to_string(p)
     {
-        let mut saw_skip_preflight = false;
+        let saw_skip_preflight = if super::paths::is_toml_settings(p) {
+            apply_settings_toml(&mut out, &content).unwrap_or_else(|e| {
+                tracing::warn!(path = %p.display(), error = %e, "invalid settings.toml; using defaults");
+                // Don't append to a file we could not parse
+                true
+            })
+        } else {
+            apply_settings_content(&mut out, &content)
+        };
 
-        for line in content.lines() {
-            let trimmed = line.trim();
-            if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with("//") {
-                continue;
-            }
-            if !trimmed.contains('=') {
-                continue;
-            }
-            let mut parts = trimmed.splitn(2, '=');
-            let raw_key = parts.next().unwrap_or("");
-            let key = raw_key.trim().to_lowercase().replace(['.', '-', ' '], "_");
-            let val_raw = parts.next().unwrap_or("").trim();
-            let val = strip_inline_comment(val_raw);
-            match key.as_str() {
-                "layout_left_pct" => {
-                    if let Ok(v) = val.parse::<u16>() {
-                        out.layout_left_pct = v;
-                    }
-                }
-                "layout_center_pct" => {
-                    if let Ok(v) = val.parse::<u16>() {
-                        out.layout_center_pct = v;
-                    }
-                }
-                "layout_right_pct" => {
-                    if let Ok(v) = val.parse::<u16>() {
-                        out.layout_right_pct = v;
-                    }
-                }
-                "app_dry_run_default" => {
-                    let lv = val.to_ascii_lowercase();
-                    out.app_dry_run_default =
-                        lv == "true" || lv == "1" || lv == "yes" || lv == "on";
-                }
-                "sort_mode" | "results_sort" => {
-                    if let Some(sm) = crate::state::SortMode::from_config_key(val) {
-                        out.sort_mode = sm;
-                    }
-                }
-                "clipboard_suffix" | "copy_suffix" => {
-                    out.clipboard_suffix = val.to_string();
-                }
-                "show_recent_pane" | "recent_visible" => {
-                    let lv = val.to_ascii_lowercase();
-                    out.show_recent_pane = lv == "true" || lv == "1" || lv == "yes" || lv == "on";
-                }
-                "show_install_pane" | "install_visible" | "show_install_list" => {
-                    let lv = val.to_ascii_lowercase();
-                    out.show_install_pane = lv == "true" || lv == "1" || lv == "yes" || lv == "on";
-                }
-                "show_keybinds_footer" | "keybinds_visible" => {
-                    let lv = val.to_ascii_lowercase();
-                    out.show_keybinds_footer =
-                        lv == "true" || lv == "1" || lv == "yes" || lv == "on";
-                }
-                "selected_countries" | "countries" | "country" => {
-                    // Accept comma-separated list; trimming occurs in normalization
-                    out.selected_countries = val.to_string();
-                }
-                "mirror_count" | "mirrors" => {
-                    if let Ok(v) = val.parse::<u16>() {
-                        out.mirror_count = v;
-                    }
-                }
-                "virustotal_api_key" | "vt_api_key" | "virustotal" => {
-                    // VirusTotal API key; stored as-is and trimmed later
-                    out.virustotal_api_key = val.to_string();
-                }
-                "scan_do_clamav" => {
-                    let lv = val.to_ascii_lowercase();
-                    out.scan_do_clamav = lv == "true" || lv == "1" || lv == "yes" || lv == "on";
-                }
-                "scan_do_trivy" => {
-                    let lv = val.to_ascii_lowercase();
-                    out.scan_do_trivy = lv == "true" || lv == "1" || lv == "yes" || lv == "on";
-                }
-                "scan_do_semgrep" => {
-                    let lv = val.to_ascii_lowercase();
-                    out.scan_do_semgrep = lv == "true" || lv == "1" || lv == "yes" || lv == "on";
-                }
-                "scan_do_shellcheck" => {
-                    let lv = val.to_ascii_lowercase();
-                    out.scan_do_shellcheck = lv == "true" || lv == "1" || lv == "yes" || lv == "on";
-                }
-                "scan_do_virustotal" => {
-                    let lv = val.to_ascii_lowercase();
-                    out.scan_do_virustotal = lv == "true" || lv == "1" || lv == "yes" || lv == "on";
-                }
-                "scan_do_custom" => {
-                    let lv = val.to_ascii_lowercase();
-                    out.scan_do_custom = lv == "true" || lv == "1" || lv == "yes" || lv == "on";
-                }
-                "scan_do_sleuth" => {
-                    let lv = val.to_ascii_lowercase();
-                    out.scan_do_sleuth = lv == "true" || lv == "1" || lv == "yes" || lv == "on";
-                }
-                "news_read_symbol" | "news_read_mark" => {
-                    out.news_read_symbol = val.to_string();
-                }
-                "news_unread_symbol" | "news_unread_mark" => {
-                    out.news_unread_symbol = val.to_string();
-                }
-                "preferred_terminal" | "terminal_preferred" | "terminal" => {
-                    out.preferred_terminal = val.to_string();
-                }
-                "package_marker" => {
-                    let lv = val.to_ascii_lowercase();
-                    out.package_marker = match lv.as_str() {
-                        "full" | "full_line" | "line" | "color_line" | "color" => {
-                            PackageMarker::FullLine
-                        }
-                        "end" | "suffix" => PackageMarker::End,
-                        "front" | "start" | "prefix" | "" => PackageMarker::Front,
-                        _ => PackageMarker::Front,
-                    };
-                }
-                "skip_preflight" | "preflight_skip" | "bypass_preflight" => {
-                    saw_skip_preflight = true;
-                    let lv = val.to_ascii_lowercase();
-                    out.skip_preflight = lv == "true" || lv == "1" || lv == "yes" || lv == "on";
-                }
-                "locale" | "language" => {
-                    out.locale = val.trim().to_string();
-                }
-                // Note: we intentionally ignore keybind_* in settings.conf now; keybinds load below
-                _ => {}
-            }
-        }
         // If the setting wasn't present, append a documented default for discoverability
         if !saw_skip_preflight {
             // Append a single line for discoverability; keep it minimal
@@ -232,6 +487,16 @@ pub fn settings() -> Settings {
                             out.keymap.reload_theme = vec![ch];
                         }
                     }
+                    "keybind_reload_settings" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.reload_settings = vec![ch];
+                        }
+                    }
+                    "keybind_cycle_theme" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.cycle_theme = vec![ch];
+                        }
+                    }
                     "keybind_exit" | "keybind_quit" => {
                         if let Some(ch) = parse_key_chord(val) {
                             out.keymap.exit = vec![ch];
@@ -242,11 +507,146 @@ pub fn settings() -> Settings {
                             out.keymap.show_pkgbuild = vec![ch];
                         }
                     }
+                    "keybind_pkgbuild_next_mark" | "keybind_pkgbuild_next_audit" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.pkgbuild_next_mark = vec![ch];
+                        }
+                    }
                     "keybind_change_sort" | "keybind_sort" => {
                         if let Some(ch) = parse_key_chord(val) {
                             out.keymap.change_sort = vec![ch];
                         }
                     }
+                    "keybind_toggle_download_server" | "keybind_download_server" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.toggle_download_server = vec![ch];
+                        }
+                    }
+                    "keybind_copy_view_settings" | "keybind_copy_view" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.copy_view_settings = vec![ch];
+                        }
+                    }
+                    "keybind_reopen_modal" | "keybind_reopen" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.reopen_modal = vec![ch];
+                        }
+                    }
+                    "keybind_toggle_status_verbose" | "keybind_status_verbose" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.toggle_status_verbose = vec![ch];
+                        }
+                    }
+                    "keybind_toggle_status_spans" | "keybind_status_spans" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.toggle_status_spans = vec![ch];
+                        }
+                    }
+                    "keybind_compare_slot_a" | "keybind_compare_a" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.compare_slot_a = vec![ch];
+                        }
+                    }
+                    "keybind_compare_slot_b" | "keybind_compare_b" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.compare_slot_b = vec![ch];
+                        }
+                    }
+                    "keybind_open_homepage" | "keybind_open_url" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.open_homepage = vec![ch];
+                        }
+                    }
+                    "keybind_open_package_page" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.open_package_page = vec![ch];
+                        }
+                    }
+                    "keybind_preview_commands" | "keybind_dry_run_preview" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.preview_commands = vec![ch];
+                        }
+                    }
+                    "keybind_why_installed" | "keybind_why" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.why_installed = vec![ch];
+                        }
+                    }
+                    "keybind_show_files" | "keybind_view_files" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.show_files = vec![ch];
+                        }
+                    }
+                    "keybind_clear_all_lists" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.clear_all_lists = vec![ch];
+                        }
+                    }
+                    "keybind_undo" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.undo = vec![ch];
+                        }
+                    }
+                    "keybind_redo" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.redo = vec![ch];
+                        }
+                    }
+                    "keybind_command_palette" | "keybind_palette" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.command_palette = vec![ch];
+                        }
+                    }
+                    "keybind_open_logs" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.open_logs = vec![ch];
+                        }
+                    }
+                    "keybind_details_scroll_left" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.details_scroll_left = vec![ch];
+                        }
+                    }
+                    "keybind_details_scroll_right" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.details_scroll_right = vec![ch];
+                        }
+                    }
+                    "keybind_toggle_wrap" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.toggle_wrap = vec![ch];
+                        }
+                    }
+                    "keybind_refresh_installed" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.refresh_installed = vec![ch];
+                        }
+                    }
+                    "keybind_preflight_tab_deps" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.preflight_tab_deps = vec![ch];
+                        }
+                    }
+                    "keybind_preflight_tab_files" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.preflight_tab_files = vec![ch];
+                        }
+                    }
+                    "keybind_preflight_tab_services" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.preflight_tab_services = vec![ch];
+                        }
+                    }
+                    "keybind_goto_top" => {
+                        if let Some(seq) = parse_key_sequence(val) {
+                            out.keymap.goto_top = vec![seq];
+                        }
+                    }
+                    "keybind_goto_bottom" => {
+                        if let Some(seq) = parse_key_sequence(val) {
+                            out.keymap.goto_bottom = vec![seq];
+                        }
+                    }
                     "keybind_pane_next" | "keybind_next_pane" | "keybind_switch_pane" => {
                         if let Some(ch) = parse_key_chord(val) {
                             out.keymap.pane_next = vec![ch];
@@ -294,6 +694,16 @@ pub fn settings() -> Settings {
                             out.keymap.search_install = vec![ch];
                         }
                     }
+                    "keybind_search_multi_select" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.search_multi_select = vec![ch];
+                        }
+                    }
+                    "keybind_search_add_selected" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.search_add_selected = vec![ch];
+                        }
+                    }
                     "keybind_search_focus_left" => {
                         if let Some(ch) = parse_key_chord(val) {
                             out.keymap.search_focus_left = vec![ch];
@@ -442,6 +852,16 @@ pub fn settings() -> Settings {
                             out.keymap.install_clear = vec![ch];
                         }
                     }
+                    "keybind_install_remove_group" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.install_remove_group = vec![ch];
+                        }
+                    }
+                    "keybind_install_pick_version" | "keybind_downgrade_pick_version" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.install_pick_version = vec![ch];
+                        }
+                    }
                     "keybind_install_find" => {
                         if let Some(ch) = parse_key_chord(val) {
                             out.keymap.install_find = vec![ch];
@@ -510,6 +930,16 @@ pub fn settings() -> Settings {
                             out.keymap.reload_theme = vec![ch];
                         }
                     }
+                    "keybind_reload_settings" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.reload_settings = vec![ch];
+                        }
+                    }
+                    "keybind_cycle_theme" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.cycle_theme = vec![ch];
+                        }
+                    }
                     "keybind_exit" | "keybind_quit" => {
                         if let Some(ch) = parse_key_chord(val) {
                             out.keymap.exit = vec![ch];
@@ -520,11 +950,146 @@ pub fn settings() -> Settings {
                             out.keymap.show_pkgbuild = vec![ch];
                         }
                     }
+                    "keybind_pkgbuild_next_mark" | "keybind_pkgbuild_next_audit" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.pkgbuild_next_mark = vec![ch];
+                        }
+                    }
                     "keybind_change_sort" | "keybind_sort" => {
                         if let Some(ch) = parse_key_chord(val) {
                             out.keymap.change_sort = vec![ch];
                         }
                     }
+                    "keybind_toggle_download_server" | "keybind_download_server" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.toggle_download_server = vec![ch];
+                        }
+                    }
+                    "keybind_copy_view_settings" | "keybind_copy_view" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.copy_view_settings = vec![ch];
+                        }
+                    }
+                    "keybind_reopen_modal" | "keybind_reopen" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.reopen_modal = vec![ch];
+                        }
+                    }
+                    "keybind_toggle_status_verbose" | "keybind_status_verbose" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.toggle_status_verbose = vec![ch];
+                        }
+                    }
+                    "keybind_toggle_status_spans" | "keybind_status_spans" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.toggle_status_spans = vec![ch];
+                        }
+                    }
+                    "keybind_compare_slot_a" | "keybind_compare_a" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.compare_slot_a = vec![ch];
+                        }
+                    }
+                    "keybind_compare_slot_b" | "keybind_compare_b" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.compare_slot_b = vec![ch];
+                        }
+                    }
+                    "keybind_open_homepage" | "keybind_open_url" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.open_homepage = vec![ch];
+                        }
+                    }
+                    "keybind_open_package_page" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.open_package_page = vec![ch];
+                        }
+                    }
+                    "keybind_preview_commands" | "keybind_dry_run_preview" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.preview_commands = vec![ch];
+                        }
+                    }
+                    "keybind_why_installed" | "keybind_why" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.why_installed = vec![ch];
+                        }
+                    }
+                    "keybind_show_files" | "keybind_view_files" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.show_files = vec![ch];
+                        }
+                    }
+                    "keybind_clear_all_lists" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.clear_all_lists = vec![ch];
+                        }
+                    }
+                    "keybind_undo" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.undo = vec![ch];
+                        }
+                    }
+                    "keybind_redo" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.redo = vec![ch];
+                        }
+                    }
+                    "keybind_command_palette" | "keybind_palette" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.command_palette = vec![ch];
+                        }
+                    }
+                    "keybind_open_logs" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.open_logs = vec![ch];
+                        }
+                    }
+                    "keybind_details_scroll_left" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.details_scroll_left = vec![ch];
+                        }
+                    }
+                    "keybind_details_scroll_right" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.details_scroll_right = vec![ch];
+                        }
+                    }
+                    "keybind_toggle_wrap" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.toggle_wrap = vec![ch];
+                        }
+                    }
+                    "keybind_refresh_installed" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.refresh_installed = vec![ch];
+                        }
+                    }
+                    "keybind_preflight_tab_deps" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.preflight_tab_deps = vec![ch];
+                        }
+                    }
+                    "keybind_preflight_tab_files" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.preflight_tab_files = vec![ch];
+                        }
+                    }
+                    "keybind_preflight_tab_services" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.preflight_tab_services = vec![ch];
+                        }
+                    }
+                    "keybind_goto_top" => {
+                        if let Some(seq) = parse_key_sequence(val) {
+                            out.keymap.goto_top = vec![seq];
+                        }
+                    }
+                    "keybind_goto_bottom" => {
+                        if let Some(seq) = parse_key_sequence(val) {
+                            out.keymap.goto_bottom = vec![seq];
+                        }
+                    }
                     "keybind_pane_next" | "keybind_next_pane" | "keybind_switch_pane" => {
                         if let Some(ch) = parse_key_chord(val) {
                             out.keymap.pane_next = vec![ch];
@@ -571,6 +1136,16 @@ pub fn settings() -> Settings {
                             out.keymap.search_install = vec![ch];
                         }
                     }
+                    "keybind_search_multi_select" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.search_multi_select = vec![ch];
+                        }
+                    }
+                    "keybind_search_add_selected" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.search_add_selected = vec![ch];
+                        }
+                    }
                     "keybind_search_focus_left" => {
                         if let Some(ch) = parse_key_chord(val) {
                             out.keymap.search_focus_left = vec![ch];
@@ -717,6 +1292,16 @@ pub fn settings() -> Settings {
                             out.keymap.install_clear = vec![ch];
                         }
                     }
+                    "keybind_install_remove_group" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.install_remove_group = vec![ch];
+                        }
+                    }
+                    "keybind_install_pick_version" | "keybind_downgrade_pick_version" => {
+                        if let Some(ch) = parse_key_chord(val) {
+                            out.keymap.install_pick_version = vec![ch];
+                        }
+                    }
                     "keybind_install_find" => {
                         if let Some(ch) = parse_key_chord(val) {
                             out.keymap.install_find = vec![ch];
@@ -742,31 +1327,82 @@ pub fn settings() -> Settings {
             }
         }
     }
-    // Validate sum; if invalid, revert to defaults
-    let sum = out
-        .layout_left_pct
-        .saturating_add(out.layout_center_pct)
-        .saturating_add(out.layout_right_pct);
-    if sum != 100
-        || out.layout_left_pct == 0
-        || out.layout_center_pct == 0
-        || out.layout_right_pct == 0
-    {
+    // Validate sum; rescale proportionally when allowed, otherwise revert to defaults
+    let written = [
+        out.layout_left_pct,
+        out.layout_center_pct,
+        out.layout_right_pct,
+    ];
+    let sum = written.iter().fold(0u16, |acc, v| acc.saturating_add(*v));
+    if sum != 100 {
+        match rescale_layout(written).filter(|_| out.layout_autoscale) {
+            Some([left, center, right]) => {
+                tracing::info!(?written, left, center, right, "rescaled layout percentages");
+                out.layout_left_pct = left;
+                out.layout_center_pct = center;
+                out.layout_right_pct = right;
+                out.layout_rescaled_from = Some(written);
+            }
+            None => out = Settings::default(),
+        }
+    } else if written.contains(&0) {
         out = Settings::default();
     }
     out
 }
 
+/// What: Scale three layout percentages to sum to 100 while keeping their ratios.
+///
+/// Inputs:
+/// - `pcts`: Left, center, and right percentages as written.
+///
+/// Output:
+/// - The rescaled values, or `None` for degenerate input (any zero).
+///
+/// Details:
+/// - Uses largest-remainder rounding so the result sums to exactly 100; every pane keeps at
+///   least 1%.
+pub(crate) fn rescale_layout(pcts: [u16; 3]) -> Option<[u16; 3]> {
+    if pcts.contains(&0) {
+        return None;
+    }
+    let total: u32 = pcts.iter().map(|&v| u32::from(v)).sum();
+    let mut out = [0u16; 3];
+    let mut rems = [(0u32, 0usize); 3];
+    for (i, &v) in pcts.iter().enumerate() {
+        let scaled = u32::from(v) * 100;
+        out[i] = ((scaled / total) as u16).max(1);
+        rems[i] = (scaled % total, i);
+    }
+    rems.sort_by_key(|r| std::cmp::Reverse(r.0));
+    let mut assigned: u16 = out.iter().sum();
+    for &(_, i) in rems.iter().cycle() {
+        match assigned.cmp(&100) {
+            std::cmp::Ordering::Less => {
+                out[i] += 1;
+                assigned += 1;
+            }
+            std::cmp::Ordering::Greater if out[i] > 1 => {
+                out[i] -= 1;
+                assigned -= 1;
+            }
+            std::cmp::Ordering::Greater => {}
+            std::cmp::Ordering::Equal => break,
+        }
+    }
+    Some(out)
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
-    /// What: Ensure settings parsing applies defaults when layout percentages sum incorrectly while still loading keybinds.
+    /// What: Ensure settings parsing normalizes layout percentages that sum incorrectly while still loading keybinds.
     ///
     /// Inputs:
     /// - Temporary configuration directory containing `settings.conf` with an invalid layout sum and `keybinds.conf` with overrides.
     ///
     /// Output:
-    /// - Resulting `Settings` fall back to default layout percentages yet pick up configured keybinds.
+    /// - Resulting `Settings` have layout percentages rescaled to 100 yet pick up configured keybinds.
     ///
     /// Details:
     /// - Overrides `HOME` to a temp dir and restores it afterwards to avoid polluting the user environment.
@@ -785,7 +1421,7 @@ mod tests {
         let _ = std::fs::create_dir_all(&cfg);
         unsafe { std::env::set_var("HOME", base.display().to_string()) };
 
-        // Write settings.conf with values and bad sum (should reset to defaults)
+        // Write settings.conf with values and bad sum (rescaled by layout_autoscale)
         let settings_path = cfg.join("settings.conf");
         std::fs::write(
             &settings_path,
@@ -797,7 +1433,8 @@ mod tests {
         std::fs::write(&keybinds_path, "keybind_exit = Ctrl+Q\nkeybind_help = F1\n").unwrap();
 
         let s = super::settings();
-        // Invalid layout sum -> defaults
+        // Invalid layout sum -> rescaled
+        assert_eq!(s.layout_rescaled_from, Some([10, 10, 10]));
         assert_eq!(
             s.layout_left_pct + s.layout_center_pct + s.layout_right_pct,
             100
@@ -815,4 +1452,59 @@ mod tests {
         }
         let _ = std::fs::remove_dir_all(&base);
     }
+
+    #[test]
+    /// What: Ensure `settings.toml` content maps onto the same settings as settings.conf.
+    ///
+    /// Inputs:
+    /// - A TOML document with tables, an array, a string containing `#`, an unknown key, and a
+    ///   number given as text; then invalid TOML.
+    ///
+    /// Output:
+    /// - Values land in `Settings` (table names prefix their keys, arrays comma-joined, `#`
+    ///   kept); the unknown key and mistyped value are skipped; invalid TOML is an error.
+    fn settings_toml_applies_same_keys() {
+        let mut s = super::Settings::default();
+        let mirrors = s.mirror_count;
+        let content = r#"
+clipboard_suffix = "see #pacsea"
+selected_countries = ["Germany", "France"]
+skip_preflight = true
+mirror_count = "many"
+no_such_setting = 1
+
+[layout]
+left_pct = 30
+
+[scan]
+do_clamav = false
+"#;
+        assert_eq!(super::apply_settings_toml(&mut s, content), Ok(true));
+        assert_eq!(s.clipboard_suffix, "see #pacsea");
+        assert_eq!(s.selected_countries, "Germany, France");
+        assert_eq!(s.layout_left_pct, 30);
+        assert!(!s.scan_do_clamav);
+        assert_eq!(s.mirror_count, mirrors);
+        assert!(s.skip_preflight);
+        assert!(super::apply_settings_toml(&mut s, "mirror_count = ").is_err());
+    }
+
+    #[test]
+    /// What: Ensure layout rescaling keeps ratios, sums to 100, and rejects zeros.
+    ///
+    /// Inputs:
+    /// - Sums above and below 100, an uneven split, and a zero pane.
+    ///
+    /// Output:
+    /// - Proportional values summing to 100; `None` for the zero pane.
+    fn settings_rescale_layout_preserves_ratios() {
+        assert_eq!(super::rescale_layout([30, 60, 30]), Some([25, 50, 25]));
+        assert_eq!(
+            super::rescale_layout([10, 10, 10]).map(|v| v.iter().sum::<u16>()),
+            Some(100)
+        );
+        assert_eq!(super::rescale_layout([10, 30, 10]), Some([20, 60, 20]));
+        assert_eq!(super::rescale_layout([1, 500, 1]), Some([1, 98, 1]));
+        assert_eq!(super::rescale_layout([0, 60, 40]), None);
+    }
 }
@@ -1,4 +1,5 @@
 use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::{OnceLock, RwLock};
 
 use super::config::{
@@ -10,6 +11,12 @@ use super::types::Theme;
 /// Global theme store with live-reload capability.
 static THEME_STORE: OnceLock<RwLock<Theme>> = OnceLock::new();
 
+/// Theme file chosen at runtime (named themes); `None` uses the resolved `theme.conf`.
+static ACTIVE_THEME_PATH: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Built-in palette in use instead of a theme file, if any.
+static ACTIVE_BUILTIN: RwLock<Option<Theme>> = RwLock::new(None);
+
 /// What: Load theme colors from disk or generate a skeleton configuration if nothing exists yet.
 ///
 /// Inputs:
@@ -84,15 +91,31 @@ fn load_initial_theme_or_exit() -> Theme {
 /// Details:
 /// - Lazily initializes a global `RwLock<Theme>` using `load_initial_theme_or_exit`.
 /// - Subsequent calls reuse the cached theme until `reload_theme` updates it.
+/// - Colors are adapted to the terminal's color depth (see [`super::color::color_support`]).
 pub fn theme() -> Theme {
     let lock = THEME_STORE.get_or_init(|| RwLock::new(load_initial_theme_or_exit()));
-    *lock.read().expect("theme store poisoned")
+    let theme = *lock.read().expect("theme store poisoned");
+    super::color::adapt_theme(theme, super::color::color_support())
+}
+
+/// What: Path of the theme file currently in use.
+///
+/// Output:
+/// - The file picked with [`load_theme_from`], else the resolved `theme.conf` (or the default
+///   location when none exists yet).
+pub fn active_theme_path() -> PathBuf {
+    ACTIVE_THEME_PATH
+        .read()
+        .ok()
+        .and_then(|g| g.clone())
+        .or_else(resolve_theme_config_path)
+        .unwrap_or_else(|| config_dir().join("theme.conf"))
 }
 
 /// What: Reload the theme configuration from disk on demand.
 ///
 /// Inputs:
-/// - None (locates the config through `resolve_theme_config_path`).
+/// - None (uses [`active_theme_path`]).
 ///
 /// Output:
 /// - `Ok(())` when the theme is reloaded successfully.
@@ -100,13 +123,56 @@ pub fn theme() -> Theme {
 ///
 /// Details:
 /// - Keeps the in-memory cache up to date so the UI can refresh without restarting Pacsea.
-/// - Returns an error if the theme file is missing or contains validation problems.
+/// - Returns an error if the theme file is missing or contains validation problems; the current
+///   theme stays in place.
+/// - A built-in palette in use is simply re-applied.
 pub fn reload_theme() -> std::result::Result<(), String> {
-    let path = resolve_theme_config_path().or_else(|| Some(config_dir().join("theme.conf")));
-    let Some(p) = path else {
-        return Err("No theme configuration file found".to_string());
+    if let Some(builtin) = ACTIVE_BUILTIN.read().ok().and_then(|g| *g) {
+        return store_theme(builtin);
+    }
+    let new_theme = super::config::try_load_theme_with_diagnostics(&active_theme_path())?;
+    store_theme(new_theme)
+}
+
+/// What: Switch to the theme in `path` and make it the active theme file.
+///
+/// Inputs:
+/// - `path`: Theme file to load, or `None` for the default `theme.conf`.
+///
+/// Output:
+/// - `Ok(())` once the theme is shown; `Err` with the validation message otherwise, in which
+///   case the current theme and active path are kept.
+pub fn load_theme_from(path: Option<&Path>) -> std::result::Result<(), String> {
+    let target = match path {
+        Some(p) => p.to_path_buf(),
+        None => resolve_theme_config_path().unwrap_or_else(|| config_dir().join("theme.conf")),
     };
-    let new_theme = super::config::try_load_theme_with_diagnostics(&p)?;
+    let new_theme = super::config::try_load_theme_with_diagnostics(&target)?;
+    store_theme(new_theme)?;
+    if let Ok(mut g) = ACTIVE_THEME_PATH.write() {
+        *g = path.map(Path::to_path_buf);
+    }
+    if let Ok(mut g) = ACTIVE_BUILTIN.write() {
+        *g = None;
+    }
+    super::watch::watch_active_theme();
+    Ok(())
+}
+
+/// What: Switch to a built-in palette without reading any file.
+///
+/// Details:
+/// - Stays active across [`reload_theme`] until a theme file is loaded with [`load_theme_from`].
+pub fn use_builtin_theme(theme: Theme) -> std::result::Result<(), String> {
+    store_theme(theme)?;
+    if let Ok(mut g) = ACTIVE_BUILTIN.write() {
+        *g = Some(theme);
+    }
+    Ok(())
+}
+
+/// What: Replace the cached theme.
+fn store_theme(new_theme: Theme) -> std::result::Result<(), String> {
     let lock = THEME_STORE.get_or_init(|| RwLock::new(load_initial_theme_or_exit()));
     if let Ok(mut guard) = lock.write() {
         *guard = new_theme;
@@ -41,6 +41,30 @@ pub struct Theme {
     pub lavender: Color,
 }
 
+/// Built-in high-contrast palette, available without a theme file.
+///
+/// Text on the background is 21:1, text on the selection surfaces at least 4.5:1, the selection
+/// surfaces at least 3:1 against the background, and every accent and status color at least
+/// 7:1 against the background, so PKGBUILD highlighting and status lines stay legible.
+pub const HIGH_CONTRAST_THEME: Theme = Theme {
+    base: Color::Rgb(0, 0, 0),
+    mantle: Color::Rgb(0, 0, 0),
+    crust: Color::Rgb(0, 0, 0),
+    surface1: Color::Rgb(0, 95, 210),
+    surface2: Color::Rgb(0, 105, 225),
+    overlay1: Color::Rgb(200, 200, 200),
+    overlay2: Color::Rgb(220, 220, 220),
+    text: Color::Rgb(255, 255, 255),
+    subtext0: Color::Rgb(235, 235, 235),
+    subtext1: Color::Rgb(245, 245, 245),
+    sapphire: Color::Rgb(0, 229, 255),
+    mauve: Color::Rgb(255, 140, 255),
+    green: Color::Rgb(0, 255, 102),
+    yellow: Color::Rgb(255, 255, 0),
+    red: Color::Rgb(255, 110, 110),
+    lavender: Color::Rgb(190, 190, 255),
+};
+
 /// User-configurable application settings parsed from `pacsea.conf`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PackageMarker {
@@ -60,6 +84,10 @@ pub struct Settings {
     pub layout_center_pct: u16,
     /// Percentage width allocated to the Install pane (right column).
     pub layout_right_pct: u16,
+    /// Rescale layout percentages that do not sum to 100 instead of resetting all settings.
+    pub layout_autoscale: bool,
+    /// Percentages as written when `layout_autoscale` rescaled them (not a config key).
+    pub layout_rescaled_from: Option<[u16; 3]>,
     /// Default value for the application's dry-run mode on startup.
     /// This can be toggled via the `--dry-run` CLI flag.
     pub app_dry_run_default: bool,
@@ -67,6 +95,11 @@ pub struct Settings {
     pub keymap: KeyMap,
     /// Initial sort mode for results list.
     pub sort_mode: crate::state::SortMode,
+    /// Results filter toggles set explicitly in settings.conf, as `(key, enabled)` pairs.
+    /// Keys come from `logic::filter::RESULTS_FILTER_KEYS`; absent keys keep their defaults.
+    pub results_filters: Vec<(String, bool)>,
+    /// Whether copy actions write an OSC 52 escape sequence so the terminal sets the clipboard.
+    pub clipboard_osc52: bool,
     /// Text appended when copying PKGBUILD to clipboard.
     pub clipboard_suffix: String,
     /// Whether the Recent pane should be shown on startup.
@@ -75,11 +108,31 @@ pub struct Settings {
     pub show_install_pane: bool,
     /// Whether the keybinds footer should be shown on startup.
     pub show_keybinds_footer: bool,
+    /// Compact layout: `None` switches automatically on small terminals, `Some(true)`/`Some(false)` force it on/off.
+    pub compact_mode: Option<bool>,
+    /// Lines (or rows) moved per mouse-wheel notch in Results, Package Info, PKGBUILD and Help (1-20).
+    pub mouse_scroll_lines: u16,
+    /// Whether the mouse wheel scrolls in the inverted (natural, touchpad-style) direction.
+    pub mouse_natural_scroll: bool,
+    /// Whether to start in installed-only mode (Results limited to explicitly installed packages).
+    pub installed_only_default: bool,
+    /// Whether in-pane find ("/") uses fuzzy subsequence matching instead of substrings.
+    pub fuzzy_find: bool,
+    /// Milliseconds the search waits after the last keystroke before querying (0 searches on every keystroke).
+    pub search_debounce_ms: u64,
+    /// Watch theme.conf and reload the theme automatically when it changes.
+    pub theme_auto_reload: bool,
+    /// Theme selected from `config_dir/themes/<name>.conf`; empty uses theme.conf.
+    pub active_theme: String,
+    /// Color depth override: auto (detect from COLORTERM/TERM/NO_COLOR), truecolor, 256, 16, or none.
+    pub force_color: String,
     /// Selected countries used when updating mirrors (comma-separated or multiple).
     pub selected_countries: String,
     /// Number of mirrors to fetch/rank when updating.
     pub mirror_count: u16,
     pub virustotal_api_key: String,
+    /// VirusTotal lookups allowed per minute during scans (0 = unlimited); the public API allows 4.
+    pub virustotal_requests_per_minute: u32,
     pub scan_do_clamav: bool,
     pub scan_do_trivy: bool,
     pub scan_do_semgrep: bool,
@@ -87,6 +140,8 @@ pub struct Settings {
     pub scan_do_virustotal: bool,
     pub scan_do_custom: bool,
     pub scan_do_sleuth: bool,
+    /// Custom PKGBUILD scanner command template with {pkgbuild} and {srcdir} placeholders; empty disables it.
+    pub custom_scan_command: String,
     /// Visual marker style for packages added to Install/Remove/Downgrade lists.
     pub package_marker: PackageMarker,
     /// Symbol used to mark a news item as read in the News modal.
@@ -99,6 +154,39 @@ pub struct Settings {
     /// When true, skip the Preflight modal and execute actions directly (install/remove/downgrade).
     /// Defaults to false to preserve the safer, review-first workflow.
     pub skip_preflight: bool,
+    /// Combined install/remove/downgrade count above which the count must be typed to confirm; 0 disables the guard.
+    pub confirm_threshold: usize,
+    /// Shell command run after a successful install, with the installed package names as arguments; empty disables it.
+    pub post_install_hook: String,
+    /// Seconds a fetched PKGBUILD is served from the on-disk cache (0 disables caching).
+    pub pkgbuild_cache_ttl_secs: u64,
+    /// Whether the PKGBUILD viewer applies bash syntax highlighting.
+    pub pkgbuild_highlight: bool,
+    /// Whether the Package Info pane wraps long lines (off scrolls them sideways).
+    pub details_wrap: bool,
+    /// Whether the PKGBUILD viewer wraps long lines.
+    pub pkgbuild_wrap: bool,
+    /// Minimum milliseconds between disk writes of each persisted list/cache.
+    pub persist_throttle_ms: u64,
+    /// Minutes between automatic background refreshes of the official index (0 disables).
+    pub index_refresh_interval_mins: u64,
+    /// Write the official index as gzip-compressed official_index.json.gz
+    pub compress_index: bool,
+    /// Treat the AUR as unavailable: no AUR search, filter toggle, or AUR preflight resolution.
+    pub aur_disabled: bool,
+    /// AUR helper tried first (paru, yay, pikaur, trizen, aurutils); empty keeps the default order.
+    pub preferred_aur_helper: String,
+    /// Proxy for `http://` requests (`[user:pass@]host:port` or a URL); empty falls back to `HTTP_PROXY`/`http_proxy`.
+    pub http_proxy: String,
+    /// Proxy for `https://` requests; empty falls back to `HTTPS_PROXY`/`https_proxy`.
+    pub https_proxy: String,
+    /// Packages resolved concurrently by the dependency resolver (1 = sequential; AUR lookups are capped lower).
+    pub deps_resolve_concurrency: usize,
+    /// Pacman binary (e.g. `pacman-static`) used for every pacman invocation, queries included;
+    /// it must support the full pacman CLI.
+    pub pacman_binary: String,
+    /// Tool used to run pacman as root: `sudo`, `doas`, or `run0`.
+    pub privilege_escalation: String,
     /// Locale code for translations (e.g., "de-DE", "en-US").
     /// Empty string means auto-detect from system locale.
     pub locale: String,
@@ -121,17 +209,31 @@ impl Default for Settings {
             layout_left_pct: 20,
             layout_center_pct: 60,
             layout_right_pct: 20,
+            layout_autoscale: true,
+            layout_rescaled_from: None,
             app_dry_run_default: false,
             keymap: KeyMap::default(),
             sort_mode: crate::state::SortMode::RepoThenName,
+            results_filters: Vec::new(),
+            clipboard_osc52: false,
             clipboard_suffix: "Check PKGBUILD and source for suspicious and malicious activities"
                 .to_string(),
             show_recent_pane: true,
             show_install_pane: true,
             show_keybinds_footer: true,
+            compact_mode: None,
+            mouse_scroll_lines: 1,
+            mouse_natural_scroll: false,
+            installed_only_default: false,
+            fuzzy_find: false,
+            search_debounce_ms: 250,
+            theme_auto_reload: false,
+            active_theme: String::new(),
+            force_color: "auto".to_string(),
             selected_countries: "Worldwide".to_string(),
             mirror_count: 20,
             virustotal_api_key: String::new(),
+            virustotal_requests_per_minute: 4,
             scan_do_clamav: true,
             scan_do_trivy: true,
             scan_do_semgrep: true,
@@ -139,11 +241,28 @@ impl Default for Settings {
             scan_do_virustotal: true,
             scan_do_custom: true,
             scan_do_sleuth: true,
+            custom_scan_command: String::new(),
             package_marker: PackageMarker::Front,
             news_read_symbol: "✓".to_string(),
             news_unread_symbol: "∘".to_string(),
             preferred_terminal: String::new(),
             skip_preflight: false,
+            confirm_threshold: 0,
+            post_install_hook: String::new(),
+            pkgbuild_cache_ttl_secs: 3600,
+            pkgbuild_highlight: true,
+            details_wrap: true,
+            pkgbuild_wrap: true,
+            persist_throttle_ms: 1000,
+            index_refresh_interval_mins: 0,
+            compress_index: false,
+            aur_disabled: false,
+            preferred_aur_helper: String::new(),
+            http_proxy: String::new(),
+            https_proxy: String::new(),
+            deps_resolve_concurrency: 4,
+            pacman_binary: "pacman".to_string(),
+            privilege_escalation: "sudo".to_string(),
             locale: String::new(), // Empty means auto-detect from system
         }
     }
@@ -260,6 +379,59 @@ mod tests {
         };
         assert_eq!(kc6.label(), "Alt+Shift+X");
     }
+
+    /// What: WCAG contrast ratio between two RGB colors.
+    fn contrast(a: Color, b: Color) -> f64 {
+        let lum = |c: Color| {
+            let Color::Rgb(r, g, b) = c else {
+                panic!("expected an RGB color, got {c:?}");
+            };
+            let ch = |v: u8| {
+                let v = f64::from(v) / 255.0;
+                if v <= 0.039_28 {
+                    v / 12.92
+                } else {
+                    ((v + 0.055) / 1.055).powf(2.4)
+                }
+            };
+            0.2126 * ch(r) + 0.7152 * ch(g) + 0.0722 * ch(b)
+        };
+        let (la, lb) = (lum(a), lum(b));
+        (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+    }
+
+    #[test]
+    /// What: Ensure the high-contrast preset meets its contrast guarantees.
+    ///
+    /// Inputs:
+    /// - `HIGH_CONTRAST_THEME` colors.
+    ///
+    /// Output:
+    /// - Text/base at least 7:1, text on selection surfaces at least 4.5:1, surfaces at least 3:1
+    ///   against the background, and every foreground color (PKGBUILD highlighter and status
+    ///   colors included) at least 7:1 against the background.
+    fn theme_high_contrast_preset_is_legible() {
+        let th = HIGH_CONTRAST_THEME;
+        assert!(contrast(th.text, th.base) >= 7.0);
+        for surface in [th.surface1, th.surface2] {
+            assert!(contrast(th.text, surface) >= 4.5, "{surface:?}");
+            assert!(contrast(surface, th.base) >= 3.0, "{surface:?}");
+        }
+        for fg in [
+            th.overlay1,
+            th.overlay2,
+            th.subtext0,
+            th.subtext1,
+            th.sapphire,
+            th.mauve,
+            th.green,
+            th.yellow,
+            th.red,
+            th.lavender,
+        ] {
+            assert!(contrast(fg, th.base) >= 7.0, "{fg:?}");
+        }
+    }
 }
 
 /// Application key bindings.
@@ -269,11 +441,65 @@ pub struct KeyMap {
     // Global
     pub help_overlay: Vec<KeyChord>,
     pub reload_theme: Vec<KeyChord>,
+    /// Global: Re-read settings.conf and keybinds.conf without restarting.
+    pub reload_settings: Vec<KeyChord>,
+    /// Switch to the next theme in `config_dir/themes`
+    pub cycle_theme: Vec<KeyChord>,
     pub exit: Vec<KeyChord>,
     /// Global: Show/Hide PKGBUILD viewer
     pub show_pkgbuild: Vec<KeyChord>,
+    /// Global: Jump the PKGBUILD viewer to the next install-path or backup line
+    pub pkgbuild_next_mark: Vec<KeyChord>,
     /// Global: Change results sorting mode
     pub change_sort: Vec<KeyChord>,
+    /// Global: Show/Hide the mirror download URL for official packages in details
+    pub toggle_download_server: Vec<KeyChord>,
+    /// Global: Copy current Results filters and sort mode as settings.conf lines
+    pub copy_view_settings: Vec<KeyChord>,
+    /// Global: Reopen the most recently closed modal (Help/News/Preflight/...)
+    pub reopen_modal: Vec<KeyChord>,
+    /// Toggle the Results status line between Arch status only and verbose details
+    pub toggle_status_verbose: Vec<KeyChord>,
+    /// Toggle showing active resolution stages (tracing spans) in the Results status line
+    pub toggle_status_spans: Vec<KeyChord>,
+    /// Global: Store the selected package in compare slot A
+    pub compare_slot_a: Vec<KeyChord>,
+    /// Global: Store the selected package in compare slot B and open the compare modal
+    pub compare_slot_b: Vec<KeyChord>,
+    /// Global: Open the selected package's upstream URL in the browser
+    pub open_homepage: Vec<KeyChord>,
+    /// Open the selected package's AUR or archlinux.org page
+    pub open_package_page: Vec<KeyChord>,
+    /// Preview the exact commands for the focused install/remove/downgrade list
+    pub preview_commands: Vec<KeyChord>,
+    /// Explain which explicitly installed packages pull in the selected package
+    pub why_installed: Vec<KeyChord>,
+    /// Global: List the files the selected package installs (filterable)
+    pub show_files: Vec<KeyChord>,
+    /// Global: Clear the install, remove, and downgrade lists after confirmation
+    pub clear_all_lists: Vec<KeyChord>,
+    /// Global: Undo the last install/remove/downgrade list change
+    pub undo: Vec<KeyChord>,
+    /// Global: Redo the last undone list change
+    pub redo: Vec<KeyChord>,
+    /// Global: Open the command palette listing every action
+    pub command_palette: Vec<KeyChord>,
+    /// Global: Open the logs viewer on Pacsea's own log
+    pub open_logs: Vec<KeyChord>,
+    /// Global: Scroll the Package Info pane (or the open PKGBUILD viewer) left
+    pub details_scroll_left: Vec<KeyChord>,
+    /// Global: Scroll the Package Info pane (or the open PKGBUILD viewer) right
+    pub details_scroll_right: Vec<KeyChord>,
+    /// Global: Toggle line wrapping in the PKGBUILD viewer (when open) or the Package Info pane
+    pub toggle_wrap: Vec<KeyChord>,
+    /// Re-read the installed and explicitly installed package sets now
+    pub refresh_installed: Vec<KeyChord>,
+    /// Open Preflight for the focused Search result or Install list directly on the Deps tab (default: Alt+D)
+    pub preflight_tab_deps: Vec<KeyChord>,
+    /// Open Preflight directly on the Files tab (default: Alt+F)
+    pub preflight_tab_files: Vec<KeyChord>,
+    /// Open Preflight directly on the Services tab (default: Alt+S)
+    pub preflight_tab_services: Vec<KeyChord>,
     pub pane_next: Vec<KeyChord>,
     pub pane_left: Vec<KeyChord>,
     pub pane_right: Vec<KeyChord>,
@@ -284,6 +510,12 @@ pub struct KeyMap {
     /// Global: Toggle Panels dropdown
     pub panels_menu_toggle: Vec<KeyChord>,
 
+    // Key sequences (each binding is a chord sequence, e.g. `g g`)
+    /// Lists: jump to the first entry of the focused list (default: `g g`)
+    pub goto_top: Vec<Vec<KeyChord>>,
+    /// Lists: jump to the last entry of the focused list (default: `Shift+G`)
+    pub goto_bottom: Vec<Vec<KeyChord>>,
+
     // Search
     pub search_move_up: Vec<KeyChord>,
     pub search_move_down: Vec<KeyChord>,
@@ -291,6 +523,10 @@ pub struct KeyMap {
     pub search_page_down: Vec<KeyChord>,
     pub search_add: Vec<KeyChord>,
     pub search_install: Vec<KeyChord>,
+    /// Search: toggle multi-select mode, where the add key marks results (default: Alt+M)
+    pub search_multi_select: Vec<KeyChord>,
+    /// Search: add every marked result to the list at once (default: Alt+L)
+    pub search_add_selected: Vec<KeyChord>,
     pub search_focus_left: Vec<KeyChord>,
     pub search_focus_right: Vec<KeyChord>,
     pub search_backspace: Vec<KeyChord>,
@@ -334,6 +570,10 @@ pub struct KeyMap {
     pub install_confirm: Vec<KeyChord>,
     pub install_remove: Vec<KeyChord>,
     pub install_clear: Vec<KeyChord>,
+    /// Install list: remove every entry expanded from the selected entry's group (default: Shift+D)
+    pub install_remove_group: Vec<KeyChord>,
+    /// Pick a cached version for the selected Downgrade entry (installed-only mode)
+    pub install_pick_version: Vec<KeyChord>,
     pub install_find: Vec<KeyChord>,
     pub install_to_search: Vec<KeyChord>,
     pub install_focus_left: Vec<KeyChord>,
@@ -377,6 +617,14 @@ impl Default for KeyMap {
                 code: Char('r'),
                 mods: ctrl,
             }],
+            reload_settings: vec![KeyChord {
+                code: KeyCode::F(5),
+                mods: KeyModifiers::empty(),
+            }],
+            cycle_theme: vec![KeyChord {
+                code: Char('t'),
+                mods: KeyModifiers::ALT,
+            }],
             exit: vec![KeyChord {
                 code: Char('c'),
                 mods: ctrl,
@@ -385,10 +633,110 @@ impl Default for KeyMap {
                 code: Char('x'),
                 mods: ctrl,
             }],
+            pkgbuild_next_mark: vec![KeyChord {
+                code: Char('n'),
+                mods: KeyModifiers::ALT,
+            }],
             change_sort: vec![KeyChord {
                 code: BackTab,
                 mods: none,
             }],
+            toggle_download_server: vec![KeyChord {
+                code: Char('s'),
+                mods: ctrl,
+            }],
+            copy_view_settings: vec![KeyChord {
+                code: Char('y'),
+                mods: ctrl,
+            }],
+            reopen_modal: vec![KeyChord {
+                code: Char('o'),
+                mods: ctrl,
+            }],
+            toggle_status_verbose: vec![KeyChord {
+                code: Char('t'),
+                mods: ctrl,
+            }],
+            toggle_status_spans: vec![KeyChord {
+                code: F(12),
+                mods: KeyModifiers::empty(),
+            }],
+            compare_slot_a: vec![KeyChord {
+                code: Char('a'),
+                mods: KeyModifiers::ALT,
+            }],
+            compare_slot_b: vec![KeyChord {
+                code: Char('b'),
+                mods: KeyModifiers::ALT,
+            }],
+            open_homepage: vec![KeyChord {
+                code: Char('h'),
+                mods: KeyModifiers::ALT,
+            }],
+            open_package_page: vec![KeyChord {
+                code: Char('o'),
+                mods: KeyModifiers::ALT,
+            }],
+            preview_commands: vec![KeyChord {
+                code: Char('p'),
+                mods: KeyModifiers::ALT,
+            }],
+            why_installed: vec![KeyChord {
+                code: Char('w'),
+                mods: KeyModifiers::ALT,
+            }],
+            show_files: vec![KeyChord {
+                code: Char('v'),
+                mods: KeyModifiers::ALT,
+            }],
+            clear_all_lists: vec![KeyChord {
+                code: Char('c'),
+                mods: KeyModifiers::ALT,
+            }],
+            undo: vec![KeyChord {
+                code: Char('z'),
+                mods: KeyModifiers::CONTROL,
+            }],
+            redo: vec![KeyChord {
+                code: Char('z'),
+                mods: KeyModifiers::ALT,
+            }],
+            command_palette: vec![KeyChord {
+                code: Char('p'),
+                mods: KeyModifiers::CONTROL,
+            }],
+            open_logs: vec![KeyChord {
+                code: KeyCode::Char('l'),
+                mods: KeyModifiers::CONTROL,
+            }],
+            details_scroll_left: vec![KeyChord {
+                code: KeyCode::Left,
+                mods: KeyModifiers::ALT,
+            }],
+            details_scroll_right: vec![KeyChord {
+                code: KeyCode::Right,
+                mods: KeyModifiers::ALT,
+            }],
+            toggle_wrap: vec![KeyChord {
+                code: KeyCode::Char('r'),
+                mods: KeyModifiers::ALT,
+            }],
+            refresh_installed: vec![KeyChord {
+                code: KeyCode::Char('i'),
+                mods: KeyModifiers::ALT,
+            }],
+            preflight_tab_deps: vec![KeyChord {
+                code: KeyCode::Char('d'),
+                mods: KeyModifiers::ALT,
+            }],
+            preflight_tab_files: vec![KeyChord {
+                code: KeyCode::Char('f'),
+                mods: KeyModifiers::ALT,
+            }],
+            preflight_tab_services: vec![KeyChord {
+                code: KeyCode::Char('s'),
+                mods: KeyModifiers::ALT,
+            }],
             pane_next: vec![KeyChord {
                 code: Tab,
                 mods: none,
@@ -416,6 +764,21 @@ impl Default for KeyMap {
                 mods: shift,
             }],
 
+            goto_top: vec![vec![
+                KeyChord {
+                    code: Char('g'),
+                    mods: none,
+                },
+                KeyChord {
+                    code: Char('g'),
+                    mods: none,
+                },
+            ]],
+            goto_bottom: vec![vec![KeyChord {
+                code: Char('g'),
+                mods: shift,
+            }]],
+
             search_move_up: vec![KeyChord {
                 code: Up,
                 mods: none,
@@ -440,6 +803,14 @@ impl Default for KeyMap {
                 code: Enter,
                 mods: none,
             }],
+            search_multi_select: vec![KeyChord {
+                code: KeyCode::Char('m'),
+                mods: KeyModifiers::ALT,
+            }],
+            search_add_selected: vec![KeyChord {
+                code: KeyCode::Char('l'),
+                mods: KeyModifiers::ALT,
+            }],
             search_focus_left: vec![KeyChord {
                 code: Left,
                 mods: none,
@@ -584,6 +955,14 @@ impl Default for KeyMap {
                 code: Delete,
                 mods: shift,
             }],
+            install_remove_group: vec![KeyChord {
+                code: Char('d'),
+                mods: shift,
+            }],
+            install_pick_version: vec![KeyChord {
+                code: Char('v'),
+                mods: none,
+            }],
             install_find: vec![KeyChord {
                 code: Char('/'),
                 mods: none,
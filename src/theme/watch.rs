@@ -0,0 +1,189 @@
+//! Background watcher that reloads the active theme file when it changes (`theme_auto_reload`).
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, mpsc};
+use std::time::Duration;
+
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use super::named::themes_dir;
+use super::store::active_theme_path;
+
+/// Quiet period after the last change before reloading; editors often write a file several times.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Set by the watcher once a burst of changes has settled; drained by the UI tick.
+static THEME_CHANGED: AtomicBool = AtomicBool::new(false);
+
+/// Running watcher and the directories it watches; `None` until [`start_theme_watcher`] succeeds.
+static WATCHER: Mutex<Option<(RecommendedWatcher, Vec<PathBuf>)>> = Mutex::new(None);
+
+/// What: Directories to watch so changes to `active` are seen.
+///
+/// Output:
+/// - The active theme's directory followed by the named themes directory, without duplicates.
+fn watch_dirs(active: &Path) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = active.parent().map(Path::to_path_buf).into_iter().collect();
+    if !dirs.contains(&themes_dir()) {
+        dirs.push(themes_dir());
+    }
+    dirs
+}
+
+/// What: Whether a filesystem event touches the active theme file.
+///
+/// Inputs:
+/// - `event`: Event reported for a watched directory.
+/// - `active`: Theme file currently in use.
+///
+/// Output:
+/// - `true` for creates, writes, and renames of that file; access events are ignored.
+fn is_theme_change(event: &notify::Event, active: &Path) -> bool {
+    matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    ) && event.paths.iter().any(|p| p == active)
+}
+
+/// What: Start watching theme files on a background thread.
+///
+/// Inputs:
+/// - `dirs`: Directories holding theme files; missing ones are skipped.
+///
+/// Output:
+/// - `Err` with a reason when the watcher cannot be created or no directory can be watched.
+///
+/// Details:
+/// - Watches directories rather than files, so editors that save by writing a new file and
+///   renaming it over the old one keep triggering reloads.
+/// - Each burst is filtered against the theme active at that time; [`watch_active_theme`] adds
+///   the directory of a theme switched to later.
+/// - Sets a flag after [`DEBOUNCE`] of quiet; the UI reloads on its next tick via
+///   [`take_theme_changed`] so a bad edit can be reported there.
+fn spawn_watcher(dirs: &[PathBuf]) -> Result<(), String> {
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx).map_err(|e| e.to_string())?;
+    let watched: Vec<PathBuf> = dirs
+        .iter()
+        .filter(|d| watcher.watch(d, RecursiveMode::NonRecursive).is_ok())
+        .cloned()
+        .collect();
+    if watched.is_empty() {
+        return Err("no theme directory to watch".to_string());
+    }
+    if let Ok(mut g) = WATCHER.lock() {
+        *g = Some((watcher, watched));
+    }
+    std::thread::spawn(move || {
+        while let Ok(first) = rx.recv() {
+            let active = active_theme_path();
+            let mut changed = matches!(&first, Ok(ev) if is_theme_change(ev, &active));
+            loop {
+                match rx.recv_timeout(DEBOUNCE) {
+                    Ok(Ok(ev)) => changed |= is_theme_change(&ev, &active),
+                    Ok(Err(_)) => {}
+                    Err(mpsc::RecvTimeoutError::Timeout) => break,
+                    Err(mpsc::RecvTimeoutError::Disconnected) => return,
+                }
+            }
+            if changed {
+                THEME_CHANGED.store(true, Ordering::Relaxed);
+            }
+        }
+    });
+    Ok(())
+}
+
+/// What: Start the theme watcher on the active theme's directory and the themes directory.
+///
+/// Details:
+/// - Failures are logged; the manual reload keybind keeps working either way.
+pub fn start_theme_watcher() {
+    let path = active_theme_path();
+    match spawn_watcher(&watch_dirs(&path)) {
+        Ok(()) => tracing::info!(path = %path.display(), "watching theme for changes"),
+        Err(e) => tracing::warn!(path = %path.display(), error = %e, "theme watcher unavailable"),
+    }
+}
+
+/// What: Retarget the running watcher after the active theme changed.
+///
+/// Details:
+/// - Adds watches for the new theme's directory and for a themes directory created since
+///   startup; a no-op when the watcher is not running.
+pub(crate) fn watch_active_theme() {
+    let Ok(mut guard) = WATCHER.lock() else {
+        return;
+    };
+    let Some((watcher, watched)) = guard.as_mut() else {
+        return;
+    };
+    let path = active_theme_path();
+    for dir in watch_dirs(&path) {
+        if watched.contains(&dir) {
+            continue;
+        }
+        match watcher.watch(&dir, RecursiveMode::NonRecursive) {
+            Ok(()) => {
+                tracing::info!(dir = %dir.display(), "watching theme directory");
+                watched.push(dir);
+            }
+            Err(e) => {
+                tracing::debug!(dir = %dir.display(), error = %e, "theme directory not watched");
+            }
+        }
+    }
+}
+
+/// What: Take the "theme file changed" flag set by the watcher.
+///
+/// Output:
+/// - `true` once per settled burst of changes.
+pub fn take_theme_changed() -> bool {
+    THEME_CHANGED.swap(false, Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure only content changes to the theme file itself count.
+    ///
+    /// Inputs:
+    /// - A modify event for `theme.conf`, one for another file, and an access event.
+    ///
+    /// Output:
+    /// - Only the first is treated as a theme change.
+    fn theme_watch_filters_events() {
+        let name = Path::new("/cfg/theme.conf");
+        let modify = EventKind::Modify(notify::event::ModifyKind::Any);
+        let ev = notify::Event::new(modify).add_path(PathBuf::from("/cfg/theme.conf"));
+        assert!(is_theme_change(&ev, name));
+        let other = notify::Event::new(modify).add_path(PathBuf::from("/cfg/settings.conf"));
+        assert!(!is_theme_change(&other, name));
+        let access = notify::Event::new(EventKind::Access(notify::event::AccessKind::Any))
+            .add_path(PathBuf::from("/cfg/theme.conf"));
+        assert!(!is_theme_change(&access, name));
+    }
+
+    #[test]
+    /// What: Ensure the watched directories follow the active theme without duplicates.
+    ///
+    /// Inputs:
+    /// - A named theme inside the themes directory and a theme file elsewhere.
+    ///
+    /// Output:
+    /// - The first watches only the themes directory; the second its own directory first.
+    fn watch_dirs_follow_active_theme() {
+        assert_eq!(
+            watch_dirs(&themes_dir().join("nord.conf")),
+            vec![themes_dir()]
+        );
+        assert_eq!(
+            watch_dirs(Path::new("/elsewhere/theme.conf")),
+            vec![PathBuf::from("/elsewhere"), themes_dir()]
+        );
+    }
+}
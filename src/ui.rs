@@ -10,6 +10,9 @@
 //! 3) Details pane (bottom): rich package information with a clickable URL and
 //!    a contextual help footer displaying keybindings
 //!
+//! In compact mode (small terminals or `compact_mode = on`) the middle panes and the
+//! details/PKGBUILD split are stacked vertically and the keybinds footer is hidden.
+//!
 //! The renderer also draws modal overlays for alerts and install confirmation.
 //! It updates `app.url_button_rect` to make the URL clickable when available.
 use ratatui::{
@@ -29,6 +32,39 @@ mod middle;
 mod modals;
 mod results;
 
+/// Terminals narrower than this use the compact layout when `compact_mode = auto`.
+const COMPACT_AUTO_WIDTH: u16 = 80;
+/// Terminals shorter than this use the compact layout when `compact_mode = auto`.
+const COMPACT_AUTO_HEIGHT: u16 = 20;
+
+/// What: Split the terminal height for the compact layout.
+///
+/// Inputs:
+/// - `total_h`: Terminal height
+/// - `side_panes`: Number of visible Recent/Install panes stacked around the Search input
+///
+/// Output:
+/// - `(results_h, middle_h, details_h)` summing to at most `total_h`.
+///
+/// Details:
+/// - Search keeps its 3 rows; stacked panes get 4 rows each (two list lines) while the middle
+///   stays within a third of the height, so on short terminals they drop out first.
+/// - Package Info gets 40% of the rest when it can show a line; otherwise Results takes it all.
+fn compact_heights(total_h: u16, side_panes: u16) -> (u16, u16, u16) {
+    const SEARCH_H: u16 = 3;
+    const SIDE_PANE_H: u16 = 4;
+    const MIN_PANE_H: u16 = 3;
+    let side_fit = (total_h / 3).saturating_sub(SEARCH_H) / SIDE_PANE_H;
+    let middle_h = (SEARCH_H + side_panes.min(side_fit) * SIDE_PANE_H).min(total_h);
+    let rest = total_h - middle_h;
+    let details_h = if rest >= 2 * MIN_PANE_H {
+        (rest * 2 / 5).max(MIN_PANE_H)
+    } else {
+        0
+    };
+    (rest - details_h, middle_h, details_h)
+}
+
 /// What: Render a full frame of the Pacsea TUI.
 ///
 /// Inputs:
@@ -53,6 +89,9 @@ pub fn ui(f: &mut Frame, app: &mut AppState) {
     f.render_widget(bg, area);
 
     let total_h = area.height;
+    app.compact_active = app
+        .compact_mode
+        .unwrap_or(area.width < COMPACT_AUTO_WIDTH || area.height < COMPACT_AUTO_HEIGHT);
 
     // Minimum heights required (including borders: 2 lines for top/bottom borders)
     const MIN_RESULTS_H: u16 = 3; // 1 visible line + 2 borders
@@ -75,7 +114,10 @@ pub fn ui(f: &mut Frame, app: &mut AppState) {
 
     // If there's space beyond minimums, allocate it to Results and Middle
     // Package Info only gets space if there's enough left after Results and Middle grow
-    let (top_h, search_h, bottom_h) = if space_after_min >= MIN_PACKAGE_INFO_H {
+    let (top_h, search_h, bottom_h) = if app.compact_active {
+        let side_panes = u16::from(app.show_recent_pane) + u16::from(app.show_install_pane);
+        compact_heights(total_h, side_panes)
+    } else if space_after_min >= MIN_PACKAGE_INFO_H {
         // Enough space for all three: Results and Middle get most of the space (75%), Package Info gets remainder (25%)
         let top_middle_share = (total_h * 3) / 4; // 75% for Results + Middle
 
@@ -345,4 +387,92 @@ mod tests {
         assert_eq!(buffer.area.width, 120);
         assert_eq!(buffer.area.height, 40);
     }
+
+    #[test]
+    /// What: Ensure small terminals switch to the stacked compact layout and modals still fit.
+    ///
+    /// Inputs:
+    /// - A 60x40 terminal (narrow) with `compact_mode` on auto, then the Help modal opened.
+    ///
+    /// Output:
+    /// - Compact layout is active, Recent sits above Search above Install at full width, no
+    ///   splitter borders are recorded, and the Help rect lies within the terminal.
+    fn ui_compact_layout_stacks_panes_and_keeps_modals_inside() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let backend = TestBackend::new(60, 40);
+        let mut term = Terminal::new(backend).unwrap();
+        let mut app = crate::state::AppState {
+            ..Default::default()
+        };
+        init_test_translations(&mut app);
+        term.draw(|f| super::ui(f, &mut app)).unwrap();
+
+        assert!(app.compact_active);
+        assert!(app.pane_border_cols.is_empty());
+        let (rx, ry, rw, _) = app.recent_rect.expect("recent pane stacked");
+        let (ix, iy, iw, _) = app.install_rect.expect("install pane stacked");
+        assert_eq!((rx, ix), (1, 1));
+        assert_eq!((rw, iw), (58, 58));
+        assert!(ry < iy);
+
+        app.modal = crate::state::Modal::Help;
+        term.draw(|f| super::ui(f, &mut app)).unwrap();
+        let (hx, hy, hw, hh) = app.help_rect.expect("help rect");
+        assert!(hx + hw <= 60 && hy + hh <= 40);
+    }
+
+    #[test]
+    /// What: Ensure fixed-size modals and dropdowns are clamped when the terminal is tiny.
+    ///
+    /// Inputs:
+    /// - 30x6 and 200x2 terminals with each Results dropdown open and fixed-height modals shown.
+    ///
+    /// Output:
+    /// - Every frame renders without drawing outside the buffer (which would panic).
+    fn ui_clamps_modals_and_menus_on_tiny_terminals() {
+        use crate::state::Modal;
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let modals = [
+            Modal::None,
+            Modal::ImportHelp,
+            Modal::GnomeTerminalPrompt,
+            Modal::VirusTotalSetup {
+                input: String::new(),
+                cursor: 0,
+            },
+            Modal::Alert {
+                message: "Network error".into(),
+            },
+            Modal::SystemUpdate {
+                do_mirrors: true,
+                do_pacman: true,
+                do_aur: true,
+                do_cache: false,
+                country_idx: 0,
+                countries: vec!["Worldwide".into()],
+                mirror_count: 20,
+                cursor: 0,
+                loading: false,
+            },
+        ];
+        for (w, h) in [(30, 6), (200, 2)] {
+            for menu in 0..4 {
+                for modal in modals.clone() {
+                    let mut term = Terminal::new(TestBackend::new(w, h)).unwrap();
+                    let mut app = crate::state::AppState {
+                        options_menu_open: menu == 0,
+                        sort_menu_open: menu == 1,
+                        panels_menu_open: menu == 2,
+                        config_menu_open: menu == 3,
+                        modal,
+                        ..Default::default()
+                    };
+                    init_test_translations(&mut app);
+                    term.draw(|f| super::ui(f, &mut app)).unwrap();
+                }
+            }
+        }
+    }
 }
@@ -116,7 +116,7 @@ pub fn calculate_footer_height(app: &AppState, bottom_container: Rect) -> u16 {
     };
 
     // Calculate required keybinds height
-    let base_help_h: u16 = if app.show_keybinds_footer {
+    let base_help_h: u16 = if app.show_keybinds_footer && !app.compact_active {
         baseline_lines
     } else {
         0
@@ -135,8 +135,8 @@ pub fn calculate_footer_height(app: &AppState, bottom_container: Rect) -> u16 {
 /// - Tuple of `(content_container, details_area, pkgb_area_opt, show_keybinds)` describing splits.
 ///
 /// Details:
-/// - Reserves footer space only when toggled on and space allows; splits remaining area evenly when
-///   PKGBUILD view is active.
+/// - Reserves footer space only when toggled on, space allows and the compact layout is off; splits
+///   the remaining area evenly when PKGBUILD view is active (stacked in the compact layout).
 pub fn calculate_layout_areas(
     app: &AppState,
     bottom_container: Rect,
@@ -148,8 +148,9 @@ pub fn calculate_layout_areas(
     // Keybinds vanish first: only show if there's enough space for Package Info + Keybinds
     // Package Info needs at least MIN_PACKAGE_INFO_H, so keybinds only show if:
     // bottom_container.height >= MIN_PACKAGE_INFO_H + footer_height
-    let show_keybinds =
-        app.show_keybinds_footer && bottom_container.height >= MIN_PACKAGE_INFO_H + footer_height;
+    let show_keybinds = app.show_keybinds_footer
+        && !app.compact_active
+        && bottom_container.height >= MIN_PACKAGE_INFO_H + footer_height;
 
     let help_h: u16 = if show_keybinds { footer_height } else { 0 };
     let content_container = Rect {
@@ -160,8 +161,14 @@ pub fn calculate_layout_areas(
     };
     let (details_area, pkgb_area_opt) = if app.pkgb_visible {
         use ratatui::layout::{Constraint, Direction, Layout};
+        // Compact layout stacks PKGBUILD under Package Info instead of beside it
+        let direction = if app.compact_active {
+            Direction::Vertical
+        } else {
+            Direction::Horizontal
+        };
         let split = Layout::default()
-            .direction(Direction::Horizontal)
+            .direction(direction)
             .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
             .split(content_container);
         (split[0], Some(split[1]))
@@ -6,6 +6,7 @@ mod footer;
 mod layout;
 mod package_info;
 mod pkgbuild;
+mod pkgbuild_highlight;
 
 /// What: Render the bottom details pane, footer, and optional PKGBUILD viewer.
 ///
@@ -90,7 +91,7 @@ mod tests {
             url: "https://example.com".into(),
             licenses: vec![],
             groups: vec![],
-            provides: vec![],
+            provides: vec!["rg".into()],
             depends: vec![],
             opt_depends: vec![],
             required_by: vec![],
@@ -102,6 +103,7 @@ mod tests {
             owner: String::new(),
             build_date: String::new(),
             popularity: None,
+            aur: None,
         };
         // Show PKGBUILD area
         app.pkgb_visible = true;
@@ -116,6 +118,8 @@ mod tests {
         assert!(app.details_rect.is_some());
         assert!(app.url_button_rect.is_some());
         assert!(app.pkgb_button_rect.is_some());
+        assert!(app.package_page_button_rect.is_some());
+        assert_eq!(app.provide_button_rects.len(), 1);
         assert!(app.pkgb_check_button_rect.is_some());
         assert!(app.pkgb_rect.is_some());
         assert!(app.mouse_disabled_in_details);
@@ -16,11 +16,14 @@ use crate::theme::theme;
 /// - `details_area`: Rect assigned to the Package Info pane content
 ///
 /// Output:
-/// - Draws package details and updates mouse hit-test rects for URL/PKGBUILD elements.
+/// - Draws package details and updates mouse hit-test rects for URL/provides/package page/PKGBUILD
+///   elements.
 ///
 /// Details:
 /// - Applies scroll offsets, styles the URL as a link when present, records button rectangles, and
 ///   enables text selection by marking `mouse_disabled_in_details`.
+/// - With `details_wrap` off or `details_hscroll` > 0 lines are not wrapped; a sideways offset
+///   shifts them left, is clamped to the longest visible line, and disables the buttons.
 pub fn render_package_info(f: &mut Frame, app: &mut AppState, details_area: Rect) {
     let th = theme();
 
@@ -33,7 +36,11 @@ pub fn render_package_info(f: &mut Frame, app: &mut AppState, details_area: Rect
         details_area.height.saturating_sub(2),
     ));
 
-    // Apply scroll offset by skipping lines from the top
+    // Apply scroll offset by skipping lines from the top (clamped so a wrap toggle or shorter
+    // package never scrolls past the end)
+    app.details_scroll = app
+        .details_scroll
+        .min(u16::try_from(details_lines.len().saturating_sub(1)).unwrap_or(u16::MAX));
     let scroll_offset = app.details_scroll as usize;
     let visible_lines: Vec<_> = details_lines.iter().skip(scroll_offset).cloned().collect();
 
@@ -41,11 +48,21 @@ pub fn render_package_info(f: &mut Frame, app: &mut AppState, details_area: Rect
     // Process original lines first to style URL and find buttons
     app.url_button_rect = None;
     app.pkgb_button_rect = None;
+    app.package_page_button_rect = None;
+    app.provide_button_rects.clear();
     let border_inset = 1u16;
     let content_x = details_area.x.saturating_add(border_inset);
     let content_y = details_area.y.saturating_add(border_inset);
     let inner_w: u16 = details_area.width.saturating_sub(2);
 
+    // Horizontal scroll: clamp to the longest visible line; lines are not wrapped while scrolled
+    let longest = visible_lines.iter().map(|l| l.width()).max().unwrap_or(0);
+    app.details_hscroll_max =
+        u16::try_from(longest.saturating_sub(inner_w as usize)).unwrap_or(u16::MAX);
+    app.details_hscroll = app.details_hscroll.min(app.details_hscroll_max);
+    let hscroll = app.details_hscroll;
+    let unwrapped = !app.details_wrap || hscroll > 0;
+
     // Process original lines to style URL
     let url_label = crate::i18n::t(app, "app.details.url_label");
     for line in details_lines.iter_mut() {
@@ -66,7 +83,11 @@ pub fn render_package_info(f: &mut Frame, app: &mut AppState, details_area: Rect
 
     // Calculate button positions based on visible lines only
     let mut cur_y: u16 = content_y;
+    // Buttons are only clickable in the wrapped layout; sideways scrolling shifts them off their text
     for (vis_idx, vis_line) in visible_lines.iter().enumerate() {
+        if hscroll > 0 {
+            break;
+        }
         let line_idx = vis_idx + scroll_offset;
         let original_line = &details_lines[line_idx];
 
@@ -88,9 +109,33 @@ pub fn render_package_info(f: &mut Frame, app: &mut AppState, details_area: Rect
             }
         }
 
-        // Check for PKGBUILD button
+        // Check for the Provides entries; only the ones on the line's first row are clickable
+        if original_line.spans.len() >= 2 && !app.details.provides.is_empty() {
+            let key_txt = original_line.spans[0].content.to_string();
+            let provides_label = crate::i18n::t(app, "app.details.fields.provides");
+            if key_txt.starts_with(&format!("{provides_label}:")) {
+                let mut col = key_txt.chars().count() as u16;
+                for spec in &app.details.provides {
+                    let w = spec.chars().count() as u16;
+                    if col.saturating_add(w) > inner_w {
+                        break;
+                    }
+                    app.provide_button_rects
+                        .push((spec.clone(), (content_x.saturating_add(col), cur_y, w, 1)));
+                    col = col.saturating_add(w + 2);
+                }
+            }
+        }
+
+        // Check for PKGBUILD and package page buttons
         if original_line.spans.len() == 1 {
             let txt = original_line.spans[0].content.to_string();
+            if txt == crate::i18n::t(app, "app.details.open_package_page") {
+                let w = txt.chars().count().min(inner_w as usize) as u16;
+                if w > 0 {
+                    app.package_page_button_rect = Some((content_x, cur_y, w, 1));
+                }
+            }
             let lowered = txt.to_lowercase();
             let show_pkgb = crate::i18n::t(app, "app.details.show_pkgbuild").to_lowercase();
             let hide_pkgb = crate::i18n::t(app, "app.details.hide_pkgbuild").to_lowercase();
@@ -105,7 +150,7 @@ pub fn render_package_info(f: &mut Frame, app: &mut AppState, details_area: Rect
 
         // Advance y accounting for wrapping
         let line_len: usize = vis_line.spans.iter().map(|s| s.content.len()).sum();
-        let rows = if inner_w == 0 {
+        let rows = if unwrapped || inner_w == 0 {
             1
         } else {
             (line_len as u16).div_ceil(inner_w).max(1)
@@ -122,10 +167,14 @@ pub fn render_package_info(f: &mut Frame, app: &mut AppState, details_area: Rect
         .border_type(BorderType::Rounded)
         .border_style(Style::default().fg(th.surface2));
     // Render only visible lines (after scroll offset)
-    let details = Paragraph::new(visible_lines)
+    let mut details = Paragraph::new(visible_lines)
         .style(Style::default().fg(th.text).bg(th.base))
-        .wrap(Wrap { trim: true })
         .block(details_block.clone());
+    details = if unwrapped {
+        details.scroll((0, hscroll))
+    } else {
+        details.wrap(Wrap { trim: true })
+    };
     f.render_widget(details, details_area);
 
     // Allow terminal to mark/select text in details: ignore clicks within details by default
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use ratatui::{
     Frame,
     prelude::Rect,
@@ -6,7 +8,9 @@ use ratatui::{
     widgets::{Block, BorderType, Borders, Paragraph, Wrap},
 };
 
+use super::pkgbuild_highlight::highlight_pkgbuild_line;
 use crate::i18n;
+use crate::logic::files::{PkgbuildMark, pkgbuild_line_marks};
 use crate::state::AppState;
 use crate::theme::theme;
 
@@ -23,9 +27,17 @@ use crate::theme::theme;
 /// Details:
 /// - Applies scroll offset, records the scrollable inner region, and toggles presence of the reload
 ///   button when the cached PKGBUILD belongs to a different package.
+/// - Only the visible lines are syntax highlighted, and only when `pkgb_highlight` is on.
+/// - A gutter marks lines installing into `$pkgdir` (yellow) and `backup=()` entries (lavender).
+/// - With `pkgb_wrap` off or `pkgb_hscroll` > 0 lines are not wrapped and can be scrolled sideways.
 pub fn render_pkgbuild(f: &mut Frame, app: &mut AppState, pkgb_area: Rect) {
     let th = theme();
 
+    // Keep the vertical offset within the text (wrap toggles and shorter PKGBUILDs change the end)
+    let line_count = app.pkgb_text.as_deref().map_or(1, |t| t.lines().count());
+    app.pkgb_scroll = app
+        .pkgb_scroll
+        .min(u16::try_from(line_count.saturating_sub(1)).unwrap_or(u16::MAX));
     let loading_text = i18n::t(app, "app.details.loading_pkgb");
     let pkgb_text = app.pkgb_text.as_deref().unwrap_or(&loading_text);
     // Remember PKGBUILD rect for mouse interactions (scrolling)
@@ -35,17 +47,47 @@ pub fn render_pkgbuild(f: &mut Frame, app: &mut AppState, pkgb_area: Rect) {
         pkgb_area.width.saturating_sub(2),
         pkgb_area.height.saturating_sub(2),
     ));
-    // Apply vertical scroll offset by trimming top lines
-    let mut visible = String::new();
-    let mut skip = app.pkgb_scroll as usize;
-    for line in pkgb_text.lines() {
-        if skip > 0 {
-            skip -= 1;
-            continue;
-        }
-        visible.push_str(line);
-        visible.push('\n');
-    }
+    // Apply vertical scroll offset by trimming top lines; highlight only what remains visible
+    let highlight = app.pkgb_highlight && app.pkgb_text.is_some();
+    // Gutter marks for lines installing into $pkgdir or listed in backup=()
+    let marks: HashMap<usize, PkgbuildMark> = app
+        .pkgb_text
+        .as_deref()
+        .map(pkgbuild_line_marks)
+        .unwrap_or_default()
+        .into_iter()
+        .collect();
+    let visible: Vec<Line> = pkgb_text
+        .lines()
+        .enumerate()
+        .skip(app.pkgb_scroll as usize)
+        .map(|(idx, line)| {
+            let mut rendered = if highlight {
+                highlight_pkgbuild_line(line, &th)
+            } else {
+                Line::raw(line.to_string())
+            };
+            if !marks.is_empty() {
+                let gutter = match marks.get(&idx) {
+                    Some(PkgbuildMark::InstallPath) => {
+                        Span::styled("▌ ", Style::default().fg(th.yellow))
+                    }
+                    Some(PkgbuildMark::Backup) => {
+                        Span::styled("▌ ", Style::default().fg(th.lavender))
+                    }
+                    None => Span::raw("  "),
+                };
+                rendered.spans.insert(0, gutter);
+            }
+            rendered
+        })
+        .collect();
+    // Sideways scroll: clamp to the longest visible line; lines are not wrapped while scrolled
+    let inner_w = pkgb_area.width.saturating_sub(2) as usize;
+    let longest = visible.iter().map(Line::width).max().unwrap_or(0);
+    app.pkgb_hscroll_max = u16::try_from(longest.saturating_sub(inner_w)).unwrap_or(u16::MAX);
+    app.pkgb_hscroll = app.pkgb_hscroll.min(app.pkgb_hscroll_max);
+    let unwrapped = !app.pkgb_wrap || app.pkgb_hscroll > 0;
     // Title with clickable "Copy PKGBUILD" button and optional "Reload PKGBUILD" button
     let check_button_label = i18n::t(app, "app.details.copy_pkgbuild");
     let pkgb_title_text = i18n::t(app, "app.titles.pkgb");
@@ -92,9 +134,8 @@ pub fn render_pkgbuild(f: &mut Frame, app: &mut AppState, pkgb_area: Rect) {
         app.pkgb_reload_button_rect = Some((reload_btn_x, btn_y, reload_btn_w, 1));
     }
 
-    let pkgb = Paragraph::new(visible)
+    let mut pkgb = Paragraph::new(visible)
         .style(Style::default().fg(th.text).bg(th.base))
-        .wrap(Wrap { trim: false })
         .block(
             Block::default()
                 .title(Line::from(pkgb_title_spans))
@@ -102,5 +143,10 @@ pub fn render_pkgbuild(f: &mut Frame, app: &mut AppState, pkgb_area: Rect) {
                 .border_type(BorderType::Rounded)
                 .border_style(Style::default().fg(th.surface2)),
         );
+    pkgb = if unwrapped {
+        pkgb.scroll((0, app.pkgb_hscroll))
+    } else {
+        pkgb.wrap(Wrap { trim: false })
+    };
     f.render_widget(pkgb, pkgb_area);
 }
@@ -26,7 +26,8 @@ use crate::{
 /// - `th`: Active theme for colors/styles
 ///
 /// Output:
-/// - Vector of formatted lines for the Details pane, ending with a Show/Hide PKGBUILD action line.
+/// - Vector of formatted lines for the Details pane, ending with the "Open package page" and
+///   Show/Hide PKGBUILD action lines.
 ///
 /// Details:
 /// - Applies repo-specific heuristics, formats numeric sizes via `human_bytes`, and appends a
@@ -62,6 +63,25 @@ pub fn format_details_lines(app: &AppState, _area_width: u16, th: &Theme) -> Vec
     } else {
         d.repository.clone()
     };
+    // AUR packages: an orphan (no maintainer) is called out, and the date is the last update
+    let owner_label = i18n::t(app, "app.details.fields.package_owner");
+    let owner_line = match &d.aur {
+        Some(aur) if aur.maintainer.is_none() => {
+            let mut line = kv(
+                &owner_label,
+                i18n::t(app, "app.details.fields.orphaned"),
+                th,
+            );
+            line.spans[1].style = Style::default().fg(th.red).add_modifier(Modifier::BOLD);
+            line
+        }
+        _ => kv(&owner_label, d.owner.clone(), th),
+    };
+    let date_label = if d.aur.is_some() {
+        i18n::t(app, "app.details.fields.last_updated")
+    } else {
+        i18n::t(app, "app.details.fields.build_date")
+    };
     // Each line is a label/value pair derived from the current details view.
     let mut lines = vec![
         kv(
@@ -79,6 +99,19 @@ pub fn format_details_lines(app: &AppState, _area_width: u16, th: &Theme) -> Vec
             d.version.clone(),
             th,
         ),
+    ];
+    // Flagged AUR packages get a red warning right below the version
+    if let Some(since) = d.aur.as_ref().and_then(|aur| aur.out_of_date) {
+        lines.push(Line::from(Span::styled(
+            i18n::t_fmt1(
+                app,
+                "app.details.flagged_out_of_date",
+                crate::util::ts_to_date(Some(since)),
+            ),
+            Style::default().fg(th.red).add_modifier(Modifier::BOLD),
+        )));
+    }
+    lines.extend([
         kv(
             &i18n::t(app, "app.details.fields.description"),
             d.description.clone(),
@@ -95,11 +128,6 @@ pub fn format_details_lines(app: &AppState, _area_width: u16, th: &Theme) -> Vec
             join(&d.licenses),
             th,
         ),
-        kv(
-            &i18n::t(app, "app.details.fields.provides"),
-            join(&d.provides),
-            th,
-        ),
         kv(
             &i18n::t(app, "app.details.fields.depends_on"),
             join(&d.depends),
@@ -120,16 +148,6 @@ pub fn format_details_lines(app: &AppState, _area_width: u16, th: &Theme) -> Vec
             join(&d.optional_for),
             th,
         ),
-        kv(
-            &i18n::t(app, "app.details.fields.conflicts_with"),
-            join(&d.conflicts),
-            th,
-        ),
-        kv(
-            &i18n::t(app, "app.details.fields.replaces"),
-            join(&d.replaces),
-            th,
-        ),
         kv(
             &i18n::t(app, "app.details.fields.download_size"),
             d.download_size
@@ -144,17 +162,109 @@ pub fn format_details_lines(app: &AppState, _area_width: u16, th: &Theme) -> Vec
                 .unwrap_or_else(|| i18n::t(app, "app.details.fields.not_available")),
             th,
         ),
-        kv(
-            &i18n::t(app, "app.details.fields.package_owner"),
-            d.owner.clone(),
+        owner_line,
+        kv(&date_label, d.build_date.clone(), th),
+    ]);
+    // AUR trust signals: votes, popularity and first submission
+    if let Some(aur) = &d.aur {
+        lines.push(kv(
+            &i18n::t(app, "app.details.fields.votes"),
+            aur.votes.to_string(),
             th,
-        ),
-        kv(
-            &i18n::t(app, "app.details.fields.build_date"),
-            d.build_date.clone(),
+        ));
+        lines.push(kv(
+            &i18n::t(app, "app.details.fields.popularity"),
+            d.popularity.map_or_else(
+                || i18n::t(app, "app.details.fields.not_available"),
+                |pop| format!("{pop:.2}"),
+            ),
             th,
-        ),
-    ];
+        ));
+        lines.push(kv(
+            &i18n::t(app, "app.details.fields.first_submitted"),
+            crate::util::ts_to_date(aur.first_submitted),
+            th,
+        ));
+    }
+    // Install history from pacman.log, once the background scan has indexed it
+    if let Some(history) = (!d.name.is_empty())
+        .then(|| crate::logic::pacman_log::history_summary(&d.name))
+        .flatten()
+    {
+        let never = i18n::t(app, "app.details.fields.not_available");
+        lines.push(Line::from(Span::styled(
+            i18n::t(app, "app.details.install_history"),
+            Style::default()
+                .fg(th.overlay1)
+                .add_modifier(Modifier::BOLD | Modifier::ITALIC),
+        )));
+        lines.push(kv(
+            &i18n::t(app, "app.details.fields.first_installed"),
+            history.first_installed.unwrap_or_else(|| never.clone()),
+            th,
+        ));
+        lines.push(kv(
+            &i18n::t(app, "app.details.fields.last_upgraded"),
+            history.last_upgraded.map_or(never, |at| {
+                i18n::t_fmt(app, "app.details.upgrade_count", &[&at, &history.upgrades])
+            }),
+            th,
+        ));
+    }
+    // Package relationships get their own section; each provide is a clickable provider search
+    lines.push(Line::from(Span::styled(
+        i18n::t(app, "app.details.relationships"),
+        Style::default()
+            .fg(th.overlay1)
+            .add_modifier(Modifier::BOLD | Modifier::ITALIC),
+    )));
+    let mut provides_line = kv(
+        &i18n::t(app, "app.details.fields.provides"),
+        join(&d.provides),
+        th,
+    );
+    if !d.provides.is_empty() {
+        provides_line.spans[1].style = Style::default()
+            .fg(th.mauve)
+            .add_modifier(Modifier::UNDERLINED);
+    }
+    lines.push(provides_line);
+    lines.push(kv(
+        &i18n::t(app, "app.details.fields.conflicts_with"),
+        join(&d.conflicts),
+        th,
+    ));
+    lines.push(kv(
+        &i18n::t(app, "app.details.fields.replaces"),
+        join(&d.replaces),
+        th,
+    ));
+    // Optional debugging aid: mirror URL the package archive would be fetched from
+    if app.show_download_server && !d.repository.is_empty() && d.repository != "AUR" {
+        let url = crate::index::active_mirror_server()
+            .map(|server| {
+                crate::index::package_download_url(
+                    &server,
+                    &d.repository,
+                    &d.name,
+                    &d.version,
+                    &d.architecture,
+                )
+            })
+            .unwrap_or_else(|| i18n::t(app, "app.details.fields.not_available"));
+        lines.push(kv(
+            &i18n::t(app, "app.details.fields.download_server"),
+            url,
+            th,
+        ));
+    }
+    // Clickable line that opens the AUR / archlinux.org page of the package
+    lines.push(Line::from(vec![Span::styled(
+        i18n::t(app, "app.details.open_package_page"),
+        Style::default()
+            .fg(th.mauve)
+            .add_modifier(Modifier::UNDERLINED | Modifier::BOLD),
+    )]));
     // Add a clickable helper line to Show/Hide PKGBUILD below Build date
     let pkgb_label = if app.pkgb_visible {
         i18n::t(app, "app.details.hide_pkgbuild")
@@ -188,6 +298,26 @@ fn join(list: &[String]) -> String {
     }
 }
 
+/// What: Render a fixed-width text progress bar.
+///
+/// Inputs:
+/// - `done`: Finished units
+/// - `total`: Total units
+/// - `width`: Number of cells between the brackets
+///
+/// Output:
+/// - Bar such as `[████░░░░░░]`; empty when `total` is zero.
+///
+/// Details:
+/// - `done` is clamped to `total` so late reports never overflow the bar.
+pub(crate) fn progress_bar(done: usize, total: usize, width: usize) -> String {
+    if total == 0 {
+        return String::new();
+    }
+    let filled = done.min(total) * width / total;
+    format!("[{}{}]", "█".repeat(filled), "░".repeat(width - filled))
+}
+
 /// What: Format a byte count using binary units with one decimal place.
 ///
 /// Inputs:
@@ -198,7 +328,7 @@ fn join(list: &[String]) -> String {
 ///
 /// Details:
 /// - Iteratively divides by 1024 up to PiB, retaining one decimal place for readability.
-fn human_bytes(n: u64) -> String {
+pub(crate) fn human_bytes(n: u64) -> String {
     const UNITS: [&str; 6] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
     let mut v = n as f64;
     let mut i = 0;
@@ -248,17 +378,36 @@ pub async fn fetch_first_match_for_query(q: String) -> Option<crate::state::Pack
     aur.into_iter().next()
 }
 
+/// What: Label shown for a theme name in the picker and toasts.
+///
+/// Inputs:
+/// - `app`: Application state (for translations)
+/// - `name`: Theme name; empty for `theme.conf`, [`crate::theme::HIGH_CONTRAST`] for the preset
+///
+/// Output:
+/// - Translated label for the default and built-in themes, otherwise the name itself.
+pub fn theme_display_name(app: &AppState, name: &str) -> String {
+    if name.is_empty() {
+        crate::i18n::t(app, "app.modals.theme_picker.default_theme")
+    } else if name == crate::theme::HIGH_CONTRAST {
+        crate::i18n::t(app, "app.modals.theme_picker.high_contrast")
+    } else {
+        name.to_string()
+    }
+}
+
 /// What: Produce visible indices into `app.recent` considering pane-find when applicable.
 ///
 /// Inputs:
-/// - `app`: Application state (focus, pane_find, recent list)
+/// - `app`: Application state (focus, pane_find, fuzzy_find, recent list)
 ///
 /// Output:
-/// - Vector of indices in ascending order without modifying application state.
+/// - Vector of indices without modifying application state; ascending unless fuzzy ranking applies.
 ///
 /// Details:
 /// - Applies pane find filtering only when the Recent pane is focused and the finder string is
 ///   non-empty; otherwise returns the full range.
+/// - With `fuzzy_find`, matches are ordered by descending fuzzy score (ties keep list order).
 pub fn filtered_recent_indices(app: &AppState) -> Vec<usize> {
     let apply = matches!(app.focus, Focus::Recent)
         && app
@@ -269,31 +418,101 @@ pub fn filtered_recent_indices(app: &AppState) -> Vec<usize> {
     if !apply {
         return (0..app.recent.len()).collect();
     }
-    let pat = app.pane_find.as_ref().unwrap().to_lowercase();
-    app.recent
-        .iter()
-        .enumerate()
-        .filter_map(|(i, s)| {
-            if s.to_lowercase().contains(&pat) {
-                Some(i)
-            } else {
-                None
-            }
-        })
-        .collect()
+    let pat = app.pane_find.as_deref().unwrap_or_default();
+    rank_pane_matches(
+        app.recent.iter().enumerate().filter_map(|(i, s)| {
+            crate::logic::filter::pane_find_match(s, pat, app.fuzzy_find).map(|(sc, _)| (i, sc))
+        }),
+        app.fuzzy_find,
+    )
+}
+
+/// What: Order pane-find hits for display.
+///
+/// Inputs:
+/// - `hits`: `(index, score)` pairs in list order.
+/// - `fuzzy`: Whether to rank by score.
+///
+/// Output:
+/// - Indices sorted by descending score when `fuzzy`; otherwise in list order.
+fn rank_pane_matches(hits: impl Iterator<Item = (usize, i64)>, fuzzy: bool) -> Vec<usize> {
+    let mut hits: Vec<(usize, i64)> = hits.collect();
+    if fuzzy {
+        hits.sort_by_key(|h| std::cmp::Reverse(h.1));
+    }
+    hits.into_iter().map(|(i, _)| i).collect()
+}
+
+/// What: Compute which characters of a list entry the active pane-find pattern matched.
+///
+/// Inputs:
+/// - `app`: Application state (pane_find, fuzzy_find)
+/// - `text`: Rendered entry text (recent query or package name)
+///
+/// Output:
+/// - Char indices to highlight; empty when no pattern is active or `text` did not match.
+pub fn pane_find_highlights(app: &AppState, text: &str) -> Vec<usize> {
+    match app.pane_find.as_deref() {
+        Some(pat) if !pat.is_empty() => {
+            crate::logic::filter::pane_find_match(text, pat, app.fuzzy_find)
+                .map(|(_, pos)| pos)
+                .unwrap_or_default()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// What: Split `text` into spans, styling the characters at `positions` with `hl`.
+///
+/// Inputs:
+/// - `text`: Text to render.
+/// - `positions`: Ascending char indices to highlight.
+/// - `base`/`hl`: Styles for regular and highlighted characters.
+///
+/// Output:
+/// - Spans covering `text` in order; a single span when nothing is highlighted.
+pub fn highlight_spans(
+    text: &str,
+    positions: &[usize],
+    base: ratatui::style::Style,
+    hl: ratatui::style::Style,
+) -> Vec<ratatui::text::Span<'static>> {
+    if positions.is_empty() {
+        return vec![ratatui::text::Span::styled(text.to_string(), base)];
+    }
+    let mut spans = Vec::new();
+    let mut run = String::new();
+    let mut run_hl = false;
+    for (ci, ch) in text.chars().enumerate() {
+        let is_hl = positions.binary_search(&ci).is_ok();
+        if is_hl != run_hl && !run.is_empty() {
+            let style = if run_hl { hl } else { base };
+            spans.push(ratatui::text::Span::styled(std::mem::take(&mut run), style));
+        }
+        run_hl = is_hl;
+        run.push(ch);
+    }
+    if !run.is_empty() {
+        spans.push(ratatui::text::Span::styled(
+            run,
+            if run_hl { hl } else { base },
+        ));
+    }
+    spans
 }
 
 /// What: Produce visible indices into `app.install_list` with optional pane-find filtering.
 ///
 /// Inputs:
-/// - `app`: Application state (focus, pane_find, install list)
+/// - `app`: Application state (focus, pane_find, fuzzy_find, install list)
 ///
 /// Output:
-/// - Vector of indices in ascending order without modifying application state.
+/// - Vector of indices without modifying application state; ascending unless fuzzy ranking applies.
 ///
 /// Details:
-/// - Restricts matches to name or description substrings when the Install pane is focused and a
+/// - Restricts matches to name or description when the Install pane is focused and a
 ///   pane-find expression is active; otherwise surfaces all indices.
+/// - With `fuzzy_find`, the better of the name/description scores ranks each entry.
 pub fn filtered_install_indices(app: &AppState) -> Vec<usize> {
     let apply = matches!(app.focus, Focus::Install)
         && app
@@ -304,20 +523,20 @@ pub fn filtered_install_indices(app: &AppState) -> Vec<usize> {
     if !apply {
         return (0..app.install_list.len()).collect();
     }
-    let pat = app.pane_find.as_ref().unwrap().to_lowercase();
-    app.install_list
-        .iter()
-        .enumerate()
-        .filter_map(|(i, p)| {
-            let name = p.name.to_lowercase();
-            let desc = p.description.to_lowercase();
-            if name.contains(&pat) || desc.contains(&pat) {
-                Some(i)
-            } else {
-                None
+    let pat = app.pane_find.as_deref().unwrap_or_default();
+    let score = |text: &str| {
+        crate::logic::filter::pane_find_match(text, pat, app.fuzzy_find).map(|(sc, _)| sc)
+    };
+    rank_pane_matches(
+        app.install_list.iter().enumerate().filter_map(|(i, p)| {
+            match (score(&p.name), score(&p.description)) {
+                (Some(a), Some(b)) => Some((i, a.max(b))),
+                (Some(a), None) | (None, Some(a)) => Some((i, a)),
+                (None, None) => None,
             }
-        })
-        .collect()
+        }),
+        app.fuzzy_find,
+    )
 }
 
 /// What: Trigger an asynchronous preview fetch for the selected Recent query when applicable.
@@ -460,6 +679,22 @@ pub fn is_package_loading_preflight(app: &AppState, package_name: &str) -> bool
 mod tests {
     use super::*;
 
+    #[test]
+    /// What: Ensure the progress bar fills proportionally and tolerates edge values.
+    ///
+    /// Inputs:
+    /// - 0/4, 1/4, 4/4 and an overshooting 9/4 on a 4-cell bar, then a zero total.
+    ///
+    /// Output:
+    /// - Empty, quarter, and full bars; the overshoot clamps to full; zero total renders nothing.
+    fn progress_bar_fills_proportionally() {
+        assert_eq!(progress_bar(0, 4, 4), "[░░░░]");
+        assert_eq!(progress_bar(1, 4, 4), "[█░░░]");
+        assert_eq!(progress_bar(4, 4, 4), "[████]");
+        assert_eq!(progress_bar(9, 4, 4), "[████]");
+        assert_eq!(progress_bar(3, 0, 4), "");
+    }
+
     /// What: Initialize minimal English translations for tests.
     ///
     /// Inputs:
@@ -556,6 +791,10 @@ mod tests {
             "Hide PKGBUILD".to_string(),
         );
         translations.insert("app.details.url_label".to_string(), "URL:".to_string());
+        translations.insert(
+            "app.details.open_package_page".to_string(),
+            "Open package page".to_string(),
+        );
         // Results
         translations.insert("app.results.title".to_string(), "Results".to_string());
         translations.insert("app.results.buttons.sort".to_string(), "Sort".to_string());
@@ -688,6 +927,7 @@ mod tests {
             owner: "owner".into(),
             build_date: "date".into(),
             popularity: None,
+            aur: None,
         };
         let th = crate::theme::theme();
         let lines = format_details_lines(&app, 80, &th);
@@ -747,6 +987,7 @@ mod tests {
             owner: String::new(),
             build_date: String::new(),
             popularity: None,
+            aur: None,
         };
         let th = crate::theme::theme();
         let lines = format_details_lines(&app, 80, &th);
@@ -772,6 +1013,61 @@ mod tests {
         );
     }
 
+    #[test]
+    /// What: Ensure AUR details show votes, popularity, the orphaned marker and the out-of-date flag.
+    ///
+    /// Inputs:
+    /// - AUR `PackageDetails` with popularity and `AurInfo` that has votes but no maintainer, then
+    ///   the same package flagged out-of-date one day after the epoch.
+    ///
+    /// Output:
+    /// - Lines carry the vote count, two-decimal popularity, the orphaned label in red, and the
+    ///   "Last updated" label instead of "Build date"; the flag adds a red dated line after Version.
+    fn details_lines_show_aur_trust_signals() {
+        let mut app = crate::state::AppState {
+            ..Default::default()
+        };
+        init_test_translations(&mut app);
+        app.details = crate::state::PackageDetails {
+            repository: "AUR".into(),
+            name: "foo".into(),
+            popularity: Some(1.234),
+            aur: Some(crate::state::AurInfo {
+                votes: 17,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let th = crate::theme::theme();
+        let lines = format_details_lines(&app, 80, &th);
+        let find = |needle: &str| {
+            lines
+                .iter()
+                .find(|l| l.spans.iter().any(|s| s.content.contains(needle)))
+                .cloned()
+        };
+        assert!(find("17").is_some());
+        assert!(find("1.23").is_some());
+        let owner = find("app.details.fields.orphaned").expect("orphan line");
+        assert_eq!(owner.spans[1].style.fg, Some(th.red));
+        assert!(find("app.details.fields.last_updated").is_some());
+        assert!(find("Build date").is_none());
+        assert!(find("app.details.flagged_out_of_date").is_none());
+
+        app.translations.insert(
+            "app.details.flagged_out_of_date".to_string(),
+            "Flagged out-of-date since {}".to_string(),
+        );
+        app.details.aur = Some(crate::state::AurInfo {
+            out_of_date: Some(86_400),
+            ..Default::default()
+        });
+        let lines = format_details_lines(&app, 80, &th);
+        let flagged = &lines[3];
+        assert_eq!(flagged.spans[0].style.fg, Some(th.red));
+        assert!(flagged.spans[0].content.contains("1970-01-02"));
+    }
+
     #[tokio::test]
     /// What: Ensure the recent preview trigger becomes a no-op when focus or selection is invalid.
     ///
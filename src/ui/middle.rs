@@ -25,6 +25,8 @@ use crate::theme::theme;
 /// - Titles and colors reflect focus; when installed-only mode is active the right column shows
 ///   Downgrade and Remove subpanes side-by-side.
 /// - Records inner rects for Recent/Install/Downgrade and sets the caret position for the Search input.
+/// - In the compact layout the panes are stacked top to bottom (Recent, Search, Install) and the
+///   borders are not draggable.
 pub fn render_middle(f: &mut Frame, app: &mut AppState, area: Rect) {
     let th = theme();
 
@@ -50,14 +52,52 @@ pub fn render_middle(f: &mut Frame, app: &mut AppState, area: Rect) {
         .saturating_sub(left_pct)
         .saturating_sub(right_pct)
         .min(100);
-    let middle = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage(left_pct),
-            Constraint::Percentage(center_pct),
-            Constraint::Percentage(right_pct),
-        ])
-        .split(area);
+    let middle = if app.compact_active {
+        // Search keeps 3 rows; the rest is shared by the stacked panes, Install first when tight
+        let side_h = area.height.saturating_sub(3);
+        let (recent_h, install_h) = match (app.show_recent_pane, app.show_install_pane) {
+            (true, true) if side_h >= 6 => (side_h / 2, side_h - side_h / 2),
+            (_, true) => (0, side_h),
+            (true, false) => (side_h, 0),
+            (false, false) => (0, 0),
+        };
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(if recent_h >= 3 { recent_h } else { 0 }),
+                Constraint::Length(3),
+                Constraint::Length(if install_h >= 3 { install_h } else { 0 }),
+            ])
+            .split(area)
+    } else {
+        Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(left_pct),
+                Constraint::Percentage(center_pct),
+                Constraint::Percentage(right_pct),
+            ])
+            .split(area)
+    };
+    // Remember the visible borders (both edge columns) so they can be dragged to resize;
+    // stacked compact panes have no vertical borders to drag
+    app.middle_rect = Some((area.x, area.y, area.width, area.height));
+    app.pane_border_cols.clear();
+    if !app.compact_active {
+        use crate::logic::layout::PaneBorder;
+        if left_pct > 0 && middle[0].width > 0 {
+            let col = middle[1].x;
+            app.pane_border_cols
+                .push((PaneBorder::LeftCenter, col.saturating_sub(1)));
+            app.pane_border_cols.push((PaneBorder::LeftCenter, col));
+        }
+        if right_pct > 0 && middle[2].width > 0 {
+            let col = middle[2].x;
+            app.pane_border_cols
+                .push((PaneBorder::CenterRight, col.saturating_sub(1)));
+            app.pane_border_cols.push((PaneBorder::CenterRight, col));
+        }
+    }
 
     // Search input (center)
     let search_focused = matches!(app.focus, Focus::Search);
@@ -144,6 +184,16 @@ pub fn render_middle(f: &mut Frame, app: &mut AppState, area: Rect) {
     } else {
         th.overlay1
     };
+    let mut search_title_spans = vec![Span::styled(
+        search_title,
+        Style::default().fg(search_title_color),
+    )];
+    if crate::logic::filter::is_invalid_regex(app.input.trim()) {
+        search_title_spans.push(Span::styled(
+            i18n::t(app, "app.titles.invalid_regex"),
+            Style::default().fg(th.overlay1),
+        ));
+    }
     let input = Paragraph::new(input_line)
         .style(
             Style::default()
@@ -152,10 +202,7 @@ pub fn render_middle(f: &mut Frame, app: &mut AppState, area: Rect) {
         )
         .block(
             Block::default()
-                .title(Span::styled(
-                    search_title,
-                    Style::default().fg(search_title_color),
-                ))
+                .title(Line::from(search_title_spans))
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .border_style(Style::default().fg(if search_focused {
@@ -189,17 +236,26 @@ pub fn render_middle(f: &mut Frame, app: &mut AppState, area: Rect) {
     // No status footer here; it is rendered on the Results pane bottom border
 
     // Recent searches (left) with filtering (render only if visible and has width)
-    if app.show_recent_pane && middle[0].width > 0 {
+    if app.show_recent_pane && middle[0].width > 0 && middle[0].height > 0 {
         let recent_focused = matches!(app.focus, Focus::Recent);
         let rec_inds = crate::ui::helpers::filtered_recent_indices(app);
         let rec_items: Vec<ListItem> = rec_inds
             .iter()
             .filter_map(|&i| app.recent.get(i))
             .map(|s| {
-                ListItem::new(Span::styled(
-                    s.clone(),
-                    Style::default().fg(if recent_focused { th.text } else { th.subtext0 }),
-                ))
+                let base = Style::default().fg(if recent_focused { th.text } else { th.subtext0 });
+                let marks = if recent_focused {
+                    crate::ui::helpers::pane_find_highlights(app, s)
+                } else {
+                    Vec::new()
+                };
+                ListItem::new(Line::from(crate::ui::helpers::highlight_spans(
+                    s,
+                    &marks,
+                    base,
+                    base.fg(th.sapphire)
+                        .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+                )))
             })
             .collect();
         let recent_title = if recent_focused {
@@ -224,6 +280,12 @@ pub fn render_middle(f: &mut Frame, app: &mut AppState, area: Rect) {
                     .add_modifier(Modifier::BOLD),
             ));
             recent_title_spans.push(Span::styled(pat.clone(), Style::default().fg(th.text)));
+            if crate::logic::filter::is_invalid_regex(pat) {
+                recent_title_spans.push(Span::styled(
+                    i18n::t(app, "app.titles.invalid_regex"),
+                    Style::default().fg(th.overlay1),
+                ));
+            }
         }
         let rec_block = Block::default()
             .title(Line::from(recent_title_spans))
@@ -256,7 +318,7 @@ pub fn render_middle(f: &mut Frame, app: &mut AppState, area: Rect) {
     }
 
     // Install/Remove List (right) with filtering (render only if visible and has width)
-    if app.show_install_pane && middle[2].width > 0 {
+    if app.show_install_pane && middle[2].width > 0 && middle[2].height > 0 {
         let install_focused = matches!(app.focus, Focus::Install);
 
         if app.installed_only_mode {
@@ -346,6 +408,18 @@ pub fn render_middle(f: &mut Frame, app: &mut AppState, area: Rect) {
                             th.surface2
                         }),
                     ));
+                    if let Some(group) = app.install_group_of.get(&p.name.to_lowercase()) {
+                        segs.push(Span::styled(
+                            format!("  [{group}]"),
+                            Style::default().fg(th.mauve),
+                        ));
+                    }
+                    if let Some(target) = app.downgrade_targets.get(&p.name) {
+                        segs.push(Span::styled(
+                            format!(" → {}", target.version),
+                            Style::default().fg(th.yellow),
+                        ));
+                    }
                     ListItem::new(Line::from(segs))
                 })
                 .collect();
@@ -592,15 +666,25 @@ pub fn render_middle(f: &mut Frame, app: &mut AppState, area: Rect) {
                         ));
                     }
                     segs.push(Span::styled(format!("{src} "), Style::default().fg(color)));
-                    segs.push(Span::styled(
-                        p.name.clone(),
-                        Style::default()
-                            .fg(if install_focused {
-                                th.text
-                            } else {
-                                th.subtext0
-                            })
-                            .add_modifier(Modifier::BOLD),
+                    let name_style = Style::default()
+                        .fg(if install_focused {
+                            th.text
+                        } else {
+                            th.subtext0
+                        })
+                        .add_modifier(Modifier::BOLD);
+                    let marks = if install_focused {
+                        crate::ui::helpers::pane_find_highlights(app, &p.name)
+                    } else {
+                        Vec::new()
+                    };
+                    segs.extend(crate::ui::helpers::highlight_spans(
+                        &p.name,
+                        &marks,
+                        name_style,
+                        name_style
+                            .fg(th.sapphire)
+                            .add_modifier(Modifier::UNDERLINED),
                     ));
                     segs.push(Span::styled(
                         format!("  {}", p.version),
@@ -35,7 +35,7 @@ pub fn render_alert(f: &mut Frame, app: &mut AppState, area: Rect, message: &str
     let h = if is_help {
         area.height.saturating_sub(6).min(28)
     } else {
-        7
+        7u16.min(area.height)
     };
     let x = area.x + (area.width.saturating_sub(w)) / 2;
     let y = area.y + (area.height.saturating_sub(h)) / 2;
@@ -7,7 +7,7 @@ use ratatui::{
 };
 
 use crate::i18n;
-use crate::state::{AppState, PackageItem};
+use crate::state::{AppState, PackageItem, modal::TypedConfirm};
 use crate::theme::theme;
 
 /// What: Render the confirmation modal listing packages slated for installation.
@@ -17,6 +17,7 @@ use crate::theme::theme;
 /// - `app`: AppState for translations
 /// - `area`: Full screen area used to center the modal
 /// - `items`: Packages selected for installation
+/// - `guard`: Typed-count confirmation required above `confirm_threshold`
 ///
 /// Output:
 /// - Draws the install confirmation dialog and informs users about scan shortcuts.
@@ -24,7 +25,13 @@ use crate::theme::theme;
 /// Details:
 /// - Highlights the heading, truncates the list to fit the modal, and shows instructions for
 ///   confirming, cancelling, or initiating security scans.
-pub fn render_confirm_install(f: &mut Frame, app: &AppState, area: Rect, items: &[PackageItem]) {
+pub fn render_confirm_install(
+    f: &mut Frame,
+    app: &AppState,
+    area: Rect,
+    items: &[PackageItem],
+    guard: Option<&TypedConfirm>,
+) {
     let th = theme();
     let w = area.width.saturating_sub(6).min(90);
     let h = area.height.saturating_sub(6).min(20);
@@ -63,6 +70,7 @@ pub fn render_confirm_install(f: &mut Frame, app: &AppState, area: Rect, items:
         }
     }
     lines.push(Line::from(""));
+    lines.extend(guard_lines(app, guard));
     lines.push(Line::from(Span::styled(
         i18n::t(app, "app.modals.confirm_install.confirm_hint"),
         Style::default().fg(th.subtext1),
@@ -95,6 +103,7 @@ pub fn render_confirm_install(f: &mut Frame, app: &AppState, area: Rect, items:
 /// - `app`: AppState for translations
 /// - `area`: Full screen area used to center the modal
 /// - `items`: Packages scheduled for removal
+/// - `guard`: Typed-count confirmation required above `confirm_threshold`
 ///
 /// Output:
 /// - Draws the removal confirmation dialog, including warnings for core packages.
@@ -102,7 +111,13 @@ pub fn render_confirm_install(f: &mut Frame, app: &AppState, area: Rect, items:
 /// Details:
 /// - Emphasizes critical warnings when core packages are present, truncates long lists, and
 ///   instructs on confirm/cancel actions while matching the theme.
-pub fn render_confirm_remove(f: &mut Frame, app: &AppState, area: Rect, items: &[PackageItem]) {
+pub fn render_confirm_remove(
+    f: &mut Frame,
+    app: &AppState,
+    area: Rect,
+    items: &[PackageItem],
+    guard: Option<&TypedConfirm>,
+) {
     let th = theme();
     let w = area.width.saturating_sub(6).min(90);
     let h = area.height.saturating_sub(6).min(20);
@@ -153,6 +168,7 @@ pub fn render_confirm_remove(f: &mut Frame, app: &AppState, area: Rect, items: &
         }
     }
     lines.push(Line::from(""));
+    lines.extend(guard_lines(app, guard));
     lines.push(Line::from(Span::styled(
         i18n::t(app, "app.modals.confirm_remove.confirm_hint"),
         Style::default().fg(th.subtext1),
@@ -173,3 +189,37 @@ pub fn render_confirm_remove(f: &mut Frame, app: &AppState, area: Rect, items: &
         );
     f.render_widget(boxw, rect);
 }
+
+/// What: Prompt lines for the typed-count confirmation of large transactions.
+///
+/// Inputs:
+/// - `app`: AppState for translations
+/// - `guard`: Active guard, if any
+///
+/// Output:
+/// - Empty without a guard; otherwise the prompt with the digits typed so far, green once
+///   they match and followed by a blank line.
+fn guard_lines(app: &AppState, guard: Option<&TypedConfirm>) -> Vec<Line<'static>> {
+    let Some(guard) = guard else {
+        return Vec::new();
+    };
+    let th = theme();
+    let color = if guard.satisfied() {
+        th.green
+    } else {
+        th.yellow
+    };
+    vec![
+        Line::from(vec![
+            Span::styled(
+                i18n::t_fmt1(app, "app.modals.confirm_threshold.prompt", guard.count),
+                Style::default().fg(th.red).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("{}_", guard.typed),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(""),
+    ]
+}
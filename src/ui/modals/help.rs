@@ -53,8 +53,8 @@ pub fn render_help(f: &mut Frame, app: &mut AppState, area: Rect) {
     )));
     lines.push(Line::from(""));
 
-    // Utility to format a binding line
-    let fmt = |label: &str, chord: KeyChord| -> Line<'static> {
+    // Utility to format a binding line; sequences show their chords separated by spaces
+    let fmt_keys = |label: &str, keys: String| -> Line<'static> {
         Line::from(vec![
             Span::styled(
                 format!("{label:18}"),
@@ -64,11 +64,12 @@ pub fn render_help(f: &mut Frame, app: &mut AppState, area: Rect) {
             ),
             Span::raw("  "),
             Span::styled(
-                format!("[{}]", chord.label()),
+                format!("[{keys}]"),
                 Style::default().fg(th.text).add_modifier(Modifier::BOLD),
             ),
         ])
     };
+    let fmt = |label: &str, chord: KeyChord| -> Line<'static> { fmt_keys(label, chord.label()) };
 
     if let Some(k) = km.help_overlay.first().copied() {
         lines.push(fmt(
@@ -85,6 +86,72 @@ pub fn render_help(f: &mut Frame, app: &mut AppState, area: Rect) {
             k,
         ));
     }
+    if let Some(k) = km.reload_settings.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.reload_settings"),
+            k,
+        ));
+    }
+    if let Some(k) = km.cycle_theme.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.cycle_theme"),
+            k,
+        ));
+    }
+    if let Some(k) = km.command_palette.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.command_palette"),
+            k,
+        ));
+    }
+    if let Some(k) = km.open_logs.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.open_logs"),
+            k,
+        ));
+    }
+    if let Some(k) = km.details_scroll_left.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.details_scroll_left"),
+            k,
+        ));
+    }
+    if let Some(k) = km.details_scroll_right.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.details_scroll_right"),
+            k,
+        ));
+    }
+    if let Some(k) = km.toggle_wrap.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.toggle_wrap"),
+            k,
+        ));
+    }
+    if let Some(k) = km.refresh_installed.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.refresh_installed"),
+            k,
+        ));
+    }
+    if let Some(k) = km.preflight_tab_deps.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.preflight_tab_deps"),
+            k,
+        ));
+    }
+    if let Some(k) = km.preflight_tab_files.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.preflight_tab_files"),
+            k,
+        ));
+    }
+    if let Some(k) = km.preflight_tab_services.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.preflight_tab_services"),
+            k,
+        ));
+    }
     // Move menu toggles into Normal Mode section; omit here
     if let Some(k) = km.pane_next.first().copied() {
         lines.push(fmt(
@@ -110,6 +177,12 @@ pub fn render_help(f: &mut Frame, app: &mut AppState, area: Rect) {
             k,
         ));
     }
+    if let Some(k) = km.pkgbuild_next_mark.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.pkgbuild_next_mark"),
+            k,
+        ));
+    }
     // Show configured key for change sorting
     if let Some(k) = km.change_sort.first().copied() {
         lines.push(fmt(
@@ -117,6 +190,103 @@ pub fn render_help(f: &mut Frame, app: &mut AppState, area: Rect) {
             k,
         ));
     }
+    if let Some(k) = km.toggle_download_server.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.toggle_download_server"),
+            k,
+        ));
+    }
+    if let Some(k) = km.copy_view_settings.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.copy_view_settings"),
+            k,
+        ));
+    }
+    if let Some(k) = km.reopen_modal.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.reopen_modal"),
+            k,
+        ));
+    }
+    if let Some(k) = km.toggle_status_verbose.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.toggle_status_verbose"),
+            k,
+        ));
+    }
+    if let Some(k) = km.toggle_status_spans.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.toggle_status_spans"),
+            k,
+        ));
+    }
+    if let Some(k) = km.compare_slot_a.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.compare_slot_a"),
+            k,
+        ));
+    }
+    if let Some(k) = km.compare_slot_b.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.compare_slot_b"),
+            k,
+        ));
+    }
+    if let Some(k) = km.open_homepage.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.open_homepage"),
+            k,
+        ));
+    }
+    if let Some(k) = km.open_package_page.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.open_package_page"),
+            k,
+        ));
+    }
+    if let Some(k) = km.preview_commands.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.preview_commands"),
+            k,
+        ));
+    }
+    if let Some(k) = km.why_installed.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.why_installed"),
+            k,
+        ));
+    }
+    if let Some(k) = km.show_files.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.show_files"),
+            k,
+        ));
+    }
+    if let Some(k) = km.clear_all_lists.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.clear_all_lists"),
+            k,
+        ));
+    }
+    if let Some(k) = km.undo.first().copied() {
+        lines.push(fmt(&i18n::t(app, "app.modals.help.key_labels.undo"), k));
+    }
+    if let Some(k) = km.redo.first().copied() {
+        lines.push(fmt(&i18n::t(app, "app.modals.help.key_labels.redo"), k));
+    }
+    for (key, seqs) in [
+        ("app.modals.help.key_labels.goto_top", &km.goto_top),
+        ("app.modals.help.key_labels.goto_bottom", &km.goto_bottom),
+    ] {
+        if let Some(seq) = seqs.first() {
+            let keys = seq
+                .iter()
+                .map(KeyChord::label)
+                .collect::<Vec<_>>()
+                .join(" ");
+            lines.push(fmt_keys(&i18n::t(app, key), keys));
+        }
+    }
     lines.push(Line::from(""));
 
     // Dynamic section for per-pane actions based on keymap
@@ -164,6 +334,18 @@ pub fn render_help(f: &mut Frame, app: &mut AppState, area: Rect) {
     if let Some(k) = km.search_install.first().copied() {
         lines.push(fmt(&i18n::t(app, "app.modals.help.key_labels.install"), k));
     }
+    if let Some(k) = km.search_multi_select.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.multi_select"),
+            k,
+        ));
+    }
+    if let Some(k) = km.search_add_selected.first().copied() {
+        lines.push(fmt(
+            &i18n::t(app, "app.modals.help.key_labels.add_selected"),
+            k,
+        ));
+    }
     if let Some(k) = km.search_backspace.first().copied() {
         lines.push(fmt(&i18n::t(app, "app.modals.help.key_labels.delete"), k));
     }
@@ -250,6 +432,12 @@ pub fn render_help(f: &mut Frame, app: &mut AppState, area: Rect) {
     if let Some(k) = km.install_clear.first().copied() {
         lines.push(fmt("  Clear", k));
     }
+    if let Some(k) = km.install_remove_group.first().copied() {
+        lines.push(fmt("  Remove group", k));
+    }
+    if let Some(k) = km.install_pick_version.first().copied() {
+        lines.push(fmt("  Pick cached version (Downgrade)", k));
+    }
     if let Some(k) = km.install_find.first().copied() {
         lines.push(fmt("  Find", k));
     }
@@ -384,6 +572,15 @@ pub fn render_help(f: &mut Frame, app: &mut AppState, area: Rect) {
         Style::default().fg(th.subtext1),
     )));
 
+    // Keep the offset within the wrapped text so fast wheel steps cannot scroll past the end
+    let inner_w = usize::from(rect.width.saturating_sub(2).max(1));
+    let wrapped: usize = lines
+        .iter()
+        .map(|l| l.width().max(1).div_ceil(inner_w))
+        .sum();
+    app.help_scroll = app
+        .help_scroll
+        .min(u16::try_from(wrapped.saturating_sub(1)).unwrap_or(u16::MAX));
     let boxw = Paragraph::new(lines)
         .style(Style::default().fg(th.text).bg(th.mantle))
         .wrap(Wrap { trim: true })
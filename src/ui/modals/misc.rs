@@ -93,6 +93,83 @@ pub fn render_optional_deps(
     );
 }
 
+/// What: Render the downgrade version picker for one package.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area used to center the modal
+/// - `package`/`installed`: Package name and its installed version
+/// - `versions`: Cached archives, newest first
+/// - `selected`: Index of the highlighted version
+///
+/// Output:
+/// - Draws the version list with the installed version marked, or a hint when the cache is empty.
+pub fn render_downgrade_versions(
+    f: &mut Frame,
+    area: Rect,
+    package: &str,
+    installed: &str,
+    versions: &[crate::logic::downgrade::CachedPackage],
+    selected: usize,
+    app: &crate::state::AppState,
+) {
+    let th = theme();
+    let mut lines: Vec<Line<'static>> = Vec::new();
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t_fmt(
+            app,
+            "app.modals.downgrade_versions.heading",
+            &[&package, &installed],
+        ),
+        Style::default().fg(th.mauve).add_modifier(Modifier::BOLD),
+    )));
+    lines.push(Line::from(""));
+
+    if versions.is_empty() {
+        for key in [
+            "app.modals.downgrade_versions.none",
+            "app.modals.downgrade_versions.none_hint",
+        ] {
+            lines.push(Line::from(Span::styled(
+                crate::i18n::t_fmt1(app, key, crate::logic::downgrade::PACMAN_CACHE_DIR),
+                Style::default().fg(th.yellow),
+            )));
+        }
+    }
+    for (i, v) in versions.iter().enumerate() {
+        let style = if selected == i {
+            Style::default()
+                .fg(th.crust)
+                .bg(th.lavender)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(th.text)
+        };
+        let mut segs = vec![Span::styled(v.version.clone(), style)];
+        if v.version == installed {
+            segs.push(Span::raw("  "));
+            segs.push(Span::styled(
+                crate::i18n::t(app, "app.modals.downgrade_versions.installed_marker"),
+                Style::default().fg(th.green),
+            ));
+        }
+        lines.push(Line::from(segs));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t(app, "app.modals.downgrade_versions.footer_hint"),
+        Style::default().fg(th.subtext1),
+    )));
+
+    render_simple_list_modal(
+        f,
+        area,
+        &crate::i18n::t(app, "app.modals.downgrade_versions.title"),
+        lines,
+    );
+}
+
 #[allow(clippy::too_many_arguments)]
 /// What: Render the scan configuration modal listing security tools to toggle.
 ///
@@ -153,7 +230,7 @@ pub fn render_scan_config(
 
     lines.push(Line::from(Span::raw("")));
     lines.push(Line::from(Span::styled(
-        "Up/Down: select  •  Space: toggle  •  Enter: run  •  Esc: cancel",
+        "Up/Down: select  •  Space: toggle  •  Enter: run  •  f: force rescan (ignore cache)  •  Esc: cancel",
         Style::default().fg(th.overlay1),
     )));
 
@@ -175,7 +252,7 @@ pub fn render_gnome_terminal_prompt(f: &mut Frame, area: Rect) {
     let th = theme();
     // Centered confirmation dialog for installing GNOME Terminal
     let w = area.width.saturating_sub(10).min(90);
-    let h = 9;
+    let h = 9u16.min(area.height);
     let x = area.x + (area.width.saturating_sub(w)) / 2;
     let y = area.y + (area.height.saturating_sub(h)) / 2;
     let rect = ratatui::prelude::Rect {
@@ -242,7 +319,7 @@ pub fn render_virustotal_setup(f: &mut Frame, app: &mut AppState, area: Rect, in
     let th = theme();
     // Centered dialog for VirusTotal API key setup with clickable URL and input field
     let w = area.width.saturating_sub(10).min(90);
-    let h = 11;
+    let h = 11u16.min(area.height);
     let x = area.x + (area.width.saturating_sub(w)) / 2;
     let y = area.y + (area.height.saturating_sub(h)) / 2;
     let rect = ratatui::prelude::Rect {
@@ -330,12 +407,12 @@ pub fn render_virustotal_setup(f: &mut Frame, app: &mut AppState, area: Rect, in
 /// - Draws instructions for import file syntax and highlights confirm/cancel keys.
 ///
 /// Details:
-/// - Enumerates formatting rules, provides an example snippet, and keeps styling aligned with other
+/// - Enumerates plain-text and JSON formatting rules, provides an example snippet, and keeps styling aligned with other
 ///   informational modals.
 pub fn render_import_help(f: &mut Frame, area: Rect) {
     let th = theme();
     let w = area.width.saturating_sub(10).min(85);
-    let h = 19;
+    let h = 22u16.min(area.height);
     let x = area.x + (area.width.saturating_sub(w)) / 2;
     let y = area.y + (area.height.saturating_sub(h)) / 2;
     let rect = ratatui::prelude::Rect {
@@ -366,7 +443,13 @@ pub fn render_import_help(f: &mut Frame, area: Rect) {
         Line::from(Span::raw("  • One package name per line")),
         Line::from(Span::raw("  • Blank lines are ignored")),
         Line::from(Span::raw(
-            "  • Lines starting with '#' are treated as comments",
+            "  • '#' starts a comment; extra columns (e.g. `pacman -Q` output) are ignored",
+        )),
+        Line::from(Span::raw(
+            "  • .json files: an array of names or of objects with a \"name\" field",
+        )),
+        Line::from(Span::raw(
+            "  • Names not found in the repositories or the AUR are skipped",
         )),
         Line::from(""),
         Line::from(Span::styled(
@@ -411,3 +494,1290 @@ pub fn render_import_help(f: &mut Frame, area: Rect) {
         );
     f.render_widget(boxw, rect);
 }
+
+/// What: Pad or truncate `text` to exactly `width` display columns (by `char`).
+fn fit_column(text: &str, width: usize) -> String {
+    let count = text.chars().count();
+    if count <= width {
+        format!("{text}{}", " ".repeat(width - count))
+    } else if width == 0 {
+        String::new()
+    } else {
+        let mut out: String = text.chars().take(width - 1).collect();
+        out.push('…');
+        out
+    }
+}
+
+/// What: Render the side-by-side comparison of the packages in compare slots A and B.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area used to center the modal
+/// - `left`/`right`: Package names in slots A and B
+/// - `rows`: Compared fields from `logic::compare::compare_details`
+/// - `app`: Application state for translations
+///
+/// Output:
+/// - Draws one line per field with both values in fixed-width columns; differing rows are
+///   highlighted.
+///
+/// Details:
+/// - List values are comma-joined and truncated to the column width; empty lists show `-`.
+pub fn render_package_compare(
+    f: &mut Frame,
+    area: Rect,
+    left: &str,
+    right: &str,
+    rows: &[crate::logic::compare::CompareRow],
+    app: &crate::state::AppState,
+) {
+    use crate::logic::compare::{CompareField, CompareValue};
+    let th = theme();
+    // Mirror the width chosen by `render_simple_list_modal`, minus borders.
+    let inner = usize::from(area.width.saturating_sub(8).min(80)).saturating_sub(2);
+    let label_w = 16;
+    let col_w = inner.saturating_sub(label_w + 2) / 2;
+    let format_value = |v: &CompareValue| match v {
+        CompareValue::Text(s) if s.is_empty() => "-".to_string(),
+        CompareValue::Text(s) => s.clone(),
+        CompareValue::Size(Some(n)) => crate::ui::helpers::human_bytes(*n),
+        CompareValue::Size(None) => "-".to_string(),
+        CompareValue::List(l) if l.is_empty() => "-".to_string(),
+        CompareValue::List(l) => l.join(", "),
+    };
+
+    let mut lines: Vec<Line<'static>> = Vec::new();
+    let head = Style::default().fg(th.mauve).add_modifier(Modifier::BOLD);
+    lines.push(Line::from(vec![
+        Span::raw(" ".repeat(label_w)),
+        Span::styled(fit_column(&format!("A: {left}"), col_w), head),
+        Span::raw("  "),
+        Span::styled(fit_column(&format!("B: {right}"), col_w), head),
+    ]));
+    lines.push(Line::from(""));
+    for row in rows {
+        let key = match row.field {
+            CompareField::Version => "version",
+            CompareField::Repository => "repository",
+            CompareField::Licenses => "licenses",
+            CompareField::DownloadSize => "download_size",
+            CompareField::InstallSize => "install_size",
+            CompareField::Depends => "depends",
+            CompareField::SharedDepends => "shared_depends",
+            CompareField::OptDepends => "opt_depends",
+            CompareField::Provides => "provides",
+            CompareField::Conflicts => "conflicts",
+        };
+        let label = crate::i18n::t(app, &format!("app.modals.package_compare.fields.{key}"));
+        let value_style = if row.differs {
+            Style::default().fg(th.yellow)
+        } else {
+            Style::default().fg(th.text)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                fit_column(&label, label_w),
+                Style::default().fg(th.sapphire),
+            ),
+            Span::styled(fit_column(&format_value(&row.left), col_w), value_style),
+            Span::raw("  "),
+            Span::styled(fit_column(&format_value(&row.right), col_w), value_style),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t(app, "app.modals.package_compare.footer_hint"),
+        Style::default().fg(th.subtext1),
+    )));
+
+    render_simple_list_modal(
+        f,
+        area,
+        &crate::i18n::t(app, "app.modals.package_compare.title"),
+        lines,
+    );
+}
+
+/// What: Render the command preview for the focused install/remove/downgrade list.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area used to center the modal
+/// - `lines`: Command lines in execution order
+/// - `app`: Application state for translations and the dry-run flag
+///
+/// Output:
+/// - Draws each command as a `$`-prefixed line followed by a hint that nothing is executed.
+pub fn render_command_preview(
+    f: &mut Frame,
+    area: Rect,
+    lines: &[String],
+    app: &crate::state::AppState,
+) {
+    let th = theme();
+    let mut out: Vec<Line<'static>> = Vec::new();
+    for cmd in lines {
+        out.push(Line::from(vec![
+            Span::styled("$ ", Style::default().fg(th.overlay1)),
+            Span::styled(cmd.clone(), Style::default().fg(th.text)),
+        ]));
+    }
+    out.push(Line::from(""));
+    let mode_key = if app.dry_run {
+        "app.modals.command_preview.dry_run_on"
+    } else {
+        "app.modals.command_preview.dry_run_off"
+    };
+    out.push(Line::from(Span::styled(
+        crate::i18n::t(app, mode_key),
+        Style::default().fg(th.sapphire),
+    )));
+    out.push(Line::from(Span::styled(
+        crate::i18n::t(app, "app.modals.command_preview.footer_hint"),
+        Style::default().fg(th.subtext1),
+    )));
+
+    render_simple_list_modal(
+        f,
+        area,
+        &crate::i18n::t(app, "app.modals.command_preview.title"),
+        out,
+    );
+}
+
+/// What: Render the reverse dependency chains explaining why a package is installed.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area used to center the modal
+/// - `package`: Package being explained
+/// - `chains`: Chains from `logic::deps::reverse_why`
+/// - `app`: Application state for translations
+///
+/// Output:
+/// - Draws one `pkg ← dependent ← explicit` line per chain with the explicit root highlighted,
+///   or a note when the package is explicit itself or nothing explicit requires it.
+pub fn render_why_installed(
+    f: &mut Frame,
+    area: Rect,
+    package: &str,
+    chains: &[Vec<String>],
+    loading: bool,
+    app: &crate::state::AppState,
+) {
+    let th = theme();
+    let mut lines: Vec<Line<'static>> = Vec::new();
+    if loading {
+        lines.push(Line::from(Span::styled(
+            crate::i18n::t(app, "app.modals.why_installed.loading"),
+            Style::default().fg(th.subtext1),
+        )));
+        render_simple_list_modal(
+            f,
+            area,
+            &crate::i18n::t_fmt1(app, "app.modals.why_installed.title", package),
+            lines,
+        );
+        return;
+    }
+    if chains.is_empty() {
+        lines.push(Line::from(Span::styled(
+            crate::i18n::t_fmt1(app, "app.modals.why_installed.orphan", package),
+            Style::default().fg(th.yellow),
+        )));
+    }
+    for chain in chains {
+        if chain.len() == 1 {
+            lines.push(Line::from(Span::styled(
+                crate::i18n::t_fmt1(app, "app.modals.why_installed.explicit", package),
+                Style::default().fg(th.green),
+            )));
+            continue;
+        }
+        let mut spans: Vec<Span<'static>> = Vec::new();
+        for (i, pkg) in chain.iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::styled(" ← ", Style::default().fg(th.overlay1)));
+            }
+            let style = if i + 1 == chain.len() {
+                Style::default().fg(th.green).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(th.text)
+            };
+            spans.push(Span::styled(pkg.clone(), style));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t(app, "app.modals.why_installed.footer_hint"),
+        Style::default().fg(th.subtext1),
+    )));
+
+    render_simple_list_modal(
+        f,
+        area,
+        &crate::i18n::t_fmt1(app, "app.modals.why_installed.title", package),
+        lines,
+    );
+}
+
+/// What: Render the confirmation shown before a pacman group is expanded into its members.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area used to center the modal
+/// - `group`: Group name that was added
+/// - `members`: Member package names
+/// - `is_package`: Whether the name is also a package (offers a choice instead of yes/no)
+/// - `app`: Application state for translations
+///
+/// Output:
+/// - Draws the member list and the key hint.
+pub fn render_group_expand(
+    f: &mut Frame,
+    area: Rect,
+    group: &str,
+    members: &[String],
+    is_package: bool,
+    app: &crate::state::AppState,
+) {
+    let th = theme();
+    let mut lines: Vec<Line<'static>> = Vec::new();
+    if is_package {
+        lines.push(Line::from(Span::styled(
+            crate::i18n::t_fmt1(app, "app.modals.group_expand.also_package", group),
+            Style::default().fg(th.yellow),
+        )));
+        lines.push(Line::from(""));
+    }
+    for name in members {
+        lines.push(Line::from(Span::styled(
+            format!("  {name}"),
+            Style::default().fg(th.text),
+        )));
+    }
+    lines.push(Line::from(""));
+    let hint = if is_package {
+        "app.modals.group_expand.footer_hint_choice"
+    } else {
+        "app.modals.group_expand.footer_hint"
+    };
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t(app, hint),
+        Style::default().fg(th.subtext1),
+    )));
+
+    render_simple_list_modal(
+        f,
+        area,
+        &crate::i18n::t_fmt(
+            app,
+            "app.modals.group_expand.title",
+            &[&group, &members.len()],
+        ),
+        lines,
+    );
+}
+
+/// What: Render the confirmation shown before the install, remove, and downgrade lists are cleared.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area used to center the modal
+/// - `counts`: Entries in the install, remove, and downgrade lists
+/// - `app`: Application state for translations
+///
+/// Output:
+/// - Draws one line per list with its entry count and the key hint.
+pub fn render_confirm_clear_lists(
+    f: &mut Frame,
+    area: Rect,
+    counts: [usize; 3],
+    app: &crate::state::AppState,
+) {
+    let th = theme();
+    let mut lines: Vec<Line<'static>> = Vec::new();
+    for (key, count) in [
+        "app.modals.confirm_clear_lists.install",
+        "app.modals.confirm_clear_lists.remove",
+        "app.modals.confirm_clear_lists.downgrade",
+    ]
+    .into_iter()
+    .zip(counts)
+    {
+        lines.push(Line::from(Span::styled(
+            crate::i18n::t_fmt1(app, key, count),
+            Style::default().fg(if count > 0 { th.text } else { th.overlay1 }),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t(app, "app.modals.confirm_clear_lists.footer_hint"),
+        Style::default().fg(th.subtext1),
+    )));
+
+    render_simple_list_modal(
+        f,
+        area,
+        &crate::i18n::t(app, "app.modals.confirm_clear_lists.title"),
+        lines,
+    );
+}
+
+/// What: Render the prompt for the name to save the install list under.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area used to center the modal
+/// - `input`: Name typed so far
+/// - `app`: Application state for translations and the install-list size
+///
+/// Output:
+/// - Draws the input line, the sanitized file name, and the key hint.
+pub fn render_profile_save(f: &mut Frame, area: Rect, input: &str, app: &crate::state::AppState) {
+    let th = theme();
+    let file = crate::logic::profiles::sanitize_profile_name(input)
+        .map(|n| format!("profiles/{n}.json"))
+        .unwrap_or_else(|| "—".to_string());
+    let lines = vec![
+        Line::from(Span::styled(
+            crate::i18n::t_fmt1(
+                app,
+                "app.modals.profiles.save_count",
+                app.install_list.len(),
+            ),
+            Style::default().fg(th.text),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("> ", Style::default().fg(th.sapphire)),
+            Span::styled(
+                format!("{input}_"),
+                Style::default().fg(th.text).add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(Span::styled(
+            crate::i18n::t_fmt1(app, "app.modals.profiles.save_file", file),
+            Style::default().fg(th.overlay1),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            crate::i18n::t(app, "app.modals.profiles.save_hint"),
+            Style::default().fg(th.subtext1),
+        )),
+    ];
+    render_simple_list_modal(
+        f,
+        area,
+        &crate::i18n::t(app, "app.modals.profiles.save_title"),
+        lines,
+    );
+}
+
+/// What: Render the command palette.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area used to center the modal
+/// - `query`: Filter text
+/// - `selected`: Highlighted row among the matches
+/// - `app`: Application state for labels, keybinds, and availability
+///
+/// Output:
+/// - Draws the query line and a window of matching actions with their keybinds; actions that
+///   do not apply right now are greyed out.
+pub fn render_command_palette(
+    f: &mut Frame,
+    area: Rect,
+    query: &str,
+    selected: usize,
+    app: &crate::state::AppState,
+) {
+    /// Rows of actions shown at once (the modal is at most 20 rows tall).
+    const VISIBLE: usize = 12;
+    let th = theme();
+    let matches = crate::events::palette_matches(app, query);
+    let mut lines: Vec<Line<'static>> = vec![
+        Line::from(vec![
+            Span::styled("> ", Style::default().fg(th.mauve)),
+            Span::styled(format!("{query}▏"), Style::default().fg(th.text)),
+        ]),
+        Line::from(""),
+    ];
+    if matches.is_empty() {
+        lines.push(Line::from(Span::styled(
+            crate::i18n::t(app, "app.modals.command_palette.no_matches"),
+            Style::default().fg(th.subtext0),
+        )));
+    }
+    let selected = selected.min(matches.len().saturating_sub(1));
+    let start = selected.saturating_sub(VISIBLE - 1);
+    for (i, action) in matches.iter().enumerate().skip(start).take(VISIBLE) {
+        let keys = action
+            .chords(&app.keymap)
+            .iter()
+            .map(|c| c.label())
+            .collect::<Vec<_>>()
+            .join(" / ");
+        let available = action.is_available(app);
+        let marker = if i == selected { "▶ " } else { "  " };
+        let mut style = Style::default().fg(if available { th.text } else { th.overlay1 });
+        if i == selected {
+            style = style.bg(th.surface2).add_modifier(Modifier::BOLD);
+        }
+        let mut spans = vec![Span::styled(
+            format!("{marker}{}", action.label(app)),
+            style,
+        )];
+        if !keys.is_empty() {
+            spans.push(Span::styled(
+                format!("  [{keys}]"),
+                Style::default().fg(th.sapphire),
+            ));
+        }
+        if !available {
+            spans.push(Span::styled(
+                format!(
+                    "  {}",
+                    crate::i18n::t(app, "app.modals.command_palette.unavailable")
+                ),
+                Style::default().fg(th.overlay1),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t(app, "app.modals.command_palette.hint"),
+        Style::default().fg(th.subtext1),
+    )));
+    render_simple_list_modal(
+        f,
+        area,
+        &crate::i18n::t(app, "app.modals.command_palette.title"),
+        lines,
+    );
+}
+
+/// What: Render the keybinds.conf diagnostics.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area used to center the modal
+/// - `issues`: `(line_number, reason)` pairs
+/// - `scroll`: Index of the first issue shown
+/// - `app`: Application state for translations
+///
+/// Output:
+/// - Draws one line per problem (or an all-clear message), the file path, and the key hint.
+pub fn render_keybind_diagnostics(
+    f: &mut Frame,
+    area: Rect,
+    issues: &[(usize, String)],
+    scroll: usize,
+    app: &crate::state::AppState,
+) {
+    let th = theme();
+    let mut lines: Vec<Line<'static>> = Vec::new();
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t_fmt1(
+            app,
+            "app.modals.keybind_diagnostics.file",
+            crate::theme::config_dir().join("keybinds.conf").display(),
+        ),
+        Style::default().fg(th.subtext1),
+    )));
+    lines.push(Line::from(""));
+    if issues.is_empty() {
+        lines.push(Line::from(Span::styled(
+            crate::i18n::t(app, "app.modals.keybind_diagnostics.no_issues"),
+            Style::default().fg(th.green),
+        )));
+    }
+    for (line_no, reason) in issues.iter().skip(scroll) {
+        lines.push(Line::from(vec![
+            Span::styled(
+                crate::i18n::t_fmt1(app, "app.modals.keybind_diagnostics.line", line_no),
+                Style::default().fg(th.yellow).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!(" {reason}"), Style::default().fg(th.text)),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t(app, "app.modals.keybind_diagnostics.hint"),
+        Style::default().fg(th.subtext1),
+    )));
+    render_simple_list_modal(
+        f,
+        area,
+        &crate::i18n::t_fmt1(app, "app.modals.keybind_diagnostics.title", issues.len()),
+        lines,
+    );
+}
+
+/// What: Render the list of leftover `.pacnew`/`.pacsave` files.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area used to center the modal
+/// - `entries`: Leftovers found by the scan
+/// - `selected`: Highlighted row
+/// - `loading`: The scan has not finished yet
+/// - `app`: Application state for translations
+///
+/// Output:
+/// - Draws the list with a privilege warning and key hints.
+///
+/// Details:
+/// - Shows a window of rows around the selection so long lists stay within the modal.
+/// - While `loading`, a scanning notice replaces the empty-list message.
+pub fn render_config_leftovers(
+    f: &mut Frame,
+    area: Rect,
+    entries: &[crate::logic::pacnew::ConfigLeftover],
+    selected: usize,
+    loading: bool,
+    app: &crate::state::AppState,
+) {
+    const VISIBLE: usize = 12;
+    let th = theme();
+    let mut lines: Vec<Line<'static>> = Vec::new();
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t_fmt1(
+            app,
+            "app.modals.config_leftovers.privilege_warning",
+            crate::install::privilege::privilege_tool().binary(),
+        ),
+        Style::default().fg(th.subtext1),
+    )));
+    lines.push(Line::from(""));
+    if loading {
+        lines.push(Line::from(Span::styled(
+            crate::i18n::t(app, "app.modals.config_leftovers.scanning"),
+            Style::default().fg(th.yellow),
+        )));
+    } else if entries.is_empty() {
+        lines.push(Line::from(Span::styled(
+            crate::i18n::t(app, "app.modals.config_leftovers.none"),
+            Style::default().fg(th.green),
+        )));
+    }
+    let start = selected.saturating_sub(VISIBLE - 1);
+    for (i, leftover) in entries.iter().enumerate().skip(start).take(VISIBLE) {
+        let (tag, color) = match leftover.kind {
+            crate::logic::pacnew::LeftoverKind::Pacnew => ("pacnew ", th.yellow),
+            crate::logic::pacnew::LeftoverKind::Pacsave => ("pacsave", th.sapphire),
+        };
+        let (marker, path_style) = if i == selected {
+            (
+                "▶ ",
+                Style::default()
+                    .fg(th.text)
+                    .bg(th.surface2)
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
+            ("  ", Style::default().fg(th.subtext0))
+        };
+        lines.push(Line::from(vec![
+            Span::styled(marker, path_style),
+            Span::styled(format!("[{tag}] "), Style::default().fg(color)),
+            Span::styled(leftover.path.display().to_string(), path_style),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t(app, "app.modals.config_leftovers.hint"),
+        Style::default().fg(th.subtext1),
+    )));
+    render_simple_list_modal(
+        f,
+        area,
+        &crate::i18n::t_fmt1(app, "app.modals.config_leftovers.title", entries.len()),
+        lines,
+    );
+}
+
+/// What: Render the diff of a config file against its `.pacnew`/`.pacsave`.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area used to center the modal
+/// - `leftover`: Leftover compared with its original
+/// - `lines`: Diff lines, or `None` when a file is binary or unreadable
+/// - `scroll`: First diff line shown
+/// - `app`: Application state for translations
+///
+/// Output:
+/// - Draws a unified diff with removed lines in red and added lines in green.
+///
+/// Details:
+/// - Uses a larger box than the list modals and does not wrap so indentation stays intact.
+pub fn render_config_diff(
+    f: &mut Frame,
+    area: Rect,
+    leftover: &crate::logic::pacnew::ConfigLeftover,
+    lines: Option<&[crate::logic::textdiff::DiffLine]>,
+    scroll: u16,
+    app: &crate::state::AppState,
+) {
+    use crate::logic::textdiff::DiffLine;
+    let th = theme();
+    let w = area.width.saturating_sub(4).min(120);
+    let h = area.height.saturating_sub(4);
+    let rect = Rect {
+        x: area.x + (area.width.saturating_sub(w)) / 2,
+        y: area.y + (area.height.saturating_sub(h)) / 2,
+        width: w,
+        height: h,
+    };
+    let mut body: Vec<Line<'static>> = vec![
+        Line::from(Span::styled(
+            format!("--- {}", leftover.original.display()),
+            Style::default().fg(th.red),
+        )),
+        Line::from(Span::styled(
+            format!("+++ {}", leftover.path.display()),
+            Style::default().fg(th.green),
+        )),
+    ];
+    match lines {
+        None => body.push(Line::from(Span::styled(
+            crate::i18n::t(app, "app.modals.config_diff.unreadable"),
+            Style::default().fg(th.yellow),
+        ))),
+        Some(lines) if !lines.iter().any(|l| !matches!(l, DiffLine::Same(_))) => {
+            body.push(Line::from(Span::styled(
+                crate::i18n::t(app, "app.modals.config_diff.identical"),
+                Style::default().fg(th.subtext1),
+            )));
+        }
+        Some(lines) => body.extend(lines.iter().skip(scroll as usize).map(|l| match l {
+            DiffLine::Same(t) => Line::from(Span::styled(
+                format!("  {t}"),
+                Style::default().fg(th.subtext0),
+            )),
+            DiffLine::Removed(t) => {
+                Line::from(Span::styled(format!("- {t}"), Style::default().fg(th.red)))
+            }
+            DiffLine::Added(t) => Line::from(Span::styled(
+                format!("+ {t}"),
+                Style::default().fg(th.green),
+            )),
+        })),
+    }
+    f.render_widget(Clear, rect);
+    let para = Paragraph::new(body)
+        .style(Style::default().fg(th.text).bg(th.mantle))
+        .block(
+            Block::default()
+                .title(Span::styled(
+                    format!(" {} ", crate::i18n::t(app, "app.modals.config_diff.title")),
+                    Style::default().fg(th.mauve).add_modifier(Modifier::BOLD),
+                ))
+                .title_bottom(Span::styled(
+                    format!(" {} ", crate::i18n::t(app, "app.modals.config_diff.hint")),
+                    Style::default().fg(th.subtext1),
+                ))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Double)
+                .border_style(Style::default().fg(th.mauve))
+                .style(Style::default().bg(th.mantle)),
+        );
+    f.render_widget(para, rect);
+}
+
+/// What: Render the file list of a package.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area used to center the modal
+/// - `package`: Package name for the title
+/// - `files`: Every path the package installs
+/// - `error`: Lookup error, shown instead of the list
+/// - `aur`: Whether the package comes from the AUR
+/// - `filter`: Substring filter typed by the user
+/// - `scroll`: First matching path shown
+/// - `loading`: Whether the list is still being read
+/// - `app`: Application state for translations
+///
+/// Output:
+/// - Draws the filter line, a window of matching paths, and the match count in the title.
+///
+/// Details:
+/// - An empty list shows the Preflight file database guidance (sync note, stale warning, AUR
+///   note) instead of a blank modal.
+#[allow(clippy::too_many_arguments)]
+pub fn render_package_files(
+    f: &mut Frame,
+    area: Rect,
+    package: &str,
+    files: &[String],
+    error: Option<&str>,
+    aur: bool,
+    filter: &str,
+    scroll: usize,
+    loading: bool,
+    app: &crate::state::AppState,
+) {
+    let th = theme();
+    let w = area.width.saturating_sub(4).min(120);
+    let h = area.height.saturating_sub(4);
+    let rect = Rect {
+        x: area.x + (area.width.saturating_sub(w)) / 2,
+        y: area.y + (area.height.saturating_sub(h)) / 2,
+        width: w,
+        height: h,
+    };
+    let shown = crate::logic::files::filter_file_list(files, filter);
+    let mut body: Vec<Line<'static>> = vec![Line::from(vec![
+        Span::styled("/ ", Style::default().fg(th.mauve)),
+        Span::styled(format!("{filter}▏"), Style::default().fg(th.text)),
+    ])];
+    let note = |key: &str, color| {
+        Line::from(Span::styled(
+            crate::i18n::t(app, key),
+            Style::default().fg(color),
+        ))
+    };
+    if loading {
+        body.push(note("app.modals.package_files.loading", th.subtext1));
+    } else if let Some(e) = error {
+        body.push(Line::from(Span::styled(
+            e.to_string(),
+            Style::default().fg(th.red),
+        )));
+    } else if files.is_empty() {
+        body.push(note("app.modals.package_files.empty", th.subtext1));
+        if aur {
+            body.push(note(
+                "app.modals.preflight.files.aur_file_note",
+                th.subtext0,
+            ));
+        } else {
+            body.push(note(
+                "app.modals.preflight.files.file_db_sync_note",
+                th.subtext0,
+            ));
+            if let Some(true) = crate::logic::files::is_file_db_stale(7) {
+                body.push(note("app.modals.preflight.files.file_db_stale", th.yellow));
+            }
+            body.push(note("app.modals.package_files.sync_hint", th.subtext0));
+        }
+    } else if shown.is_empty() {
+        body.push(note("app.modals.package_files.no_matches", th.subtext0));
+    }
+    let rows = h.saturating_sub(3) as usize;
+    for path in shown.iter().skip(scroll).take(rows) {
+        let color = if path.ends_with('/') {
+            th.overlay1
+        } else {
+            th.text
+        };
+        body.push(Line::from(Span::styled(
+            (*path).to_string(),
+            Style::default().fg(color),
+        )));
+    }
+    f.render_widget(Clear, rect);
+    let para = Paragraph::new(body)
+        .style(Style::default().fg(th.text).bg(th.mantle))
+        .block(
+            Block::default()
+                .title(Span::styled(
+                    format!(
+                        " {} ",
+                        crate::i18n::t_fmt(
+                            app,
+                            "app.modals.package_files.title",
+                            &[
+                                &package as &dyn std::fmt::Display,
+                                &shown.len(),
+                                &files.len()
+                            ],
+                        )
+                    ),
+                    Style::default().fg(th.mauve).add_modifier(Modifier::BOLD),
+                ))
+                .title_bottom(Span::styled(
+                    format!(" {} ", crate::i18n::t(app, "app.modals.package_files.hint")),
+                    Style::default().fg(th.subtext1),
+                ))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Double)
+                .border_style(Style::default().fg(th.mauve))
+                .style(Style::default().bg(th.mantle)),
+        );
+    f.render_widget(para, rect);
+}
+
+/// What: Render the logs viewer.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area used to center the modal
+/// - `path`: Log file shown, if any
+/// - `lines`: Tail of the log, oldest first
+/// - `min_level`: Least severe level shown; `None` shows all
+/// - `scroll`: Lines scrolled up from the end
+/// - `app`: Application state for translations
+///
+/// Output:
+/// - Draws the filtered lines ending `scroll` lines above the tail, colored by level.
+///
+/// Details:
+/// - Lines are not wrapped so each entry stays on one row; continuation lines take the color of
+///   the entry they belong to.
+pub fn render_log_viewer(
+    f: &mut Frame,
+    area: Rect,
+    path: Option<&std::path::Path>,
+    lines: &[String],
+    min_level: Option<tracing::Level>,
+    scroll: usize,
+    app: &crate::state::AppState,
+) {
+    let th = theme();
+    let w = area.width.saturating_sub(4).min(140);
+    let h = area.height.saturating_sub(4);
+    let rect = Rect {
+        x: area.x + (area.width.saturating_sub(w)) / 2,
+        y: area.y + (area.height.saturating_sub(h)) / 2,
+        width: w,
+        height: h,
+    };
+    let shown = crate::logic::logs::filter_by_level(lines, min_level);
+    let mut body: Vec<Line<'static>> = vec![Line::from(Span::styled(
+        match path {
+            Some(p) => p.display().to_string(),
+            None => crate::i18n::t_fmt1(
+                app,
+                "app.modals.log_viewer.no_log",
+                crate::theme::logs_dir().display(),
+            ),
+        },
+        Style::default().fg(th.subtext1),
+    ))];
+    if path.is_some() && shown.is_empty() {
+        body.push(Line::from(Span::styled(
+            crate::i18n::t(app, "app.modals.log_viewer.empty"),
+            Style::default().fg(th.subtext0),
+        )));
+    }
+    let rows = h.saturating_sub(3) as usize;
+    let end = shown.len().saturating_sub(scroll);
+    let start = end.saturating_sub(rows);
+    let mut color = th.text;
+    for line in &shown[..start] {
+        color = level_color(crate::logic::logs::line_level(line), color);
+    }
+    for line in &shown[start..end] {
+        color = level_color(crate::logic::logs::line_level(line), color);
+        body.push(Line::from(Span::styled(
+            (*line).to_string(),
+            Style::default().fg(color),
+        )));
+    }
+    let filter = match min_level {
+        Some(level) => crate::i18n::t_fmt1(app, "app.modals.log_viewer.filter_min", level),
+        None => crate::i18n::t(app, "app.modals.log_viewer.filter_all"),
+    };
+    f.render_widget(Clear, rect);
+    let para = Paragraph::new(body)
+        .style(Style::default().fg(th.text).bg(th.mantle))
+        .block(
+            Block::default()
+                .title(Span::styled(
+                    format!(
+                        " {} ",
+                        crate::i18n::t_fmt(
+                            app,
+                            "app.modals.log_viewer.title",
+                            &[&filter as &dyn std::fmt::Display, &shown.len()],
+                        )
+                    ),
+                    Style::default().fg(th.mauve).add_modifier(Modifier::BOLD),
+                ))
+                .title_bottom(Span::styled(
+                    format!(" {} ", crate::i18n::t(app, "app.modals.log_viewer.hint")),
+                    Style::default().fg(th.subtext1),
+                ))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Double)
+                .border_style(Style::default().fg(th.mauve))
+                .style(Style::default().bg(th.mantle)),
+        );
+    f.render_widget(para, rect);
+}
+
+/// What: Text color for a log line of the given level; `fallback` for continuation lines.
+fn level_color(
+    level: Option<tracing::Level>,
+    fallback: ratatui::style::Color,
+) -> ratatui::style::Color {
+    let th = theme();
+    match level {
+        Some(tracing::Level::ERROR) => th.red,
+        Some(tracing::Level::WARN) => th.yellow,
+        Some(tracing::Level::INFO) => th.text,
+        Some(_) => th.overlay1,
+        None => fallback,
+    }
+}
+
+/// What: Render the session error history.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area used to center the modal
+/// - `scroll`: Newest entries skipped
+/// - `app`: Application state holding `error_log`
+///
+/// Output:
+/// - Draws one entry per error, newest first, with time, source, repeat count, and message.
+pub fn render_error_log(f: &mut Frame, area: Rect, scroll: usize, app: &crate::state::AppState) {
+    let th = theme();
+    let mut lines: Vec<Line<'static>> = Vec::new();
+    if app.error_log.is_empty() {
+        lines.push(Line::from(Span::styled(
+            crate::i18n::t(app, "app.modals.error_log.none"),
+            Style::default().fg(th.green),
+        )));
+    }
+    for entry in app.error_log.iter().rev().skip(scroll) {
+        let mut head = vec![
+            Span::styled(
+                crate::util::ts_to_date(Some(entry.at)),
+                Style::default().fg(th.overlay1),
+            ),
+            Span::styled(
+                format!(" [{}]", entry.source),
+                Style::default().fg(th.yellow).add_modifier(Modifier::BOLD),
+            ),
+        ];
+        if entry.count > 1 {
+            head.push(Span::styled(
+                format!(" ×{}", entry.count),
+                Style::default().fg(th.lavender),
+            ));
+        }
+        lines.push(Line::from(head));
+        lines.push(Line::from(Span::styled(
+            format!("  {}", entry.message),
+            Style::default().fg(th.red),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t(app, "app.modals.error_log.hint"),
+        Style::default().fg(th.subtext1),
+    )));
+    render_simple_list_modal(
+        f,
+        area,
+        &crate::i18n::t_fmt1(app, "app.modals.error_log.title", app.error_log.len()),
+        lines,
+    );
+}
+
+/// What: Render the persisted activity log, newest entry first.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area used to center the modal
+/// - `entries`: Logged operations, oldest first
+/// - `scroll`: Number of newest entries skipped
+/// - `app`: Application state for translations
+///
+/// Output:
+/// - Draws one line per operation with its date, kind, and packages, plus the key hint.
+pub fn render_activity_log(
+    f: &mut Frame,
+    area: Rect,
+    entries: &[crate::logic::activity::ActivityEntry],
+    scroll: usize,
+    app: &crate::state::AppState,
+) {
+    use crate::logic::activity::ActivityKind;
+    let th = theme();
+    let mut lines: Vec<Line<'static>> = Vec::new();
+    if entries.is_empty() {
+        lines.push(Line::from(Span::styled(
+            crate::i18n::t(app, "app.modals.activity_log.none"),
+            Style::default().fg(th.subtext1),
+        )));
+    }
+    for entry in entries.iter().rev().skip(scroll) {
+        let (key, color) = match entry.kind {
+            ActivityKind::Install => ("app.modals.activity_log.install", th.green),
+            ActivityKind::Remove => ("app.modals.activity_log.remove", th.red),
+            ActivityKind::Downgrade => ("app.modals.activity_log.downgrade", th.yellow),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                crate::util::ts_to_date(Some(entry.at)),
+                Style::default().fg(th.overlay1),
+            ),
+            Span::styled(
+                format!(" {} ", crate::i18n::t(app, key)),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(entry.packages.join(" "), Style::default().fg(th.text)),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t(app, "app.modals.activity_log.hint"),
+        Style::default().fg(th.subtext1),
+    )));
+    render_simple_list_modal(
+        f,
+        area,
+        &crate::i18n::t_fmt1(app, "app.modals.activity_log.title", entries.len()),
+        lines,
+    );
+}
+
+/// What: Render the theme picker.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area used to center the modal
+/// - `names`: Theme names (empty first entry for `theme.conf`)
+/// - `selected`: Highlighted row
+/// - `app`: Application state for translations
+///
+/// Output:
+/// - Draws the theme list with the active theme marked and the themes directory hint.
+pub fn render_theme_picker(
+    f: &mut Frame,
+    area: Rect,
+    names: &[String],
+    selected: usize,
+    app: &crate::state::AppState,
+) {
+    let th = theme();
+    let active = crate::theme::settings().active_theme;
+    let mut lines: Vec<Line<'static>> = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let label = crate::ui::helpers::theme_display_name(app, name);
+            let mark = if *name == active.trim() { " ✓" } else { "" };
+            if i == selected {
+                Line::from(Span::styled(
+                    format!("▶ {label}{mark}"),
+                    Style::default()
+                        .fg(th.text)
+                        .bg(th.surface2)
+                        .add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(Span::styled(
+                    format!("  {label}{mark}"),
+                    Style::default().fg(th.subtext0),
+                ))
+            }
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t_fmt1(
+            app,
+            "app.modals.theme_picker.hint",
+            crate::theme::themes_dir().display(),
+        ),
+        Style::default().fg(th.subtext1),
+    )));
+    render_simple_list_modal(
+        f,
+        area,
+        &crate::i18n::t(app, "app.modals.theme_picker.title"),
+        lines,
+    );
+}
+
+/// What: Render the picker for a saved profile to load.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area used to center the modal
+/// - `names`: Saved profile names
+/// - `selected`: Highlighted row
+/// - `app`: Application state for translations
+///
+/// Output:
+/// - Draws the profile list with the highlighted row and the replace/append hint.
+pub fn render_profile_load(
+    f: &mut Frame,
+    area: Rect,
+    names: &[String],
+    selected: usize,
+    app: &crate::state::AppState,
+) {
+    let th = theme();
+    let mut lines: Vec<Line<'static>> = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            if i == selected {
+                Line::from(Span::styled(
+                    format!("▶ {name}"),
+                    Style::default()
+                        .fg(th.text)
+                        .bg(th.surface2)
+                        .add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(Span::styled(
+                    format!("  {name}"),
+                    Style::default().fg(th.subtext0),
+                ))
+            }
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t(app, "app.modals.profiles.load_hint"),
+        Style::default().fg(th.subtext1),
+    )));
+    render_simple_list_modal(
+        f,
+        area,
+        &crate::i18n::t(app, "app.modals.profiles.load_title"),
+        lines,
+    );
+}
+
+/// What: Render the installed-state breakdown of a profile or imported list before it is loaded.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area used to center the modal
+/// - `name`: Profile name, or the imported file name
+/// - `append`: Whether loading appends instead of replacing
+/// - `import`: Whether the entries come from an imported list
+/// - `diff`: Entries partitioned against installed packages
+/// - `app`: Application state for translations
+///
+/// Output:
+/// - Draws one section per non-empty bucket (new, upgrade, already installed) and the key hint.
+pub fn render_profile_diff(
+    f: &mut Frame,
+    area: Rect,
+    name: &str,
+    append: bool,
+    import: bool,
+    diff: &crate::logic::lists::InstalledDiff,
+    app: &crate::state::AppState,
+) {
+    let th = theme();
+    let upgrades: Vec<String> = diff
+        .to_upgrade
+        .iter()
+        .map(|(p, current)| format!("{} {current} → {}", p.name, p.version))
+        .collect();
+    let sections = [
+        (
+            "app.modals.profiles.diff_new",
+            diff.to_install.iter().map(|p| p.name.clone()).collect(),
+            th.green,
+        ),
+        ("app.modals.profiles.diff_upgrade", upgrades, th.yellow),
+        (
+            "app.modals.profiles.diff_installed",
+            diff.already_installed
+                .iter()
+                .map(|p| p.name.clone())
+                .collect::<Vec<String>>(),
+            th.overlay1,
+        ),
+    ];
+    let mut lines: Vec<Line<'static>> = Vec::new();
+    for (key, names, color) in sections {
+        if names.is_empty() {
+            continue;
+        }
+        lines.push(Line::from(Span::styled(
+            crate::i18n::t_fmt1(app, key, names.len()),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )));
+        for n in names {
+            lines.push(Line::from(Span::styled(
+                format!("  {n}"),
+                Style::default().fg(th.text),
+            )));
+        }
+        lines.push(Line::from(""));
+    }
+    let hint = if append {
+        "app.modals.profiles.diff_hint_append"
+    } else {
+        "app.modals.profiles.diff_hint_replace"
+    };
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t(app, hint),
+        Style::default().fg(th.subtext1),
+    )));
+    render_simple_list_modal(
+        f,
+        area,
+        &crate::i18n::t_fmt1(
+            app,
+            if import {
+                "app.modals.profiles.diff_title_import"
+            } else {
+                "app.modals.profiles.diff_title"
+            },
+            name,
+        ),
+        lines,
+    );
+}
+
+/// What: Render the ranked mirrorlist preview shown before it replaces the system mirrorlist.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area used to center the modal
+/// - `servers`: Server templates in ranked order
+/// - `app`: Application state for translations
+///
+/// Output:
+/// - Draws the numbered server list and the key hint.
+pub fn render_mirror_rank_preview(
+    f: &mut Frame,
+    area: Rect,
+    servers: &[String],
+    app: &crate::state::AppState,
+) {
+    let th = theme();
+    let mut lines: Vec<Line<'static>> = servers
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            Line::from(vec![
+                Span::styled(format!("{:>3}. ", i + 1), Style::default().fg(th.overlay1)),
+                Span::styled(s.clone(), Style::default().fg(th.text)),
+            ])
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t_fmt1(
+            app,
+            "app.modals.mirror_rank.footer_hint",
+            crate::index::SYSTEM_MIRRORLIST,
+        ),
+        Style::default().fg(th.subtext1),
+    )));
+    render_simple_list_modal(
+        f,
+        area,
+        &crate::i18n::t_fmt1(app, "app.modals.mirror_rank.title", servers.len()),
+        lines,
+    );
+}
@@ -43,9 +43,9 @@ pub fn render_modals(f: &mut Frame, app: &mut AppState, area: Rect) {
             alert::render_alert(f, app, area, &message);
             app.modal = crate::state::Modal::Alert { message };
         }
-        crate::state::Modal::ConfirmInstall { items } => {
-            confirm::render_confirm_install(f, app, area, &items);
-            app.modal = crate::state::Modal::ConfirmInstall { items };
+        crate::state::Modal::ConfirmInstall { items, guard } => {
+            confirm::render_confirm_install(f, app, area, &items, guard.as_ref());
+            app.modal = crate::state::Modal::ConfirmInstall { items, guard };
         }
         crate::state::Modal::Preflight {
             items,
@@ -187,9 +187,9 @@ pub fn render_modals(f: &mut Frame, app: &mut AppState, area: Rect) {
                 snapshot_label,
             };
         }
-        crate::state::Modal::ConfirmRemove { items } => {
-            confirm::render_confirm_remove(f, app, area, &items);
-            app.modal = crate::state::Modal::ConfirmRemove { items };
+        crate::state::Modal::ConfirmRemove { items, guard } => {
+            confirm::render_confirm_remove(f, app, area, &items, guard.as_ref());
+            app.modal = crate::state::Modal::ConfirmRemove { items, guard };
         }
         crate::state::Modal::SystemUpdate {
             do_mirrors,
@@ -200,6 +200,7 @@ pub fn render_modals(f: &mut Frame, app: &mut AppState, area: Rect) {
             countries,
             mirror_count,
             cursor,
+            loading,
         } => {
             system_update::render_system_update(
                 f,
@@ -213,6 +214,7 @@ pub fn render_modals(f: &mut Frame, app: &mut AppState, area: Rect) {
                 &countries,
                 mirror_count,
                 cursor,
+                loading,
             );
             app.modal = crate::state::Modal::SystemUpdate {
                 do_mirrors,
@@ -223,6 +225,7 @@ pub fn render_modals(f: &mut Frame, app: &mut AppState, area: Rect) {
                 countries,
                 mirror_count,
                 cursor,
+                loading,
             };
         }
         crate::state::Modal::Help => {
@@ -282,6 +285,189 @@ pub fn render_modals(f: &mut Frame, app: &mut AppState, area: Rect) {
             misc::render_import_help(f, area);
             app.modal = crate::state::Modal::ImportHelp;
         }
+        crate::state::Modal::DowngradeVersions {
+            package,
+            installed,
+            versions,
+            selected,
+        } => {
+            misc::render_downgrade_versions(
+                f, area, &package, &installed, &versions, selected, app,
+            );
+            app.modal = crate::state::Modal::DowngradeVersions {
+                package,
+                installed,
+                versions,
+                selected,
+            };
+        }
+        crate::state::Modal::PackageCompare { left, right, rows } => {
+            misc::render_package_compare(f, area, &left, &right, &rows, app);
+            app.modal = crate::state::Modal::PackageCompare { left, right, rows };
+        }
+        crate::state::Modal::CommandPreview { lines } => {
+            misc::render_command_preview(f, area, &lines, app);
+            app.modal = crate::state::Modal::CommandPreview { lines };
+        }
+        crate::state::Modal::WhyInstalled {
+            package,
+            chains,
+            loading,
+        } => {
+            misc::render_why_installed(f, area, &package, &chains, loading, app);
+            app.modal = crate::state::Modal::WhyInstalled {
+                package,
+                chains,
+                loading,
+            };
+        }
+        crate::state::Modal::ProfileSave { input } => {
+            misc::render_profile_save(f, area, &input, app);
+            app.modal = crate::state::Modal::ProfileSave { input };
+        }
+        crate::state::Modal::ProfileLoad { names, selected } => {
+            misc::render_profile_load(f, area, &names, selected, app);
+            app.modal = crate::state::Modal::ProfileLoad { names, selected };
+        }
+        crate::state::Modal::ThemePicker { names, selected } => {
+            misc::render_theme_picker(f, area, &names, selected, app);
+            app.modal = crate::state::Modal::ThemePicker { names, selected };
+        }
+        crate::state::Modal::CommandPalette { query, selected } => {
+            misc::render_command_palette(f, area, &query, selected, app);
+            app.modal = crate::state::Modal::CommandPalette { query, selected };
+        }
+        crate::state::Modal::KeybindDiagnostics { issues, scroll } => {
+            misc::render_keybind_diagnostics(f, area, &issues, scroll, app);
+            app.modal = crate::state::Modal::KeybindDiagnostics { issues, scroll };
+        }
+        crate::state::Modal::ConfigLeftovers {
+            entries,
+            selected,
+            loading,
+        } => {
+            misc::render_config_leftovers(f, area, &entries, selected, loading, app);
+            app.modal = crate::state::Modal::ConfigLeftovers {
+                entries,
+                selected,
+                loading,
+            };
+        }
+        crate::state::Modal::ErrorLog { scroll } => {
+            misc::render_error_log(f, area, scroll, app);
+            app.modal = crate::state::Modal::ErrorLog { scroll };
+        }
+        crate::state::Modal::ActivityLog { entries, scroll } => {
+            misc::render_activity_log(f, area, &entries, scroll, app);
+            app.modal = crate::state::Modal::ActivityLog { entries, scroll };
+        }
+        crate::state::Modal::PackageFiles {
+            package,
+            files,
+            error,
+            aur,
+            filter,
+            scroll,
+            loading,
+        } => {
+            misc::render_package_files(
+                f,
+                area,
+                &package,
+                &files,
+                error.as_deref(),
+                aur,
+                &filter,
+                scroll,
+                loading,
+                app,
+            );
+            app.modal = crate::state::Modal::PackageFiles {
+                package,
+                files,
+                error,
+                aur,
+                filter,
+                scroll,
+                loading,
+            };
+        }
+        crate::state::Modal::LogViewer {
+            path,
+            lines,
+            min_level,
+            scroll,
+        } => {
+            misc::render_log_viewer(f, area, path.as_deref(), &lines, min_level, scroll, app);
+            app.modal = crate::state::Modal::LogViewer {
+                path,
+                lines,
+                min_level,
+                scroll,
+            };
+        }
+        crate::state::Modal::ConfigDiff {
+            leftover,
+            lines,
+            scroll,
+            entries,
+            selected,
+        } => {
+            misc::render_config_diff(f, area, &leftover, lines.as_deref(), scroll, app);
+            app.modal = crate::state::Modal::ConfigDiff {
+                leftover,
+                lines,
+                scroll,
+                entries,
+                selected,
+            };
+        }
+        crate::state::Modal::ProfileDiff {
+            name,
+            items,
+            append,
+            diff,
+            import,
+        } => {
+            misc::render_profile_diff(f, area, &name, append, import, &diff, app);
+            app.modal = crate::state::Modal::ProfileDiff {
+                name,
+                items,
+                append,
+                diff,
+                import,
+            };
+        }
+        crate::state::Modal::MirrorRankPreview { servers, staged } => {
+            misc::render_mirror_rank_preview(f, area, &servers, app);
+            app.modal = crate::state::Modal::MirrorRankPreview { servers, staged };
+        }
+        crate::state::Modal::ConfirmClearLists {
+            install,
+            remove,
+            downgrade,
+        } => {
+            misc::render_confirm_clear_lists(f, area, [install, remove, downgrade], app);
+            app.modal = crate::state::Modal::ConfirmClearLists {
+                install,
+                remove,
+                downgrade,
+            };
+        }
+        crate::state::Modal::GroupExpand {
+            item,
+            members,
+            is_package,
+            queued,
+        } => {
+            misc::render_group_expand(f, area, &item.name, &members, is_package, app);
+            app.modal = crate::state::Modal::GroupExpand {
+                item,
+                members,
+                is_package,
+                queued,
+            };
+        }
         crate::state::Modal::None => {
             app.modal = crate::state::Modal::None;
         }
@@ -320,7 +506,10 @@ mod tests {
         .unwrap();
 
         // ConfirmInstall
-        app.modal = crate::state::Modal::ConfirmInstall { items: vec![] };
+        app.modal = crate::state::Modal::ConfirmInstall {
+            items: vec![],
+            guard: crate::state::modal::TypedConfirm::for_count(12, 10),
+        };
         term.draw(|f| {
             let area = f.area();
             super::render_modals(f, &mut app, area)
@@ -339,6 +528,7 @@ mod tests {
                 },
                 popularity: None,
             }],
+            guard: None,
         };
         term.draw(|f| {
             let area = f.area();
@@ -44,6 +44,36 @@ fn format_signed_bytes(value: i64) -> String {
     }
 }
 
+/// What: Progress line shown under a tab's loading message while its resolver runs.
+///
+/// Inputs:
+/// - `app`: Application state (translations)
+/// - `done`/`total`: Packages resolved so far and in total
+///
+/// Output:
+/// - A bar followed by e.g. "12/40 packages resolved"; empty until the resolver reports a total.
+fn progress_line(app: &AppState, done: usize, total: usize) -> Line<'static> {
+    let th = theme();
+    if total == 0 {
+        return Line::from("");
+    }
+    Line::from(vec![
+        Span::styled(
+            crate::ui::helpers::progress_bar(done, total, 20),
+            Style::default().fg(th.sapphire),
+        ),
+        Span::raw(" "),
+        Span::styled(
+            i18n::t_fmt(
+                app,
+                "app.modals.preflight.progress",
+                &[&done.min(total), &total],
+            ),
+            Style::default().fg(th.subtext1),
+        ),
+    ])
+}
+
 /// What: Render header chips as a compact horizontal line of metrics.
 ///
 /// Inputs:
@@ -135,6 +165,72 @@ fn render_header_chips(app: &AppState, chips: &PreflightHeaderChips) -> Line<'st
     Line::from(spans)
 }
 
+/// What: Lines describing the transaction `pacman -S --print` resolved.
+///
+/// Inputs:
+/// - `app`: Application state for translations
+/// - `resolved`: Result of the `--print` simulation
+///
+/// Output:
+/// - A heading plus either the dependencies pacman adds beyond the selection, or the failure
+///   note pointing to Pacsea's own plan below; AUR targets are listed as not simulated.
+fn resolved_transaction_lines(
+    app: &AppState,
+    resolved: &crate::state::modal::ResolvedTransaction,
+) -> Vec<Line<'static>> {
+    let th = theme();
+    let mut lines = Vec::new();
+    if let Some(err) = &resolved.error {
+        lines.push(Line::from(Span::styled(
+            i18n::t(app, "app.modals.preflight.summary.pacman_print_failed"),
+            Style::default().fg(th.yellow).add_modifier(Modifier::BOLD),
+        )));
+        lines.push(Line::from(Span::styled(
+            format!("  {err}"),
+            Style::default().fg(th.subtext1),
+        )));
+    } else if !resolved.targets.is_empty() {
+        lines.push(Line::from(Span::styled(
+            i18n::t_fmt1(
+                app,
+                "app.modals.preflight.summary.pacman_print",
+                resolved.targets.len(),
+            ),
+            Style::default()
+                .fg(th.overlay1)
+                .add_modifier(Modifier::BOLD),
+        )));
+        if resolved.extra.is_empty() {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  {}",
+                    i18n::t(app, "app.modals.preflight.summary.pacman_print_no_extra")
+                ),
+                Style::default().fg(th.green),
+            )));
+        }
+        for (name, version) in &resolved.targets {
+            if resolved.extra.contains(name) {
+                lines.push(Line::from(Span::styled(
+                    format!("  + {name} {version}"),
+                    Style::default().fg(th.yellow),
+                )));
+            }
+        }
+    }
+    if !resolved.aur_unresolved.is_empty() {
+        lines.push(Line::from(Span::styled(
+            i18n::t_fmt1(
+                app,
+                "app.modals.preflight.summary.pacman_print_aur",
+                resolved.aur_unresolved.join(", "),
+            ),
+            Style::default().fg(th.subtext1),
+        )));
+    }
+    lines
+}
+
 /// What: Render the preflight modal summarizing dependency/file checks before install/remove.
 ///
 /// Inputs:
@@ -647,6 +743,10 @@ pub fn render_preflight(
                         )));
                     }
                 }
+                if let Some(resolved) = &summary_data.resolved_transaction {
+                    lines.push(Line::from(""));
+                    lines.extend(resolved_transaction_lines(app, resolved));
+                }
                 if !summary_data.packages.is_empty() {
                     lines.push(Line::from(""));
                     lines.push(Line::from(Span::styled(
@@ -760,16 +860,16 @@ pub fn render_preflight(
                         // Summary header
                         let mut summary_parts = Vec::new();
                         if conflict_count > 0 {
-                            summary_parts.push(i18n::t_fmt1(
+                            summary_parts.push(i18n::tr_plural(
                                 app,
-                                "app.modals.preflight.summary.conflict_singular",
+                                "app.modals.preflight.summary.conflicts",
                                 conflict_count,
                             ));
                         }
                         if upgrade_count > 0 {
-                            summary_parts.push(i18n::t_fmt1(
+                            summary_parts.push(i18n::tr_plural(
                                 app,
-                                "app.modals.preflight.summary.upgrade_singular",
+                                "app.modals.preflight.summary.upgrades",
                                 upgrade_count,
                             ));
                         }
@@ -982,7 +1082,8 @@ pub fn render_preflight(
                         )));
 
                         let mut plan_command = format!(
-                            "sudo pacman {} --noconfirm {}",
+                            "{} {} --noconfirm {}",
+                            crate::install::privilege::root_pacman(),
                             mode.flag(),
                             removal_names.join(" ")
                         );
@@ -1006,7 +1107,7 @@ pub fn render_preflight(
                             )
                         } else if mode.allows_dependents() {
                             (
-                                i18n::t_fmt1(
+                                i18n::tr_plural(
                                     app,
                                     "app.modals.preflight.summary.cascade_will_include",
                                     dependent_count,
@@ -1015,7 +1116,7 @@ pub fn render_preflight(
                             )
                         } else {
                             (
-                                i18n::t_fmt1(
+                                i18n::tr_plural(
                                     app,
                                     "app.modals.preflight.summary.dependents_block_removal",
                                     dependent_count,
@@ -1231,7 +1332,7 @@ pub fn render_preflight(
                         )));
                     } else {
                         lines.push(Line::from(Span::styled(
-                            i18n::t_fmt1(
+                            i18n::tr_plural(
                                 app,
                                 "app.modals.preflight.summary.packages_selected",
                                 items.len(),
@@ -1373,6 +1474,9 @@ pub fn render_preflight(
                             i18n::t(app, "app.modals.preflight.deps.resolving"),
                             Style::default().fg(th.yellow),
                         )));
+                        if let Some((done, total)) = app.preflight_deps_progress {
+                            lines.push(progress_line(app, done, total));
+                        }
                     } else if let Some(err_msg) = deps_error {
                         // Display error with retry hint
                         lines.push(Line::from(Span::styled(
@@ -1633,6 +1737,29 @@ pub fn render_preflight(
         PreflightTab::Files => {
             let is_resolving = app.preflight_files_resolving || app.files_resolving;
 
+            // Background file-DB sync status ('f'), shown above the file list
+            match crate::logic::filedb::sync_status() {
+                crate::logic::filedb::SyncStatus::Running(progress) => {
+                    lines.push(Line::from(Span::styled(
+                        i18n::t_fmt1(app, "app.modals.preflight.files.sync_running", progress),
+                        Style::default().fg(th.yellow),
+                    )));
+                }
+                crate::logic::filedb::SyncStatus::Failed(err) => {
+                    lines.push(Line::from(Span::styled(
+                        i18n::t_fmt1(app, "app.modals.preflight.files.sync_failed", err),
+                        Style::default().fg(th.red),
+                    )));
+                }
+                crate::logic::filedb::SyncStatus::Synced { user_db: true } => {
+                    lines.push(Line::from(Span::styled(
+                        i18n::t(app, "app.modals.preflight.files.sync_user_db"),
+                        Style::default().fg(th.subtext1),
+                    )));
+                }
+                _ => {}
+            }
+
             if is_resolving {
                 // Show package headers first, then loading message
                 for item in items.iter() {
@@ -1651,6 +1778,9 @@ pub fn render_preflight(
                     i18n::t(app, "app.modals.preflight.files.updating"),
                     Style::default().fg(th.yellow),
                 )));
+                if let Some((done, total)) = app.preflight_files_progress {
+                    lines.push(progress_line(app, done, total));
+                }
             } else if let Some(err_msg) = files_error {
                 // Display error with retry hint
                 lines.push(Line::from(Span::styled(
@@ -1897,6 +2027,42 @@ pub fn render_preflight(
                     )));
                     lines.push(Line::from(""));
 
+                    // Warn about paths that several packages in the list would install
+                    let collisions = &app.preflight_file_collisions;
+                    let collision_lines = if collisions.is_empty() {
+                        0
+                    } else {
+                        const SHOWN: usize = 3;
+                        lines.push(Line::from(Span::styled(
+                            i18n::t_fmt1(
+                                app,
+                                "app.modals.preflight.files.collisions",
+                                collisions.len(),
+                            ),
+                            Style::default().fg(th.red).add_modifier(Modifier::BOLD),
+                        )));
+                        for c in collisions.iter().take(SHOWN) {
+                            lines.push(Line::from(Span::styled(
+                                format!("  {} ({})", c.path, c.packages.join(", ")),
+                                Style::default().fg(th.red),
+                            )));
+                        }
+                        let mut used = 1 + collisions.len().min(SHOWN);
+                        if collisions.len() > SHOWN {
+                            lines.push(Line::from(Span::styled(
+                                i18n::t_fmt1(
+                                    app,
+                                    "app.modals.preflight.files.collisions_more",
+                                    collisions.len() - SHOWN,
+                                ),
+                                Style::default().fg(th.subtext1),
+                            )));
+                            used += 1;
+                        }
+                        lines.push(Line::from(""));
+                        used + 1
+                    };
+
                     // Show file database sync timestamp
                     let sync_timestamp_lines =
                         if let Some((_age_days, date_str, color_category)) = sync_info.clone() {
@@ -1938,8 +2104,8 @@ pub fn render_preflight(
 
                     // Calculate available height for file list AFTER adding summary and sync timestamp
                     // Lines used before file list: tab header (1) + empty (1) + summary (1) + empty (1) + sync timestamp (0-2)
-                    // Total: 4-6 lines
-                    let header_lines = 4 + sync_timestamp_lines;
+                    // Total: 4-6 lines, plus the collision warning when present
+                    let header_lines = 4 + collision_lines + sync_timestamp_lines;
                     let available_height = (content_rect.height.saturating_sub(1) as usize)
                         .saturating_sub(header_lines)
                         .max(1);
@@ -2139,6 +2305,14 @@ pub fn render_preflight(
             }
         }
         PreflightTab::Services => {
+            lines.push(Line::from(Span::styled(
+                i18n::t_fmt1(
+                    app,
+                    "app.modals.preflight.services.init_system",
+                    crate::logic::services::detect_init_system().name(),
+                ),
+                Style::default().fg(th.subtext1),
+            )));
             if app.services_resolving {
                 lines.push(Line::from(Span::styled(
                     i18n::t(app, "app.modals.preflight.services.updating"),
@@ -2166,9 +2340,31 @@ pub fn render_preflight(
                     Style::default().fg(th.green),
                 )));
             } else {
+                // Dry-restart preview: which units the current decisions would bounce
+                let restarting: Vec<&str> = service_info
+                    .iter()
+                    .filter(|svc| matches!(svc.restart_decision, ServiceRestartDecision::Restart))
+                    .map(|svc| svc.unit_name.as_str())
+                    .collect();
+                lines.push(Line::from(Span::styled(
+                    if restarting.is_empty() {
+                        i18n::t(app, "app.modals.preflight.services.dry_restart_none")
+                    } else {
+                        i18n::t_fmt(
+                            app,
+                            "app.modals.preflight.services.dry_restart",
+                            &[&restarting.len(), &restarting.join(", ")],
+                        )
+                    },
+                    Style::default()
+                        .fg(th.overlay1)
+                        .add_modifier(Modifier::BOLD),
+                )));
+                lines.push(Line::from(""));
+
                 // Performance optimization: Only render visible items (viewport-based rendering)
                 // This prevents performance issues with large service lists
-                let available_height = content_rect.height.saturating_sub(6) as usize;
+                let available_height = content_rect.height.saturating_sub(9) as usize;
                 let visible = available_height.max(1);
                 let selected = (*service_selected).min(service_info.len().saturating_sub(1));
                 if *service_selected != selected {
@@ -2223,6 +2419,17 @@ pub fn render_preflight(
                         )
                     };
                     spans.push(status_span);
+                    let unit_state = [svc.active_state.as_str(), svc.enabled_state.as_str()]
+                        .into_iter()
+                        .filter(|st| !st.is_empty())
+                        .collect::<Vec<_>>()
+                        .join("/");
+                    if !unit_state.is_empty() {
+                        spans.push(Span::styled(
+                            format!(" ({unit_state})"),
+                            Style::default().fg(th.overlay1),
+                        ));
+                    }
                     spans.push(Span::raw(" "));
                     let decision_span = match svc.restart_decision {
                         ServiceRestartDecision::Restart => Span::styled(
@@ -2357,6 +2564,11 @@ pub fn render_preflight(
             }
             // For remove actions or when sandbox is resolving, resolution will be triggered by event handlers
 
+            // Terminal scans started from this Preflight, updated as each scanner finishes
+            let scan_lines = scan_progress_lines(app);
+            let scan_line_count = scan_lines.len();
+            lines.extend(scan_lines);
+
             // Display error if any
             if let Some(err) = sandbox_error.as_ref() {
                 lines.push(Line::from(Span::styled(
@@ -2423,7 +2635,26 @@ pub fn render_preflight(
                 );
                 let mut display_items: Vec<SandboxDisplayItem> = Vec::new();
 
-                for item in items.iter() {
+                // Riskiest AUR packages first, official (trusted) packages last
+                let [high, medium, low] = crate::logic::sandbox::risk_summary(sandbox_info);
+                lines.push(Line::from(Span::styled(
+                    i18n::t_fmt(
+                        app,
+                        "app.modals.preflight.sandbox.risk_summary",
+                        &[&high, &medium, &low],
+                    ),
+                    Style::default()
+                        .fg(if high > 0 {
+                            th.red
+                        } else if medium > 0 {
+                            th.yellow
+                        } else {
+                            th.green
+                        })
+                        .add_modifier(Modifier::BOLD),
+                )));
+
+                for item in crate::logic::sandbox::rank_by_risk(items, sandbox_info) {
                     let is_aur = matches!(item.source, crate::state::Source::Aur);
                     let is_expanded = sandbox_tree_expanded.contains(&item.name);
 
@@ -2474,7 +2705,9 @@ pub fn render_preflight(
                 // Calculate viewport based on selected index (like Deps/Files tabs)
                 // Performance optimization: Only render visible items (viewport-based rendering)
                 // This prevents performance issues with large dependency lists
-                let available_height = (content_rect.height as usize).saturating_sub(6);
+                let available_height = (content_rect.height as usize)
+                    .saturating_sub(7)
+                    .saturating_sub(scan_line_count);
                 let total_items = display_items.len();
                 tracing::debug!(
                     "[UI] Sandbox tab: total_items={}, sandbox_selected={}, items={}, sandbox_info={}, expanded_count={}",
@@ -2585,10 +2818,226 @@ pub fn render_preflight(
                             header_text = format!("{} {}", arrow_symbol, header_text);
                         }
 
-                        lines.push(Line::from(Span::styled(header_text, header_style)));
+                        let mut header_spans = vec![Span::styled(header_text, header_style)];
+                        let info = sandbox_info.iter().find(|s| s.package_name == *pkg_name);
+                        if !is_aur {
+                            header_spans.push(Span::styled(
+                                format!(
+                                    " [{}]",
+                                    i18n::t(app, "app.modals.preflight.sandbox.trusted")
+                                ),
+                                Style::default().fg(th.green),
+                            ));
+                        } else if let Some(info) = info {
+                            let (level_key, color) = match info.risk_level() {
+                                crate::state::modal::RiskLevel::Low => ("risk_low", th.green),
+                                crate::state::modal::RiskLevel::Medium => {
+                                    ("risk_medium", th.yellow)
+                                }
+                                crate::state::modal::RiskLevel::High => ("risk_high", th.red),
+                            };
+                            header_spans.push(Span::styled(
+                                format!(
+                                    " {}",
+                                    i18n::t_fmt(
+                                        app,
+                                        "app.modals.preflight.header_chips.risk_label",
+                                        &[
+                                            &i18n::t(
+                                                app,
+                                                &format!(
+                                                    "app.modals.preflight.header_chips.{level_key}"
+                                                )
+                                            ),
+                                            &info.severity_score(),
+                                        ],
+                                    )
+                                ),
+                                Style::default().fg(color).add_modifier(Modifier::BOLD),
+                            ));
+                        }
+                        lines.push(Line::from(header_spans));
 
                         last_dep_type = None;
 
+                        // Name the risky build-script commands behind the score
+                        if let Some(risk) = info.and_then(|i| i.pkgbuild_risk.as_ref()) {
+                            let reasons: Vec<String> = [
+                                (risk.curl_pipe_shell, "curl_pipe_shell"),
+                                (risk.sudo_usage, "sudo_usage"),
+                                (risk.network_access, "network_access"),
+                            ]
+                            .into_iter()
+                            .filter(|(hit, _)| *hit)
+                            .map(|(_, key)| {
+                                i18n::t(app, &format!("app.modals.preflight.sandbox.{key}"))
+                            })
+                            .collect();
+                            if !reasons.is_empty() {
+                                lines.push(Line::from(Span::styled(
+                                    format!(
+                                        "  ⚠ {}",
+                                        i18n::t_fmt1(
+                                            app,
+                                            "app.modals.preflight.sandbox.pkgbuild_risks",
+                                            reasons.join(", "),
+                                        )
+                                    ),
+                                    Style::default().fg(if risk.curl_pipe_shell {
+                                        th.red
+                                    } else {
+                                        th.yellow
+                                    }),
+                                )));
+                            }
+                        }
+
+                        // Line-level PKGBUILD findings
+                        for warning in info.map(|i| i.warnings.as_slice()).unwrap_or_default() {
+                            use crate::logic::sandbox::PkgbuildIssue;
+                            let (key, color) = match warning.issue {
+                                PkgbuildIssue::DownloadPipedToShell => ("curl_pipe_shell", th.red),
+                                PkgbuildIssue::UnpinnedGitSource => {
+                                    ("unpinned_git_source", th.yellow)
+                                }
+                                PkgbuildIssue::SudoInBuild => ("sudo_in_build", th.red),
+                                PkgbuildIssue::WriteOutsidePkgdir => {
+                                    ("write_outside_pkgdir", th.red)
+                                }
+                            };
+                            lines.push(Line::from(Span::styled(
+                                format!(
+                                    "    {}",
+                                    i18n::t_fmt(
+                                        app,
+                                        "app.modals.preflight.sandbox.pkgbuild_warning",
+                                        &[
+                                            &warning.line,
+                                            &i18n::t(
+                                                app,
+                                                &format!("app.modals.preflight.sandbox.{key}")
+                                            ),
+                                            &warning.text,
+                                        ],
+                                    )
+                                ),
+                                Style::default().fg(color),
+                            )));
+                        }
+
+                        // User-defined custom scanner: status plus the first lines of output
+                        if let Some(scan) = info.and_then(|i| i.custom_scan.as_ref()) {
+                            const SHOWN_LINES: usize = 5;
+                            let (status, color) = if scan.timed_out {
+                                (
+                                    i18n::t_fmt1(
+                                        app,
+                                        "app.modals.preflight.sandbox.custom_scan_timeout",
+                                        crate::logic::custom_scan::CUSTOM_SCAN_TIMEOUT.as_secs(),
+                                    ),
+                                    th.red,
+                                )
+                            } else if let Some(code) = scan.exit_code.filter(|c| *c != 0) {
+                                (
+                                    i18n::t_fmt1(
+                                        app,
+                                        "app.modals.preflight.sandbox.custom_scan_failed",
+                                        code,
+                                    ),
+                                    th.yellow,
+                                )
+                            } else if scan.exit_code.is_none() {
+                                (
+                                    i18n::t(app, "app.modals.preflight.sandbox.custom_scan_killed"),
+                                    th.yellow,
+                                )
+                            } else {
+                                (
+                                    i18n::t(app, "app.modals.preflight.sandbox.custom_scan_passed"),
+                                    th.green,
+                                )
+                            };
+                            let icon = if scan.is_warning() { "⚠" } else { "✓" };
+                            lines.push(Line::from(Span::styled(
+                                format!("  {icon} {status}"),
+                                Style::default().fg(color),
+                            )));
+                            let output: Vec<&str> = scan.output.lines().collect();
+                            for line in output.iter().take(SHOWN_LINES) {
+                                lines.push(Line::from(Span::styled(
+                                    format!("    │ {line}"),
+                                    Style::default().fg(th.subtext0),
+                                )));
+                            }
+                            if output.len() > SHOWN_LINES {
+                                lines.push(Line::from(Span::styled(
+                                    format!(
+                                        "    {}",
+                                        i18n::t_fmt1(
+                                            app,
+                                            "app.modals.preflight.sandbox.custom_scan_more",
+                                            output.len() - SHOWN_LINES,
+                                        )
+                                    ),
+                                    Style::default().fg(th.overlay1),
+                                )));
+                            } else if scan.truncated {
+                                lines.push(Line::from(Span::styled(
+                                    format!(
+                                        "    {}",
+                                        i18n::t(
+                                            app,
+                                            "app.modals.preflight.sandbox.custom_scan_truncated"
+                                        )
+                                    ),
+                                    Style::default().fg(th.overlay1),
+                                )));
+                            }
+                        }
+
+                        // Flag upstream sources that are not pinned by checksum (SKIP)
+                        if is_aur
+                            && let Some(info) =
+                                sandbox_info.iter().find(|s| s.package_name == *pkg_name)
+                        {
+                            let unpinned: Vec<&str> = info
+                                .sources
+                                .iter()
+                                .filter(|p| !p.pinned)
+                                .map(|p| p.source.as_str())
+                                .collect();
+                            if !unpinned.is_empty() {
+                                lines.push(Line::from(Span::styled(
+                                    format!(
+                                        "  ⚠ {}",
+                                        i18n::t_fmt(
+                                            app,
+                                            "app.modals.preflight.sandbox.unpinned_sources",
+                                            &[
+                                                &unpinned.len(),
+                                                &info.sources.len(),
+                                                &unpinned.join(", "),
+                                            ],
+                                        )
+                                    ),
+                                    Style::default().fg(th.yellow),
+                                )));
+                            }
+                            if !info.missing_pgp_keys.is_empty() {
+                                lines.push(Line::from(Span::styled(
+                                    format!(
+                                        "  ⚠ {}",
+                                        i18n::t_fmt1(
+                                            app,
+                                            "app.modals.preflight.sandbox.missing_pgp_keys",
+                                            info.missing_pgp_keys.join(", "),
+                                        )
+                                    ),
+                                    Style::default().fg(th.yellow),
+                                )));
+                            }
+                        }
+
                         // Show message for official packages or collapsed AUR packages
                         if !is_aur {
                             lines.push(Line::from(Span::styled(
@@ -2856,3 +3305,83 @@ pub fn render_preflight(
         tracing::debug!("[UI] render_preflight completed in {:?}", render_duration);
     }
 }
+
+/// What: Sandbox tab lines for terminal scans started from the open Preflight.
+///
+/// Inputs:
+/// - `app`: Application state holding `scan_progress`.
+///
+/// Output:
+/// - One status line per scan followed by its finished scanners and a blank separator; empty
+///   when no scan is tracked.
+///
+/// Details:
+/// - Scanners are listed in completion order with their elapsed time, so partial results show
+///   while the slower tools are still running.
+fn scan_progress_lines(app: &AppState) -> Vec<Line<'static>> {
+    use crate::state::modal::ScanPhase;
+    let th = theme();
+    let mut lines = Vec::new();
+    for scan in &app.scan_progress {
+        let (text, color) = match scan.phase {
+            ScanPhase::Preparing => (
+                i18n::t_fmt1(
+                    app,
+                    "app.modals.preflight.sandbox.scan_preparing",
+                    &scan.package,
+                ),
+                th.subtext0,
+            ),
+            ScanPhase::Running => (
+                i18n::t_fmt(
+                    app,
+                    "app.modals.preflight.sandbox.scan_running",
+                    &[&scan.package, &scan.finished.len(), &scan.total],
+                ),
+                th.yellow,
+            ),
+            ScanPhase::Finished => (
+                i18n::t_fmt(
+                    app,
+                    "app.modals.preflight.sandbox.scan_finished",
+                    &[&scan.package, &scan.finished.len()],
+                ),
+                th.green,
+            ),
+            ScanPhase::Cached => (
+                i18n::t_fmt1(
+                    app,
+                    "app.modals.preflight.sandbox.scan_cached",
+                    &scan.package,
+                ),
+                th.green,
+            ),
+            ScanPhase::Cancelled => (
+                i18n::t_fmt1(
+                    app,
+                    "app.modals.preflight.sandbox.scan_cancelled",
+                    &scan.package,
+                ),
+                th.red,
+            ),
+        };
+        lines.push(Line::from(Span::styled(
+            text,
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )));
+        for (name, secs) in &scan.finished {
+            lines.push(Line::from(Span::styled(
+                i18n::t_fmt(
+                    app,
+                    "app.modals.preflight.sandbox.scanner_done",
+                    &[name, secs],
+                ),
+                Style::default().fg(th.subtext1),
+            )));
+        }
+    }
+    if !lines.is_empty() {
+        lines.push(Line::from(""));
+    }
+    lines
+}
@@ -21,6 +21,7 @@ use crate::theme::theme;
 /// - `countries`: Available country list
 /// - `mirror_count`: Desired number of mirrors
 /// - `cursor`: Currently highlighted row index
+/// - `loading`: Pending upgrades are being listed for review
 ///
 /// Output:
 /// - Draws the update configuration dialog, highlighting the focused row and showing shortcuts.
@@ -28,6 +29,7 @@ use crate::theme::theme;
 /// Details:
 /// - Formats checkbox rows, displays the effective country list from settings, and surfaces key
 ///   hints for toggling, adjusting country, and running the update.
+/// - While `loading`, the key hints give way to a notice that upgrades are being checked.
 pub fn render_system_update(
     f: &mut Frame,
     app: &AppState,
@@ -40,10 +42,11 @@ pub fn render_system_update(
     countries: &[String],
     mirror_count: u16,
     cursor: usize,
+    loading: bool,
 ) {
     let th = theme();
     let w = area.width.saturating_sub(8).min(80);
-    let h = 14;
+    let h = 14u16.min(area.height);
     let x = area.x + (area.width.saturating_sub(w)) / 2;
     let y = area.y + (area.height.saturating_sub(h)) / 2;
     let rect = ratatui::prelude::Rect {
@@ -140,10 +143,17 @@ pub fn render_system_update(
     ]));
 
     lines.push(Line::from(""));
-    lines.push(Line::from(Span::styled(
-        i18n::t(app, "app.modals.system_update.footer_hint"),
-        Style::default().fg(th.subtext1),
-    )));
+    lines.push(Line::from(if loading {
+        Span::styled(
+            i18n::t(app, "app.modals.system_update.checking_upgrades"),
+            Style::default().fg(th.yellow),
+        )
+    } else {
+        Span::styled(
+            i18n::t(app, "app.modals.system_update.footer_hint"),
+            Style::default().fg(th.subtext1),
+        )
+    }));
 
     let boxw = Paragraph::new(lines)
         .style(Style::default().fg(th.text).bg(th.mantle))
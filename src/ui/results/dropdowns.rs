@@ -36,6 +36,9 @@ pub fn render_dropdowns(f: &mut Frame, app: &mut AppState, results_area: Rect) {
             i18n::t(app, "app.results.config_menu.options.install_list"),
             i18n::t(app, "app.results.config_menu.options.installed_packages"),
             i18n::t(app, "app.results.config_menu.options.recent_searches"),
+            i18n::t(app, "app.results.config_menu.options.remove_orphans"),
+            i18n::t(app, "app.results.config_menu.options.save_profile"),
+            i18n::t(app, "app.results.config_menu.options.load_profile"),
         ];
         let widest = opts.iter().map(|s| s.len()).max().unwrap_or(0) as u16;
         let w = widest
@@ -51,6 +54,8 @@ pub fn render_dropdowns(f: &mut Frame, app: &mut AppState, results_area: Rect) {
         let menu_x = cbx.min(max_x);
         let h = (opts.len() as u16) + 2; // borders
         let menu_y = results_area.y.saturating_add(1); // just below top border (rendered on top layer)
+        // Clamp to the terminal so a resize while open cannot draw past the bottom edge
+        let h = h.min(f.area().bottom().saturating_sub(menu_y));
         let rect = ratatui::prelude::Rect {
             x: menu_x,
             y: menu_y,
@@ -132,6 +137,8 @@ pub fn render_dropdowns(f: &mut Frame, app: &mut AppState, results_area: Rect) {
         let menu_x = pbx.min(max_x);
         let h = (opts.len() as u16) + 2; // borders
         let menu_y = results_area.y.saturating_add(1); // just below top border (rendered on top layer)
+        // Clamp to the terminal so a resize while open cannot draw past the bottom edge
+        let h = h.min(f.area().bottom().saturating_sub(menu_y));
         let rect = ratatui::prelude::Rect {
             x: menu_x,
             y: menu_y,
@@ -192,6 +199,26 @@ pub fn render_dropdowns(f: &mut Frame, app: &mut AppState, results_area: Rect) {
             i18n::t(app, "app.results.options_menu.update_system"),
             i18n::t(app, "app.results.options_menu.news"),
             i18n::t(app, "app.results.options_menu.tui_optional_deps"),
+            match app.search_scope {
+                crate::state::SearchScope::NameAndDescription => {
+                    i18n::t(app, "app.results.options_menu.search_scope_name_desc")
+                }
+                crate::state::SearchScope::NameOnly => {
+                    i18n::t(app, "app.results.options_menu.search_scope_name_only")
+                }
+            },
+            i18n::t(app, "app.results.options_menu.rank_mirrors"),
+            i18n::t(app, "app.results.options_menu.themes"),
+            i18n::t(app, "app.results.options_menu.check_keybinds"),
+            if app.results_filter_upgradable_only {
+                i18n::t(app, "app.results.options_menu.upgradable_only_on")
+            } else {
+                i18n::t(app, "app.results.options_menu.upgradable_only_off")
+            },
+            i18n::t(app, "app.results.options_menu.config_leftovers"),
+            i18n::t(app, "app.results.options_menu.logs"),
+            i18n::t(app, "app.results.options_menu.error_log"),
+            i18n::t(app, "app.results.options_menu.activity_log"),
         ];
         let widest = opts.iter().map(|s| s.len()).max().unwrap_or(0) as u16;
         let w = widest
@@ -207,6 +234,8 @@ pub fn render_dropdowns(f: &mut Frame, app: &mut AppState, results_area: Rect) {
         let menu_x = obx.min(max_x);
         let h = (opts.len() as u16) + 2; // borders
         let menu_y = results_area.y.saturating_add(1); // just below top border (rendered on top layer)
+        // Clamp to the terminal so a resize while open cannot draw past the bottom edge
+        let h = h.min(f.area().bottom().saturating_sub(menu_y));
         let rect = ratatui::prelude::Rect {
             x: menu_x,
             y: menu_y,
@@ -317,6 +346,8 @@ pub fn render_dropdowns(f: &mut Frame, app: &mut AppState, results_area: Rect) {
             let menu_x = artix_x.min(max_x);
             let h = (opts.len() as u16) + 2; // borders
             let menu_y = results_area.y.saturating_add(1); // just below top border
+            // Clamp to the terminal so a resize while open cannot draw past the bottom edge
+            let h = h.min(f.area().bottom().saturating_sub(menu_y));
             let rect = ratatui::prelude::Rect {
                 x: menu_x,
                 y: menu_y,
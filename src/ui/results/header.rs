@@ -0,0 +1,86 @@
+use ratatui::{
+    Frame,
+    prelude::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crate::i18n;
+use crate::state::{AppState, SortMode};
+use crate::theme::theme;
+
+/// What: Render the clickable column header row at the top of the Results list.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `app`: Mutable application state (sort mode/direction; receives header cell rects)
+/// - `area`: Rect of the overall Results block
+///
+/// Output:
+/// - Draws `Pop`, `Repo` and `Best match` cells on the first row inside the border and records
+///   one hit-test rect per cell in `app.results_header_rects`.
+///
+/// Details:
+/// - Cells follow the order of the row fields (popularity, repo label, name) and map to the
+///   AUR popularity, repo-then-name and best-match sort modes; the last cell is labelled after
+///   its relevance ranking, since it does not sort by name.
+/// - The active cell is highlighted with `▲`/`▼` for the direction; cells that do not fit are
+///   neither drawn nor recorded, so clicks on the remaining blank row do nothing.
+/// - Hidden in the compact layout (no row is reserved there).
+pub fn render_header(f: &mut Frame, app: &mut AppState, area: Rect) {
+    let th = theme();
+    app.results_header_rects.clear();
+    if app.compact_active || area.width < 4 || area.height < 4 {
+        return;
+    }
+    let row = Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: area.width - 2,
+        height: 1,
+    };
+    let cells = [
+        (
+            SortMode::AurPopularityThenOfficial,
+            i18n::t(app, "app.results.header.popularity"),
+        ),
+        (
+            SortMode::RepoThenName,
+            i18n::t(app, "app.results.header.repo"),
+        ),
+        (
+            SortMode::BestMatches,
+            i18n::t(app, "app.results.header.best_match"),
+        ),
+    ];
+    // Indent past the "> " highlight symbol so cells sit above the row text
+    let mut spans = vec![Span::raw("  ")];
+    let mut x = row.x + 2;
+    let end = row.x + row.width;
+    for (mode, label) in cells {
+        let active = app.sort_mode == mode;
+        let text = if active {
+            format!(" {label} {} ", if app.sort_descending { '▼' } else { '▲' })
+        } else {
+            format!(" {label} ")
+        };
+        let w = u16::try_from(text.chars().count()).unwrap_or(u16::MAX);
+        if x.saturating_add(w) > end {
+            break;
+        }
+        let style = if active {
+            Style::default().fg(th.mauve).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(th.overlay1)
+        };
+        spans.push(Span::styled(text, style));
+        spans.push(Span::raw(" "));
+        app.results_header_rects.push((mode, (x, row.y, w, 1)));
+        x = x.saturating_add(w + 1);
+    }
+    f.render_widget(
+        Paragraph::new(Line::from(spans)).style(Style::default().bg(th.base)),
+        row,
+    );
+}
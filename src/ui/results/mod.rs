@@ -3,13 +3,14 @@ use ratatui::{
     prelude::Rect,
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, List, ListItem},
+    widgets::{Block, BorderType, Borders, List, ListItem, Padding},
 };
 
 use crate::state::{AppState, Source};
 use crate::theme::theme;
 
 mod dropdowns;
+mod header;
 mod list;
 mod sort_menu;
 mod status;
@@ -30,6 +31,7 @@ mod utils;
 /// - Keeps selection centered when possible; shows repo/labels, versions, descriptions, and
 ///   install markers.
 /// - Builds the title with Sort button, filter toggles, and right-aligned options/config/panels.
+/// - The first row inside the border holds clickable column headers that sort the list.
 /// - Renders dropdown overlays for Sort/Options/Config/Panels when open, and records rects.
 pub fn render_results(f: &mut Frame, app: &mut AppState, area: Rect) {
     let th = theme();
@@ -134,7 +136,7 @@ pub fn render_results(f: &mut Frame, app: &mut AppState, area: Rect) {
     // when we try to mutate app.list_state after calling a function that borrows app
     let items: Vec<ListItem> = {
         let prefs = crate::theme::settings();
-        let viewport_rows = area.height.saturating_sub(2) as usize;
+        let viewport_rows = area.height.saturating_sub(2 + utils::header_rows(app)) as usize;
         let start = list_offset;
         let end = std::cmp::min(app.results.len(), start + viewport_rows);
 
@@ -184,6 +186,17 @@ pub fn render_results(f: &mut Frame, app: &mut AppState, area: Rect) {
                 };
                 let installed = crate::index::is_installed(&p.name);
                 let mut segs: Vec<Span> = Vec::new();
+                if app.multi_select_mode {
+                    let (mark, color) = if app.multi_selected.contains(&p.name) {
+                        ("[✓] ", th.green)
+                    } else {
+                        ("[ ] ", th.overlay1)
+                    };
+                    segs.push(Span::styled(
+                        mark,
+                        Style::default().fg(color).add_modifier(Modifier::BOLD),
+                    ));
+                }
                 if let Some(pop) = p.popularity {
                     segs.push(Span::styled(
                         format!("Pop: {pop:.2} "),
@@ -199,6 +212,26 @@ pub fn render_results(f: &mut Frame, app: &mut AppState, area: Rect) {
                     format!("  {}", p.version),
                     Style::default().fg(th.overlay1),
                 ));
+                if installed
+                    && let Some((version, upgradable)) = utils::installed_version_note(
+                        &p.version,
+                        crate::index::installed_version(&p.name).as_deref(),
+                    )
+                {
+                    segs.push(Span::styled(
+                        format!(
+                            " ({})",
+                            crate::i18n::t_fmt1(app, "app.results.installed_version", version)
+                        ),
+                        Style::default().fg(if upgradable { th.yellow } else { th.overlay1 }),
+                    ));
+                    if upgradable {
+                        segs.push(Span::styled(
+                            " ↑",
+                            Style::default().fg(th.yellow).add_modifier(Modifier::BOLD),
+                        ));
+                    }
+                }
                 if !desc.is_empty() {
                     segs.push(Span::raw("  - "));
                     segs.push(Span::styled(desc, Style::default().fg(th.overlay2)));
@@ -294,13 +327,17 @@ pub fn render_results(f: &mut Frame, app: &mut AppState, area: Rect) {
                 .title(Line::from(title_spans))
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(th.surface2)),
+                .border_style(Style::default().fg(th.surface2))
+                .padding(Padding::top(utils::header_rows(app))),
         )
         .highlight_style(Style::default().bg(th.surface1))
         .highlight_symbol("> ");
 
     f.render_stateful_widget(list, area, &mut app.list_state);
 
+    // Column headers in the padding row reserved above the list
+    header::render_header(f, app, area);
+
     // Draw status label on the bottom border line of the Results block
     status::render_status(f, app, area);
 
@@ -436,5 +473,7 @@ mod tests {
         assert!(app.panels_button_rect.is_some());
         assert!(app.arch_status_rect.is_some());
         assert!(app.results_rect.is_some());
+        assert_eq!(app.results_header_rects.len(), 3);
+        assert_eq!(app.results_rect.map(|r| r.1), Some(2));
     }
 }
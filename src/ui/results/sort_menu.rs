@@ -42,6 +42,8 @@ pub fn render_sort_menu(f: &mut Frame, app: &mut AppState, area: Rect, btn_x: u1
         let menu_x = btn_x.min(max_x);
         let menu_y = area.y.saturating_add(1); // just below top border
         let h = (opts.len() as u16) + 2; // borders
+        // Clamp to the terminal so a resize while open cannot draw past the bottom edge
+        let h = h.min(f.area().bottom().saturating_sub(menu_y));
         let rect = ratatui::prelude::Rect {
             x: menu_x,
             y: menu_y,
@@ -10,6 +10,142 @@ use crate::i18n;
 use crate::state::AppState;
 use crate::theme::theme;
 
+/// What: Build the extra segments shown when the verbose status line is enabled.
+///
+/// Inputs:
+/// - `app`: Application state (captured sync info/upgradable count and list lengths)
+///
+/// Output:
+/// - Text starting with a separator, e.g. ` • Files DB: 2024-05-01 (3d) • 5 upgradable • Queued: 2 install, 1 remove, 0 downgrade`.
+///
+/// Details:
+/// - Unknown values (not captured or unreadable) are shown as `?`.
+pub(crate) fn verbose_status_details(app: &AppState) -> String {
+    let db = match &app.status_db_sync {
+        Some((days, date)) => i18n::t_fmt(app, "app.results.status_verbose.db_sync", &[date, days]),
+        None => i18n::t_fmt1(app, "app.results.status_verbose.db_sync_unknown", "?"),
+    };
+    let upgradable = i18n::t_fmt1(
+        app,
+        "app.results.status_verbose.upgradable",
+        app.status_upgradable
+            .map_or_else(|| "?".to_string(), |n| n.to_string()),
+    );
+    let queued = i18n::t_fmt(
+        app,
+        "app.results.status_verbose.queued",
+        &[
+            &app.install_list.len(),
+            &app.remove_list.len(),
+            &app.downgrade_list.len(),
+        ],
+    );
+    format!(" • {db} • {upgradable} • {queued}")
+}
+
+/// What: Build the status segment listing resolution stages currently in progress.
+///
+/// Inputs:
+/// - `app`: Application state (translations)
+/// - `stages`: Open stage spans, usually from [`crate::logic::stage_trace::active_stages`]
+///
+/// Output:
+/// - Text starting with a separator, e.g. ` • Stages: files 3/12, dependencies 0/4`, or the idle
+///   label when nothing is running.
+pub(crate) fn stage_status_details(
+    app: &AppState,
+    stages: &[crate::logic::stage_trace::StageProgress],
+) -> String {
+    let list = if stages.is_empty() {
+        i18n::t(app, "app.results.status_verbose.stages_idle")
+    } else {
+        stages
+            .iter()
+            .map(|p| format!("{} {}/{}", p.stage, p.done, p.total))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    format!(
+        " • {}",
+        i18n::t_fmt1(app, "app.results.status_verbose.stages", list)
+    )
+}
+
+/// What: Build the official index freshness indicator shown next to the status label.
+///
+/// Inputs:
+/// - `app`: Application state (translations)
+/// - `age`: Age of the persisted index, from the cached `AppState::index_modified_at`
+///
+/// Output:
+/// - `Some((text, color_category))`, e.g. `("index: 3d old", 0)`, bucketed like the files
+///   database sync info; `None` when the age is unknown.
+pub(crate) fn index_age_label(
+    app: &AppState,
+    age: Option<std::time::Duration>,
+) -> Option<(String, u8)> {
+    let days = age?.as_secs() / 86400;
+    Some((
+        i18n::t_fmt1(app, "app.results.index_age", days),
+        crate::logic::files::age_color_category(days),
+    ))
+}
+
+/// What: Build the pending-operations summary shown next to the status label.
+///
+/// Inputs:
+/// - `app`: Application state (queued lists, details cache, translations)
+///
+/// Output:
+/// - `Some` text like `Install: 5 (120.0 MiB) · Remove: 2 · Downgrade: 1` listing only non-empty
+///   lists; `None` when nothing is queued.
+///
+/// Details:
+/// - The install size sums the cached download sizes; it is omitted when no size is known
+///   (e.g. only AUR packages) and shown as a lower bound (`≥`) when some are unknown.
+pub(crate) fn pending_ops_label(app: &AppState) -> Option<String> {
+    let mut parts = Vec::new();
+    if !app.install_list.is_empty() {
+        let sizes: Vec<Option<u64>> = app
+            .install_list
+            .iter()
+            .map(|p| app.details_cache.get(&p.name).and_then(|d| d.download_size))
+            .collect();
+        let known: u64 = sizes.iter().flatten().sum();
+        let count = app.install_list.len();
+        parts.push(if sizes.iter().all(Option::is_none) {
+            i18n::t_fmt1(app, "app.results.pending_ops.install", count)
+        } else {
+            let size = crate::ui::helpers::human_bytes(known);
+            let size = if sizes.iter().any(Option::is_none) {
+                format!("≥{size}")
+            } else {
+                size
+            };
+            i18n::t_fmt(
+                app,
+                "app.results.pending_ops.install_size",
+                &[&count, &size],
+            )
+        });
+    }
+    if !app.remove_list.is_empty() {
+        parts.push(i18n::t_fmt1(
+            app,
+            "app.results.pending_ops.remove",
+            app.remove_list.len(),
+        ));
+    }
+    if !app.downgrade_list.is_empty() {
+        parts.push(i18n::t_fmt1(
+            app,
+            "app.results.pending_ops.downgrade",
+            app.downgrade_list.len(),
+        ));
+    }
+    (!parts.is_empty()).then(|| parts.join(" · "))
+}
+
 /// What: Draw the status label on the bottom border line of the Results block.
 ///
 /// Inputs:
@@ -23,6 +159,7 @@ use crate::theme::theme;
 /// Details:
 /// - Shows optional shortcut when Search normal mode is active, centers text within the border, and
 ///   colors the status dot based on [`AppState::arch_status_color`].
+/// - The index age and the queued-operations summary ([`pending_ops_label`]) follow the text.
 pub fn render_status(f: &mut Frame, app: &mut AppState, area: Rect) {
     let th = theme();
 
@@ -36,7 +173,7 @@ pub fn render_status(f: &mut Frame, app: &mut AppState, area: Rect) {
     let show_key = matches!(app.focus, crate::state::Focus::Search)
         && app.search_normal_mode
         && key_label_opt.is_some();
-    let status_text = if show_key {
+    let mut status_text = if show_key {
         i18n::t_fmt(
             app,
             "app.results.status_with_key",
@@ -49,12 +186,34 @@ pub fn render_status(f: &mut Frame, app: &mut AppState, area: Rect) {
             app.arch_status_text
         )
     };
+    if app.status_verbose {
+        status_text.push_str(&verbose_status_details(app));
+    }
+    if app.status_spans {
+        let stages = crate::logic::stage_trace::active_stages();
+        status_text.push_str(&stage_status_details(app, &stages));
+    }
+    let index_age = index_age_label(
+        app,
+        app.index_modified_at
+            .and_then(|modified| std::time::SystemTime::now().duration_since(modified).ok()),
+    );
+    let pending = pending_ops_label(app);
     let sx = area.x.saturating_add(2); // a bit of left padding after corner
     let sy = area.y.saturating_add(area.height.saturating_sub(1));
     let maxw = area.width.saturating_sub(4); // avoid right corner
+    // Reserve room for the index indicator (two spaces + label) after the status text
+    let index_w = index_age
+        .as_ref()
+        .map_or(0, |(label, _)| label.chars().count() as u16 + 2);
+    // Same for the pending-operations summary after it
+    let pending_w = pending
+        .as_ref()
+        .map_or(0, |label| label.chars().count() as u16 + 2);
+    let extra_w = index_w + pending_w;
     let mut content = status_text.clone();
-    if content.len() as u16 > maxw {
-        content.truncate(maxw as usize);
+    if content.len() as u16 > maxw.saturating_sub(extra_w) {
+        content.truncate(maxw.saturating_sub(extra_w) as usize);
     }
     // Compute style to blend with border line
     // Compose a dot + text with color depending on status
@@ -89,7 +248,7 @@ pub fn render_status(f: &mut Frame, app: &mut AppState, area: Rect) {
         .fg(th.mauve)
         .bg(th.base)
         .add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
-    let line = Paragraph::new(Line::from(vec![
+    let mut spans = vec![
         Span::styled(
             dot.to_string(),
             Style::default()
@@ -99,9 +258,29 @@ pub fn render_status(f: &mut Frame, app: &mut AppState, area: Rect) {
         ),
         Span::raw(" "),
         Span::styled(content.clone(), style_text),
-    ]));
+    ];
+    if let Some((label, category)) = &index_age {
+        let color = match category {
+            0 => th.green,
+            1 => th.yellow,
+            _ => th.red,
+        };
+        spans.push(Span::raw("  "));
+        spans.push(Span::styled(
+            label.clone(),
+            Style::default().fg(color).bg(th.base),
+        ));
+    }
+    if let Some(label) = &pending {
+        spans.push(Span::raw("  "));
+        spans.push(Span::styled(
+            label.clone(),
+            Style::default().fg(th.lavender).bg(th.base),
+        ));
+    }
+    let line = Paragraph::new(Line::from(spans));
     // Record clickable rect centered within the available width
-    let cw = ((content.len() + dot.len() + 1) as u16).min(maxw); // +1 for the space
+    let cw = ((content.len() + dot.len() + 1) as u16 + extra_w).min(maxw); // +1 for the space
     let pad_left = maxw.saturating_sub(cw) / 2;
     let start_x = sx.saturating_add(pad_left);
     // Clickable rect only over the text portion, not the dot or space
@@ -112,6 +291,18 @@ pub fn render_status(f: &mut Frame, app: &mut AppState, area: Rect) {
         (content.len() as u16).min(maxw.saturating_sub((dot.len() + 1) as u16)),
         1,
     ));
+    // Clicking the index indicator requests an official index refresh
+    app.index_age_rect = index_age.as_ref().map(|_| {
+        let index_x = click_start_x.saturating_add(content.len() as u16 + 2);
+        (
+            index_x,
+            sy,
+            index_w
+                .saturating_sub(2)
+                .min(start_x.saturating_add(cw).saturating_sub(index_x)),
+            1,
+        )
+    });
     let rect = ratatui::prelude::Rect {
         x: start_x,
         y: sy,
@@ -120,3 +311,155 @@ pub fn render_status(f: &mut Frame, app: &mut AppState, area: Rect) {
     };
     f.render_widget(line, rect);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure the verbose status segments combine freshness, upgradable and queued counts.
+    ///
+    /// Inputs:
+    /// - Seeded sync info (3 days, fixed date), 5 upgradable, and 2/1/0 queued install/remove/downgrade.
+    ///
+    /// Output:
+    /// - The assembled string lists each value in order; missing captures render as `?`.
+    ///
+    /// Details:
+    /// - Uses inline translations so the test does not depend on locale files.
+    fn verbose_status_details_assembles_seeded_values() {
+        let mut app = AppState::default();
+        for (k, v) in [
+            ("app.results.status_verbose.db_sync", "Files DB: {} ({}d)"),
+            ("app.results.status_verbose.db_sync_unknown", "Files DB: {}"),
+            ("app.results.status_verbose.upgradable", "{} upgradable"),
+            (
+                "app.results.status_verbose.queued",
+                "Queued: {} install, {} remove, {} downgrade",
+            ),
+        ] {
+            app.translations.insert(k.to_string(), v.to_string());
+        }
+        let item = |name: &str| crate::state::PackageItem {
+            name: name.to_string(),
+            version: "1".into(),
+            description: String::new(),
+            source: crate::state::Source::Aur,
+            popularity: None,
+        };
+        app.install_list = vec![item("a"), item("b")];
+        app.remove_list = vec![item("c")];
+        app.status_db_sync = Some((3, "2024-05-01 10:00:00".into()));
+        app.status_upgradable = Some(5);
+        assert_eq!(
+            verbose_status_details(&app),
+            " • Files DB: 2024-05-01 10:00:00 (3d) • 5 upgradable • Queued: 2 install, 1 remove, 0 downgrade"
+        );
+
+        app.status_db_sync = None;
+        app.status_upgradable = None;
+        assert_eq!(
+            verbose_status_details(&app),
+            " • Files DB: ? • ? upgradable • Queued: 2 install, 1 remove, 0 downgrade"
+        );
+    }
+
+    #[test]
+    /// What: Ensure the pending-operations summary counts each list and sums known sizes.
+    ///
+    /// Inputs:
+    /// - Two queued installs with cached sizes, then one without a size, plus remove/downgrade entries.
+    ///
+    /// Output:
+    /// - Exact size when all are known, a `≥` bound when some are missing, count only when none
+    ///   are known, and `None` with empty lists.
+    ///
+    /// Details:
+    /// - Uses inline translations so the test does not depend on locale files.
+    fn pending_ops_label_counts_and_sizes() {
+        let mut app = AppState::default();
+        for (k, v) in [
+            ("app.results.pending_ops.install", "Install: {}"),
+            ("app.results.pending_ops.install_size", "Install: {} ({})"),
+            ("app.results.pending_ops.remove", "Remove: {}"),
+            ("app.results.pending_ops.downgrade", "Downgrade: {}"),
+        ] {
+            app.translations.insert(k.to_string(), v.to_string());
+        }
+        assert_eq!(pending_ops_label(&app), None);
+        let item = |name: &str| crate::state::PackageItem {
+            name: name.to_string(),
+            version: "1".into(),
+            description: String::new(),
+            source: crate::state::Source::Aur,
+            popularity: None,
+        };
+        for (name, size) in [("a", 1024 * 1024), ("b", 1024 * 1024)] {
+            app.details_cache.insert(
+                name.to_string(),
+                crate::state::PackageDetails {
+                    name: name.to_string(),
+                    download_size: Some(size),
+                    ..Default::default()
+                },
+            );
+        }
+        app.install_list = vec![item("a"), item("b")];
+        assert_eq!(
+            pending_ops_label(&app).as_deref(),
+            Some("Install: 2 (2.0 MiB)")
+        );
+        app.install_list.push(item("aur-only"));
+        app.remove_list = vec![item("c"), item("d")];
+        app.downgrade_list = vec![item("e")];
+        assert_eq!(
+            pending_ops_label(&app).as_deref(),
+            Some("Install: 3 (≥2.0 MiB) · Remove: 2 · Downgrade: 1")
+        );
+        app.install_list = vec![item("aur-only")];
+        app.remove_list.clear();
+        app.downgrade_list.clear();
+        assert_eq!(pending_ops_label(&app).as_deref(), Some("Install: 1"));
+    }
+
+    #[test]
+    /// What: Ensure the stage segment lists active stages with their progress counts.
+    ///
+    /// Inputs:
+    /// - A `files` stage at 3/12 and a `dependencies` stage at 0/4, then no stages.
+    ///
+    /// Output:
+    /// - ` • Stages: files 3/12, dependencies 0/4`, then the idle label.
+    ///
+    /// Details:
+    /// - Stages are passed explicitly so the test does not depend on the global tracing layer.
+    fn stage_status_details_lists_active_stages() {
+        use crate::logic::stage_trace::StageProgress;
+        let mut app = AppState::default();
+        app.translations.insert(
+            "app.results.status_verbose.stages".to_string(),
+            "Stages: {}".to_string(),
+        );
+        app.translations.insert(
+            "app.results.status_verbose.stages_idle".to_string(),
+            "idle".to_string(),
+        );
+        let stages = vec![
+            StageProgress {
+                stage: "files".into(),
+                done: 3,
+                total: 12,
+            },
+            StageProgress {
+                stage: "dependencies".into(),
+                done: 0,
+                total: 4,
+            },
+        ];
+        assert_eq!(
+            stage_status_details(&app, &stages),
+            " • Stages: files 3/12, dependencies 0/4"
+        );
+        assert_eq!(stage_status_details(&app, &[]), " • Stages: idle");
+    }
+}
@@ -8,6 +8,23 @@ use crate::i18n;
 use crate::state::AppState;
 use crate::theme::theme;
 
+/// What: Text of the Results title, e.g. `Results (12)`.
+///
+/// Inputs:
+/// - `app`: Application state (locale and local-fallback flag).
+/// - `results_len`: Number of visible results.
+///
+/// Output:
+/// - The title, with a "local results" marker when the results came from the offline fallback.
+fn results_title_text(app: &AppState, results_len: usize) -> String {
+    let base = format!("{} ({})", i18n::t(app, "app.results.title"), results_len);
+    if app.search_local_fallback {
+        format!("{base} {}", i18n::t(app, "app.results.local_fallback"))
+    } else {
+        base
+    }
+}
+
 /// What: Build title spans with Sort button, filter toggles, and right-aligned buttons.
 ///
 /// This version takes individual values instead of &AppState to avoid borrow conflicts.
@@ -60,7 +77,7 @@ pub fn build_title_spans_from_values(
     results_filter_show_manjaro: bool,
 ) -> Vec<Span<'static>> {
     let th = theme();
-    let results_title_text = format!("{} ({})", i18n::t(app, "app.results.title"), results_len);
+    let results_title_text = results_title_text(app, results_len);
     let sort_button_label = format!("{} v", i18n::t(app, "app.results.buttons.sort"));
     let options_button_label = format!("{} v", i18n::t(app, "app.results.buttons.options"));
     let panels_button_label = format!("{} v", i18n::t(app, "app.results.buttons.panels"));
@@ -84,7 +101,7 @@ pub fn build_title_spans_from_values(
     };
     title_spans.push(Span::styled(sort_button_label.clone(), btn_style));
     title_spans.push(Span::raw("  "));
-    // Filter toggles: [AUR] [core] [extra] [multilib] and optional [EOS]/[CachyOS]
+    // Filter toggles: [AUR] (unless disabled) [core] [extra] [multilib] and optional [EOS]/[CachyOS]
     let filt = |label: &str, on: bool| -> Span<'static> {
         let (fg, bg) = if on {
             (th.crust, th.green)
@@ -96,11 +113,14 @@ pub fn build_title_spans_from_values(
             Style::default().fg(fg).bg(bg).add_modifier(Modifier::BOLD),
         )
     };
-    title_spans.push(filt(
-        &i18n::t(app, "app.results.filters.aur"),
-        results_filter_show_aur,
-    ));
-    title_spans.push(Span::raw(" "));
+    let aur_hidden = crate::sources::aur_disabled();
+    if !aur_hidden {
+        title_spans.push(filt(
+            &i18n::t(app, "app.results.filters.aur"),
+            results_filter_show_aur,
+        ));
+        title_spans.push(Span::raw(" "));
+    }
     title_spans.push(filt(
         &i18n::t(app, "app.results.filters.core"),
         results_filter_show_core,
@@ -133,6 +153,8 @@ pub fn build_title_spans_from_values(
     // to determine if we should show Artix-specific repo filters
     let inner_width = area.width.saturating_sub(2); // exclude borders
     let aur_label = format!("[{}]", i18n::t(app, "app.results.filters.aur"));
+    // [AUR] plus its trailing space; nothing when the AUR is disabled
+    let aur_w = if aur_hidden { 0 } else { aur_label.len() + 1 };
     let core_label = format!("[{}]", i18n::t(app, "app.results.filters.core"));
     let extra_label = format!("[{}]", i18n::t(app, "app.results.filters.extra"));
     let multilib_label = format!("[{}]", i18n::t(app, "app.results.filters.multilib"));
@@ -153,8 +175,7 @@ pub fn build_title_spans_from_values(
         + 2 // spaces before Sort
         + sort_button_label.len()
         + 2 // spaces after Sort
-        + aur_label.len()
-        + 1 // space
+        + aur_w
         + core_label.len()
         + 1 // space
         + extra_label.len()
@@ -209,8 +230,7 @@ pub fn build_title_spans_from_values(
             + 2 // spaces before Sort
             + sort_button_label.len()
             + 2 // spaces after Sort
-            + aur_label.len()
-            + 1 // space
+            + aur_w
             + core_label.len()
             + 1 // space
             + extra_label.len()
@@ -416,11 +436,7 @@ pub fn record_title_rects(
     has_artix_system: bool,
     has_manjaro: bool,
 ) {
-    let results_title_text = format!(
-        "{} ({})",
-        i18n::t(app, "app.results.title"),
-        app.results.len()
-    );
+    let results_title_text = results_title_text(app, app.results.len());
     let sort_button_label = format!("{} v", i18n::t(app, "app.results.buttons.sort"));
     let options_button_label = format!("{} v", i18n::t(app, "app.results.buttons.options"));
     let panels_button_label = format!("{} v", i18n::t(app, "app.results.buttons.panels"));
@@ -443,10 +459,15 @@ pub fn record_title_rects(
         (start_x, btn_y, label.len() as u16, 1)
     };
     let aur_label = "[AUR]";
-    app.results_filter_aur_rect = Some(rec_rect(x_cursor, aur_label));
-    x_cursor = x_cursor
-        .saturating_add(aur_label.len() as u16)
-        .saturating_add(1);
+    let aur_hidden = crate::sources::aur_disabled();
+    if aur_hidden {
+        app.results_filter_aur_rect = None;
+    } else {
+        app.results_filter_aur_rect = Some(rec_rect(x_cursor, aur_label));
+        x_cursor = x_cursor
+            .saturating_add(aur_label.len() as u16)
+            .saturating_add(1);
+    }
     let core_label = "[core]";
     app.results_filter_core_rect = Some(rec_rect(x_cursor, core_label));
     x_cursor = x_cursor
@@ -483,7 +504,7 @@ pub fn record_title_rects(
     // Right-aligned Config/Lists, Panels and Options buttons: compute remaining space first
     // to determine if we should show Artix-specific repo filters
     let inner_width = area.width.saturating_sub(2); // exclude borders
-    let aur_label = "[AUR]";
+    let aur_w = if aur_hidden { 0 } else { aur_label.len() + 1 };
     let core_label = "[core]";
     let extra_label = "[extra]";
     let multilib_label = "[multilib]";
@@ -502,8 +523,7 @@ pub fn record_title_rects(
         + 2 // spaces before Sort
         + sort_button_label.len()
         + 2 // spaces after Sort
-        + aur_label.len()
-        + 1 // space
+        + aur_w
         + core_label.len()
         + 1 // space
         + extra_label.len()
@@ -557,8 +577,7 @@ pub fn record_title_rects(
             + 2 // spaces before Sort
             + sort_button_label.len()
             + 2 // spaces after Sort
-            + aur_label.len()
-            + 1 // space
+            + aur_w
             + core_label.len()
             + 1 // space
             + extra_label.len()
@@ -2,6 +2,21 @@ use ratatui::prelude::Rect;
 
 use crate::state::{AppState, Source};
 
+/// What: Installed-version note for a Results row.
+///
+/// Inputs:
+/// - `available`: Version shown for the result (official index or AUR RPC).
+/// - `installed`: Version reported by `pacman -Q`, if the package is installed.
+///
+/// Output:
+/// - `None` when not installed or already at `available`; otherwise the installed version and
+///   whether `available` is newer (an upgrade candidate).
+pub fn installed_version_note(available: &str, installed: Option<&str>) -> Option<(String, bool)> {
+    let installed = installed.filter(|v| !v.is_empty() && *v != available)?;
+    let upgradable = crate::logic::filter::is_newer_version(available, installed);
+    Some((installed.to_string(), upgradable))
+}
+
 /// What: Detect availability of optional repos from the unfiltered results set.
 ///
 /// Inputs:
@@ -93,6 +108,14 @@ pub fn detect_optional_repos(
     )
 }
 
+/// What: Rows reserved for the column header inside the Results border.
+///
+/// Output:
+/// - `1` normally; `0` in the compact layout, which hides the header.
+pub fn header_rows(app: &AppState) -> u16 {
+    u16::from(!app.compact_active)
+}
+
 /// What: Keep the results selection centered within the visible viewport when possible.
 ///
 /// Inputs:
@@ -106,7 +129,7 @@ pub fn detect_optional_repos(
 /// - Recenters around the selected index for long lists, resets offset for short lists, and ensures
 ///   the selection is applied even when the filtered list shrinks.
 pub fn center_selection(app: &mut AppState, area: Rect) {
-    let viewport_rows = area.height.saturating_sub(2) as usize; // account for borders
+    let viewport_rows = area.height.saturating_sub(2 + header_rows(app)) as usize; // borders and header row
     let len = app.results.len();
     let selected_idx = if app.results.is_empty() {
         None
@@ -148,14 +171,64 @@ pub fn center_selection(app: &mut AppState, area: Rect) {
 /// - Updates `app.results_rect` with the inner content rectangle.
 ///
 /// Details:
-/// - Offsets by one cell to exclude borders and reduces width/height accordingly for accurate
-///   click detection.
+/// - Excludes the borders and the column header row so row clicks map to list indices.
 pub fn record_results_rect(app: &mut AppState, area: Rect) {
-    // Record inner results rect for mouse hit-testing (inside borders)
+    // Record inner results rect for mouse hit-testing (inside borders, below the header row)
+    let header = header_rows(app);
     app.results_rect = Some((
         area.x + 1,
-        area.y + 1,
+        area.y + 1 + header,
         area.width.saturating_sub(2),
-        area.height.saturating_sub(2),
+        area.height.saturating_sub(2 + header),
     ));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure rows note differing installed versions and flag only newer available ones.
+    ///
+    /// Inputs:
+    /// - Not installed, same version, older installed, newer (e.g. VCS) installed, epoch and
+    ///   pre-release versions.
+    ///
+    /// Output:
+    /// - No note for the first two; the installed version with the upgrade flag set only when
+    ///   the available version is newer by `vercmp` rules.
+    fn installed_version_note_flags_upgrades() {
+        assert_eq!(installed_version_note("1.2-1", None), None);
+        assert_eq!(installed_version_note("1.2-1", Some("1.2-1")), None);
+        assert_eq!(
+            installed_version_note("1.10-1", Some("1.9-2")),
+            Some(("1.9-2".to_string(), true))
+        );
+        assert_eq!(
+            installed_version_note("1.0-1", Some("1.0.r42-1")),
+            Some(("1.0.r42-1".to_string(), false))
+        );
+        // A higher epoch wins regardless of pkgver
+        assert_eq!(
+            installed_version_note("2.1-1", Some("1:2.0-1")),
+            Some(("1:2.0-1".to_string(), false))
+        );
+        assert_eq!(
+            installed_version_note("1:2.0-1", Some("2.1-1")),
+            Some(("2.1-1".to_string(), true))
+        );
+        // Alpha suffixes mark pre-releases, which sort before the final release
+        assert_eq!(
+            installed_version_note("1.0-1", Some("1.0rc1-1")),
+            Some(("1.0rc1-1".to_string(), true))
+        );
+        assert_eq!(
+            installed_version_note("1.0rc1-1", Some("1.0-1")),
+            Some(("1.0-1".to_string(), false))
+        );
+        assert_eq!(
+            installed_version_note("1.0-2", Some("1.0-1")),
+            Some(("1.0-1".to_string(), true))
+        );
+    }
+}
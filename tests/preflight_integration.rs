@@ -190,6 +190,8 @@ async fn preflight_handles_out_of_order_data_arrival() {
         unit_name: "test.service".to_string(),
         providers: vec!["test-package-1".to_string()],
         is_active: true,
+        active_state: String::new(),
+        enabled_state: String::new(),
         needs_restart: true,
         recommended_decision: crate_root::state::modal::ServiceRestartDecision::Restart,
         restart_decision: crate_root::state::modal::ServiceRestartDecision::Restart,
@@ -317,6 +319,7 @@ async fn preflight_handles_out_of_order_data_arrival() {
             service_restart_units: vec![],
             summary_warnings: vec![],
             summary_notes: vec![],
+            resolved_transaction: None,
         },
         header: crate_root::state::modal::PreflightHeaderChips {
             package_count: test_packages.len(),
@@ -235,6 +235,7 @@ fn test_ui_renders_with_details() {
         owner: "testuser".to_string(),
         build_date: "2024-01-01".to_string(),
         popularity: None,
+        aur: None,
     };
 
     let _terminal = render_ui_to_backend(backend, &mut app);
@@ -465,6 +466,7 @@ fn test_modal_confirm_renders() {
             source: Source::Aur,
             popularity: None,
         }],
+        guard: None,
     };
 
     let terminal = render_ui_to_backend(backend, &mut app);